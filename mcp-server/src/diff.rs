@@ -0,0 +1,129 @@
+//! Minimal line-based unified diff, sized for generated layout markup.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Edit script between two line slices via a longest-common-subsequence
+/// table. Common prefix and suffix are stripped first, which keeps the table
+/// small for the typical "one option changed" case.
+fn edit_script(old: &[&str], new: &[&str]) -> Vec<Op> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let a = &old[prefix..old.len() - suffix];
+    let b = &new[prefix..new.len() - suffix];
+
+    // lcs[i][j] = LCS length of a[i..] and b[j..]
+    let width = b.len() + 1;
+    let mut lcs = vec![0u32; (a.len() + 1) * width];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i * width + j] = if a[i] == b[j] {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut ops = vec![Op::Equal; prefix];
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            ops.push(Op::Equal);
+            i += 1;
+            j += 1;
+        } else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+            ops.push(Op::Delete);
+            i += 1;
+        } else {
+            ops.push(Op::Insert);
+            j += 1;
+        }
+    }
+    ops.extend(std::iter::repeat_n(Op::Delete, a.len() - i));
+    ops.extend(std::iter::repeat_n(Op::Insert, b.len() - j));
+    ops.extend(std::iter::repeat_n(Op::Equal, suffix));
+    ops
+}
+
+/// Renders a unified diff with `context` lines around each change. Returns
+/// an empty string when the inputs are identical.
+pub fn unified(old: &str, new: &str, old_label: &str, new_label: &str, context: usize) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let ops = edit_script(&old_lines, &new_lines);
+    if ops.iter().all(|op| *op == Op::Equal) {
+        return String::new();
+    }
+
+    // Position in old/new before each op, so hunks can be sliced by op index.
+    let mut positions = Vec::with_capacity(ops.len() + 1);
+    let (mut oi, mut ni) = (0, 0);
+    for op in &ops {
+        positions.push((oi, ni));
+        match op {
+            Op::Equal => {
+                oi += 1;
+                ni += 1;
+            }
+            Op::Delete => oi += 1,
+            Op::Insert => ni += 1,
+        }
+    }
+    positions.push((oi, ni));
+
+    // Merge change runs whose context windows overlap into hunks.
+    let changes: Vec<usize> = (0..ops.len()).filter(|&k| ops[k] != Op::Equal).collect();
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for &k in &changes {
+        let start = k.saturating_sub(context);
+        let end = (k + 1 + context).min(ops.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut out = format!("--- {}\n+++ {}\n", old_label, new_label);
+    for (start, end) in hunks {
+        let (os, ns) = positions[start];
+        let (oe, ne) = positions[end];
+        // An empty range is reported at the line before it, as diff(1) does.
+        let start_of = |from: usize, to: usize| if to > from { from + 1 } else { from };
+        out.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            start_of(os, oe),
+            oe - os,
+            start_of(ns, ne),
+            ne - ns
+        ));
+        let (mut oi, mut ni) = (os, ns);
+        for op in &ops[start..end] {
+            match op {
+                Op::Equal => {
+                    out.push_str(&format!(" {}\n", old_lines[oi]));
+                    oi += 1;
+                    ni += 1;
+                }
+                Op::Delete => {
+                    out.push_str(&format!("-{}\n", old_lines[oi]));
+                    oi += 1;
+                }
+                Op::Insert => {
+                    out.push_str(&format!("+{}\n", new_lines[ni]));
+                    ni += 1;
+                }
+            }
+        }
+    }
+    out
+}
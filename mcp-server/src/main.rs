@@ -1,617 +1,1464 @@
+// The tool list is one large `json!` literal.
+#![recursion_limit = "256"]
+
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use serde_json::{json, Value};
-use std::collections::HashMap;
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
 use std::io::{self, BufRead, Write};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+#[path = "../../src/a11y.rs"]
+mod a11y;
+#[path = "../../src/cache.rs"]
+mod cache;
+#[path = "../../src/concepts.rs"]
+mod concepts;
+mod diff;
+#[path = "../../src/docs.rs"]
+mod docs;
+#[path = "../../src/document.rs"]
+mod document;
+#[path = "../../src/footprint.rs"]
+mod footprint;
+#[path = "../../src/frameworks.rs"]
+mod frameworks;
+#[path = "../../src/html.rs"]
+mod html;
+#[path = "../../src/idea.rs"]
+mod idea;
+#[path = "../../src/ids.rs"]
+mod ids;
+#[path = "../../src/images.rs"]
+mod images;
+#[path = "../../src/index.rs"]
+mod index;
+#[path = "../../src/jinja.rs"]
+mod jinja;
+mod palette;
+#[path = "../../src/playground.rs"]
+mod playground;
+#[path = "../../src/plugins.rs"]
+mod plugins;
+mod preview;
+mod provenance;
+#[path = "../../src/purity.rs"]
+mod purity;
+#[path = "../../src/responsive.rs"]
+mod responsive;
+#[path = "../../src/reverse.rs"]
+mod reverse;
+#[path = "../../src/rtl.rs"]
+mod rtl;
+#[path = "../../src/sample.rs"]
+mod sample;
+#[path = "../../src/sections.rs"]
+mod sections;
+#[path = "../../src/site.rs"]
+mod site;
+#[path = "../../src/snippets.rs"]
+mod snippets;
+#[path = "../../src/theme.rs"]
+mod theme;
+#[path = "../../src/tidy.rs"]
+mod tidy;
+#[path = "../../src/variant.rs"]
+mod variant;
+
+use cache::DocsCache;
+use concepts::ConceptEngine;
+use ids::IdAllocator;
+use sample::Sample;
+use sections::Page;
+use snippets::{AvatarShape, AvatarSize};
+use variant::Variant;
 
 const DAISYUI_DOCS_CONTENT: &str = include_str!("llms.txt");
 
-#[derive(Debug, Clone)]
-struct DocsCache {
-    components: HashMap<String, String>,
-    index: HashMap<String, Vec<String>>,
+/// One `daisyui_search` hit in `"format": "json"` output.
+#[derive(Debug, Clone, Serialize)]
+struct SearchResult {
+    name: String,
+    score: usize,
+    /// Plain text, without Markdown.
+    snippet: String,
+    /// `[start, end)` character offsets of each query word in `snippet`.
+    matches: Vec<[usize; 2]>,
 }
 
-impl DocsCache {
-    fn load() -> Self {
-        let mut components = HashMap::new();
-        let mut index: HashMap<String, Vec<String>> = HashMap::new();
-        let mut current_component = String::new();
-        let mut current_content = String::new();
-
-        for line in DAISYUI_DOCS_CONTENT.lines() {
-            if let Some(stripped) = line.strip_prefix("### ") {
-                if !current_component.is_empty() {
-                    let key = current_component.trim().to_lowercase();
-                    components.insert(key.clone(), current_content.trim().to_string());
-
-                    for word in current_content.split_whitespace() {
-                        let word_lower = word.to_lowercase();
-                        if word_lower.len() > 3 {
-                            index
-                                .entry(word_lower)
-                                .or_insert_with(Vec::new)
-                                .push(key.clone());
-                        }
-                    }
-                }
-                current_component = stripped.to_string();
-                current_content = String::new();
-                current_content.push_str(line);
-                current_content.push('\n');
-            } else if !current_component.is_empty() {
-                current_content.push_str(line);
-                current_content.push('\n');
-            }
+/// Options a layout was generated with; what the diff tool compares.
+#[derive(Debug, Clone, PartialEq)]
+struct LayoutOptions {
+    layout: String,
+    title: String,
+    theme: Option<String>,
+    annotate_responsive: bool,
+    purity: String,
+    id_prefix: Option<String>,
+    /// Design concept whose classes are added to the layout's components.
+    concept: Option<String>,
+    /// Chart types for the `analytics` layout, in order.
+    charts: Option<Vec<String>>,
+    /// Density variant; unknown names render as comfortable.
+    variant: String,
+    /// Wrap the layout in a standalone HTML document.
+    full_document: bool,
+    /// `html`, or `jsx` for an exported React component.
+    target: String,
+    /// Whitespace: `pretty` or `minified`.
+    format: String,
+    /// `ltr`, or `rtl` to mirror the layout for right-to-left languages.
+    direction: String,
+    /// Image strategy: `remote` stock photos or a local, svg or skeleton
+    /// stand-in.
+    placeholders: String,
+    /// Seed for the sample content; the title's hash when unset.
+    seed: Option<u64>,
+    /// How many items the layout repeats, by key (`cards`, `columns`, ...).
+    counts: BTreeMap<String, u64>,
+    /// Entries of `counts` that weren't whole numbers, reported as notes.
+    invalid_counts: BTreeMap<String, Value>,
+    /// Markup for the layout's slot sections (`hero`, `main`, ...), by name.
+    slots: BTreeMap<String, String>,
+}
+
+impl LayoutOptions {
+    fn from_args(args: Option<&serde_json::Map<String, Value>>) -> Self {
+        let arg = |key: &str| args.and_then(|a| a.get(key)).and_then(|v| v.as_str());
+        let (counts, invalid_counts) = Self::counts_arg(args).unwrap_or_default();
+        Self {
+            layout: arg("layout").unwrap_or("saas").to_string(),
+            title: arg("title").unwrap_or("My App").to_string(),
+            theme: arg("theme").map(String::from),
+            annotate_responsive: args
+                .and_then(|a| a.get("annotate_responsive"))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            purity: arg("purity").unwrap_or("tailwind").to_string(),
+            id_prefix: arg("id_prefix").map(String::from),
+            concept: arg("concept").map(String::from),
+            charts: Self::charts_arg(args),
+            variant: arg("variant").unwrap_or(variant::DEFAULT).to_string(),
+            full_document: args
+                .and_then(|a| a.get("full_document"))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            target: arg("target").unwrap_or("html").to_string(),
+            format: arg("format").unwrap_or("pretty").to_string(),
+            direction: arg("direction").unwrap_or("ltr").to_string(),
+            placeholders: arg("placeholders").unwrap_or("remote").to_string(),
+            seed: args.and_then(|a| a.get("seed")).and_then(|v| v.as_u64()),
+            counts,
+            invalid_counts,
+            slots: Self::slots_arg(args).unwrap_or_default(),
         }
-        if !current_component.is_empty() {
-            let key = current_component.trim().to_lowercase();
-            components.insert(key.clone(), current_content.trim().to_string());
-
-            for word in current_content.split_whitespace() {
-                let word_lower = word.to_lowercase();
-                if word_lower.len() > 3 {
-                    index
-                        .entry(word_lower)
-                        .or_insert_with(Vec::new)
-                        .push(key.clone());
+    }
+
+    /// String entries of the `slots` object; others are dropped.
+    fn slots_arg(
+        args: Option<&serde_json::Map<String, Value>>,
+    ) -> Option<BTreeMap<String, String>> {
+        args.and_then(|a| a.get("slots"))
+            .and_then(|v| v.as_object())
+            .map(|slots| {
+                slots
+                    .iter()
+                    .filter_map(|(name, markup)| Some((name.clone(), markup.as_str()?.to_string())))
+                    .collect()
+            })
+    }
+
+    /// Entries of the `counts` object split into whole numbers and the
+    /// rest (negative, fractional or not numbers at all).
+    fn counts_arg(
+        args: Option<&serde_json::Map<String, Value>>,
+    ) -> Option<(BTreeMap<String, u64>, BTreeMap<String, Value>)> {
+        let counts = args.and_then(|a| a.get("counts"))?.as_object()?;
+        let mut whole = BTreeMap::new();
+        let mut invalid = BTreeMap::new();
+        for (key, n) in counts {
+            match n.as_u64() {
+                Some(n) => {
+                    whole.insert(key.clone(), n);
+                }
+                None => {
+                    invalid.insert(key.clone(), n.clone());
                 }
             }
         }
-        DocsCache { components, index }
+        Some((whole, invalid))
     }
 
-    fn list_components(&self) -> Vec<String> {
-        let mut keys: Vec<String> = self.components.keys().cloned().collect();
-        keys.sort();
-        keys
+    /// The counts this layout reads, with a note for each invalid, ignored
+    /// or clamped one.
+    fn counts(&self) -> (sample::Counts, Vec<String>) {
+        let (counts, notes) = sample::Counts::parse(
+            &self.layout,
+            self.counts.iter().map(|(key, n)| (key.as_str(), *n)),
+        );
+        let invalid = self.invalid_counts.iter().map(|(key, n)| {
+            format!(
+                "Ignored count '{}': {} is not a whole number of items.",
+                key, n
+            )
+        });
+        (counts, invalid.chain(notes).collect())
     }
 
-    fn get_doc(&self, name: &str) -> Option<String> {
-        if name.is_empty() {
-            return None;
-        }
-        self.components.get(&name.to_lowercase()).cloned()
+    /// Sample content seeded from `seed` or the title, with the counts.
+    fn sample(&self) -> Sample {
+        Sample::new(self.seed.unwrap_or_else(|| sample::seed(&self.title)))
+            .with_counts(self.counts().0)
     }
 
-    fn search(&self, query: &str) -> Vec<(String, String)> {
-        if query.is_empty() {
-            return Vec::new();
-        }
+    fn charts_arg(args: Option<&serde_json::Map<String, Value>>) -> Option<Vec<String>> {
+        args.and_then(|a| a.get("charts"))
+            .and_then(|v| v.as_array())
+            .map(|charts| {
+                charts
+                    .iter()
+                    .filter_map(|c| c.as_str().map(String::from))
+                    .collect()
+            })
+    }
 
-        let query = query.to_lowercase();
-        let mut results = Vec::new();
-        let mut scores: HashMap<String, usize> = HashMap::new();
+    /// Same as `from_args`, but unset keys inherit from `base`.
+    fn merged(base: &Self, args: Option<&serde_json::Map<String, Value>>) -> Self {
+        let arg = |key: &str| args.and_then(|a| a.get(key)).and_then(|v| v.as_str());
+        let (counts, invalid_counts) = Self::counts_arg(args)
+            .unwrap_or_else(|| (base.counts.clone(), base.invalid_counts.clone()));
+        Self {
+            layout: arg("layout").unwrap_or(&base.layout).to_string(),
+            title: arg("title").unwrap_or(&base.title).to_string(),
+            theme: arg("theme")
+                .map(String::from)
+                .or_else(|| base.theme.clone()),
+            annotate_responsive: args
+                .and_then(|a| a.get("annotate_responsive"))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(base.annotate_responsive),
+            purity: arg("purity").unwrap_or(&base.purity).to_string(),
+            id_prefix: arg("id_prefix")
+                .map(String::from)
+                .or_else(|| base.id_prefix.clone()),
+            concept: arg("concept")
+                .map(String::from)
+                .or_else(|| base.concept.clone()),
+            charts: Self::charts_arg(args).or_else(|| base.charts.clone()),
+            variant: arg("variant").unwrap_or(&base.variant).to_string(),
+            full_document: args
+                .and_then(|a| a.get("full_document"))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(base.full_document),
+            target: arg("target").unwrap_or(&base.target).to_string(),
+            format: arg("format").unwrap_or(&base.format).to_string(),
+            direction: arg("direction").unwrap_or(&base.direction).to_string(),
+            placeholders: arg("placeholders")
+                .unwrap_or(&base.placeholders)
+                .to_string(),
+            seed: args
+                .and_then(|a| a.get("seed"))
+                .and_then(|v| v.as_u64())
+                .or(base.seed),
+            counts,
+            invalid_counts,
+            slots: Self::slots_arg(args).unwrap_or_else(|| base.slots.clone()),
+        }
+    }
 
-        for (name, content) in &self.components {
-            let mut score = 0;
+    /// The layout's HTML. An unknown concept is left out; callers check it
+    /// with [`ConceptEngine::apply`] first.
+    fn render(&self, concepts: &ConceptEngine) -> String {
+        self.render_with_notes(concepts).0
+    }
 
-            if name.contains(&query) {
-                score += 100;
+    /// [`Self::render`], with notes on the slots that weren't used and a
+    /// warning for slot markup holding a script.
+    fn render_with_notes(&self, concepts: &ConceptEngine) -> (String, Vec<String>) {
+        let mut ids = IdAllocator::new(self.id_prefix.as_deref());
+        let variant = Variant::parse(&self.variant).unwrap_or_default();
+        let mut page = match &self.charts {
+            Some(charts) if self.layout == "analytics" && !charts.is_empty() => {
+                let charts: Vec<&str> = charts.iter().map(String::as_str).collect();
+                LayoutEngine::analytics(
+                    &snippets::escape_title(&self.title),
+                    &charts,
+                    &variant,
+                    &mut ids,
+                )
             }
+            _ => LayoutEngine::build_with_purity(
+                &self.layout,
+                &self.title,
+                &self.purity,
+                &mut self.sample(),
+                &variant,
+                &mut ids,
+            ),
+        };
+        if let Some(concept) = &self.concept {
+            page = page.map(|markup| {
+                concepts
+                    .apply(concept, markup)
+                    .unwrap_or_else(|_| markup.to_string())
+            });
+        }
+        // After the concept, so the caller's markup keeps its classes.
+        let slots: Vec<(&str, &str)> = self
+            .slots
+            .iter()
+            .map(|(name, markup)| (name.as_str(), markup.as_str()))
+            .collect();
+        let (mut html, notes) = sections::fill(&self.layout, &page, &slots);
+        // A full document carries the theme on `<html>` for its toggle.
+        if let Some(theme) = self.theme.as_ref().filter(|_| !self.full_document) {
+            html = theme::scope(&html, theme);
+        }
+        html = images::substitute(&html, &self.placeholders);
+        if self.direction == "rtl" {
+            html = rtl::apply(&html);
+        }
+        if self.annotate_responsive {
+            html = responsive::annotate(&html);
+        }
+        if self.full_document {
+            html = document::wrap(
+                &html,
+                &snippets::escape_title(&self.title),
+                self.theme.as_deref(),
+            );
+        }
+        html = tidy::tidy(&html, tidy::Style::parse(&self.format).unwrap_or_default());
+        if let Some(target) = frameworks::Target::parse(&self.target) {
+            html =
+                frameworks::layout_component(&html, &snippets::escape_title(&self.title), target);
+        }
+        if let Some(engine) = jinja::Engine::parse(&self.target) {
+            html = jinja::template(&html, &snippets::escape_title(&self.title), engine);
+        }
+        (html, notes)
+    }
 
-            if content.to_lowercase().contains(&query) {
-                score += 10;
-            }
+    /// Every option, defaults included, as recorded in provenance stamps.
+    /// `from_args` reads it back unchanged.
+    fn canonical(&self) -> Value {
+        json!({
+            "layout": self.layout,
+            "title": self.title,
+            "theme": self.theme,
+            "annotate_responsive": self.annotate_responsive,
+            "purity": self.purity,
+            "id_prefix": self.id_prefix,
+            "concept": self.concept,
+            "charts": self.charts,
+            "variant": self.variant,
+            "full_document": self.full_document,
+            "target": self.target,
+            "format": self.format,
+            "direction": self.direction,
+            "placeholders": self.placeholders,
+            "seed": self.seed,
+            "counts": self.counts,
+            "slots": self.slots,
+        })
+    }
 
-            for word in query.split_whitespace() {
-                if let Some(matches) = self.index.get(word) {
-                    if matches.contains(name) {
-                        score += 5;
-                    }
+    /// One line per option that differs, e.g. `theme: (none) → dark`.
+    fn changes(&self, other: &Self) -> Vec<String> {
+        let opt = |t: &Option<String>| t.clone().unwrap_or_else(|| "(none)".into());
+        let mut out = Vec::new();
+        if self.layout != other.layout {
+            out.push(format!("layout: {} → {}", self.layout, other.layout));
+        }
+        if self.title != other.title {
+            out.push(format!("title: {} → {}", self.title, other.title));
+        }
+        if self.theme != other.theme {
+            out.push(format!(
+                "theme: {} → {}",
+                opt(&self.theme),
+                opt(&other.theme)
+            ));
+        }
+        if self.id_prefix != other.id_prefix {
+            out.push(format!(
+                "id_prefix: {} → {}",
+                opt(&self.id_prefix),
+                opt(&other.id_prefix)
+            ));
+        }
+        if self.concept != other.concept {
+            out.push(format!(
+                "concept: {} → {}",
+                opt(&self.concept),
+                opt(&other.concept)
+            ));
+        }
+        if self.purity != other.purity {
+            out.push(format!("purity: {} → {}", self.purity, other.purity));
+        }
+        if self.charts != other.charts {
+            let charts = |c: &Option<Vec<String>>| {
+                c.as_ref()
+                    .map_or_else(|| "(default)".into(), |c| c.join(", "))
+            };
+            out.push(format!(
+                "charts: {} → {}",
+                charts(&self.charts),
+                charts(&other.charts)
+            ));
+        }
+        if self.variant != other.variant {
+            out.push(format!("variant: {} → {}", self.variant, other.variant));
+        }
+        if self.full_document != other.full_document {
+            out.push(format!(
+                "full_document: {} → {}",
+                self.full_document, other.full_document
+            ));
+        }
+        if self.target != other.target {
+            out.push(format!("target: {} → {}", self.target, other.target));
+        }
+        if self.format != other.format {
+            out.push(format!("format: {} → {}", self.format, other.format));
+        }
+        if self.direction != other.direction {
+            out.push(format!(
+                "direction: {} → {}",
+                self.direction, other.direction
+            ));
+        }
+        if self.placeholders != other.placeholders {
+            out.push(format!(
+                "placeholders: {} → {}",
+                self.placeholders, other.placeholders
+            ));
+        }
+        if self.counts != other.counts {
+            let counts = |c: &BTreeMap<String, u64>| {
+                if c.is_empty() {
+                    return "(default)".to_string();
                 }
-            }
+                c.iter()
+                    .map(|(key, n)| format!("{}={}", key, n))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            out.push(format!(
+                "counts: {} → {}",
+                counts(&self.counts),
+                counts(&other.counts)
+            ));
+        }
+        if self.slots != other.slots {
+            let slots = |s: &BTreeMap<String, String>| {
+                if s.is_empty() {
+                    return "(none)".to_string();
+                }
+                s.keys().cloned().collect::<Vec<_>>().join(", ")
+            };
+            out.push(format!(
+                "slots: {} → {}",
+                slots(&self.slots),
+                slots(&other.slots)
+            ));
+        }
+        if self.seed != other.seed {
+            let seed = |s: Option<u64>| s.map_or_else(|| "(title)".into(), |s| s.to_string());
+            out.push(format!("seed: {} → {}", seed(self.seed), seed(other.seed)));
+        }
+        if self.annotate_responsive != other.annotate_responsive {
+            out.push(format!(
+                "annotate_responsive: {} → {}",
+                self.annotate_responsive, other.annotate_responsive
+            ));
+        }
+        out
+    }
+}
 
-            if score > 0 {
-                scores.insert(name.clone(), score);
-            }
+/// Per-process state shared across requests.
+#[derive(Default)]
+struct Session {
+    last_layout: Mutex<Option<LayoutOptions>>,
+    /// `daisyui_random_component` and `daisyui_style_recipe` calls without
+    /// a seed; each seeds the next.
+    draws: AtomicU64,
+    /// Theme names generated in this session, accepted as `theme` alongside
+    /// the built-in ones.
+    themes: Mutex<Vec<String>>,
+}
+
+impl Session {
+    fn remember(&self, opts: &LayoutOptions) {
+        if let Ok(mut last) = self.last_layout.lock() {
+            *last = Some(opts.clone());
         }
+    }
 
-        let mut sorted_keys: Vec<_> = scores.keys().cloned().collect();
-        sorted_keys.sort_by(|a, b| scores[b].cmp(&scores[a]).then(a.cmp(b)));
+    fn last(&self) -> Option<LayoutOptions> {
+        self.last_layout.lock().ok().and_then(|l| l.clone())
+    }
 
-        for key in sorted_keys.iter().take(20) {
-            if let Some(content) = self.components.get(key) {
-                results.push((key.clone(), content.clone()));
-            }
+    fn remember_theme(&self, name: &str) {
+        if let Ok(mut themes) = self.themes.lock() {
+            themes.retain(|t| t != name);
+            themes.push(name.to_string());
         }
+    }
 
-        results
+    fn themes(&self) -> Vec<String> {
+        self.themes.lock().map(|t| t.clone()).unwrap_or_default()
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-struct DesignConcept {
-    name: String,
-    description: String,
-    classes: Vec<String>,
-    suggestion: String,
-    snippet: String,
+fn layout_diff(from: &LayoutOptions, to: &LayoutOptions, concepts: &ConceptEngine) -> String {
+    let changes = from.changes(to);
+    if changes.is_empty() {
+        return "Options are identical; nothing changed.".into();
+    }
+    let diff = diff::unified(
+        &from.render(concepts),
+        &to.render(concepts),
+        "before",
+        "after",
+        3,
+    );
+    let body = if diff.is_empty() {
+        "The generated HTML is identical.".to_string()
+    } else {
+        format!("```diff\n{}```", diff)
+    };
+    format!("Changed options:\n- {}\n\n{}", changes.join("\n- "), body)
 }
 
-struct ConceptEngine {
-    concepts: HashMap<String, DesignConcept>,
-}
+/// Template layouts loaded at startup; empty until `main` sets it.
+static TEMPLATES: OnceLock<plugins::Registry> = OnceLock::new();
 
-impl ConceptEngine {
-    fn new() -> Self {
-        let mut m = HashMap::new();
-
-        m.insert(
-            "glassmorphism".to_string(),
-            DesignConcept {
-                name: "Glassmorphism".to_string(),
-                description: "Frosted glass aesthetic with transparency and blur effects".to_string(),
-                classes: vec!["glass".to_string(), "backdrop-blur".to_string()],
-                suggestion: "Apply glass class to cards and modals for depth".to_string(),
-                snippet: r##"<div class="card glass w-96 shadow-xl"><div class="card-body">Content</div></div>"##.to_string(),
-            },
-        );
+fn templates() -> &'static plugins::Registry {
+    TEMPLATES.get_or_init(plugins::Registry::default)
+}
 
-        m.insert(
-            "neumorphism".to_string(),
-            DesignConcept {
-                name: "Neumorphism".to_string(),
-                description: "Soft shadows creating extruded surface effect".to_string(),
-                classes: vec!["shadow-lg".to_string(), "bg-base-200".to_string()],
-                suggestion: "Combine soft shadows with subtle gradients".to_string(),
-                snippet: r##"<button class="btn shadow-lg bg-base-200">Button</button>"##
-                    .to_string(),
-            },
-        );
+/// Reads every `*.html` template in `dir`, sorted by file name. A missing
+/// directory means no templates.
+fn load_templates(dir: &std::path::Path) -> plugins::Registry {
+    let mut paths: Vec<_> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok().map(|e| e.path()))
+                .filter(|p| p.extension().is_some_and(|ext| ext == "html"))
+                .collect()
+        })
+        .unwrap_or_default();
+    paths.sort();
+    let mut errors = Vec::new();
+    let mut files = Vec::new();
+    for path in paths {
+        let name = path.display().to_string();
+        match std::fs::read_to_string(&path) {
+            Ok(text) => files.push((name, text)),
+            Err(e) => errors.push(format!("{}: {}", name, e)),
+        }
+    }
+    let mut registry = plugins::Registry::load(files, LayoutEngine::LAYOUTS);
+    errors.append(&mut registry.errors);
+    registry.errors = errors;
+    registry
+}
 
-        m.insert(
-            "darkmode".to_string(),
-            DesignConcept {
-                name: "Dark Mode".to_string(),
-                description: "Dark color scheme with high contrast for reduced eye strain".to_string(),
-                classes: vec!["bg-base-100".to_string(), "text-base-content".to_string()],
-                suggestion: "Use data-theme attribute to toggle between light and dark themes".to_string(),
-                snippet: r##"<html data-theme="dark"><body class="bg-base-100 text-base-content">Content</body></html>"##.to_string(),
-            },
-        );
+struct LayoutEngine;
 
-        m.insert(
-            "gradient".to_string(),
-            DesignConcept {
-                name: "Gradients".to_string(),
-                description: "Color transitions for visual depth and interest".to_string(),
-                classes: vec!["bg-gradient-to-r".to_string(), "from-primary".to_string(), "to-secondary".to_string()],
-                suggestion: "Use gradients sparingly on hero sections and CTAs".to_string(),
-                snippet: r##"<div class="bg-gradient-to-r from-primary to-secondary p-8">Hero Content</div>"##.to_string(),
-            },
-        );
+impl LayoutEngine {
+    const LAYOUTS: &[&str] = &[
+        "saas",
+        "blog",
+        "social",
+        "kanban",
+        "inbox",
+        "profile",
+        "docs",
+        "dashboard",
+        "auth",
+        "store",
+        "pricing",
+        "checkout",
+        "error",
+        "crud",
+        "app-landing",
+        "forum",
+        "analytics",
+        "settings",
+        "status",
+        "media",
+        "jobs",
+        "changelog",
+        "team",
+        "faq",
+        "survey",
+    ];
 
-        Self { concepts: m }
+    /// Built-in layouts followed by template layouts.
+    fn layouts() -> Vec<&'static str> {
+        let mut all = Self::LAYOUTS.to_vec();
+        all.extend(templates().layouts.iter().map(|t| t.key.as_str()));
+        all
     }
 
-    fn get_concept(&self, query: &str) -> Option<&DesignConcept> {
-        if query.is_empty() {
-            return None;
-        }
-        self.concepts.get(&query.to_lowercase())
+    /// A layout's markup, its sections joined back together.
+    fn generate(layout: &str, title: &str, v: &Variant, ids: &mut IdAllocator) -> String {
+        Self::build(layout, title, v, ids).html()
     }
 
-    fn list_concepts(&self) -> Vec<String> {
-        let mut v: Vec<String> = self.concepts.keys().cloned().collect();
-        v.sort();
-        v
+    /// Sample content is seeded from the unescaped title.
+    fn build(layout: &str, title: &str, v: &Variant, ids: &mut IdAllocator) -> Page {
+        Self::build_seeded(layout, title, &mut Sample::new(sample::seed(title)), v, ids)
     }
-}
-
-struct LayoutEngine;
 
-impl LayoutEngine {
-    fn generate(layout: &str, title: &str) -> String {
-        let sanitized_title = Self::sanitize_text(title);
+    /// Sample content, and how many items repeat, come from `s`. Template
+    /// layouts have no named sections.
+    fn build_seeded(
+        layout: &str,
+        title: &str,
+        s: &mut Sample,
+        v: &Variant,
+        ids: &mut IdAllocator,
+    ) -> Page {
+        let sanitized_title = snippets::escape_title(title);
+        if let Some(template) = templates().get(layout) {
+            return template.render(&sanitized_title, ids).into();
+        }
 
         match layout {
-            "saas" => Self::saas_landing(&sanitized_title),
-            "blog" => Self::blog_layout(&sanitized_title),
-            "social" => Self::social_feed(&sanitized_title),
-            "kanban" => Self::kanban_board(&sanitized_title),
-            "inbox" => Self::inbox_layout(&sanitized_title),
-            "profile" => Self::settings_profile(&sanitized_title),
-            "docs" => Self::docs_layout(&sanitized_title),
-            "dashboard" => Self::dashboard(&sanitized_title),
-            "auth" => Self::auth_page(&sanitized_title),
-            "store" => Self::store_page(&sanitized_title),
-            _ => Self::saas_landing(&sanitized_title),
+            "saas" => Self::saas_landing(&sanitized_title, v, ids),
+            "blog" => Self::blog_layout(&sanitized_title, v, ids, s),
+            "social" => Self::social_feed(&sanitized_title, v, s),
+            "kanban" => Self::kanban_board(&sanitized_title, v, ids, s),
+            "inbox" => Self::inbox_layout(&sanitized_title, v, ids, s),
+            "profile" => Self::settings_profile(&sanitized_title, v, ids),
+            "docs" => Self::docs_layout(&sanitized_title, &ids.next("drawer"), v),
+            "dashboard" => Self::dashboard(&sanitized_title, &ids.next("drawer"), v),
+            "auth" => Self::auth_page(&sanitized_title, v, ids),
+            "store" => Self::store_page(&sanitized_title, v, ids, s),
+            "pricing" => {
+                snippets::pricing_page(&sanitized_title, &ids.next("billing"), &ids.next("faq"), v)
+            }
+            "checkout" => snippets::checkout_page(&sanitized_title, &ids.next("checkout"), v),
+            "error" => snippets::error_page(&sanitized_title, v),
+            "crud" => snippets::crud_page(&sanitized_title, &ids.next("crud"), v),
+            "app-landing" => snippets::app_landing(&sanitized_title, &ids.next("app"), v),
+            "forum" => snippets::forum_page(&sanitized_title, &ids.next("forum"), v),
+            "analytics" => Self::analytics(&sanitized_title, snippets::DEFAULT_CHARTS, v, ids),
+            "settings" => snippets::settings_page(&sanitized_title, &ids.next("settings"), v),
+            "status" => snippets::status_page(&sanitized_title, &ids.next("subscribe"), v),
+            "media" => snippets::media_page(&sanitized_title, &ids.next("drawer"), v),
+            "jobs" => snippets::jobs_page(&sanitized_title, &ids.next("jobs"), v, s),
+            "changelog" => snippets::changelog_page(&sanitized_title, v),
+            "team" => snippets::team_page(&sanitized_title, &ids.next("team"), v),
+            "faq" => snippets::faq_page(&sanitized_title, &ids.next("help"), v),
+            "survey" => snippets::survey_page(&sanitized_title, &ids.next("survey"), v),
+            _ => Self::saas_landing(&sanitized_title, v, ids),
         }
     }
 
-    fn sanitize_text(text: &str) -> String {
-        text.chars()
-            .filter(|c| c.is_alphanumeric() || c.is_whitespace() || *c == '-' || *c == '_')
-            .take(100)
-            .collect()
+    /// `daisy-only` swaps in the layout's daisyUI-only variant when it has one.
+    fn build_with_purity(
+        layout: &str,
+        title: &str,
+        purity: &str,
+        s: &mut Sample,
+        v: &Variant,
+        ids: &mut IdAllocator,
+    ) -> Page {
+        match purity {
+            "daisy-only" => purity::layout(layout, &snippets::escape_title(title), s, v, ids)
+                .unwrap_or_else(|| Self::build_seeded(layout, title, s, v, ids)),
+            _ => Self::build_seeded(layout, title, s, v, ids),
+        }
     }
 
-    fn saas_landing(title: &str) -> String {
-        format!(
-            r##"
+    fn saas_landing(title: &str, v: &Variant, ids: &mut IdAllocator) -> Page {
+        let [
+            text_xl,
+            gap2,
+            px1,
+            text_5xl,
+            py6,
+            ml2,
+            py24,
+            px4,
+            text_3xl,
+            mb12,
+            gap8,
+            p3,
+            mb2,
+            p10,
+        ] = [
+            v.text("xl"),
+            v.space("gap", 2),
+            v.space("px", 1),
+            v.text("5xl"),
+            v.space("py", 6),
+            v.space("ml", 2),
+            v.space("py", 24),
+            v.space("px", 4),
+            v.text("3xl"),
+            v.space("mb", 12),
+            v.space("gap", 8),
+            v.space("p", 3),
+            v.space("mb", 2),
+            v.space("p", 10),
+        ];
+        let logos = snippets::logo_cloud(&snippets::default_logos(), &ids.next("logos"));
+        let features = ids.next("features");
+        let testimonials =
+            snippets::testimonials(&snippets::default_testimonials(), &ids.next("testimonials"));
+        let pricing =
+            snippets::pricing_tiers(&snippets::default_pricing_tiers(), &ids.next("pricing"));
+        Page::default()
+            .markup(
+                r#"
 <div class="min-h-screen bg-base-100 font-sans">
-  <!-- Navbar -->
-  <div class="navbar bg-base-100 sticky top-0 z-50 border-b border-base-200">
-    <div class="flex-1"><a class="btn btn-ghost text-xl font-bold">{}</a></div>
-    <div class="flex-none gap-2">
-       <ul class="menu menu-horizontal px-1 hidden sm:flex">
+  <!-- Navbar -->"#,
+            )
+            .section(
+                "navbar",
+                format!(
+                    r#"
+  <nav class="navbar bg-base-100 sticky top-0 z-50 border-b border-base-200" aria-label="Main">
+    <div class="flex-1"><a class="btn btn-ghost {text_xl} font-bold">{title}</a></div>
+    <div class="flex-none {gap2}">
+       <ul class="menu menu-horizontal {px1} hidden sm:flex">
          <li><a>Features</a></li>
          <li><a>Pricing</a></li>
          <li><a>Contact</a></li>
        </ul>
        <button class="btn btn-primary">Get Started</button>
     </div>
-  </div>
-
-  <!-- Hero -->
-  <div class="hero min-h-[80vh] bg-base-200">
+  </nav>"#
+                ),
+            )
+            .markup("\n\n  <!-- Hero -->")
+            .section(
+                "hero",
+                format!(
+                    r#"
+  <header class="hero min-h-[80vh] bg-base-200">
     <div class="hero-content text-center">
       <div class="max-w-2xl">
-        <h1 class="text-5xl font-extrabold tracking-tight">Build faster with <span class="text-primary">Daisy Days</span></h1>
-        <p class="py-6 text-xl text-base-content/80">The ultimate scaffolding engine for modern web applications. Stop writing boilerplate.</p>
+        <h1 class="{text_5xl} font-extrabold tracking-tight">Build faster with <span class="text-primary">Daisy Days</span></h1>
+        <p class="{py6} {text_xl} text-base-content/80">The ultimate scaffolding engine for modern web applications. Stop writing boilerplate.</p>
         <button class="btn btn-primary btn-lg">Start Free Trial</button>
-        <button class="btn btn-ghost btn-lg ml-2">Read Docs</button>
+        <button class="btn btn-ghost btn-lg {ml2}">Read Docs</button>
       </div>
     </div>
-  </div>
+  </header>"#
+                ),
+            )
+            .markup(
+                r#"
 
-  <!-- Features Grid -->
-  <div class="py-24 bg-base-100">
-    <div class="container mx-auto px-4">
-      <h2 class="text-3xl font-bold text-center mb-12">Everything you need</h2>
-      <div class="grid grid-cols-1 md:grid-cols-3 gap-8">
+  <main>
+  <!-- Logo Cloud -->
+"#,
+            )
+            .section("logos", logos)
+            .markup("\n\n  <!-- Features Grid -->")
+            .section(
+                "features",
+                format!(
+                    r#"
+  <section class="{py24} bg-base-100" aria-labelledby="{features}">
+    <div class="container mx-auto {px4}">
+      <h2 id="{features}" class="{text_3xl} font-bold text-center {mb12}">Everything you need</h2>
+      <div class="grid grid-cols-1 md:grid-cols-3 {gap8}">
         <div class="card bg-base-200 shadow-sm border border-base-300">
           <div class="card-body">
-             <div class="p-3 bg-primary/10 w-fit rounded-lg text-primary mb-2">⚡</div>
+             <div class="{p3} bg-primary/10 w-fit rounded-lg text-primary {mb2}">⚡</div>
              <h3 class="card-title">Lightning Fast</h3>
              <p>Optimized for speed and performance out of the box.</p>
           </div>
         </div>
         <div class="card bg-base-200 shadow-sm border border-base-300">
           <div class="card-body">
-             <div class="p-3 bg-primary/10 w-fit rounded-lg text-primary mb-2">🔒</div>
+             <div class="{p3} bg-primary/10 w-fit rounded-lg text-primary {mb2}">🔒</div>
              <h3 class="card-title">Secure by Default</h3>
              <p>Bank-grade security standards applied automatically.</p>
           </div>
         </div>
         <div class="card bg-base-200 shadow-sm border border-base-300">
           <div class="card-body">
-             <div class="p-3 bg-primary/10 w-fit rounded-lg text-primary mb-2">🎨</div>
+             <div class="{p3} bg-primary/10 w-fit rounded-lg text-primary {mb2}">🎨</div>
              <h3 class="card-title">Themable</h3>
              <p>Change the look and feel in seconds with DaisyUI themes.</p>
           </div>
         </div>
       </div>
     </div>
-  </div>
+  </section>"#
+                ),
+            )
+            .markup(
+                r#"
+
+  <!-- Testimonials -->
+"#,
+            )
+            .section("testimonials", testimonials)
+            .markup(
+                r#"
 
-  <!-- Footer -->
-  <footer class="footer p-10 bg-base-300 text-base-content">
-    <nav>
-      <header class="footer-title">Services</header>
+  <!-- Pricing -->
+"#,
+            )
+            .section("pricing", pricing)
+            .markup(
+                r#"
+  </main>
+
+  <!-- Footer -->"#,
+            )
+            .section(
+                "footer",
+                format!(
+                    r#"
+  <footer class="footer {p10} bg-base-300 text-base-content">
+    <nav aria-label="Services">
+      <h6 class="footer-title">Services</h6>
       <a class="link link-hover">Branding</a>
       <a class="link link-hover">Design</a>
     </nav>
-    <nav>
-      <header class="footer-title">Company</header>
+    <nav aria-label="Company">
+      <h6 class="footer-title">Company</h6>
       <a class="link link-hover">About us</a>
       <a class="link link-hover">Contact</a>
     </nav>
-    <nav>
-      <header class="footer-title">Legal</header>
+    <nav aria-label="Legal">
+      <h6 class="footer-title">Legal</h6>
       <a class="link link-hover">Terms of use</a>
       <a class="link link-hover">Privacy policy</a>
     </nav>
-  </footer>
-</div>
-"##,
-            title
-        )
+  </footer>"#
+                ),
+            )
+            .markup("\n</div>\n")
     }
 
-    fn blog_layout(title: &str) -> String {
-        format!(
-            r##"
-<div class="min-h-screen bg-base-100">
-  <div class="navbar bg-base-100 border-b border-base-200">
+    fn blog_layout(title: &str, v: &Variant, ids: &mut IdAllocator, s: &mut Sample) -> Page {
+        let [
+            text_2xl,
+            px4,
+            py12,
+            mb16,
+            text_4xl,
+            mb4,
+            text_lg,
+            mt4,
+            gap12,
+            mb6,
+            pb2,
+            gap8,
+            gap6,
+            mb2,
+            text_xl,
+            mt2,
+            text_sm,
+            p6,
+            gap2,
+            p3,
+        ] = [
+            v.text("2xl"),
+            v.space("px", 4),
+            v.space("py", 12),
+            v.space("mb", 16),
+            v.text("4xl"),
+            v.space("mb", 4),
+            v.text("lg"),
+            v.space("mt", 4),
+            v.space("gap", 12),
+            v.space("mb", 6),
+            v.space("pb", 2),
+            v.space("gap", 8),
+            v.space("gap", 6),
+            v.space("mb", 2),
+            v.text("xl"),
+            v.space("mt", 2),
+            v.text("sm"),
+            v.space("p", 6),
+            v.space("gap", 2),
+            v.space("p", 3),
+        ];
+        let posts = ids.next("posts");
+        let headlines = s.many(sample::HEADLINES, s.count("posts", 2) + 1);
+        let topics = s.distinct(sample::TOPICS, 4);
+        let lede = s.sentence();
+        let stories: String = headlines[1..]
+            .iter()
+            .zip(
+                ["photo-1559181567-c3190ca9959b", "photo-1601004890684-d8cbf643f5f2"]
+                    .iter()
+                    .cycle(),
+            )
+            .map(|(headline, photo)| {
+                let (topic, excerpt, date, minutes) =
+                    (s.pick(&topics), s.sentence(), s.date(), s.between(2, 12));
+                format!(
+                    r#"            <!-- Post -->
+            <div class="flex {gap6} items-start">
+               <img src="https://img.daisyui.com/images/stock/{photo}.jpg" alt="{headline}" class="w-32 h-32 rounded-xl object-cover" />
+               <div>
+                  <div class="badge badge-ghost {mb2}">{topic}</div>
+                  <h4 class="{text_xl} font-bold hover:text-primary cursor-pointer">{headline}</h4>
+                  <p class="text-base-content/70 {mt2}">{excerpt}</p>
+                  <div class="{text_sm} {mt2} opacity-50">{date} • {minutes} min read</div>
+               </div>
+            </div>
+"#
+                )
+            })
+            .collect();
+        let categories: String = topics
+            .iter()
+            .map(|topic| {
+                format!(
+                    r#"             <div class="badge badge-outline {p3}">{topic}</div>
+"#
+                )
+            })
+            .collect();
+        let headline = headlines[0];
+        Page::default()
+            .markup(
+                r#"
+<div class="min-h-screen bg-base-100">"#,
+            )
+            .section(
+                "navbar",
+                format!(
+                    r#"
+  <nav class="navbar bg-base-100 border-b border-base-200" aria-label="Main">
     <div class="container mx-auto">
-      <div class="flex-1"><a class="btn btn-ghost text-2xl font-serif">{}</a></div>
-      <div class="flex-none"><button class="btn btn-ghost btn-circle"><svg xmlns="http://www.w3.org/2000/svg" class="h-5 w-5" fill="none" viewBox="0 0 24 24" stroke="currentColor"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M21 21l-6-6m2-5a7 7 0 11-14 0 7 7 0 0114 0z" /></svg></button></div>
+      <div class="flex-1"><a class="btn btn-ghost {text_2xl} font-serif">{title}</a></div>
+      <div class="flex-none"><button class="btn btn-ghost btn-circle" aria-label="Search"><svg xmlns="http://www.w3.org/2000/svg" class="h-5 w-5" aria-hidden="true" fill="none" viewBox="0 0 24 24" stroke="currentColor"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M21 21l-6-6m2-5a7 7 0 11-14 0 7 7 0 0114 0z" /></svg></button></div>
     </div>
-  </div>
+  </nav>"#
+                ),
+            )
+            .markup(format!(
+                r#"
 
-  <div class="container mx-auto px-4 py-12">
-    <!-- Featured -->
-    <div class="card lg:card-side bg-base-200 shadow-xl mb-16">
-      <figure class="lg:w-1/2"><img src="https://img.daisyui.com/images/stock/photo-1494232410401-ad00d5433cfa.jpg" class="h-full object-cover" /></figure>
+  <main class="container mx-auto {px4} {py12}">
+    <!-- Featured -->"#
+            ))
+            .section(
+                "featured",
+                format!(
+                    r#"
+    <div class="card lg:card-side bg-base-200 shadow-xl {mb16}">
+      <figure class="lg:w-1/2"><img src="https://img.daisyui.com/images/stock/photo-1494232410401-ad00d5433cfa.jpg" alt="{headline}" class="h-full object-cover" /></figure>
       <div class="card-body lg:w-1/2 justify-center">
-        <h2 class="card-title text-4xl mb-4 font-serif">The Evolution of Modern Web Development</h2>
-        <p class="text-lg">Exploring cutting-edge patterns and practices shaping the future of user interfaces.</p>
-        <div class="card-actions justify-start mt-4">
+        <h2 class="card-title {text_4xl} {mb4} font-serif">{headline}</h2>
+        <p class="{text_lg}">{lede}</p>
+        <div class="card-actions justify-start {mt4}">
           <button class="btn btn-primary">Read Article</button>
         </div>
       </div>
-    </div>
-
-    <div class="flex flex-col lg:flex-row gap-12">
+    </div>"#
+                ),
+            )
+            .markup("\n")
+            .section(
+                "posts",
+                format!(
+                    r#"
+    <div class="flex flex-col lg:flex-row {gap12}">
       <!-- Main Content -->
-      <div class="lg:w-2/3">
-         <h3 class="text-2xl font-bold mb-6 border-b border-base-300 pb-2">Latest Stories</h3>
-         <div class="flex flex-col gap-8">
-            <!-- Post -->
-            <div class="flex gap-6 items-start">
-               <img src="https://img.daisyui.com/images/stock/photo-1559181567-c3190ca9959b.jpg" class="w-32 h-32 rounded-xl object-cover" />
-               <div>
-                  <div class="badge badge-ghost mb-2">Technology</div>
-                  <h4 class="text-xl font-bold hover:text-primary cursor-pointer">Rust vs Go in Production</h4>
-                  <p class="text-base-content/70 mt-2">A comprehensive analysis of system performance and developer experience.</p>
-                  <div class="text-sm mt-2 opacity-50">Dec 9 • 5 min read</div>
-               </div>
-            </div>
-            <!-- Post -->
-             <div class="flex gap-6 items-start">
-               <img src="https://img.daisyui.com/images/stock/photo-1601004890684-d8cbf643f5f2.jpg" class="w-32 h-32 rounded-xl object-cover" />
-               <div>
-                  <div class="badge badge-ghost mb-2">Design</div>
-                  <h4 class="text-xl font-bold hover:text-primary cursor-pointer">Digital Minimalism</h4>
-                  <p class="text-base-content/70 mt-2">Reclaiming focus in an age of endless distraction.</p>
-                  <div class="text-sm mt-2 opacity-50">Dec 8 • 3 min read</div>
-               </div>
-            </div>
-         </div>
-      </div>
+      <section class="lg:w-2/3" aria-labelledby="{posts}">
+         <h2 id="{posts}" class="{text_2xl} font-bold {mb6} border-b border-base-300 {pb2}">Latest Stories</h2>
+         <div class="flex flex-col {gap8}">
+{stories}         </div>
+      </section>
 
       <!-- Sidebar -->
-      <div class="lg:w-1/3">
-         <div class="card bg-base-200 p-6 mb-6">
-            <h3 class="font-bold text-lg mb-4">Newsletter</h3>
-            <p class="text-sm mb-4">Get the latest posts delivered right to your inbox.</p>
+      <aside class="lg:w-1/3">
+         <div class="card bg-base-200 {p6} {mb6}">
+            <h3 class="font-bold {text_lg} {mb4}">Newsletter</h3>
+            <p class="{text_sm} {mb4}">Get the latest posts delivered right to your inbox.</p>
             <div class="join w-full">
-              <input class="input input-bordered join-item w-full" placeholder="Email"/>
+              <input class="input input-bordered join-item w-full" placeholder="Email" aria-label="Email"/>
               <button class="btn btn-primary join-item">Subscribe</button>
             </div>
          </div>
 
-         <div class="mb-6">
-           <h3 class="font-bold text-lg mb-4">Categories</h3>
-           <div class="flex flex-wrap gap-2">
-             <div class="badge badge-outline p-3">Technology</div>
-             <div class="badge badge-outline p-3">Design</div>
-             <div class="badge badge-outline p-3">Culture</div>
-             <div class="badge badge-outline p-3">Business</div>
-           </div>
+         <div class="{mb6}">
+           <h3 class="font-bold {text_lg} {mb4}">Categories</h3>
+           <div class="flex flex-wrap {gap2}">
+{categories}           </div>
          </div>
-      </div>
-    </div>
-  </div>
+      </aside>
+    </div>"#
+                ),
+            )
+            .markup(
+                r#"
+  </main>
 </div>
-"##,
-            title
-        )
+"#,
+            )
     }
 
-    fn social_feed(title: &str) -> String {
-        format!(
-            r##"
+    fn social_feed(title: &str, v: &Variant, s: &mut Sample) -> Page {
+        let [
+            p4,
+            text_2xl,
+            mb4,
+            text_lg,
+            mt8,
+            text_xl,
+            gap4,
+            gap2,
+            text_sm,
+            mt1,
+            mt3,
+            mb2,
+            py2,
+            text_xs,
+        ] = [
+            v.space("p", 4),
+            v.text("2xl"),
+            v.space("mb", 4),
+            v.text("lg"),
+            v.space("mt", 8),
+            v.text("xl"),
+            v.space("gap", 4),
+            v.space("gap", 2),
+            v.text("sm"),
+            v.space("mt", 1),
+            v.space("mt", 3),
+            v.space("mb", 2),
+            v.space("py", 2),
+            v.text("xs"),
+        ];
+        let md = |src: &str| snippets::avatar(src, AvatarSize::Md, AvatarShape::Circle, None);
+        let me = md("https://img.daisyui.com/images/stock/photo-1534528741775-53994a69daeb.jpg");
+        let authors = [
+            md("https://img.daisyui.com/images/stock/photo-1606107557195-0e29a4b5b4aa.jpg"),
+            md("https://img.daisyui.com/images/stock/photo-1559181567-c3190ca9959b.jpg"),
+        ];
+        let mut hours = 0;
+        let posts: String = s
+            .many(sample::STATUSES, s.count("posts", 2))
+            .into_iter()
+            .zip(authors.iter().cycle())
+            .map(|(status, avatar)| {
+                let name = s.name();
+                let handle = sample::handle(&name);
+                hours += s.between(1, 3);
+                let [replies, reposts, likes] =
+                    [s.between(1, 40), s.between(0, 20), s.between(10, 300)];
+                format!(
+                    r#"    <div class="{p4} border-b border-base-200 hover:bg-base-200/50 cursor-pointer transition">
+       <div class="flex {gap4}">
+         {avatar}
+         <div>
+            <div class="flex {gap2} items-center"><span class="font-bold">{name}</span> <span class="{text_sm} opacity-50">@{handle} • {hours}h</span></div>
+            <p class="{mt1}">{status}</p>
+            <div class="flex justify-between {mt3} max-w-sm {text_sm} opacity-60">
+               <button class="hover:text-primary">💬 {replies}</button>
+               <button class="hover:text-green-500">♻️ {reposts}</button>
+               <button class="hover:text-red-500">❤️ {likes}</button>
+            </div>
+         </div>
+       </div>
+    </div>
+"#
+                )
+            })
+            .collect();
+        Page::default()
+            .markup(
+                r#"
 <div class="min-h-screen bg-base-100 flex justify-center">
-  <!-- Left Sidebar -->
-  <div class="w-64 hidden lg:block p-4 fixed left-0 top-0 h-screen border-r border-base-200 overflow-y-auto">
-    <div class="text-2xl font-bold text-primary p-4 mb-4">{}</div>
-    <ul class="menu w-full text-lg">
+  <!-- Left Sidebar -->"#,
+            )
+            .section(
+                "sidebar",
+                format!(
+                    r#"
+  <nav class="w-64 hidden lg:block {p4} fixed left-0 top-0 h-screen border-r border-base-200 overflow-y-auto" aria-label="Main">
+    <div class="{text_2xl} font-bold text-primary {p4} {mb4}">{title}</div>
+    <ul class="menu w-full {text_lg}">
       <li><a class="active"><svg xmlns="http://www.w3.org/2000/svg" class="h-6 w-6" fill="none" viewBox="0 0 24 24" stroke="currentColor"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M3 12l2-2m0 0l7-7 7 7M5 10v10a1 1 0 001 1h3m10-11l2 2m-2-2v10a1 1 0 01-1 1h-3m-6 0a1 1 0 001-1v-4a1 1 0 011-1h2a1 1 0 011 1v4a1 1 0 001 1m-6 0h6"/></svg> Home</a></li>
       <li><a><svg xmlns="http://www.w3.org/2000/svg" class="h-6 w-6" fill="none" viewBox="0 0 24 24" stroke="currentColor"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M15 17h5l-1.405-1.405A2.032 2.032 0 0118 14.158V11a6.002 6.002 0 00-4-5.659V5a2 2 0 10-4 0v.341C7.67 6.165 6 8.388 6 11v3.159c0 .538-.214 1.055-.595 1.436L4 17h5m6 0v1a3 3 0 11-6 0v-1m6 0H9"/></svg> Notifications</a></li>
       <li><a><svg xmlns="http://www.w3.org/2000/svg" class="h-6 w-6" fill="none" viewBox="0 0 24 24" stroke="currentColor"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M3 8l7.89 5.26a2 2 0 002.22 0L21 8M5 19h14a2 2 0 002-2V7a2 2 0 00-2-2H5a2 2 0 00-2 2v10a2 2 0 002 2z"/></svg> Messages</a></li>
       <li><a><svg xmlns="http://www.w3.org/2000/svg" class="h-6 w-6" fill="none" viewBox="0 0 24 24" stroke="currentColor"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M16 7a4 4 0 11-8 0 4 4 0 018 0zM12 14a7 7 0 00-7 7h14a7 7 0 00-7-7z"/></svg> Profile</a></li>
     </ul>
-    <button class="btn btn-primary w-full rounded-full mt-8">Post</button>
-  </div>
-
-  <!-- Main Feed -->
-  <div class="w-full lg:w-[600px] border-r border-l border-base-200 min-h-screen">
-    <div class="sticky top-0 bg-base-100/80 backdrop-blur z-20 border-b border-base-200 p-4 font-bold text-xl">Home</div>
+    <button class="btn btn-primary w-full rounded-full {mt8}">Post</button>
+  </nav>"#
+                ),
+            )
+            .markup("\n\n  <!-- Main Feed -->")
+            .section(
+                "feed",
+                format!(
+                    r#"
+  <main class="w-full lg:w-[600px] border-r border-l border-base-200 min-h-screen">
+    <h1 class="sticky top-0 bg-base-100/80 backdrop-blur z-20 border-b border-base-200 {p4} font-bold {text_xl}">Home</h1>
     <!-- Composer -->
-    <div class="p-4 border-b border-base-200 flex gap-4">
-       <div class="avatar"><div class="w-12 rounded-full"><img src="https://img.daisyui.com/images/stock/photo-1534528741775-53994a69daeb.jpg" /></div></div>
+    <div class="{p4} border-b border-base-200 flex {gap4}">
+       {me}
        <div class="w-full">
-         <textarea class="textarea textarea-ghost w-full text-lg resize-none" placeholder="What is happening?"></textarea>
+         <textarea class="textarea textarea-ghost w-full {text_lg} resize-none" placeholder="What is happening?" aria-label="New post"></textarea>
          <div class="flex justify-end"><button class="btn btn-primary btn-sm rounded-full">Tweet</button></div>
        </div>
     </div>
     <!-- Posts -->
-    <div class="p-4 border-b border-base-200 hover:bg-base-200/50 cursor-pointer transition">
-       <div class="flex gap-4">
-         <div class="avatar"><div class="w-12 rounded-full"><img src="https://img.daisyui.com/images/stock/photo-1606107557195-0e29a4b5b4aa.jpg" /></div></div>
-         <div>
-            <div class="flex gap-2 items-center"><span class="font-bold">Jane Doe</span> <span class="text-sm opacity-50">@janedoe • 2h</span></div>
-            <p class="mt-1">Just shipped a new update for the platform! Rust is blazing fast. 🦀🚀</p>
-            <div class="flex justify-between mt-3 max-w-sm text-sm opacity-60">
-               <button class="hover:text-primary">💬 12</button>
-               <button class="hover:text-green-500">♻️ 4</button>
-               <button class="hover:text-red-500">❤️ 89</button>
-            </div>
-         </div>
-       </div>
-    </div>
-    <div class="p-4 border-b border-base-200 hover:bg-base-200/50 cursor-pointer transition">
-       <div class="flex gap-4">
-         <div class="avatar"><div class="w-12 rounded-full"><img src="https://img.daisyui.com/images/stock/photo-1559181567-c3190ca9959b.jpg" /></div></div>
-         <div>
-            <div class="flex gap-2 items-center"><span class="font-bold">Tech Insider</span> <span class="text-sm opacity-50">@tech • 4h</span></div>
-            <p class="mt-1">DaisyUI 5.0 is coming soon. Are you ready?</p>
-         </div>
-       </div>
-    </div>
-  </div>
-
-  <!-- Right Sidebar -->
-  <div class="hidden xl:block w-80 p-4 fixed right-0 top-0 h-screen">
+{posts}  </main>"#
+                ),
+            )
+            .markup("\n\n  <!-- Right Sidebar -->")
+            .section(
+                "trends",
+                format!(
+                    r#"
+  <aside class="hidden xl:block w-80 {p4} fixed right-0 top-0 h-screen">
      <div class="card bg-base-200">
-        <div class="card-body p-4">
-           <h3 class="font-bold text-lg mb-2">Trends for you</h3>
-           <div class="py-2">
-             <div class="text-xs opacity-50">Technology</div>
+        <div class="card-body {p4}">
+           <h2 class="font-bold {text_lg} {mb2}">Trends for you</h2>
+           <div class="{py2}">
+             <div class="{text_xs} opacity-50">Technology</div>
              <div class="font-bold">#RustLang</div>
-             <div class="text-xs opacity-50">12K Posts</div>
+             <div class="{text_xs} opacity-50">12K Posts</div>
            </div>
-           <div class="py-2">
-             <div class="text-xs opacity-50">Design</div>
+           <div class="{py2}">
+             <div class="{text_xs} opacity-50">Design</div>
              <div class="font-bold">#UIUX</div>
-             <div class="text-xs opacity-50">8K Posts</div>
+             <div class="{text_xs} opacity-50">8K Posts</div>
            </div>
         </div>
      </div>
-  </div>
-</div>
-"##,
-            title
-        )
+  </aside>"#
+                ),
+            )
+            .markup("\n</div>\n")
     }
 
-    fn kanban_board(title: &str) -> String {
-        format!(
-            r##"
-<div class="h-screen flex flex-col bg-base-200">
-  <div class="navbar bg-base-100 shadow-sm px-4">
-    <div class="flex-1"><h1 class="text-xl font-bold">{}</h1></div>
-     <div class="flex-none gap-2">
-        <div class="avatar-group -space-x-6">
-          <div class="avatar"><div class="w-8"><img src="https://img.daisyui.com/images/stock/photo-1534528741775-53994a69daeb.jpg" /></div></div>
-          <div class="avatar"><div class="w-8"><img src="https://img.daisyui.com/images/stock/photo-1606107557195-0e29a4b5b4aa.jpg" /></div></div>
-          <div class="avatar placeholder"><div class="w-8 bg-neutral text-neutral-content"><span>+2</span></div></div>
-        </div>
-        <button class="btn btn-primary btn-sm">Share</button>
-     </div>
-  </div>
-
-  <div class="flex-1 overflow-x-auto p-6">
-    <div class="flex gap-6 h-full">
-       <!-- Lane: Todo -->
-       <div class="w-80 shrink-0 flex flex-col gap-3">
-          <div class="flex justify-between items-center px-1">
-             <h3 class="font-bold uppercase text-sm opacity-70">To Do</h3>
-             <span class="badge badge-sm">3</span>
-          </div>
-          <div class="card bg-base-100 shadow-sm p-4 cursor-pointer hover:shadow-md">
-             <div class="badge badge-warning text-xs mb-2">Design</div>
-             <p class="font-semibold">Create high-fidelity mockups</p>
-          </div>
-          <div class="card bg-base-100 shadow-sm p-4 cursor-pointer hover:shadow-md">
-             <p class="font-semibold">Research competitor market</p>
-             <div class="mt-3 flex justify-between items-center">
-                <div class="avatar w-6 rounded-full"><img src="https://img.daisyui.com/images/stock/photo-1534528741775-53994a69daeb.jpg"/></div>
-                <span class="text-xs opacity-50">Dec 12</span>
+    fn kanban_board(title: &str, v: &Variant, ids: &mut IdAllocator, s: &mut Sample) -> Page {
+        let [
+            px4,
+            text_xl,
+            gap2,
+            p6,
+            gap6,
+            gap3,
+            px1,
+            text_sm,
+            p4,
+            text_xs,
+            mb2,
+            mt3,
+            mt2,
+        ] = [
+            v.space("px", 4),
+            v.text("xl"),
+            v.space("gap", 2),
+            v.space("p", 6),
+            v.space("gap", 6),
+            v.space("gap", 3),
+            v.space("px", 1),
+            v.text("sm"),
+            v.space("p", 4),
+            v.text("xs"),
+            v.space("mb", 2),
+            v.space("mt", 3),
+            v.space("mt", 2),
+        ];
+        let team = snippets::avatar_group(
+            &[
+                "https://img.daisyui.com/images/stock/photo-1534528741775-53994a69daeb.jpg",
+                "https://img.daisyui.com/images/stock/photo-1606107557195-0e29a4b5b4aa.jpg",
+                "Sam Lee",
+                "Kim Park",
+            ],
+            AvatarSize::Sm,
+            2,
+        );
+        let assignee = snippets::avatar(
+            "https://img.daisyui.com/images/stock/photo-1534528741775-53994a69daeb.jpg",
+            AvatarSize::Xs,
+            AvatarShape::Circle,
+            None,
+        );
+        let lanes = sample::lanes(s.count("columns", 3));
+        // Without a count, To Do holds two cards and every other lane one.
+        let sizes: Vec<usize> = (0..lanes.len())
+            .map(|i| s.count("cards", if i == 0 { 2 } else { 1 }))
+            .collect();
+        let mut tasks = s.many(sample::TASKS, sizes.iter().sum()).into_iter();
+        let last = lanes.len() - 1;
+        let lanes: String = lanes
+            .iter()
+            .zip(&sizes)
+            .enumerate()
+            .map(|(i, ((name, state), size))| {
+                let id = ids.next("lane");
+                let badge = snippets::status_badge(state, &size.to_string(), &[], false);
+                let cards: String = tasks
+                    .by_ref()
+                    .take(*size)
+                    .enumerate()
+                    .map(|(n, task)| {
+                        let (label, color) = s.pick(sample::LABELS);
+                        match i {
+                            0 if n % 2 == 1 => format!(
+                                r#"          <div class="card bg-base-100 shadow-sm {p4} cursor-pointer hover:shadow-md">
+             <p class="font-semibold">{task}</p>
+             <div class="{mt3} flex justify-between items-center">
+                {assignee}
+                <span class="{text_xs} opacity-50">{}</span>
              </div>
           </div>
-          <button class="btn btn-ghost btn-block text-base-content/50">+ Add Task</button>
-       </div>
-
-       <!-- Lane: In Progress -->
-       <div class="w-80 shrink-0 flex flex-col gap-3">
-          <div class="flex justify-between items-center px-1">
-             <h3 class="font-bold uppercase text-sm opacity-70">In Progress</h3>
-             <span class="badge badge-sm">1</span>
+"#,
+                                s.date()
+                            ),
+                            0 => format!(
+                                r#"          <div class="card bg-base-100 shadow-sm {p4} cursor-pointer hover:shadow-md">
+             <div class="badge {color} {text_xs} {mb2}">{label}</div>
+             <p class="font-semibold">{task}</p>
           </div>
-          <div class="card bg-base-100 shadow-sm p-4 cursor-pointer hover:shadow-md">
-             <div class="badge badge-info text-xs mb-2">Dev</div>
-             <p class="font-semibold">Implement Authentication</p>
-             <progress class="progress progress-primary w-full mt-2" value="40" max="100"></progress>
+"#
+                            ),
+                            _ if i == last => format!(
+                                r#"          <div class="card bg-base-100 shadow-sm {p4} opacity-60">
+             <p class="font-semibold line-through">{task}</p>
           </div>
-          <button class="btn btn-ghost btn-block text-base-content/50">+ Add Task</button>
-       </div>
-
-       <!-- Lane: Done -->
-       <div class="w-80 shrink-0 flex flex-col gap-3">
-          <div class="flex justify-between items-center px-1">
-             <h3 class="font-bold uppercase text-sm opacity-70">Done</h3>
-             <span class="badge badge-sm">2</span>
+"#
+                            ),
+                            _ => format!(
+                                r#"          <div class="card bg-base-100 shadow-sm {p4} cursor-pointer hover:shadow-md">
+             <div class="badge {color} {text_xs} {mb2}">{label}</div>
+             <p class="font-semibold">{task}</p>
+             <progress class="progress progress-primary w-full {mt2}" value="{}" max="100"></progress>
           </div>
-          <div class="card bg-base-100 shadow-sm p-4 opacity-60">
-             <p class="font-semibold line-through">Setup Repo</p>
+"#,
+                                s.between(2, 8) * 10
+                            ),
+                        }
+                    })
+                    .collect();
+                let add = if i == last && i > 0 {
+                    ""
+                } else {
+                    "          <button class=\"btn btn-ghost btn-block text-base-content/50\">+ Add Task</button>\n"
+                };
+                format!(
+                    r#"       <!-- Lane: {name} -->
+       <section class="w-80 shrink-0 flex flex-col {gap3}" aria-labelledby="{id}">
+          <div class="flex justify-between items-center {px1}">
+             <h2 id="{id}" class="font-bold uppercase {text_sm} opacity-70">{name}</h2>
+             {badge}
           </div>
-       </div>
-    </div>
-  </div>
-</div>
-"##,
-            title
-        )
+{cards}{add}       </section>
+"#
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        Page::default()
+            .markup(
+                r#"
+<div class="h-screen flex flex-col bg-base-200">"#,
+            )
+            .section(
+                "navbar",
+                format!(
+                    r#"
+  <nav class="navbar bg-base-100 shadow-sm {px4}" aria-label="Main">
+    <div class="flex-1"><h1 class="{text_xl} font-bold">{title}</h1></div>
+     <div class="flex-none {gap2}">
+        {team}
+        <button class="btn btn-primary btn-sm">Share</button>
+     </div>
+  </nav>"#
+                ),
+            )
+            .markup("\n")
+            .section(
+                "board",
+                format!(
+                    r#"
+  <main class="flex-1 overflow-x-auto {p6}">
+    <div class="flex {gap6} h-full">
+{lanes}    </div>
+  </main>"#
+                ),
+            )
+            .markup("\n</div>\n")
     }
 
-    fn inbox_layout(title: &str) -> String {
-        format!(
-            r##"
+    fn inbox_layout(title: &str, v: &Variant, ids: &mut IdAllocator, s: &mut Sample) -> Page {
+        let [
+            p4,
+            gap2,
+            text_xl,
+            p2,
+            mb1,
+            text_xs,
+            text_sm,
+            p6,
+            text_2xl,
+            mt2,
+            p8,
+            mt4,
+            mt8,
+        ] = [
+            v.space("p", 4),
+            v.space("gap", 2),
+            v.text("xl"),
+            v.space("p", 2),
+            v.space("mb", 1),
+            v.text("xs"),
+            v.text("sm"),
+            v.space("p", 6),
+            v.text("2xl"),
+            v.space("mt", 2),
+            v.space("p", 8),
+            v.space("mt", 4),
+            v.space("mt", 8),
+        ];
+        let count = s.count("messages", 2);
+        let senders = s.many(sample::SENDERS, count);
+        let subjects = s.many(sample::SUBJECTS, count);
+        // The first message is today's, selected and shown on the right.
+        let messages: String = senders
+            .iter()
+            .zip(&subjects)
+            .enumerate()
+            .map(|(i, (sender, subject))| {
+                let (selected, received) = match i {
+                    0 => (" bg-base-200/50", format!("{}:00 AM", s.between(7, 11))),
+                    1 => ("", "Yesterday".to_string()),
+                    _ => ("", s.date()),
+                };
+                let preview = s.sentence();
+                format!(
+                    r#"        <div class="{p4} hover:bg-base-200 cursor-pointer{selected}">
+           <div class="flex justify-between {mb1}"><span class="font-bold">{sender}</span> <span class="{text_xs} opacity-50">{received}</span></div>
+           <div class="font-semibold truncate">{subject}</div>
+           <div class="{text_sm} opacity-60 truncate">{preview}</div>
+        </div>
+"#
+                )
+            })
+            .collect();
+        let (body, reader, total) = (s.sentence(), s.pick(sample::FIRST_NAMES), s.price(1, 99));
+        let sender = snippets::avatar(senders[0], AvatarSize::Sm, AvatarShape::Circle, None);
+        let address = sample::handle(senders[0]);
+        let list = ids.next("messages");
+        let subject = subjects[0];
+        let sender_name = senders[0];
+        Page::default()
+            .markup(
+                r#"
 <div class="h-screen flex bg-base-100">
-  <!-- Sidebar -->
-  <div class="w-64 border-r border-base-200 flex flex-col">
-     <div class="p-4 flex items-center gap-2 font-bold text-xl"><div class="badge badge-primary badge-lg">M</div> {}</div>
-     <div class="p-4"><button class="btn btn-primary btn-block gap-2"><svg xmlns="http://www.w3.org/2000/svg" class="h-5 w-5" viewBox="0 0 20 20" fill="currentColor"><path d="M13.586 3.586a2 2 0 112.828 2.828l-.793.793-2.828-2.828.793-.793zM11.379 5.793L3 14.172V17h2.828l8.38-8.379-2.83-2.828z" /></svg> Compose</button></div>
-     <ul class="menu flex-1 p-2">
+  <!-- Sidebar -->"#,
+            )
+            .section(
+                "sidebar",
+                format!(
+                    r#"
+  <nav class="w-64 border-r border-base-200 flex flex-col" aria-label="Mailboxes">
+     <div class="{p4} flex items-center {gap2} font-bold {text_xl}"><div class="badge badge-primary badge-lg">M</div> {title}</div>
+     <div class="{p4}"><button class="btn btn-primary btn-block {gap2}" aria-label="Compose"><svg xmlns="http://www.w3.org/2000/svg" class="h-5 w-5" viewBox="0 0 20 20" fill="currentColor" aria-hidden="true"><path d="M13.586 3.586a2 2 0 112.828 2.828l-.793.793-2.828-2.828.793-.793zM11.379 5.793L3 14.172V17h2.828l8.38-8.379-2.83-2.828z" /></svg> Compose</button></div>
+     <ul class="menu flex-1 {p2}">
        <li><a class="active">Inbox <span class="badge badge-sm bg-base-100">4</span></a></li>
        <li><a>Starred</a></li>
        <li><a>Sent</a></li>
        <li><a>Drafts</a></li>
      </ul>
-  </div>
-
-  <!-- List -->
-  <div class="w-80 border-r border-base-200 overflow-y-auto">
-     <div class="p-4 border-b border-base-200 sticky top-0 bg-base-100 z-10">
-        <input type="text" placeholder="Search mail" class="input input-sm input-bordered w-full" />
+  </nav>"#
+                ),
+            )
+            .markup("\n\n  <!-- List -->")
+            .section(
+                "messages",
+                format!(
+                    r#"
+  <section class="w-80 border-r border-base-200 overflow-y-auto" aria-labelledby="{list}">
+     <div class="{p4} border-b border-base-200 sticky top-0 bg-base-100 z-10">
+        <h2 id="{list}" class="sr-only">Messages</h2>
+        <input type="text" placeholder="Search mail" aria-label="Search mail" class="input input-sm input-bordered w-full" />
      </div>
      <div class="divide-y divide-base-200">
-        <div class="p-4 hover:bg-base-200 cursor-pointer bg-base-200/50">
-           <div class="flex justify-between mb-1"><span class="font-bold">Apple</span> <span class="text-xs opacity-50">10:00 AM</span></div>
-           <div class="font-semibold truncate">Your receipt for...</div>
-           <div class="text-sm opacity-60 truncate">Thank you for your purchase of...</div>
-        </div>
-        <div class="p-4 hover:bg-base-200 cursor-pointer">
-           <div class="flex justify-between mb-1"><span class="font-bold">Github</span> <span class="text-xs opacity-50">Yesterday</span></div>
-           <div class="font-semibold truncate">Security alert</div>
-           <div class="text-sm opacity-60 truncate">A new vulnerability was found...</div>
-        </div>
-     </div>
-  </div>
-
-  <!-- View -->
-  <div class="flex-1 flex flex-col">
-     <div class="p-6 border-b border-base-200 flex justify-between items-center">
+{messages}     </div>
+  </section>"#
+                ),
+            )
+            .markup("\n\n  <!-- View -->")
+            .section(
+                "main",
+                format!(
+                    r#"
+  <main class="flex-1 flex flex-col">
+     <header class="{p6} border-b border-base-200 flex justify-between items-center">
         <div>
-           <h2 class="text-2xl font-bold">Your receipt for iCloud+</h2>
-           <div class="flex gap-2 items-center mt-2">
-              <div class="avatar w-8 rounded-full bg-neutral text-neutral-content grid place-items-center">A</div>
-              <div class="text-sm"><span class="font-bold">Apple</span> &lt;no-reply@apple.com&gt;</div>
+           <h1 class="{text_2xl} font-bold">{subject}</h1>
+           <div class="flex {gap2} items-center {mt2}">
+              {sender}
+              <div class="{text_sm}"><span class="font-bold">{sender_name}</span> &lt;no-reply@{address}.com&gt;</div>
            </div>
         </div>
-        <div class="flex gap-2">
+        <div class="flex {gap2}">
            <button class="btn btn-ghost btn-sm">Reply</button>
            <button class="btn btn-ghost btn-sm">Delete</button>
         </div>
-     </div>
-     <div class="p-8 flex-1 overflow-y-auto">
-        <p>Hello Ahmad,</p>
-        <p class="mt-4">This email confirms your subscription was renewed successfully.</p>
-        <div class="card bg-base-200 max-w-sm mt-8 p-4">
-           <div class="flex justify-between font-bold"><span>Total</span> <span>$0.99</span></div>
+     </header>
+     <div class="{p8} flex-1 overflow-y-auto">
+        <p>Hello {reader},</p>
+        <p class="{mt4}">{body}</p>
+        <div class="card bg-base-200 max-w-sm {mt8} {p4}">
+           <div class="flex justify-between font-bold"><span>Total</span> <span>{total}</span></div>
         </div>
      </div>
-  </div>
-</div>
-"##,
-            title
-        )
+  </main>"#
+                ),
+            )
+            .markup("\n</div>\n")
     }
 
-    fn settings_profile(title: &str) -> String {
-        format!(
-            r##"
-<div class="min-h-screen bg-base-200 p-4 md:p-8">
-  <div class="max-w-4xl mx-auto">
-     <h1 class="text-3xl font-bold mb-8">{}</h1>
-     <div class="flex flex-col md:flex-row gap-6">
-        <!-- Sidebar -->
-        <div class="w-full md:w-64 shrink-0">
+    fn settings_profile(title: &str, v: &Variant, ids: &mut IdAllocator) -> Page {
+        let [p4, md_p8, text_3xl, mb8, gap6, mb4, mb6, gap4, mt6] = [
+            v.space("p", 4),
+            v.space("md:p", 8),
+            v.text("3xl"),
+            v.space("mb", 8),
+            v.space("gap", 6),
+            v.space("mb", 4),
+            v.space("mb", 6),
+            v.space("gap", 4),
+            v.space("mt", 6),
+        ];
+        let user = snippets::user_card(
+            "Ahmad Hamdi",
+            "ahmad@example.com",
+            &snippets::avatar("Ahmad Hamdi", AvatarSize::Xl, AvatarShape::Circle, None),
+            &["Change Avatar", "Remove"],
+        );
+        let [info, preferences] = [ids.next("profile"), ids.next("profile")];
+        Page::default()
+            .markup(format!(
+                r#"
+<div class="min-h-screen bg-base-200 {p4} {md_p8}">
+  <main class="max-w-4xl mx-auto">
+     <h1 class="{text_3xl} font-bold {mb8}">{title}</h1>
+     <div class="flex flex-col md:flex-row {gap6}">
+        <!-- Sidebar -->"#
+            ))
+            .section(
+                "menu",
+                r#"
+        <nav class="w-full md:w-64 shrink-0" aria-label="Settings">
            <ul class="menu bg-base-100 rounded-box w-full shadow-sm">
              <li><a class="active">General</a></li>
              <li><a>Account</a></li>
@@ -619,108 +1466,153 @@ impl LayoutEngine {
              <li><a>Billing</a></li>
              <li><a class="text-error">Danger Zone</a></li>
            </ul>
-        </div>
-
-        <!-- Content -->
+        </nav>"#,
+            )
+            .markup("\n\n        <!-- Content -->")
+            .section(
+                "main",
+                format!(
+                    r#"
         <div class="flex-1">
-           <div class="card bg-base-100 shadow-sm">
+           <section class="card bg-base-100 shadow-sm" aria-labelledby="{info}">
              <div class="card-body">
-                <h2 class="card-title mb-4">Profile Information</h2>
-                <div class="flex items-center gap-4 mb-6">
-                   <div class="avatar placeholder">
-                      <div class="bg-neutral text-neutral-content rounded-full w-24">
-                        <span class="text-3xl">AH</span>
-                      </div>
-                   </div>
-                   <div>
-                      <button class="btn btn-sm btn-outline">Change Avatar</button>
-                      <button class="btn btn-sm btn-ghost text-error">Remove</button>
-                   </div>
-                </div>
+                <h2 id="{info}" class="card-title {mb4}">Profile Information</h2>
+                <div class="{mb6}">{user}</div>
 
-                <div class="grid gap-4">
+                <div class="grid {gap4}">
                    <div class="form-control">
-                      <label class="label">Display Name</label>
-                      <input type="text" value="Ahmad Hamdi" class="input input-bordered" />
+                      <label class="label" for="{info}-name">Display Name</label>
+                      <input id="{info}-name" type="text" value="Ahmad Hamdi" class="input input-bordered" />
                    </div>
                    <div class="form-control">
-                      <label class="label">Email Address</label>
-                      <input type="email" value="ahmad@example.com" class="input input-bordered" />
+                      <label class="label" for="{info}-email">Email Address</label>
+                      <input id="{info}-email" type="email" value="ahmad@example.com" class="input input-bordered" />
                    </div>
                    <div class="form-control">
-                      <label class="label">Bio</label>
-                      <textarea class="textarea textarea-bordered h-24">Just shipping code.</textarea>
+                      <label class="label" for="{info}-bio">Bio</label>
+                      <textarea id="{info}-bio" class="textarea textarea-bordered h-24">Just shipping code.</textarea>
                    </div>
                 </div>
 
-                <div class="card-actions justify-end mt-6">
+                <div class="card-actions justify-end {mt6}">
                    <button class="btn btn-primary">Save Changes</button>
                 </div>
              </div>
-           </div>
+           </section>
 
-           <div class="card bg-base-100 shadow-sm mt-6">
+           <section class="card bg-base-100 shadow-sm {mt6}" aria-labelledby="{preferences}">
              <div class="card-body">
-                <h2 class="card-title">Preferences</h2>
+                <h2 id="{preferences}" class="card-title">Preferences</h2>
                 <div class="form-control">
-                  <label class="label cursor-pointer justify-start gap-4">
+                  <label class="label cursor-pointer justify-start {gap4}">
                     <input type="checkbox" class="toggle toggle-primary" checked />
                     <span class="label-text">Enable email notifications</span>
                   </label>
                 </div>
              </div>
-           </div>
-        </div>
+           </section>
+        </div>"#
+                ),
+            )
+            .markup(
+                r#"
      </div>
-  </div>
+  </main>
 </div>
-"##,
-            title
-        )
+"#,
+            )
     }
 
-    fn docs_layout(title: &str) -> String {
-        format!(
-            r##"
+    fn docs_layout(title: &str, drawer: &str, v: &Variant) -> Page {
+        let [
+            px2,
+            mx2,
+            text_xl,
+            p8,
+            md_p12,
+            text_sm,
+            mb4,
+            text_4xl,
+            mb6,
+            text_lg,
+            text_2xl,
+            mt8,
+            p4,
+            px4,
+        ] = [
+            v.space("px", 2),
+            v.space("mx", 2),
+            v.text("xl"),
+            v.space("p", 8),
+            v.space("md:p", 12),
+            v.text("sm"),
+            v.space("mb", 4),
+            v.text("4xl"),
+            v.space("mb", 6),
+            v.text("lg"),
+            v.text("2xl"),
+            v.space("mt", 8),
+            v.space("p", 4),
+            v.space("px", 4),
+        ];
+        Page::default()
+            .markup(format!(
+                r#"
 <div class="drawer lg:drawer-open">
-  <input id="my-drawer-2" type="checkbox" class="drawer-toggle" />
+  <input id="{drawer}" type="checkbox" class="drawer-toggle" />
   <div class="drawer-content flex flex-col">
-    <!-- Navbar -->
-    <div class="navbar bg-base-100 border-b border-base-200 lg:hidden">
+    <!-- Navbar -->"#
+            ))
+            .section(
+                "navbar",
+                format!(
+                    r#"
+    <nav class="navbar bg-base-100 border-b border-base-200 lg:hidden" aria-label="Main">
       <div class="flex-none">
-        <label for="my-drawer-2" class="btn btn-square btn-ghost">
-          <svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" class="inline-block w-6 h-6 stroke-current"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M4 6h16M4 12h16M4 18h16"></path></svg>
+        <label for="{drawer}" class="btn btn-square btn-ghost" aria-label="Open menu">
+          <svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" class="inline-block w-6 h-6 stroke-current" aria-hidden="true"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M4 6h16M4 12h16M4 18h16"></path></svg>
         </label>
       </div>
-      <div class="flex-1 px-2 mx-2 text-xl font-bold">{}</div>
-    </div>
-
-    <!-- Main Content -->
-    <div class="p-8 md:p-12 max-w-4xl mx-auto w-full">
-       <div class="text-sm breadcrumbs mb-4">
+      <div class="flex-1 {px2} {mx2} {text_xl} font-bold">{title}</div>
+    </nav>"#
+                ),
+            )
+            .markup("\n\n    <!-- Main Content -->")
+            .section(
+                "main",
+                format!(
+                    r#"
+    <main class="{p8} {md_p12} max-w-4xl mx-auto w-full">
+       <nav class="{text_sm} breadcrumbs {mb4}" aria-label="Breadcrumb">
           <ul><li><a>Docs</a></li><li><a>Getting Started</a></li><li>Installation</li></ul>
-       </div>
-       <h1 class="text-4xl font-bold mb-6">Installation</h1>
-       <p class="mb-4 text-lg">Learn how to get up and running with our library in minutes.</p>
+       </nav>
+       <h1 class="{text_4xl} font-bold {mb6}">Installation</h1>
+       <p class="{mb4} {text_lg}">Learn how to get up and running with our library in minutes.</p>
 
-       <div class="mockup-code mb-6">
+       <div class="mockup-code {mb6}">
          <pre data-prefix="$"><code>npm install daisy-framework</code></pre>
        </div>
 
-       <h2 class="text-2xl font-bold mt-8 mb-4">Configuration</h2>
-       <p class="mb-4">Add the plugin to your config file:</p>
-       <p class="mb-4">Lorem ipsum dolor sit amet, consectetur adipiscing elit. Sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.</p>
+       <h2 class="{text_2xl} font-bold {mt8} {mb4}">Configuration</h2>
+       <p class="{mb4}">Add the plugin to your config file:</p>
+       <p class="{mb4}">Lorem ipsum dolor sit amet, consectetur adipiscing elit. Sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.</p>
 
-       <div class="alert alert-info mt-8">
-         <svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" class="stroke-current shrink-0 w-6 h-6"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M13 16h-1v-4h-1m1-4h.01M21 12a9 9 0 11-18 0 9 9 0 0118 0z"></path></svg>
+       <div class="alert alert-info {mt8}">
+         <svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" class="stroke-current shrink-0 w-6 h-6" aria-hidden="true"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M13 16h-1v-4h-1m1-4h.01M21 12a9 9 0 11-18 0 9 9 0 0118 0z"></path></svg>
          <span>Note: Typically requires Node.js 18+.</span>
        </div>
-    </div>
-  </div>
-  <div class="drawer-side border-r border-base-200">
-    <label for="my-drawer-2" class="drawer-overlay"></label>
-    <ul class="menu p-4 w-80 min-h-full bg-base-100 text-base-content">
-      <li class="mb-4 text-xl font-bold px-4">{} Docs</li>
+    </main>"#
+                ),
+            )
+            .markup("\n  </div>")
+            .section(
+                "sidebar",
+                format!(
+                    r#"
+  <aside class="drawer-side border-r border-base-200">
+    <label for="{drawer}" class="drawer-overlay" aria-label="Close menu"></label>
+    <ul class="menu {p4} w-80 min-h-full bg-base-100 text-base-content">
+      <li class="{mb4} {text_xl} font-bold {px4}">{title} Docs</li>
       <li>
         <h2 class="menu-title">Getting Started</h2>
         <ul>
@@ -738,32 +1630,148 @@ impl LayoutEngine {
         </ul>
       </li>
     </ul>
-  </div>
-</div>
-"##,
-            title, title
-        )
+  </aside>"#
+                ),
+            )
+            .markup("\n</div>\n")
     }
 
-    fn dashboard(title: &str) -> String {
-        format!(
-            r##"<div class="drawer lg:drawer-open"><input id="my-drawer" type="checkbox" class="drawer-toggle" /><div class="drawer-content flex flex-col"><div class="w-full navbar bg-base-300"><div class="flex-none lg:hidden"><label for="my-drawer" class="btn btn-square btn-ghost"><svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" class="inline-block w-6 h-6 stroke-current"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M4 6h16M4 12h16M4 18h16"></path></svg></label></div><div class="flex-1 px-2 mx-2 text-xl font-bold">{}</div></div><div class="p-6"><h2 class="text-2xl font-bold mb-4">Dashboard</h2></div></div><div class="drawer-side"><label for="my-drawer" class="drawer-overlay"></label><ul class="menu p-4 w-80 min-h-full bg-base-200 text-base-content"><li class="menu-title">Menu</li><li><a>Overview</a></li></ul></div></div>"##,
-            title
-        )
+    fn dashboard(title: &str, drawer: &str, v: &Variant) -> Page {
+        let [px2, mx2, text_xl, p6, text_2xl, mb4, p4] = [
+            v.space("px", 2),
+            v.space("mx", 2),
+            v.text("xl"),
+            v.space("p", 6),
+            v.text("2xl"),
+            v.space("mb", 4),
+            v.space("p", 4),
+        ];
+        Page::default()
+            .markup(format!(
+                r#"<div class="drawer lg:drawer-open"><input id="{drawer}" type="checkbox" class="drawer-toggle" /><div class="drawer-content flex flex-col">"#
+            ))
+            .section(
+                "navbar",
+                format!(
+                    r#"<nav class="w-full navbar bg-base-300" aria-label="Main"><div class="flex-none lg:hidden"><label for="{drawer}" class="btn btn-square btn-ghost" aria-label="Open menu"><svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" class="inline-block w-6 h-6 stroke-current" aria-hidden="true"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M4 6h16M4 12h16M4 18h16"></path></svg></label></div><div class="flex-1 {px2} {mx2} {text_xl} font-bold">{title}</div></nav>"#
+                ),
+            )
+            .section(
+                "main",
+                format!(
+                    r#"<main class="{p6}"><h1 class="{text_2xl} font-bold {mb4}">Dashboard</h1></main>"#
+                ),
+            )
+            .markup("</div>")
+            .section(
+                "sidebar",
+                format!(
+                    r#"<aside class="drawer-side"><label for="{drawer}" class="drawer-overlay" aria-label="Close menu"></label><ul class="menu {p4} w-80 min-h-full bg-base-200 text-base-content"><li class="menu-title">Menu</li><li><a>Overview</a></li></ul></aside>"#
+                ),
+            )
+            .markup("</div>")
     }
 
-    fn auth_page(title: &str) -> String {
-        format!(
-            r##"<div class="hero min-h-screen bg-base-200"><div class="card shrink-0 w-full max-w-sm shadow-2xl bg-base-100"><form class="card-body"><h1 class="text-2xl font-bold">{}</h1><div class="form-control"><label class="label"><span class="label-text">Email</span></label><input type="email" class="input input-bordered" required /></div><div class="form-control"><label class="label"><span class="label-text">Password</span></label><input type="password" class="input input-bordered" required /></div><div class="form-control mt-6"><button class="btn btn-primary">Login</button></div></form></div></div>"##,
-            title
-        )
+    fn auth_page(title: &str, v: &Variant, ids: &mut IdAllocator) -> Page {
+        let [text_xl, text_2xl, mt6] = [v.text("xl"), v.text("2xl"), v.space("mt", 6)];
+        let form = ids.next("login");
+        Page::default()
+            .markup(format!(
+                r#"<div class="min-h-screen flex flex-col bg-base-200"><nav class="navbar" aria-label="Main"><a class="btn btn-ghost {text_xl}">{title}</a></nav><main class="hero flex-1"><div class="card shrink-0 w-full max-w-sm shadow-2xl bg-base-100">"#
+            ))
+            .section(
+                "form",
+                format!(
+                    r#"<form class="card-body"><h1 class="{text_2xl} font-bold">{title}</h1><div class="form-control"><label class="label" for="{form}-email"><span class="label-text">Email</span></label><input id="{form}-email" type="email" class="input input-bordered" required /></div><div class="form-control"><label class="label" for="{form}-password"><span class="label-text">Password</span></label><input id="{form}-password" type="password" class="input input-bordered" required /></div><div class="form-control {mt6}"><button class="btn btn-primary">Login</button></div></form>"#
+                ),
+            )
+            .markup("</div></main></div>")
     }
 
-    fn store_page(title: &str) -> String {
-        format!(
-            r##"<div class="hero min-h-screen bg-base-200"><div class="hero-content text-center"><div class="max-w-md"><h1 class="text-5xl font-bold">{}</h1><button class="btn btn-primary mt-4">Shop Now</button></div></div></div>"##,
-            title
-        )
+    fn store_page(title: &str, v: &Variant, ids: &mut IdAllocator, s: &mut Sample) -> Page {
+        let [text_xl, text_5xl, py6, py16, p8, text_2xl, mb6, gap6] = [
+            v.text("xl"),
+            v.text("5xl"),
+            v.space("py", 6),
+            v.space("py", 16),
+            v.space("p", 8),
+            v.text("2xl"),
+            v.space("mb", 6),
+            v.space("gap", 6),
+        ];
+        let cart = snippets::icon("cart", "h-5 w-5");
+        let featured = ids.next("products");
+        let products: String = s
+            .many(sample::PRODUCTS, s.count("cards", 3))
+            .into_iter()
+            .zip(
+                [
+                    "photo-1606107557195-0e29a4b5b4aa",
+                    "photo-1559181567-c3190ca9959b",
+                    "photo-1494232410401-ad00d5433cfa",
+                ]
+                .iter()
+                .cycle(),
+            )
+            .map(|(name, photo)| {
+                let price = s.price(12, 180);
+                format!(
+                    r#"        <div class="card bg-base-100 shadow-sm"><figure><img src="https://img.daisyui.com/images/stock/{photo}.jpg" alt="{name}" class="h-48 w-full object-cover" /></figure><div class="card-body"><h3 class="card-title">{name}</h3><p>{price}</p><div class="card-actions justify-end"><button class="btn btn-primary btn-sm">Add to Cart</button></div></div></div>
+"#
+                )
+            })
+            .collect();
+        Page::default()
+            .markup(r#"<div class="min-h-screen bg-base-100">"#)
+            .section(
+                "navbar",
+                format!(
+                    r#"
+  <nav class="navbar bg-base-100 border-b border-base-200" aria-label="Main">
+    <div class="flex-1"><a class="btn btn-ghost {text_xl}">{title}</a></div>
+    <div class="flex-none"><button class="btn btn-ghost btn-circle" aria-label="Cart, 3 items"><span class="indicator">{cart}<span class="badge badge-sm indicator-item">3</span></span></button></div>
+  </nav>"#
+                ),
+            )
+            .section(
+                "hero",
+                format!(
+                    r#"
+  <header class="hero bg-base-200 {py16}">
+    <div class="hero-content text-center">
+      <div class="max-w-md">
+        <h1 class="{text_5xl} font-bold">{title}</h1>
+        <p class="{py6}">Discover amazing products</p>
+        <button class="btn btn-primary">Shop Now</button>
+      </div>
+    </div>
+  </header>"#
+                ),
+            )
+            .section(
+                "products",
+                format!(
+                    r#"
+  <main class="container mx-auto {p8}">
+    <section aria-labelledby="{featured}">
+      <h2 id="{featured}" class="{text_2xl} font-bold {mb6}">Featured Products</h2>
+      <div class="grid grid-cols-1 md:grid-cols-3 {gap6}">
+{products}      </div>
+    </section>
+  </main>"#
+                ),
+            )
+            .markup("\n</div>")
+    }
+
+    /// An analytics page with one canvas per chart type, ids allocated like
+    /// `create_chart`'s.
+    fn analytics(title: &str, charts: &[&str], v: &Variant, ids: &mut IdAllocator) -> Page {
+        let charts: Vec<(&str, String)> = charts
+            .iter()
+            .map(|kind| (*kind, ids.next("chart")))
+            .collect();
+        snippets::analytics_page(title, &charts, v)
     }
 }
 
@@ -771,137 +1779,734 @@ struct IdeaEngine;
 
 impl IdeaEngine {
     fn process_prompt(prompt: &str) -> String {
-        let p = prompt.to_lowercase();
-
-        let layout = if p.contains("blog") || p.contains("article") || p.contains("news") {
-            "blog"
-        } else if p.contains("social") || p.contains("twitter") || p.contains("feed") {
-            "social"
-        } else if p.contains("kanban")
-            || p.contains("trello")
-            || p.contains("board")
-            || p.contains("task")
-        {
-            "kanban"
-        } else if p.contains("mail") || p.contains("inbox") || p.contains("message") {
-            "inbox"
-        } else if p.contains("profile") || p.contains("settings") || p.contains("account") {
-            "profile"
-        } else if p.contains("docs") || p.contains("documentation") || p.contains("wiki") {
-            "docs"
-        } else if p.contains("saas") || p.contains("startup") || p.contains("landing") {
-            "saas"
-        } else if p.contains("dashboard") || p.contains("admin") {
-            "dashboard"
-        } else {
-            "saas"
+        let idea = idea::explain(prompt, &templates().keywords());
+        let html = LayoutEngine::generate(
+            &idea.layout,
+            &idea.title,
+            &Variant::default(),
+            &mut IdAllocator::default(),
+        );
+        let html = match idea.theme {
+            Some(theme) => theme::scope(&html, theme),
+            None => html,
         };
-
-        LayoutEngine::generate(layout, "Generated UI")
+        tidy::tidy(&html, tidy::Style::Pretty)
     }
 }
 
-fn generate_dashboard(title: &str, _items: &[String], _style: &str) -> String {
-    LayoutEngine::generate("dashboard", title)
+fn generate_dashboard(
+    title: &str,
+    _items: &[String],
+    _style: &str,
+    ids: &mut IdAllocator,
+) -> String {
+    let html = LayoutEngine::generate("dashboard", title, &Variant::default(), ids);
+    tidy::tidy(&html, tidy::Style::Pretty)
 }
 
 fn generate_auth(auth_type: &str) -> String {
-    LayoutEngine::generate(
+    let html = LayoutEngine::generate(
         "auth",
         if auth_type == "login" {
             "Login"
         } else {
             "Sign Up"
         },
-    )
+        &Variant::default(),
+        &mut IdAllocator::default(),
+    );
+    tidy::tidy(&html, tidy::Style::Pretty)
 }
 
 fn generate_store(page: &str) -> String {
-    LayoutEngine::generate("store", page)
+    let html = LayoutEngine::generate(
+        "store",
+        page,
+        &Variant::default(),
+        &mut IdAllocator::default(),
+    );
+    tidy::tidy(&html, tidy::Style::Pretty)
 }
 
+/// A complete theme; colors left empty come from daisyUI's light or dark
+/// theme, whichever suits `base`.
 fn generate_theme(name: &str, primary: &str, secondary: &str, accent: &str, base: &str) -> String {
-    format!(
-        r##"@plugin "daisyui/theme" {{ name: "{}"; --color-primary: {}; --color-secondary: {}; --color-accent: {}; --color-base-100: {}; }}"##,
-        name, primary, secondary, accent, base
-    )
+    let overrides: Vec<(String, String)> = [
+        ("primary", primary),
+        ("secondary", secondary),
+        ("accent", accent),
+        ("base-100", base),
+    ]
+    .iter()
+    .filter(|(_, value)| !value.is_empty())
+    .map(|(key, value)| (key.to_string(), value.to_string()))
+    .collect();
+    theme::plugin(name, &overrides)
 }
 
-fn scaffold_form(title: &str, fields: &[serde_json::Map<String, Value>]) -> String {
+/// Concepts `scaffold_form` can lay its fields out in.
+const FORM_STYLES: &[&str] = &[
+    "inline-validation",
+    "floating-labels",
+    "progressive-disclosure",
+];
+
+/// A form with one text input per field. Each field is `{name, optional}`;
+/// `style` is one of [`FORM_STYLES`]: inline-validation gives every field an
+/// error slot and `aria-invalid`, floating-labels puts the label inside the
+/// field, and progressive-disclosure folds optional fields into a collapse.
+fn scaffold_form(
+    title: &str,
+    fields: &[serde_json::Map<String, Value>],
+    style: Option<&str>,
+    ids: &mut IdAllocator,
+) -> String {
     let mut field_html = String::new();
+    let mut optional_html = String::new();
     for f in fields {
         let name = f.get("name").and_then(|v| v.as_str()).unwrap_or("unnamed");
-        field_html.push_str(&format!(r##"<div class="form-control"><label class="label"><span class="label-text">{}</span></label><input type="text" name="{}" class="input input-bordered" /></div>"##, name, name));
+        let optional = f.get("optional").and_then(|v| v.as_bool()).unwrap_or(false);
+        let required = if optional { "" } else { " required" };
+        let id = ids.next("field");
+        let html = match style {
+            Some("inline-validation") => format!(
+                r##"<div class="form-control"><label class="label" for="{0}"><span class="label-text">{1}</span></label><input type="text" id="{0}" name="{1}" class="input input-bordered" aria-invalid="false" aria-describedby="{0}-error"{2} /><label class="label"><span id="{0}-error" class="label-text-alt text-error" aria-live="polite"></span></label></div>"##,
+                id, name, required
+            ),
+            Some("floating-labels") => format!(
+                r##"<label class="floating-label" for="{0}"><span>{1}</span><input type="text" id="{0}" name="{1}" placeholder="{1}" class="input input-bordered w-full"{2} /></label>"##,
+                id, name, required
+            ),
+            _ => format!(
+                r##"<div class="form-control"><label class="label" for="{}"><span class="label-text">{}</span></label><input type="text" id="{}" name="{}" class="input input-bordered" /></div>"##,
+                id, name, id, name
+            ),
+        };
+        if optional && style == Some("progressive-disclosure") {
+            optional_html.push_str(&html);
+        } else {
+            field_html.push_str(&html);
+        }
+    }
+    if !optional_html.is_empty() {
+        field_html.push_str(&format!(
+            r##"<div class="collapse collapse-arrow bg-base-200"><input type="checkbox" aria-label="Show optional fields" /><div class="collapse-title font-medium">More options</div><div class="collapse-content">{}</div></div>"##,
+            optional_html
+        ));
     }
+    let novalidate = if style == Some("inline-validation") {
+        " novalidate"
+    } else {
+        ""
+    };
     format!(
-        r##"<div class="card bg-base-100 w-full max-w-sm shadow-2xl"><form class="card-body"><h2 class="card-title justify-center">{}</h2>{}<div class="form-control mt-6"><button class="btn btn-primary">Submit</button></div></form></div>"##,
-        title, field_html
+        r##"<div class="card bg-base-100 w-full max-w-sm shadow-2xl"><form class="card-body"{}><h2 class="card-title justify-center">{}</h2>{}<div class="form-control mt-6"><button class="btn btn-primary">Submit</button></div></form></div>"##,
+        novalidate, title, field_html
     )
 }
 
-fn get_script(component: &str) -> String {
+/// Components `get_script` has a script for.
+const SCRIPTS: &[&str] = &[
+    "modal",
+    "drawer",
+    "palette",
+    "tag-input",
+    "settings",
+    "survey",
+];
+
+/// Scripts target the first id of their kind, i.e. what a single generation
+/// call with the same prefix emits (`dd-modal-1`, `dd-drawer-1`, ...).
+fn get_script(component: &str, id: Option<&str>, ids: &mut IdAllocator) -> String {
+    let mut target = |kind: &str| id.map_or_else(|| ids.next(kind), String::from);
     match component {
-        "modal" => "document.getElementById('my_modal_1').showModal();".to_string(),
-        "drawer" => "document.getElementById('my-drawer').checked = !document.getElementById('my-drawer').checked;".to_string(),
-        _ => "".to_string()
+        "modal" => format!(
+            "document.getElementById('{}').showModal();",
+            target("modal")
+        ),
+        "drawer" => {
+            let drawer = target("drawer");
+            format!(
+                "document.getElementById('{0}').checked = !document.getElementById('{0}').checked;",
+                drawer
+            )
+        }
+        "palette" => snippets::palette_script(&target("palette")),
+        "tag-input" => snippets::tag_input_script(&target("tags")),
+        "settings" => snippets::settings_script(&target("settings")),
+        "survey" => snippets::survey_script(&target("survey")),
+        _ => "".to_string(),
     }
 }
 
-fn create_chart(chart_type: &str, id: &str) -> String {
-    format!(
-        r##"<canvas id="{}"></canvas><script>new Chart(document.getElementById('{}'), {{ type: '{}', data: {{ datasets: [{{ data: [10, 20] }}] }} }});</script>"##,
-        id, id, chart_type
-    )
-}
+/// Outputs `daisyui_scaffold_layout` accepts in `target`.
+const LAYOUT_TARGETS: &[&str] = &["html", "jsx", "leptos", "yew", "askama", "tera"];
 
-fn create_complex_table(cols: &[String]) -> String {
-    let headers = cols
-        .iter()
-        .map(|c| format!("<th>{}</th>", c))
-        .collect::<Vec<_>>()
-        .join("");
+/// Chart.js chart types the analytics layout accepts in `charts`.
+const CHART_TYPES: &[&str] = &[
+    "line",
+    "bar",
+    "doughnut",
+    "pie",
+    "radar",
+    "polarArea",
+    "bubble",
+    "scatter",
+];
+
+fn create_chart(chart_type: &str, id: &str, colors: Option<&[String]>) -> String {
+    let style = match colors {
+        Some(colors) => {
+            let list = serde_json::to_string(colors).unwrap_or_else(|_| "[]".into());
+            format!(", backgroundColor: {}, borderColor: {}", list, list)
+        }
+        None => String::new(),
+    };
     format!(
-        r##"<table class="table w-full"><thead><tr>{}</tr></thead><tbody><tr><td>Data</td></tr></tbody></table>"##,
-        headers
+        r##"<canvas id="{}"></canvas><script>new Chart(document.getElementById('{}'), {{ type: '{}', data: {{ datasets: [{{ data: [10, 20]{} }}] }} }});</script>"##,
+        id, id, chart_type, style
     )
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-struct JsonRpcRequest {
-    jsonrpc: String,
-    method: String,
-    params: Option<Value>,
-    id: Option<Value>,
+fn chart_palette(args: Option<&serde_json::Map<String, Value>>) -> Result<String, String> {
+    let arg = |key: &str| args.and_then(|a| a.get(key)).and_then(|v| v.as_str());
+    let (theme, selector) = match (arg("theme"), arg("css")) {
+        (_, Some(css)) => (palette::ThemeColors::from_css(css)?, ":root".to_string()),
+        (Some(name), None) => {
+            let name = name.strip_prefix("theme:").unwrap_or(name);
+            (
+                palette::resolve(name)?,
+                format!("[data-theme=\"{}\"]", name),
+            )
+        }
+        (None, None) => return Err("Provide 'theme' or 'css'".into()),
+    };
+    let count = args
+        .and_then(|a| a.get("count"))
+        .and_then(|v| v.as_u64())
+        .unwrap_or(10)
+        .clamp(1, 16) as usize;
+    let colors = palette::palette(&theme, count);
+    Ok(format!(
+        "```json\n{}\n```\n\n```css\n{}\n```",
+        serde_json::to_string(&colors).unwrap_or_default(),
+        palette::css_block(&selector, &colors)
+    ))
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-struct JsonRpcResponse {
-    jsonrpc: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    result: Option<Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<JsonRpcError>,
-    id: Option<Value>,
+fn compose_block(args: Option<&serde_json::Map<String, Value>>) -> Result<String, String> {
+    let block = args
+        .and_then(|a| a.get("block"))
+        .and_then(|v| v.as_str())
+        .ok_or("Missing 'block' argument")?;
+    let items = args
+        .and_then(|a| a.get("items"))
+        .and_then(|v| v.as_array())
+        .filter(|items| !items.is_empty());
+    let text = |item: &Value, key: &str| item.get(key).and_then(|v| v.as_str()).map(String::from);
+    match block {
+        "testimonials" => {
+            let items = match items {
+                Some(items) => items
+                    .iter()
+                    .map(|i| snippets::Testimonial {
+                        quote: text(i, "quote").unwrap_or_default(),
+                        name: text(i, "name").unwrap_or_else(|| "Anonymous".into()),
+                        role: text(i, "role").unwrap_or_default(),
+                        avatar: text(i, "avatar"),
+                    })
+                    .collect(),
+                None => snippets::default_testimonials(),
+            };
+            Ok(snippets::testimonials(
+                &items,
+                &IdAllocator::default().next("testimonials"),
+            ))
+        }
+        "logo-cloud" => {
+            let items = match items {
+                Some(items) => items
+                    .iter()
+                    .map(|i| match i.as_str() {
+                        Some(name) => snippets::Logo {
+                            name: name.to_string(),
+                            image: None,
+                        },
+                        None => snippets::Logo {
+                            name: text(i, "name").unwrap_or_default(),
+                            image: text(i, "image"),
+                        },
+                    })
+                    .collect(),
+                None => snippets::default_logos(),
+            };
+            Ok(snippets::logo_cloud(
+                &items,
+                &IdAllocator::default().next("logos"),
+            ))
+        }
+        "pricing-tiers" => {
+            let items = match items {
+                Some(items) => items
+                    .iter()
+                    .map(|i| snippets::PricingTier {
+                        name: text(i, "name").unwrap_or_default(),
+                        price: text(i, "price").unwrap_or_else(|| "$0".into()),
+                        period: text(i, "period").unwrap_or_else(|| "month".into()),
+                        features: i
+                            .get("features")
+                            .and_then(|v| v.as_array())
+                            .map(|fs| {
+                                fs.iter()
+                                    .filter_map(|f| f.as_str().map(String::from))
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                        highlighted: i
+                            .get("highlighted")
+                            .and_then(|v| v.as_bool())
+                            .unwrap_or(false),
+                    })
+                    .collect(),
+                None => snippets::default_pricing_tiers(),
+            };
+            Ok(snippets::pricing_tiers(
+                &items,
+                &IdAllocator::default().next("pricing"),
+            ))
+        }
+        other => Err(format!(
+            "Unknown block '{}'. Available: {}",
+            other,
+            snippets::BLOCKS.join(", ")
+        )),
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-struct JsonRpcError {
-    code: i32,
-    message: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    data: Option<Value>,
+/// One named section of a built-in layout, taken from the whole layout so
+/// ids and the title match what `daisyui_scaffold_layout` would give.
+fn scaffold_section(args: Option<&serde_json::Map<String, Value>>) -> Result<String, String> {
+    let arg = |key: &str| args.and_then(|a| a.get(key)).and_then(|v| v.as_str());
+    let layout = arg("layout").ok_or("Missing 'layout' argument")?;
+    let section = arg("section").ok_or("Missing 'section' argument")?;
+    if !LayoutEngine::LAYOUTS.contains(&layout) {
+        return Err(format!(
+            "Unknown layout '{}'. Layouts with sections: {}",
+            layout,
+            LayoutEngine::LAYOUTS.join(", ")
+        ));
+    }
+    let style = tidy::Style::parse(arg("format").unwrap_or("pretty")).ok_or_else(|| {
+        format!(
+            "Unknown format '{}'; use one of: {}",
+            arg("format").unwrap_or_default(),
+            tidy::Style::NAMES.join(", ")
+        )
+    })?;
+    let page = LayoutEngine::build(
+        layout,
+        arg("title").unwrap_or("My App"),
+        &Variant::parse(arg("variant").unwrap_or(variant::DEFAULT)).unwrap_or_default(),
+        &mut id_allocator(args),
+    );
+    Ok(tidy::tidy(&sections::pick(layout, &page, section)?, style))
 }
 
-fn main() -> Result<()> {
-    eprintln!("Daisy Days - Engraved by Ahmad Hamdi");
+fn compose_page(
+    args: Option<&serde_json::Map<String, Value>>,
+) -> Result<(String, Vec<String>), String> {
+    let arg = |key: &str| args.and_then(|a| a.get(key)).and_then(|v| v.as_str());
+    let ids: Vec<&str> = args
+        .and_then(|a| a.get("sections"))
+        .and_then(|v| v.as_array())
+        .ok_or("Missing 'sections' argument")?
+        .iter()
+        .filter_map(|v| v.as_str())
+        .collect();
+    let style = tidy::Style::parse(arg("format").unwrap_or("pretty")).ok_or_else(|| {
+        format!(
+            "Unknown format '{}'; use one of: {}",
+            arg("format").unwrap_or_default(),
+            tidy::Style::NAMES.join(", ")
+        )
+    })?;
+    let title = arg("title").unwrap_or("My App");
+    let variant = Variant::parse(arg("variant").unwrap_or(variant::DEFAULT)).unwrap_or_default();
+    let mut ids_alloc = id_allocator(args);
+    let (html, notes) = sections::compose(&ids, |layout| {
+        LayoutEngine::build(layout, title, &variant, &mut ids_alloc)
+    })?;
+    Ok((tidy::tidy(&html, style), notes))
+}
 
-    let docs = Arc::new(DocsCache::load());
-    let concepts = Arc::new(ConceptEngine::new());
+/// Every page of a site preset, each led by a comment naming its file,
+/// generated with its own id allocator and its navbar linked to the others.
+fn scaffold_site(args: Option<&serde_json::Map<String, Value>>) -> Result<Vec<String>, String> {
+    let arg = |key: &str| args.and_then(|a| a.get(key)).and_then(|v| v.as_str());
+    let preset = site::find(arg("preset").ok_or("Missing 'preset' argument")?)?;
+    let style = tidy::Style::parse(arg("format").unwrap_or("pretty")).ok_or_else(|| {
+        format!(
+            "Unknown format '{}'; use one of: {}",
+            arg("format").unwrap_or_default(),
+            tidy::Style::NAMES.join(", ")
+        )
+    })?;
+    let title = arg("title").unwrap_or("My App");
+    let variant = Variant::parse(arg("variant").unwrap_or(variant::DEFAULT)).unwrap_or_default();
+    let pages = site::build(preset, &snippets::escape_title(title), |page| {
+        LayoutEngine::generate(page.layout, title, &variant, &mut id_allocator(args))
+    });
+    Ok(pages
+        .into_iter()
+        .map(|(page, html)| tidy::tidy(&format!("<!-- {} -->\n{}", page.file, html), style))
+        .collect())
+}
+
+fn avatar_snippet(args: Option<&serde_json::Map<String, Value>>) -> Result<String, String> {
+    let arg = |key: &str| args.and_then(|a| a.get(key)).and_then(|v| v.as_str());
+    let size = match arg("size") {
+        Some(s) => AvatarSize::parse(s).ok_or_else(|| {
+            format!(
+                "Unknown size '{}'. Valid: {}",
+                s,
+                AvatarSize::NAMES.join(", ")
+            )
+        })?,
+        None => AvatarSize::Md,
+    };
+    let shape = match arg("shape") {
+        Some(s) => AvatarShape::parse(s).ok_or_else(|| {
+            format!(
+                "Unknown shape '{}'. Valid: {}",
+                s,
+                AvatarShape::NAMES.join(", ")
+            )
+        })?,
+        None => AvatarShape::Circle,
+    };
+    let presence = match arg("presence") {
+        Some(p) => Some(snippets::Presence::parse(p).ok_or_else(|| {
+            format!(
+                "Unknown presence '{}'. Valid: {}",
+                p,
+                snippets::Presence::NAMES.join(", ")
+            )
+        })?),
+        None => None,
+    };
+    if let Some(group) = args.and_then(|a| a.get("group")).and_then(|v| v.as_array()) {
+        let sources: Vec<&str> = group.iter().filter_map(|v| v.as_str()).collect();
+        let max = args
+            .and_then(|a| a.get("max"))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(3) as usize;
+        return Ok(snippets::avatar_group(&sources, size, max));
+    }
+    let source = arg("source").ok_or("Provide 'source' (image URL or name) or 'group'")?;
+    Ok(snippets::avatar(source, size, shape, presence))
+}
+
+fn badges_snippet(args: Option<&serde_json::Map<String, Value>>) -> Result<String, String> {
+    let list = |key: &str| -> Vec<&str> {
+        args.and_then(|a| a.get(key))
+            .and_then(|v| v.as_array())
+            .map(|items| items.iter().filter_map(|v| v.as_str()).collect())
+            .unwrap_or_default()
+    };
+    let overrides = match args
+        .and_then(|a| a.get("colors"))
+        .and_then(|v| v.as_object())
+    {
+        Some(map) => snippets::parse_status_overrides(
+            &map.iter()
+                .filter_map(|(state, color)| color.as_str().map(|c| format!("{}={}", state, c)))
+                .collect::<Vec<_>>()
+                .join(","),
+        )?,
+        None => Vec::new(),
+    };
+    let dot = args
+        .and_then(|a| a.get("dot"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    let kind = args
+        .and_then(|a| a.get("kind"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("status");
+    match kind {
+        "status" => {
+            let states = list("states");
+            if states.is_empty() {
+                return Err("Provide 'states', e.g. [\"active\", \"pending\", \"failed\"]".into());
+            }
+            Ok(snippets::status_badges(&states, &overrides, dot))
+        }
+        "tags" => Ok(snippets::tag_chips(&list("tags"))),
+        "tag-input" => {
+            let id = args
+                .and_then(|a| a.get("id"))
+                .and_then(|v| v.as_str())
+                .map_or_else(|| id_allocator(args).next("tags"), String::from);
+            let id = id.as_str();
+            Ok(format!(
+                "{}\n<script>\n{}\n</script>",
+                snippets::tag_input(id, &list("tags"), &list("suggestions")),
+                snippets::tag_input_script(id)
+            ))
+        }
+        other => Err(format!(
+            "Unknown kind '{}'. Valid: status, tags, tag-input",
+            other
+        )),
+    }
+}
+
+fn keyboard_snippet(args: Option<&serde_json::Map<String, Value>>) -> Result<String, String> {
+    let arg = |key: &str| args.and_then(|a| a.get(key)).and_then(|v| v.as_str());
+    let platform = match arg("platform") {
+        Some(p) => snippets::Platform::parse(p)
+            .ok_or_else(|| format!("Unknown platform '{}'. Use mac or pc", p))?,
+        None => snippets::Platform::Mac,
+    };
+    match arg("kind").unwrap_or("kbd") {
+        "kbd" => {
+            let shortcut = arg("shortcut").ok_or("Missing 'shortcut' argument")?;
+            snippets::kbd_row(shortcut, platform, arg("size"))
+        }
+        "palette" => {
+            let commands = match args
+                .and_then(|a| a.get("commands"))
+                .and_then(|v| v.as_array())
+            {
+                Some(items) => items
+                    .iter()
+                    .filter_map(|item| {
+                        let label = item.get("label")?.as_str()?;
+                        let group = item
+                            .get("group")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("Commands");
+                        let shortcut = item.get("shortcut").and_then(|v| v.as_str());
+                        Some(snippets::PaletteCommand::new(group, label, shortcut))
+                    })
+                    .collect(),
+                None => snippets::default_palette_commands(),
+            };
+            let id = arg("id").map_or_else(|| id_allocator(args).next("palette"), String::from);
+            snippets::command_palette(&id, &commands, platform)
+        }
+        other => Err(format!("Unknown kind '{}'. Use kbd or palette", other)),
+    }
+}
+
+/// Fresh allocator for one tool call, honoring its `id_prefix` argument.
+fn id_allocator(args: Option<&serde_json::Map<String, Value>>) -> IdAllocator {
+    IdAllocator::new(
+        args.and_then(|a| a.get("id_prefix"))
+            .and_then(|v| v.as_str()),
+    )
+}
+
+/// `footprint: true` asks for a footprint table after the generated markup.
+fn want_footprint(args: Option<&serde_json::Map<String, Value>>) -> bool {
+    args.and_then(|a| a.get("footprint"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+/// `provenance: true` prefixes generated markup with a stamp that
+/// `daisyui_reproduce` accepts.
+fn want_provenance(args: Option<&serde_json::Map<String, Value>>) -> bool {
+    args.and_then(|a| a.get("provenance"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+fn preview_page(
+    html: Option<&str>,
+    layout: &str,
+    title: &str,
+    breakpoint: Option<&str>,
+    theme: Option<&str>,
+) -> Result<preview::Preview, String> {
+    let body = match html {
+        Some(html) => html.to_string(),
+        None => LayoutEngine::generate(
+            layout,
+            title,
+            &Variant::default(),
+            &mut IdAllocator::default(),
+        ),
+    };
+    preview::build(&snippets::escape_title(title), &body, breakpoint, theme)
+}
+
+fn preview_summary(page: &preview::Preview) -> String {
+    let mut summary = if page.missing.is_empty() {
+        "All classes have inlined CSS.".to_string()
+    } else {
+        format!(
+            "Warning: no inlined CSS for {} class(es): {}",
+            page.missing.len(),
+            page.missing.join(", ")
+        )
+    };
+    if let Some(theme) = &page.unstyled_theme {
+        summary.push_str(&format!(
+            " Warning: no inlined colors for theme '{}', so it shows light colors.",
+            theme
+        ));
+    }
+    summary
+}
+
+/// `daisy_days preview <layout> [title...] [--html <file>] [--breakpoint <bp>] [--theme <name>] [--data-url] [--layouts <dir>]`
+fn run_preview_cli(args: &[String]) -> Result<()> {
+    let mut html = None;
+    let mut breakpoint = None;
+    let mut theme = None;
+    let mut as_data_url = false;
+    let mut positional = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--html" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("--html requires a file path"))?;
+                html = Some(std::fs::read_to_string(path)?);
+            }
+            "--breakpoint" => {
+                breakpoint = Some(
+                    iter.next()
+                        .ok_or_else(|| anyhow::anyhow!("--breakpoint requires a name"))?
+                        .as_str(),
+                );
+            }
+            "--theme" => {
+                let name = iter
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("--theme requires a name"))?;
+                theme::check_known(name, &[]).map_err(anyhow::Error::msg)?;
+                theme = Some(name.as_str());
+            }
+            "--data-url" => as_data_url = true,
+            // Read in `main` before dispatching here.
+            "--layouts" => {
+                iter.next();
+            }
+            _ => positional.push(arg.as_str()),
+        }
+    }
+    let layout = positional.first().copied().unwrap_or("saas");
+    let title = if positional.len() > 1 {
+        positional[1..].join(" ")
+    } else {
+        "My App".to_string()
+    };
+
+    let page = preview_page(html.as_deref(), layout, &title, breakpoint, theme)
+        .map_err(anyhow::Error::msg)?;
+    eprintln!("daisy_days: {}", preview_summary(&page));
+    if as_data_url {
+        println!("{}", preview::data_url(&page.html));
+    } else {
+        println!("{}", preview::write_temp(layout, &page.html)?.display());
+    }
+    Ok(())
+}
+
+fn create_complex_table(cols: &[String]) -> String {
+    let headers = cols
+        .iter()
+        .map(|c| format!("<th>{}</th>", c))
+        .collect::<Vec<_>>()
+        .join("");
+    format!(
+        r##"<table class="table w-full"><thead><tr>{}</tr></thead><tbody><tr><td>Data</td></tr></tbody></table>"##,
+        headers
+    )
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct JsonRpcRequest {
+    jsonrpc: String,
+    method: String,
+    params: Option<Value>,
+    id: Option<Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct JsonRpcResponse {
+    jsonrpc: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<JsonRpcError>,
+    id: Option<Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct JsonRpcError {
+    code: i32,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<Value>,
+}
+
+fn main() -> Result<()> {
+    let cli_args: Vec<String> = std::env::args().skip(1).collect();
+
+    let layouts_dir = cli_args
+        .iter()
+        .position(|a| a == "--layouts")
+        .and_then(|i| cli_args.get(i + 1).cloned())
+        .or_else(|| std::env::var("DAISY_DAYS_LAYOUTS").ok())
+        .unwrap_or_else(|| plugins::DIR.to_string());
+    let registry = load_templates(std::path::Path::new(&layouts_dir));
+    for error in &registry.errors {
+        eprintln!("daisy_days: layout template skipped: {}", error);
+    }
+    if !registry.layouts.is_empty() {
+        eprintln!(
+            "daisy_days: loaded {} template layout(s) from {}",
+            registry.layouts.len(),
+            layouts_dir
+        );
+    }
+    let _ = TEMPLATES.set(registry);
+
+    if cli_args.first().map(|s| s.as_str()) == Some("preview") {
+        return run_preview_cli(&cli_args[1..]);
+    }
+
+    eprintln!("Daisy Days - Engraved by Ahmad Hamdi");
+
+    let docs = Arc::new(DocsCache::load(DAISYUI_DOCS_CONTENT));
+    if let Some(diagnostic) = &docs.diagnostic {
+        eprintln!("daisy_days: warning: {}", diagnostic);
+    }
+    let mut concepts = ConceptEngine::new();
+    for error in &concepts.errors {
+        eprintln!("daisy_days: concept skipped: {}", error);
+    }
+    if let Ok(text) = std::fs::read_to_string(concepts::PROJECT_FILE) {
+        match concepts.with_project(&text) {
+            Ok(merged) => concepts = merged,
+            Err(e) => eprintln!("daisy_days: {}", e),
+        }
+    }
+    let concepts = Arc::new(concepts);
+    let session = Arc::new(Session::default());
 
     let stdin = io::stdin();
     let mut reader = stdin.lock();
     let mut line = String::new();
     let mut stdout = io::stdout();
+    // After `shutdown` only `exit` is accepted. `exit` without a `shutdown`
+    // first ends with status 1; closing stdin still ends cleanly.
+    let mut shutting_down = false;
+    let mut status = 0;
 
     loop {
         line.clear();
@@ -919,7 +2524,34 @@ fn main() -> Result<()> {
 
                 match serde_json::from_str::<JsonRpcRequest>(req_str) {
                     Ok(req) => {
-                        let res = handle_request(req, docs.clone(), concepts.clone());
+                        if req.method == "exit" {
+                            if !shutting_down {
+                                eprintln!("daisy_days: exit without shutdown");
+                                status = 1;
+                            }
+                            break;
+                        }
+                        // Notifications (no id) must never get a reply.
+                        let notification = req.id.is_none();
+                        let res = if shutting_down {
+                            JsonRpcResponse {
+                                jsonrpc: "2.0".to_string(),
+                                result: None,
+                                error: Some(JsonRpcError {
+                                    code: -32600,
+                                    message: "Server is shutting down; only exit is accepted"
+                                        .to_string(),
+                                    data: None,
+                                }),
+                                id: req.id.clone(),
+                            }
+                        } else {
+                            shutting_down = req.method == "shutdown";
+                            handle_request(req, docs.clone(), concepts.clone(), session.clone())
+                        };
+                        if notification {
+                            continue;
+                        }
                         match serde_json::to_string(&res) {
                             Ok(res_str) => {
                                 if let Err(e) = writeln!(stdout, "{}", res_str) {
@@ -938,7 +2570,7 @@ fn main() -> Result<()> {
                                     result: None,
                                     error: Some(JsonRpcError {
                                         code: -32603,
-                                        message: format!("Internal error: serialization failed"),
+                                        message: "Internal error: serialization failed".to_string(),
                                         data: None,
                                     }),
                                     id: res.id.clone(),
@@ -952,9 +2584,7 @@ fn main() -> Result<()> {
                     }
                     Err(e) => {
                         eprintln!("daisy_days: failed to parse JSON-RPC request: {}", e);
-                        let err_str = format!(
-                            r#"{{"jsonrpc":"2.0","error":{{"code":-32700,"message":"Parse error"}},"id":null}}"#
-                        );
+                        let err_str = r#"{"jsonrpc":"2.0","error":{"code":-32700,"message":"Parse error"},"id":null}"#;
                         let _ = writeln!(stdout, "{}", err_str);
                         let _ = stdout.flush();
                     }
@@ -968,13 +2598,405 @@ fn main() -> Result<()> {
     }
 
     eprintln!("daisy_days: server stopped");
+    if status != 0 {
+        std::process::exit(status);
+    }
     Ok(())
 }
 
+const PROVENANCE_DESCRIPTION: &str = "Prefix the HTML with a comment recording the daisy-days version, tool and resolved options; pass it to daisyui_reproduce to regenerate the same output";
+
+/// Every tool with its input schema, as served by `tools/list`.
+fn tool_definitions() -> Value {
+    json!([
+        { "name": "daisyui_idea_to_ui", "description": "Turn a prompt into a stunning UI.", "inputSchema": { "type": "object", "properties": { "prompt": { "type": "string" }, "provenance": { "type": "boolean", "description": PROVENANCE_DESCRIPTION } }, "required": ["prompt"] } },
+        { "name": "daisyui_explain_idea", "description": "Show how daisyui_idea_to_ui reads a prompt: keyword scores per layout, inferred title, theme and tone, and the chosen layout. Generates no HTML.", "inputSchema": { "type": "object", "properties": { "prompt": { "type": "string" } }, "required": ["prompt"] } },
+        {
+            "name": "daisyui_scaffold_layout",
+            "description": "Generate a modern web layout skeleton.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "layout": { "type": "string", "enum": LayoutEngine::layouts(), "description": "Layout type; includes template layouts from the layouts directory" },
+                    "title": { "type": "string" },
+                    "theme": { "type": "string", "description": "daisyUI theme set as data-theme on the root element (layouts with modals beside the root are wrapped): a built-in theme or one generated in this session" },
+                    "diff": { "type": "boolean", "description": "Return a diff against the previous layout generated in this session instead of the full HTML" },
+                    "annotate_responsive": { "type": "boolean", "description": "Insert an HTML comment above each block that changes across breakpoints, describing how (derived from its responsive classes)" },
+                    "footprint": { "type": "boolean", "description": "Append a table of daisyUI components used, DOM node count, images, JavaScript needs and CDN resources" },
+                    "full_document": { "type": "boolean", "description": "Return a complete HTML5 page (doctype, head with the Tailwind and daisyUI CDN tags, title, data-theme on <html> and a theme toggle button) instead of a body fragment" },
+                    "provenance": { "type": "boolean", "description": PROVENANCE_DESCRIPTION },
+                    "target": { "type": "string", "enum": LAYOUT_TARGETS, "description": "html (default); jsx for an exported React component named after the title: className/htmlFor, self-closed void elements, style objects and arrow-function handlers; leptos/yew for a #[component] view! or #[function_component] html! function with text as string literals; or askama/tera for a server template where repeated items (cards, table rows, menu items) become {% for %} loops, the title becomes {{ title }} and a leading comment lists the context fields" },
+                    "id_prefix": { "type": "string", "description": "Namespace for generated element ids (default: dd, giving dd-<kind>-<n>)" },
+                    "variant": { "type": "string", "enum": variant::NAMES, "description": "Spacing density: padding, gap and text-size scale (default: comfortable)" },
+                    "charts": { "type": "array", "items": { "type": "string", "enum": CHART_TYPES }, "description": "Chart types for the analytics layout, one card each (default: line, bar, doughnut)" },
+                    "concept": { "type": "string", "description": "Design concept (see daisyui_list_concepts) whose classes are added to the layout's components, e.g. glass on every card, navbar and modal" },
+                    "purity": { "type": "string", "enum": ["tailwind", "daisy-only"], "description": "daisy-only uses daisyUI-native markup (join, fieldset, tabs, list) where a variant exists and reports the Tailwind utilities that remain. Variants: auth, profile, store, faq" },
+                    "format": { "type": "string", "enum": tidy::Style::NAMES, "description": "pretty (default) indents block elements two spaces per level, one per line; minified collapses the whitespace between tags. Whitespace inside pre, textarea and script is kept" },
+                    "direction": { "type": "string", "enum": rtl::DIRECTIONS, "description": "ltr (default); rtl sets dir=\"rtl\" on the root and mirrors physical utilities (border-r ↔ border-l, ml-* ↔ mr-*, left-* ↔ right-*, rounded-l ↔ rounded-r, text-left ↔ text-right). Logical ms-*/me-* classes are kept" },
+                    "placeholders": { "type": "string", "enum": images::STRATEGIES, "description": "remote (default) keeps the stock photo URLs; local points images at /images/<name>.jpg, svg at an inline gray data-URI rectangle labeled with its size, both with width and height; none swaps each image for a daisyUI skeleton of the same size" },
+                    "seed": { "type": "integer", "minimum": 0, "description": "Seed for the sample names, products, prices, dates and text in the blog, social, kanban, inbox, store and jobs layouts (default: derived from the title, so the same title gives the same content)" },
+                    "counts": { "type": "object", "additionalProperties": { "type": "integer", "minimum": 1, "maximum": sample::MAX_COUNT }, "description": format!("How many items repeat, e.g. {{\"cards\": 12}}, at most {}. Keys a layout doesn't read and values that aren't whole numbers are ignored with a note. Keys by layout: {}", sample::MAX_COUNT, sample::describe_counts()) },
+                    "slots": { "type": "object", "additionalProperties": { "type": "string" }, "description": format!("Markup to put in the layout's sections in place of their sample content, by name: {}. It is inserted as given, without escaping; the section's own element and classes stay. Slots the layout doesn't have are reported, and so is markup containing a <script> tag. The manifest lists each layout's sections", sections::SLOTS.join(", ")) }
+                },
+                "required": ["layout"]
+            }
+        },
+        {
+            "name": "daisyui_scaffold_section",
+            "description": "Generate one named section of a built-in layout, e.g. the saas hero or the dashboard sidebar. An unknown section is an error listing the layout's sections.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "layout": { "type": "string", "enum": LayoutEngine::LAYOUTS },
+                    "section": { "type": "string", "enum": sections::all_names(), "description": "Section name; daisyui_manifest lists each layout's sections" },
+                    "title": { "type": "string" },
+                    "id_prefix": { "type": "string", "description": "Namespace for generated element ids (default: dd, giving dd-<kind>-<n>)" },
+                    "variant": { "type": "string", "enum": variant::NAMES, "description": "Spacing density: padding, gap and text-size scale (default: comfortable)" },
+                    "format": { "type": "string", "enum": tidy::Style::NAMES, "description": "pretty (default) or minified" }
+                },
+                "required": ["layout", "section"]
+            }
+        },
+        {
+            "name": "daisyui_compose_page",
+            "description": "Stitch sections from one or more layouts into one page, in order: e.g. [\"navbar\", \"hero\", \"pricing\", \"faq\", \"footer\"]. The sections share one wrapper, a page keeps only its first navbar and footer, and only the first full-height section keeps its height. A leading HTML comment lists each section's layout; a second text item notes anything dropped or changed.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "sections": { "type": "array", "items": { "type": "string" }, "minItems": 1, "description": "Section identifiers in page order: layout:section (e.g. dashboard:sidebar), or a bare section name for the first layout that has it (hero is the saas hero). daisyui_manifest lists each layout's sections" },
+                    "title": { "type": "string" },
+                    "id_prefix": { "type": "string", "description": "Namespace for generated element ids (default: dd, giving dd-<kind>-<n>)" },
+                    "variant": { "type": "string", "enum": variant::NAMES, "description": "Spacing density: padding, gap and text-size scale (default: comfortable)" },
+                    "format": { "type": "string", "enum": tidy::Style::NAMES, "description": "pretty (default) or minified" }
+                },
+                "required": ["sections"]
+            }
+        },
+        {
+            "name": "daisyui_scaffold_site",
+            "description": "Generate every page of a small site at once: startup (saas landing, pricing, docs, sign-in) or shop (store, checkout, sign-in, account). Returns one text item per page, each starting with an HTML comment naming its suggested file (index.html, pricing.html, ...). Every page's navbar links the others, with the current page marked aria-current.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "preset": { "type": "string", "enum": site::PRESETS.iter().map(|p| p.name).collect::<Vec<_>>(), "description": "Which set of pages to generate" },
+                    "title": { "type": "string" },
+                    "id_prefix": { "type": "string", "description": "Namespace for generated element ids (default: dd, giving dd-<kind>-<n>)" },
+                    "variant": { "type": "string", "enum": variant::NAMES, "description": "Spacing density: padding, gap and text-size scale (default: comfortable)" },
+                    "format": { "type": "string", "enum": tidy::Style::NAMES, "description": "pretty (default) or minified" }
+                },
+                "required": ["preset"]
+            }
+        },
+        {
+            "name": "daisyui_layout_diff",
+            "description": "Generate a layout with old and new options and return a unified diff of the HTML plus the options that differ. Without 'from', the last layout generated in this session is used.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "from": { "type": "object", "description": "{layout, title, theme}; defaults to the previous generation" },
+                    "to": { "type": "object", "description": "{layout, title, theme}; unset keys inherit from 'from'" }
+                },
+                "required": ["to"]
+            }
+        },
+        { "name": "daisyui_list_components", "description": "List components.", "inputSchema": { "type": "object", "properties": {} } },
+        { "name": "daisyui_cheatsheet", "description": "Markdown table of components with their main class, category and a one-line summary.", "inputSchema": { "type": "object", "properties": { "filter": { "type": "string", "description": "Category prefix (e.g. nav for navigation) or component name substring" } } } },
+        { "name": "daisyui_random_component", "description": "Docs of a randomly chosen component, for exploration. Pass a seed to get the same pick again.", "inputSchema": { "type": "object", "properties": { "category": { "type": "string", "enum": docs::categories(), "description": "Only pick from this category (default: any)" }, "seed": { "type": "integer", "minimum": 0, "description": "Fixed seed; without one each call picks anew" } } } },
+        { "name": "daisyui_get_docs", "description": "Get docs.", "inputSchema": { "type": "object", "properties": { "component": { "type": "string", "description": "Component name, optionally with #section for one subsection (e.g. button#syntax); comma-separated for several" }, "sanitize": { "type": "string", "enum": ["strict", "lenient"], "description": "strict (default) escapes all raw HTML outside code; lenient escapes only active tags like <script>" }, "max_chars": { "type": "integer", "minimum": 1, "description": "Cut the doc at a paragraph break within this many characters (default: no limit)" } }, "required": ["component"] } },
+        { "name": "daisyui_which_component", "description": "Find the component a daisyUI class belongs to (e.g. join-item → join) and return its docs.", "inputSchema": { "type": "object", "properties": { "class": { "type": "string", "description": "Class name, with or without a leading dot" } }, "required": ["class"] } },
+        { "name": "daisyui_search", "description": "Search docs. Results come in pages; the output reports which matches are shown and the total.", "inputSchema": { "type": "object", "properties": { "query": { "type": "string", "description": "Words to find; \"quoted phrases\" must appear as written and -term drops components containing term" }, "limit": { "type": "integer", "minimum": 1, "description": "Results per page (default 20)" }, "offset": { "type": "integer", "minimum": 0, "description": "Matches to skip before the page starts (default 0)" }, "format": { "type": "string", "enum": ["markdown", "json"], "description": "markdown (default) is a readable list; json is an array of {name, score, snippet, matches} followed by the paging status: snippet is plain text and matches holds the [start, end) character offsets of the query words in it" } } } },
+        { "name": "daisyui_get_concept", "description": "Get a design concept by key, or the closest match for keywords like 'frosted' or 'dark theme'.", "inputSchema": { "type": "object", "properties": { "concept": { "type": "string", "description": "Concept key or keywords" }, "target": { "type": "string", "enum": frameworks::Target::names(), "description": "Snippet markup: html (default), jsx with className/htmlFor, vue/svelte with void elements closed, or leptos/yew view!/html! markup with text as string literals" }, "example": { "type": "string", "description": "Part of an example's title, e.g. 'navbar'; only that example is shown (default: all, with the first as the snippet)" }, "format": { "type": "string", "enum": ["markdown", "json"], "description": "markdown (default) is the display text; json is {key, name, description, classes, suggestion, category, snippet, example, examples} where snippet is the chosen example's code, example its title and examples every {title, code}, plus the script when it has one and a note when keywords picked the concept" } } } },
+        { "name": "daisyui_a11y_hints", "description": "Check HTML for form fields without labels, buttons without text or aria-label, and images without alt. Tag-level checks, not a full audit.", "inputSchema": { "type": "object", "required": ["html"], "properties": { "html": { "type": "string", "description": "Markup to check" } } } },
+        { "name": "daisyui_compare_concepts", "description": "Compare two design concepts: a markdown table of their category, description, classes and best use, then both snippets. Keywords pick the closest concept.", "inputSchema": { "type": "object", "required": ["first", "second"], "properties": { "first": { "type": "string", "description": "Concept key or keywords" }, "second": { "type": "string", "description": "Another concept key or keywords" } } } },
+        { "name": "daisyui_style_recipe", "description": "Compose a style recipe from one visual-style, one layout and one motion concept: merged classes, each concept's suggestion and an example card. Concepts that clash (e.g. darkmode and neumorphism) are never combined.", "inputSchema": { "type": "object", "properties": { "seed": { "type": "integer", "minimum": 0, "description": "Fixed seed to reproduce a recipe; without one each call picks anew" }, "include": { "type": "array", "items": { "type": "string" }, "description": "Concepts the recipe must contain, e.g. [\"darkmode\"]" } } } },
+        { "name": "daisyui_concept_theme", "description": "Generate a complete daisyUI 5 theme (@plugin \"daisyui/theme\" with every --color-* variable) in a design concept's colors, e.g. dark base colors for darkmode.", "inputSchema": { "type": "object", "required": ["concept"], "properties": { "concept": { "type": "string", "description": "Concept key or keywords" }, "name": { "type": "string", "description": "Theme name for data-theme, a-z, 0-9 and - (default: the concept key)" } } } },
+        { "name": "daisyui_list_concepts", "description": "List design concepts with one-line descriptions, grouped by category.", "inputSchema": { "type": "object", "properties": { "category": { "type": "string", "enum": concepts::CATEGORIES, "description": "Only list this category (default: all)" } } } },
+        { "name": "daisyui_scaffold_dashboard", "description": "Generate Dashboard (Legacy).", "inputSchema": { "type": "object", "properties": { "title": { "type": "string" }, "style": { "type": "string" }, "id_prefix": { "type": "string", "description": "Namespace for generated element ids (default: dd, giving dd-<kind>-<n>)" } } } },
+        { "name": "daisyui_scaffold_auth", "description": "Generate Auth (Legacy).", "inputSchema": { "type": "object", "properties": { "type": { "type": "string" } } } },
+        { "name": "daisyui_scaffold_store", "description": "Generate Store (Legacy).", "inputSchema": { "type": "object", "properties": { "page": { "type": "string" } } } },
+        { "name": "daisyui_create_chart", "description": "Generate Chart.", "inputSchema": { "type": "object", "properties": { "type": { "type": "string" }, "id": { "type": "string", "description": "Canvas id (default: generated, e.g. dd-chart-1)" }, "palette": { "type": "string", "description": "Dataset colors from a theme, e.g. \"theme:dracula\"" }, "id_prefix": { "type": "string", "description": "Namespace for generated element ids (default: dd, giving dd-<kind>-<n>)" } } } },
+        {
+            "name": "daisyui_chart_palette",
+            "description": "Ordered hex colors for Chart.js/ECharts derived from a daisyUI theme (primary, secondary, accent, info, success, warning, error, then distinguishable tints), as JSON and as CSS custom properties.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "theme": { "type": "string", "description": "Theme name, e.g. dracula or theme:dracula" },
+                    "css": { "type": "string", "description": "Theme CSS with --color-* hex values, e.g. from daisyui_generate_theme" },
+                    "count": { "type": "integer", "description": "Number of colors (default: 10)" }
+                }
+            }
+        },
+        { "name": "daisyui_create_table", "description": "Generate Table.", "inputSchema": { "type": "object", "properties": { "columns": { "type": "array" } } } },
+        { "name": "daisyui_generate_theme", "description": "Generate Theme.", "inputSchema": { "type": "object", "properties": { "name": { "type": "string" }, "primary": { "type": "string" }, "base": { "type": "string" } } } },
+        { "name": "daisyui_scaffold_form", "description": "Generate Form.", "inputSchema": { "type": "object", "properties": { "title": { "type": "string" }, "fields": { "type": "array", "description": "Field names, or {name, optional} objects (default: Name, Email and an optional Company)" }, "style": { "type": "string", "enum": FORM_STYLES, "description": "A forms concept: inline-validation adds an error slot and aria-invalid per field, floating-labels puts labels inside the fields, progressive-disclosure folds optional fields into a collapse (default: plain fields)" }, "id_prefix": { "type": "string", "description": "Namespace for generated element ids (default: dd, giving dd-<kind>-<n>)" } } } },
+        { "name": "daisyui_get_script", "description": "Get Script.", "inputSchema": { "type": "object", "required": ["component"], "properties": { "component": { "type": "string", "enum": SCRIPTS }, "id": { "type": "string", "description": "Element id the script targets (default: the first generated id of that kind, e.g. dd-modal-1)" }, "id_prefix": { "type": "string", "description": "Namespace for generated element ids (default: dd, giving dd-<kind>-<n>)" } } } },
+        {
+            "name": "daisyui_component_examples",
+            "description": "Return every code example for a component, each captioned from the nearest preceding heading or sentence in its doc.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "component": { "type": "string" },
+                    "count": { "type": "integer", "description": "Maximum number of examples to return" }
+                },
+                "required": ["component"]
+            }
+        },
+        {
+            "name": "daisyui_get_example",
+            "description": "Return one code example for a component by its 1-based index, or every example numbered when index is omitted.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "component": { "type": "string" },
+                    "index": { "type": "integer", "minimum": 1, "description": "Which example to return (default: all)" }
+                },
+                "required": ["component"]
+            }
+        },
+        {
+            "name": "daisyui_list_modifiers",
+            "description": "List a component's modifier classes (btn-sm, btn-outline, btn-primary, ...) grouped by size, color and style.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "component": { "type": "string" }
+                },
+                "required": ["component"]
+            }
+        },
+        {
+            "name": "daisyui_used_by",
+            "description": "List the components whose docs mention a component, e.g. drawer for menu.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "component": { "type": "string" }
+                },
+                "required": ["component"]
+            }
+        },
+        {
+            "name": "daisyui_component_playground",
+            "description": "Full HTML page showing one component in every color × size, once per style variant, with each cell labeled by its class string. Large matrices are capped; components without a class-name list show their first example.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "component": { "type": "string" }
+                },
+                "required": ["component"]
+            }
+        },
+        {
+            "name": "daisyui_reverse_compose",
+            "description": "Recognize the sections of an existing page (navbar, hero, logo cloud, features, testimonials, pricing, stats, sidebar, footer) and return a compose spec with per-section confidence and evidence.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "html": { "type": "string", "description": "Page markup to analyze" },
+                    "path": { "type": "string", "description": "File to read the markup from, used when 'html' is absent" }
+                }
+            }
+        },
+        {
+            "name": "daisyui_badges",
+            "description": "Generate status badges (semantic states like active/pending/failed/archived mapped to colors), removable tag chips, or a tag-input field with suggestions and its script.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "kind": { "type": "string", "enum": ["status", "tags", "tag-input"], "description": "Default: status" },
+                    "states": { "type": "array", "items": { "type": "string" } },
+                    "colors": { "type": "object", "description": "State to badge color overrides, e.g. {\"pending\": \"info\"}" },
+                    "dot": { "type": "boolean", "description": "Soft badges with a status dot" },
+                    "tags": { "type": "array", "items": { "type": "string" } },
+                    "suggestions": { "type": "array", "items": { "type": "string" } },
+                    "id": { "type": "string", "description": "Tag-input element id (default: generated, e.g. dd-tags-1)" },
+                    "id_prefix": { "type": "string", "description": "Namespace for generated element ids (default: dd, giving dd-<kind>-<n>)" }
+                }
+            }
+        },
+        {
+            "name": "daisyui_compose_block",
+            "description": "Render a data-driven marketing block (testimonials, logo-cloud, pricing-tiers) from structured items. All text is HTML-escaped.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "block": { "type": "string", "enum": ["testimonials", "logo-cloud", "pricing-tiers"] },
+                    "items": {
+                        "type": "array",
+                        "description": "testimonials: {quote, name, role, avatar}; logo-cloud: names or {name, image}; pricing-tiers: {name, price, period, features[], highlighted}. Omit for defaults."
+                    },
+                    "footprint": { "type": "boolean", "description": "Append a table of daisyUI components used, DOM node count, images, JavaScript needs and CDN resources" }
+                },
+                "required": ["block"]
+            }
+        },
+        {
+            "name": "daisyui_avatar",
+            "description": "Generate a daisyUI avatar (image or initials placeholder, with optional online/offline indicator) or an avatar group with a +N overflow.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "source": { "type": "string", "description": "Image URL or a name to render as initials" },
+                    "group": { "type": "array", "items": { "type": "string" }, "description": "Sources for an avatar group (overrides 'source')" },
+                    "max": { "type": "integer", "description": "Avatars shown in a group before '+N' (default: 3)" },
+                    "size": { "type": "string", "enum": ["xs", "sm", "md", "lg", "xl"] },
+                    "shape": { "type": "string", "enum": ["circle", "rounded", "squircle", "hexagon"] },
+                    "presence": { "type": "string", "enum": ["online", "offline"] }
+                }
+            }
+        },
+        {
+            "name": "daisyui_create_loading",
+            "description": "Generate loading states: spinners (spinner, dots, ring, ball, bars, infinity), progress bars, radial progress, a loading button, a card with a centered loader, or a full-page overlay.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "kind": { "type": "string", "enum": ["spinner", "dots", "ring", "ball", "bars", "infinity", "progress", "radial", "button", "card", "overlay"] },
+                    "size": { "type": "string", "enum": ["xs", "sm", "md", "lg", "xl"] },
+                    "color": { "type": "string", "enum": ["neutral", "primary", "secondary", "accent", "info", "success", "warning", "error"] },
+                    "value": { "type": "integer", "description": "0-100. Makes progress determinate and sets the radial fill" }
+                },
+                "required": ["kind"]
+            }
+        },
+        {
+            "name": "daisyui_keyboard",
+            "description": "Render a keyboard shortcut as <kbd> elements, or generate a command-palette modal with shortcut hints.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "kind": { "type": "string", "enum": ["kbd", "palette"] },
+                    "shortcut": { "type": "string", "description": "e.g. cmd+shift+p, ctrl+k, mod+enter" },
+                    "platform": { "type": "string", "enum": ["mac", "pc"] },
+                    "size": { "type": "string", "enum": ["xs", "sm", "md", "lg", "xl"] },
+                    "commands": { "type": "array", "description": "Palette entries: { group, label, shortcut }" },
+                    "id": { "type": "string", "pattern": "^[A-Za-z0-9_-]+$", "description": "Palette dialog id: letters, digits, - and _ (default: generated, e.g. dd-palette-1)" },
+                    "id_prefix": { "type": "string", "description": "Namespace for generated element ids (default: dd, giving dd-<kind>-<n>)" }
+                }
+            }
+        },
+        {
+            "name": "daisyui_reproduce",
+            "description": "Regenerate output from a provenance comment (from provenance: true). Errors if this version of daisy-days can't reproduce it.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "comment": { "type": "string", "description": "The <!-- daisy-days ... --> line, or the whole stamped page" }
+                },
+                "required": ["comment"]
+            }
+        },
+        { "name": "daisyui_manifest", "description": "Everything daisy-days offers as one JSON document: tools, layouts with tags and sections, concepts, components with class-name categories, themes, option values, scripts, blocks and the crate version.", "inputSchema": { "type": "object", "properties": {} } },
+        {
+            "name": "daisyui_preview_file",
+            "description": "Build an offline preview page with inlined CSS for the classes used. Returns a data: URL or writes a temp file.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "html": { "type": "string", "description": "Markup to preview; takes precedence over layout" },
+                    "layout": { "type": "string", "description": "Layout to generate when no html is given" },
+                    "title": { "type": "string" },
+                    "breakpoint": { "type": "string", "enum": ["base", "sm", "md", "lg", "xl", "2xl"], "description": "Render the arrangement for this screen size regardless of window width; base is below sm" },
+                    "theme": { "type": "string", "description": "daisyUI theme for the page: a built-in theme or one generated in this session" },
+                    "output": { "type": "string", "enum": ["data_url", "file"] }
+                }
+            }
+        }
+    ])
+}
+
+/// Everything the server offers in one document, built from the registries
+/// behind the individual tools. Bodies and snippets are left out.
+fn manifest(docs: &DocsCache, concepts: &ConceptEngine) -> Value {
+    let tools = tool_definitions();
+    let tools = tools.as_array().map(Vec::as_slice).unwrap_or_default();
+    let mut options = Vec::new();
+    for tool in tools {
+        if let Some(props) = tool["inputSchema"]["properties"].as_object() {
+            for (option, schema) in props {
+                if let Some(values) = schema.get("enum") {
+                    options
+                        .push(json!({ "tool": tool["name"], "option": option, "values": values }));
+                }
+            }
+        }
+    }
+    let layouts: Vec<Value> = LayoutEngine::LAYOUTS
+        .iter()
+        .map(|layout| {
+            let tags = idea::KEYWORDS
+                .iter()
+                .find(|(name, _)| name == layout)
+                .map_or_else(Vec::new, |(_, tags)| tags.iter().map(|(k, _)| *k).collect());
+            let page = LayoutEngine::build(
+                layout,
+                "Manifest",
+                &Variant::default(),
+                &mut IdAllocator::default(),
+            );
+            json!({
+                "name": layout,
+                "source": "builtin",
+                "tags": tags,
+                "sections": page.names(),
+            })
+        })
+        .chain(templates().layouts.iter().map(|t| {
+            json!({
+                "name": t.key,
+                "source": t.source,
+                "tags": t.tags,
+                "sections": t.sections,
+            })
+        }))
+        .collect();
+    let concepts: Vec<Value> = concepts
+        .list_concepts()
+        .iter()
+        .filter_map(|key| concepts.find(key).map(|(_, c, _)| (key, c)))
+        .map(|(key, c)| json!({ "name": key, "title": c.name, "description": c.description, "category": c.category }))
+        .collect();
+    let components: Vec<Value> = docs
+        .list_components()
+        .iter()
+        .map(|name| {
+            let categories: Vec<String> = docs
+                .get_doc(name)
+                .map(|doc| {
+                    docs::class_names(&doc)
+                        .into_iter()
+                        .map(|(k, _)| k)
+                        .collect()
+                })
+                .unwrap_or_default();
+            json!({ "name": name, "categories": categories })
+        })
+        .collect();
+    json!({
+        "name": "daisy_days",
+        "version": env!("CARGO_PKG_VERSION"),
+        "tools": tools
+            .iter()
+            .map(|t| json!({ "name": t["name"], "description": t["description"] }))
+            .collect::<Vec<_>>(),
+        "layouts": layouts,
+        "concepts": concepts,
+        "components": components,
+        "themes": theme::BUILT_IN,
+        "chart_palette_themes": palette::theme_names(),
+        "options": options,
+        "scripts": SCRIPTS,
+        "blocks": snippets::BLOCKS,
+    })
+}
+
 fn handle_request(
     req: JsonRpcRequest,
     docs: Arc<DocsCache>,
     concepts: Arc<ConceptEngine>,
+    session: Arc<Session>,
 ) -> JsonRpcResponse {
     let id = req.id.clone();
 
@@ -983,40 +3005,62 @@ fn handle_request(
             "protocolVersion": "2024-11-05",
             "serverInfo": { "name": "daisy-days", "version": "1.1.0" },
             "capabilities": {
-                "tools": {}
+                "tools": {},
+                "resources": {}
             }
         })),
-        "notifications/initialized" => Ok(json!("OK")),
-        "tools/list" => Ok(json!({
-            "tools": [
-                { "name": "daisyui_idea_to_ui", "description": "Turn a prompt into a stunning UI.", "inputSchema": { "type": "object", "properties": { "prompt": { "type": "string" } }, "required": ["prompt"] } },
-                {
-                    "name": "daisyui_scaffold_layout",
-                    "description": "Generate a modern web layout skeleton.",
-                    "inputSchema": {
-                        "type": "object",
-                        "properties": {
-                            "layout": { "type": "string", "enum": ["saas", "blog", "social", "kanban", "inbox", "profile", "docs", "dashboard", "auth"], "description": "Layout type" },
-                            "title": { "type": "string" }
-                        },
-                        "required": ["layout"]
-                    }
-                },
-                { "name": "daisyui_list_components", "description": "List components.", "inputSchema": { "type": "object", "properties": {} } },
-                { "name": "daisyui_get_docs", "description": "Get docs.", "inputSchema": { "type": "object", "properties": { "component": { "type": "string" } }, "required": ["component"] } },
-                { "name": "daisyui_search", "description": "Search docs.", "inputSchema": { "type": "object", "properties": { "query": { "type": "string" } } } },
-                { "name": "daisyui_get_concept", "description": "Get concept.", "inputSchema": { "type": "object", "properties": { "concept": { "type": "string" } } } },
-                { "name": "daisyui_list_concepts", "description": "List concepts.", "inputSchema": { "type": "object", "properties": {} } },
-                { "name": "daisyui_scaffold_dashboard", "description": "Generate Dashboard (Legacy).", "inputSchema": { "type": "object", "properties": { "title": { "type": "string" }, "style": { "type": "string" } } } },
-                { "name": "daisyui_scaffold_auth", "description": "Generate Auth (Legacy).", "inputSchema": { "type": "object", "properties": { "type": { "type": "string" } } } },
-                { "name": "daisyui_scaffold_store", "description": "Generate Store (Legacy).", "inputSchema": { "type": "object", "properties": { "page": { "type": "string" } } } },
-                { "name": "daisyui_create_chart", "description": "Generate Chart.", "inputSchema": { "type": "object", "properties": { "type": { "type": "string" }, "id": { "type": "string" } } } },
-                { "name": "daisyui_create_table", "description": "Generate Table.", "inputSchema": { "type": "object", "properties": { "columns": { "type": "array" } } } },
-                { "name": "daisyui_generate_theme", "description": "Generate Theme.", "inputSchema": { "type": "object", "properties": { "name": { "type": "string" }, "primary": { "type": "string" }, "base": { "type": "string" } } } },
-                { "name": "daisyui_scaffold_form", "description": "Generate Form.", "inputSchema": { "type": "object", "properties": { "title": { "type": "string" }, "fields": { "type": "array" } } } },
-                { "name": "daisyui_get_script", "description": "Get Script.", "inputSchema": { "type": "object", "properties": { "component": { "type": "string" } } } }
-            ]
+        "resources/list" => Ok(json!({
+            "resources": docs
+                .list_components()
+                .iter()
+                .filter(|c| !docs.get_examples(c).is_empty())
+                .map(|c| json!({
+                    "uri": format!("daisyui://examples/{}", c),
+                    "name": format!("{} examples", c),
+                    "mimeType": "text/markdown"
+                }))
+                .collect::<Vec<_>>()
         })),
+        "resources/templates/list" => Ok(json!({
+            "resourceTemplates": [{
+                "uriTemplate": "daisyui://examples/{component}",
+                "name": "Component examples",
+                "description": "Every code example for a daisyUI component, captioned",
+                "mimeType": "text/markdown"
+            }]
+        })),
+        "resources/read" => {
+            let uri = req
+                .params
+                .as_ref()
+                .and_then(|p| p.get("uri"))
+                .and_then(|v| v.as_str())
+                .unwrap_or("");
+            let examples = uri
+                .strip_prefix("daisyui://examples/")
+                .map(|c| (c, docs.get_examples(c)))
+                .filter(|(_, ex)| !ex.is_empty());
+            match examples {
+                Some((component, examples)) => Ok(json!({
+                    "contents": [{
+                        "uri": uri,
+                        "mimeType": "text/markdown",
+                        "text": docs::sanitize_markdown(
+                            &docs::examples_markdown(component, examples, None),
+                            docs::Sanitize::Strict
+                        )
+                    }]
+                })),
+                None => Err(JsonRpcError {
+                    code: -32602,
+                    message: format!("Unknown resource: {}", uri),
+                    data: None,
+                }),
+            }
+        }
+        "notifications/initialized" => Ok(json!("OK")),
+        "shutdown" => Ok(Value::Null),
+        "tools/list" => Ok(json!({ "tools": tool_definitions() })),
         "tools/call" => {
             if let Some(params) = req.params {
                 let name = params["name"].as_str().unwrap_or("");
@@ -1028,62 +3072,670 @@ fn handle_request(
                             .and_then(|a| a.get("prompt"))
                             .and_then(|v| v.as_str())
                             .unwrap_or("");
-                        let html = IdeaEngine::process_prompt(prompt);
+                        let mut html = IdeaEngine::process_prompt(prompt);
+                        if want_provenance(args) {
+                            html = provenance::stamp(
+                                &html,
+                                "daisyui_idea_to_ui",
+                                &json!({ "prompt": prompt }),
+                            );
+                        }
                         Ok(json!({ "content": [{ "type": "text", "text": html }] }))
                     }
-                    "daisyui_scaffold_layout" => {
-                        let layout = args
-                            .and_then(|a| a.get("layout"))
+                    "daisyui_explain_idea" => {
+                        let prompt = args
+                            .and_then(|a| a.get("prompt"))
                             .and_then(|v| v.as_str())
-                            .unwrap_or("saas");
-                        let title = args
-                            .and_then(|a| a.get("title"))
+                            .unwrap_or("");
+                        let text = idea::explain(prompt, &templates().keywords()).markdown();
+                        Ok(json!({ "content": [{ "type": "text", "text": text }] }))
+                    }
+                    "daisyui_scaffold_layout" => {
+                        let opts = LayoutOptions::from_args(args);
+                        let want_diff = args
+                            .and_then(|a| a.get("diff"))
+                            .and_then(|v| v.as_bool())
+                            .unwrap_or(false);
+                        // Applying to no markup checks the concept on its own.
+                        let concept = opts
+                            .concept
+                            .as_deref()
+                            .map(|c| concepts.apply(c, ""))
+                            .transpose();
+                        let theme = opts
+                            .theme
+                            .as_deref()
+                            .map(|t| theme::check_known(t, &session.themes()))
+                            .transpose();
+                        let bad_chart = opts
+                            .charts
+                            .iter()
+                            .flatten()
+                            .find(|c| !CHART_TYPES.contains(&c.as_str()));
+                        if let Some(chart) = bad_chart {
+                            Err(JsonRpcError {
+                                code: -32602,
+                                message: format!(
+                                    "Unknown chart type '{}'; use one of: {}",
+                                    chart,
+                                    CHART_TYPES.join(", ")
+                                ),
+                                data: None,
+                            })
+                        } else if !LAYOUT_TARGETS.contains(&opts.target.as_str()) {
+                            Err(JsonRpcError {
+                                code: -32602,
+                                message: format!(
+                                    "Unknown target '{}'; use one of: {}",
+                                    opts.target,
+                                    LAYOUT_TARGETS.join(", ")
+                                ),
+                                data: None,
+                            })
+                        } else if tidy::Style::parse(&opts.format).is_none() {
+                            Err(JsonRpcError {
+                                code: -32602,
+                                message: format!(
+                                    "Unknown format '{}'; use one of: {}",
+                                    opts.format,
+                                    tidy::Style::NAMES.join(", ")
+                                ),
+                                data: None,
+                            })
+                        } else if !images::STRATEGIES.contains(&opts.placeholders.as_str()) {
+                            Err(JsonRpcError {
+                                code: -32602,
+                                message: format!(
+                                    "Unknown placeholders '{}'; use one of: {}",
+                                    opts.placeholders,
+                                    images::STRATEGIES.join(", ")
+                                ),
+                                data: None,
+                            })
+                        } else if !rtl::DIRECTIONS.contains(&opts.direction.as_str()) {
+                            Err(JsonRpcError {
+                                code: -32602,
+                                message: format!(
+                                    "Unknown direction '{}'; use one of: {}",
+                                    opts.direction,
+                                    rtl::DIRECTIONS.join(", ")
+                                ),
+                                data: None,
+                            })
+                        } else if opts.full_document && opts.target != "html" {
+                            Err(JsonRpcError {
+                                code: -32602,
+                                message:
+                                    "full_document builds an HTML page, so it needs target html"
+                                        .into(),
+                                data: None,
+                            })
+                        } else if !purity::MODES.contains(&opts.purity.as_str()) {
+                            Err(JsonRpcError {
+                                code: -32602,
+                                message: format!(
+                                    "Invalid purity '{}'. Use one of: {}",
+                                    opts.purity,
+                                    purity::MODES.join(", ")
+                                ),
+                                data: None,
+                            })
+                        } else if let Err(message) = concept.and(theme) {
+                            Err(JsonRpcError {
+                                code: -32602,
+                                message,
+                                data: None,
+                            })
+                        } else {
+                            let mut content = Vec::new();
+                            if Variant::parse(&opts.variant).is_none() {
+                                content.push(json!({ "type": "text", "text": format!(
+                                    "Unknown variant '{}', so comfortable was used. Use one of: {}.",
+                                    opts.variant,
+                                    variant::NAMES.join(", ")
+                                ) }));
+                            }
+                            for note in opts.counts().1 {
+                                content.push(json!({ "type": "text", "text": note }));
+                            }
+                            match session.last() {
+                                Some(prev) if want_diff => content.push(
+                                    json!({ "type": "text", "text": layout_diff(&prev, &opts, &concepts) }),
+                                ),
+                                _ => {
+                                    let (html, notes) = opts.render_with_notes(&concepts);
+                                    for note in notes {
+                                        content.push(json!({ "type": "text", "text": note }));
+                                    }
+                                    let text = if want_provenance(args) {
+                                        provenance::stamp(
+                                            &html,
+                                            "daisyui_scaffold_layout",
+                                            &opts.canonical(),
+                                        )
+                                    } else {
+                                        html.clone()
+                                    };
+                                    content.push(json!({ "type": "text", "text": text }));
+                                    if opts.purity == "daisy-only" {
+                                        let report = purity::report(&opts.layout, &html);
+                                        content.push(json!({ "type": "text", "text": report }));
+                                    }
+                                    if want_footprint(args) {
+                                        let table = footprint::analyze(&html).markdown();
+                                        content.push(json!({ "type": "text", "text": table }));
+                                    }
+                                }
+                            }
+                            session.remember(&opts);
+                            Ok(json!({ "content": content }))
+                        }
+                    }
+                    "daisyui_layout_diff" => {
+                        let object =
+                            |key: &str| args.and_then(|a| a.get(key)).and_then(|v| v.as_object());
+                        let from = match object("from") {
+                            Some(f) => Some(LayoutOptions::from_args(Some(f))),
+                            None => session.last(),
+                        };
+                        match from {
+                            Some(from) => {
+                                let to = LayoutOptions::merged(&from, object("to"));
+                                session.remember(&to);
+                                Ok(
+                                    json!({ "content": [{ "type": "text", "text": layout_diff(&from, &to, &concepts) }] }),
+                                )
+                            }
+                            None => Err(JsonRpcError {
+                                code: -32602,
+                                message: "No previous layout in this session; pass 'from'".into(),
+                                data: None,
+                            }),
+                        }
+                    }
+                    "daisyui_random_component" => {
+                        let category = args
+                            .and_then(|a| a.get("category"))
                             .and_then(|v| v.as_str())
-                            .unwrap_or("My App");
-                        Ok(
-                            json!({ "content": [{ "type": "text", "text": LayoutEngine::generate(layout, title) }] }),
+                            .unwrap_or("");
+                        let seed = args
+                            .and_then(|a| a.get("seed"))
+                            .and_then(|v| v.as_u64())
+                            .unwrap_or_else(|| session.draws.fetch_add(1, Ordering::Relaxed));
+                        let names = docs.list_components();
+                        match docs::random_component(
+                            names.iter().map(String::as_str),
+                            category,
+                            seed,
                         )
+                        .and_then(|name| docs.find_doc(name))
+                        {
+                            Ok((_, doc, _)) => {
+                                let text = docs::sanitize_markdown(&doc, docs::Sanitize::Strict);
+                                Ok(json!({ "content": [{ "type": "text", "text": text }] }))
+                            }
+                            Err(message) => Err(JsonRpcError {
+                                code: -32602,
+                                message,
+                                data: None,
+                            }),
+                        }
+                    }
+                    "daisyui_cheatsheet" => {
+                        let filter = args
+                            .and_then(|a| a.get("filter"))
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("");
+                        match docs::cheatsheet(&docs.summaries, filter) {
+                            Ok(text) => {
+                                Ok(json!({ "content": [{ "type": "text", "text": text }] }))
+                            }
+                            Err(message) => Err(JsonRpcError {
+                                code: -32602,
+                                message,
+                                data: None,
+                            }),
+                        }
+                    }
+                    "daisyui_list_components" => {
+                        let list = docs.list_components().join(", ");
+                        let text = match &docs.diagnostic {
+                            Some(diagnostic) => format!("Warning: {}\n\n{}", diagnostic, list),
+                            None => list,
+                        };
+                        Ok(json!({ "content": [{ "type": "text", "text": text }] }))
                     }
-                    "daisyui_list_components" => Ok(
-                        json!({ "content": [{ "type": "text", "text": docs.list_components().join(", ") }] }),
-                    ),
                     "daisyui_get_docs" => {
                         let c = args
                             .and_then(|a| a.get("component"))
                             .and_then(|v| v.as_str())
                             .unwrap_or("");
-                        Ok(
-                            json!({ "content": [{ "type": "text", "text": docs.get_doc(c).unwrap_or("Not found".to_string()) }] }),
-                        )
+                        let mode = match args
+                            .and_then(|a| a.get("sanitize"))
+                            .and_then(|v| v.as_str())
+                        {
+                            Some("lenient") => docs::Sanitize::Lenient,
+                            _ => docs::Sanitize::Strict,
+                        };
+                        let max = args
+                            .and_then(|a| a.get("max_chars"))
+                            .and_then(|v| v.as_u64());
+                        let render = |(_, doc, note): (String, String, Option<String>)| {
+                            let mut text = docs::sanitize_markdown(&doc, mode);
+                            if let Some(max) = max {
+                                text = docs::truncate(
+                                    &text,
+                                    max as usize,
+                                    "call again without max_chars to see everything",
+                                );
+                            }
+                            if let Some(note) = note {
+                                text = format!("> {}\n\n{}", note, text);
+                            }
+                            json!({ "type": "text", "text": text })
+                        };
+                        // A list gives one content item per doc; unknown
+                        // names are listed last rather than failing the call.
+                        let mut content = Vec::new();
+                        let mut missing = Vec::new();
+                        let list = c.contains(',');
+                        let names: Vec<&str> = if list {
+                            c.split(',')
+                                .map(str::trim)
+                                .filter(|n| !n.is_empty())
+                                .collect()
+                        } else {
+                            vec![c]
+                        };
+                        for name in names {
+                            match docs.find_section(name) {
+                                Ok(found) => content.push(render(found)),
+                                Err(_) if list => missing.push(name.to_string()),
+                                Err(message) => missing.push(message),
+                            }
+                        }
+                        if content.is_empty() {
+                            Err(JsonRpcError {
+                                code: -32602,
+                                message: if list {
+                                    format!("Documentation not found for {}", missing.join(", "))
+                                } else {
+                                    missing.concat()
+                                },
+                                data: None,
+                            })
+                        } else {
+                            if !missing.is_empty() {
+                                content.push(json!({ "type": "text", "text": format!("> Not found: {}", missing.join(", ")) }));
+                            }
+                            Ok(json!({ "content": content }))
+                        }
+                    }
+                    "daisyui_which_component" => {
+                        let class = args
+                            .and_then(|a| a.get("class"))
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("");
+                        match docs.describe_class(class, docs::Sanitize::Strict) {
+                            Ok((_, text)) => {
+                                Ok(json!({ "content": [{ "type": "text", "text": text }] }))
+                            }
+                            Err(message) => Err(JsonRpcError {
+                                code: -32602,
+                                message,
+                                data: None,
+                            }),
+                        }
                     }
                     "daisyui_search" => {
                         let q = args
                             .and_then(|a| a.get("query"))
                             .and_then(|v| v.as_str())
                             .unwrap_or("");
-                        Ok(
-                            json!({ "content": [{ "type": "text", "text": format!("Found {}", docs.search(q).len()) }] }),
-                        )
+                        let number = |key: &str| {
+                            args.and_then(|a| a.get(key))
+                                .and_then(|v| v.as_u64())
+                                .map(|n| n as usize)
+                        };
+                        let limit = number("limit").unwrap_or(index::PAGE_SIZE);
+                        let offset = number("offset").unwrap_or(0);
+                        let format = args
+                            .and_then(|a| a.get("format"))
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("markdown");
+                        let (results, total) = docs.search(q, limit, offset);
+                        let mut status = format!(
+                            "Found {}. {}",
+                            total,
+                            index::showing(offset, results.len(), total)
+                        );
+                        if let Some(note) = docs.alias_note(q) {
+                            status = format!("{}. {}", status, note);
+                        }
+                        match format {
+                            _ if limit == 0 => Err(JsonRpcError {
+                                code: -32602,
+                                message: "limit must be 1 or more, got 0".to_string(),
+                                data: None,
+                            }),
+                            "json" => {
+                                let results: Vec<SearchResult> = results
+                                    .into_iter()
+                                    .map(|(name, snippet, score)| {
+                                        let chars = |at: usize| snippet.text[..at].chars().count();
+                                        SearchResult {
+                                            name,
+                                            score,
+                                            matches: snippet
+                                                .matches
+                                                .iter()
+                                                .map(|&(start, end)| [chars(start), chars(end)])
+                                                .collect(),
+                                            snippet: snippet.text,
+                                        }
+                                    })
+                                    .collect();
+                                let array = serde_json::to_string(&results).unwrap_or_default();
+                                Ok(json!({ "content": [
+                                    { "type": "text", "text": array },
+                                    { "type": "text", "text": status }
+                                ] }))
+                            }
+                            "markdown" => {
+                                let text = if total == 0 {
+                                    format!("No results found for '{}'", q)
+                                } else {
+                                    let lines: Vec<String> = results
+                                        .iter()
+                                        .map(|(name, snippet, score)| {
+                                            format!(
+                                                "- **{}** (score: {})\n  {}",
+                                                name,
+                                                score,
+                                                snippet.markdown()
+                                            )
+                                        })
+                                        .collect();
+                                    format!("{}\n\n{}", status, lines.join("\n"))
+                                        .trim_end()
+                                        .to_string()
+                                };
+                                Ok(json!({ "content": [{ "type": "text", "text": text }] }))
+                            }
+                            other => Err(JsonRpcError {
+                                code: -32602,
+                                message: format!(
+                                    "Unknown format '{}'; use markdown or json",
+                                    other
+                                ),
+                                data: None,
+                            }),
+                        }
                     }
                     "daisyui_get_concept" => {
                         let c = args
                             .and_then(|a| a.get("concept"))
                             .and_then(|v| v.as_str())
                             .unwrap_or("");
-                        Ok(
-                            json!({ "content": [{ "type": "text", "text": format!("{:?}", concepts.get_concept(c)) }] }),
-                        )
+                        let format = args
+                            .and_then(|a| a.get("format"))
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("markdown");
+                        let target = args
+                            .and_then(|a| a.get("target"))
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("html");
+                        let example = args.and_then(|a| a.get("example")).and_then(|v| v.as_str());
+                        match (concepts.find(c), format, frameworks::Target::parse(target)) {
+                            (_, _, None) => Err(JsonRpcError {
+                                code: -32602,
+                                message: format!(
+                                    "Unknown target '{}'; use {}",
+                                    target,
+                                    frameworks::Target::names().join(", ")
+                                ),
+                                data: None,
+                            }),
+                            (Some((key, concept, _)), _, _)
+                                if example.is_some_and(|e| concept.example(e).is_none()) =>
+                            {
+                                Err(JsonRpcError {
+                                    code: -32602,
+                                    message: format!(
+                                        "'{}' has no example matching '{}'. Examples: {}",
+                                        key,
+                                        example.unwrap_or_default(),
+                                        concept.example_titles().join(", ")
+                                    ),
+                                    data: None,
+                                })
+                            }
+                            (Some((key, concept, note)), "json", Some(target)) => {
+                                let index = example.and_then(|e| concept.example(e)).unwrap_or(0);
+                                let examples: Vec<Value> = (0..concept.examples.len())
+                                    .map(|i| {
+                                        json!({
+                                            "title": concept.examples[i].0,
+                                            "code": concept.example_for(i, target),
+                                        })
+                                    })
+                                    .collect();
+                                let mut object = json!({
+                                    "key": key,
+                                    "name": concept.name,
+                                    "description": concept.description,
+                                    "classes": concept.classes,
+                                    "suggestion": concept.suggestion,
+                                    "category": concept.category,
+                                    "snippet": concept.example_for(index, target),
+                                    "example": concept.examples[index].0,
+                                    "examples": examples,
+                                    "target": target.name(),
+                                });
+                                if let Some(script) = &concept.script {
+                                    object["script"] = json!(script);
+                                }
+                                if let Some(note) = note {
+                                    object["note"] = json!(note);
+                                }
+                                let text = serde_json::to_string(&object).unwrap_or_default();
+                                Ok(json!({ "content": [{ "type": "text", "text": text }] }))
+                            }
+                            (Some((_, concept, note)), "markdown", Some(target)) => {
+                                let mut text = match example.and_then(|e| concept.example(e)) {
+                                    Some(index) => concept.to_display_example(target, index),
+                                    None => concept.to_display(target),
+                                };
+                                if let Some(note) = note {
+                                    text = format!("> {}\n\n{}", note, text);
+                                }
+                                Ok(json!({ "content": [{ "type": "text", "text": text }] }))
+                            }
+                            (Some(_), other, _) => Err(JsonRpcError {
+                                code: -32602,
+                                message: format!(
+                                    "Unknown format '{}'; use markdown or json",
+                                    other
+                                ),
+                                data: None,
+                            }),
+                            (None, _, _) => Err(JsonRpcError {
+                                code: -32602,
+                                message: format!(
+                                    "Concept '{}' not found. Available: {}",
+                                    c,
+                                    concepts.list_concepts().join(", ")
+                                ),
+                                data: None,
+                            }),
+                        }
+                    }
+                    "daisyui_a11y_hints" => {
+                        let html = args
+                            .and_then(|a| a.get("html"))
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("");
+                        let text = concepts::audit_markdown(&concepts.audit_hint(html));
+                        Ok(json!({ "content": [{ "type": "text", "text": text }] }))
+                    }
+                    "daisyui_compare_concepts" => {
+                        let arg = |key: &str| {
+                            args.and_then(|a| a.get(key))
+                                .and_then(|v| v.as_str())
+                                .unwrap_or("")
+                        };
+                        match concepts.compare(arg("first"), arg("second")) {
+                            Ok(comparison) => {
+                                let mut text = comparison.header();
+                                for snippet in comparison.snippets() {
+                                    text.push_str(&format!("\n{}\n", snippet));
+                                }
+                                Ok(json!({ "content": [{ "type": "text", "text": text }] }))
+                            }
+                            Err(e) => Err(JsonRpcError {
+                                code: -32602,
+                                message: e,
+                                data: None,
+                            }),
+                        }
+                    }
+                    "daisyui_style_recipe" => {
+                        let seed = args
+                            .and_then(|a| a.get("seed"))
+                            .and_then(|v| v.as_u64())
+                            .unwrap_or_else(|| session.draws.fetch_add(1, Ordering::Relaxed));
+                        let include: Vec<&str> = args
+                            .and_then(|a| a.get("include"))
+                            .and_then(|v| v.as_array())
+                            .map(|a| a.iter().filter_map(|v| v.as_str()).collect())
+                            .unwrap_or_default();
+                        match concepts.recipe(seed, &include) {
+                            Ok(recipe) => {
+                                let mut text = format!(
+                                    "{}\n### Example\n\n```html\n{}\n```\n",
+                                    recipe.header(),
+                                    recipe.snippet()
+                                );
+                                for script in recipe.scripts() {
+                                    text.push_str(&format!("\n```js\n{}\n```\n", script));
+                                }
+                                Ok(json!({ "content": [{ "type": "text", "text": text }] }))
+                            }
+                            Err(e) => Err(JsonRpcError {
+                                code: -32602,
+                                message: e,
+                                data: None,
+                            }),
+                        }
+                    }
+                    "daisyui_concept_theme" => {
+                        let arg =
+                            |key: &str| args.and_then(|a| a.get(key)).and_then(|v| v.as_str());
+                        let c = arg("concept").unwrap_or("");
+                        match concepts.find(c) {
+                            Some((key, concept, note)) => {
+                                let name = arg("name").unwrap_or(key);
+                                match theme::check_name(name) {
+                                    Ok(()) => {
+                                        session.remember_theme(name);
+                                        let mut css = concept.to_theme(name);
+                                        if let Some(note) = note {
+                                            css = format!("/* {} */\n{}", note, css);
+                                        }
+                                        Ok(json!({ "content": [{ "type": "text", "text": css }] }))
+                                    }
+                                    Err(e) => Err(JsonRpcError {
+                                        code: -32602,
+                                        message: e,
+                                        data: None,
+                                    }),
+                                }
+                            }
+                            None => Err(JsonRpcError {
+                                code: -32602,
+                                message: format!(
+                                    "Concept '{}' not found. Available: {}",
+                                    c,
+                                    concepts.list_concepts().join(", ")
+                                ),
+                                data: None,
+                            }),
+                        }
+                    }
+                    "daisyui_reproduce" => {
+                        let comment = args
+                            .and_then(|a| a.get("comment"))
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("");
+                        match provenance::parse(comment).and_then(|stamp| {
+                            stamp.check()?;
+                            Ok(stamp)
+                        }) {
+                            Ok(stamp) => {
+                                let mut arguments = stamp.options;
+                                arguments["provenance"] = json!(true);
+                                let call = JsonRpcRequest {
+                                    jsonrpc: "2.0".into(),
+                                    method: "tools/call".into(),
+                                    params: Some(
+                                        json!({ "name": stamp.tool, "arguments": arguments }),
+                                    ),
+                                    id: None,
+                                };
+                                let response = handle_request(
+                                    call,
+                                    docs.clone(),
+                                    concepts.clone(),
+                                    session.clone(),
+                                );
+                                match (response.result, response.error) {
+                                    (Some(result), _) => Ok(result),
+                                    (None, Some(error)) => Err(error),
+                                    (None, None) => Ok(json!({ "content": [] })),
+                                }
+                            }
+                            Err(message) => Err(JsonRpcError {
+                                code: -32602,
+                                message,
+                                data: None,
+                            }),
+                        }
+                    }
+                    "daisyui_manifest" => {
+                        let text =
+                            serde_json::to_string(&manifest(&docs, &concepts)).unwrap_or_default();
+                        Ok(json!({ "content": [{ "type": "text", "text": text }] }))
+                    }
+                    "daisyui_list_concepts" => {
+                        let category = args
+                            .and_then(|a| a.get("category"))
+                            .and_then(|v| v.as_str());
+                        match concepts.by_category(category) {
+                            Ok(groups) => {
+                                let mut text = String::from("## Design Concepts\n");
+                                for (category, members) in &groups {
+                                    text.push('\n');
+                                    text.push_str(&concepts::category_markdown(category, members));
+                                }
+                                if groups.is_empty() {
+                                    text.push_str("\nNo concepts in this category yet.\n");
+                                }
+                                Ok(json!({ "content": [{ "type": "text", "text": text }] }))
+                            }
+                            Err(message) => Err(JsonRpcError {
+                                code: -32602,
+                                message,
+                                data: None,
+                            }),
+                        }
                     }
-                    "daisyui_list_concepts" => Ok(
-                        json!({ "content": [{ "type": "text", "text": concepts.list_concepts().join(", ") }] }),
-                    ),
                     "daisyui_scaffold_dashboard" => {
                         let t = args
                             .and_then(|a| a.get("title"))
                             .and_then(|v| v.as_str())
                             .unwrap_or("Dash");
                         Ok(
-                            json!({ "content": [{ "type": "text", "text": generate_dashboard(t, &[], "") }] }),
+                            json!({ "content": [{ "type": "text", "text": generate_dashboard(t, &[], "", &mut id_allocator(args)) }] }),
                         )
                     }
                     "daisyui_scaffold_auth" => {
@@ -1108,8 +3760,24 @@ fn handle_request(
                         let id = args
                             .and_then(|a| a.get("id"))
                             .and_then(|v| v.as_str())
-                            .unwrap_or("c1");
-                        Ok(json!({ "content": [{ "type": "text", "text": create_chart(t, id) }] }))
+                            .map_or_else(|| id_allocator(args).next("chart"), String::from);
+                        let colors = args
+                            .and_then(|a| a.get("palette"))
+                            .and_then(|v| v.as_str())
+                            .map(|spec| {
+                                palette::resolve(spec).map(|theme| palette::palette(&theme, 10))
+                            })
+                            .transpose();
+                        match colors {
+                            Ok(colors) => Ok(
+                                json!({ "content": [{ "type": "text", "text": create_chart(t, &id, colors.as_deref()) }] }),
+                            ),
+                            Err(e) => Err(JsonRpcError {
+                                code: -32602,
+                                message: e,
+                                data: None,
+                            }),
+                        }
                     }
                     "daisyui_create_table" => Ok(
                         json!({ "content": [{ "type": "text", "text": create_complex_table(&[]) }] }),
@@ -1127,6 +3795,7 @@ fn handle_request(
                             .and_then(|a| a.get("base"))
                             .and_then(|v| v.as_str())
                             .unwrap_or("#fff");
+                        session.remember_theme(name);
                         Ok(
                             json!({ "content": [{ "type": "text", "text": generate_theme(name, p, "", "", b) }] }),
                         )
@@ -1136,16 +3805,362 @@ fn handle_request(
                             .and_then(|a| a.get("title"))
                             .and_then(|v| v.as_str())
                             .unwrap_or("Form");
-                        Ok(
-                            json!({ "content": [{ "type": "text", "text": scaffold_form(t, &[]) }] }),
-                        )
+                        let style = args.and_then(|a| a.get("style")).and_then(|v| v.as_str());
+                        // A bare string is a required field of that name.
+                        let mut fields: Vec<serde_json::Map<String, Value>> = args
+                            .and_then(|a| a.get("fields"))
+                            .and_then(|v| v.as_array())
+                            .map(|items| {
+                                items
+                                    .iter()
+                                    .filter_map(|item| match item {
+                                        Value::String(name) => {
+                                            json!({ "name": name }).as_object().cloned()
+                                        }
+                                        other => other.as_object().cloned(),
+                                    })
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+                        if fields.is_empty() {
+                            fields = [
+                                json!({ "name": "Name" }),
+                                json!({ "name": "Email" }),
+                                json!({ "name": "Company", "optional": true }),
+                            ]
+                            .iter()
+                            .filter_map(|f| f.as_object().cloned())
+                            .collect();
+                        }
+                        match style {
+                            Some(s) if !FORM_STYLES.contains(&s) => Err(JsonRpcError {
+                                code: -32602,
+                                message: format!(
+                                    "Unknown form style '{}'; use {}",
+                                    s,
+                                    FORM_STYLES.join(", ")
+                                ),
+                                data: None,
+                            }),
+                            _ => {
+                                let html =
+                                    scaffold_form(t, &fields, style, &mut id_allocator(args));
+                                Ok(json!({ "content": [{ "type": "text", "text": html }] }))
+                            }
+                        }
                     }
                     "daisyui_get_script" => {
                         let c = args
                             .and_then(|a| a.get("component"))
                             .and_then(|v| v.as_str())
                             .unwrap_or("");
-                        Ok(json!({ "content": [{ "type": "text", "text": get_script(c) }] }))
+                        let id = args.and_then(|a| a.get("id")).and_then(|v| v.as_str());
+                        let script = get_script(c, id, &mut id_allocator(args));
+                        Ok(json!({ "content": [{ "type": "text", "text": script }] }))
+                    }
+                    "daisyui_component_examples" => {
+                        let component = args
+                            .and_then(|a| a.get("component"))
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("");
+                        let count = args
+                            .and_then(|a| a.get("count"))
+                            .and_then(|v| v.as_u64())
+                            .map(|n| n as usize);
+                        match docs.find_doc(component) {
+                            Ok((name, _, note)) => {
+                                let examples = docs.get_examples(&name);
+                                if examples.is_empty() {
+                                    Err(JsonRpcError {
+                                        code: -32602,
+                                        message: format!("No examples found for '{}'", name),
+                                        data: None,
+                                    })
+                                } else {
+                                    let mut text = docs::sanitize_markdown(
+                                        &docs::examples_markdown(&name, examples, count),
+                                        docs::Sanitize::Strict,
+                                    );
+                                    if let Some(note) = note {
+                                        text = format!("> {}\n\n{}", note, text);
+                                    }
+                                    Ok(json!({ "content": [{ "type": "text", "text": text }] }))
+                                }
+                            }
+                            Err(message) => Err(JsonRpcError {
+                                code: -32602,
+                                message,
+                                data: None,
+                            }),
+                        }
+                    }
+                    "daisyui_get_example" => {
+                        let component = args
+                            .and_then(|a| a.get("component"))
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("");
+                        let index = args
+                            .and_then(|a| a.get("index"))
+                            .and_then(|v| v.as_u64())
+                            .map(|n| n as usize);
+                        match docs.find_doc(component).and_then(|(name, _, note)| {
+                            docs::pick_example(&name, docs.get_examples(&name), index)
+                                .map(|text| (text, note))
+                        }) {
+                            Ok((text, note)) => {
+                                let mut text =
+                                    docs::sanitize_markdown(&text, docs::Sanitize::Strict);
+                                if let Some(note) = note {
+                                    text = format!("> {}\n\n{}", note, text);
+                                }
+                                Ok(json!({ "content": [{ "type": "text", "text": text }] }))
+                            }
+                            Err(message) => Err(JsonRpcError {
+                                code: -32602,
+                                message,
+                                data: None,
+                            }),
+                        }
+                    }
+                    "daisyui_list_modifiers" => {
+                        let component = args
+                            .and_then(|a| a.get("component"))
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("");
+                        match docs.find_doc(component) {
+                            Ok((name, _, note)) => {
+                                let mut text =
+                                    docs::format_modifiers(&name, docs.get_modifiers(&name));
+                                if let Some(note) = note {
+                                    text = format!("> {}\n\n{}", note, text);
+                                }
+                                Ok(json!({ "content": [{ "type": "text", "text": text }] }))
+                            }
+                            Err(message) => Err(JsonRpcError {
+                                code: -32602,
+                                message,
+                                data: None,
+                            }),
+                        }
+                    }
+                    "daisyui_used_by" => {
+                        let component = args
+                            .and_then(|a| a.get("component"))
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("");
+                        match docs.find_doc(component) {
+                            Ok((name, _, note)) => {
+                                let mut text =
+                                    docs::used_by_markdown(&name, &docs.references_to(&name));
+                                if let Some(note) = note {
+                                    text = format!("> {}\n\n{}", note, text);
+                                }
+                                Ok(json!({ "content": [{ "type": "text", "text": text }] }))
+                            }
+                            Err(message) => Err(JsonRpcError {
+                                code: -32602,
+                                message,
+                                data: None,
+                            }),
+                        }
+                    }
+                    "daisyui_component_playground" => {
+                        let component = args
+                            .and_then(|a| a.get("component"))
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("");
+                        match docs.find_doc(component) {
+                            Ok((name, doc, note)) => {
+                                let playground = playground::build(&name, &doc);
+                                let mut summary = playground.summary(&name);
+                                if let Some(note) = note {
+                                    summary = format!("{} {}", note, summary);
+                                }
+                                Ok(json!({ "content": [
+                                    { "type": "text", "text": playground.html },
+                                    { "type": "text", "text": summary }
+                                ] }))
+                            }
+                            Err(message) => Err(JsonRpcError {
+                                code: -32602,
+                                message,
+                                data: None,
+                            }),
+                        }
+                    }
+                    "daisyui_reverse_compose" => {
+                        let arg =
+                            |key: &str| args.and_then(|a| a.get(key)).and_then(|v| v.as_str());
+                        let html = match (arg("html"), arg("path")) {
+                            (Some(html), _) => Ok(html.to_string()),
+                            (None, Some(path)) => std::fs::read_to_string(path)
+                                .map_err(|e| format!("Failed to read '{}': {}", path, e)),
+                            (None, None) => Err("Provide 'html' or 'path'".to_string()),
+                        };
+                        match html {
+                            Ok(html) => {
+                                let found = reverse::analyze(&html);
+                                Ok(json!({ "content": [
+                                    { "type": "text", "text": found.markdown() },
+                                    { "type": "text", "text": found.spec_json() }
+                                ] }))
+                            }
+                            Err(e) => Err(JsonRpcError {
+                                code: -32602,
+                                message: e,
+                                data: None,
+                            }),
+                        }
+                    }
+                    "daisyui_badges" => match badges_snippet(args) {
+                        Ok(html) => Ok(json!({ "content": [{ "type": "text", "text": html }] })),
+                        Err(e) => Err(JsonRpcError {
+                            code: -32602,
+                            message: e,
+                            data: None,
+                        }),
+                    },
+                    "daisyui_chart_palette" => match chart_palette(args) {
+                        Ok(text) => Ok(json!({ "content": [{ "type": "text", "text": text }] })),
+                        Err(e) => Err(JsonRpcError {
+                            code: -32602,
+                            message: e,
+                            data: None,
+                        }),
+                    },
+                    "daisyui_compose_block" => match compose_block(args) {
+                        Ok(html) if want_footprint(args) => Ok(json!({ "content": [
+                            { "type": "text", "text": html },
+                            { "type": "text", "text": footprint::analyze(&html).markdown() }
+                        ] })),
+                        Ok(html) => Ok(json!({ "content": [{ "type": "text", "text": html }] })),
+                        Err(e) => Err(JsonRpcError {
+                            code: -32602,
+                            message: e,
+                            data: None,
+                        }),
+                    },
+                    "daisyui_scaffold_section" => match scaffold_section(args) {
+                        Ok(html) => Ok(json!({ "content": [{ "type": "text", "text": html }] })),
+                        Err(e) => Err(JsonRpcError {
+                            code: -32602,
+                            message: e,
+                            data: None,
+                        }),
+                    },
+                    "daisyui_scaffold_site" => match scaffold_site(args) {
+                        Ok(pages) => Ok(json!({ "content": pages
+                            .into_iter()
+                            .map(|html| json!({ "type": "text", "text": html }))
+                            .collect::<Vec<_>>() })),
+                        Err(e) => Err(JsonRpcError {
+                            code: -32602,
+                            message: e,
+                            data: None,
+                        }),
+                    },
+                    "daisyui_compose_page" => match compose_page(args) {
+                        Ok((html, notes)) if notes.is_empty() => {
+                            Ok(json!({ "content": [{ "type": "text", "text": html }] }))
+                        }
+                        Ok((html, notes)) => Ok(json!({ "content": [
+                            { "type": "text", "text": html },
+                            { "type": "text", "text": notes.join("\n") }
+                        ] })),
+                        Err(e) => Err(JsonRpcError {
+                            code: -32602,
+                            message: e,
+                            data: None,
+                        }),
+                    },
+                    "daisyui_avatar" => match avatar_snippet(args) {
+                        Ok(html) => Ok(json!({ "content": [{ "type": "text", "text": html }] })),
+                        Err(e) => Err(JsonRpcError {
+                            code: -32602,
+                            message: e,
+                            data: None,
+                        }),
+                    },
+                    "daisyui_create_loading" => {
+                        let arg =
+                            |key: &str| args.and_then(|a| a.get(key)).and_then(|v| v.as_str());
+                        let value = args
+                            .and_then(|a| a.get("value"))
+                            .and_then(|v| v.as_u64())
+                            .map(|v| v.min(100) as u8);
+                        match snippets::loading(
+                            arg("kind").unwrap_or("spinner"),
+                            arg("size"),
+                            arg("color"),
+                            value,
+                        ) {
+                            Ok(html) => {
+                                Ok(json!({ "content": [{ "type": "text", "text": html }] }))
+                            }
+                            Err(e) => Err(JsonRpcError {
+                                code: -32602,
+                                message: e,
+                                data: None,
+                            }),
+                        }
+                    }
+                    "daisyui_keyboard" => match keyboard_snippet(args) {
+                        Ok(html) => Ok(json!({ "content": [{ "type": "text", "text": html }] })),
+                        Err(e) => Err(JsonRpcError {
+                            code: -32602,
+                            message: e,
+                            data: None,
+                        }),
+                    },
+                    "daisyui_preview_file" => {
+                        let html = args.and_then(|a| a.get("html")).and_then(|v| v.as_str());
+                        let layout = args
+                            .and_then(|a| a.get("layout"))
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("saas");
+                        let title = args
+                            .and_then(|a| a.get("title"))
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("My App");
+                        let output = args
+                            .and_then(|a| a.get("output"))
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("data_url");
+                        let breakpoint = args
+                            .and_then(|a| a.get("breakpoint"))
+                            .and_then(|v| v.as_str());
+                        let theme = args.and_then(|a| a.get("theme")).and_then(|v| v.as_str());
+                        let checked = theme
+                            .map(|t| theme::check_known(t, &session.themes()))
+                            .transpose()
+                            .and_then(|_| preview_page(html, layout, title, breakpoint, theme));
+                        match checked {
+                            Err(e) => Err(JsonRpcError {
+                                code: -32602,
+                                message: e,
+                                data: None,
+                            }),
+                            Ok(page) => {
+                                let summary = preview_summary(&page);
+                                match output {
+                                    "file" => match preview::write_temp(layout, &page.html) {
+                                        Ok(path) => Ok(json!({ "content": [
+                                            { "type": "text", "text": summary },
+                                            { "type": "text", "text": path.display().to_string() }
+                                        ] })),
+                                        Err(e) => Err(JsonRpcError {
+                                            code: -32603,
+                                            message: format!("Failed to write preview file: {}", e),
+                                            data: None,
+                                        }),
+                                    },
+                                    _ => Ok(json!({ "content": [
+                                        { "type": "text", "text": summary },
+                                        { "type": "text", "text": preview::data_url(&page.html) }
+                                    ] })),
+                                }
+                            }
+                        }
                     }
 
                     _ => Err(JsonRpcError {
@@ -0,0 +1,323 @@
+//! Chart palettes derived from daisyUI theme colors.
+//!
+//! Charting libraries want a flat list of distinguishable colors. This takes
+//! a theme's semantic colors in a fixed order and pads the list with tints
+//! that move away from the theme's base color, so they stay readable on it.
+
+/// Semantic colors in palette order, plus the base the chart sits on.
+#[derive(Debug, Clone)]
+pub struct ThemeColors {
+    pub primary: String,
+    pub secondary: String,
+    pub accent: String,
+    pub info: String,
+    pub success: String,
+    pub warning: String,
+    pub error: String,
+    pub base: String,
+}
+
+const THEMES: &[(&str, [&str; 8])] = &[
+    // primary, secondary, accent, info, success, warning, error, base-100
+    (
+        "light",
+        [
+            "#570df8", "#f000b8", "#37cdbe", "#3abff8", "#36d399", "#fbbd23", "#f87272", "#ffffff",
+        ],
+    ),
+    (
+        "dark",
+        [
+            "#661ae6", "#d926aa", "#1fb2a5", "#3abff8", "#36d399", "#fbbd23", "#f87272", "#2a303c",
+        ],
+    ),
+    (
+        "cupcake",
+        [
+            "#65c3c8", "#ef9fbc", "#eeaf3a", "#3abff8", "#36d399", "#fbbd23", "#f87272", "#faf7f5",
+        ],
+    ),
+    (
+        "corporate",
+        [
+            "#4b6bfb", "#7b92b2", "#67cba0", "#3abff8", "#36d399", "#fbbd23", "#f87272", "#ffffff",
+        ],
+    ),
+    (
+        "nord",
+        [
+            "#5e81ac", "#81a1c1", "#88c0d0", "#b48ead", "#a3be8c", "#ebcb8b", "#bf616a", "#eceff4",
+        ],
+    ),
+    (
+        "dracula",
+        [
+            "#ff79c6", "#bd93f9", "#ffb86c", "#8be9fd", "#50fa7b", "#f1fa8c", "#ff5555", "#282a36",
+        ],
+    ),
+    (
+        "synthwave",
+        [
+            "#e779c1", "#58c7f3", "#f3cc30", "#53c0f3", "#71ead2", "#f3cc30", "#e24056", "#1a103d",
+        ],
+    ),
+    (
+        "forest",
+        [
+            "#1eb854", "#1db88e", "#1db8ab", "#3abff8", "#36d399", "#fbbd23", "#f87272", "#171212",
+        ],
+    ),
+    (
+        "night",
+        [
+            "#38bdf8", "#818cf8", "#f471b5", "#0ca5e9", "#2dd4bf", "#f4bf50", "#fb7085", "#0f172a",
+        ],
+    ),
+];
+
+pub fn theme_names() -> Vec<&'static str> {
+    THEMES.iter().map(|(name, _)| *name).collect()
+}
+
+impl ThemeColors {
+    pub fn named(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+        THEMES
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, c)| Self::from_array(c))
+    }
+
+    fn from_array(c: &[&str; 8]) -> Self {
+        Self {
+            primary: c[0].into(),
+            secondary: c[1].into(),
+            accent: c[2].into(),
+            info: c[3].into(),
+            success: c[4].into(),
+            warning: c[5].into(),
+            error: c[6].into(),
+            base: c[7].into(),
+        }
+    }
+
+    /// Reads `--color-*` hex values from theme CSS such as the output of
+    /// `daisyui_generate_theme`. Colors it doesn't define fall back to the
+    /// light theme.
+    pub fn from_css(css: &str) -> Result<Self, String> {
+        let mut colors = Self::from_array(&THEMES[0].1);
+        let mut found = 0;
+        for decl in css.split([';', '{', '}']) {
+            let Some((key, value)) = decl.split_once(':') else {
+                continue;
+            };
+            let Some(name) = key.trim().strip_prefix("--color-") else {
+                continue;
+            };
+            let value = value.trim();
+            if parse_hex(value).is_none() {
+                continue;
+            }
+            let slot = match name {
+                "primary" => &mut colors.primary,
+                "secondary" => &mut colors.secondary,
+                "accent" => &mut colors.accent,
+                "info" => &mut colors.info,
+                "success" => &mut colors.success,
+                "warning" => &mut colors.warning,
+                "error" => &mut colors.error,
+                "base-100" => &mut colors.base,
+                _ => continue,
+            };
+            *slot = value.to_lowercase();
+            found += 1;
+        }
+        if found == 0 {
+            return Err("No hex --color-* values found in theme CSS".into());
+        }
+        Ok(colors)
+    }
+
+    fn semantic(&self) -> [&str; 7] {
+        [
+            &self.primary,
+            &self.secondary,
+            &self.accent,
+            &self.info,
+            &self.success,
+            &self.warning,
+            &self.error,
+        ]
+    }
+
+    fn is_dark(&self) -> bool {
+        parse_hex(&self.base).is_some_and(|rgb| luminance(rgb) < 0.5)
+    }
+}
+
+/// Resolves `theme:<name>`, a bare theme name, or theme CSS.
+pub fn resolve(spec: &str) -> Result<ThemeColors, String> {
+    let spec = spec.trim();
+    if spec.contains("--color-") {
+        return ThemeColors::from_css(spec);
+    }
+    let name = spec.strip_prefix("theme:").unwrap_or(spec);
+    ThemeColors::named(name).ok_or_else(|| {
+        format!(
+            "Unknown theme '{}'. Available: {}",
+            name,
+            theme_names().join(", ")
+        )
+    })
+}
+
+/// Ordered palette of `count` hex colors: the seven semantic colors first,
+/// then tints of them. Tints step lightness away from the base color and
+/// skip candidates too close to a color already in the palette.
+pub fn palette(theme: &ThemeColors, count: usize) -> Vec<String> {
+    let mut out: Vec<String> = Vec::with_capacity(count);
+    for color in theme.semantic() {
+        let color = color.to_lowercase();
+        if !out.contains(&color) {
+            out.push(color);
+        }
+    }
+    // Lighten on dark bases, darken on light ones, then try the other
+    // direction, then relax the distinctness threshold if still short.
+    let direction = if theme.is_dark() { 1.0 } else { -1.0 };
+    let seeds: Vec<String> = out.clone();
+    'fill: for min_distance in [60.0, 35.0] {
+        for step in [1.0, 2.0, 3.0, -1.0, -2.0] {
+            for seed in &seeds {
+                if out.len() >= count {
+                    break 'fill;
+                }
+                let Some(rgb) = parse_hex(seed) else {
+                    continue;
+                };
+                let (h, s, l) = rgb_to_hsl(rgb);
+                let l = (l + direction * 0.16 * step).clamp(0.12, 0.88);
+                let tint = hsl_to_rgb(h, s, l);
+                if out
+                    .iter()
+                    .filter_map(|c| parse_hex(c))
+                    .all(|other| distance(tint, other) >= min_distance)
+                {
+                    out.push(to_hex(tint));
+                }
+            }
+        }
+    }
+    out.truncate(count);
+    out
+}
+
+/// `--chart-1` … `--chart-N` custom properties scoped to the theme.
+pub fn css_block(selector: &str, colors: &[String]) -> String {
+    let props = colors
+        .iter()
+        .enumerate()
+        .map(|(i, c)| format!("  --chart-{}: {};", i + 1, c))
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!("{} {{\n{}\n}}", selector, props)
+}
+
+// ============================================================================
+// Color math
+// ============================================================================
+
+fn parse_hex(value: &str) -> Option<(u8, u8, u8)> {
+    let hex = value.trim().strip_prefix('#')?;
+    if !hex.is_ascii() {
+        return None;
+    }
+    let expand = |s: &str| u8::from_str_radix(s, 16).ok();
+    match hex.len() {
+        3 => {
+            let d: Vec<String> = hex.chars().map(|c| format!("{c}{c}")).collect();
+            Some((expand(&d[0])?, expand(&d[1])?, expand(&d[2])?))
+        }
+        6 => Some((
+            expand(&hex[0..2])?,
+            expand(&hex[2..4])?,
+            expand(&hex[4..6])?,
+        )),
+        _ => None,
+    }
+}
+
+fn to_hex((r, g, b): (u8, u8, u8)) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+fn luminance((r, g, b): (u8, u8, u8)) -> f64 {
+    (0.2126 * r as f64 + 0.7152 * g as f64 + 0.0722 * b as f64) / 255.0
+}
+
+/// Euclidean distance in RGB space; crude but enough to reject near-duplicates.
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> f64 {
+    let d = |x: u8, y: u8| (x as f64 - y as f64).powi(2);
+    (d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)).sqrt()
+}
+
+fn rgb_to_hsl((r, g, b): (u8, u8, u8)) -> (f64, f64, f64) {
+    let (r, g, b) = (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    if max == min {
+        return (0.0, 0.0, l);
+    }
+    let d = max - min;
+    let s = if l > 0.5 {
+        d / (2.0 - max - min)
+    } else {
+        d / (max + min)
+    };
+    let h = if max == r {
+        (g - b) / d + if g < b { 6.0 } else { 0.0 }
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+    (h / 6.0, s, l)
+}
+
+fn hsl_to_rgb(h: f64, s: f64, l: f64) -> (u8, u8, u8) {
+    let channel = |p: f64, q: f64, mut t: f64| {
+        if t < 0.0 {
+            t += 1.0;
+        }
+        if t > 1.0 {
+            t -= 1.0;
+        }
+        if t < 1.0 / 6.0 {
+            p + (q - p) * 6.0 * t
+        } else if t < 0.5 {
+            q
+        } else if t < 2.0 / 3.0 {
+            p + (q - p) * (2.0 / 3.0 - t) * 6.0
+        } else {
+            p
+        }
+    };
+    let (r, g, b) = if s == 0.0 {
+        (l, l, l)
+    } else {
+        let q = if l < 0.5 {
+            l * (1.0 + s)
+        } else {
+            l + s - l * s
+        };
+        let p = 2.0 * l - q;
+        (
+            channel(p, q, h + 1.0 / 3.0),
+            channel(p, q, h),
+            channel(p, q, h - 1.0 / 3.0),
+        )
+    };
+    let to_u8 = |v: f64| (v * 255.0).round().clamp(0.0, 255.0) as u8;
+    (to_u8(r), to_u8(g), to_u8(b))
+}
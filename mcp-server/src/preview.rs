@@ -0,0 +1,945 @@
+//! Offline previews: wraps generated markup in a standalone HTML page whose
+//! stylesheet is assembled from an embedded class → CSS map, so the page
+//! renders without reaching a CDN.
+
+use std::io;
+use std::path::PathBuf;
+
+pub struct Preview {
+    pub html: String,
+    pub missing: Vec<String>,
+    /// A requested theme with no inlined colors; the page falls back to
+    /// light colors.
+    pub unstyled_theme: Option<String>,
+}
+
+const THEME_CSS: &str = r#":root, [data-theme="light"] {
+  --color-base-100: #ffffff; --color-base-200: #f2f2f2; --color-base-300: #e5e6e6; --color-base-content: #1f2937;
+  --color-primary: #605dff; --color-primary-content: #edf1fe; --color-secondary: #f43098; --color-secondary-content: #f9e4f0;
+  --color-accent: #00d3bb; --color-accent-content: #084d49; --color-neutral: #09090b; --color-neutral-content: #e4e4e7;
+  --color-info: #00bafe; --color-success: #00d390; --color-warning: #fcb700; --color-error: #ff627d;
+  --radius-box: 1rem; --radius-field: 0.5rem; --radius-selector: 0.5rem;
+}
+[data-theme="dark"] {
+  --color-base-100: #1d232a; --color-base-200: #191e24; --color-base-300: #15191e; --color-base-content: #ecf9ff;
+  --color-neutral: #09090b; --color-neutral-content: #e4e4e7;
+}
+*, ::before, ::after { box-sizing: border-box; border: 0 solid var(--color-base-300); margin: 0; padding: 0; }
+html { font-family: ui-sans-serif, system-ui, sans-serif; line-height: 1.5; }
+body { background: var(--color-base-100); color: var(--color-base-content); }
+img, svg { display: block; max-width: 100%; }
+a { color: inherit; text-decoration: inherit; }
+ul { list-style: none; }
+button, input, textarea, select { font: inherit; color: inherit; background: transparent; }
+table { border-collapse: collapse; }
+"#;
+
+/// Component rules. `&` stands for the (escaped, variant-aware) selector.
+const COMPONENTS: &[(&str, &str)] = &[
+    (
+        "btn",
+        "& { display: inline-flex; align-items: center; justify-content: center; gap: .375rem; height: 2.5rem; padding: 0 1rem; border-radius: var(--radius-field); background: var(--color-base-200); border: 1px solid var(--color-base-300); font-weight: 600; font-size: .875rem; cursor: pointer; }",
+    ),
+    (
+        "btn-primary",
+        "& { background: var(--color-primary); color: var(--color-primary-content); border-color: var(--color-primary); }",
+    ),
+    (
+        "btn-secondary",
+        "& { background: var(--color-secondary); color: var(--color-secondary-content); border-color: var(--color-secondary); }",
+    ),
+    (
+        "btn-accent",
+        "& { background: var(--color-accent); color: var(--color-accent-content); border-color: var(--color-accent); }",
+    ),
+    (
+        "btn-neutral",
+        "& { background: var(--color-neutral); color: var(--color-neutral-content); border-color: var(--color-neutral); }",
+    ),
+    (
+        "btn-error",
+        "& { background: var(--color-error); color: #fff; border-color: var(--color-error); }",
+    ),
+    (
+        "btn-ghost",
+        "& { background: transparent; border-color: transparent; } &:hover { background: var(--color-base-200); }",
+    ),
+    (
+        "btn-link",
+        "& { background: transparent; border-color: transparent; color: var(--color-primary); text-decoration: underline; }",
+    ),
+    (
+        "btn-outline",
+        "& { background: transparent; border-color: currentColor; }",
+    ),
+    (
+        "btn-xs",
+        "& { height: 1.5rem; padding: 0 .5rem; font-size: .75rem; }",
+    ),
+    (
+        "btn-sm",
+        "& { height: 2rem; padding: 0 .75rem; font-size: .75rem; }",
+    ),
+    (
+        "btn-lg",
+        "& { height: 3rem; padding: 0 1.25rem; font-size: 1.125rem; }",
+    ),
+    ("btn-block", "& { width: 100%; }"),
+    ("btn-wide", "& { width: 16rem; }"),
+    (
+        "btn-circle",
+        "& { width: 2.5rem; padding: 0; border-radius: 9999px; }",
+    ),
+    ("btn-square", "& { width: 2.5rem; padding: 0; }"),
+    (
+        "card",
+        "& { position: relative; display: flex; flex-direction: column; border-radius: var(--radius-box); overflow: hidden; }",
+    ),
+    (
+        "card-body",
+        "& { display: flex; flex: auto; flex-direction: column; gap: .5rem; padding: 1.5rem; }",
+    ),
+    (
+        "card-title",
+        "& { display: flex; align-items: center; gap: .5rem; font-size: 1.125rem; font-weight: 600; }",
+    ),
+    (
+        "card-actions",
+        "& { display: flex; flex-wrap: wrap; align-items: flex-start; gap: .5rem; }",
+    ),
+    (
+        "card-side",
+        "& { flex-direction: row; align-items: stretch; }",
+    ),
+    (
+        "navbar",
+        "& { display: flex; align-items: center; width: 100%; min-height: 4rem; padding: .5rem; }",
+    ),
+    (
+        "navbar-start",
+        "& { display: inline-flex; align-items: center; width: 50%; }",
+    ),
+    (
+        "navbar-center",
+        "& { display: inline-flex; align-items: center; }",
+    ),
+    (
+        "navbar-end",
+        "& { display: inline-flex; align-items: center; justify-content: flex-end; width: 50%; }",
+    ),
+    (
+        "hero",
+        "& { display: grid; place-items: center; width: 100%; background-size: cover; }",
+    ),
+    (
+        "hero-content",
+        "& { display: flex; align-items: center; justify-content: center; gap: 1rem; max-width: 80rem; padding: 1rem; }",
+    ),
+    (
+        "menu",
+        "& { display: flex; flex-direction: column; flex-wrap: wrap; padding: .5rem; font-size: .875rem; } & li > a, & li > span { display: flex; align-items: center; gap: .5rem; padding: .375rem .75rem; border-radius: var(--radius-field); cursor: pointer; } & li > a:hover { background: var(--color-base-200); } & li > a.active { background: var(--color-neutral); color: var(--color-neutral-content); } & ul { margin-inline-start: 1rem; }",
+    ),
+    (
+        "menu-title",
+        "& { padding: .5rem .75rem; font-size: .875rem; font-weight: 600; opacity: .6; }",
+    ),
+    ("menu-horizontal", "& { flex-direction: row; }"),
+    (
+        "footer",
+        "& { display: grid; grid-auto-flow: column; gap: 2.5rem 1rem; width: 100%; font-size: .875rem; } & > * { display: grid; gap: .5rem; place-items: start; }",
+    ),
+    (
+        "footer-title",
+        "& { margin-bottom: .5rem; font-weight: 700; text-transform: uppercase; opacity: .6; }",
+    ),
+    (
+        "badge",
+        "& { display: inline-flex; align-items: center; justify-content: center; gap: .5rem; height: 1.5rem; padding: 0 .625rem; border-radius: var(--radius-selector); font-size: .875rem; background: var(--color-base-100); border: 1px solid var(--color-base-200); }",
+    ),
+    (
+        "badge-primary",
+        "& { background: var(--color-primary); color: var(--color-primary-content); border-color: var(--color-primary); }",
+    ),
+    (
+        "badge-secondary",
+        "& { background: var(--color-secondary); color: var(--color-secondary-content); border-color: var(--color-secondary); }",
+    ),
+    (
+        "badge-accent",
+        "& { background: var(--color-accent); color: var(--color-accent-content); border-color: var(--color-accent); }",
+    ),
+    (
+        "badge-neutral",
+        "& { background: var(--color-neutral); color: var(--color-neutral-content); border-color: var(--color-neutral); }",
+    ),
+    (
+        "badge-info",
+        "& { background: var(--color-info); color: #fff; border-color: var(--color-info); }",
+    ),
+    (
+        "badge-success",
+        "& { background: var(--color-success); color: #fff; border-color: var(--color-success); }",
+    ),
+    (
+        "badge-warning",
+        "& { background: var(--color-warning); color: #000; border-color: var(--color-warning); }",
+    ),
+    (
+        "badge-error",
+        "& { background: var(--color-error); color: #fff; border-color: var(--color-error); }",
+    ),
+    (
+        "badge-ghost",
+        "& { background: var(--color-base-200); border-color: var(--color-base-200); }",
+    ),
+    (
+        "badge-outline",
+        "& { background: transparent; border-color: currentColor; }",
+    ),
+    (
+        "badge-sm",
+        "& { height: 1.25rem; padding: 0 .5rem; font-size: .75rem; }",
+    ),
+    (
+        "badge-lg",
+        "& { height: 1.75rem; padding: 0 .75rem; font-size: 1rem; }",
+    ),
+    (
+        "input",
+        "& { display: inline-flex; height: 2.5rem; padding: 0 .75rem; border: 1px solid var(--color-base-300); border-radius: var(--radius-field); background: var(--color-base-100); }",
+    ),
+    (
+        "input-bordered",
+        "& { border-color: var(--color-base-300); }",
+    ),
+    ("input-sm", "& { height: 2rem; font-size: .75rem; }"),
+    (
+        "textarea",
+        "& { min-height: 5rem; padding: .5rem .75rem; border: 1px solid var(--color-base-300); border-radius: var(--radius-field); background: var(--color-base-100); }",
+    ),
+    (
+        "textarea-bordered",
+        "& { border-color: var(--color-base-300); }",
+    ),
+    (
+        "textarea-ghost",
+        "& { border-color: transparent; background: transparent; }",
+    ),
+    (
+        "form-control",
+        "& { display: flex; flex-direction: column; }",
+    ),
+    (
+        "label",
+        "& { display: flex; align-items: center; justify-content: space-between; padding: .5rem .25rem; font-size: .875rem; }",
+    ),
+    ("label-text", "& { font-size: .875rem; }"),
+    ("label-text-alt", "& { font-size: .75rem; }"),
+    (
+        "avatar",
+        "& { position: relative; display: inline-flex; } & > div { display: block; aspect-ratio: 1 / 1; overflow: hidden; } & img { width: 100%; height: 100%; object-fit: cover; }",
+    ),
+    (
+        "avatar-group",
+        "& { display: flex; overflow: hidden; } & .avatar { border: 4px solid var(--color-base-100); border-radius: 9999px; overflow: hidden; }",
+    ),
+    (
+        "placeholder",
+        "& > div { display: flex; align-items: center; justify-content: center; }",
+    ),
+    (
+        "drawer",
+        "& { position: relative; display: grid; grid-auto-columns: max-content auto; width: 100%; }",
+    ),
+    (
+        "drawer-toggle",
+        "& { position: fixed; height: 0; width: 0; appearance: none; opacity: 0; }",
+    ),
+    (
+        "drawer-content",
+        "& { grid-column-start: 2; grid-row-start: 1; min-width: 0; }",
+    ),
+    (
+        "drawer-side",
+        "& { grid-column-start: 1; grid-row-start: 1; display: none; }",
+    ),
+    ("drawer-overlay", "& { display: none; }"),
+    (
+        "drawer-open",
+        "& > .drawer-side { display: block; position: sticky; top: 0; height: 100vh; overflow-y: auto; }",
+    ),
+    (
+        "stats",
+        "& { display: inline-grid; grid-auto-flow: column; border-radius: var(--radius-box); overflow-x: auto; }",
+    ),
+    (
+        "stat",
+        "& { display: grid; gap: 0 1rem; padding: 1rem 1.5rem; border-inline-end: 1px dashed var(--color-base-300); }",
+    ),
+    (
+        "stat-title",
+        "& { font-size: .75rem; opacity: .6; white-space: nowrap; }",
+    ),
+    (
+        "stat-value",
+        "& { font-size: 2rem; font-weight: 800; white-space: nowrap; }",
+    ),
+    ("stat-desc", "& { font-size: .75rem; opacity: .6; }"),
+    (
+        "alert",
+        "& { display: grid; grid-auto-flow: column; justify-content: start; align-items: center; gap: 1rem; padding: .75rem 1rem; border-radius: var(--radius-box); border: 1px solid var(--color-base-200); background: var(--color-base-200); }",
+    ),
+    (
+        "alert-info",
+        "& { background: var(--color-info); color: #fff; }",
+    ),
+    (
+        "alert-success",
+        "& { background: var(--color-success); color: #fff; }",
+    ),
+    (
+        "alert-warning",
+        "& { background: var(--color-warning); color: #000; }",
+    ),
+    (
+        "alert-error",
+        "& { background: var(--color-error); color: #fff; }",
+    ),
+    (
+        "progress",
+        "& { appearance: none; width: 100%; height: .5rem; border-radius: var(--radius-box); overflow: hidden; background: var(--color-base-300); } &::-webkit-progress-bar { background: transparent; } &::-webkit-progress-value { background: var(--color-base-content); } &::-moz-progress-bar { background: var(--color-base-content); }",
+    ),
+    (
+        "progress-primary",
+        "&::-webkit-progress-value { background: var(--color-primary); } &::-moz-progress-bar { background: var(--color-primary); }",
+    ),
+    (
+        "toggle",
+        "& { appearance: none; width: 3rem; height: 1.5rem; border-radius: 9999px; border: 1px solid currentColor; cursor: pointer; }",
+    ),
+    (
+        "toggle-primary",
+        "&:checked { background: var(--color-primary); border-color: var(--color-primary); }",
+    ),
+    (
+        "checkbox",
+        "& { appearance: none; width: 1.5rem; height: 1.5rem; border: 1px solid currentColor; border-radius: var(--radius-selector); } &:checked { background: var(--color-base-content); }",
+    ),
+    (
+        "join",
+        "& { display: inline-flex; align-items: stretch; } & > .join-item { border-radius: 0; } & > .join-item:first-child { border-start-start-radius: var(--radius-field); border-end-start-radius: var(--radius-field); } & > .join-item:last-child { border-start-end-radius: var(--radius-field); border-end-end-radius: var(--radius-field); }",
+    ),
+    ("join-item", "& { margin: 0; }"),
+    (
+        "divider",
+        "& { display: flex; align-items: center; gap: 1rem; margin: 1rem 0; white-space: nowrap; } &::before, &::after { content: ''; flex-grow: 1; height: 1px; background: var(--color-base-300); }",
+    ),
+    (
+        "breadcrumbs",
+        "& > ul { display: flex; align-items: center; } & > ul > li + li::before { content: '/'; margin: 0 .5rem; opacity: .4; }",
+    ),
+    (
+        "mockup-code",
+        "& { padding: 1.25rem 0; border-radius: var(--radius-box); background: var(--color-neutral); color: var(--color-neutral-content); font-family: ui-monospace, monospace; font-size: .875rem; overflow-x: auto; } & pre { padding-inline: 1.25rem; } & pre[data-prefix]::before { content: attr(data-prefix); margin-inline-end: 1.5rem; opacity: .5; }",
+    ),
+    (
+        "glass",
+        "& { background: linear-gradient(135deg, rgba(255,255,255,.3), rgba(255,255,255,.05)); backdrop-filter: blur(40px); border: 1px solid rgba(255,255,255,.2); }",
+    ),
+    (
+        "skeleton",
+        "& { border-radius: var(--radius-box); background: var(--color-base-300); animation: dd-skeleton 1.8s ease-in-out infinite; } @keyframes dd-skeleton { 50% { opacity: .5; } }",
+    ),
+    (
+        "table",
+        "& { width: 100%; font-size: .875rem; text-align: left; } & th, & td { padding: .75rem 1rem; border-bottom: 1px solid var(--color-base-200); } & thead { font-size: .75rem; opacity: .6; }",
+    ),
+    (
+        "table-zebra",
+        "& tbody tr:nth-child(even) { background: var(--color-base-200); }",
+    ),
+    ("link", "& { cursor: pointer; text-decoration: underline; }"),
+    (
+        "link-hover",
+        "& { text-decoration: none; } &:hover { text-decoration: underline; }",
+    ),
+    (
+        "indicator",
+        "& { position: relative; display: inline-flex; }",
+    ),
+    (
+        "indicator-item",
+        "& { position: absolute; top: 0; right: 0; transform: translate(50%, -50%); z-index: 1; }",
+    ),
+    (
+        "kbd",
+        "& { display: inline-flex; align-items: center; justify-content: center; min-width: 1.5rem; height: 1.5rem; padding: 0 .375rem; border: 1px solid var(--color-base-300); border-bottom-width: 2px; border-radius: var(--radius-field); background: var(--color-base-200); font-size: .875rem; }",
+    ),
+    (
+        "loading",
+        "& { display: inline-block; width: 1.5rem; aspect-ratio: 1 / 1; border: 2px solid currentColor; border-right-color: transparent; border-radius: 9999px; animation: dd-spin .75s linear infinite; } @keyframes dd-spin { to { transform: rotate(360deg); } }",
+    ),
+    (
+        "modal",
+        "& { position: fixed; inset: 0; display: grid; place-items: center; background: rgba(0,0,0,.4); opacity: 0; pointer-events: none; } &[open], &.modal-open { opacity: 1; pointer-events: auto; }",
+    ),
+    (
+        "modal-box",
+        "& { width: 91%; max-width: 32rem; padding: 1.5rem; border-radius: var(--radius-box); background: var(--color-base-100); }",
+    ),
+    (
+        "modal-action",
+        "& { display: flex; justify-content: flex-end; gap: .5rem; margin-top: 1.5rem; }",
+    ),
+    ("tabs", "& { display: flex; flex-wrap: wrap; }"),
+    (
+        "tab",
+        "& { display: inline-flex; align-items: center; height: 2.5rem; padding: 0 1rem; cursor: pointer; opacity: .7; }",
+    ),
+    (
+        "tab-active",
+        "& { opacity: 1; font-weight: 600; border-bottom: 2px solid currentColor; }",
+    ),
+    (
+        "collapse",
+        "& { display: grid; overflow: hidden; border-radius: var(--radius-box); }",
+    ),
+    (
+        "collapse-title",
+        "& { padding: 1rem; font-weight: 600; cursor: pointer; }",
+    ),
+    ("collapse-content", "& { padding: 0 1rem 1rem; }"),
+    (
+        "dropdown",
+        "& { position: relative; display: inline-block; }",
+    ),
+    (
+        "dropdown-content",
+        "& { position: absolute; z-index: 50; display: none; } .dropdown:focus-within > & { display: block; }",
+    ),
+    (
+        "steps",
+        "& { display: inline-grid; grid-auto-flow: column; grid-auto-columns: 1fr; counter-reset: step; }",
+    ),
+    (
+        "step",
+        "& { display: grid; place-items: center; text-align: center; min-width: 4rem; }",
+    ),
+    (
+        "timeline",
+        "& { display: flex; flex-direction: column; gap: 1rem; }",
+    ),
+    ("rating", "& { display: inline-flex; }"),
+    (
+        "range",
+        "& { width: 100%; accent-color: var(--color-primary); }",
+    ),
+    (
+        "select",
+        "& { height: 2.5rem; padding: 0 .75rem; border: 1px solid var(--color-base-300); border-radius: var(--radius-field); }",
+    ),
+    (
+        "radio",
+        "& { appearance: none; width: 1.5rem; height: 1.5rem; border: 1px solid currentColor; border-radius: 9999px; } &:checked { box-shadow: 0 0 0 4px var(--color-base-100) inset; background: var(--color-base-content); }",
+    ),
+];
+
+/// Plain utility classes with fixed declarations.
+const UTILITIES: &[(&str, &str)] = &[
+    ("flex", "display: flex;"),
+    ("inline-flex", "display: inline-flex;"),
+    ("grid", "display: grid;"),
+    ("block", "display: block;"),
+    ("inline-block", "display: inline-block;"),
+    ("hidden", "display: none;"),
+    ("flex-1", "flex: 1 1 0%;"),
+    ("flex-none", "flex: none;"),
+    ("flex-col", "flex-direction: column;"),
+    ("flex-row", "flex-direction: row;"),
+    ("flex-wrap", "flex-wrap: wrap;"),
+    ("shrink-0", "flex-shrink: 0;"),
+    ("items-center", "align-items: center;"),
+    ("items-start", "align-items: flex-start;"),
+    ("items-end", "align-items: flex-end;"),
+    ("justify-center", "justify-content: center;"),
+    ("justify-between", "justify-content: space-between;"),
+    ("justify-start", "justify-content: flex-start;"),
+    ("justify-end", "justify-content: flex-end;"),
+    ("place-items-center", "place-items: center;"),
+    ("container", "width: 100%; max-width: 80rem;"),
+    ("mx-auto", "margin-left: auto; margin-right: auto;"),
+    ("w-full", "width: 100%;"),
+    ("w-fit", "width: fit-content;"),
+    ("h-full", "height: 100%;"),
+    ("h-screen", "height: 100vh;"),
+    ("min-h-screen", "min-height: 100vh;"),
+    ("min-h-full", "min-height: 100%;"),
+    ("max-w-sm", "max-width: 24rem;"),
+    ("max-w-md", "max-width: 28rem;"),
+    ("max-w-lg", "max-width: 32rem;"),
+    ("max-w-xl", "max-width: 36rem;"),
+    ("max-w-2xl", "max-width: 42rem;"),
+    ("max-w-3xl", "max-width: 48rem;"),
+    ("max-w-4xl", "max-width: 56rem;"),
+    ("max-w-5xl", "max-width: 64rem;"),
+    ("max-w-6xl", "max-width: 72rem;"),
+    ("text-xs", "font-size: .75rem; line-height: 1rem;"),
+    ("text-sm", "font-size: .875rem; line-height: 1.25rem;"),
+    ("text-base", "font-size: 1rem; line-height: 1.5rem;"),
+    ("text-lg", "font-size: 1.125rem; line-height: 1.75rem;"),
+    ("text-xl", "font-size: 1.25rem; line-height: 1.75rem;"),
+    ("text-2xl", "font-size: 1.5rem; line-height: 2rem;"),
+    ("text-3xl", "font-size: 1.875rem; line-height: 2.25rem;"),
+    ("text-4xl", "font-size: 2.25rem; line-height: 2.5rem;"),
+    ("text-5xl", "font-size: 3rem; line-height: 1;"),
+    ("text-6xl", "font-size: 3.75rem; line-height: 1;"),
+    ("text-center", "text-align: center;"),
+    ("text-left", "text-align: left;"),
+    ("text-right", "text-align: right;"),
+    (
+        "font-sans",
+        "font-family: ui-sans-serif, system-ui, sans-serif;",
+    ),
+    ("font-serif", "font-family: ui-serif, Georgia, serif;"),
+    ("font-mono", "font-family: ui-monospace, monospace;"),
+    ("font-semibold", "font-weight: 600;"),
+    ("font-bold", "font-weight: 700;"),
+    ("font-extrabold", "font-weight: 800;"),
+    ("uppercase", "text-transform: uppercase;"),
+    ("tracking-tight", "letter-spacing: -.025em;"),
+    ("line-through", "text-decoration-line: line-through;"),
+    (
+        "truncate",
+        "overflow: hidden; text-overflow: ellipsis; white-space: nowrap;",
+    ),
+    ("border", "border-width: 1px;"),
+    ("border-b", "border-bottom-width: 1px;"),
+    ("border-t", "border-top-width: 1px;"),
+    ("border-l", "border-left-width: 1px;"),
+    ("border-r", "border-right-width: 1px;"),
+    ("rounded", "border-radius: .25rem;"),
+    ("rounded-lg", "border-radius: .5rem;"),
+    ("rounded-xl", "border-radius: .75rem;"),
+    ("rounded-2xl", "border-radius: 1rem;"),
+    ("rounded-full", "border-radius: 9999px;"),
+    ("rounded-box", "border-radius: var(--radius-box);"),
+    (
+        "shadow",
+        "box-shadow: 0 1px 3px rgba(0,0,0,.1), 0 1px 2px rgba(0,0,0,.06);",
+    ),
+    ("shadow-sm", "box-shadow: 0 1px 2px rgba(0,0,0,.05);"),
+    ("shadow-md", "box-shadow: 0 4px 6px rgba(0,0,0,.1);"),
+    ("shadow-lg", "box-shadow: 0 10px 15px rgba(0,0,0,.1);"),
+    ("shadow-xl", "box-shadow: 0 20px 25px rgba(0,0,0,.1);"),
+    ("shadow-2xl", "box-shadow: 0 25px 50px rgba(0,0,0,.25);"),
+    ("sticky", "position: sticky;"),
+    ("fixed", "position: fixed;"),
+    ("relative", "position: relative;"),
+    ("absolute", "position: absolute;"),
+    ("top-0", "top: 0;"),
+    ("left-0", "left: 0;"),
+    ("right-0", "right: 0;"),
+    ("bottom-0", "bottom: 0;"),
+    ("z-10", "z-index: 10;"),
+    ("z-20", "z-index: 20;"),
+    ("z-50", "z-index: 50;"),
+    ("overflow-x-auto", "overflow-x: auto;"),
+    ("overflow-y-auto", "overflow-y: auto;"),
+    ("overflow-hidden", "overflow: hidden;"),
+    ("object-cover", "object-fit: cover;"),
+    ("cursor-pointer", "cursor: pointer;"),
+    ("resize-none", "resize: none;"),
+    ("float-right", "float: right;"),
+    (
+        "transition",
+        "transition: all .15s cubic-bezier(.4,0,.2,1);",
+    ),
+    ("backdrop-blur", "backdrop-filter: blur(8px);"),
+    (
+        "bg-gradient-to-r",
+        "background-image: linear-gradient(to right, var(--tw-gradient-from, transparent), var(--tw-gradient-to, transparent));",
+    ),
+];
+
+const SPACING_PROPS: &[(&str, &[&str])] = &[
+    ("p", &["padding"]),
+    ("px", &["padding-left", "padding-right"]),
+    ("py", &["padding-top", "padding-bottom"]),
+    ("pt", &["padding-top"]),
+    ("pb", &["padding-bottom"]),
+    ("pl", &["padding-left"]),
+    ("pr", &["padding-right"]),
+    ("m", &["margin"]),
+    ("mx", &["margin-left", "margin-right"]),
+    ("my", &["margin-top", "margin-bottom"]),
+    ("mt", &["margin-top"]),
+    ("mb", &["margin-bottom"]),
+    ("ml", &["margin-left"]),
+    ("mr", &["margin-right"]),
+    ("gap", &["gap"]),
+    ("w", &["width"]),
+    ("h", &["height"]),
+    ("min-h", &["min-height"]),
+    ("max-w", &["max-width"]),
+];
+
+const COLORS: &[&str] = &[
+    "primary",
+    "primary-content",
+    "secondary",
+    "secondary-content",
+    "accent",
+    "accent-content",
+    "neutral",
+    "neutral-content",
+    "base-100",
+    "base-200",
+    "base-300",
+    "base-content",
+    "info",
+    "success",
+    "warning",
+    "error",
+];
+
+const BREAKPOINTS: &[(&str, &str)] = &[
+    ("sm", "40rem"),
+    ("md", "48rem"),
+    ("lg", "64rem"),
+    ("xl", "80rem"),
+    ("2xl", "96rem"),
+];
+
+/// Names accepted by [`build`]'s `breakpoint`: `base` is below `sm`.
+pub fn breakpoint_names() -> Vec<&'static str> {
+    std::iter::once("base")
+        .chain(BREAKPOINTS.iter().map(|(bp, _)| *bp))
+        .collect()
+}
+
+/// Builds a standalone preview page for `body`, inlining CSS for every class
+/// the embedded map knows and reporting the ones it doesn't.
+///
+/// With a `breakpoint`, the page shows that screen size's arrangement
+/// regardless of the browser window: variants up to it apply
+/// unconditionally, larger ones are dropped, and the body is capped at the
+/// breakpoint's width. A `theme` is set on `<html>`; besides light and dark,
+/// themes with known colors get a rule built from them.
+pub fn build(
+    title: &str,
+    body: &str,
+    breakpoint: Option<&str>,
+    theme: Option<&str>,
+) -> Result<Preview, String> {
+    let pinned = match breakpoint {
+        None => None,
+        Some("base") => Some(0),
+        Some(name) => match BREAKPOINTS.iter().position(|(bp, _)| *bp == name) {
+            Some(i) => Some(i + 1),
+            None => {
+                return Err(format!(
+                    "Unknown breakpoint '{}'. Use one of: {}",
+                    name,
+                    breakpoint_names().join(", ")
+                ));
+            }
+        },
+    };
+    let mut css = String::from(THEME_CSS);
+    let theme = theme.unwrap_or("light");
+    let mut unstyled_theme = None;
+    if theme != "light" && theme != "dark" {
+        match theme_rule(theme) {
+            Some(rule) => css.push_str(&rule),
+            None => unstyled_theme = Some(theme.to_string()),
+        }
+    }
+    if let Some(active) = pinned {
+        let width = active.checked_sub(1).map_or("24rem", |i| BREAKPOINTS[i].1);
+        css.push_str(&format!(
+            "body {{ max-width: {}; margin: 0 auto; outline: 1px dashed var(--color-base-300); }}\n",
+            width
+        ));
+    }
+    let mut missing = Vec::new();
+    for class in crate::html::classes(body) {
+        match rule_for(&class, pinned) {
+            Some(rule) => {
+                css.push_str(&rule);
+                css.push('\n');
+            }
+            None => missing.push(class),
+        }
+    }
+    let html = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\" data-theme=\"{}\">\n<head>\n<meta charset=\"utf-8\" />\n<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\" />\n<title>{}</title>\n<style>\n{}</style>\n</head>\n<body>\n{}\n</body>\n</html>\n",
+        theme,
+        title,
+        css,
+        body.trim()
+    );
+    Ok(Preview {
+        html,
+        missing,
+        unstyled_theme,
+    })
+}
+
+/// A `[data-theme]` rule for a theme whose chart-palette colors are known,
+/// with the rest of its variables from the light or dark base.
+fn theme_rule(name: &str) -> Option<String> {
+    let colors = crate::palette::ThemeColors::named(name)?;
+    let overrides: Vec<(String, String)> = [
+        ("primary", colors.primary),
+        ("secondary", colors.secondary),
+        ("accent", colors.accent),
+        ("info", colors.info),
+        ("success", colors.success),
+        ("warning", colors.warning),
+        ("error", colors.error),
+        ("base-100", colors.base),
+    ]
+    .into_iter()
+    .map(|(key, value)| (key.to_string(), value))
+    .collect();
+    let (dark, variables) = crate::theme::declarations(&overrides);
+    Some(format!(
+        "[data-theme=\"{}\"] {{\n  color-scheme: {};\n{}}}\n",
+        name,
+        if dark { "dark" } else { "light" },
+        variables
+    ))
+}
+
+/// CSS for one class. `pinned` is the number of breakpoints treated as
+/// active; `None` keeps the usual `@media` queries.
+fn rule_for(class: &str, pinned: Option<usize>) -> Option<String> {
+    let (variants, base) = match class.rfind(':') {
+        Some(i) => (&class[..i], &class[i + 1..]),
+        None => ("", class),
+    };
+    let template = component_rule(base)
+        .or_else(|| sibling_rule(base))
+        .or_else(|| utility_rule(base).map(|d| format!("& {{ {} }}", d)))?;
+
+    let mut selector = format!(".{}", escape_class(class));
+    let mut media = None;
+    for variant in variants.split(':').filter(|v| !v.is_empty()) {
+        if let Some(i) = BREAKPOINTS.iter().position(|(bp, _)| *bp == variant) {
+            media = Some(i);
+        } else if variant == "hover" || variant == "focus" || variant == "active" {
+            selector.push(':');
+            selector.push_str(variant);
+        } else {
+            return None;
+        }
+    }
+
+    let rule = template.replace('&', &selector);
+    Some(match (media, pinned) {
+        (Some(i), Some(active)) if i >= active => String::new(),
+        (Some(i), None) => format!("@media (min-width: {}) {{ {} }}", BREAKPOINTS[i].1, rule),
+        _ => rule,
+    })
+}
+
+fn component_rule(class: &str) -> Option<String> {
+    COMPONENTS
+        .iter()
+        .find(|(name, _)| *name == class)
+        .map(|(_, css)| css.to_string())
+}
+
+/// `space-*` and `divide-*` utilities style the children, not the element.
+fn sibling_rule(class: &str) -> Option<String> {
+    let (negative, class) = match class.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, class),
+    };
+    let sign = if negative { "-" } else { "" };
+    let decls = if let Some(rest) = class.strip_prefix("space-x-") {
+        format!("margin-left: {}{};", sign, spacing_value(rest)?)
+    } else if let Some(rest) = class.strip_prefix("space-y-") {
+        format!("margin-top: {}{};", sign, spacing_value(rest)?)
+    } else if class == "divide-y" {
+        "border-top-width: 1px;".to_string()
+    } else if class == "divide-x" {
+        "border-left-width: 1px;".to_string()
+    } else if let Some(color) = class.strip_prefix("divide-") {
+        color_rule(&format!("border-{}", color))?
+    } else {
+        return None;
+    };
+    Some(format!("& > * + * {{ {} }}", decls))
+}
+
+fn utility_rule(class: &str) -> Option<String> {
+    if let Some((_, decls)) = UTILITIES.iter().find(|(name, _)| *name == class) {
+        return Some(decls.to_string());
+    }
+    if let Some(rest) = class.strip_prefix("opacity-") {
+        let n: u32 = rest.parse().ok()?;
+        return Some(format!("opacity: {};", n as f32 / 100.0));
+    }
+    if let Some(rest) = class.strip_prefix("grid-cols-") {
+        let n: u32 = rest.parse().ok()?;
+        return Some(format!(
+            "grid-template-columns: repeat({}, minmax(0, 1fr));",
+            n
+        ));
+    }
+    if let Some(decls) = color_rule(class) {
+        return Some(decls);
+    }
+    spacing_rule(class)
+}
+
+fn color_rule(class: &str) -> Option<String> {
+    let (prop, rest) = [
+        ("bg-", "background-color"),
+        ("text-", "color"),
+        ("border-", "border-color"),
+        ("from-", "--tw-gradient-from"),
+        ("to-", "--tw-gradient-to"),
+    ]
+    .iter()
+    .find_map(|(prefix, prop)| class.strip_prefix(prefix).map(|rest| (*prop, rest)))?;
+
+    let (color, alpha) = match rest.split_once('/') {
+        Some((c, a)) => (c, Some(a.parse::<u32>().ok()?)),
+        None => (rest, None),
+    };
+    let value = if COLORS.contains(&color) {
+        format!("var(--color-{})", color)
+    } else {
+        match color {
+            "black" => "#000".to_string(),
+            "white" => "#fff".to_string(),
+            "red-500" => "#ef4444".to_string(),
+            "green-500" => "#22c55e".to_string(),
+            _ => return None,
+        }
+    };
+    Some(match alpha {
+        Some(a) => format!(
+            "{}: color-mix(in oklab, {} {}%, transparent);",
+            prop, value, a
+        ),
+        None => format!("{}: {};", prop, value),
+    })
+}
+
+fn spacing_rule(class: &str) -> Option<String> {
+    // Longest prefix first so `min-h-` wins over `h-` style ambiguities.
+    let mut props: Vec<_> = SPACING_PROPS.iter().collect();
+    props.sort_by_key(|(p, _)| std::cmp::Reverse(p.len()));
+    for (prefix, properties) in props {
+        if let Some(value) = class
+            .strip_prefix(prefix)
+            .and_then(|r| r.strip_prefix('-'))
+            .and_then(spacing_value)
+        {
+            return Some(
+                properties
+                    .iter()
+                    .map(|p| format!("{}: {};", p, value))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            );
+        }
+    }
+    None
+}
+
+fn spacing_value(value: &str) -> Option<String> {
+    if let Some(arbitrary) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+        return Some(arbitrary.replace('_', " "));
+    }
+    if let Some((a, b)) = value.split_once('/') {
+        let (a, b): (f32, f32) = (a.parse().ok()?, b.parse().ok()?);
+        return Some(format!("{}%", (a / b * 100.0 * 1000.0).round() / 1000.0));
+    }
+    let n: f32 = value.parse().ok()?;
+    Some(if n == 0.0 {
+        "0".to_string()
+    } else {
+        format!("{}rem", n * 0.25)
+    })
+}
+
+fn escape_class(class: &str) -> String {
+    let mut out = String::with_capacity(class.len());
+    for c in class.chars() {
+        if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+            out.push(c);
+        } else {
+            out.push('\\');
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Encodes a page as a `data:text/html;base64,...` URL.
+pub fn data_url(html: &str) -> String {
+    format!("data:text/html;base64,{}", base64(html.as_bytes()))
+}
+
+/// Writes a page into a new file in the system temp directory and returns its
+/// path. The name carries the process id, a counter and the time, and the
+/// file is created exclusively, so an existing file is never written through.
+pub fn write_temp(name: &str, html: &str) -> io::Result<PathBuf> {
+    use std::io::Write;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    let file: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    loop {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.subsec_nanos());
+        let path = std::env::temp_dir().join(format!(
+            "daisy-days-preview-{}-{}-{}-{:08x}.html",
+            file,
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed),
+            nanos
+        ));
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(mut out) => {
+                out.write_all(html.as_bytes())?;
+                return Ok(path);
+            }
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
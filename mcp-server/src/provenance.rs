@@ -0,0 +1,115 @@
+//! One-line comments recording how a page was generated, and parsing them
+//! back so `daisyui_reproduce` can regenerate the same bytes.
+//!
+//! ```text
+//! <!-- daisy-days 0.3.0 daisyui_scaffold_layout seed=0 {"layout":"saas",...} -->
+//! ```
+//!
+//! Options are serialized as compact JSON with sorted keys, so the same
+//! options always give the same line.
+
+use serde_json::Value;
+
+const MARKER: &str = "<!-- daisy-days ";
+const END: &str = " -->";
+
+/// Generation is deterministic, so the seed is always 0. Sample content is
+/// seeded from the options themselves (the title, or their `seed`), so the
+/// stamp's seed is recorded only so stamps stay readable if a randomized
+/// generator is ever added.
+pub const SEED: u64 = 0;
+
+/// Tools whose output can carry a stamp and be reproduced from one.
+pub const TOOLS: &[&str] = &["daisyui_scaffold_layout", "daisyui_idea_to_ui"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stamp {
+    pub version: String,
+    pub tool: String,
+    pub seed: u64,
+    pub options: Value,
+}
+
+/// Compact JSON with sorted keys. `--` can only occur inside strings, where
+/// `-` means the same thing, so the result is safe inside a comment.
+fn canonical(options: &Value) -> String {
+    serde_json::to_string(options)
+        .unwrap_or_default()
+        .replace("--", "-\\u002d")
+}
+
+/// `html` prefixed with a stamp for `tool` and `options`.
+pub fn stamp(html: &str, tool: &str, options: &Value) -> String {
+    format!(
+        "{}{} {} seed={} {}{}\n{}",
+        MARKER,
+        env!("CARGO_PKG_VERSION"),
+        tool,
+        SEED,
+        canonical(options),
+        END,
+        html
+    )
+}
+
+/// Finds and parses the stamp line in `text`, which may be the comment
+/// alone or a whole stamped page.
+pub fn parse(text: &str) -> Result<Stamp, String> {
+    let line = text
+        .lines()
+        .map(str::trim)
+        .find(|l| l.starts_with(MARKER))
+        .ok_or("No daisy-days provenance comment found (expected a line starting with `<!-- daisy-days `)")?;
+    let body = line[MARKER.len()..]
+        .strip_suffix(END)
+        .ok_or("Provenance comment is not closed with `-->`")?;
+    let mut fields = body.splitn(4, ' ');
+    let (Some(version), Some(tool), Some(seed), Some(options)) =
+        (fields.next(), fields.next(), fields.next(), fields.next())
+    else {
+        return Err("Provenance comment needs a version, tool, seed and options".into());
+    };
+    let seed = seed
+        .strip_prefix("seed=")
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| format!("Invalid seed '{}' in provenance comment", seed))?;
+    let options: Value = serde_json::from_str(options)
+        .map_err(|e| format!("Invalid options in provenance comment: {}", e))?;
+    if !options.is_object() {
+        return Err("Provenance options must be a JSON object".into());
+    }
+    Ok(Stamp {
+        version: version.to_string(),
+        tool: tool.to_string(),
+        seed,
+        options,
+    })
+}
+
+impl Stamp {
+    /// Why the running server can't reproduce this stamp, if it can't.
+    pub fn check(&self) -> Result<(), String> {
+        if self.version != env!("CARGO_PKG_VERSION") {
+            return Err(format!(
+                "Generated by daisy-days {}, but this is {}; install {} to reproduce it",
+                self.version,
+                env!("CARGO_PKG_VERSION"),
+                self.version
+            ));
+        }
+        if !TOOLS.contains(&self.tool.as_str()) {
+            return Err(format!(
+                "'{}' output can't be reproduced. Supported: {}",
+                self.tool,
+                TOOLS.join(", ")
+            ));
+        }
+        if self.seed != SEED {
+            return Err(format!(
+                "Seed {} is not supported; this version only generates with seed {}",
+                self.seed, SEED
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+//! Tag-level accessibility hints: labels, button names and image `alt`.
+
+#[path = "../../src/a11y.rs"]
+mod a11y;
+#[path = "../../src/html.rs"]
+#[allow(dead_code)] // a11y depends on it
+mod html;
+
+use a11y::audit;
+
+/// `(rule, tag)` for each finding.
+fn found(html: &str) -> Vec<(&'static str, String)> {
+    audit(html).into_iter().map(|f| (f.rule, f.tag)).collect()
+}
+
+#[test]
+fn fields_need_a_label() {
+    assert_eq!(
+        found(
+            "<label for=\"a\">A</label><input id=\"a\">\
+             <label class=\"label\">B <input type=\"checkbox\" class=\"checkbox\"></label>\
+             <input aria-label=\"Search\" type=\"search\">\
+             <input type=\"hidden\" name=\"token\">\
+             <input id=\"orphan\" class=\"input\">\
+             <select><option>One</option></select>\
+             <textarea aria-labelledby=\"note-heading\"></textarea>"
+        ),
+        [
+            ("label", "<input id=\"orphan\" class=\"input\">".to_string()),
+            ("label", "<select>".to_string()),
+        ]
+    );
+    assert_eq!(
+        audit("<input>")[0].message,
+        "input has no label; wrap it in a `<label>`, point a `<label for>` at its `id` or add `aria-label`"
+    );
+}
+
+#[test]
+fn buttons_need_a_name_but_icon_buttons_can_use_aria() {
+    assert_eq!(
+        found(
+            "<button class=\"btn\">Save</button>\
+             <button class=\"btn btn-square\" aria-label=\"Close\"><svg aria-hidden=\"true\"></svg></button>\
+             <button class=\"btn\" title=\"Menu\"><svg></svg></button>\
+             <button class=\"btn\"><img src=\"x.png\" alt=\"Settings\"></button>\
+             <button class=\"btn btn-circle\"><svg viewBox=\"0 0 24 24\"></svg></button>\
+             <div role=\"button\" tabindex=\"0\"> </div>\
+             <input type=\"submit\" class=\"btn\">\
+             <input type=\"button\" class=\"btn\">\
+             <button aria-label=\"  \"></button>"
+        ),
+        [
+            (
+                "button-name",
+                "<button class=\"btn btn-circle\">".to_string()
+            ),
+            (
+                "button-name",
+                "<div role=\"button\" tabindex=\"0\">".to_string()
+            ),
+            (
+                "button-name",
+                "<input type=\"button\" class=\"btn\">".to_string()
+            ),
+            ("button-name", "<button aria-label=\"  \">".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn images_need_alt_even_if_empty() {
+    assert_eq!(
+        found(
+            "<img src=\"a.png\" alt=\"A chart\"><img src='b.png' alt>\
+             <img src=\"c.png\" role=\"presentation\"><img src=\"d.png\" class=\"rounded\" />\
+             <input type=\"image\" src=\"go.png\">"
+        ),
+        [
+            (
+                "img-alt",
+                "<img src=\"d.png\" class=\"rounded\" />".to_string()
+            ),
+            (
+                "img-alt",
+                "<input type=\"image\" src=\"go.png\">".to_string()
+            ),
+        ]
+    );
+    assert!(audit("").is_empty());
+    assert!(audit("<p>No markup to check <!-- <img> --></p>").is_empty());
+}
@@ -0,0 +1,24 @@
+//! The built-in alias table, the `aliases:` block in llms.txt and the note
+//! shown for an alias.
+
+#[path = "../../src/docs.rs"]
+#[allow(dead_code)] // only the alias helpers are under test
+mod docs;
+
+#[test]
+fn alias_blocks_are_read_from_llms_txt() {
+    let text = "# daisyUI\n\naliases:\n- Dialog: Modal\nsnackbar: toast\n- broken line\n\n- later: ignored\n### modal\nA dialog.\n";
+    assert_eq!(
+        docs::parse_aliases(text),
+        [
+            ("Dialog".to_string(), "modal".to_string()),
+            ("snackbar".to_string(), "toast".to_string()),
+        ]
+    );
+    assert!(docs::parse_aliases("### modal\nNo aliases here.\n").is_empty());
+    assert!(docs::ALIASES.len() >= 10);
+    assert_eq!(
+        docs::alias_note("dialog", "modal"),
+        "Showing 'modal' (alias of 'dialog')"
+    );
+}
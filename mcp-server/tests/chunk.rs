@@ -0,0 +1,102 @@
+//! Output splitting from the extension's chunk module: parts fit the limit,
+//! render with balanced fences, and reassemble into the original text.
+
+#[path = "../../src/chunk.rs"]
+mod chunk;
+
+/// A slash-command-shaped document: prose, then a long fenced HTML block
+/// with multi-byte text on every line.
+fn sample(lines: usize) -> String {
+    let mut text =
+        String::from("## Layout: store\n\nStorefront hero — héro « vitrine ».\n\n```html\n");
+    for i in 0..lines {
+        text.push_str(&format!(
+            "<div class=\"card\"><h2 class=\"card-title\">Produit {} — ☕ café 🍰</h2></div>\n",
+            i
+        ));
+    }
+    text.push_str("```\n\nFooter note ✓");
+    text
+}
+
+fn fences(text: &str) -> usize {
+    text.lines()
+        .filter(|l| l.trim_start().starts_with("```"))
+        .count()
+}
+
+#[test]
+fn small_output_is_one_untouched_part() {
+    let text = sample(3);
+    let parts = chunk::split(&text, 10_000);
+    assert_eq!(parts.len(), 1);
+    assert_eq!(parts[0].render(), text);
+}
+
+#[test]
+fn forced_truncation_reassembles_exactly() {
+    let text = sample(200);
+    for limit in [200, 512, 1024, 4096] {
+        let parts = chunk::split(&text, limit);
+        assert!(parts.len() > 1, "limit {} did not split", limit);
+        let joined: String = parts.iter().map(|p| p.body).collect();
+        assert_eq!(joined, text, "limit {}", limit);
+        for (i, part) in parts.iter().enumerate() {
+            let rendered = part.render();
+            assert!(
+                rendered.len() <= limit,
+                "part {} is {} bytes over a {} limit",
+                i + 1,
+                rendered.len(),
+                limit
+            );
+            assert_eq!(
+                fences(&rendered) % 2,
+                0,
+                "part {} has an unbalanced fence:\n{}",
+                i + 1,
+                rendered
+            );
+        }
+    }
+}
+
+#[test]
+fn parts_inside_a_fence_reopen_it_with_its_language() {
+    let text = sample(100);
+    let parts = chunk::split(&text, 1024);
+    let middle = parts[parts.len() / 2].render();
+    assert!(middle.starts_with("```html\n"), "{}", middle);
+    assert!(middle.ends_with("```"), "{}", middle);
+}
+
+#[test]
+fn cuts_fall_at_line_ends_when_lines_fit() {
+    let text = sample(100);
+    for part in &chunk::split(&text, 1024)[..] {
+        assert!(part.body.ends_with('\n') || text.ends_with(part.body));
+    }
+}
+
+#[test]
+fn overlong_lines_split_on_char_boundaries() {
+    let text = "é☕🍰".repeat(500);
+    let parts = chunk::split(&text, 100);
+    assert!(parts.len() > 1);
+    assert_eq!(parts.iter().map(|p| p.body).collect::<String>(), text);
+    assert!(parts.iter().all(|p| p.render().len() <= 100));
+}
+
+#[test]
+fn splitting_is_deterministic() {
+    let text = sample(150);
+    let first: Vec<String> = chunk::split(&text, 2048)
+        .iter()
+        .map(|p| p.render())
+        .collect();
+    let second: Vec<String> = chunk::split(&text, 2048)
+        .iter()
+        .map(|p| p.render())
+        .collect();
+    assert_eq!(first, second);
+}
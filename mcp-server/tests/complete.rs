@@ -0,0 +1,55 @@
+//! Component-name completion from the extension's complete module: typed
+//! prefixes narrow the list, substrings are the fallback, and only a
+//! finished name runs the command.
+
+#[path = "../../src/complete.rs"]
+mod complete;
+
+fn names() -> Vec<String> {
+    [
+        "accordion",
+        "badge",
+        "breadcrumbs",
+        "button",
+        "calendar",
+        "card",
+        "menu",
+        "radial-progress",
+        "tab",
+    ]
+    .iter()
+    .map(|n| n.to_string())
+    .collect()
+}
+
+#[test]
+fn typed_prefix_filters_case_insensitively() {
+    let names = names();
+    assert_eq!(complete::matching("bu", &names, 20), ["button"]);
+    assert_eq!(complete::matching("  BU", &names, 20), ["button"]);
+    assert_eq!(
+        complete::matching("b", &names, 20)[..2],
+        ["badge", "breadcrumbs"]
+    );
+    assert_eq!(complete::matching("", &names, 20).len(), names.len());
+}
+
+#[test]
+fn substrings_are_the_fallback_and_the_limit_applies_last() {
+    let names = names();
+    assert_eq!(
+        complete::matching("progress", &names, 20),
+        ["radial-progress"]
+    );
+    assert_eq!(complete::matching("ca", &names, 20), ["calendar", "card"]);
+    assert_eq!(complete::matching("a", &names, 1), ["accordion"]);
+    assert_eq!(complete::matching("r", &names, 2), ["radial-progress"]);
+    assert!(complete::matching("zz", &names, 20).is_empty());
+}
+
+#[test]
+fn only_a_finished_name_runs_the_command() {
+    assert!(!complete::runs("bu", "button"));
+    assert!(!complete::runs("", "button"));
+    assert!(complete::runs("Button ", "button"));
+}
@@ -0,0 +1,789 @@
+//! Design concepts from `concepts.toml`: the TOML subset, validation, the
+//! bundled file both crates load, the classes its snippets use, keyword
+//! search, applying a concept to markup, snippets per framework, themes,
+//! accessibility hints and style recipes.
+
+#[path = "../../src/a11y.rs"]
+#[allow(dead_code)] // concepts depend on it
+mod a11y;
+#[path = "../../src/concepts.rs"]
+mod concepts;
+#[path = "../../src/docs.rs"]
+#[allow(dead_code)] // concepts depend on it
+mod docs;
+#[path = "../../src/footprint.rs"]
+#[allow(dead_code)] // purity depends on it
+mod footprint;
+#[path = "../../src/frameworks.rs"]
+#[allow(dead_code)] // only snippet conversion is used
+mod frameworks;
+#[path = "../../src/html.rs"]
+#[allow(dead_code)] // only the class parser is used
+mod html;
+#[path = "../../src/ids.rs"]
+#[allow(dead_code)] // purity depends on it
+mod ids;
+#[path = "../../src/index.rs"]
+#[allow(dead_code)] // concepts depend on it
+mod index;
+#[path = "../../src/purity.rs"]
+#[allow(dead_code)] // only the daisyUI class check is used
+mod purity;
+#[path = "../../src/sample.rs"]
+#[allow(dead_code)] // purity depends on it
+mod sample;
+#[path = "../../src/sections.rs"]
+#[allow(dead_code)] // purity depends on it
+mod sections;
+#[path = "../../src/snippets.rs"]
+#[allow(dead_code)] // purity depends on it
+mod snippets;
+#[path = "../../src/theme.rs"]
+#[allow(dead_code)] // only theme blocks and their checks are used
+mod theme;
+#[path = "../../src/variant.rs"]
+#[allow(dead_code)] // purity depends on it
+mod variant;
+
+use concepts::{Catalog, ConceptEngine, DesignConcept};
+use frameworks::Target;
+
+fn keys(catalog: &Catalog) -> Vec<&str> {
+    catalog.concepts.iter().map(|(k, _)| k.as_str()).collect()
+}
+
+#[test]
+fn the_bundled_file_loads_cleanly() {
+    let catalog = concepts::parse(concepts::SOURCE);
+    assert_eq!(catalog.errors, Vec::<String>::new());
+    assert_eq!(
+        keys(&catalog),
+        [
+            "glassmorphism",
+            "neumorphism",
+            "darkmode",
+            "gradient",
+            "skeleton",
+            "responsive",
+            "neubrutalism",
+            "bento",
+            "claymorphism",
+            "retro-terminal",
+            "minimal",
+            "micro-interactions",
+            "page-transitions",
+            "scroll-reveal",
+            "loading-choreography",
+            "accessible-forms",
+            "keyboard-navigation",
+            "focus-visible",
+            "reduced-motion",
+            "inline-validation",
+            "floating-labels",
+            "progressive-disclosure"
+        ]
+    );
+    for (key, concept) in &catalog.concepts {
+        assert!(!concept.description.is_empty(), "{}", key);
+        assert!(!concept.suggestion.is_empty(), "{}", key);
+        assert!(concept.examples.len() >= 2, "{}", key);
+        for (title, code) in &concept.examples {
+            assert!(code.contains("class=\""), "{} {}", key, title);
+        }
+    }
+}
+
+/// Tailwind utilities the bundled snippets may use: whole class names, and
+/// prefixes taking a value, a color or an `[arbitrary]` value.
+const UTILITIES: &[&str] = &[
+    "block",
+    "border",
+    "flex",
+    "flex-col",
+    "flex-row",
+    "grid",
+    "hidden",
+    "italic",
+    "rounded",
+    "shadow",
+    "sr-only",
+    "not-sr-only",
+    "transition",
+    "uppercase",
+];
+const UTILITY_PREFIXES: &[&str] = &[
+    "animate-",
+    "auto-rows-",
+    "backdrop-",
+    "bg-",
+    "border-",
+    "col-span-",
+    "duration-",
+    "font-",
+    "from-",
+    "gap-",
+    "grid-cols-",
+    "h-",
+    "items-",
+    "justify-",
+    "leading-",
+    "m-",
+    "max-w-",
+    "mb-",
+    "min-h-",
+    "mt-",
+    "mx-",
+    "my-",
+    "opacity-",
+    "outline-",
+    "p-",
+    "px-",
+    "py-",
+    "ring-",
+    "rounded-",
+    "row-span-",
+    "scale-",
+    "shadow-",
+    "space-y-",
+    "text-",
+    "to-",
+    "tracking-",
+    "transition-",
+    "translate-y-",
+    "via-",
+    "w-",
+];
+
+/// True for a Tailwind utility, ignoring `md:`-style variants.
+fn tailwind(class: &str) -> bool {
+    let base = class.rsplit(':').next().unwrap_or(class);
+    let balanced = base.matches('[').count() == base.matches(']').count();
+    balanced
+        && (UTILITIES.contains(&base)
+            || UTILITY_PREFIXES
+                .iter()
+                .any(|p| base.strip_prefix(p).is_some_and(|rest| !rest.is_empty())))
+}
+
+#[test]
+fn snippets_use_only_daisyui_and_tailwind_classes() {
+    for (key, concept) in concepts::parse(concepts::SOURCE).concepts {
+        for class in concept.injected() {
+            assert!(
+                purity::is_daisy_class(class) || tailwind(class),
+                "{}: `{}`",
+                key,
+                class
+            );
+        }
+        for (title, code) in &concept.examples {
+            let classes = html::classes(code);
+            assert!(!classes.is_empty(), "{} {}", key, title);
+            for class in classes {
+                assert!(
+                    purity::is_daisy_class(&class) || tailwind(&class),
+                    "{} {}: `{}` is neither a daisyUI class nor a Tailwind utility",
+                    key,
+                    title,
+                    class
+                );
+            }
+        }
+    }
+    assert!(!tailwind("btn-primary") && !tailwind("shadow-[4px"));
+}
+
+#[test]
+fn fields_parse_into_a_concept() {
+    let catalog = concepts::parse(
+        "# comment\n\
+         [Cards]\n\
+         description = \"Say \\\"hi\\\"\" # trailing\n\
+         classes = [ \"card\", \"shadow\" ]\n\
+         suggestion = \"Tab\\tand\\\\\"\n\
+         category = \"state\"\n\
+         theme = [\"base-100=#000\", \"depth=0\"]\n\
+         snippet = '''\n<div class=\"card\">\n</div>'''\n",
+    );
+    assert_eq!(catalog.errors, Vec::<String>::new());
+    let (key, concept) = &catalog.concepts[0];
+    assert_eq!(key, "cards");
+    assert_eq!(
+        *concept,
+        DesignConcept {
+            name: "cards".into(),
+            description: "Say \"hi\"".into(),
+            classes: vec!["card".into(), "shadow".into()],
+            suggestion: "Tab\tand\\".into(),
+            examples: vec![("Example".into(), "<div class=\"card\">\n</div>".into())],
+            script: None,
+            category: "state".into(),
+            targets: Vec::new(),
+            inject: None,
+            theme: vec![
+                ("base-100".into(), "#000".into()),
+                ("depth".into(), "0".into()),
+            ],
+        }
+    );
+    assert_eq!(
+        concept.to_display(Target::Html),
+        "## cards\n\n**Description:** Say \"hi\"\n\n**Classes:** card, shadow\n\n\
+         **Suggestion:** Tab\tand\\\n\n### Example\n\n```html\n<div class=\"card\">\n</div>\n```"
+    );
+}
+
+#[test]
+fn examples_follow_their_concept_and_the_first_is_the_default() {
+    let catalog = concepts::parse(
+        "[cards]\nclasses = [\"card\"]\ncategory = \"state\"\nsnippet = '''<div class=\"card\"></div>'''\n\
+         [[cards.examples]]\ntitle = \"Pricing table\"\ncode = '''<div class=\"card w-96\"></div>'''\n\
+         [[cards.examples]]\ncode = '''<div class=\"card card-sm\"></div>'''\n",
+    );
+    assert_eq!(catalog.errors, Vec::<String>::new());
+    let cards = &catalog.concepts[0].1;
+    assert_eq!(
+        cards.example_titles(),
+        ["Example", "Pricing table", "Example 3"]
+    );
+    assert_eq!(cards.snippet(), "<div class=\"card\"></div>");
+    assert_eq!(cards.example("PRICING"), Some(1));
+    assert_eq!(cards.example("navbar"), None);
+    let one = cards.to_display_example(Target::Jsx, 1);
+    assert!(
+        one.ends_with("### Pricing table\n\n```jsx\n<div className=\"card w-96\"></div>\n```"),
+        "{}",
+        one
+    );
+    assert_eq!(one.matches("### ").count(), 1);
+    assert_eq!(cards.to_display(Target::Html).matches("### ").count(), 3);
+
+    let engine = ConceptEngine::new();
+    let (_, glass, _) = engine.find("glassmorphism").unwrap();
+    let navbar = glass.example("navbar").unwrap();
+    assert!(glass.examples[navbar].1.contains("class=\"navbar glass"));
+
+    let catalog = concepts::parse(
+        "[a]\nclasses = [\"x\"]\ncategory = \"state\"\n\
+         [[b.examples]]\ncode = '''<a></a>'''\n\
+         [c]\nclasses = [\"x\"]\ncategory = \"state\"\n\
+         [[c.examples]]\ntitle = \"One\"\n\
+         [d]\nclasses = [\"x\"]\ncategory = \"state\"\n\
+         [[d.examples]]\ncaption = \"One\"\n\
+         [e]\nclasses = [\"x\"]\ncategory = \"state\"\n",
+    );
+    assert_eq!(
+        catalog.errors,
+        [
+            "concept 'a': line 4: expected `[[a.examples]]`, got `[[b.examples]]`",
+            "concept 'c': line 6: example 1 has no `code`",
+            "concept 'd': line 15: unknown example field `caption` (fields: title, code)",
+            "concept 'e': line 16: needs a `snippet` or a `[[e.examples]]` table",
+        ]
+    );
+}
+
+#[test]
+fn malformed_entries_are_reported_by_name_and_skipped() {
+    let catalog = concepts::parse(
+        "[good]\nclasses = [\"a\"]\nsnippet = '''<a></a>'''\ncategory = \"state\"\n\
+         [no-classes]\nclasses = []\nsnippet = '''<a></a>'''\n\
+         [blank]\nclasses = [\"a\"]\nsnippet = '''  '''\n\
+         [typo]\nclases = [\"a\"]\nsnippet = '''<a></a>'''\n\
+         [good]\nclasses = [\"b\"]\nsnippet = '''<b></b>'''\ncategory = \"state\"\n\
+         [odd]\nclasses = [\"a\"]\nsnippet = '''<a></a>'''\ncategory = \"fun\"\n\
+         [open]\nclasses = [\"a\"]\nsnippet = '''<a>\n</a>\n",
+    );
+    assert_eq!(keys(&catalog), ["good"]);
+    assert_eq!(catalog.concepts[0].1.classes, ["a"]);
+    assert_eq!(
+        catalog.errors,
+        [
+            "concept 'no-classes': line 5: needs at least one class",
+            "concept 'blank': line 10: `snippet` is empty",
+            "concept 'typo': line 12: unknown field `clases` (fields: name, description, classes, suggestion, snippet, script, category, targets, inject, theme)",
+            "concept 'good': line 14: is defined more than once",
+            "concept 'odd': line 18: category 'fun' isn't one of visual-style, layout, motion, accessibility, state",
+            "concept 'open': line 24: unclosed `'''` string",
+        ]
+    );
+}
+
+#[test]
+fn project_concepts_extend_and_override_the_bundled_ones() {
+    let bundled = ConceptEngine::new();
+    let engine = bundled
+        .with_project(
+            "# Our brand\n\
+             [acme-card]\nname = \"Acme Card\"\nclasses = [\"card\", \"bg-primary\"]\n\
+             snippet = '''<div class=\"card bg-primary\"></div>'''\ncategory = \"visual-style\"\n\
+             [glassmorphism]\nclasses = [\"glass\"]\nsnippet = '''<div class=\"glass\"></div>'''\n\
+             category = \"visual-style\"\n",
+        )
+        .unwrap();
+    let keys = engine.list_concepts();
+    assert_eq!(keys.len(), bundled.list_concepts().len() + 1);
+    assert!(keys.contains(&"acme-card".to_string()));
+    assert_eq!(engine.find("acme-card").unwrap().1.name, "Acme Card");
+    let (_, glass, _) = engine.find("glassmorphism").unwrap();
+    assert_eq!(
+        (glass.name.as_str(), &glass.classes[..]),
+        ("glassmorphism", &["glass".to_string()][..])
+    );
+    // The bundled engine is left as it was.
+    assert_eq!(
+        bundled.find("glassmorphism").unwrap().1.name,
+        "Glassmorphism"
+    );
+
+    assert_eq!(
+        bundled
+            .with_project("[acme-card]\nclasses = [\"card\"]\nsnipet = '''<div></div>'''\n")
+            .unwrap_err(),
+        format!(
+            "Couldn't load {}:\n- concept 'acme-card': line 3: unknown field `snipet` \
+             (fields: name, description, classes, suggestion, snippet, script, category, targets, inject, theme)",
+            concepts::PROJECT_FILE
+        )
+    );
+}
+
+#[test]
+fn keywords_find_the_closest_concept() {
+    let engine = ConceptEngine::new();
+    for (query, expected) in [
+        ("glass", "glassmorphism"),
+        ("frosted", "glassmorphism"),
+        ("dark theme", "darkmode"),
+        ("loading placeholder", "skeleton"),
+        ("screen sizes", "responsive"),
+        ("Hero sections", "gradient"),
+    ] {
+        let (key, _, note) = engine.find(query).expect(query);
+        assert_eq!(key, expected);
+        assert_eq!(
+            note,
+            Some(format!(
+                "No concept named '{}'; showing '{}'.",
+                query, expected
+            ))
+        );
+        assert_eq!(engine.search(query)[0].0, expected);
+    }
+
+    // An exact key needs no note, whatever its case.
+    let (key, concept, note) = engine.find(" DarkMode ").unwrap();
+    assert_eq!(
+        (key, concept.name.as_str(), note),
+        ("darkmode", "Dark Mode", None)
+    );
+
+    assert!(engine.find("baroque").is_none());
+    assert!(engine.find("").is_none());
+    assert!(engine.search("zzzz").is_empty());
+}
+
+#[test]
+fn the_engine_serves_every_bundled_concept() {
+    let engine = ConceptEngine::new();
+    assert!(engine.errors.is_empty(), "{:?}", engine.errors);
+    let mut keys: Vec<String> = concepts::parse(concepts::SOURCE)
+        .concepts
+        .into_iter()
+        .map(|(k, _)| k)
+        .collect();
+    keys.sort();
+    assert_eq!(engine.list_concepts(), keys);
+    assert!(ConceptEngine::default().list_concepts().is_empty());
+}
+
+#[test]
+fn applying_a_concept_styles_its_target_components_once() {
+    let engine = ConceptEngine::new();
+    let html = "<nav class=\"navbar bg-base-100\"><div class=\"card-body\"></div></nav>\
+                <div class=\"card glass\"></div><dialog class=\"modal\"></dialog>\
+                <div data-class=\"card\" class=\"hero\"></div>";
+    assert_eq!(
+        engine.apply("glassmorphism", html).unwrap(),
+        "<nav class=\"navbar bg-base-100 glass backdrop-blur\"><div class=\"card-body\"></div></nav>\
+         <div class=\"card glass backdrop-blur\"></div><dialog class=\"modal glass backdrop-blur\"></dialog>\
+         <div data-class=\"card\" class=\"hero\"></div>"
+    );
+    let once = engine.apply("glassmorphism", html).unwrap();
+    assert_eq!(engine.apply("glassmorphism", &once).unwrap(), once);
+
+    // Listed targets and injected classes replace the defaults.
+    let clay = engine
+        .apply(
+            "claymorphism",
+            "<a class=\"btn\"></a><nav class=\"navbar\"></nav>",
+        )
+        .unwrap();
+    assert_eq!(
+        clay,
+        "<a class=\"btn rounded-3xl shadow-xl\"></a><nav class=\"navbar\"></nav>"
+    );
+
+    assert_eq!(
+        engine.apply("responsive", html).unwrap_err(),
+        "Concept 'responsive' has no classes to apply"
+    );
+    assert_eq!(
+        engine.apply("bento", html).unwrap_err(),
+        "Concept 'bento' has no classes to apply"
+    );
+    assert!(
+        engine
+            .apply("baroque", html)
+            .unwrap_err()
+            .starts_with("Concept 'baroque' not found. Available: accessible-forms, ")
+    );
+}
+
+#[test]
+fn concepts_are_listed_by_category() {
+    let engine = ConceptEngine::new();
+    let groups = engine.by_category(None).unwrap();
+    let names: Vec<&str> = groups.iter().map(|(c, _)| *c).collect();
+    assert_eq!(
+        names,
+        ["visual-style", "layout", "motion", "accessibility", "state"]
+    );
+    let total: usize = groups.iter().map(|(_, m)| m.len()).sum();
+    assert_eq!(total, engine.list_concepts().len());
+
+    let layout = engine.by_category(Some("layout")).unwrap();
+    assert_eq!(layout.len(), 1);
+    assert_eq!(
+        concepts::category_markdown(layout[0].0, &layout[0].1),
+        "### layout\n\n\
+         - `bento` — Asymmetric grid of cards in mixed sizes, like the compartments of a bento box\n\
+         - `progressive-disclosure` — Only the fields everyone needs up front; the optional ones fold away\n\
+         - `responsive` — Adapts layout to different screen sizes\n"
+    );
+    let motion = engine.by_category(Some("motion")).unwrap();
+    let keys: Vec<&str> = motion[0].1.iter().map(|(k, _)| *k).collect();
+    assert_eq!(
+        keys,
+        [
+            "loading-choreography",
+            "micro-interactions",
+            "page-transitions",
+            "scroll-reveal"
+        ]
+    );
+    // Empty categories are left out.
+    assert!(
+        ConceptEngine::default()
+            .by_category(Some("motion"))
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(
+        engine.by_category(Some("fun")).unwrap_err(),
+        "Unknown category 'fun'. Categories: visual-style, layout, motion, accessibility, state"
+    );
+}
+
+#[test]
+fn snippets_come_in_each_framework() {
+    let engine = ConceptEngine::new();
+    let (_, glass, _) = engine.find("glassmorphism").unwrap();
+    let jsx = glass.to_display(Target::Jsx);
+    assert!(
+        jsx.contains(
+            "### Card\n\n```jsx\n<div className=\"card glass w-96 shadow-xl\"><div className=\"card-body\">Content</div></div>\n```\n\n### Navbar\n\n```jsx\n"
+        ),
+        "{}",
+        jsx
+    );
+    for (key, concept) in concepts::parse(concepts::SOURCE).concepts {
+        for (i, (title, code)) in concept.examples.iter().enumerate() {
+            assert_eq!(
+                concept.example_for(i, Target::Html),
+                *code,
+                "{} {}",
+                key,
+                title
+            );
+            assert!(
+                !concept.example_for(i, Target::Jsx).contains(" class=\""),
+                "{} {}",
+                key,
+                title
+            );
+            assert_eq!(
+                concept.example_for(i, Target::Vue),
+                concept.example_for(i, Target::Svelte),
+                "{} {}",
+                key,
+                title
+            );
+        }
+    }
+}
+
+#[test]
+fn every_concept_makes_a_complete_theme() {
+    let engine = ConceptEngine::new();
+    for key in engine.list_concepts() {
+        let css = engine.find(&key).unwrap().1.to_theme(&key);
+        for color in theme::COLORS {
+            assert_eq!(
+                css.matches(&format!("--color-{}:", color)).count(),
+                1,
+                "{} sets --color-{} once",
+                key,
+                color
+            );
+        }
+    }
+    let dark = engine.find("darkmode").unwrap().1.to_theme("night-owl");
+    assert!(dark.contains("name: \"night-owl\";\n"));
+    assert!(dark.contains("color-scheme: dark;\n  --color-base-100: #1d232a;"));
+    let glass = engine.find("glassmorphism").unwrap().1.to_theme("glass");
+    assert!(glass.contains("color-scheme: light;\n  --color-base-100: #ffffff99;"));
+
+    let catalog = concepts::parse(
+        "[acme]\nclasses = [\"card\"]\nsnippet = '''<div></div>'''\ncategory = \"state\"\n\
+         theme = [\"brand=#f00\"]\n",
+    );
+    assert!(
+        catalog.errors[0].starts_with("concept 'acme': line 5: unknown theme variable `brand`")
+    );
+}
+
+#[test]
+fn scripts_follow_the_snippet_in_their_own_block() {
+    let engine = ConceptEngine::new();
+    let (_, reveal, _) = engine.find("scroll-reveal").unwrap();
+    let script = reveal.script.as_deref().unwrap();
+    assert!(script.contains("new IntersectionObserver("));
+    let text = reveal.to_display(Target::Jsx);
+    let (markup, js) = text.split_once("\n```\n\n```js\n").unwrap();
+    assert!(markup.contains("```jsx\n") && markup.contains("className=\"card bg-base-200"));
+    assert_eq!(js, format!("{}\n```", script));
+
+    let motion = engine.by_category(Some("motion")).unwrap();
+    for (key, concept) in &motion[0].1 {
+        assert_eq!(
+            concept.script.is_some(),
+            *key != "micro-interactions",
+            "{}",
+            key
+        );
+    }
+    let (_, glass, _) = engine.find("glassmorphism").unwrap();
+    assert!(!glass.to_display(Target::Html).contains("```js"));
+
+    let catalog = concepts::parse(
+        "[quiet]\nclasses = [\"a\"]\nsnippet = '''<a></a>'''\ncategory = \"motion\"\nscript = ''' '''\n",
+    );
+    assert_eq!(
+        catalog.errors,
+        ["concept 'quiet': line 1: `script` is empty"]
+    );
+}
+
+#[test]
+fn two_concepts_compare_side_by_side() {
+    let engine = ConceptEngine::new();
+    let comparison = engine.compare("glassmorphism", "soft shadows").unwrap();
+    let keys = comparison.concepts.map(|(key, _)| key);
+    assert_eq!(keys, ["glassmorphism", "neumorphism"]);
+    assert_eq!(
+        comparison.notes,
+        ["No concept named 'soft shadows'; showing 'neumorphism'."]
+    );
+    let header = comparison.header();
+    assert!(header.starts_with(
+        "> No concept named 'soft shadows'; showing 'neumorphism'.\n\n\
+         ## Glassmorphism vs Neumorphism\n\n| | Glassmorphism | Neumorphism |\n|---|---|---|\n\
+         | Key | `glassmorphism` | `neumorphism` |\n| Category | visual-style | visual-style |\n"
+    ));
+    assert!(header.contains("| Classes | `glass` `backdrop-blur` | `shadow-lg` `bg-base-200` |\n"));
+    assert!(header.contains("| Best for | Apply glass class to cards and modals for depth | "));
+    let [glass, neu] = comparison.snippets();
+    assert!(glass.starts_with("### Glassmorphism\n\n```html\n<div class=\"card glass"));
+    assert!(neu.starts_with("### Neumorphism\n\n```html\n<button"));
+
+    let reveal = engine.compare("scroll-reveal", "bento").unwrap().snippets();
+    assert!(reveal[0].contains("\n```\n\n```js\n"));
+    assert!(!reveal[1].contains("```js"));
+
+    for (first, second, message) in [
+        (
+            "glassmorphism",
+            " Glassmorphism",
+            "Can't compare 'glassmorphism' with itself; pick a second concept, e.g. 'claymorphism'",
+        ),
+        (
+            "bento",
+            "bento",
+            "Can't compare 'bento' with itself; pick a second concept, e.g. 'progressive-disclosure'",
+        ),
+        (
+            "glass",
+            "frosted",
+            "'glass' and 'frosted' both pick 'glassmorphism'; pick a second concept, e.g. 'claymorphism'",
+        ),
+    ] {
+        assert_eq!(engine.compare(first, second).unwrap_err(), message);
+    }
+    assert!(
+        engine
+            .compare("glassmorphism", "baroque")
+            .unwrap_err()
+            .starts_with("Concept 'baroque' not found. Available: accessible-forms, ")
+    );
+}
+
+#[test]
+fn bundled_snippets_pass_their_own_accessibility_hints() {
+    let engine = ConceptEngine::new();
+    for key in engine.list_concepts() {
+        let (_, concept, _) = engine.find(&key).unwrap();
+        for (title, code) in &concept.examples {
+            assert_eq!(
+                engine.audit_hint(code),
+                Vec::<String>::new(),
+                "{} {}",
+                key,
+                title
+            );
+        }
+    }
+    let hints = engine.audit_hint(
+        "<input class=\"input\"><button class=\"btn\"><svg></svg></button><img src=\"a.png\">",
+    );
+    assert_eq!(
+        hints,
+        [
+            "`<input class=\"input\">`: input has no label; wrap it in a `<label>`, point a `<label for>` at its `id` or add `aria-label` (see the `accessible-forms` concept)",
+            "`<button class=\"btn\">`: button has no text; add some, or `aria-label` for an icon button (see the `keyboard-navigation` concept)",
+            "`<img src=\"a.png\">`: image has no `alt`; describe it, or use `alt=\"\"` if it's decorative",
+        ]
+    );
+    // Long tags are cut short, and without the concepts there's no pointer.
+    let long = format!("<input class=\"{}\">", "input ".repeat(20));
+    assert_eq!(
+        ConceptEngine::default().audit_hint(&long),
+        [format!(
+            "`{}…`: input has no label; wrap it in a `<label>`, point a `<label for>` at its `id` or add `aria-label`",
+            &long[..60]
+        )]
+    );
+    assert_eq!(
+        concepts::audit_markdown(&[]),
+        "## Accessibility Hints\n\nNo problems found: fields are labelled, buttons have names and images have `alt`.\n\n\
+         _Tag-level checks only, not a full audit._\n"
+    );
+    assert!(concepts::audit_markdown(&hints).contains("\n- `<img src=\"a.png\">`: image"));
+}
+
+#[test]
+fn style_recipes_take_one_concept_per_category() {
+    let engine = ConceptEngine::new();
+    let keys = |recipe: &concepts::Recipe| -> Vec<String> {
+        recipe.concepts.iter().map(|(k, _)| k.to_string()).collect()
+    };
+    let recipe = engine.recipe(7, &[]).unwrap();
+    let categories: Vec<&str> = recipe
+        .concepts
+        .iter()
+        .map(|(_, c)| c.category.as_str())
+        .collect();
+    assert_eq!(categories, concepts::RECIPE_CATEGORIES);
+    assert_eq!(keys(&engine.recipe(7, &[]).unwrap()), keys(&recipe));
+    let mut seen: Vec<Vec<String>> = (0..40)
+        .map(|seed| keys(&engine.recipe(seed, &[]).unwrap()))
+        .collect();
+    seen.sort();
+    seen.dedup();
+    assert!(seen.len() > 10, "{:?}", seen);
+
+    // Merged classes keep the first of each, and the example card carries
+    // every concept's injected classes and passes the accessibility hints.
+    let classes = recipe.classes();
+    for (_, concept) in &recipe.concepts {
+        for class in &concept.classes {
+            assert_eq!(classes.iter().filter(|c| **c == class).count(), 1);
+        }
+    }
+    for seed in 0..40 {
+        let recipe = engine.recipe(seed, &[]).unwrap();
+        let snippet = recipe.snippet();
+        assert_eq!(engine.audit_hint(&snippet), Vec::<String>::new());
+        let card = snippet.lines().next().unwrap();
+        for (key, concept) in &recipe.concepts {
+            if concept.targets().contains(&"card") {
+                for class in concept.injected() {
+                    assert!(card.contains(class), "{} {}: {}", seed, key, card);
+                }
+            }
+        }
+        assert_eq!(
+            recipe.scripts().len(),
+            recipe
+                .concepts
+                .iter()
+                .filter(|(_, c)| c.script.is_some())
+                .count()
+        );
+    }
+    let header = recipe.header();
+    assert!(header.starts_with("## Style Recipe: "));
+    assert!(header.contains(
+        "\n\n_Seed 7: the same seed and concepts give this recipe again._\n\n**Classes:** `"
+    ));
+    for (key, concept) in &recipe.concepts {
+        assert!(header.contains(&format!(
+            "- **{}** (`{}`, {}): {}\n",
+            concept.name, key, concept.category, concept.suggestion
+        )));
+    }
+}
+
+#[test]
+fn style_recipes_keep_included_concepts_and_avoid_clashes() {
+    let engine = ConceptEngine::new();
+    for seed in 0..60 {
+        let recipe = engine.recipe(seed, &["darkmode"]).unwrap();
+        let keys: Vec<&str> = recipe.concepts.iter().map(|(k, _)| *k).collect();
+        assert_eq!(keys[0], "darkmode");
+        assert_eq!(keys.len(), 4);
+        assert!(!keys.contains(&"neumorphism") && !keys.contains(&"claymorphism"));
+
+        let recipe = engine.recipe(seed, &["retro-terminal"]).unwrap();
+        assert_eq!(recipe.concepts[0].0, "retro-terminal");
+        assert_eq!(recipe.concepts[1].1.category, "layout");
+        assert_ne!(recipe.concepts[1].0, "bento");
+    }
+    // An included concept fills its category; keywords work too.
+    let recipe = engine.recipe(3, &["bento", "frosted"]).unwrap();
+    let keys: Vec<&str> = recipe.concepts.iter().map(|(k, _)| *k).collect();
+    assert_eq!(&keys[..2], ["bento", "glassmorphism"]);
+    assert_eq!(recipe.concepts[2].1.category, "motion");
+
+    assert_eq!(
+        engine.recipe(0, &["darkmode", "neumorphism"]).unwrap_err(),
+        "'darkmode' and 'neumorphism' don't go together: soft extruded shadows need a light surface"
+    );
+    assert_eq!(
+        engine.recipe(0, &["bento", "retro-terminal"]).unwrap_err(),
+        "'bento' and 'retro-terminal' don't go together: a terminal is one monospace column, not a grid of tiles"
+    );
+    for (a, b, _) in concepts::INCOMPATIBLE {
+        assert!(engine.find(a).is_some() && engine.find(b).is_some());
+    }
+    assert!(
+        engine
+            .recipe(0, &["baroque"])
+            .unwrap_err()
+            .starts_with("Concept 'baroque' not found.")
+    );
+    assert_eq!(
+        ConceptEngine::default().recipe(0, &[]).unwrap_err(),
+        "No concepts in visual-style, layout, motion to make a recipe from"
+    );
+}
@@ -0,0 +1,237 @@
+//! Unified diffs between layouts rendered with different themes and
+//! densities.
+
+#[path = "../src/diff.rs"]
+mod diff;
+#[path = "../../src/html.rs"]
+#[allow(dead_code)] // theme depends on it
+mod html;
+#[path = "../../src/theme.rs"]
+#[allow(dead_code)] // only scoping is used
+mod theme;
+#[path = "../../src/variant.rs"]
+#[allow(dead_code)] // only the built-in variants are used
+mod variant;
+
+use variant::Variant;
+
+/// A small page that spaces itself through `v`, one element per line like
+/// tidied layout output.
+fn page(v: &Variant) -> String {
+    [
+        r#"<div class="min-h-screen bg-base-200">"#.to_string(),
+        format!(
+            r#"  <header class="navbar bg-base-100 {}">"#,
+            v.space("px", 4)
+        ),
+        r#"    <a class="btn btn-ghost text-xl">Acme</a>"#.to_string(),
+        "  </header>".to_string(),
+        format!(r#"  <section class="hero {}">"#, v.space("py", 24)),
+        r#"    <div class="hero-content text-center">"#.to_string(),
+        format!(
+            r#"      <h1 class="{} font-bold">Ship faster</h1>"#,
+            v.text("5xl")
+        ),
+        r#"      <button class="btn btn-primary">Start</button>"#.to_string(),
+        "    </div>".to_string(),
+        "  </section>".to_string(),
+        r#"  <main class="container mx-auto">"#.to_string(),
+        r#"    <p>Body copy that stays the same.</p>"#.to_string(),
+        "  </main>".to_string(),
+        format!(r#"  <footer class="footer {}">"#, v.space("p", 10)),
+        r#"    <p>© Acme</p>"#.to_string(),
+        "  </footer>".to_string(),
+        "</div>".to_string(),
+    ]
+    .join("\n")
+}
+
+/// Applies a unified diff to `old`, checking every context and removed line
+/// against it.
+fn apply(old: &str, diff: &str) -> String {
+    let old: Vec<&str> = old.lines().collect();
+    let mut out = Vec::new();
+    let mut at = 0;
+    for line in diff.lines().skip(2) {
+        if let Some(header) = line.strip_prefix("@@ -") {
+            let (start, len) = header.split_once(' ').unwrap().0.split_once(',').unwrap();
+            let start: usize = start.parse().unwrap();
+            let len: usize = len.parse().unwrap();
+            // An empty range names the line before it.
+            let start = if len == 0 { start } else { start - 1 };
+            out.extend_from_slice(&old[at..start]);
+            at = start;
+        } else if let Some(kept) = line.strip_prefix(' ') {
+            assert_eq!(old[at], kept);
+            out.push(kept);
+            at += 1;
+        } else if let Some(removed) = line.strip_prefix('-') {
+            assert_eq!(old[at], removed);
+            at += 1;
+        } else {
+            out.push(line.strip_prefix('+').unwrap());
+        }
+    }
+    out.extend_from_slice(&old[at..]);
+    out.join("\n")
+}
+
+#[test]
+fn identical_pages_have_no_diff() {
+    let page = page(&Variant::COMFORTABLE);
+    assert_eq!(diff::unified(&page, &page, "before", "after", 3), "");
+    let dark = theme::scope(&page, "dark");
+    assert_eq!(diff::unified(&dark, &dark, "before", "after", 3), "");
+}
+
+#[test]
+fn a_theme_change_touches_only_the_root() {
+    let light = page(&Variant::COMFORTABLE);
+    assert_eq!(
+        diff::unified(&light, &theme::scope(&light, "dark"), "before", "after", 3),
+        r#"--- before
++++ after
+@@ -1,4 +1,4 @@
+-<div class="min-h-screen bg-base-200">
++<div data-theme="dark" class="min-h-screen bg-base-200">
+   <header class="navbar bg-base-100 px-4">
+     <a class="btn btn-ghost text-xl">Acme</a>
+   </header>
+"#
+    );
+}
+
+#[test]
+fn a_theme_wrapper_adds_lines_at_both_ends() {
+    let light = format!(
+        "{}\n<dialog class=\"modal\"></dialog>",
+        page(&Variant::COMFORTABLE)
+    );
+    assert_eq!(
+        diff::unified(&light, &theme::scope(&light, "dark"), "before", "after", 2),
+        r#"--- before
++++ after
+@@ -1,2 +1,3 @@
++<div data-theme="dark">
+ <div class="min-h-screen bg-base-200">
+   <header class="navbar bg-base-100 px-4">
+@@ -17,2 +18,3 @@
+ </div>
+ <dialog class="modal"></dialog>
++</div>
+"#
+    );
+}
+
+#[test]
+fn nearby_density_changes_share_a_hunk() {
+    let comfortable = page(&Variant::COMFORTABLE);
+    assert_eq!(
+        diff::unified(&comfortable, &page(&Variant::COMPACT), "before", "after", 3),
+        r#"--- before
++++ after
+@@ -1,17 +1,17 @@
+ <div class="min-h-screen bg-base-200">
+-  <header class="navbar bg-base-100 px-4">
++  <header class="navbar bg-base-100 px-1">
+     <a class="btn btn-ghost text-xl">Acme</a>
+   </header>
+-  <section class="hero py-24">
++  <section class="hero py-8">
+     <div class="hero-content text-center">
+-      <h1 class="text-5xl font-bold">Ship faster</h1>
++      <h1 class="text-4xl font-bold">Ship faster</h1>
+       <button class="btn btn-primary">Start</button>
+     </div>
+   </section>
+   <main class="container mx-auto">
+     <p>Body copy that stays the same.</p>
+   </main>
+-  <footer class="footer p-10">
++  <footer class="footer p-3">
+     <p>© Acme</p>
+   </footer>
+ </div>
+"#
+    );
+}
+
+#[test]
+fn distant_density_changes_get_their_own_hunks() {
+    let comfortable = page(&Variant::COMFORTABLE);
+    assert_eq!(
+        diff::unified(&comfortable, &page(&Variant::SPACIOUS), "a", "b", 1),
+        r#"--- a
++++ b
+@@ -1,8 +1,8 @@
+ <div class="min-h-screen bg-base-200">
+-  <header class="navbar bg-base-100 px-4">
++  <header class="navbar bg-base-100 px-7">
+     <a class="btn btn-ghost text-xl">Acme</a>
+   </header>
+-  <section class="hero py-24">
++  <section class="hero py-40">
+     <div class="hero-content text-center">
+-      <h1 class="text-5xl font-bold">Ship faster</h1>
++      <h1 class="text-6xl font-bold">Ship faster</h1>
+       <button class="btn btn-primary">Start</button>
+@@ -13,3 +13,3 @@
+   </main>
+-  <footer class="footer p-10">
++  <footer class="footer p-16">
+     <p>© Acme</p>
+"#
+    );
+}
+
+#[test]
+fn theme_and_density_together_without_context() {
+    let before = theme::scope(&page(&Variant::COMFORTABLE), "light");
+    let after = theme::scope(&page(&Variant::COMPACT), "dark");
+    assert_eq!(
+        diff::unified(&before, &after, "light", "dark", 0),
+        r#"--- light
++++ dark
+@@ -1,2 +1,2 @@
+-<div data-theme="light" class="min-h-screen bg-base-200">
+-  <header class="navbar bg-base-100 px-4">
++<div data-theme="dark" class="min-h-screen bg-base-200">
++  <header class="navbar bg-base-100 px-1">
+@@ -5,1 +5,1 @@
+-  <section class="hero py-24">
++  <section class="hero py-8">
+@@ -7,1 +7,1 @@
+-      <h1 class="text-5xl font-bold">Ship faster</h1>
++      <h1 class="text-4xl font-bold">Ship faster</h1>
+@@ -14,1 +14,1 @@
+-  <footer class="footer p-10">
++  <footer class="footer p-3">
+"#
+    );
+}
+
+#[test]
+fn every_theme_and_density_pair_applies_cleanly() {
+    let variants = [Variant::COMPACT, Variant::COMFORTABLE, Variant::SPACIOUS];
+    let themes = [None, Some("light"), Some("dark"), Some("cupcake")];
+    let pages: Vec<String> = variants
+        .iter()
+        .flat_map(|v| {
+            themes.iter().map(move |t| match t {
+                Some(t) => theme::scope(&page(v), t),
+                None => page(v),
+            })
+        })
+        .collect();
+    for old in &pages {
+        for new in &pages {
+            for context in [0, 1, 3] {
+                let diff = diff::unified(old, new, "old", "new", context);
+                assert_eq!(diff.is_empty(), old == new);
+                if !diff.is_empty() {
+                    assert_eq!(&apply(old, &diff), new, "{}", diff);
+                }
+            }
+        }
+    }
+}
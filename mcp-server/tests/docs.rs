@@ -0,0 +1,229 @@
+//! Splitting llms.txt into components under each heading style it comes in,
+//! splitting a component's doc into subsections, and sanitizing the result.
+
+#[path = "../../src/docs.rs"]
+#[allow(dead_code)] // only parsing and sanitizing are under test
+mod docs;
+
+use docs::{
+    MIN_COMPONENTS, Sanitize, class_index, owner, parse, sanitize_markdown, subsections, truncate,
+};
+use std::collections::HashMap;
+
+const NAMES: [&str; 5] = ["alert", "badge", "button", "card", "modal"];
+
+/// Components keyed by lowercase name, the way the docs caches hold them.
+fn components(text: &str) -> HashMap<String, String> {
+    parse(text)
+        .sections
+        .into_iter()
+        .map(|(name, content)| (name.to_lowercase(), content))
+        .collect()
+}
+
+fn names(sections: &[(String, String)]) -> Vec<&str> {
+    sections.iter().map(|(name, _)| name.as_str()).collect()
+}
+
+#[test]
+fn triple_hash_headings_are_the_upstream_style() {
+    let mut text = String::from("# daisyUI\n\n## daisyUI 5 components\n\nIntro.\n");
+    for name in NAMES {
+        text.push_str(&format!(
+            "\n### {}\n{} docs.\n\n#### Class names\n- component: `{}`\n",
+            name, name, name
+        ));
+    }
+    let parsed = parse(&text);
+    assert_eq!(names(&parsed.sections), NAMES);
+    assert_eq!(
+        parsed.sections[0].1,
+        "### alert\nalert docs.\n\n#### Class names\n- component: `alert`"
+    );
+    assert_eq!(parsed.bytes, text.len());
+    assert_eq!(parsed.diagnostic(), None);
+}
+
+#[test]
+fn double_hash_headings_are_read_when_triple_ones_are_missing() {
+    let mut text = String::from("# daisyUI\n");
+    for name in NAMES {
+        text.push_str(&format!(
+            "\n## {}\nUse `{}`.\n\n#### Example\n```html\n<div class=\"{}\"></div>\n```\n",
+            name, name, name
+        ));
+    }
+    let parsed = parse(&text);
+    assert_eq!(names(&parsed.sections), NAMES);
+    assert_eq!(
+        parsed.sections[4].1,
+        "## modal\nUse `modal`.\n\n#### Example\n```html\n<div class=\"modal\"></div>\n```"
+    );
+    assert_eq!(parsed.diagnostic(), None);
+}
+
+#[test]
+fn frontmatter_blocks_get_a_heading() {
+    let mut text = String::new();
+    for (i, name) in NAMES.iter().enumerate() {
+        let key = ["name", "title", "component"][i % 3];
+        text.push_str(&format!(
+            "---\n{}: \"{}\"\norder: {}\n---\n{} body.\n\n",
+            key, name, i, name
+        ));
+    }
+    let parsed = parse(&text);
+    assert_eq!(names(&parsed.sections), NAMES);
+    assert_eq!(parsed.sections[1].1, "### badge\nbadge body.");
+    assert_eq!(parsed.diagnostic(), None);
+}
+
+#[test]
+fn unrecognized_headings_degrade_to_a_diagnostic() {
+    // Two `##` sections beat one `###` section, but neither is enough.
+    let text = "# daisyUI\n## alert\nAlert.\n### Example\nx\n## badge\nBadge.\n= card =\nCard.\n";
+    let parsed = parse(text);
+    assert_eq!(names(&parsed.sections), ["alert", "badge"]);
+    assert!(parsed.sections.len() < MIN_COMPONENTS);
+    assert_eq!(
+        parsed.diagnostic().as_deref(),
+        Some(
+            "docs failed to parse: 2 components from 71B — heading format not recognized (expected `### name`, `## name` or `---` frontmatter with `name:`)"
+        )
+    );
+    let nothing = parse(&"plain text\n".repeat(200));
+    assert!(nothing.sections.is_empty());
+    assert!(
+        nothing
+            .diagnostic()
+            .unwrap()
+            .starts_with("docs failed to parse: 0 components from 2KB"),
+        "{:?}",
+        nothing.diagnostic()
+    );
+    // An unclosed or nameless frontmatter block yields nothing.
+    assert!(parse("---\nname: alert\nbody\n").sections.is_empty());
+    assert!(parse("---\norder: 1\n---\nbody\n").sections.is_empty());
+}
+
+#[test]
+fn the_bundled_docs_parse_cleanly() {
+    let parsed = parse(include_str!("../../src/llms.txt"));
+    assert_eq!(parsed.diagnostic(), None);
+    for name in ["button", "card", "modal", "avatar"] {
+        assert!(names(&parsed.sections).contains(&name), "{}", name);
+    }
+}
+
+#[test]
+fn override_docs_explain_their_own_classes() {
+    let bundled = components(include_str!("../../src/llms.txt"));
+    let mut text = String::from("# Project docs\n");
+    for name in NAMES {
+        text.push_str(&format!(
+            "\n### {}\nOur {} docs.\n\n#### Class names\n- component: `{}`\n",
+            name, name, name
+        ));
+    }
+    text.push_str(
+        "\n### ribbon\nA corner ribbon.\n\n#### Class names\n- component: `ribbon`\n- part: `ribbon-label`\n",
+    );
+    let project = components(&text);
+    let (bundled_index, project_index) = (class_index(&bundled), class_index(&project));
+    // A class only the override defines resolves there, to the override's text.
+    assert_eq!(owner("ribbon-label", &project_index), Ok("ribbon"));
+    assert!(owner("ribbon-label", &bundled_index).is_err());
+    assert!(project["ribbon"].contains("A corner ribbon."));
+    assert!(!bundled.contains_key("ribbon"));
+    // A component both define is explained by the override's own text.
+    assert_eq!(owner(".card", &project_index), Ok("card"));
+    assert_eq!(owner(".card", &bundled_index), Ok("card"));
+    assert!(project["card"].starts_with("### card\nOur card docs."));
+    assert_ne!(project["card"], bundled["card"]);
+}
+
+#[test]
+fn subsections_nest_by_level_and_skip_code() {
+    let doc = "### card\nIntro.\n#### Class names\n- component: `card`\n#### Syntax\n```html\n# not a heading\n<div class=\"card\"></div>\n```\n##### Notes\nA note.\n#### Rules\n- one\n#no-space\n";
+    let sections = subsections(doc);
+    assert_eq!(
+        names(&sections),
+        ["Class names", "Syntax", "Notes", "Rules"]
+    );
+    assert_eq!(sections[0].1, "#### Class names\n- component: `card`");
+    assert_eq!(
+        sections[1].1,
+        "#### Syntax\n```html\n# not a heading\n<div class=\"card\"></div>\n```\n##### Notes\nA note."
+    );
+    assert_eq!(sections[2].1, "##### Notes\nA note.");
+    assert_eq!(sections[3].1, "#### Rules\n- one\n#no-space");
+    assert!(subsections("### card\nNo headings here.").is_empty());
+    assert!(subsections("").is_empty());
+}
+
+/// Whether every code fence in `text` is closed: a fence opens with three or
+/// more backticks or tildes and closes with a bare run of the same character
+/// at least as long.
+fn fences_balanced(text: &str) -> bool {
+    let mut open: Option<(char, usize)> = None;
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        let Some(c) = trimmed.chars().next().filter(|c| *c == '`' || *c == '~') else {
+            continue;
+        };
+        let run = trimmed.chars().take_while(|x| *x == c).count();
+        if run < 3 {
+            continue;
+        }
+        match open {
+            None => open = Some((c, run)),
+            Some((o, len)) if o == c && run >= len && trimmed[run..].trim().is_empty() => {
+                open = None
+            }
+            Some(_) => {}
+        }
+    }
+    open.is_none()
+}
+
+#[test]
+fn sanitized_bundled_docs_keep_their_fences_balanced() {
+    let parsed = parse(include_str!("../../src/llms.txt"));
+    assert!(parsed.sections.len() >= MIN_COMPONENTS);
+    for (name, doc) in &parsed.sections {
+        assert!(
+            fences_balanced(doc),
+            "{} is unbalanced before sanitizing",
+            name
+        );
+        // Subsections and length budgets can cut a doc inside a fence.
+        let mut pieces: Vec<String> = vec![doc.clone()];
+        pieces.extend(subsections(doc).into_iter().map(|(_, block)| block));
+        for budget in [40, 200, 600] {
+            pieces.push(truncate(doc, budget, "run with full"));
+        }
+        let lines: Vec<&str> = doc.lines().collect();
+        for cut in [1, lines.len() / 3, lines.len() / 2] {
+            pieces.push(lines[..cut.min(lines.len())].join("\n"));
+        }
+        for piece in &pieces {
+            for mode in [Sanitize::Strict, Sanitize::Lenient] {
+                let clean = sanitize_markdown(piece, mode);
+                assert!(fences_balanced(&clean), "{} ({:?}):\n{}", name, mode, clean);
+            }
+        }
+    }
+}
+
+#[test]
+fn the_fence_check_catches_open_fences() {
+    assert!(fences_balanced("```html\n<p>\n```"));
+    assert!(fences_balanced("~~~~\n```\n~~~~~"));
+    assert!(!fences_balanced("```html\n<p>"));
+    assert!(!fences_balanced("````\n```\n"));
+    assert!(!fences_balanced("```\n~~~\n"));
+    assert!(fences_balanced(&sanitize_markdown(
+        "````\n```\n",
+        Sanitize::Strict
+    )));
+}
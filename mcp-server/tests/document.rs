@@ -0,0 +1,77 @@
+//! Standalone HTML documents wrapped around generated fragments.
+
+#[path = "../../src/document.rs"]
+mod document;
+#[path = "../../src/footprint.rs"]
+#[allow(dead_code)] // document depends on it
+mod footprint;
+#[path = "../../src/html.rs"]
+#[allow(dead_code)] // only the element parser is used
+mod html;
+
+use document::wrap;
+use html::elements;
+
+/// Tag names of `parent`'s children, in order.
+fn children(html: &str, parent: Option<usize>) -> Vec<String> {
+    elements(html)
+        .into_iter()
+        .filter(|e| e.parent == parent)
+        .map(|e| e.tag)
+        .collect()
+}
+
+#[test]
+fn documents_are_balanced_with_one_head_and_body() {
+    let fragment =
+        "<div class=\"hero\">\n  <h1>Hi</h1>\n</div>\n<dialog class=\"modal\"></dialog>\n";
+    let html = wrap(fragment, "Acme", None);
+    assert!(html.starts_with("<!DOCTYPE html>\n<html lang=\"en\" data-theme=\"light\">\n"));
+    assert!(html.ends_with("</body>\n</html>\n"));
+    for tag in ["<html", "<head>", "</head>", "<body>", "</body>", "</html>"] {
+        assert_eq!(html.matches(tag).count(), 1, "{}", tag);
+    }
+
+    // Every element closes where the parser expects: the root spans the
+    // document and the fragment sits whole inside the body.
+    let all = elements(&html);
+    assert_eq!(children(&html, None), ["html"]);
+    assert_eq!(all[0].end, html.trim_end().len());
+    assert_eq!(children(&html, Some(0)), ["head", "body"]);
+    let body = all.iter().position(|e| e.tag == "body").unwrap();
+    assert_eq!(
+        children(&html, Some(body)),
+        ["div", "dialog", "button", "script"]
+    );
+    assert!(html.contains(fragment.trim()));
+    assert!(html.contains("<title>Acme</title>"));
+    assert!(
+        html.contains("<link href=\"https://cdn.jsdelivr.net/npm/daisyui@5\" rel=\"stylesheet\"")
+    );
+    assert!(
+        html.contains(
+            "<script src=\"https://cdn.jsdelivr.net/npm/@tailwindcss/browser@4\"></script>"
+        )
+    );
+    assert!(!html.contains("chart.js"));
+}
+
+#[test]
+fn the_toggle_switches_from_the_chosen_theme() {
+    let html = wrap("<main></main>", "Acme", Some("dracula"));
+    assert!(html.contains("<html lang=\"en\" data-theme=\"dracula\">"));
+    assert!(html.contains("root.dataset.theme === 'dracula' ? 'dark' : 'dracula';"));
+    let html = wrap("<main></main>", "Acme", Some("dark"));
+    assert!(html.contains("root.dataset.theme === 'dark' ? 'light' : 'dark';"));
+}
+
+#[test]
+fn charts_pull_in_chart_js_and_titles_stay_text() {
+    let html = wrap(
+        "<canvas id=\"c\"></canvas>",
+        "&lt;b&gt;Q3&lt;/b&gt; &amp; more",
+        None,
+    );
+    assert!(html.contains("<script src=\"https://cdn.jsdelivr.net/npm/chart.js\"></script>"));
+    assert!(html.contains("<title>&lt;b&gt;Q3&lt;/b&gt; &amp; more</title>"));
+}
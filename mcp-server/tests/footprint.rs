@@ -0,0 +1,134 @@
+//! Footprint reports pinned for a few layouts, so a change to a layout or
+//! to the analyzer shows up as a diff of the report.
+
+#[path = "../../src/docs.rs"]
+#[allow(dead_code)] // sample depends on it
+mod docs;
+#[path = "../../src/footprint.rs"]
+#[allow(dead_code)] // only analyze is used
+mod footprint;
+#[path = "../../src/html.rs"]
+#[allow(dead_code)] // footprint depends on it
+mod html;
+#[path = "../../src/ids.rs"]
+mod ids;
+#[path = "../../src/purity.rs"]
+#[allow(dead_code)] // only the pure layouts are used
+mod purity;
+#[path = "../../src/sample.rs"]
+#[allow(dead_code)] // only seeded content is used
+mod sample;
+#[path = "../../src/sections.rs"]
+#[allow(dead_code)] // purity depends on it
+mod sections;
+#[path = "../../src/snippets.rs"]
+#[allow(dead_code)] // only a few page builders are used
+mod snippets;
+#[path = "../../src/variant.rs"]
+#[allow(dead_code)] // only the default density is used
+mod variant;
+
+use footprint::analyze;
+use ids::IdAllocator;
+use sample::Sample;
+use variant::Variant;
+
+const CDN: &str = "https://cdn.jsdelivr.net/npm/daisyui@5 (CSS), https://cdn.jsdelivr.net/npm/@tailwindcss/browser@4 (JS)";
+
+fn report(html: &str) -> String {
+    analyze(html).markdown()
+}
+
+#[test]
+fn analytics_needs_chart_js() {
+    let charts: Vec<(&str, String)> = snippets::DEFAULT_CHARTS
+        .iter()
+        .enumerate()
+        .map(|(i, kind)| (*kind, format!("dd-chart-{}", i + 1)))
+        .collect();
+    let html = snippets::analytics_page("Metrics", &charts, &Variant::default()).html();
+    assert_eq!(
+        report(&html),
+        format!(
+            "| Footprint | |
+|---|---|
+| daisyUI components (12) | navbar, btn, join, input, dropdown, menu, stats, stat, badge, card, table, progress |
+| DOM nodes | ~88 |
+| Images | 0 |
+| JavaScript | chart |
+| CDN (standalone page) | {}, https://cdn.jsdelivr.net/npm/chart.js (JS) |",
+            CDN
+        )
+    );
+}
+
+#[test]
+fn faq_needs_the_modal_script() {
+    let html = snippets::faq_page("Help", "dd-help-1", &Variant::default()).html();
+    assert_eq!(
+        report(&html),
+        format!(
+            "| Footprint | |
+|---|---|
+| daisyUI components (9) | navbar, btn, hero, input, card, collapse, modal, label, textarea |
+| DOM nodes | ~86 |
+| Images | 0 |
+| JavaScript | modal |
+| CDN (standalone page) | {} |",
+            CDN
+        )
+    );
+}
+
+#[test]
+fn the_pure_store_counts_its_product_images() {
+    let (counts, _) = sample::Counts::parse("store", [("cards", 3)]);
+    let mut s = Sample::new(sample::seed("Shop")).with_counts(counts);
+    let html = purity::layout(
+        "store",
+        "Shop",
+        &mut s,
+        &Variant::default(),
+        &mut IdAllocator::default(),
+    )
+    .unwrap()
+    .html();
+    assert_eq!(
+        report(&html),
+        format!(
+            "| Footprint | |
+|---|---|
+| daisyUI components (10) | navbar, btn, indicator, badge, hero, card, list, avatar, mask, label |
+| DOM nodes | ~46 |
+| Images | 3 |
+| JavaScript | none |
+| CDN (standalone page) | {} |",
+            CDN
+        )
+    );
+}
+
+#[test]
+fn scripts_are_named_by_what_the_markup_uses() {
+    for (html, scripts) in [
+        ("<p>plain</p>", vec![]),
+        ("<dialog class=\"modal\"></dialog>", vec!["modal"]),
+        (
+            "<dialog class=\"modal\"><input data-palette-input /></dialog>",
+            vec!["palette"],
+        ),
+        (
+            "<input data-tag-input /><script>go()</script>",
+            vec!["tag-input", "inline"],
+        ),
+        ("<canvas id=\"c\"></canvas>", vec!["chart"]),
+    ] {
+        assert_eq!(analyze(html).scripts, scripts, "{}", html);
+    }
+    let styled = analyze(
+        r#"<div class="hero" style="background-image: url(/a.png)"><img src="/b.png" /></div>"#,
+    );
+    assert_eq!(styled.images, 2);
+    assert_eq!(styled.nodes, 2);
+    assert_eq!(styled.components, ["hero"]);
+}
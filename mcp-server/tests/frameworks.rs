@@ -0,0 +1,281 @@
+//! Converting HTML snippets to JSX, Vue and Svelte markup.
+
+#[path = "../../src/frameworks.rs"]
+mod frameworks;
+#[path = "../../src/html.rs"]
+#[allow(dead_code)] // frameworks depend on it
+mod html;
+
+use frameworks::{
+    Target, component, component_name, convert, html_to_jsx, layout_component, rust_component,
+};
+
+const FORM: &str = "<!-- sign in -->\n<label for=\"email\" class=\"label\">Email</label>\n\
+<input id=\"email\" type=\"email\" class=\"input\" tabindex=\"1\">\n<br/>\n\
+<p class=\"hint\" data-for=\"x\">Use class=\"work\" mail &lt;here&gt;</p>";
+
+#[test]
+fn jsx_renames_attributes_and_closes_void_elements() {
+    assert_eq!(
+        convert(FORM, Target::Jsx),
+        "{/* sign in */}\n<label htmlFor=\"email\" className=\"label\">Email</label>\n\
+<input id=\"email\" type=\"email\" className=\"input\" tabIndex=\"1\" />\n<br />\n\
+<p className=\"hint\" data-for=\"x\">Use class=\"work\" mail &lt;here&gt;</p>"
+    );
+}
+
+#[test]
+fn vue_and_svelte_keep_class_but_close_void_elements() {
+    let expected = "<!-- sign in -->\n<label for=\"email\" class=\"label\">Email</label>\n\
+<input id=\"email\" type=\"email\" class=\"input\" tabindex=\"1\" />\n<br />\n\
+<p class=\"hint\" data-for=\"x\">Use class=\"work\" mail &lt;here&gt;</p>";
+    assert_eq!(convert(FORM, Target::Vue), expected);
+    assert_eq!(convert(FORM, Target::Svelte), expected);
+}
+
+#[test]
+fn html_is_left_alone_and_targets_parse_by_name() {
+    assert_eq!(convert(FORM, Target::Html), FORM);
+    // Quoted `>` and multi-line tags stay inside their tag.
+    assert_eq!(
+        convert("<img\n  alt=\"a > b\"\n  class=\"mask\">", Target::Jsx),
+        "<img\n  alt=\"a > b\"\n  className=\"mask\" />"
+    );
+    for target in Target::ALL {
+        assert_eq!(Target::parse(target.name()), Some(target));
+    }
+    assert_eq!(Target::parse(" React "), Some(Target::Jsx));
+    assert_eq!(Target::parse("angular"), None);
+    assert_eq!(
+        Target::names(),
+        ["html", "jsx", "vue", "svelte", "leptos", "yew"]
+    );
+}
+
+#[test]
+fn jsx_keeps_embedded_quotes_inside_their_attribute() {
+    assert_eq!(
+        html_to_jsx("<input placeholder='Say \"hi\"' class=\"input\" value=\"it's\">"),
+        "<input placeholder='Say \"hi\"' className=\"input\" value=\"it's\" />"
+    );
+    // A quoted attribute name isn't renamed, and neither is one it starts.
+    assert_eq!(
+        html_to_jsx("<a title=\"class=x for=y\" classic formaction=\"z\">x</a>"),
+        "<a title=\"class=x for=y\" classic formaction=\"z\">x</a>"
+    );
+    assert_eq!(
+        html_to_jsx("<hr><img src=\"a.png\"/><br class=\"x\" ><div></div>"),
+        "<hr /><img src=\"a.png\" /><br className=\"x\" /><div></div>"
+    );
+}
+
+#[test]
+fn jsx_styles_become_objects_and_handlers_functions() {
+    assert_eq!(
+        html_to_jsx(
+            "<div class=\"radial-progress\" style=\"--value:70; --size:8rem;\" role=\"progressbar\"></div>"
+        ),
+        "<div className=\"radial-progress\" style={{ \"--value\": \"70\", \"--size\": \"8rem\" }} role=\"progressbar\"></div>"
+    );
+    assert_eq!(
+        html_to_jsx("<p style=\"font-family: 'Inter', sans-serif; background-color:red\">x</p>"),
+        "<p style={{ fontFamily: \"'Inter', sans-serif\", backgroundColor: \"red\" }}>x</p>"
+    );
+    assert_eq!(
+        html_to_jsx("<p style='font-family: \"Inter\", sans-serif'>x</p>"),
+        "<p style={{ fontFamily: \"\\\"Inter\\\", sans-serif\" }}>x</p>"
+    );
+    assert_eq!(
+        html_to_jsx(
+            "<button onclick=\"document.getElementById('m').showModal()\" oninput=\"a(); b()\">Open</button>"
+        ),
+        "<button onClick={() => document.getElementById('m').showModal()} onInput={() => { a(); b(); }}>Open</button>"
+    );
+    assert_eq!(
+        html_to_jsx("<svg><path stroke-width=\"2\" stroke-linecap=\"round\"></path></svg>"),
+        "<svg><path strokeWidth=\"2\" strokeLinecap=\"round\"></path></svg>"
+    );
+}
+
+#[test]
+fn jsx_escapes_text_braces_and_drops_inline_scripts() {
+    assert_eq!(
+        html_to_jsx("<code>{ a }</code>\n<script>\nif (x) { y(); }\n</script>\n<p>done</p>"),
+        "<code>{'{'} a {'}'}</code>\n{/* inline <script> left out: React doesn't run it */}\n<p>done</p>"
+    );
+    // Vue and Svelte keep both.
+    assert_eq!(
+        convert("<p style=\"a:b\" onclick=\"f()\">{x}</p>", Target::Vue),
+        "<p style=\"a:b\" onclick=\"f()\">{x}</p>"
+    );
+}
+
+#[test]
+fn components_are_named_after_the_title() {
+    assert_eq!(component_name("My App"), "MyApp");
+    assert_eq!(component_name("acme_admin-panel"), "AcmeAdminPanel");
+    assert_eq!(component_name("404 page"), "Layout404Page");
+    assert_eq!(component_name(""), "Layout");
+    assert_eq!(
+        component("Acme", "<div>\n\n  <p>x</p>\n</div>\n<dialog></dialog>"),
+        "export default function Acme() {\n  return (\n    <>\n      <div>\n\n        <p>x</p>\n      </div>\n      <dialog></dialog>\n    </>\n  );\n}\n"
+    );
+}
+
+/// `text` with string literals and comments replaced by spaces, so what's
+/// left is what the Rust macro reads as tokens.
+fn tokens(text: &str) -> String {
+    let mut out = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                        }
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+                out.push_str("\"\"");
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                let mut last = ' ';
+                for c in chars.by_ref() {
+                    if last == '*' && c == '/' {
+                        break;
+                    }
+                    last = c;
+                }
+                out.push(' ');
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// The string literals in `text`, unescaped.
+fn literals(text: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut chars = text.chars();
+    let mut in_comment = false;
+    let mut last = ' ';
+    while let Some(c) = chars.next() {
+        match (in_comment, last, c) {
+            (false, '/', '*') => in_comment = true,
+            (true, '*', '/') => in_comment = false,
+            (false, _, '"') => {
+                let mut literal = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => literal.extend(chars.next()),
+                        '"' => break,
+                        c => literal.push(c),
+                    }
+                }
+                out.push(literal);
+            }
+            _ => {}
+        }
+        last = c;
+    }
+    out
+}
+
+#[test]
+fn leptos_quotes_text_and_attribute_values() {
+    assert_eq!(
+        convert(FORM, Target::Leptos),
+        "/* sign in */\n<label for=\"email\" class=\"label\">\"Email\"</label>\n\
+<input id=\"email\" type=\"email\" class=\"input\" tabindex=\"1\" />\n<br />\n\
+<p class=\"hint\" data-for=\"x\">\"Use class=\\\"work\\\" mail <here>\"</p>"
+    );
+    assert_eq!(
+        convert(
+            "<p>Build <b>faster</b>, {today}.</p>\n<svg viewBox=\"0 0 24 24\"><path d=\"M1\"/></svg>",
+            Target::Leptos
+        ),
+        "<p>\"Build \"<b>\"faster\"</b>\", {today}.\"</p>\n<svg viewBox=\"0 0 24 24\"><path d=\"M1\" /></svg>"
+    );
+}
+
+#[test]
+fn yew_wraps_text_and_gives_bare_attributes_values() {
+    assert_eq!(
+        convert(
+            "<form novalidate><input required data-toggle class='say \"hi\"'>\n  <button onclick=\"m.showModal()\">&ldquo;Open&rdquo; &amp; go</button></form>",
+            Target::Yew
+        ),
+        "<form novalidate=true><input required=true data-toggle=\"\" class=\"say \\\"hi\\\"\" />\n  \
+<button /* onclick=\"m.showModal()\" */>{ \"\u{201c}Open\u{201d} & go\" }</button></form>"
+    );
+    assert_eq!(
+        convert(
+            "<div>\n<script>\nlet x = {};\n</script>\n</div>",
+            Target::Yew
+        ),
+        "<div>\n/* inline <script> left out: port it to Rust or load it as a file */\n</div>"
+    );
+}
+
+#[test]
+fn rust_markup_round_trips_text_without_stray_braces() {
+    let html = "<div class=\"card\">\n  <!-- {note} -->\n  <h2 class=\"card-title\">Sets {a, b} &amp; maps</h2>\n  \
+<p>Use <code>{{ name }}</code> or \\n, then \"quote\"</p>\n  <input placeholder=\"{x}\" disabled>\n</div>";
+    let text = [
+        "Sets {a, b} & maps",
+        "Use ",
+        "{{ name }}",
+        " or \\n, then \"quote\"",
+    ];
+    for target in [Target::Leptos, Target::Yew] {
+        let markup = convert(html, target);
+        let tokens = tokens(&markup);
+        let braces = match target {
+            Target::Yew => tokens.replace("{ \"\" }", ""),
+            _ => tokens.clone(),
+        };
+        assert!(!braces.contains(['{', '}']), "{}", tokens);
+        let literals = literals(&markup);
+        let values = ["card", "card-title", "{x}"];
+        let found: Vec<&str> = literals
+            .iter()
+            .map(String::as_str)
+            .filter(|l| !values.contains(l))
+            .collect();
+        assert_eq!(found, text, "{}", markup);
+    }
+}
+
+#[test]
+fn rust_components_wrap_the_markup_in_a_function() {
+    assert_eq!(
+        rust_component("Acme", "<div>\n  <p>\"x\"</p>\n</div>", Target::Leptos),
+        "use leptos::prelude::*;\n\n#[component]\npub fn Acme() -> impl IntoView {\n    view! {\n        <div>\n          <p>\"x\"</p>\n        </div>\n    }\n}\n"
+    );
+    assert_eq!(
+        rust_component("Acme", "<p>{ \"x\" }</p>\n<dialog></dialog>", Target::Yew),
+        "use yew::prelude::*;\n\n#[function_component]\npub fn Acme() -> Html {\n    html! {\n        <>\n            <p>{ \"x\" }</p>\n            <dialog></dialog>\n        </>\n    }\n}\n"
+    );
+}
+
+#[test]
+fn layouts_become_components_for_component_targets() {
+    let html = "<div class=\"hero\">\n  <img src=\"a.png\">\n</div>";
+    assert_eq!(
+        layout_component(html, "acme &amp; co", Target::Jsx),
+        component("AcmeCo", &html_to_jsx(html))
+    );
+    for target in [Target::Leptos, Target::Yew] {
+        assert_eq!(
+            layout_component(html, "acme", target),
+            rust_component("Acme", &convert(html, target), target)
+        );
+    }
+    for target in [Target::Html, Target::Vue, Target::Svelte] {
+        assert_eq!(layout_component(html, "acme", target), html);
+    }
+}
@@ -0,0 +1,211 @@
+//! Layouts scored from prompts, titles taken from them, and the keyword a
+//! title falls back to.
+
+#[path = "../../src/html.rs"]
+#[allow(dead_code)] // theme depends on it
+mod html;
+#[path = "../../src/idea.rs"]
+mod idea;
+#[path = "../../src/theme.rs"]
+#[allow(dead_code)] // idea depends on it
+mod theme;
+
+use idea::{DEFAULT_TITLE, KEYWORDS, explain, extract_title};
+
+#[test]
+fn prompts_name_their_titles_in_many_ways() {
+    for (prompt, expected) in [
+        ("a landing page for Acme Rockets", "Acme Rockets"),
+        ("a calm \"Field Notes\" blog", "Field Notes"),
+        ("a store called “Bean There” for coffee", "Bean There"),
+        ("a wiki named 'Ops Handbook'", "Ops Handbook"),
+        ("a playful sprint board for tasks called acme", "Acme"),
+        ("a blog called field notes about coffee", "Field Notes"),
+        ("an inbox named Postbox, with folders", "Postbox"),
+        ("docs site titled Rust Patterns", "Rust Patterns"),
+        ("pricing page for the Bank of Ayr team", "Bank of Ayr"),
+        ("an admin dashboard for AWS accounts", "AWS"),
+        ("a landing page for my startup Orbital Labs", "Orbital Labs"),
+        ("Build a checkout flow. Brand: Tea & Co", "Tea & Co"),
+        ("a job board for Acme, Inc.", "Acme"),
+        ("what's new page for Zed 2.0", "Zed 2.0"),
+        ("a shop called <b>Evil</b>", "<b>Evil</b>"),
+    ] {
+        assert_eq!(
+            extract_title(prompt).as_deref(),
+            Some(expected),
+            "{}",
+            prompt
+        );
+    }
+}
+
+#[test]
+fn prompts_without_a_name_give_none() {
+    for prompt in [
+        "",
+        "a dashboard for my team",
+        "Dashboard with charts",
+        "a kanban board for tracking tasks",
+        "what's new in the app",
+        "a blog called",
+        "a page called \"\" for me",
+        "\"   \"",
+    ] {
+        assert_eq!(extract_title(prompt), None, "{}", prompt);
+    }
+}
+
+#[test]
+fn titles_are_one_line_and_kept_short() {
+    let long = format!("a blog called {}", "word ".repeat(30));
+    assert_eq!(
+        extract_title(&long).as_deref(),
+        Some("Word Word Word Word Word Word")
+    );
+    let quoted = format!("a blog \"{}\"", "Lorem ipsum ".repeat(10));
+    let title = extract_title(&quoted).unwrap();
+    assert!(title.chars().count() <= 60, "{}", title);
+    assert!(title.ends_with("ipsum"), "{}", title);
+    assert_eq!(
+        extract_title("a blog \"Field\nNotes\t!\"").as_deref(),
+        Some("Field Notes !")
+    );
+}
+
+#[test]
+fn without_a_name_the_winning_keyword_is_the_title() {
+    for (prompt, layout, title) in [
+        ("a dashboard for my team", "dashboard", "Dashboard"),
+        ("an admin dashboard", "dashboard", "Admin"),
+        ("a kanban board for tracking tasks", "kanban", "Kanban"),
+        ("a maintenance page", "error", "Maintenance"),
+        (
+            "a what's new page with release notes",
+            "changelog",
+            "What's New",
+        ),
+        ("pricing plans for my app", "pricing", "Pricing"),
+        ("not much of a page", "saas", DEFAULT_TITLE),
+    ] {
+        let idea = explain(prompt, &[]);
+        assert_eq!(idea.layout, layout, "{}", prompt);
+        assert_eq!(idea.title, title, "{}", prompt);
+    }
+    let extra = [("roster".to_string(), vec!["crew".to_string()])];
+    assert_eq!(explain("a roster for my crew", &extra).title, "Crew");
+}
+
+#[test]
+fn the_heaviest_keywords_win_ambiguous_prompts() {
+    for (prompt, layout) in [
+        ("blog about my social network", "social"),
+        ("ecommerce site", "store"),
+        ("an online shop with products", "store"),
+        ("login page", "auth"),
+        ("sign up form", "auth"),
+        ("a sign-in screen with password reset", "auth"),
+        ("register for an account", "auth"),
+        ("an admin dashboard to manage records", "dashboard"),
+        ("a news feed", "social"),
+        ("a support forum", "forum"),
+        ("cart and payment for my shop", "checkout"),
+        ("team settings", "settings"),
+        ("a product page for the music store", "store"),
+        ("not much of a page", "saas"),
+    ] {
+        assert_eq!(explain(prompt, &[]).layout, layout, "{}", prompt);
+    }
+}
+
+#[test]
+fn keywords_match_whole_words_and_their_plurals() {
+    let author = explain("a portfolio site for an author", &[]);
+    assert_eq!(author.layout, "saas");
+    assert_eq!(author.title, DEFAULT_TITLE);
+    assert_eq!(explain("cartoon gallery for kids", &[]).layout, "saas");
+    let shop = explain("ecommerce site", &[]);
+    assert_eq!((shop.layout.as_str(), shop.theme), ("store", None));
+    let docs = explain("function reference docs", &[]);
+    assert_eq!((docs.layout.as_str(), docs.theme), ("docs", None));
+
+    let sprints = explain("a board for sprints and tasks", &[]);
+    assert_eq!(sprints.layout, "kanban");
+    assert_eq!(
+        sprints
+            .scores
+            .iter()
+            .find(|s| s.layout == "kanban")
+            .unwrap()
+            .matched,
+        [
+            ("board".to_string(), 1),
+            ("task".to_string(), 2),
+            ("sprint".to_string(), 2)
+        ]
+    );
+    assert_eq!(explain("companies page", &[]).layout, "team");
+}
+
+#[test]
+fn ties_go_to_more_keywords_then_to_the_first_listed() {
+    // Blog's one keyword weighs as much as media's two.
+    let idea = explain("a podcast player with a blog", &[]);
+    assert_eq!(idea.layout, "media");
+    assert_eq!(
+        idea.decision,
+        "`media` ties with `blog` at 3 and matched more keywords (2)"
+    );
+    let idea = explain("a social blog", &[]);
+    assert_eq!(idea.layout, "blog");
+    assert_eq!(
+        idea.decision,
+        "`blog` ties with `social` at 3 with as many keywords and is listed first"
+    );
+    let extra = [("roster".to_string(), vec!["crew".to_string()])];
+    assert_eq!(explain("a roster for my crew", &extra).layout, "roster");
+}
+
+#[test]
+fn keywords_are_weighted_and_listed_once() {
+    for (layout, keywords) in KEYWORDS {
+        assert!(!keywords.is_empty(), "{}", layout);
+        for (keyword, weight) in *keywords {
+            assert!((1..=3).contains(weight), "{} {}", layout, keyword);
+            let owners = KEYWORDS
+                .iter()
+                .filter(|(_, ks)| ks.iter().any(|(k, _)| k == keyword))
+                .count();
+            assert_eq!(owners, 1, "{}", keyword);
+        }
+    }
+}
+
+#[test]
+fn no_prompt_panics() {
+    let long = "shop ".repeat(2000);
+    for prompt in [
+        "",
+        "   ",
+        "!!!",
+        "\"",
+        "'",
+        "“",
+        "called",
+        "for",
+        "a blog for",
+        "named \"",
+        "e-",
+        "sign",
+        "日本語のブログ for Ünïcödé Café",
+        "🚀 launch page for 🚀",
+        "\u{0}\u{7f} for \u{200b}Acme",
+        "a 'quoted' \"mixed“ title” for",
+        long.as_str(),
+    ] {
+        let idea = explain(prompt, &[]);
+        assert!(!idea.title.is_empty(), "{:?}", prompt);
+        assert!(idea.title.chars().count() <= 60, "{:?}", prompt);
+        assert!(!idea.markdown().is_empty());
+    }
+}
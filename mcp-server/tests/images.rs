@@ -0,0 +1,139 @@
+//! Image placeholder strategies for generated layouts.
+
+#[path = "../../src/images.rs"]
+mod images;
+
+use images::{STRATEGIES, substitute, svg_uri};
+
+const PAGE: &str = r#"<figure class="lg:w-1/2"><img src="https://picsum.photos/800/600" class="h-full object-cover" /></figure>
+<div class="avatar"><div class="w-12 rounded-full"><img src="https://picsum.photos/100" alt="" /></div></div>
+<img src="https://img.daisyui.com/images/stock/photo-1559181567-c3190ca9959b.jpg" class="w-32 h-32 rounded-xl object-cover" />
+<img src="https://placehold.co/400x300" alt="Team photo">
+<img src="https://picsum.photos/seed/a/1200/400" alt="Team photo" class="w-full" />"#;
+
+/// Percent-decodes a `data:` URI's payload.
+fn decode(uri: &str) -> String {
+    let payload = uri.strip_prefix("data:image/svg+xml,").expect(uri);
+    let bytes = payload.as_bytes();
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap();
+            out.push(u8::from_str_radix(hex, 16).expect(hex));
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(out).unwrap()
+}
+
+fn srcs(html: &str) -> Vec<&str> {
+    html.match_indices(" src=\"")
+        .map(|(i, m)| {
+            let rest = &html[i + m.len()..];
+            &rest[..rest.find('"').unwrap()]
+        })
+        .collect()
+}
+
+#[test]
+fn remote_keeps_the_stock_photos() {
+    assert_eq!(STRATEGIES, ["remote", "local", "svg", "none"]);
+    assert_eq!(substitute(PAGE, "remote"), PAGE);
+    assert_eq!(substitute(PAGE, "sepia"), PAGE);
+}
+
+#[test]
+fn svg_data_uris_are_valid_and_labeled_with_their_size() {
+    let uri = svg_uri(400, 300);
+    // Only unreserved and sub-delimiter characters, so the URI needs no
+    // further escaping inside a double-quoted attribute.
+    assert!(
+        uri.chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-._~:/=',;%+".contains(c)),
+        "{}",
+        uri
+    );
+    let svg = decode(&uri);
+    assert!(svg.starts_with(
+        "<svg xmlns='http://www.w3.org/2000/svg' width='400' height='300' viewBox='0 0 400 300'>"
+    ));
+    assert!(svg.contains("fill='#d1d5db'"));
+    assert!(svg.contains(">400 × 300</text>"));
+    assert!(svg.ends_with("</svg>"));
+}
+
+#[test]
+fn svg_images_get_their_size_from_the_url_or_classes() {
+    let html = substitute(PAGE, "svg");
+    let sizes: Vec<String> = srcs(&html)
+        .iter()
+        .map(|uri| {
+            let svg = decode(uri);
+            let start = svg.find("viewBox='0 0 ").unwrap() + 13;
+            svg[start..start + svg[start..].find('\'').unwrap()].to_string()
+        })
+        .collect();
+    assert_eq!(
+        sizes,
+        ["800 600", "100 100", "128 128", "400 300", "1200 400"]
+    );
+    assert!(html.contains(r#"class="h-full object-cover" width="800" height="600" />"#));
+    assert!(html.contains(r#"alt="Team photo" width="400" height="300">"#));
+}
+
+#[test]
+fn local_paths_are_named_after_the_alt_text() {
+    let html = substitute(PAGE, "local");
+    assert_eq!(
+        srcs(&html),
+        [
+            "/images/image-1.jpg",
+            "/images/image-2.jpg",
+            "/images/image-3.jpg",
+            "/images/team-photo.jpg",
+            "/images/team-photo-2.jpg",
+        ]
+    );
+    assert!(html.contains(r#"<img src="/images/image-2.jpg" alt="" width="100" height="100" />"#));
+}
+
+#[test]
+fn skeletons_keep_the_image_aspect_ratio() {
+    let html = substitute(PAGE, "none");
+    assert!(!html.contains("<img"));
+    let skeletons: Vec<&str> = html
+        .match_indices("<div class=\"skeleton ")
+        .map(|(i, m)| {
+            let rest = &html[i + m.len()..];
+            &rest[..rest.find('"').unwrap()]
+        })
+        .collect();
+    assert_eq!(
+        skeletons,
+        [
+            // A height with no fixed value can only be filled across.
+            "h-full w-full",
+            "w-[100px] h-[100px]",
+            "w-32 h-32 rounded-xl",
+            "w-[400px] h-[300px]",
+            "w-full aspect-[1200/400]",
+        ]
+    );
+    // The figure wrapper stays so the card keeps its shape.
+    assert!(html.starts_with(r#"<figure class="lg:w-1/2"><div class="skeleton h-full w-full" aria-hidden="true"></div></figure>"#));
+    assert_eq!(
+        substitute(r#"<img src="x.png" class="h-8" />"#, "none"),
+        r#"<div class="skeleton h-8 w-[32px]" aria-hidden="true"></div>"#
+    );
+    assert_eq!(
+        substitute(
+            r#"<img src="https://picsum.photos/800/400" class="w-40" />"#,
+            "none"
+        ),
+        r#"<div class="skeleton w-40 h-[80px]" aria-hidden="true"></div>"#
+    );
+}
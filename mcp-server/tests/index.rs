@@ -0,0 +1,325 @@
+//! The shared docs index: one posting per term and component, with mention
+//! counts feeding the search ranking.
+
+#[path = "../../src/index.rs"]
+mod index;
+
+use index::{Index, Query};
+use std::collections::HashMap;
+
+fn docs(pairs: &[(&str, String)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(name, content)| (name.to_string(), content.clone()))
+        .collect()
+}
+
+fn build(docs: &HashMap<String, String>) -> Index {
+    Index::build(docs.iter().map(|(k, v)| (k.as_str(), v.as_str())))
+}
+
+#[test]
+fn repeated_mentions_rank_higher() {
+    let docs = docs(&[
+        ("alpha", "Use a tooltip here.".into()),
+        ("beta", "Tooltip, ".repeat(10)),
+        ("gamma", "Nothing relevant at all.".into()),
+    ]);
+    let index = build(&docs);
+    assert_eq!(index.frequency("tooltip", "beta"), 10);
+    assert_eq!(index.frequency("Tooltip", "alpha"), 1);
+    assert_eq!(index.frequency("tooltip", "gamma"), 0);
+
+    let ranked = index.rank(&Query::parse("tooltip"), &docs);
+    let names: Vec<&str> = ranked.iter().map(|(n, _)| n.as_str()).collect();
+    assert_eq!(names, ["beta", "alpha"]);
+    assert!(ranked[0].1 > ranked[1].1);
+}
+
+#[test]
+fn duplicates_do_not_grow_the_index() {
+    let once = docs(&[("card", "card title body".into())]);
+    let many = docs(&[("card", "card title body ".repeat(50))]);
+    assert_eq!(build(&once).postings(), build(&many).postings());
+    assert_eq!(build(&many).frequency("title", "card"), 50);
+}
+
+#[test]
+fn names_and_phrases_still_lead() {
+    let docs = docs(&[
+        ("modal", "Dialog box.".into()),
+        ("drawer", "Opens a modal box. Modal, modal.".into()),
+    ]);
+    let ranked = build(&docs).rank(&Query::parse("modal"), &docs);
+    assert_eq!(ranked[0].0, "modal");
+    assert!(build(&docs).rank(&Query::parse("   "), &docs).is_empty());
+}
+
+#[test]
+fn pages_slice_the_ranking_and_report_the_total() {
+    let docs = docs(&[
+        ("one", "Badge ".repeat(3)),
+        ("two", "Badge ".repeat(2)),
+        ("three", "Badge".into()),
+        ("four", "Avatar".into()),
+    ]);
+    let index = build(&docs);
+    let (all, total) = index.page(&Query::parse("badge"), &docs, index::PAGE_SIZE, 0);
+    assert_eq!((all.len(), total), (3, 3));
+    let (page, total) = index.page(&Query::parse("badge"), &docs, 1, 1);
+    assert_eq!((page[0].0.as_str(), total), ("two", 3));
+    assert_eq!(index::showing(1, page.len(), total), "Showing 2–2 of 3");
+    let (past, total) = index.page(&Query::parse("badge"), &docs, 10, 30);
+    assert!(past.is_empty());
+    assert_eq!(
+        index::showing(30, past.len(), total),
+        "No results at offset 30; 3 in total"
+    );
+}
+
+#[test]
+fn queries_parse_phrases_exclusions_and_stray_quotes() {
+    let q = Query::parse("Modal  \"Close   Button\" -outline -\"btn ghost\"");
+    assert_eq!(q.terms, ["modal"]);
+    assert_eq!(q.phrases, ["close button"]);
+    assert_eq!(q.excluded, ["outline", "btn ghost"]);
+    assert_eq!(q.highlight(), "modal close button");
+
+    // An unbalanced quote is an ordinary character.
+    let q = Query::parse("modal \"close");
+    assert_eq!(q.terms, ["modal", "\"close"]);
+    assert!(q.phrases.is_empty());
+    assert_eq!(Query::parse("  - "), Query::default());
+}
+
+#[test]
+fn phrases_must_be_contiguous_and_exclusions_drop() {
+    let docs = docs(&[
+        ("modal", "Press the close\n  button to dismiss.".into()),
+        ("drawer", "A button to close the drawer.".into()),
+        ("card", "Cards with an outline button.".into()),
+    ]);
+    let index = build(&docs);
+    let names = |q: &str| -> Vec<String> {
+        index
+            .rank(&Query::parse(q), &docs)
+            .into_iter()
+            .map(|(n, _)| n)
+            .collect()
+    };
+    // The phrase spans a line break in modal, and drawer has both words
+    // but not together.
+    assert_eq!(names("\"close button\""), ["modal"]);
+    assert_eq!(names("button -outline"), ["drawer", "modal"]);
+    // Only exclusions: everything else, by name.
+    assert_eq!(names("-drawer"), ["card", "modal"]);
+    assert_eq!(names("modal \"close"), ["drawer", "modal"]);
+    assert!(names("\"close the button\"").is_empty());
+}
+
+/// A small docs set where common words ("class", "style") fill every
+/// component, as they do in llms.txt.
+fn ambiguous() -> HashMap<String, String> {
+    docs(&[
+        (
+            "toggle",
+            "Toggle is a checkbox styled to look like a switch. Use the toggle class. style: toggle-primary".into(),
+        ),
+        (
+            "swap",
+            "Swap is a toggle between two elements; the toggle flips a rotate or flip effect. class swap-rotate, style swap-flip".into(),
+        ),
+        (
+            "checkbox",
+            "Checkbox lets the user select or toggle an option. class checkbox, style checkbox-primary".into(),
+        ),
+        (
+            "theme",
+            "Every class and style: class style class style class style class style, with themes applied to each class.".into(),
+        ),
+        (
+            "card",
+            "Card groups content. class card, style card-border, rotate the image for effect".into(),
+        ),
+    ])
+}
+
+#[test]
+fn rare_words_outweigh_common_ones() {
+    let docs = ambiguous();
+    let index = build(&docs);
+    assert_eq!(index.idf("class"), 0.0);
+    assert!(index.idf("rotate") > index.idf("toggle"));
+    assert_eq!(index.idf("nowhere"), 0.0);
+    let names = |q: &str| -> Vec<String> {
+        index
+            .rank(&Query::parse(q), &docs)
+            .into_iter()
+            .map(|(n, _)| n)
+            .collect()
+    };
+    assert_eq!(names("toggle")[..3], ["toggle", "swap", "checkbox"]);
+    // "theme" says "class" and "style" most, but both are in every doc.
+    assert_eq!(names("toggle class")[..3], ["toggle", "swap", "checkbox"]);
+    assert_ne!(names("toggle style")[0], "theme");
+    // Under flat scoring theme led this; now only the "rotate" docs match.
+    assert_eq!(names("rotate style"), ["card", "swap"]);
+}
+
+#[test]
+fn plurals_and_punctuation_share_a_bucket() {
+    let docs = docs(&[
+        (
+            "button",
+            "Buttons and a `button`. Classes: <a class=\"btn btn-primary\">Go</a>".into(),
+        ),
+        ("badge", "Badges, badge.".into()),
+        ("status", "The status class.".into()),
+    ]);
+    let index = build(&docs);
+    assert_eq!(index.frequency("button", "button"), 2);
+    assert_eq!(index.frequency("buttons", "button"), 2);
+    assert_eq!(index.frequency("`Buttons`,", "button"), 2);
+    assert_eq!(index.frequency("badges", "badge"), 2);
+    // "Classes" counts; the `class` attribute name inside the tag doesn't.
+    assert_eq!(index.frequency("class", "button"), 1);
+    assert_eq!(index.frequency("status", "status"), 1);
+    // `btn` only appears inside a quoted attribute.
+    assert_eq!(index.frequency("btn", "button"), 1);
+    assert_eq!(index.frequency("btn-primary", "button"), 1);
+
+    let names = |q: &str| -> Vec<String> {
+        index
+            .rank(&Query::parse(q), &docs)
+            .into_iter()
+            .map(|(n, _)| n)
+            .collect()
+    };
+    assert_eq!(names("buttons"), ["button"]);
+    assert_eq!(names("btn"), ["button"]);
+}
+
+#[test]
+fn repeated_queries_reuse_a_bounded_cache() {
+    let docs = docs(&[
+        ("one", "Badge ".repeat(3)),
+        ("two", "Badge avatar".into()),
+        ("three", "Avatar".into()),
+    ]);
+    let index = build(&docs);
+    let first = index.page(&Query::parse("badge"), &docs, index::PAGE_SIZE, 0);
+    let again = index.page(&Query::parse("  BADGE "), &docs, index::PAGE_SIZE, 0);
+    assert_eq!(first, again);
+    assert_eq!(index.cached(), 1);
+    assert_eq!(
+        index.page(&Query::parse("badge"), &docs, 1, 1).0,
+        first.0[1..]
+    );
+    assert_eq!(index.cached(), 1);
+
+    for n in 0..100 {
+        index.page(&Query::parse(&format!("badge{}", n)), &docs, 5, 0);
+    }
+    assert_eq!(index.cached(), index::CACHE_SIZE);
+    // The oldest queries were dropped; ranking again still gives the same
+    // answer.
+    assert_eq!(
+        index.page(&Query::parse("badge"), &docs, index::PAGE_SIZE, 0),
+        first
+    );
+    assert_eq!(index.cached(), index::CACHE_SIZE);
+}
+
+#[test]
+fn alias_keys_ignore_case_spaces_and_underscores() {
+    for text in [
+        "Button Group",
+        "button_group",
+        " button-group ",
+        "BUTTON--group",
+    ] {
+        assert_eq!(index::alias_key(text), "button-group", "{:?}", text);
+    }
+}
+
+#[test]
+fn an_aliased_component_ranks_first() {
+    let components: HashMap<String, String> = [
+        ("modal", "A box shown over the page."),
+        ("card", "A dialog-like dialog box with a dialog title."),
+        ("badge", "A small label."),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect();
+    let index = index::Index::build(components.iter().map(|(k, v)| (k.as_str(), v.as_str())))
+        .with_aliases(HashMap::from([
+            ("dialog".to_string(), "modal".to_string()),
+            ("chip".to_string(), "badge".to_string()),
+        ]));
+    let names = |q: &str| -> Vec<String> {
+        index
+            .rank(&Query::parse(q), &components)
+            .into_iter()
+            .map(|(n, _)| n)
+            .collect()
+    };
+    assert_eq!(names("dialog"), ["modal", "card"]);
+    assert_eq!(names("Chip"), ["badge"]);
+    assert_eq!(
+        index.aliased(&Query::parse("dialog chip")),
+        [
+            ("dialog".to_string(), "modal".to_string()),
+            ("chip".to_string(), "badge".to_string()),
+        ]
+    );
+    assert_eq!(names("dialog -box"), Vec::<String>::new());
+}
+
+#[test]
+fn markup_urls_and_stopwords_stay_out_of_the_index() {
+    let docs = docs(&[
+        (
+            "card",
+            "The card has a title. [card docs](https://daisyui.com/components/card/)\n```html\n<div class=\"card bg-base-100\"><img src=\"https://img.daisyui.com/photo.webp\" alt=\"Shoes\" /></div>\n```".into(),
+        ),
+    ]);
+    let index = build(&docs);
+    for noise in [
+        "div", "img", "src", "alt", "https", "daisyui", "webp", "html", "the", "has",
+    ] {
+        assert_eq!(index.frequency(noise, "card"), 0, "{}", noise);
+    }
+    for kept in ["card", "title", "docs", "bg-base-100", "shoes"] {
+        assert!(index.frequency(kept, "card") > 0, "{}", kept);
+    }
+}
+
+#[test]
+fn the_bundled_docs_index_only_meaningful_words() {
+    let text = include_str!("../../src/llms.txt");
+    let sections: HashMap<String, String> = text
+        .split("\n### ")
+        .skip(1)
+        .filter_map(|s| {
+            let (name, body) = s.split_once('\n')?;
+            Some((name.trim().to_string(), body.to_string()))
+        })
+        .collect();
+    let index = build(&sections);
+    // 493 distinct terms before markup, URLs, numbers and stopwords were
+    // dropped.
+    assert!(index.vocabulary() <= 440, "{}", index.vocabulary());
+    for noise in ["div", "https", "the", "and", "onclick", "100"] {
+        assert_eq!(index.idf(noise), 0.0, "{}", noise);
+    }
+    let first = |q: &str| {
+        index
+            .rank(&Query::parse(q), &sections)
+            .first()
+            .map(|(n, _)| n.clone())
+    };
+    assert_eq!(first("sticky navbar").as_deref(), Some("navbar"));
+    assert_eq!(first("drawer sidebar").as_deref(), Some("drawer"));
+    assert_eq!(first("modal-box").as_deref(), Some("modal"));
+}
@@ -0,0 +1,213 @@
+//! Askama and Tera templates made from generated layouts.
+
+#[path = "../../src/frameworks.rs"]
+#[allow(dead_code)] // jinja depends on it
+mod frameworks;
+#[path = "../../src/html.rs"]
+#[allow(dead_code)] // frameworks depend on it
+mod html;
+#[path = "../../src/jinja.rs"]
+mod jinja;
+
+use jinja::{Engine, template};
+
+/// Loop variables of `{% for %}` blocks, checking each closes in order and
+/// every `{{ }}` reads the title or an open loop's variable.
+fn loops(text: &str) -> Vec<String> {
+    let mut open: Vec<String> = Vec::new();
+    let mut seen = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        rest = &rest[start..];
+        let (close, body) = match &rest[..2.min(rest.len())] {
+            "{%" => ("%}", true),
+            "{{" => ("}}", false),
+            _ => {
+                rest = &rest[1..];
+                continue;
+            }
+        };
+        let end = rest.find(close).expect("unclosed tag");
+        let inner: Vec<&str> = rest[2..end].split_whitespace().collect();
+        match (body, inner.as_slice()) {
+            (true, ["for", var, "in", list]) => {
+                let root = list.split('.').next().unwrap_or_default();
+                assert!(
+                    !list.contains('.') || open.iter().any(|v| v == root),
+                    "{} reads an unknown variable",
+                    list
+                );
+                open.push(var.to_string());
+                seen.push(var.to_string());
+            }
+            (true, ["endfor"]) => {
+                assert!(open.pop().is_some(), "endfor without a for");
+            }
+            (true, ["raw"]) => {
+                let skip = rest.find("{% endraw %}").expect("unclosed raw block");
+                rest = &rest[skip + 12..];
+                continue;
+            }
+            (false, [value]) => {
+                let root = value.split('.').next().unwrap_or_default();
+                assert!(
+                    root == "title" || open.iter().any(|v| v == root),
+                    "{} isn't in scope",
+                    value
+                );
+            }
+            other => panic!("unexpected tag {:?}", other),
+        }
+        rest = &rest[end + 2..];
+    }
+    assert!(open.is_empty(), "unclosed loops: {:?}", open);
+    seen
+}
+
+/// The template without the context comment.
+fn body(text: &str) -> &str {
+    text.split_once("#}\n").map_or(text, |(_, body)| body)
+}
+
+const TABLE: &str = r#"<table class="table">
+  <thead><tr><th>Name</th><th>Status</th></tr></thead>
+  <tbody>
+    <tr>
+      <td>Ada</td>
+      <td><span class="badge badge-success" aria-label="Ada is active">Active</span></td>
+    </tr>
+    <tr>
+      <td>Grace</td>
+      <td><span class="badge badge-warning" aria-label="Grace is active">Away</span></td>
+    </tr>
+  </tbody>
+</table>"#;
+
+#[test]
+fn repeated_rows_become_one_loop_named_by_column() {
+    let text = template(TABLE, "Team", Engine::Tera);
+    assert_eq!(loops(&text), ["column", "row"]);
+    assert!(body(&text).contains(
+        r#"    {% for row in rows %}
+    <tr>
+      <td>{{ row.name }}</td>
+      <td><span class="badge {{ row.status_class }}" aria-label="{{ row.name }} is active">{{ row.status }}</span></td>
+    </tr>
+    {% endfor %}
+  </tbody>"#
+    ));
+    assert!(text.contains("    rows: list of { name, status_class, status }"));
+    assert_eq!(body(&text).matches("<tr>").count(), 2);
+}
+
+#[test]
+fn nested_runs_become_nested_loops() {
+    let html = r#"<footer>
+  <nav>
+    <header class="footer-title">Services</header>
+    <a class="link">Branding</a>
+    <a class="link">Design</a>
+  </nav>
+  <!-- Company -->
+  <nav>
+    <header class="footer-title">Company</header>
+    <a class="link">About</a>
+  </nav>
+</footer>"#;
+    let text = template(html, "", Engine::Tera);
+    assert_eq!(loops(&text), ["section", "link"]);
+    assert_eq!(
+        body(&text),
+        r#"<footer>
+  {% for section in sections %}
+  <nav>
+    <header class="footer-title">{{ section.title }}</header>
+    {% for link in section.links %}
+    <a class="link">{{ link.label }}</a>
+    {% endfor %}
+  </nav>
+  {% endfor %}
+</footer>"#
+    );
+    assert!(text.contains("sections: list of { title, links: list of { label } }"));
+
+    // Inline runs stay on their line.
+    let inline = template("<ul><li>A</li><li>B</li></ul>", "", Engine::Tera);
+    assert_eq!(
+        body(&inline),
+        "<ul>{% for item in items %}<li>{{ item.text }}</li>{% endfor %}</ul>"
+    );
+}
+
+#[test]
+fn different_siblings_and_svg_parts_stay_apart() {
+    let html = r#"<div><h2>One</h2><p>Two</p><button class="btn">A</button><button class="link">B</button></div>
+<svg><path d="M1" /><path d="M2" /></svg>"#;
+    let text = template(html, "", Engine::Tera);
+    assert!(loops(&text).is_empty());
+    assert_eq!(body(&text), html);
+    assert!(text.starts_with("{# Tera template with no context"));
+}
+
+#[test]
+fn the_title_is_interpolated_as_a_whole_word() {
+    let html =
+        "<h1>Acme</h1>\n<p>Acme Corp and Acmeville</p>\n<script>const name = 'Acme';</script>";
+    let text = template(html, "Acme", Engine::Tera);
+    assert_eq!(
+        body(&text),
+        "<h1>{{ title }}</h1>\n<p>{{ title }} Corp and Acmeville</p>\n<script>const name = 'Acme';</script>"
+    );
+    assert!(text.contains("    title: string"));
+
+    // Text an engine would read as a tag is kept raw.
+    let raw = template("<code>{{ name }}</code>", "", Engine::Tera);
+    assert_eq!(body(&raw), "<code>{% raw %}{{ name }}{% endraw %}</code>");
+    loops(&raw);
+}
+
+#[test]
+fn askama_context_is_a_struct_per_list() {
+    let html = r#"<form>
+  <div class="form-control"><label for="f-name">Name</label><input id="f-name" type="text" /></div>
+  <div class="form-control"><label for="f-email">Email</label><input id="f-email" type="email" /></div>
+  <select><option>One</option><option>Two</option></select>
+</form>"#;
+    let text = template(html, "Sign up", Engine::Askama);
+    assert!(
+        text.starts_with(
+            r#"{#
+  Askama context for this template:
+
+  #[derive(Template)]
+  #[template(path = "sign_up.html")]
+  struct SignUp {
+      fields: Vec<Field>,
+      choices: Vec<Choice>,
+  }
+
+  struct Field {
+      id: String,
+      label: String,
+      type_: String,
+  }
+
+  struct Choice {
+      label: String,
+  }
+#}
+"#
+        ),
+        "{}",
+        text
+    );
+    assert!(body(&text).contains(r#"<label for="f-{{ field.id }}">{{ field.label }}</label><input id="f-{{ field.id }}" type="{{ field.type_ }}" />"#));
+    assert_eq!(loops(&text), ["field", "choice"]);
+}
+
+#[test]
+fn engines_parse_by_name() {
+    assert_eq!(Engine::parse("Askama"), Some(Engine::Askama));
+    assert_eq!(Engine::parse(" tera "), Some(Engine::Tera));
+    assert_eq!(Engine::parse("jinja"), None);
+}
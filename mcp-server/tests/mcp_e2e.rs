@@ -0,0 +1,3808 @@
+//! Drives the compiled MCP server over stdio the way a client does:
+//! initialize → initialized → tools/list → one call per listed tool →
+//! shutdown → exit.
+//!
+//! Tools are discovered from `tools/list` and called with arguments
+//! synthesized from their input schema, so new tools are exercised without
+//! touching this file.
+
+use serde_json::{Map, Value, json};
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, Command, ExitStatus, Stdio};
+use std::sync::mpsc::{Receiver, RecvTimeoutError, channel};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+struct Server {
+    child: Child,
+    stdin: Option<ChildStdin>,
+    lines: Receiver<String>,
+    next_id: u64,
+}
+
+impl Server {
+    fn spawn() -> Self {
+        Self::spawn_with(&[])
+    }
+
+    fn spawn_with(args: &[&str]) -> Self {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_daisy_days"))
+            .args(args)
+            .env_remove("DAISY_DAYS_LAYOUTS")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("failed to spawn daisy_days");
+        let stdin = child.stdin.take();
+        let stdout = child.stdout.take().expect("no stdout");
+        let (tx, lines) = channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else { break };
+                if tx.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        Self {
+            child,
+            stdin,
+            lines,
+            next_id: 1,
+        }
+    }
+
+    fn send(&mut self, message: &Value) {
+        let stdin = self.stdin.as_mut().expect("stdin already closed");
+        writeln!(stdin, "{}", message).expect("failed to write to server");
+        stdin.flush().expect("failed to flush server stdin");
+    }
+
+    fn notify(&mut self, method: &str) {
+        self.send(&json!({ "jsonrpc": "2.0", "method": method }));
+    }
+
+    /// Sends a request and returns its response. Every stdout line must be
+    /// JSON-RPC for the id just sent; anything else is stray output.
+    fn request(&mut self, method: &str, params: Value) -> Value {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.send(&json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }));
+        let line = match self.lines.recv_timeout(TIMEOUT) {
+            Ok(line) => line,
+            Err(RecvTimeoutError::Timeout) => {
+                panic!("no response to {} within {:?}", method, TIMEOUT)
+            }
+            Err(RecvTimeoutError::Disconnected) => panic!("server exited during {}", method),
+        };
+        let response: Value = serde_json::from_str(&line)
+            .unwrap_or_else(|e| panic!("stdout line is not JSON ({}): {}", e, line));
+        assert_eq!(response["jsonrpc"], "2.0", "bad envelope: {}", line);
+        assert_eq!(
+            response["id"],
+            json!(id),
+            "response id mismatch for {} (stray or late output?): {}",
+            method,
+            line
+        );
+        response
+    }
+
+    fn result(&mut self, method: &str, params: Value) -> Value {
+        let response = self.request(method, params);
+        assert!(
+            response.get("error").is_none(),
+            "{} failed: {}",
+            method,
+            response["error"]
+        );
+        response["result"].clone()
+    }
+
+    /// Sends `shutdown` and `exit` with stdin still open, checks the server
+    /// exits cleanly, and returns whatever it printed after the reply.
+    fn shutdown(mut self) -> Vec<String> {
+        let result = self.result("shutdown", Value::Null);
+        assert!(result.is_null(), "shutdown returned {}", result);
+        self.notify("exit");
+        let status = self.wait();
+        assert!(status.success(), "server exited with {}", status);
+        self.lines.try_iter().collect()
+    }
+
+    /// Waits for the server to exit on its own, killing it after
+    /// [`TIMEOUT`].
+    fn wait(&mut self) -> ExitStatus {
+        let deadline = Instant::now() + TIMEOUT;
+        loop {
+            if let Some(status) = self.child.try_wait().expect("failed to wait for server") {
+                return status;
+            }
+            if Instant::now() > deadline {
+                let _ = self.child.kill();
+                panic!("server still running {:?} after exit", TIMEOUT);
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+}
+
+/// A value that satisfies `schema`, preferring realistic samples so tools
+/// do real work rather than hitting their fallbacks.
+fn sample(name: &str, schema: &Value, component: &str) -> Value {
+    if let Some(first) = schema["enum"].as_array().and_then(|e| e.first()) {
+        return first.clone();
+    }
+    match schema["type"].as_str().unwrap_or("string") {
+        "boolean" => json!(false),
+        "integer" | "number" => json!(3),
+        "array" => match name {
+            "group" | "tags" | "suggestions" | "states" => json!(["alpha", "beta"]),
+            "sections" => json!(["navbar", "hero", "footer"]),
+            _ => json!([]),
+        },
+        "object" => json!({}),
+        _ if name == "comment" => json!(format!(
+            r#"<!-- daisy-days {} daisyui_idea_to_ui seed=0 {{"prompt":"a blog"}} -->"#,
+            env!("CARGO_PKG_VERSION")
+        )),
+        _ => json!(match name {
+            "component" => component,
+            "prompt" => "a kanban board for my team",
+            "query" => "button",
+            "concept" | "first" => "glassmorphism",
+            "second" => "neumorphism",
+            "example" => "navbar",
+            "shortcut" => "mod+k",
+            "class" => "join-item",
+            "source" => "Ada Lovelace",
+            "palette" | "theme" => "dark",
+            "css" => "--color-primary: #570df8; --color-base-100: #ffffff;",
+            "title" => "E2E",
+            _ => "test",
+        }),
+    }
+}
+
+/// Every required property plus optional enums (so the first variant is
+/// exercised too), or every property when `all` is set.
+fn synthesize_args(schema: &Value, component: &str, all: bool) -> Value {
+    let required: Vec<&str> = schema["required"]
+        .as_array()
+        .map(|r| r.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    let mut args = Map::new();
+    if let Some(props) = schema["properties"].as_object() {
+        for (name, prop) in props {
+            if all || required.contains(&name.as_str()) || prop.get("enum").is_some() {
+                args.insert(name.clone(), sample(name, prop, component));
+            }
+        }
+    }
+    Value::Object(args)
+}
+
+fn text_of(result: &Value) -> String {
+    result["content"]
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|c| c["text"].as_str())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .unwrap_or_default()
+}
+
+#[test]
+fn stdio_session_exercises_every_tool() {
+    let mut server = Server::spawn();
+
+    let init = server.result(
+        "initialize",
+        json!({
+            "protocolVersion": "2024-11-05",
+            "capabilities": {},
+            "clientInfo": { "name": "e2e", "version": "0" }
+        }),
+    );
+    assert!(init["serverInfo"]["name"].is_string(), "{}", init);
+    assert!(init["capabilities"]["tools"].is_object(), "{}", init);
+
+    // No reply may be sent for a notification; the next line read must be
+    // the tools/list response.
+    server.notify("notifications/initialized");
+    let tools = server.result("tools/list", json!({}));
+    let tools = tools["tools"].as_array().expect("tools/list has no tools");
+    assert!(!tools.is_empty());
+
+    let components = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_list_components", "arguments": {} }),
+    );
+    let component = text_of(&components)
+        .lines()
+        .last()
+        .and_then(|l| l.split(", ").next())
+        .unwrap_or("button")
+        .to_string();
+
+    let mut failures = Vec::new();
+    for tool in tools {
+        let name = tool["name"].as_str().expect("tool without a name");
+        assert!(
+            tool["description"].is_string(),
+            "{} has no description",
+            name
+        );
+        assert_eq!(
+            tool["inputSchema"]["type"], "object",
+            "{} input schema is not an object",
+            name
+        );
+        // Some tools need one of several optional arguments ('theme' or
+        // 'css', 'source' or 'group'); retry with everything filled in.
+        let mut args = synthesize_args(&tool["inputSchema"], &component, false);
+        let mut response = server.request(
+            "tools/call",
+            json!({ "name": name, "arguments": args.clone() }),
+        );
+        if response["error"]["code"] == -32602 {
+            args = synthesize_args(&tool["inputSchema"], &component, true);
+            response = server.request(
+                "tools/call",
+                json!({ "name": name, "arguments": args.clone() }),
+            );
+        }
+        if let Some(error) = response.get("error") {
+            failures.push(format!("{} {}: {}", name, args, error));
+            continue;
+        }
+        if text_of(&response["result"]).trim().is_empty() {
+            failures.push(format!("{} {}: empty content", name, args));
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "tool calls failed:\n{}",
+        failures.join("\n")
+    );
+
+    let stray = server.shutdown();
+    assert!(stray.is_empty(), "unexpected output after EOF: {:?}", stray);
+}
+
+#[test]
+fn unknown_methods_and_bad_json_get_errors_not_silence() {
+    let mut server = Server::spawn();
+    let response = server.request("no/such/method", json!({}));
+    assert!(response["error"]["code"].is_i64(), "{}", response);
+
+    server.send(&json!("not a request"));
+    let line = server
+        .lines
+        .recv_timeout(TIMEOUT)
+        .expect("no reply to malformed request");
+    let parse_error: Value = serde_json::from_str(&line).expect("parse error reply is not JSON");
+    assert_eq!(parse_error["error"]["code"], -32700);
+
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn shutdown_refuses_requests_until_exit() {
+    let mut server = Server::spawn();
+    assert!(server.result("shutdown", Value::Null).is_null());
+    let response = server.request("tools/list", json!({}));
+    assert_eq!(response["error"]["code"], -32600, "{}", response);
+    assert_eq!(
+        response["error"]["message"],
+        "Server is shutting down; only exit is accepted"
+    );
+    server.notify("exit");
+    assert!(server.wait().success());
+
+    // Exit without a shutdown first is an error exit.
+    let mut server = Server::spawn();
+    server.result("tools/list", json!({}));
+    server.notify("exit");
+    assert_eq!(server.wait().code(), Some(1));
+
+    // Clients that just close stdin still get a clean exit.
+    let mut server = Server::spawn();
+    server.result("tools/list", json!({}));
+    drop(server.stdin.take());
+    assert!(server.wait().success());
+}
+
+#[test]
+fn explain_idea_reports_scores_and_decision() {
+    let mut server = Server::spawn();
+    let result = server.result(
+        "tools/call",
+        json!({
+            "name": "daisyui_explain_idea",
+            "arguments": { "prompt": "a playful sprint board for tasks called acme" }
+        }),
+    );
+    let text = text_of(&result);
+    assert!(
+        text.contains("| kanban ✓ | board (1), task (2), sprint (2) | 5 |"),
+        "{}",
+        text
+    );
+    assert!(text.contains("**Title:** Acme"), "{}", text);
+    assert!(text.contains("**Theme:** `cupcake`"), "{}", text);
+    assert!(
+        !text.contains("<div"),
+        "explanation must not include HTML: {}",
+        text
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+/// Page HTML and summary line from `daisyui_component_playground`.
+fn playground(server: &mut Server, component: &str) -> (String, String) {
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_component_playground", "arguments": { "component": component } }),
+    );
+    let content = result["content"].as_array().expect("no content");
+    let text = |i: usize| content[i]["text"].as_str().unwrap_or_default().to_string();
+    (text(0), text(1))
+}
+
+#[test]
+fn playground_matrices_label_every_cell() {
+    let mut server = Server::spawn();
+
+    // 9 color rows × 5 sizes per style; default + outline fit under the cap.
+    let (html, summary) = playground(&mut server, "button");
+    assert!(html.starts_with("<!DOCTYPE html>"), "{}", html);
+    assert_eq!(html.matches("<td>").count(), 90);
+    assert!(
+        html.contains(
+            r#"<code class="text-xs opacity-70">btn btn-outline btn-primary btn-lg</code>"#
+        )
+    );
+    assert!(html.contains(r#"<button class="btn btn-outline btn-primary btn-lg">"#));
+    assert!(
+        summary.contains("omitted styles: `btn-dash`, `btn-soft`, `btn-ghost`, `btn-link`"),
+        "{}",
+        summary
+    );
+
+    let (html, _) = playground(&mut server, "badge");
+    assert_eq!(html.matches("<td>").count(), 90);
+    assert!(html.contains(r#"<span class="badge badge-error badge-xs">"#));
+
+    // No sizes: one column, 5 color rows × 4 styles, nothing truncated.
+    let (html, summary) = playground(&mut server, "alert");
+    assert_eq!(html.matches("<td>").count(), 20);
+    assert!(html.contains(r#"class="alert alert-soft alert-warning""#));
+    assert_eq!(summary, "20 cells for `alert`.");
+
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_component_playground", "arguments": { "component": "no-such" } }),
+    );
+    assert_eq!(response["error"]["code"], -32602);
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn reverse_compose_recovers_generated_layout_sections() {
+    let mut server = Server::spawn();
+    for (layout, expected) in [
+        (
+            "saas",
+            r#""sections": ["navbar", "hero", "logo-cloud", "features", "testimonials", "pricing", "footer"]"#,
+        ),
+        ("dashboard", r#""sections": ["navbar", "sidebar"]"#),
+        ("pricing", r#""sections": ["navbar", "hero", "pricing"#),
+    ] {
+        let generated = server.result(
+            "tools/call",
+            json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": layout } }),
+        );
+        let html = generated["content"][0]["text"].as_str().unwrap_or_default();
+        let result = server.result(
+            "tools/call",
+            json!({ "name": "daisyui_reverse_compose", "arguments": { "html": html } }),
+        );
+        let spec = result["content"][1]["text"].as_str().unwrap_or_default();
+        assert!(spec.contains(expected), "{}: {}", layout, spec);
+    }
+
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_reverse_compose", "arguments": {} }),
+    );
+    assert_eq!(response["error"]["code"], -32602);
+    assert!(server.shutdown().is_empty());
+}
+
+/// Landmark problems in a generated layout: not exactly one `<main>`, no
+/// `<nav>`, or a `<button>` with no accessible text. Text inside an
+/// `aria-hidden` element doesn't count; an `aria-label` does.
+fn landmark_problems(html: &str) -> Vec<String> {
+    let mut problems = Vec::new();
+    let mains = html.matches("<main").count();
+    if mains != 1 {
+        problems.push(format!("{} <main> elements", mains));
+    }
+    if !html.contains("<nav") {
+        problems.push("no <nav>".to_string());
+    }
+    let mut rest = html;
+    while let Some(start) = rest.find("<button") {
+        let open_end = start + rest[start..].find('>').unwrap() + 1;
+        let close = open_end + rest[open_end..].find("</button>").unwrap();
+        let open = &rest[start..open_end];
+        let labelled = open
+            .split(" aria-label=\"")
+            .nth(1)
+            .is_some_and(|label| !label.starts_with('"'));
+        if !labelled && !spoken_text(&rest[open_end..close]) {
+            problems.push(format!("unlabelled {}", open));
+        }
+        rest = &rest[close..];
+    }
+    problems
+}
+
+/// Whether markup has text outside its tags and `aria-hidden` elements.
+fn spoken_text(markup: &str) -> bool {
+    let mut rest = markup;
+    while let Some(lt) = rest.find('<') {
+        if !rest[..lt].trim().is_empty() {
+            return true;
+        }
+        let gt = lt + rest[lt..].find('>').unwrap();
+        let tag = &rest[lt..=gt];
+        rest = &rest[gt + 1..];
+        if tag.contains("aria-hidden=\"true\"") && !tag.ends_with("/>") {
+            let name: String = tag[1..]
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric())
+                .collect();
+            let end = format!("</{}>", name);
+            rest = rest.find(&end).map_or("", |at| &rest[at + end.len()..]);
+        }
+    }
+    !rest.trim().is_empty()
+}
+
+#[test]
+fn the_layout_slash_command_lists_every_layout() {
+    let manifest = include_str!("../../extension.toml");
+    let description = manifest
+        .split("[slash_commands.daisy-layout]")
+        .nth(1)
+        .and_then(|rest| rest.lines().find_map(|l| l.strip_prefix("description = ")))
+        .unwrap()
+        .trim_matches('"');
+    // The extension's /daisy-help entry repeats the manifest's wording.
+    assert!(
+        include_str!("../../src/lib.rs").contains(&format!("description: \"{}\"", description))
+    );
+    let listed: Vec<&str> = description
+        .split_once('(')
+        .and_then(|(_, rest)| rest.strip_suffix(')'))
+        .unwrap()
+        .split(", ")
+        .collect();
+    let mut server = Server::spawn();
+    let tools = server.result("tools/list", json!({}));
+    let scaffold = tools["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|t| t["name"] == "daisyui_scaffold_layout")
+        .unwrap()
+        .clone();
+    let layouts: Vec<&str> = scaffold["inputSchema"]["properties"]["layout"]["enum"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|l| l.as_str().unwrap())
+        .collect();
+    assert_eq!(listed, layouts);
+}
+
+#[test]
+fn every_layout_has_one_main_a_nav_and_no_a11y_hints() {
+    let mut server = Server::spawn();
+    let tools = server.result("tools/list", json!({}));
+    let scaffold = tools["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|t| t["name"] == "daisyui_scaffold_layout")
+        .unwrap()
+        .clone();
+    let layouts = scaffold["inputSchema"]["properties"]["layout"]["enum"]
+        .as_array()
+        .unwrap();
+    assert!(layouts.len() > 20);
+    for layout in layouts {
+        let layout = layout.as_str().unwrap();
+        let html = first_text(
+            &mut server,
+            "daisyui_scaffold_layout",
+            json!({ "layout": layout, "title": "Acme" }),
+        );
+        let problems = landmark_problems(&html);
+        assert!(problems.is_empty(), "{}: {:?}", layout, problems);
+        for purity in ["tailwind", "daisy-only"] {
+            let html = first_text(
+                &mut server,
+                "daisyui_scaffold_layout",
+                json!({ "layout": layout, "title": "Acme", "purity": purity }),
+            );
+            let hints = first_text(&mut server, "daisyui_a11y_hints", json!({ "html": html }));
+            assert!(
+                hints.contains("No problems found"),
+                "{} ({}): {}",
+                layout,
+                purity,
+                hints
+            );
+        }
+    }
+
+    assert_eq!(
+        landmark_problems(
+            r#"<div><button class="btn btn-ghost"><svg aria-hidden="true"><path d="M0" /></svg></button></div>"#
+        ),
+        [
+            "0 <main> elements",
+            "no <nav>",
+            r#"unlabelled <button class="btn btn-ghost">"#
+        ]
+    );
+    assert!(spoken_text(r#"<span aria-hidden="true">✏️</span> Compose"#));
+    assert!(!spoken_text(r#"<span aria-hidden="true">✏️</span>"#));
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn template_layouts_join_the_registry() {
+    let dir = std::env::temp_dir().join(format!("daisy-days-layouts-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("failed to create template dir");
+    for (name, text) in [
+        (
+            "crew.html",
+            "---\nkey: crew\ntitle: Crew page\ntags: crew, roster\n---\n<main data-page=\"crew\"><h1>{{title}}</h1><input id=\"{{id:drawer}}\" /></main>",
+        ),
+        ("shadow.html", "---\nkey: blog\n---\n<p>never used</p>"),
+        ("broken.html", "---\nkey: broken\n---\n<p>{{nope}}</p>"),
+        ("notes.txt", "---\nkey: notes\n---\n<p>not html</p>"),
+    ] {
+        std::fs::write(dir.join(name), text).expect("failed to write template");
+    }
+    let mut server = Server::spawn_with(&["--layouts", dir.to_str().unwrap()]);
+
+    let tools = server.result("tools/list", json!({}));
+    let scaffold = tools["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|t| t["name"] == "daisyui_scaffold_layout")
+        .expect("no scaffold tool");
+    let layouts = &scaffold["inputSchema"]["properties"]["layout"]["enum"];
+    assert_eq!(layouts.as_array().unwrap().last(), Some(&json!("crew")));
+    for missing in ["broken", "notes"] {
+        assert!(
+            !layouts.as_array().unwrap().contains(&json!(missing)),
+            "{}",
+            layouts
+        );
+    }
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "crew", "title": "<Ops>" } }),
+    );
+    let html = text_of(&result);
+    assert!(html.contains("<h1>&lt;Ops&gt;</h1>"), "{}", html);
+    assert!(html.contains(r#"id="dd-drawer-1""#), "{}", html);
+
+    // A template can't replace a built-in.
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "blog" } }),
+    );
+    assert!(!text_of(&result).contains("never used"));
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_idea_to_ui", "arguments": { "prompt": "a roster for my crew" } }),
+    );
+    assert!(text_of(&result).contains(r#"data-page="crew""#));
+
+    assert!(server.shutdown().is_empty());
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn manifest_covers_every_tool_layout_and_concept() {
+    let mut server = Server::spawn();
+    let tools = server.result("tools/list", json!({}));
+    let tools = tools["tools"].as_array().unwrap().clone();
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_manifest", "arguments": {} }),
+    );
+    let manifest: Value = serde_json::from_str(&text_of(&result)).expect("manifest is not JSON");
+    let names = |key: &str| -> Vec<String> {
+        manifest[key]
+            .as_array()
+            .unwrap_or_else(|| panic!("manifest has no {}", key))
+            .iter()
+            .filter_map(|v| v["name"].as_str().map(String::from))
+            .collect()
+    };
+    assert_eq!(manifest["version"], env!("CARGO_PKG_VERSION"));
+
+    let listed = names("tools");
+    for tool in &tools {
+        let name = tool["name"].as_str().unwrap();
+        assert!(listed.iter().any(|n| n == name), "tool {} missing", name);
+    }
+
+    let layouts = names("layouts");
+    let scaffold = tools
+        .iter()
+        .find(|t| t["name"] == "daisyui_scaffold_layout")
+        .unwrap();
+    for layout in scaffold["inputSchema"]["properties"]["layout"]["enum"]
+        .as_array()
+        .unwrap()
+    {
+        let layout = layout.as_str().unwrap();
+        assert!(
+            layouts.iter().any(|l| l == layout),
+            "layout {} missing",
+            layout
+        );
+    }
+    assert!(
+        manifest["layouts"][0]["sections"]
+            .as_array()
+            .is_some_and(|s| !s.is_empty())
+    );
+
+    let concepts = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_list_concepts", "arguments": {} }),
+    );
+    let listed = names("concepts");
+    for concept in concept_keys(&text_of(&concepts)) {
+        assert!(
+            listed.contains(&concept),
+            "concept {} missing from {:?}",
+            concept,
+            listed
+        );
+    }
+
+    let components = names("components");
+    assert!(components.iter().any(|c| c == "button"), "{:?}", components);
+    assert!(
+        manifest["scripts"]
+            .as_array()
+            .is_some_and(|s| !s.is_empty())
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+/// First content item of a tool call.
+fn first_text(server: &mut Server, name: &str, arguments: Value) -> String {
+    let result = server.result(
+        "tools/call",
+        json!({ "name": name, "arguments": arguments }),
+    );
+    result["content"][0]["text"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[test]
+fn provenance_stamps_reproduce_byte_for_byte() {
+    let mut server = Server::spawn();
+    for (tool, arguments) in [
+        (
+            "daisyui_scaffold_layout",
+            json!({ "layout": "docs", "title": "Ops -- <Guide>", "theme": "dracula", "id_prefix": "ops", "annotate_responsive": true, "provenance": true }),
+        ),
+        (
+            "daisyui_scaffold_layout",
+            json!({ "layout": "auth", "purity": "daisy-only", "provenance": true }),
+        ),
+        (
+            "daisyui_scaffold_layout",
+            json!({ "layout": "store", "seed": 42, "provenance": true }),
+        ),
+        (
+            "daisyui_idea_to_ui",
+            json!({ "prompt": "a calm \"Field Notes\" blog", "provenance": true }),
+        ),
+    ] {
+        let stamped = first_text(&mut server, tool, arguments.clone());
+        let comment = stamped.lines().next().unwrap_or_default().to_string();
+        assert!(
+            comment.starts_with("<!-- daisy-days ") && comment.ends_with(" -->"),
+            "{}",
+            comment
+        );
+        assert_eq!(comment.matches("--").count(), 2, "{}", comment);
+        assert!(comment.contains(tool), "{}", comment);
+
+        // Same options, same line: the serialization is canonical.
+        assert_eq!(first_text(&mut server, tool, arguments), stamped);
+
+        for input in [comment.as_str(), stamped.as_str()] {
+            let reproduced = first_text(
+                &mut server,
+                "daisyui_reproduce",
+                json!({ "comment": input }),
+            );
+            assert_eq!(reproduced, stamped, "{} did not reproduce", tool);
+        }
+    }
+
+    let stale = r#"<!-- daisy-days 0.0.1 daisyui_scaffold_layout seed=0 {"layout":"saas"} -->"#;
+    for (comment, expected) in [
+        (stale, "install 0.0.1"),
+        ("<div>no stamp</div>", "No daisy-days provenance comment"),
+    ] {
+        let response = server.request(
+            "tools/call",
+            json!({ "name": "daisyui_reproduce", "arguments": { "comment": comment } }),
+        );
+        assert_eq!(response["error"]["code"], -32602, "{}", response);
+        let message = response["error"]["message"].as_str().unwrap_or_default();
+        assert!(message.contains(expected), "{}", message);
+    }
+    assert!(server.shutdown().is_empty());
+}
+
+/// Product names on a generated store page.
+fn products(html: &str) -> Vec<String> {
+    html.split(r#"<h3 class="card-title">"#)
+        .skip(1)
+        .filter_map(|rest| rest.split('<').next())
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+fn sample_content_follows_the_seed_or_the_title() {
+    let mut server = Server::spawn();
+    let mut layout =
+        |arguments: Value| first_text(&mut server, "daisyui_scaffold_layout", arguments);
+    for name in ["blog", "social", "kanban", "inbox", "store", "jobs"] {
+        let acme = layout(json!({ "layout": name, "title": "Acme" }));
+        assert_eq!(
+            layout(json!({ "layout": name, "title": "Acme" })),
+            acme,
+            "{}",
+            name
+        );
+        let globex = layout(json!({ "layout": name, "title": "Globex" }));
+        assert_ne!(globex.replace("Globex", "Acme"), acme, "{}", name);
+    }
+
+    let seeded = layout(json!({ "layout": "store", "title": "Acme", "seed": 7 }));
+    let renamed = layout(json!({ "layout": "store", "title": "Globex", "seed": 7 }));
+    assert_eq!(products(&seeded).len(), 3, "{}", seeded);
+    assert_eq!(products(&seeded), products(&renamed));
+    assert_eq!(renamed.replace("Globex", "Acme"), seeded);
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn counts_set_how_many_items_repeat() {
+    let mut server = Server::spawn();
+    let mut layout =
+        |arguments: Value| first_text(&mut server, "daisyui_scaffold_layout", arguments);
+    let store = layout(json!({ "layout": "store", "counts": { "cards": 12 } }));
+    assert_eq!(store.matches("Add to Cart").count(), 12);
+    assert_eq!(products(&store).len(), 12);
+    let kanban = layout(json!({ "layout": "kanban", "counts": { "columns": 5, "cards": 2 } }));
+    assert_eq!(kanban.matches("aria-labelledby=\"dd-lane-").count(), 5);
+    assert!(kanban.contains("<!-- Lane: Testing -->"), "{}", kanban);
+    let jobs = layout(json!({ "layout": "jobs", "counts": { "cards": 1 } }));
+    assert!(jobs.contains("1 job found"), "{}", jobs);
+    let inbox = layout(json!({ "layout": "inbox", "counts": { "messages": 9 } }));
+    assert!(inbox.contains("Yesterday"));
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "blog", "counts": { "posts": 80, "cards": 3 } } }),
+    );
+    let notes: Vec<&str> = result["content"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|c| c["text"].as_str())
+        .collect();
+    assert_eq!(notes.len(), 3, "{:?}", notes);
+    assert_eq!(
+        notes[0],
+        "Ignored count 'cards': the blog layout reads posts."
+    );
+    assert_eq!(
+        notes[1],
+        "Count 'posts' must be between 1 and 50, so 50 was used."
+    );
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "store", "counts": { "cards": -2, "columns": 2.5, "posts": "4" } } }),
+    );
+    let notes: Vec<&str> = result["content"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|c| c["text"].as_str())
+        .collect();
+    assert_eq!(
+        notes[..3],
+        [
+            "Ignored count 'cards': -2 is not a whole number of items.",
+            "Ignored count 'columns': 2.5 is not a whole number of items.",
+            "Ignored count 'posts': \"4\" is not a whole number of items.",
+        ],
+        "{:?}",
+        notes
+    );
+    // The invalid counts fall back to the defaults.
+    let default = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "store" }),
+    );
+    assert_eq!(notes.last(), Some(&default.as_str()));
+
+    let stamped = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "store", "counts": { "cards": 7 }, "provenance": true }),
+    );
+    let reproduced = first_text(
+        &mut server,
+        "daisyui_reproduce",
+        json!({ "comment": stamped.lines().next().unwrap() }),
+    );
+    assert_eq!(reproduced, stamped);
+    assert_eq!(products(&stamped).len(), 7);
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn slots_put_the_caller_s_markup_in_a_layout() {
+    let mut server = Server::spawn();
+    let custom = r#"<my-widget data-note="a & b">Hi</my-widget>"#;
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "saas", "title": "Acme", "slots": { "hero": custom, "footer": "<p>Built by Acme</p>" } } }),
+    );
+    let html = result["content"][0]["text"].as_str().unwrap();
+    assert!(html.contains(custom), "{}", html);
+    assert!(html.contains("<p>Built by Acme</p>"), "{}", html);
+    assert!(html.contains("Loved by teams"), "{}", html);
+    assert_eq!(result["content"].as_array().unwrap().len(), 1);
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "dashboard", "slots": { "main": "<script>boot()</script>", "hero": "<p>x</p>", "aside": "" } } }),
+    );
+    let texts: Vec<&str> = result["content"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|c| c["text"].as_str())
+        .collect();
+    assert_eq!(texts.len(), 4, "{:?}", texts);
+    assert!(texts[0].starts_with("Unknown slot 'aside'"), "{}", texts[0]);
+    assert!(
+        texts[1].starts_with("The dashboard layout has no hero slot"),
+        "{}",
+        texts[1]
+    );
+    assert!(texts[2].starts_with("Warning: the main slot contains a <script> tag"));
+    assert!(texts[3].contains("<script>boot()</script>"));
+
+    let stamped = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "jobs", "slots": { "sidebar": "<p>-- filters --</p>" }, "provenance": true }),
+    );
+    let reproduced = first_text(
+        &mut server,
+        "daisyui_reproduce",
+        json!({ "comment": stamped.lines().next().unwrap() }),
+    );
+    assert_eq!(reproduced, stamped);
+    assert!(stamped.contains("<p>-- filters --</p>"));
+    assert!(server.shutdown().is_empty());
+}
+
+/// `(name, snippet)` pairs from `daisyui_search`.
+fn search(server: &mut Server, query: &str) -> Vec<(String, String)> {
+    let text = first_text(server, "daisyui_search", json!({ "query": query }));
+    let lines: Vec<&str> = text.lines().collect();
+    lines
+        .windows(2)
+        .filter_map(|pair| {
+            let name = pair[0].strip_prefix("- **")?.split("**").next()?;
+            Some((name.to_string(), pair[1].trim().to_string()))
+        })
+        .collect()
+}
+
+#[test]
+fn search_results_carry_highlighted_snippets() {
+    let mut server = Server::spawn();
+
+    let results = search(&mut server, "  Button ");
+    assert_eq!(results[0].0, "button", "{:?}", results);
+    for (name, snippet) in &results {
+        assert!(
+            snippet.to_lowercase().contains("**button**"),
+            "{}: {}",
+            name,
+            snippet
+        );
+        assert!(
+            !snippet.contains('\n') && !snippet.contains("https://"),
+            "{}",
+            snippet
+        );
+        assert!(snippet.chars().count() < 400, "{}: {}", name, snippet);
+    }
+    // A match at the very start gets no leading ellipsis.
+    let (_, accordion) = &search(&mut server, "accordion")[0];
+    assert!(accordion.starts_with("**Accordion**"), "{}", accordion);
+
+    // Both words of a multi-word query land in one window.
+    let (name, snippet) = &search(&mut server, "modal close")[0];
+    assert_eq!(name, "modal");
+    assert!(
+        snippet.contains("**modal**") && snippet.to_lowercase().contains("**close**"),
+        "{}",
+        snippet
+    );
+
+    assert_eq!(
+        first_text(&mut server, "daisyui_search", json!({ "query": "zzzz" })),
+        "No results found for 'zzzz'"
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn search_pages_report_their_place_among_all_matches() {
+    let mut server = Server::spawn();
+    let everything = first_text(
+        &mut server,
+        "daisyui_search",
+        json!({ "query": "component", "limit": 1000 }),
+    );
+    let total: usize = everything
+        .strip_prefix("Found ")
+        .and_then(|t| t.split('.').next())
+        .and_then(|n| n.parse().ok())
+        .expect(&everything);
+    assert!(total > 25, "{}", everything);
+    let names = |text: &str| -> Vec<String> {
+        text.lines()
+            .filter_map(|l| Some(l.strip_prefix("- **")?.split("**").next()?.to_string()))
+            .collect()
+    };
+    let all = names(&everything);
+    assert_eq!(all.len(), total);
+
+    // The default page is still 20.
+    let first = first_text(
+        &mut server,
+        "daisyui_search",
+        json!({ "query": "component" }),
+    );
+    assert_eq!(names(&first), all[..20]);
+    assert!(
+        first.contains(&format!("Showing 1–20 of {}", total)),
+        "{}",
+        first
+    );
+
+    let page = first_text(
+        &mut server,
+        "daisyui_search",
+        json!({ "query": "component", "limit": 5, "offset": 20 }),
+    );
+    assert_eq!(names(&page), all[20..25]);
+    assert!(
+        page.contains(&format!("Showing 21–25 of {}", total)),
+        "{}",
+        page
+    );
+
+    let past = first_text(
+        &mut server,
+        "daisyui_search",
+        json!({ "query": "component", "offset": 500 }),
+    );
+    assert!(names(&past).is_empty());
+    assert!(
+        past.ends_with(&format!("No results at offset 500; {} in total", total)),
+        "{}",
+        past
+    );
+
+    let empty = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_search", "arguments": { "query": "component", "limit": 0 } }),
+    );
+    assert_eq!(empty["error"]["code"], -32602);
+    assert_eq!(empty["error"]["message"], "limit must be 1 or more, got 0");
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn search_returns_json_results_on_request() {
+    let mut server = Server::spawn();
+    let markdown = first_text(
+        &mut server,
+        "daisyui_search",
+        json!({ "query": "component", "limit": 3 }),
+    );
+    assert_eq!(
+        first_text(
+            &mut server,
+            "daisyui_search",
+            json!({ "query": "component", "limit": 3, "format": "markdown" }),
+        ),
+        markdown
+    );
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_search", "arguments": { "query": "component", "limit": 3, "format": "json" } }),
+    );
+    let content = result["content"].as_array().expect("content");
+    assert_eq!(content.len(), 2, "{}", result);
+    let array: Value =
+        serde_json::from_str(content[0]["text"].as_str().unwrap_or_default()).expect("JSON array");
+    let hits = array.as_array().expect("array");
+    assert_eq!(hits.len(), 3, "{}", array);
+    for hit in hits {
+        let (name, score) = (
+            hit["name"].as_str().unwrap(),
+            hit["score"].as_u64().unwrap(),
+        );
+        let snippet = hit["snippet"].as_str().unwrap();
+        assert!(!snippet.contains("**"), "{}", hit);
+        let chars: Vec<char> = snippet.chars().collect();
+        let matches = hit["matches"].as_array().unwrap();
+        assert!(!matches.is_empty(), "{}", hit);
+        for range in matches {
+            let [start, end] = [0, 1].map(|i| range[i].as_u64().unwrap() as usize);
+            let word: String = chars[start..end].iter().collect();
+            assert_eq!(word.to_lowercase(), "component", "{}", hit);
+        }
+        assert!(
+            markdown.contains(&format!("- **{}** (score: {})", name, score)),
+            "{} missing from {}",
+            hit,
+            markdown
+        );
+    }
+    assert!(
+        markdown.starts_with(content[1]["text"].as_str().unwrap()),
+        "{}",
+        result
+    );
+
+    let empty = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_search", "arguments": { "query": "zzzz", "format": "json" } }),
+    );
+    assert_eq!(empty["content"][0]["text"], "[]");
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_search", "arguments": { "query": "modal", "format": "yaml" } }),
+    );
+    assert_eq!(
+        response["error"]["message"],
+        "Unknown format 'yaml'; use markdown or json"
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn classes_lead_back_to_their_component() {
+    let mut server = Server::spawn();
+    for (class, component) in [
+        ("join-item", "join"),
+        (".btn-primary", "button"),
+        ("btn", "button"),
+        ("modal-open", "modal"),
+        ("drawer-toggle", "drawer"),
+    ] {
+        let text = first_text(
+            &mut server,
+            "daisyui_which_component",
+            json!({ "class": class }),
+        );
+        assert!(
+            text.starts_with(&format!(
+                "`{}` belongs to **{}**.\n\n### {}",
+                class.trim_start_matches('.'),
+                component,
+                component
+            )),
+            "{}",
+            text
+        );
+    }
+    for (class, expected) in [
+        ("btn-primry", "Did you mean 'btn-primary'?"),
+        ("w-full", "No component uses the class 'w-full'"),
+    ] {
+        let response = server.request(
+            "tools/call",
+            json!({ "name": "daisyui_which_component", "arguments": { "class": class } }),
+        );
+        let message = response["error"]["message"].as_str().unwrap_or_default();
+        assert!(message.ends_with(expected), "{}", message);
+    }
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn examples_can_be_picked_by_index() {
+    let mut server = Server::spawn();
+    let one = first_text(
+        &mut server,
+        "daisyui_get_example",
+        json!({ "component": "modal", "index": 1 }),
+    );
+    assert!(one.starts_with("## modal example 1 of 1: "), "{}", one);
+    assert!(
+        one.contains("```html\n") && !one.contains("### modal"),
+        "{}",
+        one
+    );
+
+    let all = first_text(
+        &mut server,
+        "daisyui_get_example",
+        json!({ "component": "modal" }),
+    );
+    assert!(all.starts_with("## modal examples (1 of 1)"), "{}", all);
+    let code = one.split("```html\n").nth(1).unwrap_or_default();
+    assert!(all.contains(code), "{}", all);
+
+    let corrected = first_text(
+        &mut server,
+        "daisyui_get_example",
+        json!({ "component": "modl", "index": 1 }),
+    );
+    assert!(
+        corrected.starts_with("> No component named 'modl'"),
+        "{}",
+        corrected
+    );
+
+    for index in [0, 2] {
+        let response = server.request(
+            "tools/call",
+            json!({ "name": "daisyui_get_example", "arguments": { "component": "modal", "index": index } }),
+        );
+        assert_eq!(
+            response["error"]["message"],
+            "'modal' has 1 example; pick an index from 1 to 1"
+        );
+    }
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn docs_can_be_cut_to_a_character_budget() {
+    let mut server = Server::spawn();
+    let full = first_text(
+        &mut server,
+        "daisyui_get_docs",
+        json!({ "component": "modal" }),
+    );
+    let total = full.chars().count();
+    assert_eq!(
+        first_text(
+            &mut server,
+            "daisyui_get_docs",
+            json!({ "component": "modal", "max_chars": total }),
+        ),
+        full
+    );
+    for budget in [120, 500, 700] {
+        let text = first_text(
+            &mut server,
+            "daisyui_get_docs",
+            json!({ "component": "modal", "max_chars": budget }),
+        );
+        let (kept, marker) = text.split_once("\n\n…(truncated, ").expect(&text);
+        // A fence left open by the cut is closed after the kept text.
+        let kept = match kept.strip_suffix("\n```") {
+            Some(open) if !full.starts_with(kept) => open,
+            _ => kept,
+        };
+        assert!(kept.chars().count() <= budget, "{}", kept);
+        assert!(full.starts_with(kept), "{}", kept);
+        assert!(
+            full[kept.len()..].starts_with('\n'),
+            "cut mid-line: {}",
+            kept
+        );
+        let more = total - kept.chars().count();
+        assert_eq!(
+            marker,
+            format!(
+                "{} more chars — call again without max_chars to see everything)",
+                more
+            )
+        );
+        assert_eq!(text.matches("```").count() % 2, 0, "{}", text);
+    }
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn docs_for_a_list_come_back_one_per_item() {
+    let mut server = Server::spawn();
+    let single = first_text(
+        &mut server,
+        "daisyui_get_docs",
+        json!({ "component": "menu" }),
+    );
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_get_docs", "arguments": { "component": "navbar, drawr,nope , menu," } }),
+    );
+    let texts: Vec<&str> = result["content"]
+        .as_array()
+        .expect("content")
+        .iter()
+        .filter_map(|c| c["text"].as_str())
+        .collect();
+    assert_eq!(texts.len(), 4, "{:?}", texts);
+    assert!(texts[0].starts_with("### navbar"), "{}", texts[0]);
+    assert!(
+        texts[1].starts_with("> No component named 'drawr'; showing results for 'drawer'."),
+        "{}",
+        texts[1]
+    );
+    assert_eq!(texts[2], single);
+    assert_eq!(texts[3], "> Not found: nope");
+
+    for (component, expected) in [
+        ("nope", "Documentation not found for 'nope'"),
+        ("nope, zilch", "Documentation not found for nope, zilch"),
+    ] {
+        let response = server.request(
+            "tools/call",
+            json!({ "name": "daisyui_get_docs", "arguments": { "component": component } }),
+        );
+        assert_eq!(response["error"]["message"], expected);
+    }
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn docs_anchors_return_one_subsection() {
+    let mut server = Server::spawn();
+    let section = |server: &mut Server, spec: &str| {
+        first_text(server, "daisyui_get_docs", json!({ "component": spec }))
+    };
+    let rules = section(&mut server, "Button#RULES");
+    assert!(rules.starts_with("#### Rules\n"), "{}", rules);
+    assert!(!rules.contains("#### Syntax"), "{}", rules);
+    let classes = section(&mut server, "button#class");
+    assert!(classes.starts_with("#### Class names\n"), "{}", classes);
+    assert!(classes.contains("`btn-sm`"), "{}", classes);
+    assert!(!classes.contains("#### Syntax"), "{}", classes);
+    let corrected = section(&mut server, "buton#syntax");
+    assert!(
+        corrected.starts_with("> No component named 'buton'; showing results for 'button'."),
+        "{}",
+        corrected
+    );
+    assert!(corrected.contains("\n\n#### Syntax\n"), "{}", corrected);
+
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_get_docs", "arguments": { "component": "button#sizes" } }),
+    );
+    assert_eq!(
+        response["error"]["message"],
+        "'button' has no section matching 'sizes'. Sections: 'Class names', 'Syntax', 'Rules'"
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn random_components_follow_the_seed_and_category() {
+    let mut server = Server::spawn();
+    let random =
+        |server: &mut Server, args: Value| first_text(server, "daisyui_random_component", args);
+    let seeded = random(&mut server, json!({ "seed": 7 }));
+    assert_eq!(random(&mut server, json!({ "seed": 7 })), seeded);
+    let name = seeded
+        .lines()
+        .next()
+        .and_then(|l| l.strip_prefix("### "))
+        .expect("doc heading")
+        .to_string();
+    let doc = first_text(
+        &mut server,
+        "daisyui_get_docs",
+        json!({ "component": name }),
+    );
+    assert_eq!(seeded, doc);
+
+    let mut seen = std::collections::BTreeSet::new();
+    for _ in 0..10 {
+        let nav = random(&mut server, json!({ "category": "navigation" }));
+        seen.insert(nav.lines().next().unwrap_or_default().to_string());
+    }
+    assert!(seen.len() > 1, "{:?}", seen);
+    for heading in &seen {
+        assert!(
+            ["### menu", "### navbar", "### tabs", "### breadcrumbs"].contains(&heading.as_str()),
+            "{}",
+            heading
+        );
+    }
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_random_component", "arguments": { "category": "nope" } }),
+    );
+    assert_eq!(
+        response["error"]["message"],
+        "No components match 'nope'. Categories: actions, data-display, navigation, feedback, data-input, layout, other"
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn docs_link_the_components_they_mention() {
+    let mut server = Server::spawn();
+    let drawer = first_text(
+        &mut server,
+        "daisyui_get_docs",
+        json!({ "component": "drawer" }),
+    );
+    let footer = drawer.lines().last().unwrap_or_default();
+    assert!(footer.starts_with("**See also:** "), "{}", footer);
+    assert!(
+        footer.contains("menu") && footer.contains("navbar"),
+        "{}",
+        footer
+    );
+    assert!(!footer.contains("drawer"), "{}", footer);
+
+    // Checkbox, drawer, swap and toggle all use `<input>` tags but never
+    // mention the input component.
+    let input = first_text(
+        &mut server,
+        "daisyui_used_by",
+        json!({ "component": "input" }),
+    );
+    assert_eq!(input, "No other component's docs mention `input`.\n");
+    let menu = first_text(
+        &mut server,
+        "daisyui_used_by",
+        json!({ "component": "Menu" }),
+    );
+    assert!(
+        menu.starts_with("## Components that mention menu\n\n"),
+        "{}",
+        menu
+    );
+    assert!(menu.contains("\n- drawer\n"), "{}", menu);
+    let progress = first_text(
+        &mut server,
+        "daisyui_used_by",
+        json!({ "component": "progress" }),
+    );
+    // Prose ("the progress of a task") counts; the class `radial-progress`
+    // alone wouldn't.
+    assert!(progress.contains("- radial-progress\n"), "{}", progress);
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn cheatsheet_summarizes_and_filters_components() {
+    let mut server = Server::spawn();
+    let rows = |text: &str| -> Vec<String> {
+        text.lines()
+            .skip(2)
+            .filter_map(|l| Some(l.strip_prefix("| ")?.split(" |").next()?.to_string()))
+            .collect()
+    };
+    let all = first_text(&mut server, "daisyui_cheatsheet", json!({}));
+    let components = first_text(&mut server, "daisyui_list_components", json!({}));
+    assert_eq!(rows(&all).join(", "), components);
+    assert!(
+        all.contains(
+            "| button | `btn` | actions | Buttons allow the user to take actions or make choices. |"
+        ),
+        "{}",
+        all
+    );
+    for line in all.lines().skip(2) {
+        let summary = line
+            .trim_end_matches(" |")
+            .rsplit(" | ")
+            .next()
+            .unwrap_or_default();
+        assert!(!summary.is_empty() && !summary.contains("```"), "{}", line);
+    }
+
+    let nav = first_text(
+        &mut server,
+        "daisyui_cheatsheet",
+        json!({ "filter": "Nav" }),
+    );
+    assert_eq!(rows(&nav), ["breadcrumbs", "menu", "navbar", "tabs"]);
+    let progress = first_text(
+        &mut server,
+        "daisyui_cheatsheet",
+        json!({ "filter": "progress" }),
+    );
+    assert_eq!(rows(&progress), ["progress", "radial-progress"]);
+
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_cheatsheet", "arguments": { "filter": "zzz" } }),
+    );
+    let message = response["error"]["message"].as_str().unwrap_or_default();
+    assert!(
+        message.starts_with("No components match 'zzz'. Categories: actions"),
+        "{}",
+        message
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn modifiers_are_grouped_by_size_color_and_style() {
+    let mut server = Server::spawn();
+    let button = first_text(
+        &mut server,
+        "daisyui_list_modifiers",
+        json!({ "component": "button" }),
+    );
+    assert!(button.starts_with("## button modifiers\n"), "{}", button);
+    assert!(
+        button.contains("- size: `btn-xs`, `btn-sm`, `btn-md`, `btn-lg`, `btn-xl`\n"),
+        "{}",
+        button
+    );
+    assert!(
+        button.contains("- color: `btn-accent`, `btn-error`, `btn-info`, `btn-neutral`, `btn-primary`, `btn-secondary`, `btn-success`, `btn-warning`\n"),
+        "{}",
+        button
+    );
+    let style = button.lines().find(|l| l.starts_with("- style: ")).unwrap();
+    for class in ["`btn-outline`", "`btn-ghost`", "`btn-wide`"] {
+        assert!(style.contains(class), "{}", style);
+    }
+    assert!(!button.contains("`btn`"), "{}", button);
+    let again = first_text(
+        &mut server,
+        "daisyui_list_modifiers",
+        json!({ "component": "Button" }),
+    );
+    assert_eq!(again, button);
+
+    let card = first_text(
+        &mut server,
+        "daisyui_list_modifiers",
+        json!({ "component": "card" }),
+    );
+    assert!(!card.contains("card-body"), "{}", card);
+    assert!(!card.contains("- color: "), "{}", card);
+
+    let hero = first_text(
+        &mut server,
+        "daisyui_list_modifiers",
+        json!({ "component": "hero" }),
+    );
+    assert!(hero.contains("`hero` has no modifier classes."), "{}", hero);
+
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_list_modifiers", "arguments": { "component": "zzzz" } }),
+    );
+    assert!(response["error"]["message"].is_string(), "{}", response);
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn aliases_from_other_frameworks_find_daisyui_components() {
+    let mut server = Server::spawn();
+    for (alias, component) in [
+        ("dialog", "modal"),
+        ("Popup", "modal"),
+        ("spinner", "loading"),
+        ("hamburger", "menu"),
+        ("sidebar", "drawer"),
+        ("chip", "badge"),
+        ("pill", "badge"),
+        ("breadcrumb", "breadcrumbs"),
+        ("switch", "toggle"),
+        ("text field", "input"),
+        ("button_group", "join"),
+        ("progress bar", "progress"),
+        ("keyboard", "kbd"),
+    ] {
+        let doc = first_text(
+            &mut server,
+            "daisyui_get_docs",
+            json!({ "component": alias }),
+        );
+        let key = alias.to_lowercase().replace([' ', '_'], "-");
+        assert!(
+            doc.starts_with(&format!(
+                "> Showing '{}' (alias of '{}')\n\n### {}\n",
+                component, key, component
+            )),
+            "{} gave {}",
+            alias,
+            doc
+        );
+        let search = first_text(
+            &mut server,
+            "daisyui_search",
+            json!({ "query": alias, "limit": 1 }),
+        );
+        assert!(
+            search.contains(&format!("- **{}** (score: ", component)),
+            "{} gave {}",
+            alias,
+            search
+        );
+        assert!(
+            search.contains(&format!("Showing '{}' (alias of '{}')", component, key)),
+            "{}",
+            search
+        );
+    }
+    // A real component is never shadowed by an alias.
+    let accordion = first_text(
+        &mut server,
+        "daisyui_get_docs",
+        json!({ "component": "accordion" }),
+    );
+    assert!(accordion.starts_with("### accordion\n"), "{}", accordion);
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn misspelled_component_names_resolve_fuzzily() {
+    let mut server = Server::spawn();
+    let docs = |server: &mut Server, component: &str| {
+        server.request(
+            "tools/call",
+            json!({ "name": "daisyui_get_docs", "arguments": { "component": component } }),
+        )
+    };
+    for (typed, shown) in [
+        ("buttno", "button"),      // transposition
+        ("buton", "button"),       // missing letter
+        ("drop down", "dropdown"), // spaces ignored
+        ("radialprogress", "radial-progress"),
+    ] {
+        let response = docs(&mut server, typed);
+        let text = text_of(&response["result"]);
+        assert!(
+            text.starts_with(&format!(
+                "> No component named '{}'; showing results for '{}'.",
+                typed, shown
+            )),
+            "{}: {}",
+            typed,
+            response
+        );
+        assert!(text.contains(&format!("### {}", shown)), "{}", text);
+    }
+
+    // Exact names win over near misses and get no note.
+    for exact in ["tabs", "Table"] {
+        let text = text_of(&docs(&mut server, exact)["result"]);
+        assert!(
+            text.starts_with(&format!("### {}", exact.to_lowercase())),
+            "{}",
+            text
+        );
+    }
+
+    let response = docs(&mut server, "tabe");
+    assert_eq!(response["error"]["code"], -32602);
+    assert_eq!(
+        response["error"]["message"],
+        "No component named 'tabe'. Did you mean 'table', 'tabs'?"
+    );
+    let response = docs(&mut server, "zzzzz");
+    assert_eq!(
+        response["error"]["message"],
+        "Documentation not found for 'zzzzz'"
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+/// Keys from a `daisyui_list_concepts` listing, sorted.
+fn concept_keys(listing: &str) -> Vec<String> {
+    let mut keys: Vec<String> = listing
+        .lines()
+        .filter_map(|l| Some(l.strip_prefix("- `")?.split('`').next()?.to_string()))
+        .collect();
+    keys.sort();
+    keys
+}
+
+#[test]
+fn concepts_come_from_the_shared_file() {
+    let mut server = Server::spawn();
+    let listed =
+        concept_keys(&first_text(&mut server, "daisyui_list_concepts", json!({}))).join(", ");
+    assert_eq!(
+        listed,
+        "accessible-forms, bento, claymorphism, darkmode, floating-labels, focus-visible, glassmorphism, gradient, inline-validation, keyboard-navigation, loading-choreography, micro-interactions, minimal, neubrutalism, neumorphism, page-transitions, progressive-disclosure, reduced-motion, responsive, retro-terminal, scroll-reveal, skeleton"
+    );
+    assert_eq!(
+        first_text(
+            &mut server,
+            "daisyui_list_concepts",
+            json!({ "category": "state" })
+        ),
+        "## Design Concepts\n\n### state\n\n\
+         - `inline-validation` — Each field says what's wrong right under it, as soon as you leave it\n\
+         - `skeleton` — Placeholder UI while content loads\n"
+    );
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_list_concepts", "arguments": { "category": "fun" } }),
+    );
+    assert_eq!(
+        response["error"]["message"],
+        "Unknown category 'fun'. Categories: visual-style, layout, motion, accessibility, state"
+    );
+
+    let text = first_text(
+        &mut server,
+        "daisyui_get_concept",
+        json!({ "concept": "Skeleton" }),
+    );
+    assert!(text.starts_with(
+        "## Skeleton Loading\n\n**Description:** Placeholder UI while content loads\n\n\
+         **Classes:** skeleton\n\n**Suggestion:** Use skeleton class on elements for loading state\n\n\
+         ### Block\n\n```html\n<div class=\"skeleton h-32 w-full\"></div>\n```\n\n### Card\n\n```html\n"
+    ));
+
+    // An example can be picked by part of its title.
+    let text = first_text(
+        &mut server,
+        "daisyui_get_concept",
+        json!({ "concept": "skeleton", "example": "card" }),
+    );
+    assert!(
+        text.contains("### Card\n\n```html\n<div class=\"card"),
+        "{}",
+        text
+    );
+    assert!(!text.contains("### Block"), "{}", text);
+    let object: Value = serde_json::from_str(&first_text(
+        &mut server,
+        "daisyui_get_concept",
+        json!({ "concept": "skeleton", "example": "card", "format": "json" }),
+    ))
+    .expect("json concept");
+    assert_eq!(object["example"], "Card");
+    assert!(
+        object["snippet"]
+            .as_str()
+            .unwrap()
+            .starts_with("<div class=\"card")
+    );
+    assert_eq!(object["examples"][0]["title"], "Block");
+    assert_eq!(object["examples"].as_array().unwrap().len(), 2);
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_get_concept", "arguments": { "concept": "skeleton", "example": "table" } }),
+    );
+    assert_eq!(
+        response["error"]["message"],
+        "'skeleton' has no example matching 'table'. Examples: Block, Card"
+    );
+
+    let text = first_text(
+        &mut server,
+        "daisyui_get_concept",
+        json!({ "concept": "frosted" }),
+    );
+    assert!(
+        text.starts_with(
+            "> No concept named 'frosted'; showing 'glassmorphism'.\n\n## Glassmorphism"
+        ),
+        "{}",
+        text
+    );
+
+    let text = first_text(
+        &mut server,
+        "daisyui_get_concept",
+        json!({ "concept": "skeleton", "target": "jsx" }),
+    );
+    assert!(
+        text.contains("```jsx\n<div className=\"skeleton h-32 w-full\"></div>\n```"),
+        "{}",
+        text
+    );
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_get_concept", "arguments": { "concept": "skeleton", "target": "angular" } }),
+    );
+    assert_eq!(
+        response["error"]["message"],
+        "Unknown target 'angular'; use html, jsx, vue, svelte, leptos, yew"
+    );
+
+    let reveal: Value = serde_json::from_str(&first_text(
+        &mut server,
+        "daisyui_get_concept",
+        json!({ "concept": "scroll-reveal", "format": "json" }),
+    ))
+    .unwrap();
+    assert!(
+        reveal["script"]
+            .as_str()
+            .unwrap()
+            .contains("IntersectionObserver")
+    );
+    let markdown = first_text(
+        &mut server,
+        "daisyui_get_concept",
+        json!({ "concept": "scroll-reveal" }),
+    );
+    assert!(markdown.ends_with(&format!(
+        "```js\n{}\n```",
+        reveal["script"].as_str().unwrap()
+    )));
+
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_get_concept", "arguments": { "concept": "baroque" } }),
+    );
+    assert_eq!(response["error"]["code"], -32602);
+    assert_eq!(
+        response["error"]["message"],
+        format!("Concept 'baroque' not found. Available: {}", listed)
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn every_bundled_concept_renders_over_mcp() {
+    // The extension loads the same file, so these are its concepts too.
+    let mut keys: Vec<&str> = include_str!("../../src/concepts.toml")
+        .lines()
+        .filter(|l| !l.starts_with("[["))
+        .filter_map(|l| l.trim().strip_prefix('[')?.strip_suffix(']'))
+        .collect();
+    keys.sort();
+    let mut server = Server::spawn();
+    let listed = first_text(&mut server, "daisyui_list_concepts", json!({}));
+    assert_eq!(concept_keys(&listed), keys);
+
+    for key in keys {
+        let text = first_text(
+            &mut server,
+            "daisyui_get_concept",
+            json!({ "concept": key }),
+        );
+        assert!(text.starts_with("## "), "{}: {}", key, text);
+        assert!(text.ends_with("\n```"), "{}: {}", key, text);
+        for artifact in ["Some(", "None", "DesignConcept"] {
+            assert!(!text.contains(artifact), "{}: {}", key, text);
+        }
+
+        let object: Value = serde_json::from_str(&first_text(
+            &mut server,
+            "daisyui_get_concept",
+            json!({ "concept": key, "format": "json" }),
+        ))
+        .expect("json concept");
+        assert_eq!(object["key"], key);
+        assert!(text.starts_with(&format!("## {}\n", object["name"].as_str().unwrap())));
+        assert!(object["classes"].as_array().is_some_and(|c| !c.is_empty()));
+        assert!(object.get("note").is_none(), "{}", object);
+    }
+
+    let object: Value = serde_json::from_str(&first_text(
+        &mut server,
+        "daisyui_get_concept",
+        json!({ "concept": "frosted", "format": "json" }),
+    ))
+    .expect("json concept");
+    assert_eq!(object["key"], "glassmorphism");
+    assert_eq!(
+        object["note"],
+        "No concept named 'frosted'; showing 'glassmorphism'."
+    );
+
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_get_concept", "arguments": { "concept": "glass", "format": "yaml" } }),
+    );
+    assert_eq!(
+        response["error"]["message"],
+        "Unknown format 'yaml'; use markdown or json"
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn scaffolded_layouts_take_a_concept() {
+    let mut server = Server::spawn();
+    let plain = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "saas", "title": "MyApp" }),
+    );
+    let glass = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "saas", "title": "MyApp", "concept": "glassmorphism" }),
+    );
+    assert!(plain.contains("class=\"navbar ") && !plain.contains("glass"));
+    for (before, after) in plain.split("class=\"").zip(glass.split("class=\"")).skip(1) {
+        let (before, after) = (before.split('"').next(), after.split('"').next());
+        let classes: Vec<&str> = before.unwrap().split_whitespace().collect();
+        if classes
+            .iter()
+            .any(|c| ["card", "navbar", "modal"].contains(c))
+        {
+            assert_eq!(
+                after.unwrap(),
+                format!("{} glass backdrop-blur", before.unwrap())
+            );
+        } else {
+            assert_eq!(after, before);
+        }
+    }
+
+    let diff = first_text(
+        &mut server,
+        "daisyui_layout_diff",
+        json!({ "to": { "concept": "neubrutalism" } }),
+    );
+    assert!(
+        diff.contains("concept: glassmorphism → neubrutalism"),
+        "{}",
+        diff
+    );
+
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "saas", "concept": "responsive" } }),
+    );
+    assert_eq!(response["error"]["code"], -32602);
+    assert_eq!(
+        response["error"]["message"],
+        "Concept 'responsive' has no classes to apply"
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn concepts_make_themes_the_palette_tools_can_read() {
+    let mut server = Server::spawn();
+    let css = first_text(
+        &mut server,
+        "daisyui_concept_theme",
+        json!({ "concept": "darkmode", "name": "night-owl" }),
+    );
+    assert!(css.starts_with("@plugin \"daisyui/theme\" {\n  name: \"night-owl\";"));
+    assert!(css.contains("color-scheme: dark;"));
+    assert_eq!(css.matches("--color-").count(), 20);
+
+    let palette = first_text(&mut server, "daisyui_chart_palette", json!({ "css": css }));
+    assert!(palette.contains("#605dff"), "{}", palette);
+
+    let noted = first_text(
+        &mut server,
+        "daisyui_concept_theme",
+        json!({ "concept": "terminal" }),
+    );
+    assert!(noted.starts_with("/* No concept named 'terminal'; showing 'retro-terminal'. */\n"));
+    assert!(noted.contains("name: \"retro-terminal\";"));
+
+    let generated = first_text(
+        &mut server,
+        "daisyui_generate_theme",
+        json!({ "name": "brand", "primary": "#ff0000" }),
+    );
+    assert!(generated.contains("--color-primary: #ff0000;"));
+    assert_eq!(generated.matches("--color-").count(), 20);
+
+    for (arguments, message) in [
+        (
+            json!({ "concept": "darkmode", "name": "Night Owl" }),
+            "Theme names may only contain a-z, 0-9 and `-`, got 'Night Owl'",
+        ),
+        (
+            json!({ "concept": "baroque" }),
+            "Concept 'baroque' not found. Available: accessible-forms, bento, claymorphism, darkmode, floating-labels, focus-visible, glassmorphism, gradient, inline-validation, keyboard-navigation, loading-choreography, micro-interactions, minimal, neubrutalism, neumorphism, page-transitions, progressive-disclosure, reduced-motion, responsive, retro-terminal, scroll-reveal, skeleton",
+        ),
+    ] {
+        let response = server.request(
+            "tools/call",
+            json!({ "name": "daisyui_concept_theme", "arguments": arguments }),
+        );
+        assert_eq!(response["error"]["code"], -32602);
+        assert_eq!(response["error"]["message"], message);
+    }
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn concepts_compare_over_mcp() {
+    let mut server = Server::spawn();
+    let text = first_text(
+        &mut server,
+        "daisyui_compare_concepts",
+        json!({ "first": "glassmorphism", "second": "neumorphism" }),
+    );
+    assert!(
+        text.starts_with("## Glassmorphism vs Neumorphism\n\n| | Glassmorphism | Neumorphism |\n")
+    );
+    let glass = text.find("\n### Glassmorphism\n\n```html\n").unwrap();
+    let neu = text.find("\n### Neumorphism\n\n```html\n").unwrap();
+    assert!(glass < neu);
+
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_compare_concepts", "arguments": { "first": "minimal", "second": "minimal" } }),
+    );
+    assert_eq!(response["error"]["code"], -32602);
+    assert_eq!(
+        response["error"]["message"],
+        "Can't compare 'minimal' with itself; pick a second concept, e.g. 'claymorphism'"
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn html_gets_accessibility_hints_over_mcp() {
+    let mut server = Server::spawn();
+    let text = first_text(
+        &mut server,
+        "daisyui_a11y_hints",
+        json!({ "html": "<button class=\"btn\" aria-label=\"Close\"><svg></svg></button><img src=\"a.png\">" }),
+    );
+    assert_eq!(
+        text,
+        "## Accessibility Hints\n\n\
+         - `<img src=\"a.png\">`: image has no `alt`; describe it, or use `alt=\"\"` if it's decorative\n\n\
+         _Tag-level checks only, not a full audit._\n"
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn style_recipes_are_reproducible_by_seed() {
+    let mut server = Server::spawn();
+    let first = first_text(&mut server, "daisyui_style_recipe", json!({ "seed": 11 }));
+    let again = first_text(&mut server, "daisyui_style_recipe", json!({ "seed": 11 }));
+    assert_eq!(first, again);
+    assert!(first.starts_with("## Style Recipe: "));
+    assert!(first.contains("_Seed 11: "));
+    assert!(first.contains("\n### Example\n\n```html\n<div class=\"card "));
+
+    // Unseeded calls name the seed they used, which reproduces them.
+    let unseeded = first_text(
+        &mut server,
+        "daisyui_style_recipe",
+        json!({ "include": ["darkmode"] }),
+    );
+    let seed: u64 = unseeded
+        .split("_Seed ")
+        .nth(1)
+        .and_then(|rest| rest.split(':').next())
+        .and_then(|n| n.parse().ok())
+        .unwrap();
+    assert_eq!(
+        first_text(
+            &mut server,
+            "daisyui_style_recipe",
+            json!({ "seed": seed, "include": ["darkmode"] })
+        ),
+        unseeded
+    );
+
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_style_recipe", "arguments": { "include": ["claymorphism", "darkmode"] } }),
+    );
+    assert_eq!(response["error"]["code"], -32602);
+    assert_eq!(
+        response["error"]["message"],
+        "'claymorphism' and 'darkmode' don't go together: pastel clay surfaces only work light"
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn form_styles_change_the_field_markup() {
+    let mut server = Server::spawn();
+    let form = |server: &mut Server, style: Option<&str>| {
+        let mut arguments = json!({ "fields": ["Email", { "name": "Phone", "optional": true }] });
+        if let Some(style) = style {
+            arguments["style"] = json!(style);
+        }
+        first_text(server, "daisyui_scaffold_form", arguments)
+    };
+
+    let plain = form(&mut server, None);
+    assert!(
+        plain.contains(
+            r#"<input type="text" id="dd-field-1" name="Email" class="input input-bordered" />"#
+        ),
+        "{}",
+        plain
+    );
+    for marker in ["aria-invalid", "floating-label", "collapse"] {
+        assert!(!plain.contains(marker), "{}", plain);
+    }
+
+    let inline = form(&mut server, Some("inline-validation"));
+    assert!(
+        inline.contains(r#"<form class="card-body" novalidate>"#),
+        "{}",
+        inline
+    );
+    assert!(
+        inline.contains(r#"aria-invalid="false" aria-describedby="dd-field-1-error" required />"#),
+        "{}",
+        inline
+    );
+    assert!(
+        inline.contains(r#"aria-describedby="dd-field-2-error" />"#),
+        "{}",
+        inline
+    );
+    assert!(
+        inline.contains(r#"<span id="dd-field-2-error" class="label-text-alt text-error" aria-live="polite"></span>"#),
+        "{}",
+        inline
+    );
+
+    let floating = form(&mut server, Some("floating-labels"));
+    assert_eq!(
+        floating.matches(r#"<label class="floating-label""#).count(),
+        2
+    );
+    assert!(
+        floating.contains(r#"<span>Phone</span><input type="text" id="dd-field-2" name="Phone" placeholder="Phone""#),
+        "{}",
+        floating
+    );
+    assert!(!floating.contains("label-text"), "{}", floating);
+
+    let disclosed = form(&mut server, Some("progressive-disclosure"));
+    let (required, folded) = disclosed
+        .split_once(r#"<div class="collapse collapse-arrow"#)
+        .unwrap();
+    assert!(required.contains(r#"name="Email""#) && !required.contains(r#"name="Phone""#));
+    assert!(folded.contains(r#"<div class="collapse-title font-medium">More options</div>"#));
+    assert!(folded.contains(r#"name="Phone""#), "{}", disclosed);
+
+    // Without fields the form gets Name, Email and an optional Company.
+    let defaults = first_text(
+        &mut server,
+        "daisyui_scaffold_form",
+        json!({ "style": "progressive-disclosure" }),
+    );
+    let (required, folded) = defaults.split_once("collapse-content").unwrap();
+    assert!(required.contains(r#"name="Name""#) && required.contains(r#"name="Email""#));
+    assert!(folded.contains(r#"name="Company""#), "{}", defaults);
+
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_form", "arguments": { "style": "wizard" } }),
+    );
+    assert_eq!(response["error"]["code"], -32602);
+    assert_eq!(
+        response["error"]["message"],
+        "Unknown form style 'wizard'; use inline-validation, floating-labels, progressive-disclosure"
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn pricing_layout_has_plans_comparison_and_faq() {
+    let mut server = Server::spawn();
+    let tools = server.result("tools/list", json!({}));
+    let scaffold = tools["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|t| t["name"] == "daisyui_scaffold_layout")
+        .unwrap();
+    assert!(
+        scaffold["inputSchema"]["properties"]["layout"]["enum"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("pricing"))
+    );
+
+    let html = text_of(&server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "pricing", "title": "Acme Plans" } }),
+    ));
+    assert!(
+        html.contains(r#"<h1 class="text-5xl font-bold">Acme Plans</h1>"#),
+        "{}",
+        html
+    );
+    assert!(html.contains(r#"<div role="tablist" class="tabs tabs-box justify-center">"#));
+    assert!(html.contains(
+        r#"<input type="radio" name="dd-billing-1" class="tab" aria-label="Monthly" checked />"#
+    ));
+    assert!(
+        html.contains(
+            r#"<input type="radio" name="dd-billing-1" class="tab" aria-label="Yearly" />"#
+        )
+    );
+    // Three plans per billing period, one of them highlighted.
+    assert_eq!(html.matches(r#"<div class="card "#).count(), 6);
+    assert_eq!(html.matches("border-primary").count(), 2);
+    assert_eq!(html.matches("Most popular").count(), 2);
+    assert!(html.contains("$19</span><span class=\"opacity-60\">/month"));
+    assert!(html.contains("$190</span><span class=\"opacity-60\">/year"));
+
+    let table =
+        &html[html.find(r#"<table class="table">"#).unwrap()..html.find("</table>").unwrap()];
+    assert_eq!(table.matches("<tr>").count(), 6);
+    assert_eq!(table.matches("Not included").count(), 6);
+    assert_eq!(
+        table.matches(r#"<span class="sr-only">Included"#).count(),
+        9
+    );
+
+    assert_eq!(html.matches(r#"class="collapse collapse-arrow"#).count(), 3);
+    assert_eq!(html.matches(r#"name="dd-faq-1""#).count(), 3);
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_idea_to_ui", "arguments": { "prompt": "pricing plans for my app" } }),
+    );
+    assert!(text_of(&result).contains("Compare plans"));
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn checkout_layout_has_steps_payment_fields_and_summary() {
+    let mut server = Server::spawn();
+    let html = text_of(&server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "checkout", "title": "Pay up" } }),
+    ));
+    assert!(
+        html.contains(r#"<h1 class="text-4xl font-bold mt-2 mb-6">Pay up</h1>"#),
+        "{}",
+        html
+    );
+    let steps: Vec<&str> = html
+        .split(r#"<li class="step"#)
+        .skip(1)
+        .filter_map(|s| s.split('>').nth(1)?.split('<').next())
+        .collect();
+    assert_eq!(steps, ["Cart", "Shipping", "Payment", "Done"]);
+    assert!(html.contains(r#"<li class="step step-primary" aria-current="step">Payment</li>"#));
+
+    for (id, attrs) in [
+        (
+            "dd-checkout-1-email",
+            r#"type="email" autocomplete="email""#,
+        ),
+        (
+            "dd-checkout-1-card-number",
+            r#"type="text" inputmode="numeric" autocomplete="cc-number""#,
+        ),
+        (
+            "dd-checkout-1-expiry",
+            r#"type="text" inputmode="numeric" autocomplete="cc-exp""#,
+        ),
+        (
+            "dd-checkout-1-cvc",
+            r#"type="text" inputmode="numeric" autocomplete="cc-csc""#,
+        ),
+    ] {
+        assert!(
+            html.contains(&format!(r#"<label class="label" for="{}">"#, id)),
+            "{}",
+            id
+        );
+        let input = html
+            .split(&format!(r#"<input id="{}""#, id))
+            .nth(1)
+            .unwrap();
+        assert!(
+            input.split('>').next().unwrap().contains(attrs),
+            "{}: {}",
+            id,
+            input
+        );
+    }
+    assert!(!html.contains(r#"type="number""#));
+
+    assert!(html.contains(r#"<div class="join w-full">"#));
+    assert!(html.contains(r#"aria-label="Promo code""#));
+    assert!(html.contains("<span>Charging cable × 2</span><span>$38.00</span>"));
+    assert!(html.contains("<span>Subtotal</span><span>$167.00</span>"));
+    assert!(html.contains("<span>Shipping</span><span>$5.00</span>"));
+    assert!(html.contains("<span>Total</span><span>$172.00</span>"));
+    assert!(html.contains("Pay $172.00"));
+    assert!(html.contains(r#"href="/store""#));
+
+    for prompt in ["a checkout page", "cart and payment for my shop"] {
+        let result = server.result(
+            "tools/call",
+            json!({ "name": "daisyui_idea_to_ui", "arguments": { "prompt": prompt } }),
+        );
+        assert!(text_of(&result).contains("Order summary"), "{}", prompt);
+    }
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn error_layout_variants_come_from_the_title() {
+    let mut server = Server::spawn();
+    let mut page = |title: &str| {
+        text_of(&server.result(
+            "tools/call",
+            json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "error", "title": title } }),
+        ))
+    };
+    for (title, code, heading) in [
+        ("", "404", "Page not found"),
+        ("Acme", "404", "Page not found"),
+        ("404", "404", "Page not found"),
+        ("500", "500", "Something went wrong"),
+        ("Maintenance", "503", "Down for maintenance"),
+    ] {
+        let html = page(title);
+        assert!(
+            html.contains(&format!(
+                r#"<p class="text-9xl font-black text-primary/80">{}</p>"#,
+                code
+            )),
+            "{}: {}",
+            title,
+            html
+        );
+        assert!(html.contains(&format!(
+            "<h1 class=\"text-3xl font-bold mt-4\">{}</h1>",
+            heading
+        )));
+        assert!(html.contains(r#"<a href="/" class="btn btn-primary">Go home</a>"#));
+        assert!(html.contains(r#"<a href="/support" class="btn btn-ghost">Contact support</a>"#));
+        assert!(html.contains("bg-[radial-gradient("), "{}", title);
+        assert_eq!(html.contains("alert-warning"), code == "503", "{}", title);
+    }
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_explain_idea", "arguments": { "prompt": "an error page for when things are not found" } }),
+    );
+    let text = text_of(&result);
+    assert!(
+        text.contains("| error ✓ | not found (2), error page (3) | 5 |"),
+        "{}",
+        text
+    );
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_explain_idea", "arguments": { "prompt": "not much of a page" } }),
+    );
+    assert!(!text_of(&result).contains("error ✓"));
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn crud_layout_has_toolbar_table_pagination_and_modals() {
+    let mut server = Server::spawn();
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "crud", "title": "Customers" } }),
+    );
+    let html = text_of(&result);
+    for needle in [
+        r#"<h1 class="text-3xl font-bold mb-6">Customers</h1>"#,
+        r#"type="search""#,
+        r#"<div class="dropdown">"#,
+        r#"class="btn btn-primary ml-auto" onclick="document.getElementById('dd-crud-1-edit').showModal()">New</button>"#,
+        r#"<table class="table table-zebra">"#,
+        r#"aria-label="Select all""#,
+        r#"<span class="badge badge-success">Active</span>"#,
+        r#"<div class="dropdown dropdown-end">"#,
+        r#"<div class="join">"#,
+        r#"<dialog id="dd-crud-1-edit" class="modal">"#,
+        r#"<dialog id="dd-crud-1-delete" class="modal">"#,
+        r#"class="btn btn-error">Delete</button>"#,
+    ] {
+        assert!(html.contains(needle), "{}\n{}", needle, html);
+    }
+    assert_eq!(html.matches(r#"class="checkbox checkbox-sm""#).count(), 5);
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_explain_idea", "arguments": { "prompt": "an admin table to manage customer records" } }),
+    );
+    let text = text_of(&result);
+    assert!(
+        text.contains("| crud ✓ | admin table (2), manage (1), records (2) | 5 |"),
+        "{}",
+        text
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn app_landing_layout_has_phone_mockup_carousel_reviews_and_mobile_bar() {
+    let mut server = Server::spawn();
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "app-landing", "title": "Pocket" } }),
+    );
+    let html = text_of(&result);
+    for needle in [
+        r#"<div class="mockup-phone">"#,
+        r#"<div class="mockup-phone-display">"#,
+        r#"aria-label="Download on the App Store""#,
+        r#"aria-label="Get it on Google Play""#,
+        r#"<div class="carousel carousel-center w-full gap-4 px-4">"#,
+        r#"<div class="rating rating-sm" aria-label="4 out of 5 stars">"#,
+        "md:hidden",
+        r##"<a href="#" class="btn btn-primary">Get the app</a>"##,
+    ] {
+        assert!(html.contains(needle), "{}\n{}", needle, html);
+    }
+    assert_eq!(html.matches(r#"<div class="carousel-item">"#).count(), 5);
+    assert_eq!(html.matches(r#"aria-current="true""#).count(), 3);
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_explain_idea", "arguments": { "prompt": "an app landing page for our ios and android mobile app" } }),
+    );
+    let text = text_of(&result);
+    assert!(
+        text.contains(
+            "| app-landing ✓ | mobile app (3), ios (2), android (2), app landing (3) | 10 |"
+        ),
+        "{}",
+        text
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn forum_layout_has_threads_nested_replies_and_new_topic_modal() {
+    let mut server = Server::spawn();
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "forum", "title": "Community" } }),
+    );
+    let html = text_of(&result);
+    for needle in [
+        r#"<li class="menu-title">Categories</li>"#,
+        r#"<li><a class="menu-active">General</a></li>"#,
+        r#"<ul class="list bg-base-100 rounded-box shadow-sm">"#,
+        r#"<span class="badge badge-ghost" aria-label="31 replies">31</span>"#,
+        "avatar-placeholder",
+        "last activity 5 minutes ago",
+        r#"<article class="card bg-base-100 shadow-sm ml-16 border-l-4 border-base-300">"#,
+        r#"<textarea id="dd-forum-1-reply""#,
+        r#"onclick="document.getElementById('dd-forum-1-new-topic').showModal()">New topic</button>"#,
+        r#"<dialog id="dd-forum-1-new-topic" class="modal">"#,
+    ] {
+        assert!(html.contains(needle), "{}\n{}", needle, html);
+    }
+    assert_eq!(
+        html.matches(r#"badge-primary badge-sm">Pinned</span>"#)
+            .count(),
+        1
+    );
+    assert_eq!(html.matches("<article ").count(), 4);
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_explain_idea", "arguments": { "prompt": "a community forum for discussion threads" } }),
+    );
+    let text = text_of(&result);
+    assert!(
+        text.contains("| forum ✓ | forum (3), community (2), discussion (2), threads (2) | 9 |"),
+        "{}",
+        text
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn analytics_layout_places_chart_canvases_with_create_chart_ids() {
+    let mut server = Server::spawn();
+    let mut layout = |arguments: Value| {
+        text_of(&server.result(
+            "tools/call",
+            json!({ "name": "daisyui_scaffold_layout", "arguments": arguments }),
+        ))
+    };
+    let html = layout(json!({ "layout": "analytics", "title": "Traffic" }));
+    for needle in [
+        r#"<h1 class="text-3xl font-bold mr-auto">Traffic</h1>"#,
+        r#"<input type="date" name="from" class="input input-bordered join-item" aria-label="From" />"#,
+        r#"<input type="date" name="to" class="input input-bordered join-item" aria-label="To" />"#,
+        r#"<div class="stats stats-vertical lg:stats-horizontal shadow w-full bg-base-100">"#,
+        r#"<span class="badge badge-soft badge-success">↑ 12%</span>"#,
+        r#"<span class="badge badge-soft badge-error">↓ 3%</span>"#,
+        r#"<canvas id="dd-chart-1" data-chart="line""#,
+        r#"<canvas id="dd-chart-2" data-chart="bar""#,
+        r#"<canvas id="dd-chart-3" data-chart="doughnut""#,
+        "<h2 class=\"card-title\">Top sources</h2>",
+        r#"<div tabindex="0" role="button" class="btn btn-primary">Export</div>"#,
+    ] {
+        assert!(html.contains(needle), "{}\n{}", needle, html);
+    }
+    assert_eq!(html.matches("<canvas").count(), 3);
+
+    let html =
+        layout(json!({ "layout": "analytics", "charts": ["pie", "radar"], "id_prefix": "app" }));
+    assert!(html.contains(r#"<canvas id="app-chart-1" data-chart="pie""#));
+    assert!(html.contains(r#"<canvas id="app-chart-2" data-chart="radar""#));
+    assert_eq!(html.matches("<canvas").count(), 2);
+    let chart = text_of(&server.result(
+        "tools/call",
+        json!({ "name": "daisyui_create_chart", "arguments": { "type": "pie", "id_prefix": "app" } }),
+    ));
+    assert!(
+        chart.starts_with(r#"<canvas id="app-chart-1">"#),
+        "{}",
+        chart
+    );
+
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "analytics", "charts": ["line", "gauge"] } }),
+    );
+    assert_eq!(response["error"]["code"], -32602);
+    let message = response["error"]["message"].as_str().unwrap();
+    assert!(message.starts_with("Unknown chart type 'gauge'; use one of: line, bar"));
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_explain_idea", "arguments": { "prompt": "a weekly report with traffic insights" } }),
+    );
+    let text = text_of(&result);
+    assert!(
+        text.contains("| analytics ✓ | report (1), insights (2) | 3 |"),
+        "{}",
+        text
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn settings_layout_shows_the_first_tab_panel_and_hides_the_rest() {
+    let mut server = Server::spawn();
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "settings", "title": "Settings", "footprint": true } }),
+    );
+    let html = text_of(&result);
+    assert!(html.contains(r#"<div role="tablist" class="tabs tabs-box">"#));
+    for (key, label) in [
+        ("general", "General"),
+        ("security", "Security"),
+        ("api-keys", "API Keys"),
+        ("integrations", "Integrations"),
+        ("billing", "Billing"),
+    ] {
+        let hidden = if key == "general" { "" } else { " hidden" };
+        assert!(
+            html.contains(&format!(
+                r#"aria-controls="dd-settings-1-{}" data-tab="{}">{}</button>"#,
+                key, key, label
+            )),
+            "{}",
+            key
+        );
+        assert!(
+            html.contains(&format!(
+                r#"<section id="dd-settings-1-{}" role="tabpanel" aria-labelledby="dd-settings-1-{}-tab" class="card bg-base-100 shadow-sm" data-panel="{}"{}>"#,
+                key, key, key, hidden
+            )),
+            "{}\n{}",
+            key,
+            html
+        );
+    }
+    for needle in [
+        r#"class="tab tab-active" aria-selected="true""#,
+        r#"class="toggle toggle-primary" checked"#,
+        r#"autocomplete="current-password""#,
+        r#"data-copy="dd_live_8f3k2m9q7x4t1c6w""#,
+        r#"<button type="button" class="btn btn-primary btn-sm">Connect</button>"#,
+        r#"<span class="badge badge-success">Paid</span>"#,
+        "const root = document.getElementById('dd-settings-1');",
+    ] {
+        assert!(html.contains(needle), "{}\n{}", needle, html);
+    }
+    let footprint = result["content"][1]["text"].as_str().unwrap();
+    assert!(footprint.contains("settings"), "{}", footprint);
+
+    let script = text_of(&server.result(
+        "tools/call",
+        json!({ "name": "daisyui_get_script", "arguments": { "component": "settings" } }),
+    ));
+    assert!(html.contains(&script));
+
+    for (prompt, layout) in [
+        ("team settings", "settings"),
+        ("user profile", "profile"),
+        ("account preferences", "profile"),
+    ] {
+        let result = server.result(
+            "tools/call",
+            json!({ "name": "daisyui_explain_idea", "arguments": { "prompt": prompt } }),
+        );
+        let text = text_of(&result);
+        assert!(
+            text.contains(&format!("| {} ✓ |", layout)),
+            "{}: {}",
+            prompt,
+            text
+        );
+    }
+    assert!(server.shutdown().is_empty());
+}
+
+/// Values of `attr` on every element of `html`, in order.
+fn attribute_values(html: &str, attr: &str) -> Vec<String> {
+    let needle = format!(" {}=\"", attr);
+    html.match_indices(&needle)
+        .map(|(i, _)| {
+            let rest = &html[i + needle.len()..];
+            rest[..rest.find('"').unwrap_or(rest.len())].to_string()
+        })
+        .collect()
+}
+
+/// Ids defined more than once in `html`, and references to ids it doesn't
+/// define: `for`, `#` links, `aria-*` id lists and `getElementById` calls.
+fn id_problems(html: &str) -> Vec<String> {
+    let ids = attribute_values(html, "id");
+    let mut problems = Vec::new();
+    for (i, id) in ids.iter().enumerate() {
+        if ids[..i].contains(id) {
+            problems.push(format!("duplicate id {}", id));
+        }
+    }
+    let mut references: Vec<String> = attribute_values(html, "for");
+    for attr in ["aria-labelledby", "aria-describedby", "aria-controls"] {
+        for value in attribute_values(html, attr) {
+            references.extend(value.split_whitespace().map(String::from));
+        }
+    }
+    references.extend(
+        attribute_values(html, "href")
+            .into_iter()
+            .filter_map(|href| href.strip_prefix('#').map(String::from))
+            .filter(|target| !target.is_empty()),
+    );
+    let call = "getElementById('";
+    references.extend(html.match_indices(call).map(|(i, _)| {
+        let rest = &html[i + call.len()..];
+        rest[..rest.find('\'').unwrap_or(rest.len())].to_string()
+    }));
+    for target in references {
+        if !ids.contains(&target) {
+            problems.push(format!("unresolved reference {}", target));
+        }
+    }
+    problems
+}
+
+#[test]
+fn pages_composed_from_several_calls_keep_ids_apart() {
+    let mut server = Server::spawn();
+    let mut call = |name: &str, arguments: Value| first_text(&mut server, name, arguments);
+    // Two modals: the FAQ's support form and the forum's new-topic form, plus
+    // a second FAQ to show the prefix keeps one generator's ids apart.
+    let faq = call(
+        "daisyui_scaffold_layout",
+        json!({ "layout": "faq", "id_prefix": "help" }),
+    );
+    let more = call(
+        "daisyui_scaffold_layout",
+        json!({ "layout": "faq", "id_prefix": "more" }),
+    );
+    let forum = call(
+        "daisyui_scaffold_layout",
+        json!({ "layout": "forum", "id_prefix": "talk" }),
+    );
+    // Two charts, drawn by the scripts `daisyui_create_chart` gives for the
+    // same ids; its own canvases are left out since the layout has them.
+    let analytics = call(
+        "daisyui_scaffold_layout",
+        json!({ "layout": "analytics", "charts": ["line", "bar"], "id_prefix": "stats" }),
+    );
+    let canvases: Vec<String> = attribute_values(&analytics, "id")
+        .into_iter()
+        .filter(|id| id.starts_with("stats-chart-"))
+        .collect();
+    assert_eq!(canvases, ["stats-chart-1", "stats-chart-2"]);
+    let scripts: String = canvases
+        .iter()
+        .zip(["line", "bar"])
+        .map(|(id, kind)| {
+            let chart = call("daisyui_create_chart", json!({ "type": kind, "id": id }));
+            chart[chart.find("<script>").unwrap()..].to_string()
+        })
+        .collect();
+    // Tabs, switched by the layout's own script.
+    let settings = call(
+        "daisyui_scaffold_layout",
+        json!({ "layout": "settings", "id_prefix": "account" }),
+    );
+    let page = [&faq, &more, &forum, &analytics, &scripts, &settings]
+        .map(|part| part.as_str())
+        .join("\n");
+
+    assert_eq!(page.matches("<dialog").count(), 3);
+    assert_eq!(page.matches("<canvas").count(), 2);
+    assert_eq!(page.matches("new Chart(").count(), 2);
+    assert!(page.contains(r#"role="tablist""#));
+    assert_eq!(id_problems(&page), Vec::<String>::new());
+    for id in [
+        "help-help-1-support",
+        "more-help-1-support",
+        "talk-forum-1-new-topic",
+        "stats-chart-1",
+        "account-settings-1",
+    ] {
+        assert!(page.contains(&format!("id=\"{}\"", id)), "{}", id);
+    }
+    // Each chart script draws its own canvas.
+    for id in &canvases {
+        let call = format!("new Chart(document.getElementById('{}')", id);
+        assert_eq!(scripts.matches(&call).count(), 1, "{}", id);
+    }
+    // Every settings tab has its panel.
+    let tabs = attribute_values(&settings, "data-tab");
+    assert!(tabs.len() > 1);
+    assert_eq!(tabs, attribute_values(&settings, "data-panel"));
+
+    // Without prefixes the two FAQs collide, and the check says so.
+    let plain = call("daisyui_scaffold_layout", json!({ "layout": "faq" }));
+    let problems = id_problems(&format!("{}\n{}", plain, plain));
+    assert!(
+        problems.contains(&"duplicate id dd-help-1-support".to_string()),
+        "{:?}",
+        problems
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn status_layout_has_services_uptime_bars_incidents_and_subscribe_form() {
+    let mut server = Server::spawn();
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "status", "title": "Acme Status" } }),
+    );
+    let html = text_of(&result);
+    for needle in [
+        r#"<div role="alert" class="alert alert-success"><span>All systems operational</span></div>"#,
+        r#"<span class="badge badge-success">Operational</span>"#,
+        r#"<span class="badge badge-warning">Degraded</span>"#,
+        r#"aria-label="99.95% uptime over the last 90 days""#,
+        r#"<ul class="timeline timeline-vertical timeline-compact">"#,
+        "Elevated API latency",
+        r#"<input id="dd-subscribe-1-email" name="email" type="email""#,
+        ">Subscribe</button>",
+    ] {
+        assert!(html.contains(needle), "{}\n{}", needle, html);
+    }
+    let days = r#"<div class="h-8 flex-1 rounded-sm bg-"#;
+    assert_eq!(html.matches(days).count(), 4 * 90);
+    assert_eq!(html.matches(&format!("{}error", days)).count(), 2);
+    assert_eq!(html.matches(&format!("{}warning", days)).count(), 4);
+    // The webhooks bar ends with today's issue.
+    assert!(html.contains(&format!(
+        r#"{}warning"></div>
+            </div>
+            <div class="flex justify-between text-xs text-base-content/60 mt-1"><span>90 days ago</span><span>99.80% uptime"#,
+        days
+    )));
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_explain_idea", "arguments": { "prompt": "a status page showing uptime and past incidents" } }),
+    );
+    let text = text_of(&result);
+    assert!(
+        text.contains("| status ✓ | status page (3), uptime (2), incidents (2) | 7 |"),
+        "{}",
+        text
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn media_layout_has_library_drawer_album_grid_and_player_bar() {
+    let mut server = Server::spawn();
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "media", "title": "Tunes", "annotate_responsive": true } }),
+    );
+    let html = text_of(&result);
+    for needle in [
+        r#"<div class="drawer lg:drawer-open">"#,
+        r#"<input id="dd-drawer-1" type="checkbox" class="drawer-toggle" />"#,
+        r#"<label for="dd-drawer-1" class="btn btn-square btn-ghost" aria-label="Open library">"#,
+        r#"<li class="menu-title">Your library</li>"#,
+        r#"<div class="card bg-base-200 group">"#,
+        r#"opacity-0 group-hover:opacity-100 focus:opacity-100 transition-opacity" aria-label="Play Night Drive">"#,
+        r#"<div class="fixed bottom-0 inset-x-0 z-50"#,
+        "<div class=\"avatar\">\n        <div class=\"w-12 rounded",
+        r#"<button type="button" class="btn btn-circle btn-primary" aria-label="Play">"#,
+        r#"class="range range-xs range-primary flex-1" aria-label="Seek" />"#,
+        r#"aria-label="Volume" />"#,
+        "off-canvas drawer below lg",
+    ] {
+        assert!(html.contains(needle), "{}\n{}", needle, html);
+    }
+    assert_eq!(html.matches("btn-circle").count(), 6 + 3);
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_explain_idea", "arguments": { "prompt": "a spotify style music player for podcasts" } }),
+    );
+    let text = text_of(&result);
+    assert!(
+        text.contains("| media ✓ | music (2), player (1), spotify (3), podcast (2) | 8 |"),
+        "{}",
+        text
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn jobs_layout_has_search_filters_listings_and_pagination() {
+    let mut server = Server::spawn();
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "jobs", "title": "Careers" } }),
+    );
+    let html = text_of(&result);
+    for needle in [
+        r#"<form class="join w-full">"#,
+        r#"<input id="dd-jobs-1-keyword" name="q" type="search""#,
+        r#"<input id="dd-jobs-1-location" name="location""#,
+        r#"value="remote" class="checkbox checkbox-sm checkbox-primary" checked />"#,
+        r#"value="contract" class="checkbox checkbox-sm checkbox-primary" />"#,
+        r#"<input id="dd-jobs-1-salary" name="salary" type="range""#,
+        r#"<select id="dd-jobs-1-sort" name="sort" class="select select-bordered select-sm""#,
+        "avatar-placeholder",
+        r#"<span class="badge badge-outline">"#,
+        "k–$",
+        r#"aria-current="page">1</button>"#,
+    ] {
+        assert!(html.contains(needle), "{}\n{}", needle, html);
+    }
+    assert_eq!(html.matches(">Apply</button>").count(), 4);
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_explain_idea", "arguments": { "prompt": "a hiring marketplace with job listings" } }),
+    );
+    let text = text_of(&result);
+    assert!(
+        text.contains("| jobs ✓ | hiring (2), listings (1), marketplace (1) | 4 |"),
+        "{}",
+        text
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn changelog_layout_has_release_timeline_and_older_releases() {
+    let mut server = Server::spawn();
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "changelog", "title": "What's new" } }),
+    );
+    let html = text_of(&result);
+    for needle in [
+        r#"<a href="/changelog.xml" class="btn btn-outline btn-sm">RSS</a>"#,
+        r#"<ul class="timeline timeline-vertical timeline-compact">"#,
+        r#"<span class="badge badge-primary">v2.4.0</span><time class="text-sm text-base-content/60">Oct 8, 2026</time>"#,
+        r#"<span class="badge badge-success badge-sm">Added</span>"#,
+        r#"<span class="badge badge-info badge-sm">Fixed</span>"#,
+        r#"<span class="badge badge-warning badge-sm">Changed</span>"#,
+        r#"<div class="collapse-title font-medium">Older releases</div>"#,
+    ] {
+        assert!(html.contains(needle), "{}\n{}", needle, html);
+    }
+    let (timeline, older) = html.split_once("Older releases").unwrap();
+    assert_eq!(timeline.matches("badge-primary\">v").count(), 3);
+    assert_eq!(older.matches("badge-ghost\">v").count(), 2);
+    assert!(older.contains("v2.1.0") && !timeline.contains("v2.1.0"));
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_explain_idea", "arguments": { "prompt": "a what's new page with release notes for all versions" } }),
+    );
+    let text = text_of(&result);
+    assert!(
+        text.contains("| changelog ✓ | release notes (3), what's new (2), versions (1) | 6 |"),
+        "{}",
+        text
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn team_layout_has_members_values_offices_and_join_us() {
+    let mut server = Server::spawn();
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "team", "title": "About Acme" } }),
+    );
+    let html = text_of(&result);
+    for needle in [
+        r#"<h1 class="text-5xl font-bold">About Acme</h1>"#,
+        "Our mission is",
+        r#"<div class="grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-3 gap-6">"#,
+        r##"<a href="#" class="btn btn-ghost btn-circle btn-sm" aria-label="Grace Hopper on GitHub">GH</a>"##,
+        "<h3 class=\"card-title\">Work anywhere</h3>",
+        r#"<div class="carousel carousel-center w-full gap-4 px-4">"#,
+        r#"<a href="/jobs" class="btn btn-primary btn-lg">See open roles</a>"#,
+    ] {
+        assert!(html.contains(needle), "{}\n{}", needle, html);
+    }
+    assert_eq!(html.matches("avatar-placeholder").count(), 6);
+    assert_eq!(html.matches(r#"<div class="carousel-item">"#).count(), 4);
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_explain_idea", "arguments": { "prompt": "an about us page: who we are and our team" } }),
+    );
+    let text = text_of(&result);
+    assert!(
+        text.contains("| team ✓ | team (1), about us (3), who we are (2) | 6 |"),
+        "{}",
+        text
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn faq_layout_groups_questions_and_opens_a_support_modal() {
+    let mut server = Server::spawn();
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "faq", "title": "Help Center" } }),
+    );
+    let html = text_of(&result);
+    for needle in [
+        "<p class=\"py-4\">How can we help?</p>",
+        r#"<label class="input input-lg w-full text-base-content">"#,
+        r##"<a href="#dd-help-1-billing" class="card"##,
+        r#"<section id="dd-help-1-account" class="space-y-2" aria-labelledby="dd-help-1-account-heading">"#,
+        r#"<input type="radio" name="dd-help-1-billing" aria-label="Can I change plans at any time?" />"#,
+        "<h2 class=\"card-title\">Still need help?</h2>",
+        r#"onclick="document.getElementById('dd-help-1-support').showModal()">Contact support</button>"#,
+        r#"<dialog id="dd-help-1-support" class="modal">"#,
+    ] {
+        assert!(html.contains(needle), "{}\n{}", needle, html);
+    }
+    assert_eq!(html.matches("collapse collapse-arrow").count(), 6);
+    let headings: Vec<usize> = [
+        ("getting-started", "Getting Started"),
+        ("account", "Account"),
+        ("billing", "Billing"),
+    ]
+    .iter()
+    .map(|(key, h)| {
+        html.find(&format!(
+            "<h2 id=\"dd-help-1-{}-heading\" class=\"text-2xl font-bold mb-2\">{}</h2>",
+            key, h
+        ))
+        .unwrap()
+    })
+    .collect();
+    assert!(headings.windows(2).all(|w| w[0] < w[1]));
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_explain_idea", "arguments": { "prompt": "a help center and knowledge base for support" } }),
+    );
+    let text = text_of(&result);
+    assert!(
+        text.contains("| faq ✓ | help center (3), support (1), knowledge base (2) | 6 |"),
+        "{}",
+        text
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn survey_layout_shows_one_question_per_page_with_a_script() {
+    let mut server = Server::spawn();
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "survey", "title": "Web quiz", "footprint": true } }),
+    );
+    let html = text_of(&result);
+    for needle in [
+        r#"<div id="dd-survey-1" class="min-h-screen"#,
+        r#"<progress class="progress progress-primary" value="1" max="3""#,
+        r#"<fieldset class="space-y-3" data-step="1">"#,
+        r#"<fieldset class="space-y-3" data-step="2" hidden>"#,
+        r#"<fieldset class="space-y-3" data-step="3" hidden>"#,
+        r#"<input type="radio" name="dd-survey-1-q1" value="1" class="radio radio-primary" data-correct />"#,
+        "<span>&lt;dialog&gt;</span>",
+        r#"<div class="text-center space-y-4" data-step="results" hidden>"#,
+        r#"<div class="radial-progress text-primary mx-auto""#,
+        r#"data-survey-back disabled>Back</button>"#,
+        "const root = document.getElementById('dd-survey-1');",
+    ] {
+        assert!(html.contains(needle), "{}\n{}", needle, html);
+    }
+    assert_eq!(html.matches(" data-correct />").count(), 3);
+    assert_eq!(html.matches("has-[:checked]:border-primary").count(), 9);
+    let footprint = result["content"][1]["text"].as_str().unwrap();
+    assert!(footprint.contains("survey"), "{}", footprint);
+
+    let script = text_of(&server.result(
+        "tools/call",
+        json!({ "name": "daisyui_get_script", "arguments": { "component": "survey" } }),
+    ));
+    assert!(html.contains(&script));
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_explain_idea", "arguments": { "prompt": "a quick quiz or poll" } }),
+    );
+    let text = text_of(&result);
+    assert!(
+        text.contains("| survey ✓ | quiz (3), poll (2) | 5 |"),
+        "{}",
+        text
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn variants_scale_padding_gaps_and_text_together() {
+    let mut server = Server::spawn();
+    let mut scaffold = |variant: Option<&str>| {
+        let mut arguments = json!({ "layout": "dashboard", "title": "Admin" });
+        if let Some(variant) = variant {
+            arguments["variant"] = json!(variant);
+        }
+        first_text(&mut server, "daisyui_scaffold_layout", arguments)
+    };
+    let default = scaffold(None);
+    assert_eq!(scaffold(Some("comfortable")), default);
+    assert!(default.contains("<main class=\"p-6\">\n      <h1 class=\"text-2xl font-bold mb-4\">"));
+    assert!(default.contains(r#"<ul class="menu p-4 w-80"#));
+
+    let compact = scaffold(Some("compact"));
+    assert!(compact.contains("<main class=\"p-2\">\n      <h1 class=\"text-xl font-bold mb-1\">"));
+    assert!(compact.contains(r#"<ul class="menu p-1 w-80"#));
+    let spacious = scaffold(Some("spacious"));
+    assert!(
+        spacious.contains("<main class=\"p-10\">\n      <h1 class=\"text-3xl font-bold mb-7\">")
+    );
+    assert!(spacious.contains(r#"<ul class="menu p-7 w-80"#));
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "dashboard", "title": "Admin", "variant": "roomy" } }),
+    );
+    let note = result["content"][0]["text"].as_str().unwrap();
+    assert!(
+        note.contains("Unknown variant 'roomy', so comfortable was used"),
+        "{}",
+        note
+    );
+    assert_eq!(result["content"][1]["text"].as_str().unwrap(), default);
+
+    let diff = first_text(
+        &mut server,
+        "daisyui_layout_diff",
+        json!({ "to": { "variant": "spacious" } }),
+    );
+    assert!(diff.contains("variant: roomy → spacious"), "{}", diff);
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn themes_scope_the_whole_layout_and_are_validated() {
+    let mut server = Server::spawn();
+    let mut scaffold = |layout: &str, theme: &str| {
+        server.request(
+            "tools/call",
+            json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": layout, "theme": theme } }),
+        )
+    };
+    let saas = text_of(&scaffold("saas", "dracula")["result"]);
+    assert!(saas.contains(r#"<div data-theme="dracula" class="min-h-screen"#));
+    assert_eq!(saas.matches("data-theme").count(), 1);
+    // The edit and delete modals follow the table, so the layout is wrapped.
+    let crud = text_of(&scaffold("crud", "dracula")["result"]);
+    assert!(
+        crud.starts_with("<div data-theme=\"dracula\">\n  <div class=\"min-h-screen"),
+        "{}",
+        crud
+    );
+    assert!(crud.ends_with("</dialog>\n</div>"));
+    assert_eq!(crud.matches("data-theme").count(), 1);
+
+    let typo = scaffold("saas", "draculla");
+    let message = typo["error"]["message"].as_str().unwrap();
+    assert!(message.contains("Unknown theme 'draculla'"), "{}", message);
+    assert!(message.contains("dracula, cmyk"), "{}", message);
+    assert!(scaffold("saas", "brand").get("error").is_some());
+
+    server.result(
+        "tools/call",
+        json!({ "name": "daisyui_generate_theme", "arguments": { "name": "brand", "primary": "#ff0000" } }),
+    );
+    let brand = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "auth", "theme": "brand" }),
+    );
+    assert!(brand.starts_with(r#"<div data-theme="brand" class="min-h-screen"#));
+
+    let mut preview = |theme: &str| {
+        let result = server.result(
+            "tools/call",
+            json!({ "name": "daisyui_preview_file", "arguments": { "layout": "store", "title": "Theme preview", "theme": theme, "output": "file" } }),
+        );
+        let summary = result["content"][0]["text"].as_str().unwrap().to_string();
+        let path = result["content"][1]["text"].as_str().unwrap().to_string();
+        (summary, std::fs::read_to_string(path).unwrap())
+    };
+    let (_, page) = preview("dracula");
+    assert!(page.contains(r#"<html lang="en" data-theme="dracula">"#));
+    assert!(page.contains("[data-theme=\"dracula\"] {\n  color-scheme: dark;"));
+    assert!(page.contains("--color-primary: #ff79c6;"));
+    let (summary, page) = preview("brand");
+    assert!(page.contains(r#"data-theme="brand""#));
+    assert!(
+        summary.contains("no inlined colors for theme 'brand'"),
+        "{}",
+        summary
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn full_documents_wrap_the_layout_in_a_themed_page() {
+    let mut server = Server::spawn();
+    let html = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "faq", "title": "Help", "theme": "cupcake", "full_document": true }),
+    );
+    assert!(
+        html.starts_with("<!DOCTYPE html>\n<html lang=\"en\" data-theme=\"cupcake\">\n<head>"),
+        "{}",
+        html
+    );
+    assert!(html.ends_with("</script>\n</body>\n</html>\n"));
+    assert_eq!(html.matches("<body>").count(), 1);
+    assert_eq!(html.matches("</body>").count(), 1);
+    // The theme lives on <html> so the toggle can switch it.
+    assert_eq!(html.matches("data-theme=\"cupcake\"").count(), 1);
+    assert!(html.contains("<title>Help</title>"));
+    assert!(html.contains("data-theme-toggle aria-label=\"Toggle theme\""));
+    assert!(html.contains("<body>\n<div class=\"min-h-screen"));
+
+    let fragment = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "faq", "title": "Help" }),
+    );
+    assert!(!fragment.contains("<body"));
+    assert!(html.contains(&fragment));
+
+    let stamped = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "analytics", "full_document": true, "provenance": true }),
+    );
+    assert!(stamped.contains("\"full_document\":true"));
+    assert!(stamped.contains("https://cdn.jsdelivr.net/npm/chart.js"));
+    let reproduced = first_text(
+        &mut server,
+        "daisyui_reproduce",
+        json!({ "comment": stamped }),
+    );
+    assert_eq!(reproduced, stamped);
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn jsx_target_returns_a_react_component() {
+    let mut server = Server::spawn();
+    let crud = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "crud", "title": "Team Members", "target": "jsx" }),
+    );
+    assert!(
+        crud.starts_with("export default function TeamMembers() {\n  return (\n    <>\n"),
+        "{}",
+        crud
+    );
+    assert!(crud.ends_with("    </>\n  );\n}\n"));
+    assert!(crud.contains("onClick={() => document.getElementById('dd-crud-1-edit').showModal()}"));
+    assert!(crud.contains("className=\""));
+    assert!(!crud.contains(" class="));
+    assert!(!crud.contains(" onclick="));
+
+    let survey = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "survey", "target": "jsx" }),
+    );
+    assert!(
+        survey.contains(r#"style={{ "--value": "0", "--size": "8rem" }}"#),
+        "{}",
+        survey
+    );
+    assert!(survey.contains("{/* inline <script> left out: React doesn't run it */}"));
+    assert!(!survey.contains("</script>"));
+
+    let diff = first_text(
+        &mut server,
+        "daisyui_layout_diff",
+        json!({ "to": { "target": "html" } }),
+    );
+    assert!(diff.contains("target: jsx → html"), "{}", diff);
+
+    for (arguments, expected) in [
+        (
+            json!({ "layout": "saas", "target": "vue" }),
+            "Unknown target 'vue'",
+        ),
+        (
+            json!({ "layout": "saas", "target": "jsx", "full_document": true }),
+            "full_document builds an HTML page",
+        ),
+    ] {
+        let response = server.request(
+            "tools/call",
+            json!({ "name": "daisyui_scaffold_layout", "arguments": arguments }),
+        );
+        let message = response["error"]["message"].as_str().unwrap_or_default();
+        assert!(message.contains(expected), "{}", response);
+    }
+    assert!(server.shutdown().is_empty());
+}
+
+/// Checks a Tera template's blocks nest and every `{{ }}` reads the title
+/// or an open loop's variable; returns how many loops it has.
+fn tera_loops(text: &str) -> Result<usize, String> {
+    let mut open: Vec<&str> = Vec::new();
+    let mut count = 0;
+    let mut rest = text;
+    while let Some(start) = rest.find(['{']) {
+        rest = &rest[start..];
+        let close = match rest.get(..2) {
+            Some("{%") => "%}",
+            Some("{{") => "}}",
+            Some("{#") => "#}",
+            _ => {
+                rest = &rest[1..];
+                continue;
+            }
+        };
+        let end = rest.find(close).ok_or("unclosed tag")?;
+        let words: Vec<&str> = rest[2..end].split_whitespace().collect();
+        match (close, words.as_slice()) {
+            ("#}", _) => {}
+            ("%}", ["for", var, "in", list]) => {
+                let root = list.split('.').next().unwrap_or_default();
+                if list.contains('.') && !open.contains(&root) {
+                    return Err(format!("{} isn't in scope", list));
+                }
+                open.push(var);
+                count += 1;
+            }
+            ("%}", ["endfor"]) => {
+                open.pop().ok_or("endfor without a for")?;
+            }
+            ("}}", [value]) => {
+                let root = value.split('.').next().unwrap_or_default();
+                if root != "title" && !open.contains(&root) {
+                    return Err(format!("{} isn't in scope", value));
+                }
+            }
+            (_, other) => return Err(format!("unexpected tag {:?}", other)),
+        }
+        rest = &rest[end + 2..];
+    }
+    match open.is_empty() {
+        true => Ok(count),
+        false => Err(format!("unclosed loops: {:?}", open)),
+    }
+}
+
+#[test]
+fn askama_and_tera_targets_loop_over_repeated_items() {
+    let mut server = Server::spawn();
+    for (layout, expected) in [
+        ("saas", "{% for link in section.links %}"),
+        ("blog", "<img src=\"{{ item.image }}\""),
+        ("dashboard", "{{ title }}</div>"),
+        ("store", "<h1 class=\"text-5xl font-bold\">{{ title }}</h1>"),
+        ("crud", "<td>{{ row.email }}</td>"),
+    ] {
+        for target in ["tera", "askama"] {
+            let text = first_text(
+                &mut server,
+                "daisyui_scaffold_layout",
+                json!({ "layout": layout, "title": "Acme", "target": target }),
+            );
+            let loops = tera_loops(&text).unwrap_or_else(|e| panic!("{} {}: {}", layout, e, text));
+            assert_eq!(loops > 0, layout != "dashboard", "{}", text);
+            assert!(text.contains(expected), "{}", text);
+            assert!(!text.contains(">Acme<"), "{}", text);
+        }
+    }
+
+    let crud = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "crud", "title": "Acme", "target": "tera" }),
+    );
+    assert!(crud.starts_with("{#\n  Tera context for this template:\n    title: string\n"));
+    assert!(crud.contains("    rows: list of { name, email, status_class, status, updated }"));
+    assert_eq!(crud.matches("{% for row in rows %}").count(), 1);
+    assert_eq!(
+        crud.matches("<tr>").count(),
+        2,
+        "header row and one body row"
+    );
+    assert!(crud.contains("aria-label=\"Select {{ row.name }}\""));
+    let askama = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "crud", "title": "Acme", "target": "askama" }),
+    );
+    assert!(askama.contains("  #[template(path = \"acme.html\")]\n  struct Acme {\n"));
+    assert!(askama.contains("      rows: Vec<Row>,\n"));
+    assert_eq!(
+        askama.split_once("#}\n").map(|(_, body)| body),
+        crud.split_once("#}\n").map(|(_, body)| body),
+        "the engines share the markup"
+    );
+
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "crud", "target": "tera", "full_document": true } }),
+    );
+    assert!(response.get("error").is_some(), "{}", response);
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn titles_are_escaped_rather_than_stripped() {
+    let mut server = Server::spawn();
+    let tools = server.result("tools/list", json!({}));
+    let layouts: Vec<String> = tools["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|t| t["name"] == "daisyui_scaffold_layout")
+        .and_then(|t| t["inputSchema"]["properties"]["layout"]["enum"].as_array())
+        .expect("no layout enum")
+        .iter()
+        .filter_map(|l| l.as_str().map(String::from))
+        .collect();
+    let title = "\"><script>alert(1)</script><img src=x onerror=alert(1)>";
+    let escaped =
+        "&quot;&gt;&lt;script&gt;alert(1)&lt;/script&gt;&lt;img src=x onerror=alert(1)&gt;";
+    let mut calls: Vec<Value> = layouts
+        .iter()
+        .map(|layout| json!({ "layout": layout, "title": title }))
+        .collect();
+    calls.extend([
+        json!({ "layout": "auth", "title": title, "purity": "daisy-only" }),
+        json!({ "layout": "analytics", "title": title, "charts": ["pie"] }),
+        json!({ "layout": "store", "title": title, "full_document": true }),
+    ]);
+    for arguments in calls {
+        let html = first_text(&mut server, "daisyui_scaffold_layout", arguments.clone());
+        // The error page only reads keywords from its title.
+        assert!(
+            html.contains(escaped) || arguments["layout"] == "error",
+            "{}: {}",
+            arguments,
+            html
+        );
+        for injected in ["<script>alert", "<img src=x"] {
+            assert!(!html.contains(injected), "{}: {}", arguments, html);
+        }
+    }
+
+    // Letters, emoji and symbols survive; only the length is capped.
+    let html = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "store", "title": "Café 🚀 № 5 'Deluxe'" }),
+    );
+    assert!(
+        html.contains(">Café 🚀 № 5 &#39;Deluxe&#39;</h1>"),
+        "{}",
+        html
+    );
+    let html = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "store", "title": "é".repeat(150) }),
+    );
+    assert!(
+        html.contains(&format!(">{}</h1>", "é".repeat(100))),
+        "{}",
+        html
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn layouts_are_pretty_printed_or_minified() {
+    let mut server = Server::spawn();
+    for layout in ["dashboard", "auth", "store", "survey"] {
+        let pretty = first_text(
+            &mut server,
+            "daisyui_scaffold_layout",
+            json!({ "layout": layout }),
+        );
+        let minified = first_text(
+            &mut server,
+            "daisyui_scaffold_layout",
+            json!({ "layout": layout, "format": "minified" }),
+        );
+        assert!(pretty.lines().count() > 5, "{}", pretty);
+        let script = |html: &str| {
+            html.find("<script>").map(|at| {
+                html[at..]
+                    .split_inclusive("</script>")
+                    .next()
+                    .unwrap()
+                    .to_string()
+            })
+        };
+        assert_eq!(script(&pretty), script(&minified));
+        let outside = |html: &str| match script(html) {
+            Some(script) => html.replace(&script, ""),
+            None => html.to_string(),
+        };
+        // Two spaces per level, closing tags lined up with their openers.
+        let mut open = Vec::new();
+        for line in outside(&pretty).lines() {
+            let indent = line.len() - line.trim_start().len();
+            assert_eq!(indent % 2, 0, "{}", line);
+            if line.trim_start().starts_with("</") {
+                assert_eq!(open.pop(), Some(indent), "{}", line);
+            } else if line.trim_start().starts_with('<')
+                && !line.contains("</")
+                && !line.ends_with("/>")
+                && !line.contains("<!--")
+            {
+                open.push(indent);
+            }
+        }
+        assert!(!outside(&minified).contains('\n'), "{}", minified);
+        let squeeze = |s: &str| s.split_whitespace().collect::<String>();
+        assert_eq!(squeeze(&pretty), squeeze(&minified));
+    }
+
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "saas", "format": "compact" } }),
+    );
+    let message = response["error"]["message"].as_str().unwrap_or_default();
+    assert_eq!(
+        message,
+        "Unknown format 'compact'; use one of: pretty, minified"
+    );
+
+    let diff = first_text(
+        &mut server,
+        "daisyui_layout_diff",
+        json!({ "from": { "layout": "store" }, "to": { "format": "minified" } }),
+    );
+    assert!(diff.contains("format: pretty → minified"), "{}", diff);
+    assert!(server.shutdown().is_empty());
+}
+
+/// The macro body of a Leptos or Yew component with string literals and
+/// comments blanked out, leaving only the tokens the macro parses.
+fn macro_tokens(text: &str) -> String {
+    let mut out = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                        }
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+                out.push_str("\"\"");
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                let mut last = ' ';
+                for c in chars.by_ref() {
+                    if last == '*' && c == '/' {
+                        break;
+                    }
+                    last = c;
+                }
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[test]
+fn leptos_and_yew_targets_return_rust_components() {
+    let mut server = Server::spawn();
+    for layout in [
+        "saas",
+        "blog",
+        "kanban",
+        "dashboard",
+        "auth",
+        "store",
+        "crud",
+        "survey",
+    ] {
+        for (target, prelude, open, close) in [
+            (
+                "leptos",
+                "use leptos::prelude::*;\n\n#[component]\npub fn Acme() -> impl IntoView {\n",
+                "    view! {\n",
+                "\n    }\n}\n",
+            ),
+            (
+                "yew",
+                "use yew::prelude::*;\n\n#[function_component]\npub fn Acme() -> Html {\n",
+                "    html! {\n        <>\n",
+                "\n        </>\n    }\n}\n",
+            ),
+        ] {
+            let text = first_text(
+                &mut server,
+                "daisyui_scaffold_layout",
+                json!({ "layout": layout, "title": "Acme", "target": target }),
+            );
+            assert!(text.starts_with(prelude), "{} {}: {}", layout, target, text);
+            assert!(text.ends_with(close), "{} {}: {}", layout, target, text);
+            let body = &text[prelude.len() + open.len()..text.len() - close.len()];
+            let tokens = macro_tokens(body);
+            let braces = match target {
+                "yew" => tokens.replace("{ \"\" }", ""),
+                _ => tokens.clone(),
+            };
+            assert!(
+                !braces.contains(['{', '}']),
+                "{} {}: {}",
+                layout,
+                target,
+                tokens
+            );
+            assert!(!tokens.contains("<script"), "{} {}", layout, target);
+            assert!(!tokens.contains("onclick="), "{} {}", layout, target);
+            assert!(!tokens.contains("<!--"), "{} {}", layout, target);
+        }
+    }
+
+    let crud = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "crud", "title": "Team Members", "target": "leptos" }),
+    );
+    assert!(crud.contains("pub fn TeamMembers() -> impl IntoView"));
+    assert!(
+        crud.contains("/* onclick=\"document.getElementById('dd-crud-1-edit').showModal()\" */")
+    );
+    let survey = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "survey", "target": "yew" }),
+    );
+    assert!(
+        survey.contains("/* inline <script> left out: port it to Rust or load it as a file */")
+    );
+
+    let response = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "saas", "target": "leptos", "full_document": true } }),
+    );
+    assert!(response.get("error").is_some(), "{}", response);
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn sections_come_out_of_a_layout_one_at_a_time() {
+    let mut server = Server::spawn();
+    let section = |server: &mut Server, layout: &str, section: &str| {
+        first_text(
+            server,
+            "daisyui_scaffold_section",
+            json!({ "layout": layout, "section": section, "title": "Acme" }),
+        )
+    };
+    let hero = section(&mut server, "saas", "hero");
+    assert!(hero.starts_with("<header class=\"hero"), "{}", hero);
+    assert!(!hero.contains("navbar") && !hero.contains("<footer"));
+    assert!(hero.contains("Build faster with"));
+
+    let sidebar = section(&mut server, "dashboard", "sidebar");
+    assert!(
+        sidebar.starts_with("<aside class=\"drawer-side"),
+        "{}",
+        sidebar
+    );
+    // The overlay label points at the drawer id the whole layout would use.
+    assert!(sidebar.contains("for=\"dd-drawer-1\""));
+
+    let footer = section(&mut server, "saas", "footer");
+    assert!(footer.starts_with("<footer") && footer.ends_with("</footer>"));
+
+    let unknown = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_section", "arguments": { "layout": "dashboard", "section": "hero" } }),
+    );
+    assert_eq!(unknown["error"]["code"], -32602);
+    assert_eq!(
+        unknown["error"]["message"],
+        "Unknown section 'hero' for dashboard. Available: navbar, main, sidebar"
+    );
+
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_manifest", "arguments": {} }),
+    );
+    let manifest: Value = serde_json::from_str(&text_of(&result)).unwrap();
+    let saas = &manifest["layouts"][0];
+    assert_eq!(saas["name"], "saas");
+    assert_eq!(
+        saas["sections"],
+        json!([
+            "navbar",
+            "hero",
+            "logos",
+            "features",
+            "testimonials",
+            "pricing",
+            "footer"
+        ])
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn pages_are_composed_from_sections_of_several_layouts() {
+    let mut server = Server::spawn();
+    let result = server.result(
+        "tools/call",
+        json!({ "name": "daisyui_compose_page", "arguments": {
+            "sections": ["navbar", "error:hero", "hero", "pricing", "faq", "blog:navbar", "dashboard:sidebar", "footer"],
+            "title": "Acme"
+        } }),
+    );
+    let html = result["content"][0]["text"].as_str().unwrap();
+    assert!(
+        html.starts_with(
+            "<!-- Composed from: navbar (saas), hero (error), hero (saas), pricing (saas), faq (pricing), sidebar (dashboard), footer (saas) -->"
+        ),
+        "{}",
+        html
+    );
+    assert_eq!(html.matches("class=\"navbar").count(), 1);
+    assert_eq!(html.matches("<footer").count(), 1);
+    assert_eq!(
+        html.matches("min-h-screen").count(),
+        2,
+        "wrapper and error hero"
+    );
+    // Layouts share one id allocator, so ids stay unique across them.
+    assert!(html.contains("for=\"dd-drawer-1\""));
+    let notes = result["content"][1]["text"].as_str().unwrap();
+    assert!(notes.contains("Dropped blog:navbar"), "{}", notes);
+    assert!(notes.contains("from saas:hero"), "{}", notes);
+
+    let unknown = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_compose_page", "arguments": { "sections": ["hero", "gallery"] } }),
+    );
+    assert_eq!(unknown["error"]["code"], -32602);
+    assert!(
+        unknown["error"]["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("No layout has a section named 'gallery'")
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn rtl_layouts_mirror_their_physical_classes() {
+    let mut server = Server::spawn();
+    let ltr = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "inbox", "title": "Acme" }),
+    );
+    let rtl = first_text(
+        &mut server,
+        "daisyui_scaffold_layout",
+        json!({ "layout": "inbox", "title": "Acme", "direction": "rtl" }),
+    );
+    assert!(!ltr.contains("dir=\"rtl\""));
+    assert!(rtl.starts_with("<div dir=\"rtl\""), "{}", rtl);
+    let count = |html: &str, class: &str| html.matches(class).count();
+    assert!(count(&ltr, "border-r") > 0);
+    assert_eq!(count(&ltr, "border-r"), count(&rtl, "border-l"));
+    assert_eq!(count(&ltr, "border-l"), count(&rtl, "border-r"));
+
+    let bad = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "inbox", "direction": "up" } }),
+    );
+    assert_eq!(bad["error"]["code"], -32602);
+    assert_eq!(
+        bad["error"]["message"],
+        "Unknown direction 'up'; use one of: ltr, rtl"
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+#[test]
+fn layout_images_can_be_swapped_for_placeholders() {
+    let mut server = Server::spawn();
+    let mut blog = |placeholders: &str| {
+        first_text(
+            &mut server,
+            "daisyui_scaffold_layout",
+            json!({ "layout": "blog", "placeholders": placeholders }),
+        )
+    };
+    let remote = blog("remote");
+    assert!(remote.contains("https://img.daisyui.com/"));
+    let svg = blog("svg");
+    assert!(!svg.contains("https://img.daisyui.com/"));
+    assert_eq!(
+        svg.matches("src=\"data:image/svg+xml,").count(),
+        remote.matches("<img").count()
+    );
+    let local = blog("local");
+    assert!(
+        local.contains("src=\"/images/a-field-guide-to-design-tokens.jpg\""),
+        "{}",
+        local
+    );
+    let none = blog("none");
+    assert!(!none.contains("<img"));
+    assert!(none.contains("class=\"skeleton w-32 h-32 rounded-xl\""));
+
+    let bad = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_layout", "arguments": { "layout": "blog", "placeholders": "cats" } }),
+    );
+    assert_eq!(
+        bad["error"]["message"],
+        "Unknown placeholders 'cats'; use one of: remote, local, svg, none"
+    );
+    assert!(server.shutdown().is_empty());
+}
+
+/// The `href`s in the menu of a page's first navbar, in order.
+fn navbar_links(html: &str) -> Vec<String> {
+    let navbar = html.find("class=\"navbar").unwrap();
+    let start = navbar + html[navbar..].find("menu-horizontal").unwrap();
+    let end = start + html[start..].find("</ul>").unwrap();
+    html[start..end]
+        .split(" href=\"")
+        .skip(1)
+        .filter_map(|rest| rest.split('"').next())
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+fn site_pages_share_one_set_of_navbar_links() {
+    let mut server = Server::spawn();
+    for (preset, files) in [
+        (
+            "startup",
+            ["index.html", "pricing.html", "docs.html", "login.html"],
+        ),
+        (
+            "shop",
+            ["index.html", "checkout.html", "login.html", "account.html"],
+        ),
+    ] {
+        let result = server.result(
+            "tools/call",
+            json!({ "name": "daisyui_scaffold_site", "arguments": { "preset": preset, "title": "Acme" } }),
+        );
+        let pages: Vec<&str> = result["content"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| c["text"].as_str().unwrap())
+            .collect();
+        assert_eq!(pages.len(), files.len(), "{}", preset);
+        let links = navbar_links(pages[0]);
+        for (html, file) in pages.iter().zip(files) {
+            assert!(
+                html.starts_with(&format!("<!-- {} -->\n", file)),
+                "{}",
+                html
+            );
+            assert_eq!(navbar_links(html), links, "{} {}", preset, file);
+            assert!(html.contains(&format!(
+                r#"<a href="{}" class="menu-active" aria-current="page">"#,
+                file
+            )));
+            assert_eq!(html.matches("aria-current=\"page\"").count(), 1);
+        }
+        assert_eq!(links, files);
+    }
+
+    let unknown = server.request(
+        "tools/call",
+        json!({ "name": "daisyui_scaffold_site", "arguments": { "preset": "blog" } }),
+    );
+    assert_eq!(unknown["error"]["code"], -32602);
+    assert_eq!(
+        unknown["error"]["message"],
+        "Unknown site preset 'blog'. Available: startup, shop"
+    );
+    assert!(server.shutdown().is_empty());
+}
@@ -0,0 +1,60 @@
+//! Sectioned slash command output from the extension's output module:
+//! ranges land on char boundaries and cover exactly their labeled text.
+
+#[path = "../../src/output.rs"]
+mod output;
+
+use output::Output;
+
+fn results() -> Vec<(String, String, usize)> {
+    vec![
+        (
+            "button".into(),
+            "**Button**s — “primary” or 🎉 **button**…".into(),
+            125,
+        ),
+        ("join".into(), "…group **button**s, inputs, etc.".into(), 20),
+        (
+            "modal".into(),
+            "Dialog with a close **button** ✕".into(),
+            20,
+        ),
+    ]
+}
+
+#[test]
+fn every_result_gets_its_own_section() {
+    let out = output::search("bütton", "Showing 1–3 of 3", &results());
+    assert_eq!(out.sections.len(), 4);
+    let (label, header) = &out.sections[0];
+    assert_eq!(label, "Search: bütton");
+    assert_eq!(
+        &out.text[header.clone()],
+        "## Search Results for 'bütton'\n\n_Showing 1–3 of 3_"
+    );
+    for ((label, range), (name, snippet, score)) in out.sections[1..].iter().zip(results()) {
+        assert_eq!(label, &name);
+        assert!(out.text.is_char_boundary(range.start));
+        assert!(out.text.is_char_boundary(range.end));
+        assert_eq!(
+            &out.text[range.clone()],
+            format!("- **{}** (score: {})\n  {}", name, score, snippet)
+        );
+    }
+    // Sections are in order, don't overlap, and the last one ends the text.
+    for pair in out.sections.windows(2) {
+        assert!(pair[0].1.end < pair[1].1.start);
+    }
+    assert_eq!(out.sections[3].1.end, out.text.len());
+}
+
+#[test]
+fn unlabeled_text_stays_outside_sections() {
+    let mut out = Output::default();
+    out.push("→ ");
+    out.section("café", "naïve");
+    out.push("\n");
+    assert_eq!(out.text, "→ naïve\n");
+    assert_eq!(out.sections, [("café".to_string(), 4..10)]);
+    assert_eq!(output::search("x", "none", &[]).sections.len(), 1);
+}
@@ -0,0 +1,142 @@
+//! Chart palettes built from theme colors, on light and dark bases.
+
+#[path = "../src/palette.rs"]
+mod palette;
+
+use palette::{ThemeColors, css_block, palette, resolve, theme_names};
+
+/// HSL lightness of a `#rrggbb` color, from 0 to 1.
+fn lightness(hex: &str) -> f64 {
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap() as f64 / 255.0;
+    let rgb = [channel(1), channel(3), channel(5)];
+    let max = rgb.iter().cloned().fold(0.0, f64::max);
+    let min = rgb.iter().cloned().fold(1.0, f64::min);
+    (max + min) / 2.0
+}
+
+fn mean_lightness(colors: &[String]) -> f64 {
+    colors.iter().map(|c| lightness(c)).sum::<f64>() / colors.len() as f64
+}
+
+fn theme(name: &str) -> ThemeColors {
+    ThemeColors::named(name).unwrap()
+}
+
+#[test]
+fn light_and_dark_palettes_are_pinned() {
+    assert_eq!(
+        palette(&theme("light"), 12),
+        [
+            "#570df8", "#f000b8", "#37cdbe", "#3abff8", "#36d399", "#fbbd23", "#f87272", "#3a05ae",
+            "#9e0079", "#248f84", "#089ad9", "#c99004",
+        ]
+    );
+    assert_eq!(
+        palette(&theme("dark"), 12),
+        [
+            "#661ae6", "#d926aa", "#1fb2a5", "#3abff8", "#36d399", "#fbbd23", "#f87272", "#9764ee",
+            "#e56bc5", "#89d9fb", "#79e2bb", "#fcd573",
+        ]
+    );
+}
+
+#[test]
+fn semantic_colors_come_first() {
+    for name in ["light", "dark"] {
+        let colors = theme(name);
+        let semantic = [
+            colors.primary.as_str(),
+            &colors.secondary,
+            &colors.accent,
+            &colors.info,
+            &colors.success,
+            &colors.warning,
+            &colors.error,
+        ];
+        assert_eq!(palette(&colors, 7), semantic, "{}", name);
+        assert_eq!(palette(&colors, 3), semantic[..3], "{}", name);
+        assert!(palette(&colors, 0).is_empty(), "{}", name);
+    }
+}
+
+#[test]
+fn tints_move_away_from_the_base() {
+    let light = palette(&theme("light"), 14);
+    let dark = palette(&theme("dark"), 14);
+    assert!(
+        mean_lightness(&light[7..]) < mean_lightness(&light[..7]),
+        "{:?}",
+        light
+    );
+    assert!(
+        mean_lightness(&dark[7..]) > mean_lightness(&dark[..7]),
+        "{:?}",
+        dark
+    );
+    // The first tint is the primary color, darkened or lightened.
+    assert!(lightness(&light[7]) < lightness(&light[0]));
+    assert!(lightness(&dark[7]) > lightness(&dark[0]));
+}
+
+#[test]
+fn every_count_gives_distinct_hex_colors() {
+    for name in theme_names() {
+        for count in 1..=20 {
+            let colors = palette(&theme(name), count);
+            assert_eq!(colors.len(), count, "{} {}", name, count);
+            for (i, color) in colors.iter().enumerate() {
+                assert!(
+                    color.len() == 7
+                        && color.starts_with('#')
+                        && color[1..]
+                            .chars()
+                            .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c)),
+                    "{} {}",
+                    name,
+                    color
+                );
+                assert!(!colors[..i].contains(color), "{} {}", name, color);
+                let l = lightness(color);
+                assert!((0.1..=0.9).contains(&l) || i < 7, "{} {}", name, color);
+            }
+        }
+    }
+}
+
+#[test]
+fn specs_name_a_theme_or_carry_its_css() {
+    assert_eq!(resolve("theme:dark").unwrap().base, "#2a303c");
+    assert_eq!(resolve(" Light ").unwrap().base, "#ffffff");
+    assert_eq!(
+        resolve("theme:nonesuch").err().as_deref(),
+        Some(
+            "Unknown theme 'nonesuch'. Available: light, dark, cupcake, corporate, nord, dracula, synthwave, forest, night"
+        )
+    );
+    // CSS on a dark base tints lighter; missing colors come from light.
+    let css =
+        resolve("[data-theme=\"mine\"] { --color-primary: #FF0000; --color-base-100: #101010; }")
+            .unwrap();
+    assert_eq!(css.primary, "#ff0000");
+    assert_eq!(css.secondary, theme("light").secondary);
+    let colors = palette(&css, 8);
+    assert_eq!(colors[0], "#ff0000");
+    assert!(
+        lightness(&colors[7]) > lightness(&colors[0]),
+        "{:?}",
+        colors
+    );
+    assert_eq!(
+        resolve("--color-primary: red;").err().as_deref(),
+        Some("No hex --color-* values found in theme CSS")
+    );
+}
+
+#[test]
+fn css_blocks_number_the_colors() {
+    let colors = palette(&theme("dark"), 2);
+    assert_eq!(
+        css_block(":root", &colors),
+        ":root {\n  --chart-1: #661ae6;\n  --chart-2: #d926aa;\n}"
+    );
+}
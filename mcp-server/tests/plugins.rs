@@ -0,0 +1,130 @@
+//! Template layouts from data files: front-matter parsing, placeholder
+//! substitution, validation messages and key collisions.
+
+#[path = "../../src/ids.rs"]
+mod ids;
+#[path = "../../src/plugins.rs"]
+mod plugins;
+
+use ids::{DEFAULT_PREFIX, IdAllocator};
+use plugins::{LayoutTemplate, Registry};
+
+const BUILTINS: &[&str] = &["saas", "blog", "dashboard"];
+
+const TEAM: &str = "---
+key: team-page
+title: Team page
+description: Member grid with a drawer filter.
+tags: [team, crew, people]
+sections: navbar, \"members\", footer
+---
+<div class=\"navbar\">{{title}}</div>
+<input id=\"{{ id:drawer }}\" type=\"checkbox\" class=\"drawer-toggle\" />
+<input id=\"{{id:drawer}}\" type=\"checkbox\" class=\"drawer-toggle\" />
+";
+
+fn file(name: &str, text: &str) -> (String, String) {
+    (name.to_string(), text.to_string())
+}
+
+#[test]
+fn front_matter_fields_are_parsed() {
+    let t = LayoutTemplate::parse("team.html", TEAM).expect("valid template");
+    assert_eq!(t.key, "team-page");
+    assert_eq!(t.title, "Team page");
+    assert_eq!(t.description, "Member grid with a drawer filter.");
+    assert_eq!(t.tags, ["team", "crew", "people"]);
+    assert_eq!(t.sections, ["navbar", "members", "footer"]);
+    assert_eq!(t.source, "team.html");
+    assert_eq!(plugins::DIR, ".daisy-days/layouts");
+}
+
+#[test]
+fn placeholders_take_the_title_and_allocated_ids() {
+    let t = LayoutTemplate::parse("team.html", TEAM).unwrap();
+    let html = t.render("Acme &amp; Co", &mut IdAllocator::default());
+    assert!(html.starts_with("<div class=\"navbar\">Acme &amp; Co</div>"));
+    assert!(html.contains(&format!("id=\"{}-drawer-1\"", DEFAULT_PREFIX)));
+    assert!(html.contains(&format!("id=\"{}-drawer-2\"", DEFAULT_PREFIX)));
+    assert!(!html.contains("{{"), "{}", html);
+    let prefixed = t.render("Acme", &mut IdAllocator::new(Some("team")));
+    assert!(prefixed.contains("id=\"team-drawer-1\""), "{}", prefixed);
+}
+
+#[test]
+fn invalid_templates_explain_what_is_wrong() {
+    for (text, expected) in [
+        ("<div>{{title}}</div>", "must start with a `---`"),
+        ("---\nkey: a\ntitle: A\n", "missing its closing `---`"),
+        ("---\ntitle: A\n---\n<div></div>", "`key` is required"),
+        ("---\nkey: Big Page\n---\n<div></div>", "may only contain"),
+        (
+            "---\nkey: a\ncolor: red\n---\n<div></div>",
+            "unknown field `color`",
+        ),
+        (
+            "---\nkey: a\njust words\n---\n<div></div>",
+            "expected `field: value`",
+        ),
+        ("---\nkey: a\n---\n\n", "template body is empty"),
+        (
+            "---\nkey: a\n---\n<div>{{name}}</div>",
+            "unknown placeholder `{{name}}`",
+        ),
+        ("---\nkey: a\n---\n<div>{{title</div>", "unclosed `{{`"),
+    ] {
+        let error = LayoutTemplate::parse("bad.html", text).unwrap_err();
+        assert!(error.starts_with("bad.html: "), "{}", error);
+        assert!(error.contains(expected), "{:?} gave {}", text, error);
+    }
+}
+
+#[test]
+fn title_defaults_to_the_key() {
+    let t = LayoutTemplate::parse("x.html", "---\nkey: wall\n---\n<p>{{title}}</p>").unwrap();
+    assert_eq!(t.title, "wall");
+    assert!(t.tags.is_empty() && t.description.is_empty());
+}
+
+#[test]
+fn builtins_and_earlier_templates_win_collisions() {
+    let registry = Registry::load(
+        vec![
+            file("a.html", TEAM),
+            file("b.html", "---\nkey: team-page\n---\n<p>second</p>"),
+            file("c.html", "---\nkey: blog\n---\n<p>shadow</p>"),
+            file(
+                "d.html",
+                "---\nkey: gallery\ntags: photos\n---\n<p>{{title}}</p>",
+            ),
+            file("e.html", "no front matter"),
+        ],
+        BUILTINS,
+    );
+    let keys: Vec<&str> = registry.layouts.iter().map(|t| t.key.as_str()).collect();
+    assert_eq!(keys, ["team-page", "gallery"]);
+    assert_eq!(registry.get("team-page").unwrap().source, "a.html");
+    assert!(registry.get("blog").is_none());
+    assert_eq!(registry.errors.len(), 3, "{:?}", registry.errors);
+    assert!(registry.errors[0].contains("already defined in a.html"));
+    assert!(registry.errors[1].contains("`blog` is a built-in layout"));
+    assert!(registry.errors[2].starts_with("e.html: "));
+    assert_eq!(
+        registry.keywords(),
+        [
+            (
+                "team-page".to_string(),
+                vec![
+                    "team-page".into(),
+                    "team".into(),
+                    "crew".into(),
+                    "people".into()
+                ]
+            ),
+            (
+                "gallery".to_string(),
+                vec!["gallery".into(), "photos".into()]
+            ),
+        ]
+    );
+}
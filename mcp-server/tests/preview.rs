@@ -0,0 +1,122 @@
+//! Offline preview pages: inlined CSS, breakpoints, themes and how the page
+//! leaves the server.
+
+#[path = "../../src/html.rs"]
+#[allow(dead_code)] // preview depends on it
+mod html;
+#[path = "../src/palette.rs"]
+#[allow(dead_code)] // preview depends on it
+mod palette;
+#[path = "../src/preview.rs"]
+mod preview;
+#[path = "../../src/theme.rs"]
+#[allow(dead_code)] // preview depends on it
+mod theme;
+
+use preview::{breakpoint_names, build, data_url, write_temp};
+
+const BODY: &str = r#"<div class="card bg-base-100 md:w-96 lg:w-1/2 made-up-class">
+  <button class="btn btn-primary">Go</button>
+</div>"#;
+
+/// Decodes standard padded base64.
+fn unbase64(text: &str) -> Vec<u8> {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = Vec::new();
+    for chunk in text.as_bytes().chunks(4) {
+        let mut n = 0u32;
+        let mut len = 0;
+        for &b in chunk {
+            n <<= 6;
+            if b != b'=' {
+                n |= ALPHABET.iter().position(|&a| a == b).expect("base64") as u32;
+                len += 1;
+            }
+        }
+        let bytes = [(n >> 16) as u8, (n >> 8) as u8, n as u8];
+        out.extend_from_slice(&bytes[..len - 1]);
+    }
+    out
+}
+
+#[test]
+fn pages_inline_css_and_report_unknown_classes() {
+    let page = build("Cards &amp; more", BODY, None, None).unwrap();
+    assert!(page.html.starts_with("<!DOCTYPE html>"));
+    assert!(
+        page.html
+            .contains("<html lang=\"en\" data-theme=\"light\">")
+    );
+    assert!(page.html.contains("<title>Cards &amp; more</title>"));
+    assert!(
+        page.html
+            .contains("<button class=\"btn btn-primary\">Go</button>")
+    );
+    assert!(page.html.contains(".btn-primary"));
+    assert!(page.html.contains("@media (min-width: 48rem)"));
+    assert_eq!(page.missing, ["made-up-class"]);
+    assert_eq!(page.unstyled_theme, None);
+}
+
+#[test]
+fn breakpoints_pin_the_arrangement() {
+    assert_eq!(breakpoint_names(), ["base", "sm", "md", "lg", "xl", "2xl"]);
+    let md = build("t", BODY, Some("md"), None).unwrap().html;
+    assert!(!md.contains("@media"), "{}", md);
+    assert!(md.contains("max-width: 48rem"));
+    assert!(md.contains(".md\\:w-96"));
+    assert!(!md.contains(".lg\\:w-1\\/2"));
+    let base = build("t", BODY, Some("base"), None).unwrap().html;
+    assert!(base.contains("max-width: 24rem"));
+    assert!(!base.contains(".md\\:w-96"));
+    assert_eq!(
+        build("t", BODY, Some("huge"), None).err().as_deref(),
+        Some("Unknown breakpoint 'huge'. Use one of: base, sm, md, lg, xl, 2xl")
+    );
+}
+
+#[test]
+fn themes_are_set_and_known_colors_inlined() {
+    let dark = build("t", BODY, None, Some("dark")).unwrap();
+    assert!(dark.html.contains("data-theme=\"dark\""));
+    assert_eq!(dark.unstyled_theme, None);
+    let known = build("t", BODY, None, Some("cupcake")).unwrap();
+    assert!(known.html.contains("[data-theme=\"cupcake\"]"));
+    assert_eq!(known.unstyled_theme, None);
+    let unknown = build("t", BODY, None, Some("nonesuch")).unwrap();
+    assert!(unknown.html.contains("data-theme=\"nonesuch\""));
+    assert_eq!(unknown.unstyled_theme.as_deref(), Some("nonesuch"));
+}
+
+#[test]
+fn data_urls_carry_the_page_as_base64() {
+    assert_eq!(data_url(""), "data:text/html;base64,");
+    assert_eq!(data_url("<p>"), "data:text/html;base64,PHA+");
+    assert_eq!(data_url("<p>a"), "data:text/html;base64,PHA+YQ==");
+    assert_eq!(data_url("<p>ab"), "data:text/html;base64,PHA+YWI=");
+    let page = build("Ünïcödé", BODY, None, Some("dark")).unwrap().html;
+    let url = data_url(&page);
+    let payload = url.strip_prefix("data:text/html;base64,").unwrap();
+    assert_eq!(String::from_utf8(unbase64(payload)).unwrap(), page);
+}
+
+#[test]
+fn temp_files_are_new_each_time() {
+    let first = write_temp("blog/../x", "<p>one</p>").unwrap();
+    let second = write_temp("blog/../x", "<p>two</p>").unwrap();
+    assert_ne!(first, second);
+    for path in [&first, &second] {
+        assert_eq!(path.parent(), Some(std::env::temp_dir().as_path()));
+        let name = path.file_name().unwrap().to_str().unwrap();
+        assert!(
+            name.starts_with("daisy-days-preview-blog----x-"),
+            "{}",
+            name
+        );
+        assert!(name.ends_with(".html"), "{}", name);
+    }
+    assert_eq!(std::fs::read_to_string(&first).unwrap(), "<p>one</p>");
+    assert_eq!(std::fs::read_to_string(&second).unwrap(), "<p>two</p>");
+    std::fs::remove_file(first).unwrap();
+    std::fs::remove_file(second).unwrap();
+}
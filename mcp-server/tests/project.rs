@@ -0,0 +1,63 @@
+//! Project names offered as `/daisy-layout` titles.
+
+#[path = "../../src/project.rs"]
+mod project;
+
+use project::{FALLBACK, cargo_toml_name, package_json_name, title_case, titles};
+
+#[test]
+fn package_json_names_come_from_the_top_level_only() {
+    let text = r#"{
+  "author": { "name": "Ada" },
+  "description": "the \"name\" of {it}",
+  "keywords": ["name"],
+  "name": "@acme/storefront",
+  "version": "1.0.0"
+}"#;
+    assert_eq!(package_json_name(text).as_deref(), Some("@acme/storefront"));
+    assert_eq!(
+        package_json_name(r#"{ "author": { "name": "Ada" } }"#),
+        None
+    );
+    assert_eq!(package_json_name(r#"{ "name": "" }"#), None);
+    assert_eq!(package_json_name("not json"), None);
+}
+
+#[test]
+fn cargo_toml_names_come_from_the_package_table() {
+    let text = r#"[workspace]
+members = ["cli"]
+
+[dependencies]
+name = "not-this"
+
+[package]
+name = "daisy_days"
+version = "0.3.0"
+"#;
+    assert_eq!(cargo_toml_name(text).as_deref(), Some("daisy_days"));
+    assert_eq!(cargo_toml_name("[workspace]\nmembers = []\n"), None);
+    assert_eq!(cargo_toml_name("[package]\nnamespace = \"x\"\n"), None);
+}
+
+#[test]
+fn names_become_titles() {
+    assert_eq!(title_case("daisy_days-web"), "Daisy Days Web");
+    assert_eq!(title_case("@acme/storefront"), "Storefront");
+    assert_eq!(title_case("MyApp"), "MyApp");
+    assert_eq!(title_case("über.site"), "Über Site");
+}
+
+#[test]
+fn titles_list_the_directory_then_the_manifests_then_the_fallback() {
+    assert_eq!(
+        titles(
+            "/home/ada/field-notes/",
+            Some(r#"{ "name": "field-notes-web" }"#),
+            Some("[package]\nname = \"field_notes\"\n"),
+        ),
+        ["Field Notes", "Field Notes Web", FALLBACK]
+    );
+    assert_eq!(titles(r"C:\code\acme", None, None), ["Acme", FALLBACK]);
+    assert_eq!(titles("", None, None), [FALLBACK]);
+}
@@ -0,0 +1,283 @@
+//! daisyUI-only layout variants: the classes they may emit, and the density,
+//! ids and item counts they share with the Tailwind layouts.
+
+#[path = "../../src/docs.rs"]
+#[allow(dead_code)] // sample depends on it
+mod docs;
+#[path = "../../src/footprint.rs"]
+#[allow(dead_code)] // purity depends on it
+mod footprint;
+#[path = "../../src/html.rs"]
+#[allow(dead_code)] // only the class parser is used
+mod html;
+#[path = "../../src/ids.rs"]
+mod ids;
+#[path = "../../src/purity.rs"]
+#[allow(dead_code)] // the option parsers read MODES
+mod purity;
+#[path = "../../src/sample.rs"]
+#[allow(dead_code)] // only seeded content and counts are used
+mod sample;
+#[path = "../../src/sections.rs"]
+#[allow(dead_code)] // only the declared section names are used
+mod sections;
+#[path = "../../src/snippets.rs"]
+#[allow(dead_code)] // purity depends on it
+mod snippets;
+#[path = "../../src/variant.rs"]
+#[allow(dead_code)] // only the density presets are used
+mod variant;
+
+use ids::IdAllocator;
+use purity::{PURE_LAYOUTS, is_daisy_class, report, utilities};
+use sample::{Counts, Sample};
+use variant::Variant;
+
+/// The utilities each pure layout can't avoid, whatever the density, ids or
+/// counts. Any other class must come from daisyUI.
+const ALLOWED: &[(&str, &[&str])] = &[
+    ("auth", &["min-h-screen", "bg-base-200", "bg-base-100"]),
+    (
+        "profile",
+        &[
+            "min-h-screen",
+            "bg-base-200",
+            "bg-base-100",
+            "w-12",
+            "bg-neutral",
+            "text-neutral-content",
+        ],
+    ),
+    (
+        "store",
+        &["min-h-screen", "bg-base-100", "bg-base-200", "w-12"],
+    ),
+    (
+        "faq",
+        &[
+            "min-h-screen",
+            "bg-base-200",
+            "bg-primary",
+            "text-primary-content",
+            "bg-base-100",
+        ],
+    ),
+];
+
+fn pure(layout: &str, v: &Variant, prefix: Option<&str>, cards: Option<u64>) -> String {
+    let (counts, _) = Counts::parse(layout, cards.map(|n| ("cards", n)));
+    let mut s = Sample::new(sample::seed("Acme")).with_counts(counts);
+    let mut ids = IdAllocator::new(prefix);
+    purity::layout(layout, "Acme", &mut s, v, &mut ids)
+        .unwrap_or_else(|| panic!("{} has no pure variant", layout))
+        .html()
+}
+
+/// Every pure page: each layout at each density, with and without an id
+/// prefix and with a range of item counts.
+fn pages() -> Vec<(String, String)> {
+    let mut pages = Vec::new();
+    for layout in PURE_LAYOUTS {
+        for name in variant::NAMES {
+            let v = Variant::parse(name).unwrap();
+            for prefix in [None, Some("shop")] {
+                for cards in [None, Some(1), Some(4), Some(12)] {
+                    let label = format!("{} {} {:?} {:?}", layout, name, prefix, cards);
+                    pages.push((label, pure(layout, &v, prefix, cards)));
+                }
+            }
+        }
+    }
+    pages
+}
+
+/// Values of `attr` in `html`, in order.
+fn attribute(html: &str, attr: &str) -> Vec<String> {
+    let needle = format!(" {}=\"", attr);
+    html.match_indices(&needle)
+        .map(|(i, _)| {
+            let rest = &html[i + needle.len()..];
+            rest[..rest.find('"').unwrap()].to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn every_pure_layout_has_an_allowed_set() {
+    let listed: Vec<&str> = ALLOWED.iter().map(|(layout, _)| *layout).collect();
+    assert_eq!(listed, PURE_LAYOUTS);
+}
+
+#[test]
+fn pure_layouts_emit_only_daisyui_and_allowed_classes() {
+    for (label, html) in pages() {
+        let layout = label.split(' ').next().unwrap();
+        let allowed = ALLOWED.iter().find(|(l, _)| *l == layout).unwrap().1;
+        for class in html::classes(&html) {
+            assert!(
+                is_daisy_class(&class) || allowed.contains(&class.as_str()),
+                "{}: `{}` is neither daisyUI nor allowed",
+                label,
+                class
+            );
+        }
+        let mut left = utilities(&html);
+        left.sort();
+        let mut expected: Vec<&str> = allowed.to_vec();
+        expected.sort();
+        assert_eq!(left, expected, "{}", label);
+    }
+}
+
+#[test]
+fn density_picks_daisyui_sizes() {
+    for (name, btn, small, input) in [
+        ("compact", "btn btn-sm", "btn btn-xs", "input input-sm"),
+        ("comfortable", "btn", "btn btn-sm", "input"),
+        ("spacious", "btn btn-lg", "btn", "input input-lg"),
+    ] {
+        let v = Variant::parse(name).unwrap();
+        let profile = pure("profile", &v, None, None);
+        assert!(
+            profile.contains(&format!(
+                r#"<button class="{} btn-primary join-item">"#,
+                btn
+            )),
+            "{}",
+            name
+        );
+        assert!(
+            profile.contains(&format!(
+                r#"<button class="{} join-item">Change Avatar"#,
+                small
+            )),
+            "{}",
+            name
+        );
+        assert!(
+            profile.contains(&format!(r#"type="text" class="{}""#, input)),
+            "{}",
+            name
+        );
+        let auth = pure("auth", &v, None, None);
+        assert!(
+            auth.contains(&format!(r#"<button class="{} btn-primary">Login"#, btn)),
+            "{}",
+            name
+        );
+    }
+    let compact = pure("store", &Variant::COMPACT, None, None);
+    assert!(compact.contains(r#"class="card card-sm card-border bg-base-100""#));
+    let spacious = pure("auth", &Variant::SPACIOUS, None, None);
+    assert!(spacious.contains(r#"class="card card-lg card-border bg-base-100""#));
+}
+
+#[test]
+fn ids_follow_the_prefix_and_labels_resolve() {
+    for (label, html) in pages() {
+        let ids = attribute(&html, "id");
+        assert!(!ids.is_empty(), "{}", label);
+        let prefix = if label.contains("\"shop\"") {
+            "shop-"
+        } else {
+            "dd-"
+        };
+        for (i, id) in ids.iter().enumerate() {
+            assert!(id.starts_with(prefix), "{}: {}", label, id);
+            assert!(!ids[..i].contains(id), "{}: {} twice", label, id);
+        }
+        for target in attribute(&html, "for")
+            .into_iter()
+            .chain(attribute(&html, "aria-labelledby"))
+        {
+            assert!(ids.contains(&target), "{}: {} is missing", label, target);
+        }
+    }
+    // One allocator keeps two pure layouts on a page apart.
+    let mut s = Sample::new(1);
+    let mut ids = IdAllocator::new(Some("page"));
+    let v = Variant::default();
+    let first = purity::layout("auth", "A", &mut s, &v, &mut ids)
+        .unwrap()
+        .html();
+    let second = purity::layout("auth", "B", &mut s, &v, &mut ids)
+        .unwrap()
+        .html();
+    assert!(first.contains(r#"id="page-login-1-email""#));
+    assert!(second.contains(r#"id="page-login-2-email""#));
+}
+
+/// A pure layout may leave out a section its Tailwind layout has (the pure
+/// profile has no single main element), but never adds one or reorders them.
+#[test]
+fn pure_layouts_name_declared_sections_in_order() {
+    for layout in PURE_LAYOUTS {
+        let mut s = Sample::new(1);
+        let mut ids = IdAllocator::default();
+        let page = purity::layout(layout, "A", &mut s, &Variant::default(), &mut ids).unwrap();
+        let mut declared = sections::SECTIONS
+            .iter()
+            .find(|(l, _)| l == layout)
+            .unwrap()
+            .1
+            .iter();
+        let names = page.names();
+        assert!(!names.is_empty(), "{}", layout);
+        for name in names {
+            assert!(declared.any(|d| *d == name), "{}: {}", layout, name);
+        }
+    }
+}
+
+#[test]
+fn the_store_lists_as_many_products_as_asked() {
+    for (cards, rows) in [(None, 1), (Some(1), 1), (Some(4), 4), (Some(12), 12)] {
+        let html = pure("store", &Variant::default(), None, cards);
+        assert_eq!(html.matches(r#"<li class="list-row">"#).count(), rows);
+    }
+    let v = Variant::default();
+    assert_eq!(
+        pure("store", &v, None, Some(5)),
+        pure("store", &v, None, Some(5))
+    );
+}
+
+#[test]
+fn the_faq_keeps_its_questions_and_support_modal() {
+    let html = pure("faq", &Variant::default(), None, None);
+    assert_eq!(
+        html.matches(r#"<input type="radio" name="dd-help-1-"#)
+            .count(),
+        6
+    );
+    for needle in [
+        r##"<a href="#dd-help-1-billing" class="link link-hover">Billing</a>"##,
+        r#"<div class="join join-vertical">"#,
+        r#"onclick="document.getElementById('dd-help-1-support').showModal()">Contact support</button>"#,
+        r#"<dialog id="dd-help-1-support" class="modal">"#,
+    ] {
+        assert!(html.contains(needle), "{}\n{}", needle, html);
+    }
+}
+
+#[test]
+fn layouts_without_a_pure_variant_say_so() {
+    let mut s = Sample::new(1);
+    let mut ids = IdAllocator::default();
+    assert_eq!(
+        purity::layout("blog", "A", &mut s, &Variant::default(), &mut ids),
+        None
+    );
+    assert_eq!(
+        report("blog", r#"<div class="flex gap-2 btn"></div>"#),
+        "**Purity:** `blog` has no daisyUI-only variant yet (2 utility classes). Pure variants: auth, profile, store, faq."
+    );
+    assert_eq!(
+        report("auth", r#"<div class="card"><p class="join"></p></div>"#),
+        "**Purity:** `auth` uses daisyUI classes only."
+    );
+    assert_eq!(
+        report("store", &pure("store", &Variant::default(), None, None)),
+        "**Purity:** `store` still needs 4 utility class(es) with no daisyUI equivalent: `min-h-screen`, `bg-base-100`, `bg-base-200`, `w-12`"
+    );
+}
@@ -0,0 +1,108 @@
+//! Right-to-left mirroring of generated layouts.
+
+#[path = "../../src/html.rs"]
+#[allow(dead_code)] // rtl depends on it
+mod html;
+#[path = "../../src/rtl.rs"]
+mod rtl;
+
+use rtl::{PAIRS, apply, flip};
+
+#[test]
+fn every_pair_mirrors_both_ways() {
+    for (left, right) in PAIRS {
+        assert_eq!(flip(left), *right);
+        assert_eq!(flip(right), *left);
+    }
+}
+
+#[test]
+fn each_pair_mirrors_with_values() {
+    let cases = [
+        ("ml-4", "mr-4"),
+        ("mr-auto", "ml-auto"),
+        ("pl-2", "pr-2"),
+        ("pr-0.5", "pl-0.5"),
+        ("scroll-ml-6", "scroll-mr-6"),
+        ("scroll-pr-4", "scroll-pl-4"),
+        ("left-0", "right-0"),
+        ("right-1/2", "left-1/2"),
+        ("left-[3px]", "right-[3px]"),
+        ("border-l", "border-r"),
+        ("border-r-4", "border-l-4"),
+        ("border-l-primary", "border-r-primary"),
+        ("rounded-l", "rounded-r"),
+        ("rounded-r-box", "rounded-l-box"),
+        ("rounded-tl-lg", "rounded-tr-lg"),
+        ("rounded-br-xl", "rounded-bl-xl"),
+        ("text-left", "text-right"),
+        ("text-right", "text-left"),
+        ("float-right", "float-left"),
+        ("clear-left", "clear-right"),
+        ("origin-left", "origin-right"),
+        ("origin-top-right", "origin-top-left"),
+        ("origin-bottom-left", "origin-bottom-right"),
+        ("bg-left-top", "bg-right-top"),
+        ("bg-gradient-to-br", "bg-gradient-to-bl"),
+        ("bg-gradient-to-l", "bg-gradient-to-r"),
+        ("bg-linear-to-tr", "bg-linear-to-tl"),
+        ("dropdown-left", "dropdown-right"),
+        ("tooltip-right", "tooltip-left"),
+    ];
+    for (class, mirrored) in cases {
+        assert_eq!(flip(class), mirrored, "{}", class);
+    }
+}
+
+#[test]
+fn variants_signs_and_important_marks_are_kept() {
+    assert_eq!(flip("md:ml-4"), "md:mr-4");
+    assert_eq!(flip("-ml-2"), "-mr-2");
+    assert_eq!(flip("lg:hover:-left-4"), "lg:hover:-right-4");
+    assert_eq!(flip("!pl-0"), "!pr-0");
+    assert_eq!(flip("[&>*]:border-l"), "[&>*]:border-r");
+    assert_eq!(flip("ml-[calc(100%-1rem)]"), "mr-[calc(100%-1rem)]");
+}
+
+#[test]
+fn logical_and_lookalike_classes_are_untouched() {
+    for class in [
+        "ms-4",
+        "me-auto",
+        "ps-2",
+        "pe-6",
+        "border-s",
+        "border-e-2",
+        "rounded-s-box",
+        "start-0",
+        "end-4",
+        "rounded-lg",
+        "border-lime-500",
+        "place-items-center",
+        "text-lg",
+        "leading-tight",
+        "btn-lg",
+        "-space-x-4",
+        "bg-gradient-to-b",
+        "menu",
+    ] {
+        assert_eq!(flip(class), class);
+    }
+}
+
+#[test]
+fn markup_gets_dir_on_its_root_and_mirrored_classes() {
+    assert_eq!(
+        apply(r#"<div class="flex border-r ms-2"><span class="ml-auto text-left">x</span></div>"#),
+        r#"<div dir="rtl" class="flex border-l ms-2"><span class="mr-auto text-right">x</span></div>"#
+    );
+    assert_eq!(
+        apply(r#"<div dir="ltr" class="left-0">x</div>"#),
+        r#"<div dir="rtl" class="right-0">x</div>"#
+    );
+    // A page with modals beside it is wrapped so the modals mirror too.
+    assert_eq!(
+        apply("<main class=\"pl-4\"></main>\n<dialog class=\"modal\"></dialog>"),
+        "<div dir=\"rtl\">\n<main class=\"pr-4\"></main>\n<dialog class=\"modal\"></dialog>\n</div>"
+    );
+}
@@ -0,0 +1,154 @@
+//! Seeded sample content: determinism, pools safe to put in markup and
+//! item counts.
+
+#[path = "../../src/docs.rs"]
+#[allow(dead_code)] // sample depends on it
+mod docs;
+#[path = "../../src/sample.rs"]
+mod sample;
+
+use sample::{Counts, MAX_COUNT, POOLS, Sample, count_keys, handle, lanes, seed};
+
+/// Everything a layout might draw, in one line.
+fn draw(sample: &mut Sample) -> String {
+    format!(
+        "{} | {} | {} | {} | {} | {:?}",
+        sample.name(),
+        sample.pick(sample::PRODUCTS),
+        sample.price(12, 180),
+        sample.date(),
+        sample.sentence(),
+        sample.distinct(sample::TASKS, 4),
+    )
+}
+
+#[test]
+fn the_same_seed_draws_the_same_content() {
+    for s in [0, 1, 42, u64::MAX] {
+        assert_eq!(draw(&mut Sample::new(s)), draw(&mut Sample::new(s)));
+    }
+    assert_eq!(seed("Acme"), seed("Acme"));
+    assert_ne!(draw(&mut Sample::new(1)), draw(&mut Sample::new(2)));
+}
+
+#[test]
+fn different_titles_draw_different_content() {
+    let titles = ["Acme", "Globex", "Field Notes", "My App", "acme", ""];
+    let drawn: Vec<String> = titles
+        .iter()
+        .map(|t| draw(&mut Sample::new(seed(t))))
+        .collect();
+    for (i, a) in drawn.iter().enumerate() {
+        for b in &drawn[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+}
+
+#[test]
+fn draws_stay_in_range_and_distinct_items_never_repeat() {
+    let mut sample = Sample::new(seed("ranges"));
+    for _ in 0..500 {
+        let n = sample.between(3, 5);
+        assert!((3..=5).contains(&n), "{}", n);
+        let price = sample.price(12, 180);
+        let dollars: u32 = price[1..price.len() - 3].parse().unwrap();
+        assert!((12..=180).contains(&dollars), "{}", price);
+        let (month, day) = {
+            let date = sample.date();
+            let (m, d) = date.split_once(' ').unwrap();
+            (m.to_string(), d.parse::<u32>().unwrap())
+        };
+        assert!(sample::MONTHS.contains(&month.as_str()) && (1..=28).contains(&day));
+    }
+    let tasks = sample.distinct(sample::TASKS, 4);
+    assert_eq!(tasks.len(), 4);
+    for task in &tasks {
+        assert_eq!(tasks.iter().filter(|t| *t == task).count(), 1, "{}", task);
+    }
+    assert_eq!(sample.distinct(&["a", "b"], 5).len(), 2);
+}
+
+#[test]
+fn no_pool_item_needs_escaping() {
+    for (pool, items) in POOLS {
+        assert!(!items.is_empty(), "{}", pool);
+        for item in *items {
+            assert!(
+                !item.is_empty() && !item.contains(['<', '>', '&', '"', '\'']),
+                "{} item {:?} needs escaping",
+                pool,
+                item
+            );
+        }
+    }
+    for (label, color) in sample::LABELS {
+        assert!(label.chars().all(char::is_alphanumeric), "{}", label);
+        assert!(color.starts_with("badge-"), "{}", color);
+    }
+    assert_eq!(handle("Ada Lovelace"), "adalovelace");
+    assert_eq!(handle("Pour-Over Kettle"), "pouroverkettle");
+}
+
+#[test]
+fn counts_keep_the_keys_a_layout_reads_and_clamp_the_rest() {
+    let (counts, notes) = Counts::parse("store", [("cards", 12), ("posts", 3)]);
+    assert_eq!(counts.get("cards", 3), 12);
+    assert_eq!(counts.get("posts", 2), 2);
+    assert_eq!(
+        notes,
+        ["Ignored count 'posts': the store layout reads cards."]
+    );
+
+    let (counts, notes) = Counts::parse("kanban", [("columns", 80), ("cards", 0)]);
+    assert_eq!(counts.get("columns", 3), MAX_COUNT);
+    assert_eq!(counts.get("cards", 1), 1);
+    assert_eq!(
+        notes,
+        [
+            "Count 'columns' must be between 1 and 50, so 50 was used.",
+            "Count 'cards' must be between 1 and 50, so 1 was used.",
+        ]
+    );
+
+    let (counts, notes) = Counts::parse("saas", [("cards", 4)]);
+    assert_eq!(counts, Counts::default());
+    assert_eq!(notes, ["Ignored count 'cards': the saas layout has none."]);
+    assert!(count_keys("saas").is_empty());
+    assert_eq!(count_keys("kanban").len(), 2);
+}
+
+#[test]
+fn many_repeats_the_pool_only_once_it_runs_out() {
+    let mut sample = Sample::new(seed("many"));
+    let few = sample.many(sample::PRODUCTS, 5);
+    assert_eq!(few.len(), 5);
+    for item in &few {
+        assert_eq!(few.iter().filter(|i| *i == item).count(), 1, "{}", item);
+    }
+    let lots = sample.many(&["a", "b", "c"], 7);
+    assert_eq!(lots.len(), 7);
+    for round in lots.chunks(3) {
+        for item in round {
+            assert_eq!(round.iter().filter(|i| *i == item).count(), 1);
+        }
+    }
+    let counted = Sample::new(1).with_counts(Counts::parse("blog", [("posts", 6)]).0);
+    assert_eq!(counted.count("posts", 1), 6);
+    assert_eq!(counted.count("cards", 1), 1);
+}
+
+#[test]
+fn lanes_run_from_to_do_to_done() {
+    let names = |n| lanes(n).into_iter().map(|(l, _)| l).collect::<Vec<_>>();
+    assert_eq!(names(1), ["To Do"]);
+    assert_eq!(names(2), ["To Do", "Done"]);
+    assert_eq!(names(3), ["To Do", "In Progress", "Done"]);
+    let eight = names(8);
+    assert_eq!(eight[6], "Stage 7");
+    assert_eq!(eight[7], "Done");
+    assert_eq!(
+        lanes(3).iter().map(|(_, s)| *s).collect::<Vec<_>>(),
+        ["todo", "in-progress", "done"]
+    );
+}
@@ -0,0 +1,291 @@
+//! Named layout sections: how pages hold them, and picking, composing and
+//! filling them.
+
+#[path = "../../src/sections.rs"]
+mod sections;
+
+use sections::{Page, SECTIONS, SLOTS, all_names, compose, fill, pick, resolve};
+
+fn drawer() -> Page {
+    Page::default()
+        .markup(r#"<div class="drawer"><input type="checkbox" class="drawer-toggle" /><div class="drawer-content">"#)
+        .section("navbar", r#"<div class="navbar">Acme</div>"#)
+        .section("main", r#"<div class="p-6"><h2>Dashboard</h2></div>"#)
+        .markup("</div>")
+        .section(
+            "sidebar",
+            r#"<div class="drawer-side"><ul class="menu"><li>Overview</li></ul></div>"#,
+        )
+        .markup("</div>")
+}
+
+const DRAWER: &str = r#"<div class="drawer"><input type="checkbox" class="drawer-toggle" /><div class="drawer-content"><div class="navbar">Acme</div><div class="p-6"><h2>Dashboard</h2></div></div><div class="drawer-side"><ul class="menu"><li>Overview</li></ul></div></div>"#;
+
+#[test]
+fn pages_join_their_markup_and_name_their_sections() {
+    let page = drawer();
+    assert_eq!(page.html(), DRAWER);
+    assert_eq!(page.names(), ["navbar", "main", "sidebar"]);
+    assert_eq!(
+        page.get("main").as_deref(),
+        Some(r#"<div class="p-6"><h2>Dashboard</h2></div>"#)
+    );
+    assert_eq!(page.get("footer"), None);
+    assert_eq!(Page::from(DRAWER.to_string()).html(), DRAWER);
+    assert!(Page::from(DRAWER.to_string()).names().is_empty());
+}
+
+#[test]
+fn whitespace_stays_in_the_page_but_not_the_section() {
+    let page = Page::default()
+        .markup("<main>")
+        .section("hero", "\n  <div class=\"hero\">hi</div>\n")
+        .markup("</main>");
+    assert_eq!(
+        page.html(),
+        "<main>\n  <div class=\"hero\">hi</div>\n</main>"
+    );
+    assert_eq!(
+        page.get("hero").as_deref(),
+        Some("<div class=\"hero\">hi</div>")
+    );
+    let blank = Page::default().section("hero", "  ");
+    assert_eq!(blank.html(), "  ");
+    assert!(blank.names().is_empty());
+}
+
+#[test]
+fn pieces_of_a_section_join_with_newlines() {
+    let page = Page::default()
+        .markup(r#"<div class="min-h-screen"><main><h1>Users</h1>"#)
+        .section("toolbar", "<div>toolbar</div>")
+        .markup("</main></div>\n")
+        .beside("modals", r#"<dialog class="modal">edit</dialog>"#)
+        .markup("\n")
+        .beside("modals", r#"<dialog class="modal">delete</dialog>"#);
+    assert_eq!(page.names(), ["toolbar", "modals"]);
+    assert_eq!(
+        pick("crud", &page, "modals").unwrap(),
+        "<dialog class=\"modal\">edit</dialog>\n<dialog class=\"modal\">delete</dialog>"
+    );
+    assert_eq!(
+        pick("crud", &page, "toolbar").unwrap(),
+        "<div>toolbar</div>"
+    );
+}
+
+#[test]
+fn mapping_a_page_keeps_its_sections() {
+    let page = drawer().map(|markup| markup.replace("Acme", "Initech"));
+    assert_eq!(page.names(), ["navbar", "main", "sidebar"]);
+    assert_eq!(
+        page.get("navbar").as_deref(),
+        Some(r#"<div class="navbar">Initech</div>"#)
+    );
+    assert_eq!(page.html(), DRAWER.replace("Acme", "Initech"));
+}
+
+#[test]
+fn unknown_sections_list_the_rest() {
+    let error = pick("dashboard", &drawer(), "footer").unwrap_err();
+    assert_eq!(
+        error,
+        "Unknown section 'footer' for dashboard. Available: navbar, main, sidebar"
+    );
+    assert!(
+        pick("my-template", &Page::from(DRAWER.to_string()), "hero")
+            .unwrap_err()
+            .contains("no named sections")
+    );
+}
+
+#[test]
+fn every_declared_name_is_kebab_case_and_listed_once() {
+    let all = all_names();
+    for (layout, sections) in SECTIONS {
+        assert!(!sections.is_empty(), "{}", layout);
+        for name in *sections {
+            assert!(
+                name.chars().all(|c| c.is_ascii_lowercase() || c == '-'),
+                "{}",
+                name
+            );
+            assert_eq!(all.iter().filter(|n| *n == name).count(), 1, "{}", name);
+            assert_eq!(
+                sections.iter().filter(|n| *n == name).count(),
+                1,
+                "{} {}",
+                layout,
+                name
+            );
+        }
+    }
+}
+
+#[test]
+fn section_ids_resolve_with_or_without_a_layout() {
+    assert_eq!(resolve("hero"), Ok(("saas", "hero")));
+    assert_eq!(resolve("faq"), Ok(("pricing", "faq")));
+    assert_eq!(resolve("dashboard:sidebar"), Ok(("dashboard", "sidebar")));
+    assert_eq!(
+        resolve("nope:hero"),
+        Err("Unknown layout 'nope' in 'nope:hero'".to_string())
+    );
+    assert!(
+        resolve("auth:hero")
+            .unwrap_err()
+            .starts_with("Unknown section 'hero' for auth. Available: form")
+    );
+    assert!(
+        resolve("gallery")
+            .unwrap_err()
+            .starts_with("No layout has a section named 'gallery'")
+    );
+}
+
+fn fixture(layout: &str) -> Page {
+    match layout {
+        "saas" => Page::default()
+            .markup(r#"<div class="min-h-screen">"#)
+            .section("navbar", r#"<div class="navbar">saas nav</div>"#)
+            .section(
+                "hero",
+                r#"<header class="hero min-h-[80vh] bg-base-200">saas hero</header>"#,
+            )
+            .markup("<main>")
+            .section("logos", "<div>logos</div>")
+            .section("features", "<section>features</section>")
+            .markup("</main>")
+            .section("footer", r#"<footer class="footer">saas foot</footer>"#)
+            .markup("</div>"),
+        "error" => Page::default().section("hero", r#"<div class="hero min-h-screen">oops</div>"#),
+        "blog" => Page::default()
+            .markup(r#"<div class="min-h-screen">"#)
+            .section("navbar", r#"<div class="navbar">blog nav</div>"#)
+            .section(
+                "featured",
+                r#"<div class="card lg:card-side">featured</div>"#,
+            )
+            .markup("</div>"),
+        "crud" => Page::default()
+            .markup(r#"<div class="min-h-screen"><main class="container">"#)
+            .section("table", "<div>table</div>")
+            .markup("</main></div>\n")
+            .beside("modals", r#"<dialog class="modal">edit</dialog>"#),
+        _ => Page::default(),
+    }
+}
+
+#[test]
+fn composed_pages_share_one_wrapper_and_keep_one_navbar() {
+    let (html, notes) = compose(
+        &[
+            "crud:modals",
+            "navbar",
+            "blog:navbar",
+            "hero",
+            "crud:table",
+            "footer",
+        ],
+        fixture,
+    )
+    .unwrap();
+    assert_eq!(
+        html,
+        r#"<!-- Composed from: modals (crud), navbar (saas), hero (saas), table (crud), footer (saas) -->
+<div class="min-h-screen bg-base-100">
+<div class="navbar">saas nav</div>
+<header class="hero min-h-[80vh] bg-base-200">saas hero</header>
+<div>table</div>
+<footer class="footer">saas foot</footer>
+</div>
+<dialog class="modal">edit</dialog>"#
+    );
+    assert_eq!(
+        notes,
+        ["Dropped blog:navbar; the page already has the navbar from saas."]
+    );
+}
+
+#[test]
+fn only_the_first_full_height_section_keeps_its_height() {
+    let (html, notes) = compose(&["error:hero", "saas:hero", "hero"], fixture).unwrap();
+    assert!(html.contains(r#"<div class="hero min-h-screen">oops</div>"#));
+    assert!(html.contains(r#"<header class="hero bg-base-200">saas hero</header>"#));
+    assert_eq!(
+        notes,
+        [
+            "Removed the full-height classes from saas:hero; only the first full-height section keeps them.",
+            "Dropped the repeated saas:hero.",
+        ]
+    );
+    assert_eq!(compose(&[], fixture), Err("No sections given".to_string()));
+}
+
+#[test]
+fn each_layout_is_generated_once() {
+    let mut calls = Vec::new();
+    compose(&["navbar", "hero", "footer", "blog:featured"], |layout| {
+        calls.push(layout.to_string());
+        fixture(layout)
+    })
+    .unwrap();
+    assert_eq!(calls, ["saas", "blog"]);
+}
+
+#[test]
+fn slots_replace_a_section_s_children_and_keep_its_element() {
+    let (html, notes) = fill(
+        "dashboard",
+        &drawer(),
+        &[
+            ("main", "<my-chart data-x=\"1 & 2\"></my-chart>"),
+            ("sidebar", "<p>Custom</p>"),
+        ],
+    );
+    assert_eq!(
+        html,
+        r#"<div class="drawer"><input type="checkbox" class="drawer-toggle" /><div class="drawer-content"><div class="navbar">Acme</div><div class="p-6"><my-chart data-x="1 & 2"></my-chart></div></div><div class="drawer-side"><p>Custom</p></div></div>"#
+    );
+    assert!(notes.is_empty(), "{:?}", notes);
+
+    let (html, notes) = fill("dashboard", &drawer(), &[]);
+    assert_eq!(html, DRAWER);
+    assert!(notes.is_empty());
+}
+
+#[test]
+fn unknown_and_missing_slots_are_reported_and_scripts_warned_about() {
+    let (html, notes) = fill(
+        "dashboard",
+        &drawer(),
+        &[
+            ("footer", "<p>foot</p>"),
+            ("navbar", "<p>nav</p>"),
+            ("main", "<SCRIPT>alert(1)</SCRIPT>"),
+        ],
+    );
+    assert!(html.contains(r#"<div class="p-6"><SCRIPT>alert(1)</SCRIPT></div>"#));
+    assert!(html.contains(r#"<div class="navbar">Acme</div>"#));
+    assert_eq!(
+        notes,
+        [
+            "The dashboard layout has no footer slot, so it was not used. Its slots: main, sidebar.",
+            "Unknown slot 'navbar'. Slots: hero, sidebar, main, footer.",
+            "Warning: the main slot contains a <script> tag. It was inserted as given, so check it before using the page.",
+        ]
+    );
+    let (html, notes) = fill(
+        "my-template",
+        &Page::from(DRAWER.to_string()),
+        &[("hero", "<p>hi</p>")],
+    );
+    assert_eq!(html, DRAWER);
+    assert_eq!(
+        notes,
+        ["The my-template layout has no slots, so 'hero' was not used."]
+    );
+    for slot in SLOTS {
+        assert!(all_names().contains(slot), "{}", slot);
+    }
+}
@@ -0,0 +1,171 @@
+//! Slash command flags and the defaults Zed's settings and a worktree's
+//! settings file provide.
+
+#[path = "../../src/docs.rs"]
+#[allow(dead_code)] // sample depends on it
+mod docs;
+#[path = "../../src/sample.rs"]
+#[allow(dead_code)] // settings depend on it
+mod sample;
+#[path = "../../src/settings.rs"]
+#[allow(dead_code)] // most option lists are only read by the extension
+mod settings;
+
+use serde_json::{Value, json};
+use settings::{FILE, Settings};
+use std::collections::HashMap;
+
+fn split(args: &[&str]) -> (Vec<String>, HashMap<String, String>) {
+    let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+    Settings::split_flags(&args)
+}
+
+/// Settings from JSON, read the way the extension reads its settings file.
+fn from_json(value: &Value) -> Settings {
+    Settings::from_values(
+        |key| match value.get(key)? {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        },
+        |key| value.get(key)?.as_u64(),
+    )
+}
+
+#[test]
+fn switches_leave_the_next_argument_alone() {
+    let (positional, flags) = split(&["dashboard", "--rtl", "Admin"]);
+    assert_eq!(positional, ["dashboard", "Admin"]);
+    assert_eq!(flags["rtl"], "");
+    assert!(Settings::enabled(&flags, "rtl"));
+    for switch in [
+        "jsx",
+        "full",
+        "minify",
+        "footprint",
+        "annotate-responsive",
+        "explain",
+    ] {
+        let (positional, flags) = split(&["blog", &format!("--{}", switch), "Field", "Notes"]);
+        assert_eq!(positional, ["blog", "Field", "Notes"], "{}", switch);
+        assert!(Settings::enabled(&flags, switch), "{}", switch);
+    }
+    let (positional, flags) = split(&["blog", "--made-up", "Notes"]);
+    assert_eq!(positional, ["blog", "Notes"]);
+    assert!(flags.contains_key("made-up"));
+}
+
+#[test]
+fn value_flags_take_the_next_argument() {
+    let (positional, flags) = split(&[
+        "store",
+        "--theme",
+        "dracula",
+        "Bean",
+        "--cards",
+        "4",
+        "There",
+        "--id-prefix",
+        "shop",
+    ]);
+    assert_eq!(positional, ["store", "Bean", "There"]);
+    assert_eq!(flags["theme"], "dracula");
+    assert_eq!(flags["cards"], "4");
+    assert_eq!(flags["id-prefix"], "shop");
+    // A value flag followed by another flag gets no value.
+    let (positional, flags) = split(&["store", "--theme", "--rtl", "Shop"]);
+    assert_eq!(positional, ["store", "Shop"]);
+    assert_eq!(flags["theme"], "");
+    assert!(Settings::enabled(&flags, "rtl"));
+}
+
+#[test]
+fn equals_signs_set_any_flag() {
+    let (positional, flags) = split(&["blog", "--rtl=false", "--theme=dark", "--jsx=true", "x"]);
+    assert_eq!(positional, ["blog", "x"]);
+    assert!(!Settings::enabled(&flags, "rtl"));
+    assert!(Settings::enabled(&flags, "jsx"));
+    assert!(!Settings::enabled(&flags, "full"));
+    assert_eq!(flags["theme"], "dark");
+}
+
+#[test]
+fn flags_beat_settings_beat_defaults() {
+    let with = from_json(&json!({ "theme": "cupcake", "format": "jsx", "search_results": 7 }));
+    let without = Settings::default();
+    for (args, settings, key, expected) in [
+        (&["--theme=dark"][..], &with, "theme", "dark"),
+        (&["--theme", "dark"][..], &with, "theme", "dark"),
+        (&[][..], &with, "theme", "cupcake"),
+        (&[][..], &without, "theme", ""),
+        (&["--format=yew"][..], &with, "format", "yew"),
+        (&[][..], &with, "format", "jsx"),
+        (&[][..], &without, "format", "html"),
+        (&["--limit", "3"][..], &with, "limit", "3"),
+        (&[][..], &with, "limit", "7"),
+        (&[][..], &without, "limit", "20"),
+    ] {
+        let (_, flags) = split(args);
+        let (setting, default) = match key {
+            "theme" => (settings.theme.clone(), ""),
+            "format" => (settings.format.clone(), "html"),
+            _ => (settings.search_results.map(|n| n.to_string()), "20"),
+        };
+        assert_eq!(
+            Settings::resolve(&flags, key, setting.as_deref(), default),
+            expected,
+            "{:?} {}",
+            args,
+            key
+        );
+    }
+}
+
+#[test]
+fn settings_files_are_read_leniently() {
+    let settings = from_json(&json!({
+        "theme": "dark",
+        "daisyui_version": 4,
+        "format": ["html"],
+        "search_results": 500,
+        "completion_results": 0,
+        "max_output": 10,
+        "unknown": "ignored",
+    }));
+    assert_eq!(settings.theme.as_deref(), Some("dark"));
+    assert_eq!(settings.daisyui_version.as_deref(), Some("4"));
+    assert_eq!(settings.format, None);
+    assert_eq!(settings.search_results, Some(100));
+    assert_eq!(settings.completion_results, Some(1));
+    assert_eq!(settings.max_output, Some(Settings::MIN_OUTPUT));
+    assert_eq!(from_json(&json!({})), Settings::default());
+    assert_eq!(from_json(&json!("not an object")), Settings::default());
+}
+
+#[test]
+fn project_settings_override_user_settings_key_by_key() {
+    let user = from_json(&json!({ "theme": "dark", "format": "jsx", "search_results": 5 }));
+    let project = from_json(&json!({ "theme": "cupcake", "purity": "daisy-only" }));
+    let settings = project.over(user.clone());
+    assert_eq!(settings.theme.as_deref(), Some("cupcake"));
+    assert_eq!(settings.format.as_deref(), Some("jsx"));
+    assert_eq!(settings.search_results, Some(5));
+    assert_eq!(settings.purity.as_deref(), Some("daisy-only"));
+    assert_eq!(Settings::default().over(user.clone()), user);
+}
+
+#[test]
+fn bad_values_name_the_settings_file() {
+    assert_eq!(Settings::check("format", "html", Settings::FORMATS), Ok(()));
+    let error = Settings::check("format", "php", Settings::FORMATS).unwrap_err();
+    assert!(
+        error.starts_with("Invalid format 'php'. Valid: html, jsx, leptos, yew, askama, tera."),
+        "{}",
+        error
+    );
+    assert!(error.contains(FILE), "{}", error);
+    assert!(error.contains("lsp.daisy-days.settings"), "{}", error);
+    for key in Settings::KEYS {
+        assert!(error.contains(key), "{}", key);
+    }
+}
@@ -0,0 +1,81 @@
+//! Site presets and the navbar links between their pages.
+
+#[path = "../../src/html.rs"]
+#[allow(dead_code)] // site depends on it
+mod html;
+#[path = "../../src/site.rs"]
+mod site;
+
+use site::{PRESETS, build, find, link_navbar};
+
+#[test]
+fn presets_start_at_index_and_name_each_file_once() {
+    for preset in PRESETS {
+        assert_eq!(preset.pages[0].file, "index.html", "{}", preset.name);
+        for page in preset.pages {
+            assert!(page.file.ends_with(".html"), "{}", page.file);
+            assert_eq!(
+                preset.pages.iter().filter(|p| p.file == page.file).count(),
+                1,
+                "{} {}",
+                preset.name,
+                page.file
+            );
+        }
+    }
+    assert_eq!(find(" Shop ").unwrap().pages[1].layout, "checkout");
+    assert_eq!(
+        find("blog").unwrap_err(),
+        "Unknown site preset 'blog'. Available: startup, shop"
+    );
+}
+
+#[test]
+fn an_existing_menu_gets_the_site_links() {
+    let startup = find("startup").unwrap();
+    let html = r#"<div><nav class="navbar"><a class="btn">Acme</a><ul class="menu menu-horizontal hidden sm:flex"><li><a>Features</a></li></ul><button class="btn">Go</button></nav><main>body</main></div>"#;
+    assert_eq!(
+        link_navbar(html, startup, "pricing.html", "Acme"),
+        r#"<div><nav class="navbar"><a href="index.html" class="btn">Acme</a><ul class="menu menu-horizontal hidden sm:flex"><li><a href="index.html">Home</a></li><li><a href="pricing.html" class="menu-active" aria-current="page">Pricing</a></li><li><a href="docs.html">Docs</a></li><li><a href="login.html">Sign in</a></li></ul><button class="btn">Go</button></nav><main>body</main></div>"#
+    );
+}
+
+#[test]
+fn a_navbar_without_a_menu_gets_one_at_the_end() {
+    let shop = find("shop").unwrap();
+    let html = r#"<div><nav class="navbar"><a href="/" class="btn">Acme</a></nav></div>"#;
+    let linked = link_navbar(html, shop, "index.html", "Acme");
+    assert!(linked.starts_with(
+        r#"<div><nav class="navbar"><a href="index.html" class="btn">Acme</a><ul class="menu menu-horizontal px-1 ml-auto"><li><a href="index.html" class="menu-active" aria-current="page">Shop</a></li>"#
+    ));
+    assert!(linked.ends_with("</ul></nav></div>"));
+}
+
+#[test]
+fn a_drawer_bar_is_shown_on_large_screens_with_its_button_hidden() {
+    let startup = find("startup").unwrap();
+    let html = r#"<div class="drawer"><div class="drawer-content"><nav class="navbar bg-base-100 lg:hidden"><label for="dd-drawer-1" class="btn btn-square">≡</label><div class="flex-1">Acme</div></nav></div></div>"#;
+    let linked = link_navbar(html, startup, "docs.html", "Acme");
+    assert!(linked.contains(r#"<nav class="navbar bg-base-100"><label for="dd-drawer-1" class="lg:hidden btn btn-square">"#));
+    assert!(
+        linked.contains(r#"<a href="docs.html" class="menu-active" aria-current="page">Docs</a>"#)
+    );
+}
+
+#[test]
+fn pages_without_a_navbar_get_one_first_in_the_root() {
+    let shop = find("shop").unwrap();
+    let pages = build(shop, "Field &amp; Co", |page| {
+        format!(
+            r#"<div class="min-h-screen"><main>{}</main></div>"#,
+            page.layout
+        )
+    });
+    assert_eq!(pages.len(), 4);
+    let (page, html) = &pages[3];
+    assert_eq!(page.file, "account.html");
+    assert!(html.starts_with(
+        "<div class=\"min-h-screen\">\n<nav class=\"navbar bg-base-100 border-b border-base-200\" aria-label=\"Main\"><a href=\"index.html\" class=\"btn btn-ghost text-xl\">Field &amp; Co</a>"
+    ));
+    assert!(html.ends_with("</ul></nav><main>profile</main></div>"));
+}
@@ -0,0 +1,291 @@
+//! Keyboard shortcuts, the command palette built from them, and avatars.
+
+#[path = "../../src/docs.rs"]
+#[allow(dead_code)] // sample depends on it
+mod docs;
+#[path = "../../src/html.rs"]
+mod html;
+#[path = "../../src/sample.rs"]
+#[allow(dead_code)] // snippets depend on it
+mod sample;
+#[path = "../../src/sections.rs"]
+#[allow(dead_code)] // snippets depend on it
+mod sections;
+#[path = "../../src/snippets.rs"]
+#[allow(dead_code)] // only shortcuts, the palette and avatars are tested here
+mod snippets;
+#[path = "../../src/variant.rs"]
+#[allow(dead_code)] // snippets depend on it
+mod variant;
+
+use html::{Element, elements};
+use snippets::{
+    AvatarShape, AvatarSize, PaletteCommand, Platform, Presence, Shortcut, avatar, avatar_group,
+    check_id, command_palette, default_palette_commands, kbd_row, user_card,
+};
+
+fn labels(input: &str, platform: Platform) -> Vec<String> {
+    Shortcut::parse(input, platform)
+        .unwrap_or_else(|e| panic!("{}: {}", input, e))
+        .labels()
+}
+
+#[test]
+fn shortcuts_normalize_modifiers_per_platform() {
+    for (input, mac, pc) in [
+        ("mod+k", "⌘ K", "Ctrl K"),
+        ("cmd+shift+p", "⇧ ⌘ P", "Ctrl Shift P"),
+        ("Command + K", "⌘ K", "Ctrl K"),
+        ("⌘+enter", "⌘ ↵", "Ctrl Enter"),
+        ("cmdorctrl+s", "⌘ S", "Ctrl S"),
+        ("ctrl+alt+delete", "⌃ ⌥ ⌦", "Ctrl Alt Del"),
+        ("shift+ctrl+tab", "⌃ ⇧ ⇥", "Ctrl Shift Tab"),
+        ("meta+up", "⌘ ↑", "Win ↑"),
+        ("win+shift+s", "⇧ ⌘ S", "Win Shift S"),
+        ("super+alt+f12", "⌥ ⌘ F12", "Win Alt F12"),
+        ("opt+esc", "⌥ Esc", "Alt Esc"),
+        ("mod+,", "⌘ ,", "Ctrl ,"),
+        ("mod+/", "⌘ /", "Ctrl /"),
+        ("ctrl++", "⌃ +", "Ctrl +"),
+        ("+", "+", "+"),
+        ("f1", "F1", "F1"),
+    ] {
+        assert_eq!(labels(input, Platform::Mac).join(" "), mac, "{}", input);
+        assert_eq!(labels(input, Platform::Pc).join(" "), pc, "{}", input);
+    }
+}
+
+#[test]
+fn duplicate_modifiers_collapse() {
+    assert_eq!(labels("cmd+mod+k", Platform::Mac), ["⌘", "K"]);
+    assert_eq!(labels("cmd+ctrl+k", Platform::Pc), ["Ctrl", "K"]);
+    assert_eq!(labels("shift+shift+a", Platform::Pc), ["Shift", "A"]);
+}
+
+#[test]
+fn bad_shortcuts_are_refused() {
+    for (input, error) in [
+        ("", "Empty shortcut"),
+        ("   ", "Empty shortcut"),
+        ("cmd+shift", "Shortcut 'cmd+shift' needs a non-modifier key"),
+        (
+            "cmd+a+b",
+            "Shortcut 'cmd+a+b' has more than one non-modifier key ('A' and 'B')",
+        ),
+        ("cmd+hyper", "Unknown key 'hyper' in shortcut 'cmd+hyper'"),
+        ("f25", "Unknown key 'f25' in shortcut 'f25'"),
+    ] {
+        assert_eq!(
+            Shortcut::parse(input, Platform::Mac).err().as_deref(),
+            Some(error),
+            "{:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn kbd_rows_join_keys_on_pc_only() {
+    assert_eq!(
+        kbd_row("mod+k", Platform::Mac, Some("sm")).unwrap(),
+        r#"<kbd class="kbd kbd-sm">⌘</kbd><kbd class="kbd kbd-sm">K</kbd>"#
+    );
+    assert_eq!(
+        kbd_row("cmd+k", Platform::Pc, None).unwrap(),
+        r#"<kbd class="kbd">Ctrl</kbd>+<kbd class="kbd">K</kbd>"#
+    );
+    assert_eq!(
+        kbd_row("mod+k", Platform::Mac, Some("huge"))
+            .err()
+            .as_deref(),
+        Some("Unknown kbd size 'huge'. Valid: xs, sm, md, lg, xl")
+    );
+}
+
+#[test]
+fn palette_ids_are_checked() {
+    for id in ["dd-palette-1", "search_2", "P"] {
+        assert_eq!(check_id(id), Ok(()), "{}", id);
+        let html = command_palette(id, &default_palette_commands(), Platform::Mac).unwrap();
+        assert!(html.contains(&format!("<dialog id=\"{}\"", id)));
+        assert!(html.contains(&format!("document.getElementById('{}')", id)));
+    }
+    for id in [
+        "",
+        "a b",
+        "x');alert(1);('",
+        "x\" onmouseover=\"alert(1)",
+        "<p>",
+        "é",
+    ] {
+        assert_eq!(
+            command_palette(id, &default_palette_commands(), Platform::Pc)
+                .err()
+                .as_deref(),
+            Some(format!("Invalid id '{}'. Use letters, digits, '-' and '_'", id).as_str()),
+            "{:?}",
+            id
+        );
+    }
+}
+
+#[test]
+fn palettes_group_commands_and_escape_labels() {
+    let commands = [
+        PaletteCommand::new("Files", "Open <recent>", Some("cmd+o")),
+        PaletteCommand::new("Help", "About", None),
+        PaletteCommand::new("Files", "Save", Some("mod+s")),
+    ];
+    let html = command_palette("cp", &commands, Platform::Pc).unwrap();
+    let files = html.find(">Files</li>").unwrap();
+    let help = html.find(">Help</li>").unwrap();
+    assert!(files < html.find("Save").unwrap() && html.find("Save").unwrap() < help);
+    assert!(html.contains("Open &lt;recent&gt;"));
+    assert!(!html.contains("Win"));
+    assert_eq!(html.matches(">Ctrl</kbd>").count(), 3);
+    assert_eq!(
+        command_palette(
+            "cp",
+            &[PaletteCommand::new("G", "Bad", Some("cmd+a+b"))],
+            Platform::Mac
+        )
+        .err()
+        .as_deref(),
+        Some("Shortcut 'cmd+a+b' has more than one non-modifier key ('A' and 'B')")
+    );
+}
+
+/// Where `html` breaks daisyUI's avatar structure: `.avatar` holds exactly
+/// one sized, shaped `div`, which holds an image, or a `span` of initials
+/// when the avatar is an `avatar-placeholder`; `.avatar-group` holds only
+/// avatars.
+fn avatar_problems(html: &str) -> Vec<String> {
+    let elements = elements(html);
+    let children =
+        |i: usize| -> Vec<&Element> { elements.iter().filter(|e| e.parent == Some(i)).collect() };
+    let mut problems = Vec::new();
+    for (i, element) in elements.iter().enumerate() {
+        let markup = &html[element.start..element.end];
+        if element.has_class("avatar-group") {
+            if children(i).iter().any(|c| !c.has_class("avatar")) {
+                problems.push(format!("group holds a non-avatar: {}", markup));
+            }
+            continue;
+        }
+        if !element.has_class("avatar") {
+            for class in ["avatar-placeholder", "avatar-online", "avatar-offline"] {
+                if element.has_class(class) {
+                    problems.push(format!("{} outside .avatar: {}", class, markup));
+                }
+            }
+            continue;
+        }
+        let inner = children(i);
+        let [wrapper] = inner.as_slice() else {
+            problems.push(format!("avatar needs one wrapper: {}", markup));
+            continue;
+        };
+        let wrapper_index = elements.iter().position(|e| e == *wrapper).unwrap();
+        if wrapper.tag != "div"
+            || !wrapper.classes.iter().any(|c| c.starts_with("w-"))
+            || !["rounded-full", "rounded-box", "mask"]
+                .iter()
+                .any(|c| wrapper.has_class(c))
+        {
+            problems.push(format!("wrapper is not a sized, shaped div: {}", markup));
+        }
+        let content: Vec<&str> = children(wrapper_index)
+            .iter()
+            .map(|c| c.tag.as_str())
+            .collect();
+        let expected = if element.has_class("avatar-placeholder") {
+            "span"
+        } else {
+            "img"
+        };
+        if content != [expected] {
+            problems.push(format!("wrapper should hold one {}: {}", expected, markup));
+        }
+    }
+    problems
+}
+
+#[test]
+fn avatars_keep_daisyui_structure() {
+    let mut pages = Vec::new();
+    for size in AvatarSize::NAMES {
+        for shape in AvatarShape::NAMES {
+            for presence in [None, Some(Presence::Online), Some(Presence::Offline)] {
+                for source in [
+                    "https://example.com/a.png",
+                    "./me.webp",
+                    "Ada Lovelace",
+                    "x",
+                ] {
+                    pages.push(avatar(
+                        source,
+                        AvatarSize::parse(size).unwrap(),
+                        AvatarShape::parse(shape).unwrap(),
+                        presence,
+                    ));
+                }
+            }
+        }
+    }
+    let sources = [
+        "/a.jpg",
+        "Grace Hopper",
+        "https://example.com/b.png",
+        "Linus",
+    ];
+    for size in AvatarSize::NAMES {
+        for max in 0..=5 {
+            pages.push(avatar_group(
+                &sources,
+                AvatarSize::parse(size).unwrap(),
+                max,
+            ));
+        }
+    }
+    let face = avatar("Ada Lovelace", AvatarSize::Md, AvatarShape::Circle, None);
+    pages.push(user_card("Ada", "Engineer", &face, &[]));
+    pages.push(user_card("Ada", "Engineer", &face, &["Follow", "Message"]));
+    for page in &pages {
+        let avatars = elements(page)
+            .iter()
+            .filter(|e| e.has_class("avatar"))
+            .count();
+        assert!(avatars > 0, "{}", page);
+        assert_eq!(avatar_problems(page), Vec::<String>::new(), "{}", page);
+    }
+}
+
+#[test]
+fn avatar_checks_catch_broken_markup() {
+    for broken in [
+        r#"<div class="avatar"><img src="/a.png" /></div>"#,
+        r#"<div class="avatar"><div class="rounded-full"><img src="/a.png" /></div></div>"#,
+        r#"<div class="avatar"><div class="w-12 rounded-full"><span>AL</span></div></div>"#,
+        r#"<div class="avatar avatar-placeholder"><div class="w-12 rounded-full"><img src="/a.png" /></div></div>"#,
+        r#"<div class="avatar-group"><img src="/a.png" /></div>"#,
+        r#"<div class="placeholder avatar-online"></div>"#,
+    ] {
+        assert!(!avatar_problems(broken).is_empty(), "{}", broken);
+    }
+}
+
+#[test]
+fn avatar_groups_collapse_past_max_and_cards_escape() {
+    let group = avatar_group(&["A", "B", "C", "D"], AvatarSize::Sm, 2);
+    let avatars = elements(&group)
+        .iter()
+        .filter(|e| e.has_class("avatar"))
+        .count();
+    assert_eq!(avatars, 3);
+    assert!(group.contains(">+2</span>"));
+    assert!(group.starts_with(r#"<div class="avatar-group -space-x-3">"#));
+    let card = user_card("<Ada>", "R&D", "", &["Follow", "Mute"]);
+    assert!(card.contains("&lt;Ada&gt;") && card.contains("R&amp;D"));
+    assert!(card.contains(r#"<button class="btn btn-sm btn-outline">Follow</button>"#));
+    assert!(card.contains(r#"<button class="btn btn-sm btn-ghost">Mute</button>"#));
+}
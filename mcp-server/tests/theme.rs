@@ -0,0 +1,121 @@
+//! Complete daisyUI 5 theme blocks built from overrides.
+
+#[path = "../../src/html.rs"]
+#[allow(dead_code)] // theme depends on it
+mod html;
+#[path = "../../src/theme.rs"]
+mod theme;
+
+use theme::{
+    COLORS, SHAPE, check_known, check_name, is_dark, parse_override, plugin, scope, variables,
+};
+
+/// `(variable, value)` pairs declared in a theme block, in order.
+fn declarations(css: &str) -> Vec<(String, String)> {
+    css.lines()
+        .filter_map(|line| line.trim().strip_suffix(';')?.split_once(':'))
+        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+        .collect()
+}
+
+#[test]
+fn a_theme_declares_every_daisyui_variable() {
+    let css = plugin("plain", &[]);
+    assert!(css.starts_with("@plugin \"daisyui/theme\" {\n"));
+    assert!(css.ends_with("\n}"));
+    let declared = declarations(&css);
+    let names: Vec<&str> = declared.iter().map(|(k, _)| k.as_str()).collect();
+    let mut expected = vec!["name", "default", "prefersdark", "color-scheme"];
+    let colors: Vec<String> = COLORS.iter().map(|c| format!("--color-{}", c)).collect();
+    let shape: Vec<String> = SHAPE.iter().map(|s| format!("--{}", s)).collect();
+    expected.extend(colors.iter().map(String::as_str));
+    expected.extend(shape.iter().map(String::as_str));
+    assert_eq!(names, expected);
+    assert_eq!(declared[0].1, "\"plain\"");
+    assert_eq!(declared[3].1, "light");
+    assert_eq!(COLORS.len() + SHAPE.len(), variables().len());
+}
+
+#[test]
+fn a_dark_base_switches_to_the_dark_palette() {
+    let overrides = [
+        parse_override("base-100=#000").unwrap(),
+        parse_override(" primary = #33ff33 ").unwrap(),
+        parse_override("radius-box=0rem").unwrap(),
+    ];
+    let declared = declarations(&plugin("crt", &overrides));
+    let get = |key: &str| declared.iter().find(|(k, _)| k == key).unwrap().1.as_str();
+    assert_eq!(get("color-scheme"), "dark");
+    assert_eq!(get("--color-base-100"), "#000");
+    assert_eq!(get("--color-base-200"), "#191e24");
+    assert_eq!(get("--color-primary"), "#33ff33");
+    assert_eq!(get("--radius-box"), "0rem");
+    assert_eq!(get("--radius-field"), "0.25rem");
+
+    assert_eq!(is_dark("#1d232a"), Some(true));
+    assert_eq!(is_dark("#ffffff99"), Some(false));
+    assert_eq!(is_dark("#e0e5ec"), Some(false));
+    assert_eq!(is_dark("oklch(20% 0 0)"), None);
+}
+
+#[test]
+fn overrides_and_names_are_checked() {
+    assert_eq!(
+        parse_override("primary"),
+        Err("theme entry `primary` must be `variable=value`".to_string())
+    );
+    assert!(
+        parse_override("base=#fff")
+            .unwrap_err()
+            .starts_with("unknown theme variable `base` (variables: base-100, ")
+    );
+    assert_eq!(
+        parse_override("primary=red; }"),
+        Err("theme value for `primary` must be a CSS value".to_string())
+    );
+    assert!(check_name("acme-dark2").is_ok());
+    assert_eq!(
+        check_name("Acme Dark"),
+        Err("Theme names may only contain a-z, 0-9 and `-`, got 'Acme Dark'".to_string())
+    );
+    assert!(check_name("").is_err());
+}
+
+#[test]
+fn known_themes_are_built_in_or_generated() {
+    assert!(check_known("dracula", &[]).is_ok());
+    assert!(check_known("silk", &[]).is_ok());
+    let err = check_known("draculla", &[]).unwrap_err();
+    assert!(err.starts_with("Unknown theme 'draculla'. Built-in themes: light, dark,"));
+    assert!(!err.contains("Generated"));
+    let custom = vec!["brand".to_string()];
+    assert!(check_known("brand", &custom).is_ok());
+    let err = check_known("brnad", &custom).unwrap_err();
+    assert!(err.ends_with(". Generated themes: brand"), "{}", err);
+}
+
+#[test]
+fn scope_themes_one_root_or_wraps_several() {
+    assert_eq!(
+        scope("\n<div class=\"hero\"><div></div></div>", "dim"),
+        "\n<div data-theme=\"dim\" class=\"hero\"><div></div></div>"
+    );
+    // Leading comments aren't roots.
+    assert_eq!(
+        scope("<!-- responsive: x -->\n<main></main>", "dim"),
+        "<!-- responsive: x -->\n<main data-theme=\"dim\"></main>"
+    );
+    // Applying twice replaces the theme instead of nesting or repeating it.
+    let once = scope("<div class=\"hero\"></div>", "dim");
+    assert_eq!(
+        scope(&once, "nord"),
+        "<div data-theme=\"nord\" class=\"hero\"></div>"
+    );
+    let wrapped = scope("<div></div>\n<dialog></dialog>\n", "dim");
+    assert_eq!(
+        wrapped,
+        "<div data-theme=\"dim\">\n<div></div>\n<dialog></dialog>\n</div>"
+    );
+    assert_eq!(scope(&wrapped, "nord").matches("data-theme").count(), 1);
+    assert_eq!(scope("<div></div>", "!!"), "<div></div>");
+}
@@ -0,0 +1,100 @@
+//! Pretty and minified whitespace for generated markup.
+
+#[path = "../../src/html.rs"]
+#[allow(dead_code)] // tidy depends on it
+mod html;
+#[path = "../../src/tidy.rs"]
+mod tidy;
+
+use tidy::{Style, tidy};
+
+const BLOB: &str = r#"<div class="hero"><div class="hero-content"><h1 class="text-5xl">Acme</h1><p>Fast <b>and</b> <i>simple</i>.</p><button class="btn">Go</button></div></div>"#;
+
+#[test]
+fn pretty_puts_blocks_on_their_own_lines() {
+    assert_eq!(
+        tidy(BLOB, Style::Pretty),
+        r#"<div class="hero">
+  <div class="hero-content">
+    <h1 class="text-5xl">Acme</h1>
+    <p>Fast <b>and</b> <i>simple</i>.</p>
+    <button class="btn">Go</button>
+  </div>
+</div>"#
+    );
+    // A lone block child still gets its own line.
+    assert_eq!(
+        tidy("<label><div>A</div></label>", Style::Pretty),
+        "<label>\n  <div>A</div>\n</label>"
+    );
+}
+
+#[test]
+fn pretty_breaks_text_only_where_the_source_did() {
+    let html = "<ul>\n      <li>\n  One</li><li>Two <a href=\"#\">three</a>\n four</li>\n<!-- end --></ul>";
+    assert_eq!(
+        tidy(html, Style::Pretty),
+        "<ul>\n  <li>One</li>\n  <li>\n    Two <a href=\"#\">three</a>\n    four\n  </li>\n  <!-- end -->\n</ul>"
+    );
+    // Inline elements keep their padding.
+    assert_eq!(
+        tidy("<p>a<span> b </span>c</p>", Style::Pretty),
+        "<p>a<span> b </span>c</p>"
+    );
+}
+
+#[test]
+fn raw_elements_keep_their_whitespace() {
+    let html = "<div><pre>  a\n    b</pre><textarea>\n x  y</textarea>\n<script>\nif (a)  {\n  b();\n}\n</script></div>";
+    for style in [Style::Pretty, Style::Minified] {
+        let out = tidy(html, style);
+        for raw in [
+            "<pre>  a\n    b</pre>",
+            "<textarea>\n x  y</textarea>",
+            "<script>\nif (a)  {\n  b();\n}\n</script>",
+        ] {
+            assert!(out.contains(raw), "{:?}: {}", style, out);
+        }
+    }
+}
+
+#[test]
+fn minified_collapses_whitespace_between_tags() {
+    let html = "<nav>\n  <!-- links -->\n  <ul>\n    <li><a>Home</a></li>\n    <li><a>About</a>\n    </li>\n  </ul>\n  <p>\n    Made  with <b>care</b>\n    <i>here</i> .\n  </p>\n</nav>\n";
+    assert_eq!(
+        tidy(html, Style::Minified),
+        "<nav><!-- links --><ul><li><a>Home</a></li><li><a>About</a></li></ul><p>Made with <b>care</b> <i>here</i> .</p></nav>"
+    );
+}
+
+#[test]
+fn styles_agree_and_settle() {
+    let page = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<title>Acme</title>\n</head>\n<body>\n{}\n</body>\n</html>\n",
+        BLOB
+    );
+    for html in [BLOB, page.as_str()] {
+        let pretty = tidy(html, Style::Pretty);
+        assert_eq!(tidy(&pretty, Style::Pretty), pretty);
+        assert_eq!(tidy(&pretty, Style::Minified), tidy(html, Style::Minified));
+        let squeeze = |s: &str| s.split_whitespace().collect::<String>();
+        assert_eq!(squeeze(&pretty), squeeze(html));
+    }
+
+    // A page's body isn't indented, so it holds the fragment as is.
+    let pretty = tidy(&page, Style::Pretty);
+    assert!(pretty.contains(&format!("<body>\n{}\n</body>", tidy(BLOB, Style::Pretty))));
+    assert!(pretty.starts_with(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n  <title>Acme</title>\n</head>"
+    ));
+    assert!(pretty.ends_with("</html>\n"));
+}
+
+#[test]
+fn styles_parse_by_name() {
+    assert_eq!(Style::parse("Minified"), Some(Style::Minified));
+    assert_eq!(Style::parse(" pretty "), Some(Style::Pretty));
+    assert_eq!(Style::parse("compact"), None);
+    assert!(Style::NAMES.iter().all(|n| Style::parse(n).is_some()));
+    assert_eq!(Style::default(), Style::Pretty);
+}
@@ -0,0 +1,77 @@
+//! Slow-command footers from the extension's timing module, driven by an
+//! injected clock so no test depends on how fast the machine is.
+
+#[path = "../../src/timing.rs"]
+mod timing;
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+use timing::{Clock, SLOW, SLOW_WORK, SystemClock, Timer};
+
+/// Manually advanced clock; `None` simulates a host without one.
+#[derive(Clone)]
+struct FakeClock(Rc<Cell<Option<Duration>>>);
+
+impl FakeClock {
+    fn at(ms: Option<u64>) -> Self {
+        Self(Rc::new(Cell::new(ms.map(Duration::from_millis))))
+    }
+
+    fn advance(&self, ms: u64) {
+        self.0
+            .set(self.0.get().map(|t| t + Duration::from_millis(ms)));
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> Option<Duration> {
+        self.0.get()
+    }
+}
+
+#[test]
+fn quick_commands_get_no_footer() {
+    let clock = FakeClock::at(Some(1_000));
+    let timer = Timer::start(clock.clone());
+    clock.advance(SLOW.as_millis() as u64 - 1);
+    assert_eq!(timer.footer("narrow it"), None);
+}
+
+#[test]
+fn slow_commands_report_elapsed_time_and_hint() {
+    let clock = FakeClock::at(Some(1_000));
+    let timer = Timer::start(clock.clone());
+    clock.advance(412);
+    assert_eq!(
+        timer.footer("consider narrowing the query").as_deref(),
+        Some("took 412ms — consider narrowing the query")
+    );
+}
+
+#[test]
+fn without_a_clock_work_units_decide() {
+    let mut timer = Timer::start(FakeClock::at(None));
+    timer.work(SLOW_WORK - 1);
+    assert_eq!(timer.footer("limit files"), None);
+    timer.work(1);
+    assert_eq!(
+        timer.footer("limit files"),
+        Some(format!("processed {} items — limit files", SLOW_WORK))
+    );
+}
+
+#[test]
+fn work_units_are_ignored_when_a_clock_exists() {
+    let mut timer = Timer::start(FakeClock::at(Some(0)));
+    timer.work(SLOW_WORK * 10);
+    assert_eq!(timer.footer("hint"), None);
+}
+
+#[test]
+fn system_clock_is_monotonic() {
+    let clock = SystemClock::default();
+    let first = clock.now().expect("system clock unavailable");
+    assert!(clock.now().expect("system clock unavailable") >= first);
+    assert_eq!(Timer::start(clock).footer("hint"), None);
+}
@@ -0,0 +1,199 @@
+//! Quick accessibility hints for snippets.
+//!
+//! Three checks at the level of tags and attributes, not a full audit:
+//! form fields need a label, buttons need a name, images need `alt`. A name
+//! can come from text, `aria-label`, `aria-labelledby` or `title`, so icon
+//! buttons labelled with ARIA pass.
+
+use crate::html::{self, Element};
+
+/// One problem [`audit`] found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    /// `label`, `button-name` or `img-alt`.
+    pub rule: &'static str,
+    /// The opening tag at fault, as written.
+    pub tag: String,
+    pub message: String,
+}
+
+/// `(name, value)` for each attribute of an opening tag; names are
+/// lowercased and bare attributes have an empty value.
+fn attributes(tag: &str) -> Vec<(String, String)> {
+    let inner = tag.trim_start_matches('<').trim_end_matches('>');
+    let inner = inner.trim_end_matches('/');
+    // Skip the tag name.
+    let mut rest = inner.trim_start_matches(|c: char| !c.is_whitespace());
+    let mut out = Vec::new();
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+        let name_len = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let name = rest[..name_len].to_ascii_lowercase();
+        rest = rest[name_len..].trim_start();
+        let Some(after) = rest.strip_prefix('=') else {
+            out.push((name, String::new()));
+            continue;
+        };
+        let after = after.trim_start();
+        let (value, remainder) = match after.chars().next() {
+            Some(q @ ('"' | '\'')) => {
+                let body = &after[1..];
+                let end = body.find(q).unwrap_or(body.len());
+                (&body[..end], body.get(end + 1..).unwrap_or(""))
+            }
+            _ => {
+                let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                (&after[..end], &after[end..])
+            }
+        };
+        out.push((name, value.to_string()));
+        rest = remainder;
+    }
+    out
+}
+
+fn attribute<'a>(attributes: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    attributes
+        .iter()
+        .find(|(n, _)| n == name)
+        .map(|(_, v)| v.as_str())
+}
+
+/// The opening tag of `element`, up to its first `>` outside quotes.
+fn opening_tag<'a>(html: &'a str, element: &Element) -> &'a str {
+    let span = &html[element.start..element.end];
+    let mut quote = None;
+    for (i, c) in span.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '>') => return &span[..=i],
+            _ => {}
+        }
+    }
+    span
+}
+
+/// Whether `element` has an accessible name of its own.
+fn named(attributes: &[(String, String)]) -> bool {
+    ["aria-label", "aria-labelledby", "title"]
+        .iter()
+        .any(|name| attribute(attributes, name).is_some_and(|v| !v.trim().is_empty()))
+}
+
+/// Text inside `element`, counting the `alt` of images it contains.
+fn inner_text(html: &str, elements: &[Element], index: usize) -> String {
+    let element = &elements[index];
+    let body = &html[element.start + opening_tag(html, element).len()..element.end];
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in body.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    for inner in &elements[index + 1..] {
+        if inner.start >= element.end {
+            break;
+        }
+        if inner.tag == "img" {
+            let attrs = attributes(opening_tag(html, inner));
+            text.push_str(attribute(&attrs, "alt").unwrap_or_default());
+        }
+    }
+    text
+}
+
+/// Whether an ancestor of `elements[index]` is a `tag`.
+fn inside(elements: &[Element], index: usize, tag: &str) -> bool {
+    let mut parent = elements[index].parent;
+    while let Some(p) = parent {
+        if elements[p].tag == tag {
+            return true;
+        }
+        parent = elements[p].parent;
+    }
+    false
+}
+
+/// Unlabelled form fields, nameless buttons and images without `alt`, in
+/// document order.
+pub fn audit(html: &str) -> Vec<Finding> {
+    let elements = html::elements(html);
+    let tags: Vec<&str> = elements.iter().map(|e| opening_tag(html, e)).collect();
+    let attrs: Vec<Vec<(String, String)>> = tags.iter().map(|t| attributes(t)).collect();
+    let labelled: Vec<&str> = elements
+        .iter()
+        .zip(&attrs)
+        .filter(|(e, _)| e.tag == "label")
+        .filter_map(|(_, a)| attribute(a, "for"))
+        .collect();
+    let mut findings = Vec::new();
+    for (i, element) in elements.iter().enumerate() {
+        let attributes = &attrs[i];
+        let kind = attribute(attributes, "type")
+            .unwrap_or("text")
+            .to_ascii_lowercase();
+        let finding = |rule, message: String| Finding {
+            rule,
+            tag: tags[i].to_string(),
+            message,
+        };
+        let button = element.tag == "button"
+            || attribute(attributes, "role") == Some("button")
+            || (element.tag == "input" && ["button", "submit", "reset"].contains(&kind.as_str()));
+        if button {
+            let text = if element.tag == "input" {
+                attribute(attributes, "value")
+                    .unwrap_or_default()
+                    .to_string()
+            } else {
+                inner_text(html, &elements, i)
+            };
+            // A submit or reset input without a value still reads its type.
+            let default_label = element.tag == "input" && kind != "button";
+            if text.trim().is_empty() && !named(attributes) && !default_label {
+                findings.push(finding(
+                    "button-name",
+                    "button has no text; add some, or `aria-label` for an icon button".into(),
+                ));
+            }
+            continue;
+        }
+        let field = matches!(element.tag.as_str(), "select" | "textarea")
+            || (element.tag == "input" && !["hidden", "image"].contains(&kind.as_str()));
+        if field {
+            let by_for = attribute(attributes, "id").is_some_and(|id| labelled.contains(&id));
+            if !by_for && !named(attributes) && !inside(&elements, i, "label") {
+                findings.push(finding(
+                    "label",
+                    format!(
+                        "{} has no label; wrap it in a `<label>`, point a `<label for>` at its `id` or add `aria-label`",
+                        element.tag
+                    ),
+                ));
+            }
+            continue;
+        }
+        let image = element.tag == "img" || (element.tag == "input" && kind == "image");
+        if image
+            && attribute(attributes, "alt").is_none()
+            && !named(attributes)
+            && !matches!(attribute(attributes, "role"), Some("presentation" | "none"))
+        {
+            findings.push(finding(
+                "img-alt",
+                "image has no `alt`; describe it, or use `alt=\"\"` if it's decorative".into(),
+            ));
+        }
+    }
+    findings
+}
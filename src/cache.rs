@@ -0,0 +1,227 @@
+//! The parsed daisyUI docs with everything derived from them: the search
+//! index, class owners, examples, summaries, modifiers, subsections and
+//! cross-references. Shared by the Zed extension and the MCP server, which
+//! each load one from the bundled llms.txt (and the extension one per
+//! project override).
+
+use crate::{docs, index};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct DocsCache {
+    pub components: HashMap<String, String>,
+    index: index::Index,
+    /// daisyUI class → owning component, e.g. `join-item` → `join`.
+    class_index: HashMap<String, String>,
+    /// Fenced code blocks per component, captioned, in doc order.
+    examples: HashMap<String, Vec<docs::Example>>,
+    /// Main class and one-line summary per component, for the cheatsheet.
+    pub summaries: HashMap<String, docs::Summary>,
+    /// Modifier classes per component, e.g. `btn-sm` and `btn-outline`.
+    modifiers: HashMap<String, Vec<String>>,
+    /// `(heading, block)` per component for `component#anchor` lookups.
+    sections: HashMap<String, Vec<(String, String)>>,
+    /// Other components each component's doc mentions.
+    references: HashMap<String, Vec<String>>,
+    /// Alias key → component, from [`docs::ALIASES`] and any `aliases:`
+    /// block in the docs, which wins.
+    aliases: HashMap<String, String>,
+    /// Set when llms.txt yielded too few components to be usable.
+    pub diagnostic: Option<String>,
+    /// Project file the docs were read from; `None` for the bundled copy.
+    #[allow(dead_code)] // only the extension reads project overrides
+    pub origin: Option<&'static str>,
+}
+
+impl DocsCache {
+    /// Parses and indexes an llms.txt-style `source`.
+    pub fn load(source: &str) -> Self {
+        let parsed = docs::parse(source);
+        let diagnostic = parsed.diagnostic();
+        let components: HashMap<String, String> = parsed
+            .sections
+            .into_iter()
+            .map(|(name, content)| (name.to_lowercase(), content))
+            .collect();
+        let aliases: HashMap<String, String> = docs::ALIASES
+            .iter()
+            .map(|(alias, target)| (alias.to_string(), target.to_string()))
+            .chain(docs::parse_aliases(source))
+            .map(|(alias, target)| (index::alias_key(&alias), target))
+            .filter(|(alias, target)| {
+                components.contains_key(target) && !components.contains_key(alias)
+            })
+            .collect();
+        let index = index::Index::build(components.iter().map(|(k, v)| (k.as_str(), v.as_str())))
+            .with_aliases(aliases.clone());
+        let class_index = docs::class_index(&components);
+        let examples = components
+            .iter()
+            .map(|(name, doc)| (name.clone(), docs::extract_examples(doc)))
+            .collect();
+        let summaries = components
+            .iter()
+            .map(|(name, doc)| (name.clone(), docs::summarize(name, doc)))
+            .collect();
+        let modifiers = components
+            .iter()
+            .map(|(name, doc)| (name.clone(), docs::modifiers(name, doc)))
+            .collect();
+        let sections = components
+            .iter()
+            .map(|(name, doc)| (name.clone(), docs::subsections(doc)))
+            .collect();
+        let references = docs::references(&components);
+        DocsCache {
+            components,
+            index,
+            class_index,
+            examples,
+            summaries,
+            modifiers,
+            sections,
+            references,
+            aliases,
+            diagnostic,
+            origin: None,
+        }
+    }
+
+    pub fn list_components(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self.components.keys().cloned().collect();
+        keys.sort();
+        keys
+    }
+
+    /// [`Self::get_doc`] that also takes aliases like "dialog" and falls back
+    /// to fuzzy matching for misspelled names: `(name, doc, note)`, where the
+    /// note says which component is shown.
+    pub fn find_doc(&self, name: &str) -> Result<(String, String, Option<String>), String> {
+        let (found, note) = match self.aliases.get(&index::alias_key(name)) {
+            Some(target) => (
+                target.clone(),
+                Some(docs::alias_note(&index::alias_key(name), target)),
+            ),
+            None => {
+                let names = self.list_components();
+                docs::lookup(name, names.iter().map(String::as_str)).resolve(name)?
+            }
+        };
+        let doc = self
+            .get_doc(&found)
+            .ok_or_else(|| format!("Documentation not found for '{}'", name))?;
+        Ok((found, doc, note))
+    }
+
+    /// [`Self::find_doc`], or with `component#anchor` just the matching
+    /// subsection, named `component › heading`.
+    pub fn find_section(&self, spec: &str) -> Result<(String, String, Option<String>), String> {
+        let Some((name, anchor)) = spec.split_once('#') else {
+            return self.find_doc(spec);
+        };
+        let (found, _, note) = self.find_doc(name)?;
+        let sections = self.sections.get(&found).map_or(&[][..], Vec::as_slice);
+        let (heading, block) = docs::pick_section(&found, sections, anchor)?;
+        Ok((format!("{} › {}", found, heading), block.to_string(), note))
+    }
+
+    /// A component's code examples; empty for unknown names.
+    pub fn get_examples(&self, name: &str) -> &[docs::Example] {
+        self.examples
+            .get(&name.to_lowercase())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    pub fn get_modifiers(&self, name: &str) -> &[String] {
+        self.modifiers
+            .get(&name.to_lowercase())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// The component a daisyUI class belongs to, or why none matched.
+    pub fn get_component_for_class(&self, class: &str) -> Result<String, String> {
+        docs::owner(class, &self.class_index).map(str::to_string)
+    }
+
+    /// `class`, the component it belongs to and that component's doc, all
+    /// from these docs so a project's override explains its own classes:
+    /// `(component, text)`.
+    pub fn describe_class(
+        &self,
+        class: &str,
+        mode: docs::Sanitize,
+    ) -> Result<(String, String), String> {
+        let component = self.get_component_for_class(class)?;
+        let doc = self
+            .get_doc(&component)
+            .ok_or_else(|| format!("Documentation not found for '{}'", component))?;
+        let text = format!(
+            "`{}` belongs to **{}**.\n\n{}",
+            class.trim().trim_start_matches('.'),
+            component,
+            docs::sanitize_markdown(&doc, mode)
+        );
+        Ok((component, text))
+    }
+
+    /// A component's doc, ending with a "See also" line naming the other
+    /// components it mentions.
+    pub fn get_doc(&self, name: &str) -> Option<String> {
+        if name.is_empty() {
+            return None;
+        }
+        let name = name.to_lowercase();
+        let doc = self.components.get(&name)?;
+        match self.references.get(&name) {
+            Some(others) if !others.is_empty() => {
+                Some(format!("{}\n\n**See also:** {}", doc, others.join(", ")))
+            }
+            _ => Some(doc.clone()),
+        }
+    }
+
+    /// Components whose docs mention `name`, in name order.
+    pub fn references_to(&self, name: &str) -> Vec<String> {
+        let name = name.to_lowercase();
+        let mut found: Vec<String> = self
+            .references
+            .iter()
+            .filter(|(_, others)| others.contains(&name))
+            .map(|(owner, _)| owner.clone())
+            .collect();
+        found.sort();
+        found
+    }
+
+    /// One page of `(name, snippet, score)` matches, best first, plus the
+    /// total count.
+    pub fn search(
+        &self,
+        query: &str,
+        limit: usize,
+        offset: usize,
+    ) -> (Vec<(String, docs::Snippet, usize)>, usize) {
+        let query = index::Query::parse(query);
+        let (page, total) = self.index.page(&query, &self.components, limit, offset);
+        let highlight = query.highlight();
+        let results = page
+            .into_iter()
+            .filter_map(|(k, score)| {
+                let snippet = docs::snippet(self.components.get(&k)?, &highlight);
+                Some((k, snippet, score))
+            })
+            .collect();
+        (results, total)
+    }
+
+    /// Which components a search query names by alias, e.g. "Showing
+    /// 'modal' (alias of 'dialog')".
+    pub fn alias_note(&self, query: &str) -> Option<String> {
+        let aliased = self.index.aliased(&index::Query::parse(query));
+        let notes: Vec<String> = aliased
+            .iter()
+            .map(|(alias, component)| docs::alias_note(alias, component))
+            .collect();
+        (!notes.is_empty()).then(|| notes.join("; "))
+    }
+}
@@ -0,0 +1,132 @@
+//! Splits oversized slash command output into parts that each fit a size
+//! limit, so a large generation can be read with `--part N`.
+//!
+//! Cuts fall at line ends where possible and always on a char boundary. A
+//! part that ends inside a code fence closes it, and the next part reopens
+//! it with the same opening line, so every part renders on its own. Bodies
+//! are exact slices of the input: concatenating them gives the original.
+
+/// Smallest limit honored; below this a fence line and its close don't fit.
+const MIN_LIMIT: usize = 32;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chunk<'a> {
+    /// This part's slice of the original text.
+    pub body: &'a str,
+    /// Fence opening line carried over from the previous part.
+    reopen: Option<&'a str>,
+    /// Fence marker that closes a fence left open at the end of the part.
+    close: Option<&'a str>,
+}
+
+impl Chunk<'_> {
+    /// The part as shown: reopened fence, body, closing fence.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        if let Some(open) = self.reopen {
+            out.push_str(open);
+            out.push('\n');
+        }
+        out.push_str(self.body);
+        if let Some(marker) = self.close {
+            if !out.ends_with('\n') {
+                out.push('\n');
+            }
+            out.push_str(marker);
+        }
+        out
+    }
+}
+
+/// Leading run of backticks or tildes, e.g. "```" for "```html".
+fn marker(line: &str) -> &str {
+    let line = line.trim_start();
+    let len = line.find(|c| c != '`' && c != '~').unwrap_or(line.len());
+    &line[..len]
+}
+
+/// Fence open after `line`, given the fence open before it.
+fn fence_after<'a>(open: Option<&'a str>, line: &'a str) -> Option<&'a str> {
+    let trimmed = line.trim();
+    match open {
+        None if trimmed.starts_with("```") || trimmed.starts_with("~~~") => {
+            Some(line.trim_end_matches(['\n', '\r']))
+        }
+        None => None,
+        Some(open) => {
+            let closes = trimmed.starts_with(marker(open))
+                && trimmed.trim_matches(|c| c == '`' || c == '~').is_empty();
+            if closes { None } else { Some(open) }
+        }
+    }
+}
+
+/// Bytes a part spends reopening and closing `open`.
+fn overhead(reopen: Option<&str>, close: Option<&str>) -> usize {
+    reopen.map_or(0, |l| l.len() + 1) + close.map_or(0, |l| marker(l).len() + 1)
+}
+
+/// Splits `text` into parts whose rendered size is at most `limit` bytes.
+/// Text that fits comes back as a single part.
+pub fn split(text: &str, limit: usize) -> Vec<Chunk<'_>> {
+    let limit = limit.max(MIN_LIMIT);
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut end = 0;
+    // Fence open at `start` and at `end`.
+    let mut reopen: Option<&str> = None;
+    let mut fence: Option<&str> = None;
+
+    for mut line in text.split_inclusive('\n') {
+        loop {
+            let after = fence_after(fence, line);
+            if overhead(reopen, after) + (end - start) + line.len() <= limit {
+                end += line.len();
+                fence = after;
+                break;
+            }
+            if end > start {
+                chunks.push(Chunk {
+                    body: &text[start..end],
+                    reopen,
+                    close: fence.map(marker),
+                });
+                start = end;
+                reopen = fence;
+                continue;
+            }
+            // The line alone is too long: take as much as fits.
+            let room = limit.saturating_sub(overhead(reopen, fence)).max(1);
+            let mut at = room.min(line.len());
+            while !line.is_char_boundary(at) {
+                at -= 1;
+            }
+            if at == 0 {
+                at = line.chars().next().map_or(line.len(), char::len_utf8);
+            }
+            if at == line.len() {
+                fence = fence_after(fence, line);
+            }
+            end += at;
+            line = &line[at..];
+            chunks.push(Chunk {
+                body: &text[start..end],
+                reopen,
+                close: fence.map(marker),
+            });
+            start = end;
+            reopen = fence;
+            if line.is_empty() {
+                break;
+            }
+        }
+    }
+    if end > start || chunks.is_empty() {
+        chunks.push(Chunk {
+            body: &text[start..end],
+            reopen,
+            close: None,
+        });
+    }
+    chunks
+}
@@ -0,0 +1,34 @@
+//! Argument completion for slash commands that take a component name.
+//!
+//! Zed passes the arguments typed so far; the candidates narrow as you type
+//! instead of always offering the first names alphabetically.
+
+/// `names` that start with `typed`, ignoring case, in their original order.
+/// When none do, names containing it instead. At most `limit` are returned.
+pub fn matching<'a>(typed: &str, names: &'a [String], limit: usize) -> Vec<&'a str> {
+    let typed = typed.trim().to_lowercase();
+    let lower: Vec<String> = names.iter().map(|n| n.to_lowercase()).collect();
+    let mut found: Vec<&str> = names
+        .iter()
+        .zip(&lower)
+        .filter(|(_, l)| l.starts_with(&typed))
+        .map(|(n, _)| n.as_str())
+        .collect();
+    if found.is_empty() {
+        found = names
+            .iter()
+            .zip(&lower)
+            .filter(|(_, l)| l.contains(&typed))
+            .map(|(n, _)| n.as_str())
+            .collect();
+    }
+    found.truncate(limit);
+    found
+}
+
+/// Whether choosing `name` should run the command straight away. Only when
+/// the typed text already names it; a partial match is filled in first so
+/// it can be confirmed.
+pub fn runs(typed: &str, name: &str) -> bool {
+    typed.trim().eq_ignore_ascii_case(name)
+}
@@ -0,0 +1,974 @@
+//! Design concepts read from `concepts.toml`, shared by the Zed extension
+//! and the MCP server so both describe a concept the same way.
+//!
+//! The file uses a small TOML subset, parsed here so the extension needs no
+//! extra dependencies:
+//!
+//! ```text
+//! [glassmorphism]
+//! name = "Glassmorphism"
+//! description = "Frosted glass aesthetic"
+//! classes = ["glass", "backdrop-blur"]
+//! suggestion = "Apply glass class to cards"
+//! snippet = '''<div class="card glass">Content</div>'''
+//!
+//! [[glassmorphism.examples]]
+//! title = "Navbar"
+//! code = '''<div class="navbar glass">Brand</div>'''
+//! ```
+//!
+//! A lone `snippet` is the example titled "Example"; each
+//! `[[key.examples]]` table after a concept adds another, and the first
+//! example is the default.
+//!
+//! Strings are `"basic"` (with `\"`, `\\`, `\n` and `\t` escapes) or
+//! `'''literal'''`, which may span lines. Every rejected concept is reported
+//! in [`Catalog::errors`] under its name, with the line at fault.
+
+use crate::a11y;
+use crate::docs::mix;
+use crate::frameworks::{self, Target};
+use crate::index::{Index, Query};
+use crate::theme;
+use std::collections::HashMap;
+
+/// The bundled concepts.
+pub const SOURCE: &str = include_str!("concepts.toml");
+
+const FIELDS: &[&str] = &[
+    "name",
+    "description",
+    "classes",
+    "suggestion",
+    "snippet",
+    "script",
+    "category",
+    "targets",
+    "inject",
+    "theme",
+];
+
+/// A project's own concepts, relative to its worktree root.
+pub const PROJECT_FILE: &str = ".daisy/concepts.toml";
+
+/// Categories a style recipe takes one concept from, in recipe order.
+pub const RECIPE_CATEGORIES: &[&str] = &["visual-style", "layout", "motion"];
+
+/// Concepts a style recipe never combines, and why.
+pub const INCOMPATIBLE: &[(&str, &str, &str)] = &[
+    (
+        "darkmode",
+        "neumorphism",
+        "soft extruded shadows need a light surface",
+    ),
+    (
+        "darkmode",
+        "claymorphism",
+        "pastel clay surfaces only work light",
+    ),
+    (
+        "retro-terminal",
+        "bento",
+        "a terminal is one monospace column, not a grid of tiles",
+    ),
+];
+
+/// Why `a` and `b` don't combine, in either order.
+fn incompatible(a: &str, b: &str) -> Option<&'static str> {
+    INCOMPATIBLE
+        .iter()
+        .find(|(x, y, _)| (*x == a && *y == b) || (*x == b && *y == a))
+        .map(|(_, _, reason)| *reason)
+}
+
+/// The concept whose snippet shows how to fix each [`a11y::audit`] rule.
+const A11Y_FIXES: &[(&str, &str)] = &[
+    ("label", "accessible-forms"),
+    ("button-name", "keyboard-navigation"),
+];
+
+/// A concept and its key.
+pub type Keyed<'a> = (&'a str, &'a DesignConcept);
+
+/// Values of a concept's `category`, in listing order.
+pub const CATEGORIES: &[&str] = &["visual-style", "layout", "motion", "accessibility", "state"];
+
+/// daisyUI components a concept styles unless it lists its own `targets`.
+pub const DEFAULT_TARGETS: &[&str] = &["card", "navbar", "modal"];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DesignConcept {
+    pub name: String,
+    pub description: String,
+    pub classes: Vec<String>,
+    pub suggestion: String,
+    /// `(title, code)` pairs; the first is the default.
+    pub examples: Vec<(String, String)>,
+    /// Vanilla JS the examples need, if any.
+    pub script: Option<String>,
+    /// One of [`CATEGORIES`].
+    pub category: String,
+    /// Components [`ConceptEngine::apply`] styles; empty for the defaults.
+    pub targets: Vec<String>,
+    /// Classes [`ConceptEngine::apply`] adds; `None` for `classes`.
+    pub inject: Option<Vec<String>>,
+    /// Theme variables [`DesignConcept::to_theme`] sets, as `(variable, value)`.
+    pub theme: Vec<(String, String)>,
+}
+
+impl DesignConcept {
+    /// Markdown shown by `/daisy-concept` and `daisyui_get_concept`, with
+    /// every example as `target` markup and any script after them.
+    pub fn to_display(&self, target: Target) -> String {
+        self.display(target, 0, &self.examples)
+    }
+
+    /// [`DesignConcept::to_display`] with only the example at `index`.
+    pub fn to_display_example(&self, target: Target, index: usize) -> String {
+        self.display(target, index, &self.examples[index..=index])
+    }
+
+    /// The display text with `examples`, which start at index `first`.
+    fn display(&self, target: Target, first: usize, examples: &[(String, String)]) -> String {
+        let mut text = format!(
+            "## {}\n\n**Description:** {}\n\n**Classes:** {}\n\n**Suggestion:** {}",
+            self.name,
+            self.description,
+            self.classes.join(", "),
+            self.suggestion
+        );
+        for (index, (title, _)) in examples.iter().enumerate() {
+            text.push_str(&format!(
+                "\n\n### {}\n\n```{}\n{}\n```",
+                title,
+                target.name(),
+                self.example_for(first + index, target)
+            ));
+        }
+        if let Some(script) = &self.script {
+            text.push_str(&format!("\n\n```js\n{}\n```", script));
+        }
+        text
+    }
+
+    /// The default example's code.
+    pub fn snippet(&self) -> &str {
+        self.examples.first().map_or("", |(_, code)| code)
+    }
+
+    /// The example at `index` as `target` markup.
+    pub fn example_for(&self, index: usize, target: Target) -> String {
+        frameworks::convert(&self.examples[index].1, target)
+    }
+
+    /// The first example whose title contains `query`, ignoring case.
+    pub fn example(&self, query: &str) -> Option<usize> {
+        let query = query.trim().to_lowercase();
+        self.examples
+            .iter()
+            .position(|(title, _)| title.to_lowercase().contains(&query))
+    }
+
+    /// Example titles, in order.
+    pub fn example_titles(&self) -> Vec<&str> {
+        self.examples.iter().map(|(t, _)| t.as_str()).collect()
+    }
+
+    /// A daisyUI theme named `name` in this concept's colors and shapes.
+    pub fn to_theme(&self, name: &str) -> String {
+        theme::plugin(name, &self.theme)
+    }
+
+    /// The component classes [`ConceptEngine::apply`] looks for.
+    pub fn targets(&self) -> Vec<&str> {
+        if self.targets.is_empty() {
+            DEFAULT_TARGETS.to_vec()
+        } else {
+            self.targets.iter().map(String::as_str).collect()
+        }
+    }
+
+    /// The classes [`ConceptEngine::apply`] adds.
+    pub fn injected(&self) -> Vec<&str> {
+        self.inject
+            .as_ref()
+            .unwrap_or(&self.classes)
+            .iter()
+            .map(String::as_str)
+            .filter(|class| !class.ends_with(':'))
+            .collect()
+    }
+}
+
+/// Concepts that loaded, by key in file order, plus one message per concept
+/// that didn't.
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+    pub concepts: Vec<(String, DesignConcept)>,
+    pub errors: Vec<String>,
+}
+
+/// A `"basic"` string with escapes, and whatever follows it.
+fn basic(value: &str) -> Result<(String, &str), String> {
+    let mut out = String::new();
+    let mut chars = value.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok((out, &value[i + 1..])),
+            '\\' => match chars.next().map(|(_, e)| e) {
+                Some('"') => out.push('"'),
+                Some('\\') => out.push('\\'),
+                Some('n') => out.push('\n'),
+                Some('t') => out.push('\t'),
+                other => {
+                    return Err(format!(
+                        "unknown escape `\\{}`",
+                        other.map(String::from).unwrap_or_default()
+                    ));
+                }
+            },
+            _ => out.push(c),
+        }
+    }
+    Err("unclosed `\"` string".into())
+}
+
+/// `["a", "b"]` on one line.
+fn array(value: &str) -> Result<Vec<String>, String> {
+    let mut rest = value
+        .strip_prefix('[')
+        .and_then(|v| v.strip_suffix(']'))
+        .ok_or("expected a `[\"...\"]` list on one line")?
+        .trim();
+    let mut items = Vec::new();
+    while !rest.is_empty() {
+        let body = rest
+            .strip_prefix('"')
+            .ok_or("list items must be \"quoted\"")?;
+        let (item, after) = basic(body)?;
+        items.push(item);
+        let after = after.trim_start();
+        rest = match after.strip_prefix(',') {
+            Some(more) => more.trim_start(),
+            None if after.is_empty() => after,
+            None => return Err("list items must be separated by `,`".into()),
+        };
+    }
+    Ok(items)
+}
+
+/// Sets `field` on `concept` from a single-line value.
+fn assign(concept: &mut DesignConcept, field: &str, value: &str) -> Result<(), String> {
+    if !FIELDS.contains(&field) {
+        return Err(format!(
+            "unknown field `{}` (fields: {})",
+            field,
+            FIELDS.join(", ")
+        ));
+    }
+    match field {
+        "classes" => concept.classes = array(value)?,
+        "targets" => concept.targets = array(value)?,
+        "inject" => concept.inject = Some(array(value)?),
+        "theme" => {
+            concept.theme = array(value)?
+                .iter()
+                .map(|entry| theme::parse_override(entry))
+                .collect::<Result<_, _>>()?
+        }
+        _ => {}
+    }
+    if ["classes", "targets", "inject", "theme"].contains(&field) {
+        return Ok(());
+    }
+    let text = string(field, value)?;
+    match field {
+        "name" => concept.name = text,
+        "description" => concept.description = text,
+        "suggestion" => concept.suggestion = text,
+        "category" => concept.category = text,
+        "script" => concept.script = Some(text),
+        _ if text.trim().is_empty() => return Err("`snippet` is empty".into()),
+        // Fields come before any `[[key.examples]]`, so this stays first.
+        _ => concept.examples.insert(0, ("Example".into(), text)),
+    }
+    Ok(())
+}
+
+/// Sets `title` or `code` of an example from `[[key.examples]]`.
+fn assign_example(example: &mut (String, String), field: &str, value: &str) -> Result<(), String> {
+    let text = match field {
+        "title" | "code" => string(field, value)?,
+        _ => {
+            return Err(format!(
+                "unknown example field `{}` (fields: title, code)",
+                field
+            ));
+        }
+    };
+    if text.trim().is_empty() {
+        return Err(format!("`{}` is empty", field));
+    }
+    if field == "title" {
+        example.0 = text;
+    } else {
+        example.1 = text;
+    }
+    Ok(())
+}
+
+/// A `"basic"` or `'''literal'''` string value of `field`.
+fn string(field: &str, value: &str) -> Result<String, String> {
+    if let Some(body) = value.strip_prefix('"') {
+        let (text, after) = basic(body)?;
+        if !after.trim().is_empty() && !after.trim_start().starts_with('#') {
+            return Err(format!("unexpected `{}` after the string", after.trim()));
+        }
+        Ok(text)
+    } else if let Some(body) = value
+        .strip_prefix("'''")
+        .and_then(|v| v.strip_suffix("'''"))
+    {
+        Ok(body.to_string())
+    } else {
+        Err(format!("`{}` must be a quoted string", field))
+    }
+}
+
+/// A concept being read: its key, the line of its heading, the first
+/// problem found in it, and whether fields now go to its last example.
+struct Pending {
+    key: String,
+    line: usize,
+    concept: DesignConcept,
+    error: Option<String>,
+    in_example: bool,
+}
+
+/// Parses a concepts file. A concept with any problem is left out and
+/// reported; the rest still load.
+pub fn parse(text: &str) -> Catalog {
+    let mut catalog = Catalog::default();
+    let mut current: Option<Pending> = None;
+    let mut lines = text.lines().enumerate();
+    let finish = |catalog: &mut Catalog, entry: Option<Pending>| {
+        let Some(Pending {
+            key,
+            line,
+            mut concept,
+            error,
+            ..
+        }) = entry
+        else {
+            return;
+        };
+        // Problems with the concept as a whole point at its heading.
+        let error = error.or_else(|| {
+            let problem = if concept.classes.is_empty() {
+                "needs at least one class".to_string()
+            } else if concept.examples.is_empty() {
+                format!("needs a `snippet` or a `[[{}.examples]]` table", key)
+            } else if let Some(n) = concept.examples.iter().position(|(_, c)| c.is_empty()) {
+                format!("example {} has no `code`", n + 1)
+            } else if concept
+                .script
+                .as_deref()
+                .is_some_and(|s| s.trim().is_empty())
+            {
+                "`script` is empty".to_string()
+            } else if !CATEGORIES.contains(&concept.category.as_str()) {
+                format!(
+                    "category '{}' isn't one of {}",
+                    concept.category,
+                    CATEGORIES.join(", ")
+                )
+            } else if catalog.concepts.iter().any(|(k, _)| *k == key) {
+                "is defined more than once".to_string()
+            } else {
+                return None;
+            };
+            Some(format!("line {}: {}", line, problem))
+        });
+        match error {
+            Some(error) => catalog.errors.push(format!("concept '{}': {}", key, error)),
+            None => {
+                if concept.name.is_empty() {
+                    concept.name = key.clone();
+                }
+                for (n, (title, _)) in concept.examples.iter_mut().enumerate() {
+                    if title.is_empty() {
+                        *title = format!("Example {}", n + 1);
+                    }
+                }
+                catalog.concepts.push((key, concept));
+            }
+        }
+    };
+    while let Some((number, line)) = lines.next() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(table) = line.strip_prefix("[[").and_then(|l| l.strip_suffix("]]")) {
+            let table = table.trim().to_lowercase();
+            match current.as_mut() {
+                Some(pending) if table == format!("{}.examples", pending.key) => {
+                    pending.concept.examples.push(Default::default());
+                    pending.in_example = true;
+                }
+                Some(pending) => {
+                    let problem = format!(
+                        "line {}: expected `[[{}.examples]]`, got `[[{}]]`",
+                        number + 1,
+                        pending.key,
+                        table
+                    );
+                    pending.error.get_or_insert(problem);
+                }
+                None => catalog.errors.push(format!(
+                    "line {}: `[[{}]]` must follow its concept's heading",
+                    number + 1,
+                    table
+                )),
+            }
+            continue;
+        }
+        if let Some(key) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            finish(&mut catalog, current.take());
+            let key = key.trim().to_lowercase();
+            let error = (key.is_empty()
+                || !key
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-'))
+            .then(|| {
+                format!(
+                    "line {}: keys may only contain a-z, 0-9 and `-`",
+                    number + 1
+                )
+            });
+            current = Some(Pending {
+                key,
+                line: number + 1,
+                concept: DesignConcept::default(),
+                error,
+                in_example: false,
+            });
+            continue;
+        }
+        let Some(Pending {
+            concept,
+            error,
+            in_example,
+            ..
+        }) = current.as_mut()
+        else {
+            catalog.errors.push(format!(
+                "line {}: expected a `[concept]` heading before `{}`",
+                number + 1,
+                line
+            ));
+            continue;
+        };
+        let Some((field, value)) = line.split_once('=') else {
+            error.get_or_insert(format!(
+                "line {}: expected `field = value`, got `{}`",
+                number + 1,
+                line
+            ));
+            continue;
+        };
+        let (field, mut value) = (field.trim(), value.trim().to_string());
+        // A literal string left open continues on the following lines.
+        if value.starts_with("'''") && (value.len() < 6 || !value.ends_with("'''")) {
+            let mut closed = false;
+            for (_, more) in lines.by_ref() {
+                value.push('\n');
+                value.push_str(more);
+                if more.trim_end().ends_with("'''") {
+                    closed = true;
+                    break;
+                }
+            }
+            if !closed {
+                error.get_or_insert(format!("line {}: unclosed `'''` string", number + 1));
+                continue;
+            }
+            value = value.trim_end().replacen("'''\n", "'''", 1);
+        }
+        let assigned = match concept.examples.last_mut() {
+            Some(example) if *in_example => assign_example(example, field, &value),
+            _ => assign(concept, field, &value),
+        };
+        if let Err(e) = assigned {
+            error.get_or_insert(format!("line {}: {}", number + 1, e));
+        }
+    }
+    finish(&mut catalog, current.take());
+    catalog
+}
+
+/// What a concept is found by: everything but its snippet.
+fn searchable(concept: &DesignConcept) -> String {
+    format!(
+        "{}\n{}\n{}\n{}",
+        concept.name,
+        concept.description,
+        concept.classes.join(" "),
+        concept.suggestion
+    )
+}
+
+/// Concepts by key, as both the extension and the MCP server serve them.
+#[derive(Debug, Clone, Default)]
+pub struct ConceptEngine {
+    concepts: HashMap<String, DesignConcept>,
+    /// Entries of `concepts.toml` that failed validation.
+    pub errors: Vec<String>,
+}
+
+impl ConceptEngine {
+    /// The bundled concepts.
+    pub fn new() -> Self {
+        let catalog = parse(SOURCE);
+        Self {
+            concepts: catalog.concepts.into_iter().collect(),
+            errors: catalog.errors,
+        }
+    }
+
+    /// These concepts plus those in a project's [`PROJECT_FILE`] text, which
+    /// replace any with the same key. Unlike the bundled file, one bad entry
+    /// fails the lot: a typo shouldn't quietly leave the built-in in place.
+    pub fn with_project(&self, text: &str) -> Result<Self, String> {
+        let catalog = parse(text);
+        if !catalog.errors.is_empty() {
+            return Err(format!(
+                "Couldn't load {}:\n- {}",
+                PROJECT_FILE,
+                catalog.errors.join("\n- ")
+            ));
+        }
+        let mut engine = self.clone();
+        engine.concepts.extend(catalog.concepts);
+        Ok(engine)
+    }
+
+    /// Keys in alphabetical order.
+    pub fn list_concepts(&self) -> Vec<String> {
+        let mut v: Vec<String> = self.concepts.keys().cloned().collect();
+        v.sort();
+        v
+    }
+
+    /// Concepts grouped by category in [`CATEGORIES`] order, each group
+    /// sorted by key; empty groups are left out. `filter` keeps one category.
+    pub fn by_category(
+        &self,
+        filter: Option<&str>,
+    ) -> Result<Vec<(&'static str, Vec<Keyed<'_>>)>, String> {
+        if let Some(filter) = filter.filter(|f| !CATEGORIES.contains(f)) {
+            return Err(format!(
+                "Unknown category '{}'. Categories: {}",
+                filter,
+                CATEGORIES.join(", ")
+            ));
+        }
+        let mut keys: Vec<&String> = self.concepts.keys().collect();
+        keys.sort();
+        Ok(CATEGORIES
+            .iter()
+            .filter(|category| filter.is_none_or(|f| f == **category))
+            .map(|category| {
+                let members = keys
+                    .iter()
+                    .filter_map(|key| {
+                        let concept = self.concepts.get(*key)?;
+                        (concept.category == *category).then_some((key.as_str(), concept))
+                    })
+                    .collect::<Vec<_>>();
+                (*category, members)
+            })
+            .filter(|(_, members)| !members.is_empty())
+            .collect())
+    }
+
+    /// Concept keys matching `query`, best first, scored the way component
+    /// search is: key matches, then the words as a run, then TF-IDF mentions.
+    pub fn search(&self, query: &str) -> Vec<(String, usize)> {
+        let texts: HashMap<String, String> = self
+            .concepts
+            .iter()
+            .map(|(key, concept)| (key.clone(), searchable(concept)))
+            .collect();
+        let index = Index::build(texts.iter().map(|(k, t)| (k.as_str(), t.as_str())));
+        index.rank(&Query::parse(query), &texts)
+    }
+
+    /// `html` with the classes of the concept `query` names added to every
+    /// element whose class list has one of its target components, e.g.
+    /// `glass backdrop-blur` on each `card`, `navbar` and `modal`. Only
+    /// double-quoted `class` attributes are touched, and classes an element
+    /// already has aren't repeated.
+    pub fn apply(&self, query: &str, html: &str) -> Result<String, String> {
+        let (key, concept, _) = self.find(query).ok_or_else(|| self.not_found(query))?;
+        let injected = concept.injected();
+        if injected.is_empty() {
+            return Err(format!("Concept '{}' has no classes to apply", key));
+        }
+        let targets = concept.targets();
+        let mut out = String::with_capacity(html.len());
+        let mut rest = html;
+        while let Some(pos) = rest.find("class=\"") {
+            let start = pos + "class=\"".len();
+            out.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let end = rest.find('"').unwrap_or(rest.len());
+            let value = &rest[..end];
+            // `data-class="..."` and the like aren't the class list.
+            let attribute = out[..out.len() - "class=\"".len()]
+                .chars()
+                .next_back()
+                .is_none_or(char::is_whitespace);
+            out.push_str(value);
+            let present: Vec<&str> = value.split_whitespace().collect();
+            if attribute && present.iter().any(|c| targets.contains(c)) {
+                for class in &injected {
+                    if !present.contains(class) {
+                        out.push(' ');
+                        out.push_str(class);
+                    }
+                }
+            }
+            rest = &rest[end..];
+        }
+        out.push_str(rest);
+        Ok(out)
+    }
+
+    /// The concept `query` names and its key: the one with that key, or
+    /// else the best [`Self::search`] match and a note saying which concept
+    /// was picked.
+    pub fn find(&self, query: &str) -> Option<(&str, &DesignConcept, Option<String>)> {
+        let query = query.trim();
+        if query.is_empty() {
+            return None;
+        }
+        if let Some((key, concept)) = self.concepts.get_key_value(&query.to_lowercase()) {
+            return Some((key, concept, None));
+        }
+        let (best, _) = self.search(query).into_iter().next()?;
+        let (key, concept) = self.concepts.get_key_value(&best)?;
+        let note = format!("No concept named '{}'; showing '{}'.", query, key);
+        Some((key, concept, Some(note)))
+    }
+
+    /// The two concepts `first` and `second` name, looked up like
+    /// [`Self::find`]. Both names picking the same concept is an error that
+    /// suggests another from its category.
+    pub fn compare(&self, first: &str, second: &str) -> Result<Comparison<'_>, String> {
+        let find = |query: &str| self.find(query).ok_or_else(|| self.not_found(query));
+        let (first_key, first_concept, first_note) = find(first)?;
+        let (second_key, second_concept, second_note) = find(second)?;
+        if first_key == second_key {
+            let mut others: Vec<Keyed> = self
+                .by_category(Some(&first_concept.category))
+                .unwrap_or_default()
+                .into_iter()
+                .flat_map(|(_, members)| members)
+                .filter(|(key, _)| *key != first_key)
+                .collect();
+            if others.is_empty() {
+                others = self
+                    .concepts
+                    .iter()
+                    .filter(|(key, _)| *key != first_key)
+                    .map(|(key, concept)| (key.as_str(), concept))
+                    .collect();
+                others.sort_by_key(|(key, _)| *key);
+            }
+            let suggestion = others
+                .first()
+                .map(|(key, _)| format!(", e.g. '{}'", key))
+                .unwrap_or_default();
+            let subject = if first.trim().eq_ignore_ascii_case(second.trim()) {
+                format!("Can't compare '{}' with itself", first_key)
+            } else {
+                format!("'{}' and '{}' both pick '{}'", first, second, first_key)
+            };
+            return Err(format!("{}; pick a second concept{}", subject, suggestion));
+        }
+        Ok(Comparison {
+            concepts: [(first_key, first_concept), (second_key, second_concept)],
+            notes: first_note.into_iter().chain(second_note).collect(),
+        })
+    }
+
+    /// One line per accessibility problem [`a11y::audit`] finds in `html`:
+    /// the tag, what's wrong, and the concept showing the fix when this
+    /// engine has it.
+    pub fn audit_hint(&self, html: &str) -> Vec<String> {
+        a11y::audit(html)
+            .into_iter()
+            .map(|finding| {
+                let mut tag: String = finding.tag.chars().take(60).collect();
+                if tag.len() < finding.tag.len() {
+                    tag.push('…');
+                }
+                let mut hint = format!("`{}`: {}", tag, finding.message);
+                let fix = A11Y_FIXES
+                    .iter()
+                    .find(|(rule, key)| *rule == finding.rule && self.concepts.contains_key(*key));
+                if let Some((_, key)) = fix {
+                    hint.push_str(&format!(" (see the `{}` concept)", key));
+                }
+                hint
+            })
+            .collect()
+    }
+
+    /// A style recipe: the `include` concepts plus one concept from each
+    /// [`RECIPE_CATEGORIES`] category they don't cover, chosen by `seed`
+    /// among those compatible with everything chosen so far. A category
+    /// with no concepts is skipped.
+    pub fn recipe(&self, seed: u64, include: &[&str]) -> Result<Recipe<'_>, String> {
+        let mut chosen: Vec<Keyed> = Vec::new();
+        for query in include {
+            let (key, concept, _) = self.find(query).ok_or_else(|| self.not_found(query))?;
+            if chosen.iter().any(|(k, _)| *k == key) {
+                continue;
+            }
+            if let Some((other, reason)) = chosen
+                .iter()
+                .find_map(|(k, _)| incompatible(k, key).map(|reason| (*k, reason)))
+            {
+                return Err(format!(
+                    "'{}' and '{}' don't go together: {}",
+                    other, key, reason
+                ));
+            }
+            chosen.push((key, concept));
+        }
+        for (slot, category) in RECIPE_CATEGORIES.iter().enumerate() {
+            if chosen.iter().any(|(_, c)| c.category == *category) {
+                continue;
+            }
+            let groups = self.by_category(Some(category))?;
+            let candidates: Vec<Keyed> = groups
+                .into_iter()
+                .flat_map(|(_, members)| members)
+                .filter(|(key, _)| chosen.iter().all(|(k, _)| incompatible(k, key).is_none()))
+                .collect();
+            if candidates.is_empty() {
+                continue;
+            }
+            let pick = mix(seed.wrapping_add(slot as u64)) % candidates.len() as u64;
+            chosen.push(candidates[pick as usize]);
+        }
+        if chosen.is_empty() {
+            return Err(format!(
+                "No concepts in {} to make a recipe from",
+                RECIPE_CATEGORIES.join(", ")
+            ));
+        }
+        Ok(Recipe {
+            seed,
+            concepts: chosen,
+        })
+    }
+
+    fn not_found(&self, query: &str) -> String {
+        format!(
+            "Concept '{}' not found. Available: {}",
+            query,
+            self.list_concepts().join(", ")
+        )
+    }
+}
+
+/// Concepts composed into one look by [`ConceptEngine::recipe`].
+#[derive(Debug)]
+pub struct Recipe<'a> {
+    /// The seed that picked the concepts; the same seed and includes give
+    /// the same recipe.
+    pub seed: u64,
+    pub concepts: Vec<Keyed<'a>>,
+}
+
+impl Recipe<'_> {
+    /// Every concept's classes, in recipe order without repeats.
+    pub fn classes(&self) -> Vec<&str> {
+        let mut out: Vec<&str> = Vec::new();
+        for (_, concept) in &self.concepts {
+            for class in &concept.classes {
+                if !out.contains(&class.as_str()) {
+                    out.push(class);
+                }
+            }
+        }
+        out
+    }
+
+    /// The classes concepts add to `component`, as [`ConceptEngine::apply`]
+    /// would.
+    fn injected_into(&self, component: &str) -> String {
+        let mut out: Vec<&str> = Vec::new();
+        for (_, concept) in &self.concepts {
+            if concept.targets().contains(&component) {
+                for class in concept.injected() {
+                    if !out.contains(&class) {
+                        out.push(class);
+                    }
+                }
+            }
+        }
+        out.iter().map(|c| format!(" {}", c)).collect()
+    }
+
+    /// An example card styled by every concept in the recipe.
+    pub fn snippet(&self) -> String {
+        let names: Vec<&str> = self.concepts.iter().map(|(_, c)| c.name.as_str()).collect();
+        format!(
+            "<div class=\"card bg-base-100 w-96 shadow-xl{}\">\n  <div class=\"card-body\">\n    <h2 class=\"card-title\">{}</h2>\n    <p>One card, every ingredient.</p>\n    <div class=\"card-actions justify-end\">\n      <button type=\"button\" class=\"btn btn-primary{}\">Try it</button>\n    </div>\n  </div>\n</div>",
+            self.injected_into("card"),
+            names.join(" + "),
+            self.injected_into("btn")
+        )
+    }
+
+    /// Scripts of the concepts that have one, in recipe order.
+    pub fn scripts(&self) -> Vec<&str> {
+        self.concepts
+            .iter()
+            .filter_map(|(_, c)| c.script.as_deref())
+            .collect()
+    }
+
+    /// A heading, how to get the recipe again, the merged classes and a
+    /// line per concept with its suggestion.
+    pub fn header(&self) -> String {
+        let names: Vec<&str> = self.concepts.iter().map(|(_, c)| c.name.as_str()).collect();
+        let mut out = format!(
+            "## Style Recipe: {}\n\n_Seed {}: the same seed and concepts give this recipe again._\n\n**Classes:** {}\n\n### Ingredients\n\n",
+            names.join(" + "),
+            self.seed,
+            self.classes()
+                .iter()
+                .map(|c| format!("`{}`", c))
+                .collect::<Vec<_>>()
+                .join(" ")
+        );
+        for (key, concept) in &self.concepts {
+            out.push_str(&format!(
+                "- **{}** (`{}`, {}): {}\n",
+                concept.name, key, concept.category, concept.suggestion
+            ));
+        }
+        out
+    }
+}
+
+/// Two concepts side by side, from [`ConceptEngine::compare`].
+#[derive(Debug)]
+pub struct Comparison<'a> {
+    pub concepts: [Keyed<'a>; 2],
+    /// Which concept each name picked, for names that weren't keys.
+    pub notes: Vec<String>,
+}
+
+impl Comparison<'_> {
+    /// Markdown table of both concepts' fields, one column each.
+    fn table(&self) -> String {
+        let [(first_key, first), (second_key, second)] = self.concepts;
+        let cell = |text: &str| text.replace('|', "\\|").replace('\n', " ");
+        let classes = |c: &DesignConcept| {
+            c.classes
+                .iter()
+                .map(|class| format!("`{}`", class))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let rows = [
+            (
+                "Key",
+                format!("`{}`", first_key),
+                format!("`{}`", second_key),
+            ),
+            ("Category", first.category.clone(), second.category.clone()),
+            (
+                "Description",
+                cell(&first.description),
+                cell(&second.description),
+            ),
+            ("Classes", classes(first), classes(second)),
+            (
+                "Best for",
+                cell(&first.suggestion),
+                cell(&second.suggestion),
+            ),
+        ];
+        let mut out = format!(
+            "| | {} | {} |\n|---|---|---|\n",
+            cell(&first.name),
+            cell(&second.name)
+        );
+        for (label, a, b) in rows {
+            out.push_str(&format!("| {} | {} | {} |\n", label, a, b));
+        }
+        out
+    }
+
+    /// Each concept's snippet, and script if it has one, under its name.
+    pub fn snippets(&self) -> [String; 2] {
+        self.concepts.map(|(_, concept)| {
+            let mut out = format!(
+                "### {}\n\n```html\n{}\n```",
+                concept.name,
+                concept.snippet()
+            );
+            if let Some(script) = &concept.script {
+                out.push_str(&format!("\n\n```js\n{}\n```", script));
+            }
+            out
+        })
+    }
+
+    /// Any notes, a heading and the table.
+    pub fn header(&self) -> String {
+        let mut out = String::new();
+        for note in &self.notes {
+            out.push_str(&format!("> {}\n\n", note));
+        }
+        let [(_, first), (_, second)] = self.concepts;
+        out.push_str(&format!(
+            "## {} vs {}\n\n{}",
+            first.name,
+            second.name,
+            self.table()
+        ));
+        out
+    }
+}
+
+/// The `/daisy-a11y` report for [`ConceptEngine::audit_hint`]'s hints.
+pub fn audit_markdown(hints: &[String]) -> String {
+    let mut out = String::from("## Accessibility Hints\n\n");
+    if hints.is_empty() {
+        out.push_str(
+            "No problems found: fields are labelled, buttons have names and images have `alt`.\n",
+        );
+    }
+    for hint in hints {
+        out.push_str(&format!("- {}\n", hint));
+    }
+    out.push_str("\n_Tag-level checks only, not a full audit._\n");
+    out
+}
+
+/// One category of the concept listing: a heading and a line per concept.
+pub fn category_markdown(category: &str, concepts: &[Keyed]) -> String {
+    let mut out = format!("### {}\n\n", category);
+    for (key, concept) in concepts {
+        out.push_str(&format!("- `{}` — {}\n", key, concept.description));
+    }
+    out
+}
@@ -0,0 +1,1345 @@
+//! llms.txt parsing shared by the Zed extension and the MCP server.
+//!
+//! The upstream file uses `### component` headings, but forks and older
+//! snapshots use `## Component` or frontmatter blocks. Each style is tried in
+//! turn so a different convention degrades to a diagnostic instead of an
+//! empty docs cache.
+
+use std::collections::HashMap;
+
+/// Fewer components than this means the heading style wasn't recognized.
+pub const MIN_COMPONENTS: usize = 5;
+
+#[derive(Debug, Clone)]
+pub struct ParsedDocs {
+    /// `(name, content)` in file order; content starts with a heading line.
+    pub sections: Vec<(String, String)>,
+    pub bytes: usize,
+}
+
+impl ParsedDocs {
+    /// Human-readable warning when parsing found too few components.
+    pub fn diagnostic(&self) -> Option<String> {
+        if self.sections.len() >= MIN_COMPONENTS {
+            return None;
+        }
+        let size = if self.bytes >= 1024 {
+            format!("{}KB", self.bytes / 1024)
+        } else {
+            format!("{}B", self.bytes)
+        };
+        Some(format!(
+            "docs failed to parse: {} components from {} — heading format not recognized (expected `### name`, `## name` or `---` frontmatter with `name:`)",
+            self.sections.len(),
+            size
+        ))
+    }
+}
+
+/// Splits docs into component sections, trying `###`, then `##`, then
+/// frontmatter. The first style reaching [`MIN_COMPONENTS`] wins; otherwise
+/// the style that found the most is kept.
+pub fn parse(text: &str) -> ParsedDocs {
+    let candidates = [
+        by_heading(text, "### "),
+        by_heading(text, "## "),
+        by_frontmatter(text),
+    ];
+    let mut best: Vec<(String, String)> = Vec::new();
+    for sections in candidates {
+        if sections.len() >= MIN_COMPONENTS {
+            best = sections;
+            break;
+        }
+        if sections.len() > best.len() {
+            best = sections;
+        }
+    }
+    ParsedDocs {
+        sections: best,
+        bytes: text.len(),
+    }
+}
+
+fn by_heading(text: &str, prefix: &str) -> Vec<(String, String)> {
+    let mut sections = Vec::new();
+    let mut current: Option<(String, String)> = None;
+    for line in text.lines() {
+        match line.strip_prefix(prefix) {
+            Some(name) => {
+                sections.extend(current.take());
+                current = Some((name.trim().to_string(), format!("{}\n", line)));
+            }
+            None => {
+                if let Some((_, content)) = current.as_mut() {
+                    content.push_str(line);
+                    content.push('\n');
+                }
+            }
+        }
+    }
+    sections.extend(current);
+    sections
+        .into_iter()
+        .filter(|(name, _)| !name.is_empty())
+        .map(|(name, content)| (name, content.trim().to_string()))
+        .collect()
+}
+
+/// Blocks shaped like `---\nname: button\n---\nbody…`, repeated.
+fn by_frontmatter(text: &str) -> Vec<(String, String)> {
+    let mut sections = Vec::new();
+    let mut lines = text.lines().peekable();
+    while let Some(line) = lines.next() {
+        if line.trim() != "---" {
+            continue;
+        }
+        let mut name = None;
+        let mut closed = false;
+        for meta in lines.by_ref() {
+            if meta.trim() == "---" {
+                closed = true;
+                break;
+            }
+            if let Some((key, value)) = meta.split_once(':')
+                && matches!(key.trim(), "name" | "title" | "component")
+            {
+                name = Some(value.trim().trim_matches('"').to_string());
+            }
+        }
+        let (Some(name), true) = (name, closed) else {
+            continue;
+        };
+        let mut body = String::new();
+        while let Some(next) = lines.next_if(|l| l.trim() != "---") {
+            body.push_str(next);
+            body.push('\n');
+        }
+        if !name.is_empty() {
+            sections.push((name.clone(), format!("### {}\n{}", name, body.trim())));
+        }
+    }
+    sections
+}
+
+// ============================================================================
+// Class names
+// ============================================================================
+
+/// Groups under a component's `#### Class names` heading, in file order:
+/// `- color: \`btn-primary\`, ...` gives `("color", ["btn-primary", ...])`.
+pub fn class_names(doc: &str) -> Vec<(String, Vec<String>)> {
+    let mut groups = Vec::new();
+    let mut in_list = false;
+    for line in doc.lines() {
+        let line = line.trim();
+        if line.starts_with("####") {
+            in_list = line.eq_ignore_ascii_case("#### Class names");
+            continue;
+        }
+        let Some((key, values)) = line
+            .strip_prefix("- ")
+            .filter(|_| in_list)
+            .and_then(|l| l.split_once(':'))
+        else {
+            continue;
+        };
+        let values: Vec<String> = values
+            .split(',')
+            .map(|v| v.trim().trim_matches('`').to_string())
+            .filter(|v| !v.is_empty())
+            .collect();
+        groups.push((key.trim().to_string(), values));
+    }
+    groups
+}
+
+/// A daisyUI-style class token: lowercase letters, digits and hyphens,
+/// starting with a letter, e.g. `join-item`.
+fn is_class(token: &str) -> bool {
+    token.starts_with(|c: char| c.is_ascii_lowercase())
+        && !token.ends_with('-')
+        && token
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// Class tokens in a doc's code spans and `class="..."` attributes.
+fn class_tokens(doc: &str) -> Vec<&str> {
+    let mut tokens: Vec<&str> = doc
+        .split('`')
+        .skip(1)
+        .step_by(2)
+        .flat_map(str::split_whitespace)
+        .collect();
+    let mut rest = doc;
+    while let Some(start) = rest.find("class=\"") {
+        rest = &rest[start + 7..];
+        let end = rest.find('"').unwrap_or(rest.len());
+        tokens.extend(rest[..end].split_whitespace());
+        rest = &rest[end..];
+    }
+    tokens.retain(|t| is_class(t));
+    tokens
+}
+
+/// Maps each daisyUI class to the component that owns it. Classes listed
+/// under a component's `#### Class names` heading belong to it. Other class
+/// tokens in code spans and `class` attributes are claimed only when they
+/// extend the component's name or one of its `component` classes, so
+/// `modal-open` goes to modal while `btn` in join's example stays with
+/// button. Components are visited in name order, and the first owner wins.
+pub fn class_index(components: &HashMap<String, String>) -> HashMap<String, String> {
+    let mut names: Vec<&String> = components.keys().collect();
+    names.sort();
+    let mut index = HashMap::new();
+    for name in &names {
+        for (_, classes) in class_names(&components[*name]) {
+            for class in classes.into_iter().filter(|c| is_class(c)) {
+                index.entry(class).or_insert_with(|| name.to_string());
+            }
+        }
+    }
+    for name in &names {
+        let doc = &components[*name];
+        let mut roots = vec![name.to_string()];
+        for (group, classes) in class_names(doc) {
+            if group == "component" {
+                roots.extend(classes);
+            }
+        }
+        for token in class_tokens(doc) {
+            let owned = roots.iter().any(|root| {
+                token == root
+                    || token
+                        .strip_prefix(root.as_str())
+                        .is_some_and(|rest| rest.starts_with('-'))
+            });
+            if owned {
+                index
+                    .entry(token.to_string())
+                    .or_insert_with(|| name.to_string());
+            }
+        }
+    }
+    index
+}
+
+/// The component that owns `class` in a [`class_index`]. Accepts `.btn` and
+/// `` `btn` `` forms; a near miss gets suggestions rather than a guess.
+pub fn owner<'a>(class: &str, index: &'a HashMap<String, String>) -> Result<&'a str, String> {
+    let wanted = class
+        .trim()
+        .trim_matches('`')
+        .trim_start_matches('.')
+        .to_lowercase();
+    if let Some(owner) = index.get(&wanted) {
+        return Ok(owner);
+    }
+    let suggestions = match lookup(&wanted, index.keys().map(String::as_str)) {
+        Lookup::Exact(found) => return Ok(&index[&found]),
+        Lookup::Corrected(found) => vec![found],
+        Lookup::Ambiguous(found) => found,
+        Lookup::Missing => Vec::new(),
+    };
+    let mut message = format!("No component uses the class '{}'", class.trim());
+    if !suggestions.is_empty() {
+        let quoted: Vec<String> = suggestions.iter().map(|c| format!("'{}'", c)).collect();
+        message.push_str(&format!(". Did you mean {}?", quoted.join(", ")));
+    }
+    Err(message)
+}
+
+// ============================================================================
+// Cross-references
+// ============================================================================
+
+/// Whether lowercase `doc` names `component` as a whole word: not inside a
+/// longer word or hyphenated class (`radiobutton`, `radial-progress`), and
+/// not as an HTML tag name (`<button>`).
+fn mentions(doc: &str, component: &str) -> bool {
+    let word = |c: char| c.is_alphanumeric() || c == '-' || c == '_';
+    doc.match_indices(component).any(|(at, _)| {
+        let head = &doc[..at];
+        !head.chars().next_back().is_some_and(word)
+            && !doc[at + component.len()..].chars().next().is_some_and(word)
+            && !head.ends_with('<')
+            && !head.ends_with("</")
+    })
+}
+
+/// For each component, the other components its doc mentions, in name
+/// order.
+pub fn references(components: &HashMap<String, String>) -> HashMap<String, Vec<String>> {
+    let mut names: Vec<&String> = components.keys().collect();
+    names.sort();
+    components
+        .iter()
+        .map(|(name, doc)| {
+            let doc = doc.to_lowercase();
+            let mentioned = names
+                .iter()
+                .filter(|other| *other != &name && mentions(&doc, other))
+                .map(|other| other.to_string())
+                .collect();
+            (name.clone(), mentioned)
+        })
+        .collect()
+}
+
+/// Markdown list of the components that mention `component`.
+pub fn used_by_markdown(component: &str, users: &[String]) -> String {
+    if users.is_empty() {
+        return format!("No other component's docs mention `{}`.\n", component);
+    }
+    let mut out = format!("## Components that mention {}\n\n", component);
+    for user in users {
+        out.push_str(&format!("- {}\n", user));
+    }
+    out
+}
+
+// ============================================================================
+// Modifiers
+// ============================================================================
+
+/// Size suffixes, smallest first.
+const SIZES: &[&str] = &["xs", "sm", "md", "lg", "xl"];
+
+/// Theme color suffixes.
+const COLORS: &[&str] = &[
+    "neutral",
+    "primary",
+    "secondary",
+    "accent",
+    "info",
+    "success",
+    "warning",
+    "error",
+];
+
+/// Modifier classes of a component: every class in its doc that extends the
+/// name or one of its `component` classes with a hyphen, as `btn-sm` extends
+/// `btn`. Parts such as `card-body` are listed under `part` and left out.
+/// Sorted and deduplicated.
+pub fn modifiers(component: &str, doc: &str) -> Vec<String> {
+    let mut roots = vec![component.to_string()];
+    let mut parts = Vec::new();
+    let mut listed = Vec::new();
+    for (group, classes) in class_names(doc) {
+        match group.as_str() {
+            "component" => roots.extend(classes),
+            "part" => parts.extend(classes),
+            _ => listed.extend(classes),
+        }
+    }
+    let mut found: Vec<String> = listed
+        .iter()
+        .map(String::as_str)
+        .chain(class_tokens(doc))
+        .filter(|class| is_class(class) && !parts.iter().any(|p| p == class))
+        .filter(|class| {
+            roots.iter().any(|root| {
+                class
+                    .strip_prefix(root.as_str())
+                    .and_then(|rest| rest.strip_prefix('-'))
+                    .is_some_and(|rest| !rest.is_empty())
+            })
+        })
+        .map(str::to_string)
+        .collect();
+    found.sort();
+    found.dedup();
+    found
+}
+
+/// Splits modifiers into size, color and style groups by suffix: `-xs` to
+/// `-xl` are sizes, theme colors are colors, and the rest are styles. Sizes
+/// run smallest first and the other groups stay in name order; empty groups
+/// are left out.
+pub fn group_modifiers(modifiers: &[String]) -> Vec<(&'static str, Vec<String>)> {
+    let suffix = |class: &str| class.rsplit('-').next().unwrap_or_default().to_string();
+    let size = |class: &String| SIZES.iter().position(|s| *s == suffix(class));
+    let mut sizes: Vec<String> = modifiers
+        .iter()
+        .filter(|c| size(c).is_some())
+        .cloned()
+        .collect();
+    sizes.sort_by_key(|c| (size(c), c.clone()));
+    let (colors, styles): (Vec<String>, Vec<String>) = modifiers
+        .iter()
+        .filter(|c| size(c).is_none())
+        .cloned()
+        .partition(|c| COLORS.contains(&suffix(c).as_str()));
+    [("size", sizes), ("color", colors), ("style", styles)]
+        .into_iter()
+        .filter(|(_, classes)| !classes.is_empty())
+        .collect()
+}
+
+/// Markdown listing of a component's modifiers by group.
+pub fn format_modifiers(component: &str, modifiers: &[String]) -> String {
+    let groups = group_modifiers(modifiers);
+    if groups.is_empty() {
+        return format!(
+            "## {} modifiers\n\n`{}` has no modifier classes.\n",
+            component, component
+        );
+    }
+    let mut out = format!("## {} modifiers\n\n", component);
+    for (group, classes) in groups {
+        let classes: Vec<String> = classes.iter().map(|c| format!("`{}`", c)).collect();
+        out.push_str(&format!("- {}: {}\n", group, classes.join(", ")));
+    }
+    out
+}
+
+// ============================================================================
+// Cheatsheet
+// ============================================================================
+
+/// daisyUI's own component groups, as on daisyui.com. Components not listed
+/// fall under "other".
+pub const CATEGORIES: &[(&str, &[&str])] = &[
+    (
+        "actions",
+        &["button", "dropdown", "modal", "swap", "theme-controller"],
+    ),
+    (
+        "data-display",
+        &[
+            "accordion",
+            "avatar",
+            "badge",
+            "card",
+            "carousel",
+            "chat",
+            "collapse",
+            "countdown",
+            "diff",
+            "kbd",
+            "list",
+            "stat",
+            "status",
+            "table",
+            "timeline",
+        ],
+    ),
+    (
+        "navigation",
+        &[
+            "breadcrumbs",
+            "dock",
+            "link",
+            "menu",
+            "navbar",
+            "pagination",
+            "steps",
+            "tabs",
+        ],
+    ),
+    (
+        "feedback",
+        &[
+            "alert",
+            "loading",
+            "progress",
+            "radial-progress",
+            "skeleton",
+            "toast",
+            "tooltip",
+        ],
+    ),
+    (
+        "data-input",
+        &[
+            "calendar",
+            "checkbox",
+            "fieldset",
+            "file-input",
+            "filter",
+            "input",
+            "label",
+            "radio",
+            "range",
+            "rating",
+            "select",
+            "textarea",
+            "toggle",
+            "validator",
+        ],
+    ),
+    (
+        "layout",
+        &[
+            "divider",
+            "drawer",
+            "footer",
+            "hero",
+            "indicator",
+            "join",
+            "mask",
+            "stack",
+        ],
+    ),
+];
+
+pub fn category(component: &str) -> &'static str {
+    CATEGORIES
+        .iter()
+        .find(|(_, names)| names.contains(&component))
+        .map_or("other", |(category, _)| category)
+}
+
+/// One cheatsheet row: a component's main class and what it's for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub class: String,
+    pub text: String,
+}
+
+/// The first `component` class (or the name) and the first sentence of the
+/// first prose line, skipping headings, code blocks and link-only lines.
+pub fn summarize(component: &str, doc: &str) -> Summary {
+    let class = class_names(doc)
+        .into_iter()
+        .find(|(group, _)| group == "component")
+        .and_then(|(_, classes)| classes.into_iter().next())
+        .unwrap_or_else(|| component.to_string());
+    let mut in_code = false;
+    let line = doc
+        .lines()
+        .map(str::trim)
+        .find(|line| {
+            if line.starts_with("```") {
+                in_code = !in_code;
+                return false;
+            }
+            !in_code
+                && !line.is_empty()
+                && !line.starts_with('#')
+                && !line.starts_with('[')
+                && !line.starts_with('<')
+                && !line.starts_with("- ")
+        })
+        .unwrap_or_default();
+    let sentence = match line.find(". ") {
+        Some(end) => &line[..=end],
+        None => line,
+    };
+    Summary {
+        class,
+        text: sentence.trim().to_string(),
+    }
+}
+
+/// Every category name, "other" last.
+pub fn categories() -> Vec<&'static str> {
+    CATEGORIES
+        .iter()
+        .map(|(c, _)| *c)
+        .chain(["other"])
+        .collect()
+}
+
+/// Error for a category or name filter that matched nothing.
+fn unmatched(filter: &str) -> String {
+    format!(
+        "No components match '{}'. Categories: {}",
+        filter,
+        categories().join(", ")
+    )
+}
+
+/// Markdown table of every summary whose category starts with `filter` or
+/// whose name contains it; an empty filter keeps everything.
+pub fn cheatsheet(summaries: &HashMap<String, Summary>, filter: &str) -> Result<String, String> {
+    let filter = filter.trim().to_lowercase();
+    let mut names: Vec<&String> = summaries
+        .keys()
+        .filter(|name| category(name).starts_with(&filter) || name.contains(&filter))
+        .collect();
+    if names.is_empty() {
+        return Err(unmatched(&filter));
+    }
+    names.sort();
+    let mut out = String::from("| Component | Class | Category | Summary |\n|---|---|---|---|\n");
+    for name in names {
+        let summary = &summaries[name];
+        out.push_str(&format!(
+            "| {} | `{}` | {} | {} |\n",
+            name,
+            summary.class,
+            category(name),
+            summary.text.replace('|', "\\|")
+        ));
+    }
+    Ok(out)
+}
+
+// ============================================================================
+// Random picks
+// ============================================================================
+
+/// One splitmix64 step: spreads consecutive seeds across the whole range,
+/// so a counter makes a good seed. No OS randomness is needed, which the
+/// wasm extension doesn't have.
+pub fn mix(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// A component chosen by `seed` among `names` whose category starts with
+/// `category`; an empty category allows every component. The same seed and
+/// names always give the same pick.
+pub fn random_component<'a>(
+    names: impl IntoIterator<Item = &'a str>,
+    category_filter: &str,
+    seed: u64,
+) -> Result<&'a str, String> {
+    let filter = category_filter.trim().to_lowercase();
+    let mut names: Vec<&str> = names
+        .into_iter()
+        .filter(|name| category(name).starts_with(&filter))
+        .collect();
+    if names.is_empty() {
+        return Err(unmatched(&filter));
+    }
+    names.sort_unstable();
+    Ok(names[(mix(seed) % names.len() as u64) as usize])
+}
+
+// ============================================================================
+// Aliases
+// ============================================================================
+
+/// Names other frameworks use, and the daisyUI component they mean. An
+/// alias whose target isn't in the docs, or that is itself a component
+/// name, is ignored.
+pub const ALIASES: &[(&str, &str)] = &[
+    ("dialog", "modal"),
+    ("popup", "modal"),
+    ("lightbox", "modal"),
+    ("spinner", "loading"),
+    ("loader", "loading"),
+    ("hamburger", "menu"),
+    ("nav-menu", "menu"),
+    ("sidebar", "drawer"),
+    ("offcanvas", "drawer"),
+    ("accordion", "collapse"),
+    ("expander", "collapse"),
+    ("chip", "badge"),
+    ("pill", "badge"),
+    ("tag", "badge"),
+    ("breadcrumb", "breadcrumbs"),
+    ("switch", "toggle"),
+    ("text-field", "input"),
+    ("textbox", "input"),
+    ("button-group", "join"),
+    ("app-bar", "navbar"),
+    ("toolbar", "navbar"),
+    ("progress-bar", "progress"),
+    ("progressbar", "progress"),
+    ("keyboard", "kbd"),
+    ("shimmer", "skeleton"),
+    ("statistic", "stat"),
+    ("data-table", "table"),
+    ("tab", "tabs"),
+    ("popover", "dropdown"),
+];
+
+/// `alias: component` pairs from an `aliases:` block in llms.txt: the line
+/// `aliases:` followed by `- dialog: modal` or `dialog: modal` lines, up to
+/// the first blank line or heading.
+pub fn parse_aliases(text: &str) -> Vec<(String, String)> {
+    let mut aliases = Vec::new();
+    let mut lines = text.lines().map(str::trim);
+    while lines
+        .by_ref()
+        .any(|line| line.eq_ignore_ascii_case("aliases:"))
+    {
+        for line in lines.by_ref() {
+            if line.is_empty() || line.starts_with('#') {
+                break;
+            }
+            let entry = line.strip_prefix("- ").unwrap_or(line);
+            if let Some((alias, target)) = entry.split_once(':') {
+                let (alias, target) = (alias.trim(), target.trim().to_lowercase());
+                if !alias.is_empty() && !target.is_empty() {
+                    aliases.push((alias.to_string(), target));
+                }
+            }
+        }
+    }
+    aliases
+}
+
+/// Note shown when a name resolved through an alias.
+pub fn alias_note(alias: &str, component: &str) -> String {
+    format!("Showing '{}' (alias of '{}')", component, alias)
+}
+
+// ============================================================================
+// Fuzzy names
+// ============================================================================
+
+/// Result of resolving a possibly misspelled component name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Lookup {
+    Exact(String),
+    /// The single closest name; show a "showing results for" note.
+    Corrected(String),
+    /// Several names are equally close; up to three suggestions.
+    Ambiguous(Vec<String>),
+    Missing,
+}
+
+impl Lookup {
+    /// The name to show and, when it was corrected, a note saying so.
+    /// Ambiguous and missing names become the error message.
+    pub fn resolve(self, name: &str) -> Result<(String, Option<String>), String> {
+        match self {
+            Lookup::Exact(found) => Ok((found, None)),
+            Lookup::Corrected(found) => {
+                let note = format!(
+                    "No component named '{}'; showing results for '{}'.",
+                    name, found
+                );
+                Ok((found, Some(note)))
+            }
+            Lookup::Ambiguous(names) => Err(format!(
+                "No component named '{}'. Did you mean {}?",
+                name,
+                names
+                    .iter()
+                    .map(|n| format!("'{}'", n))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+            Lookup::Missing => Err(format!("Documentation not found for '{}'", name)),
+        }
+    }
+}
+
+/// Lowercase without spaces, hyphens or underscores: `Drop down` and
+/// `radial_progress` compare equal to `dropdown` and `radial-progress`.
+fn normalize(name: &str) -> Vec<char> {
+    name.chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Edit distance counting insertions, deletions, substitutions and swaps of
+/// adjacent characters as one edit each (optimal string alignment).
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+/// Resolves `name` against `names`: an exact (case-insensitive) match wins,
+/// then a match ignoring spaces and hyphens, then the unique closest name
+/// within 1 edit (2 for names over 4 characters).
+pub fn lookup<'a>(name: &str, names: impl IntoIterator<Item = &'a str>) -> Lookup {
+    let wanted = normalize(name);
+    if wanted.is_empty() {
+        return Lookup::Missing;
+    }
+    let limit = if wanted.len() > 4 { 2 } else { 1 };
+    let mut scored: Vec<(usize, &str)> = Vec::new();
+    for candidate in names {
+        if candidate.eq_ignore_ascii_case(name.trim()) {
+            return Lookup::Exact(candidate.to_string());
+        }
+        scored.push((edit_distance(&wanted, &normalize(candidate)), candidate));
+    }
+    scored.sort();
+    match scored.as_slice() {
+        [(best, _), ..] if *best > limit => Lookup::Missing,
+        [(_, only)] => Lookup::Corrected(only.to_string()),
+        [(best, first), (next, _), ..] if next > best => Lookup::Corrected(first.to_string()),
+        [] => Lookup::Missing,
+        _ => Lookup::Ambiguous(
+            scored
+                .iter()
+                .take(3)
+                .filter(|(d, _)| *d <= limit)
+                .map(|(_, n)| n.to_string())
+                .collect(),
+        ),
+    }
+}
+
+// ============================================================================
+// Search snippets
+// ============================================================================
+
+/// Approximate snippet width in bytes.
+pub const SNIPPET_LEN: usize = 200;
+
+/// `[text](url)` reduced to `text`, so highlights never land in a URL.
+fn link_text(line: &str) -> String {
+    let mut out = String::new();
+    let mut rest = line;
+    while let Some(close) = rest.find("](") {
+        let Some(open) = rest[..close].rfind('[') else {
+            break;
+        };
+        let Some(end) = rest[close..].find(')') else {
+            break;
+        };
+        out.push_str(&rest[..open]);
+        out.push_str(&rest[open + 1..close]);
+        rest = &rest[close + end + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// A search snippet as plain text, with the byte ranges in it that match a
+/// query word, in order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Snippet {
+    pub text: String,
+    pub matches: Vec<(usize, usize)>,
+}
+
+impl Snippet {
+    /// The text with each match in bold and tags escaped, so it renders as
+    /// plain prose in Markdown.
+    pub fn markdown(&self) -> String {
+        let mut out = String::new();
+        let mut at = 0;
+        for &(start, end) in &self.matches {
+            out.push_str(&self.text[at..start]);
+            out.push_str(&format!("**{}**", &self.text[start..end]));
+            at = end;
+        }
+        out.push_str(&self.text[at..]);
+        sanitize_markdown(&out, Sanitize::Strict)
+    }
+}
+
+/// Up to [`SNIPPET_LEN`] bytes of `content` around the best match for
+/// `query`, on one line, with where each query word appears. The window
+/// holding the whole query wins; otherwise the one with the most distinct
+/// query words (earliest on ties). Headings, link targets and backticks are
+/// dropped.
+pub fn snippet(content: &str, query: &str) -> Snippet {
+    // ASCII lowercasing keeps byte offsets identical to `text`.
+    let text = content
+        .lines()
+        .filter(|l| !l.trim_start().starts_with('#'))
+        .map(link_text)
+        .flat_map(|l| {
+            l.split_whitespace()
+                .map(|w| w.replace('`', ""))
+                .collect::<Vec<_>>()
+        })
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let lower = text.to_ascii_lowercase();
+    let query = query.to_ascii_lowercase();
+    let phrase = query.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut terms: Vec<&str> = phrase.split(' ').filter(|t| !t.is_empty()).collect();
+    terms.sort_by_key(|t| std::cmp::Reverse(t.len()));
+    terms.dedup();
+
+    let window = |at: usize, len: usize| {
+        let mut start = (at + len / 2)
+            .saturating_sub(SNIPPET_LEN / 2)
+            .min(text.len().saturating_sub(SNIPPET_LEN));
+        while !text.is_char_boundary(start) {
+            start -= 1;
+        }
+        let mut end = (start + SNIPPET_LEN).min(text.len());
+        while !text.is_char_boundary(end) {
+            end += 1;
+        }
+        (start, end)
+    };
+    let (start, end) = match lower.find(&phrase).filter(|_| !phrase.is_empty()) {
+        Some(at) => window(at, phrase.len()),
+        None => terms
+            .iter()
+            .flat_map(|t| {
+                lower
+                    .match_indices(t)
+                    .take(50)
+                    .map(|(at, t)| window(at, t.len()))
+            })
+            .map(|(s, e)| {
+                let found = terms.iter().filter(|t| lower[s..e].contains(*t)).count();
+                ((s, e), found)
+            })
+            .max_by(|(a, x), (b, y)| x.cmp(y).then(b.0.cmp(&a.0)))
+            .map_or_else(|| window(0, 0), |(w, _)| w),
+    };
+
+    let mut out = Snippet::default();
+    if start > 0 {
+        out.text.push('…');
+    }
+    let mut at = start;
+    while at < end {
+        match terms.iter().find(|t| lower[at..end].starts_with(*t)) {
+            Some(t) => {
+                let from = out.text.len();
+                out.text.push_str(&text[at..at + t.len()]);
+                out.matches.push((from, out.text.len()));
+                at += t.len();
+            }
+            None => {
+                let c = text[at..].chars().next().unwrap_or(' ');
+                out.text.push(c);
+                at += c.len_utf8();
+            }
+        }
+    }
+    if end < text.len() {
+        out.text.push('…');
+    }
+    out
+}
+
+// ============================================================================
+// Examples
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub caption: String,
+    pub lang: String,
+    pub code: String,
+}
+
+/// Pulls every fenced code block out of a component doc, captioning each
+/// from the nearest preceding heading or prose line.
+pub fn extract_examples(doc: &str) -> Vec<Example> {
+    let mut examples = Vec::new();
+    let mut caption: Option<String> = None;
+    let mut open: Option<(String, String, String)> = None; // marker, lang, code
+
+    for line in doc.lines() {
+        let trimmed = line.trim_start();
+        if let Some((marker, lang, code)) = open.as_mut() {
+            if trimmed.starts_with(marker.as_str())
+                && trimmed.trim_matches(|c| c == '`' || c == '~').is_empty()
+            {
+                if !code.trim().is_empty() {
+                    examples.push(Example {
+                        caption: caption
+                            .clone()
+                            .unwrap_or_else(|| format!("Example {}", examples.len() + 1)),
+                        lang: lang.clone(),
+                        code: code.trim_end().to_string(),
+                    });
+                }
+                open = None;
+            } else {
+                code.push_str(line);
+                code.push('\n');
+            }
+            continue;
+        }
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            let marker: String = trimmed
+                .chars()
+                .take_while(|c| *c == '`' || *c == '~')
+                .collect();
+            let lang = trimmed[marker.len()..].trim().to_string();
+            open = Some((marker, lang, String::new()));
+            continue;
+        }
+        if let Some(text) = caption_from(trimmed) {
+            caption = Some(text);
+        }
+    }
+    examples
+}
+
+/// Headings give their text; prose gives its last sentence. Lists, tables
+/// and blank lines don't caption anything.
+fn caption_from(line: &str) -> Option<String> {
+    if line.is_empty() || line.starts_with(['|', '-', '*', '>', '[']) {
+        return None;
+    }
+    let text = line.trim_start_matches('#').trim();
+    let text = if line.starts_with('#') {
+        text
+    } else {
+        let body = text.trim_end_matches(['.', ':']);
+        body.rsplit(". ").next().unwrap_or(body)
+    };
+    let text = text.trim_end_matches(':').trim();
+    if text.is_empty() {
+        return None;
+    }
+    let mut caption: String = text.chars().take(80).collect();
+    if text.chars().count() > 80 {
+        caption.push('…');
+    }
+    Some(caption)
+}
+
+/// Markdown gallery: one captioned fenced block per example, capped at `count`.
+pub fn examples_markdown(component: &str, examples: &[Example], count: Option<usize>) -> String {
+    let shown = count.unwrap_or(examples.len()).min(examples.len());
+    let mut out = format!(
+        "## {} examples ({} of {})\n",
+        component,
+        shown,
+        examples.len()
+    );
+    for (i, ex) in examples.iter().take(shown).enumerate() {
+        out.push_str(&format!(
+            "\n### {}. {}\n\n```{}\n{}\n```\n",
+            i + 1,
+            ex.caption,
+            ex.lang,
+            ex.code
+        ));
+    }
+    out
+}
+
+/// One example by its 1-based `index`, or the whole gallery when there's no
+/// index.
+pub fn pick_example(
+    component: &str,
+    examples: &[Example],
+    index: Option<usize>,
+) -> Result<String, String> {
+    if examples.is_empty() {
+        return Err(format!("No examples found for '{}'", component));
+    }
+    let Some(index) = index else {
+        return Ok(examples_markdown(component, examples, None));
+    };
+    let ex = index
+        .checked_sub(1)
+        .and_then(|i| examples.get(i))
+        .ok_or_else(|| {
+            let noun = if examples.len() == 1 {
+                "example"
+            } else {
+                "examples"
+            };
+            format!(
+                "'{}' has {} {}; pick an index from 1 to {}",
+                component,
+                examples.len(),
+                noun,
+                examples.len()
+            )
+        })?;
+    Ok(format!(
+        "## {} example {} of {}: {}\n\n```{}\n{}\n```\n",
+        component,
+        index,
+        examples.len(),
+        ex.caption,
+        ex.lang,
+        ex.code
+    ))
+}
+
+// ============================================================================
+// Subsections
+// ============================================================================
+
+/// Markdown heading level of a line, e.g. 4 for `#### Rules`.
+fn heading_level(line: &str) -> Option<usize> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    (level > 0 && line[level..].starts_with(' ')).then_some(level)
+}
+
+/// `(heading, block)` for every heading below a doc's first line, in doc
+/// order. A block runs from its heading to the next heading of the same or a
+/// higher level, so it keeps any deeper headings inside it. Lines in code
+/// fences are never headings.
+pub fn subsections(doc: &str) -> Vec<(String, String)> {
+    let lines: Vec<&str> = doc.lines().collect();
+    let mut in_code = false;
+    let levels: Vec<Option<usize>> = lines
+        .iter()
+        .map(|line| {
+            if line.trim_start().starts_with("```") {
+                in_code = !in_code;
+                return None;
+            }
+            heading_level(line).filter(|_| !in_code)
+        })
+        .collect();
+    let mut sections = Vec::new();
+    for (start, level) in levels.iter().enumerate().skip(1) {
+        let Some(level) = level else {
+            continue;
+        };
+        let end = levels[start + 1..]
+            .iter()
+            .position(|l| l.is_some_and(|l| l <= *level))
+            .map_or(lines.len(), |i| start + 1 + i);
+        let heading = lines[start][*level..].trim().to_string();
+        sections.push((heading, lines[start..end].join("\n").trim().to_string()));
+    }
+    sections
+}
+
+/// The block for `anchor` among a component's [`subsections`]. Case, hyphens
+/// and extra spaces are ignored, so `class-names` finds "Class names"; an
+/// exact heading wins, then the first heading containing the anchor.
+pub fn pick_section<'a>(
+    component: &str,
+    sections: &'a [(String, String)],
+    anchor: &str,
+) -> Result<(&'a str, &'a str), String> {
+    let simplify = |text: &str| {
+        text.replace('-', " ")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+    };
+    let wanted = simplify(anchor);
+    sections
+        .iter()
+        .find(|(heading, _)| simplify(heading) == wanted)
+        .or_else(|| {
+            sections
+                .iter()
+                .find(|(heading, _)| !wanted.is_empty() && simplify(heading).contains(&wanted))
+        })
+        .map(|(heading, block)| (heading.as_str(), block.as_str()))
+        .ok_or_else(|| {
+            if sections.is_empty() {
+                return format!("'{}' has no subsections", component);
+            }
+            let anchors: Vec<String> = sections
+                .iter()
+                .map(|(heading, _)| format!("'{}'", heading))
+                .collect();
+            format!(
+                "'{}' has no section matching '{}'. Sections: {}",
+                component,
+                anchor.trim(),
+                anchors.join(", ")
+            )
+        })
+}
+
+// ============================================================================
+// Length budget
+// ============================================================================
+
+/// `doc` cut to at most `max_chars` characters, plus a marker saying how
+/// much was left out and `hint` on how to see it. Cuts fall at the last
+/// paragraph break that fits, else the last line break, and a code fence
+/// left open is closed. Docs within budget come back unchanged.
+pub fn truncate(doc: &str, max_chars: usize, hint: &str) -> String {
+    let total = doc.chars().count();
+    if total <= max_chars {
+        return doc.to_string();
+    }
+    let limit = doc
+        .char_indices()
+        .nth(max_chars)
+        .map_or(doc.len(), |(at, _)| at);
+    let head = &doc[..limit];
+    let cut = head
+        .rfind("\n\n")
+        .or_else(|| head.rfind('\n'))
+        .unwrap_or(limit);
+    let kept = doc[..cut].trim_end();
+    let mut out = kept.to_string();
+    if kept
+        .lines()
+        .filter(|l| l.trim_start().starts_with("```"))
+        .count()
+        % 2
+        == 1
+    {
+        out.push_str("\n```");
+    }
+    out.push_str(&format!(
+        "\n\n…(truncated, {} more chars — {})",
+        total - kept.chars().count(),
+        hint
+    ));
+    out
+}
+
+// ============================================================================
+// Markdown sanitization
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sanitize {
+    /// Escapes every raw HTML tag outside code; for MCP text blocks whose
+    /// renderer is unknown.
+    Strict,
+    /// Escapes only active tags (`<script>`, `<iframe>`, ...); for Zed
+    /// sections, which render Markdown but not HTML.
+    Lenient,
+}
+
+const ACTIVE_TAGS: &[&str] = &[
+    "script", "style", "iframe", "object", "embed", "link", "meta",
+];
+
+/// Makes docs-derived Markdown safe to paste into an assistant context:
+/// unclosed code fences are closed, raw HTML outside code is escaped per
+/// `mode`, and table rows are padded or trimmed to the header's width.
+pub fn sanitize_markdown(text: &str, mode: Sanitize) -> String {
+    let mut out = String::with_capacity(text.len());
+    // Marker of the open fence, e.g. "```" or "~~~~".
+    let mut fence: Option<String> = None;
+    let mut table_width: Option<usize> = None;
+
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        let marker: String = trimmed
+            .chars()
+            .take_while(|c| *c == '`' || *c == '~')
+            .collect();
+        let is_fence =
+            marker.len() >= 3 && marker.chars().all(|c| c == marker.chars().next().unwrap());
+
+        if let Some(open) = &fence {
+            out.push_str(line);
+            out.push('\n');
+            if is_fence
+                && marker.starts_with(open.as_str())
+                && trimmed[marker.len()..].trim().is_empty()
+            {
+                fence = None;
+            }
+            continue;
+        }
+        if is_fence {
+            fence = Some(marker);
+            table_width = None;
+            out.push_str(line);
+            out.push('\n');
+            continue;
+        }
+
+        let line = if trimmed.starts_with('|') {
+            let cells = table_cells(trimmed);
+            let width = *table_width.get_or_insert(cells.len());
+            normalize_row(cells, width)
+        } else {
+            table_width = None;
+            line.to_string()
+        };
+        out.push_str(&escape_html_outside_code(&line, mode));
+        out.push('\n');
+    }
+
+    if let Some(open) = fence {
+        out.push_str(&open);
+        out.push('\n');
+    }
+    if !text.ends_with('\n') {
+        out.pop();
+    }
+    out
+}
+
+/// Splits `| a | b \| c |` into cells, honoring escaped pipes.
+fn table_cells(row: &str) -> Vec<String> {
+    let inner = row.trim().trim_start_matches('|');
+    let inner = inner
+        .strip_suffix('|')
+        .filter(|s| !s.ends_with('\\'))
+        .unwrap_or(inner);
+    let mut cells = vec![String::new()];
+    let mut escaped = false;
+    for c in inner.chars() {
+        match c {
+            '|' if !escaped => cells.push(String::new()),
+            _ => cells.last_mut().unwrap().push(c),
+        }
+        escaped = c == '\\' && !escaped;
+    }
+    cells.into_iter().map(|c| c.trim().to_string()).collect()
+}
+
+/// Pads short rows with empty cells and folds overflow into the last cell.
+fn normalize_row(mut cells: Vec<String>, width: usize) -> String {
+    if cells.len() > width && width > 0 {
+        let overflow = cells.split_off(width);
+        let last = cells.last_mut().unwrap();
+        for extra in overflow {
+            last.push_str(" \\| ");
+            last.push_str(&extra);
+        }
+    }
+    cells.resize(width.max(1), String::new());
+    format!("| {} |", cells.join(" | "))
+}
+
+fn escape_html_outside_code(line: &str, mode: Sanitize) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut in_code = false;
+    // Inside a tag whose `<` was escaped, so its `>` is escaped too.
+    let mut in_tag = false;
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        let len = c.len_utf8();
+        match c {
+            '`' if !in_tag => {
+                in_code = !in_code;
+                out.push(c);
+            }
+            '<' if !in_code && escapes_tag(&rest[1..], mode) => {
+                in_tag = true;
+                out.push_str("&lt;");
+            }
+            '>' if in_tag => {
+                in_tag = false;
+                out.push_str("&gt;");
+            }
+            _ => out.push(c),
+        }
+        rest = &rest[len..];
+    }
+    out
+}
+
+fn escapes_tag(after_lt: &str, mode: Sanitize) -> bool {
+    let name: String = after_lt
+        .trim_start_matches('/')
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .collect::<String>()
+        .to_lowercase();
+    if name.is_empty() {
+        return false;
+    }
+    match mode {
+        Sanitize::Strict => true,
+        Sanitize::Lenient => ACTIVE_TAGS.contains(&name.as_str()),
+    }
+}
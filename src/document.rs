@@ -0,0 +1,58 @@
+//! Full HTML documents around generated fragments, for output that should
+//! open as-is: `--full` on `/daisy-layout` and `full_document` on
+//! `daisyui_scaffold_layout`.
+
+use crate::footprint;
+
+/// The theme the toggle switches to from `theme`.
+fn alternate(theme: &str) -> &'static str {
+    if theme == "dark" { "light" } else { "dark" }
+}
+
+/// `body` as a standalone page: doctype, `<html data-theme>`, viewport,
+/// `title` (escaped by the caller), the daisyUI and Tailwind CDN tags (plus Chart.js when the markup
+/// draws charts), and a button that toggles `data-theme` between `theme`
+/// (default light) and light or dark.
+pub fn wrap(body: &str, title: &str, theme: Option<&str>) -> String {
+    let theme: String = theme
+        .unwrap_or("light")
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .take(40)
+        .collect();
+    let theme = if theme.is_empty() {
+        "light".into()
+    } else {
+        theme
+    };
+    let chart = if footprint::analyze(body).scripts.contains(&"chart") {
+        "\n<script src=\"https://cdn.jsdelivr.net/npm/chart.js\"></script>"
+    } else {
+        ""
+    };
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en" data-theme="{theme}">
+<head>
+<meta charset="utf-8" />
+<meta name="viewport" content="width=device-width, initial-scale=1" />
+<title>{title}</title>
+<link href="https://cdn.jsdelivr.net/npm/daisyui@5" rel="stylesheet" type="text/css" />
+<script src="https://cdn.jsdelivr.net/npm/@tailwindcss/browser@4"></script>{chart}
+</head>
+<body>
+{body}
+<button type="button" class="btn btn-circle btn-sm fixed bottom-4 right-4 z-50" data-theme-toggle aria-label="Toggle theme">◐</button>
+<script>
+document.querySelector('[data-theme-toggle]').addEventListener('click', () => {{
+  const root = document.documentElement;
+  root.dataset.theme = root.dataset.theme === '{theme}' ? '{alternate}' : '{theme}';
+}});
+</script>
+</body>
+</html>
+"#,
+        body = body.trim(),
+        alternate = alternate(&theme)
+    )
+}
@@ -0,0 +1,172 @@
+//! Weight of a piece of generated markup: which daisyUI components it uses,
+//! its approximate DOM size, images, JavaScript needs and the CDN resources a
+//! standalone page would load.
+
+use crate::html::{classes, elements};
+
+/// Resources a standalone page always loads.
+const BASE_CDN: &[&str] = &[
+    "https://cdn.jsdelivr.net/npm/daisyui@5 (CSS)",
+    "https://cdn.jsdelivr.net/npm/@tailwindcss/browser@4 (JS)",
+];
+const CHART_CDN: &str = "https://cdn.jsdelivr.net/npm/chart.js (JS)";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Footprint {
+    /// daisyUI component roots in order of first use.
+    pub components: Vec<&'static str>,
+    pub nodes: usize,
+    pub images: usize,
+    /// Script-library entries (`modal`, `palette`, `tag-input`, `chart`) the
+    /// markup depends on, plus `inline` when it carries its own `<script>`.
+    pub scripts: Vec<&'static str>,
+    pub cdn: Vec<&'static str>,
+}
+
+/// daisyUI component roots; a class is daisyUI-native when it is one of
+/// these or starts with `<root>-` (`btn-primary`, `card-body`, ...).
+const COMPONENTS: &[&str] = &[
+    "alert",
+    "avatar",
+    "badge",
+    "breadcrumbs",
+    "btn",
+    "card",
+    "carousel",
+    "chat",
+    "checkbox",
+    "collapse",
+    "countdown",
+    "diff",
+    "divider",
+    "dock",
+    "drawer",
+    "dropdown",
+    "fieldset",
+    "file-input",
+    "floating-label",
+    "footer",
+    "hero",
+    "indicator",
+    "input",
+    "join",
+    "kbd",
+    "label",
+    "link",
+    "list",
+    "loading",
+    "mask",
+    "menu",
+    "mockup",
+    "modal",
+    "navbar",
+    "progress",
+    "radial-progress",
+    "radio",
+    "range",
+    "rating",
+    "select",
+    "skeleton",
+    "stack",
+    "stat",
+    "stats",
+    "status",
+    "steps",
+    "swap",
+    "tab",
+    "tabs",
+    "table",
+    "textarea",
+    "theme-controller",
+    "timeline",
+    "toast",
+    "toggle",
+    "tooltip",
+    "validator",
+];
+
+/// The daisyUI component `class` belongs to (ignoring any `md:`-style
+/// variant), e.g. `card` for `card-body`.
+pub fn component_of(class: &str) -> Option<&'static str> {
+    let base = class.rsplit(':').next().unwrap_or(class);
+    COMPONENTS.iter().copied().find(|root| {
+        base == *root
+            || base
+                .strip_prefix(root)
+                .is_some_and(|rest| rest.starts_with('-'))
+    })
+}
+
+pub fn analyze(html: &str) -> Footprint {
+    let mut components = Vec::new();
+    for class in classes(html) {
+        if let Some(root) = component_of(&class)
+            && !components.contains(&root)
+        {
+            components.push(root);
+        }
+    }
+
+    let nodes = elements(html).len();
+    let images = html.matches("<img").count() + html.matches("url(").count();
+
+    let mut scripts = Vec::new();
+    let palette = html.contains("data-palette-input");
+    if palette {
+        scripts.push("palette");
+    }
+    if !palette && html.contains("<dialog") {
+        scripts.push("modal");
+    }
+    if html.contains("data-tag-input") {
+        scripts.push("tag-input");
+    }
+    if html.contains("data-panel=") {
+        scripts.push("settings");
+    }
+    if html.contains("data-survey-next") {
+        scripts.push("survey");
+    }
+    let chart = html.contains("<canvas") || html.contains("new Chart(");
+    if chart {
+        scripts.push("chart");
+    }
+    if html.contains("<script") {
+        scripts.push("inline");
+    }
+
+    let mut cdn = BASE_CDN.to_vec();
+    if chart {
+        cdn.push(CHART_CDN);
+    }
+
+    Footprint {
+        components,
+        nodes,
+        images,
+        scripts,
+        cdn,
+    }
+}
+
+impl Footprint {
+    /// Short Markdown table, meant to follow the code block it describes.
+    pub fn markdown(&self) -> String {
+        let or_none = |items: &[&str]| {
+            if items.is_empty() {
+                "none".to_string()
+            } else {
+                items.join(", ")
+            }
+        };
+        format!(
+            "| Footprint | |\n|---|---|\n| daisyUI components ({}) | {} |\n| DOM nodes | ~{} |\n| Images | {} |\n| JavaScript | {} |\n| CDN (standalone page) | {} |",
+            self.components.len(),
+            or_none(&self.components),
+            self.nodes,
+            self.images,
+            or_none(&self.scripts),
+            self.cdn.join(", ")
+        )
+    }
+}
@@ -0,0 +1,598 @@
+//! HTML snippets converted for the frameworks people paste them into.
+//!
+//! Every target but HTML closes void elements (`<input />`), which JSX
+//! requires and Vue and Svelte templates prefer. JSX also renames attributes
+//! like `class` and `for`, turns `style` strings into objects and `on*`
+//! handlers into arrow functions, turns comments into `{/* */}`, escapes
+//! braces in text and leaves out inline scripts, which React wouldn't run.
+//!
+//! Leptos `view!` and Yew `html!` markup is read by a Rust macro, so text
+//! and attribute values become string literals (Yew wraps text in braces,
+//! `{ "Save" }`), entities are decoded, comments become `/* */` and inline
+//! `on*` handlers and scripts are left in comments to port to Rust. Yew
+//! gives bare attributes a value: `true` for boolean ones, else `""`.
+//! Other text and attribute values are copied as they are.
+
+use std::ops::Range;
+
+use crate::html::VOID;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Html,
+    Jsx,
+    Vue,
+    Svelte,
+    Leptos,
+    Yew,
+}
+
+impl Target {
+    pub const ALL: [Target; 6] = [
+        Target::Html,
+        Target::Jsx,
+        Target::Vue,
+        Target::Svelte,
+        Target::Leptos,
+        Target::Yew,
+    ];
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "html" => Some(Self::Html),
+            "jsx" | "react" | "tsx" => Some(Self::Jsx),
+            "vue" => Some(Self::Vue),
+            "svelte" => Some(Self::Svelte),
+            "leptos" => Some(Self::Leptos),
+            "yew" => Some(Self::Yew),
+            _ => None,
+        }
+    }
+
+    /// Name used in arguments and as the code fence language.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Html => "html",
+            Self::Jsx => "jsx",
+            Self::Vue => "vue",
+            Self::Svelte => "svelte",
+            Self::Leptos => "leptos",
+            Self::Yew => "yew",
+        }
+    }
+
+    /// Every target's name, for schemas and messages.
+    pub fn names() -> Vec<&'static str> {
+        Self::ALL.iter().map(|t| t.name()).collect()
+    }
+
+    /// Leptos and Yew, whose markup is Rust macro input.
+    fn is_rust(self) -> bool {
+        matches!(self, Self::Leptos | Self::Yew)
+    }
+}
+
+/// JSX spellings of HTML and SVG attributes.
+const JSX_ATTRIBUTES: &[(&str, &str)] = &[
+    ("class", "className"),
+    ("for", "htmlFor"),
+    ("tabindex", "tabIndex"),
+    ("autocomplete", "autoComplete"),
+    ("autofocus", "autoFocus"),
+    ("readonly", "readOnly"),
+    ("maxlength", "maxLength"),
+    ("colspan", "colSpan"),
+    ("rowspan", "rowSpan"),
+    ("novalidate", "noValidate"),
+    ("stroke-width", "strokeWidth"),
+    ("stroke-linecap", "strokeLinecap"),
+    ("stroke-linejoin", "strokeLinejoin"),
+    ("fill-rule", "fillRule"),
+    ("clip-rule", "clipRule"),
+];
+
+/// Attributes Yew types as `bool`, so a bare one is `true` rather than `""`.
+const BOOLEAN_ATTRIBUTES: &[&str] = &[
+    "allowfullscreen",
+    "async",
+    "autofocus",
+    "autoplay",
+    "checked",
+    "controls",
+    "default",
+    "defer",
+    "disabled",
+    "formnovalidate",
+    "hidden",
+    "inert",
+    "loop",
+    "multiple",
+    "muted",
+    "novalidate",
+    "open",
+    "readonly",
+    "required",
+    "reversed",
+    "selected",
+];
+
+/// Entities generated markup uses, decoded for Rust string literals.
+const ENTITIES: &[(&str, &str)] = &[
+    ("amp", "&"),
+    ("lt", "<"),
+    ("gt", ">"),
+    ("quot", "\""),
+    ("apos", "'"),
+    ("nbsp", "\u{a0}"),
+    ("ldquo", "\u{201c}"),
+    ("rdquo", "\u{201d}"),
+    ("lsquo", "\u{2018}"),
+    ("rsquo", "\u{2019}"),
+    ("mdash", "\u{2014}"),
+    ("ndash", "\u{2013}"),
+    ("hellip", "\u{2026}"),
+];
+
+/// Attributes of a start tag, `<` to `>`: each name as written, with the
+/// span of its value when it has one.
+pub fn attributes(tag: &str) -> Vec<(&str, Option<Range<usize>>)> {
+    let mut out = Vec::new();
+    let bytes = tag.as_bytes();
+    let mut i = tag[1..]
+        .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .map_or(tag.len(), |i| i + 1);
+    while i < tag.len() {
+        if bytes[i].is_ascii_whitespace() || matches!(bytes[i], b'/' | b'>') {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < tag.len()
+            && !bytes[i].is_ascii_whitespace()
+            && !matches!(bytes[i], b'=' | b'>' | b'/')
+        {
+            i += 1;
+        }
+        let name = &tag[start..i];
+        if bytes.get(i) != Some(&b'=') {
+            out.push((name, None));
+            continue;
+        }
+        i += 1;
+        let value = match bytes.get(i) {
+            Some(&q) if q == b'"' || q == b'\'' => {
+                let end = tag[i + 1..]
+                    .find(q as char)
+                    .map_or(tag.len(), |e| i + 1 + e);
+                let range = i + 1..end;
+                i = end + 1;
+                range
+            }
+            _ => {
+                let from = i;
+                while i < tag.len() && !bytes[i].is_ascii_whitespace() && bytes[i] != b'>' {
+                    i += 1;
+                }
+                from..i
+            }
+        };
+        out.push((name, Some(value)));
+    }
+    out
+}
+
+/// `text` with entities decoded: named ones from [`ENTITIES`] and numeric
+/// ones. Unknown entities are kept.
+pub fn decode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest[1..]
+            .find(';')
+            .filter(|&end| end <= 8)
+            .map(|end| &rest[1..end + 1]);
+        let decoded = entity.and_then(|name| match name.strip_prefix('#') {
+            Some(code) => match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                None => code.parse().ok(),
+            }
+            .and_then(char::from_u32)
+            .map(String::from),
+            None => ENTITIES
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, c)| c.to_string()),
+        });
+        match (entity, decoded) {
+            (Some(name), Some(decoded)) => {
+                out.push_str(&decoded);
+                rest = &rest[name.len() + 2..];
+            }
+            _ => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// A quoted Rust string literal.
+fn rust_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// A Rust block comment holding `text`.
+fn rust_comment(text: &str) -> String {
+    format!("/* {} */", text.trim().replace("*/", "* /"))
+}
+
+/// One start tag as Leptos or Yew markup.
+fn rust_tag(tag: &str, target: Target) -> String {
+    let name: String = tag[1..]
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .collect();
+    let closed = tag[..tag.len() - 1].trim_end().ends_with('/');
+    let mut out = format!("<{}", name);
+    for (attr, value) in attributes(tag) {
+        let value = value.map(|r| &tag[r]);
+        let handler = attr.len() > 2
+            && attr.starts_with("on")
+            && attr[2..].chars().all(|c| c.is_ascii_lowercase());
+        out.push(' ');
+        match value {
+            Some(value) if handler => {
+                out.push_str(&rust_comment(&format!("{}=\"{}\"", attr, value)));
+            }
+            Some(value) => {
+                out.push_str(&format!("{}={}", attr, rust_string(&decode(value))));
+            }
+            None if target == Target::Yew => {
+                let boolean = BOOLEAN_ATTRIBUTES.contains(&attr.to_ascii_lowercase().as_str());
+                out.push_str(&format!(
+                    "{}={}",
+                    attr,
+                    if boolean { "true" } else { "\"\"" }
+                ));
+            }
+            None => out.push_str(attr),
+        }
+    }
+    let void = VOID.contains(&name.to_lowercase().as_str());
+    out.push_str(if closed || void { " />" } else { ">" });
+    out
+}
+
+/// Text between tags as a Leptos or Yew string literal. Whitespace with a
+/// line break is layout, as in JSX; other whitespace is collapsed into the
+/// literal.
+fn rust_text(text: &str, target: Target) -> String {
+    if text.trim().is_empty() {
+        return text.to_string();
+    }
+    let trimmed = text.trim_start();
+    let lead = &text[..text.len() - trimmed.len()];
+    let trail = &trimmed[trimmed.trim_end().len()..];
+    let (lead, lead_space) = match lead.contains('\n') {
+        true => (lead, ""),
+        false => ("", if lead.is_empty() { "" } else { " " }),
+    };
+    let (trail, trail_space) = match trail.contains('\n') {
+        true => (trail, ""),
+        false => ("", if trail.is_empty() { "" } else { " " }),
+    };
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let literal = rust_string(&decode(&format!(
+        "{}{}{}",
+        lead_space,
+        words.join(" "),
+        trail_space
+    )));
+    match target {
+        Target::Yew => format!("{}{{ {} }}{}", lead, literal, trail),
+        _ => format!("{}{}{}", lead, literal, trail),
+    }
+}
+
+/// A quoted JavaScript string literal.
+fn js_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// `width: 40%; --value:70` as `{{ width: "40%", "--value": "70" }}`:
+/// properties in camelCase, custom properties quoted.
+fn style_object(css: &str) -> String {
+    let entries: Vec<String> = css
+        .split(';')
+        .filter_map(|decl| decl.split_once(':'))
+        .map(|(key, value)| {
+            let key = key.trim();
+            let key = if key.starts_with("--") {
+                js_string(key)
+            } else {
+                let mut camel = String::new();
+                let mut upper = false;
+                for c in key.chars() {
+                    match c {
+                        '-' => upper = true,
+                        c if upper => {
+                            camel.extend(c.to_uppercase());
+                            upper = false;
+                        }
+                        c => camel.push(c),
+                    }
+                }
+                camel
+            };
+            format!("{}: {}", key, js_string(value.trim()))
+        })
+        .collect();
+    format!("{{{{ {} }}}}", entries.join(", "))
+}
+
+/// A `style` or `on*` attribute at the start of `rest`, as JSX, with the
+/// length it spans; `None` for any other attribute.
+fn jsx_expression(rest: &str) -> Option<(usize, String)> {
+    let name: String = rest
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .collect();
+    let quote = rest[name.len()..].strip_prefix('=')?.chars().next()?;
+    if quote != '"' && quote != '\'' {
+        return None;
+    }
+    let start = name.len() + 2;
+    let len = rest[start..].find(quote)?;
+    let value = &rest[start..start + len];
+    let attribute = if name == "style" {
+        format!("style={}", style_object(value))
+    } else if let Some(event) = name
+        .strip_prefix("on")
+        .filter(|e| !e.is_empty() && e.chars().all(|c| c.is_ascii_lowercase()))
+    {
+        let body = value.trim().trim_end_matches(';');
+        let handler = if body.contains(';') {
+            format!("() => {{ {}; }}", body)
+        } else {
+            format!("() => {}", body)
+        };
+        format!(
+            "on{}{}={{{}}}",
+            event[..1].to_uppercase(),
+            &event[1..],
+            handler
+        )
+    } else {
+        return None;
+    };
+    Some((start + len + 1, attribute))
+}
+
+/// One start tag, `<` to `>`, rewritten for `target` (never HTML).
+fn tag(tag: &str, target: Target) -> String {
+    let inner = &tag[1..tag.len() - 1];
+    let closed = inner.trim_end().ends_with('/');
+    let inner = inner.trim_end().trim_end_matches('/').trim_end();
+    let name: String = inner
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .collect();
+    let mut out = String::from("<");
+    let mut rest = inner;
+    let mut quote = None;
+    // Attribute names start after whitespace outside quotes.
+    let mut at_name = false;
+    while let Some(c) = rest.chars().next() {
+        let expression = (quote.is_none() && at_name && target == Target::Jsx)
+            .then(|| jsx_expression(rest))
+            .flatten();
+        if let Some((len, attribute)) = expression {
+            out.push_str(&attribute);
+            rest = &rest[len..];
+            at_name = false;
+            continue;
+        }
+        let jsx = (quote.is_none() && at_name && target == Target::Jsx)
+            .then(|| {
+                JSX_ATTRIBUTES.iter().find(|(html, _)| {
+                    rest.strip_prefix(html).is_some_and(|after| {
+                        after.is_empty()
+                            || after.starts_with(['=', '/'])
+                            || after.starts_with(char::is_whitespace)
+                    })
+                })
+            })
+            .flatten();
+        if let Some((html, jsx)) = jsx {
+            out.push_str(jsx);
+            rest = &rest[html.len()..];
+            at_name = false;
+            continue;
+        }
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            _ => {}
+        }
+        at_name = quote.is_none() && c.is_whitespace();
+        out.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    let void = VOID.contains(&name.to_lowercase().as_str());
+    out.push_str(if closed || void { " />" } else { ">" });
+    out
+}
+
+/// Text between tags; JSX would read braces as expressions.
+fn text(text: &str, target: Target) -> String {
+    if target.is_rust() {
+        return rust_text(text, target);
+    }
+    if target != Target::Jsx {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '{' => out.push_str("{'{'}"),
+            '}' => out.push_str("{'}'}"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// `html` as `target` markup.
+pub fn convert(html: &str, target: Target) -> String {
+    if target == Target::Html {
+        return html.to_string();
+    }
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        out.push_str(&text(&rest[..start], target));
+        rest = &rest[start..];
+        if (target == Target::Jsx || target.is_rust()) && rest.starts_with("<script") {
+            let end = rest.find("</script>").map_or(rest.len(), |e| e + 9);
+            out.push_str(match target {
+                Target::Jsx => "{/* inline <script> left out: React doesn't run it */}",
+                _ => "/* inline <script> left out: port it to Rust or load it as a file */",
+            });
+            rest = &rest[end..];
+            continue;
+        }
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            let (body, after) = comment.split_once("-->").unwrap_or((comment, ""));
+            if target == Target::Jsx {
+                out.push_str(&format!("{{/*{}*/}}", body));
+            } else if target.is_rust() {
+                out.push_str(&rust_comment(body));
+            } else {
+                out.push_str(&format!("<!--{}-->", body));
+            }
+            rest = after;
+            continue;
+        }
+        if rest.starts_with("</") {
+            let end = rest.find('>').map_or(rest.len(), |e| e + 1);
+            out.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+        let starts_tag = rest[1..].starts_with(|c: char| c.is_ascii_alphabetic());
+        let end = starts_tag.then(|| tag_end(rest)).flatten();
+        match end {
+            Some(end) if target.is_rust() => {
+                out.push_str(&rust_tag(&rest[..=end], target));
+                rest = &rest[end + 1..];
+            }
+            Some(end) => {
+                out.push_str(&tag(&rest[..=end], target));
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('<');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(&text(rest, target));
+    out
+}
+
+/// `html` as JSX markup; see the module docs for what changes.
+pub fn html_to_jsx(html: &str) -> String {
+    convert(html, Target::Jsx)
+}
+
+/// `title` as a PascalCase component name: `my app` is `MyApp`. Names that
+/// would start with a digit, or be empty, get a `Layout` prefix.
+pub fn component_name(title: &str) -> String {
+    let name: String = decode(title)
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w[..1].to_uppercase() + &w[1..])
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name
+    } else {
+        format!("Layout{}", name)
+    }
+}
+
+/// `jsx` markup as an exported function component named `name`, inside a
+/// fragment so several roots are fine.
+pub fn component(name: &str, jsx: &str) -> String {
+    let body: Vec<String> = jsx
+        .trim()
+        .lines()
+        .map(|line| {
+            if line.trim().is_empty() {
+                String::new()
+            } else {
+                format!("      {}", line)
+            }
+        })
+        .collect();
+    format!(
+        "export default function {}() {{\n  return (\n    <>\n{}\n    </>\n  );\n}}\n",
+        name,
+        body.join("\n")
+    )
+}
+
+/// `markup` from [`convert`] as a Leptos `#[component]` or Yew
+/// `#[function_component]` named `name`. Yew's `html!` takes one root, so
+/// the markup sits in a fragment.
+pub fn rust_component(name: &str, markup: &str, target: Target) -> String {
+    let indent = if target == Target::Yew { 12 } else { 8 };
+    let body: Vec<String> = markup
+        .trim()
+        .lines()
+        .map(|line| match line.trim().is_empty() {
+            true => String::new(),
+            false => format!("{:indent$}{}", "", line),
+        })
+        .collect();
+    let body = body.join("\n");
+    match target {
+        Target::Yew => format!(
+            "use yew::prelude::*;\n\n#[function_component]\npub fn {}() -> Html {{\n    html! {{\n        <>\n{}\n        </>\n    }}\n}}\n",
+            name, body
+        ),
+        _ => format!(
+            "use leptos::prelude::*;\n\n#[component]\npub fn {}() -> impl IntoView {{\n    view! {{\n{}\n    }}\n}}\n",
+            name, body
+        ),
+    }
+}
+
+/// `html` as a JSX, Leptos or Yew component named after `title`. Other
+/// targets get the markup back unchanged.
+pub fn layout_component(html: &str, title: &str, target: Target) -> String {
+    let name = component_name(title);
+    match target {
+        Target::Jsx => component(&name, &html_to_jsx(html)),
+        Target::Leptos | Target::Yew => rust_component(&name, &convert(html, target), target),
+        _ => html.to_string(),
+    }
+}
+
+/// Index of the `>` closing the tag `text` starts with, skipping quoted
+/// attribute values.
+fn tag_end(text: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in text.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
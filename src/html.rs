@@ -0,0 +1,127 @@
+//! A forgiving HTML tokenizer for generated or pasted markup: the elements
+//! of a page with their classes and nesting, and the class names it uses.
+//! Nothing here knows about daisyUI.
+
+use std::collections::HashSet;
+
+/// Unique class names in order of first appearance.
+pub fn classes(html: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut classes = Vec::new();
+    let mut rest = html;
+    while let Some(pos) = rest.find("class=\"") {
+        rest = &rest[pos + 7..];
+        let end = rest.find('"').unwrap_or(rest.len());
+        for class in rest[..end].split_whitespace() {
+            if seen.insert(class) {
+                classes.push(class.to_string());
+            }
+        }
+        rest = &rest[end..];
+    }
+    classes
+}
+
+/// An element found by [`elements`]. `start..end` spans the opening tag
+/// through the closing tag (or just the opening tag for void elements).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Element {
+    pub tag: String,
+    pub classes: Vec<String>,
+    pub parent: Option<usize>,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Element {
+    pub fn has_class(&self, class: &str) -> bool {
+        self.classes.iter().any(|c| c == class)
+    }
+}
+
+/// Elements that never have children or a closing tag.
+pub const VOID: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Forgiving element parser for generated or pasted markup: comments and
+/// doctypes are skipped, `<script>`/`<style>` bodies are opaque, and a stray
+/// closing tag closes the nearest matching open element.
+pub fn elements(html: &str) -> Vec<Element> {
+    let mut out: Vec<Element> = Vec::new();
+    let mut open: Vec<usize> = Vec::new();
+    let mut i = 0;
+    while let Some(pos) = html[i..].find('<') {
+        let at = i + pos;
+        let rest = &html[at..];
+        if rest.starts_with("<!--") {
+            i = rest.find("-->").map_or(html.len(), |e| at + e + 3);
+            continue;
+        }
+        if rest.starts_with("<!") || rest.starts_with("<?") {
+            i = rest.find('>').map_or(html.len(), |e| at + e + 1);
+            continue;
+        }
+        if let Some(close) = rest.strip_prefix("</") {
+            let end = rest.find('>').map_or(html.len(), |e| at + e + 1);
+            let name = close
+                .split(|c: char| c == '>' || c.is_whitespace())
+                .next()
+                .unwrap_or_default()
+                .to_ascii_lowercase();
+            if let Some(depth) = open.iter().rposition(|&e| out[e].tag == name) {
+                for e in open.drain(depth..) {
+                    out[e].end = end;
+                }
+            }
+            i = end;
+            continue;
+        }
+        let name: String = rest[1..]
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == '-')
+            .collect::<String>()
+            .to_ascii_lowercase();
+        if name.is_empty() || !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            i = at + 1;
+            continue;
+        }
+        // Find the tag's closing '>' outside quoted attribute values.
+        let mut quote = None;
+        let mut tag_end = html.len();
+        for (j, c) in rest.char_indices().skip(1) {
+            match (quote, c) {
+                (Some(q), c) if c == q => quote = None,
+                (None, '"' | '\'') => quote = Some(c),
+                (None, '>') => {
+                    tag_end = at + j + 1;
+                    break;
+                }
+                _ => {}
+            }
+        }
+        let tag = &html[at..tag_end];
+        let self_closing = tag.ends_with("/>") || VOID.contains(&name.as_str());
+        out.push(Element {
+            classes: classes(tag),
+            parent: open.last().copied(),
+            start: at,
+            end: tag_end,
+            tag: name.clone(),
+        });
+        i = tag_end;
+        if self_closing {
+            continue;
+        }
+        if name == "script" || name == "style" {
+            let close = format!("</{}", name);
+            i = html[i..].find(&close).map_or(html.len(), |e| i + e);
+        }
+        open.push(out.len() - 1);
+    }
+    for e in open {
+        out[e].end = html.len();
+    }
+    out
+}
@@ -0,0 +1,662 @@
+//! Prompt → layout inference behind `daisyui_idea_to_ui` and `/daisy-idea`.
+//!
+//! Every layout is scored by the weights of its keywords that appear in the
+//! prompt and the highest total wins, so the decision can be shown (and
+//! tested) rather than hidden in an if-chain. A tie goes to the layout that
+//! matched more keywords, then to the one listed first; template layouts
+//! (see `plugins`) are listed after the built-ins.
+//!
+//! The title comes from the prompt too, by [`extract_title`]; without one,
+//! the winning layout's first keyword in the prompt is the title.
+
+use crate::theme;
+
+/// Weight of a keyword that names its layout outright (`kanban`, `login`).
+const NAMES: u32 = 3;
+/// Weight of a keyword that strongly suggests its layout (`sprint`, `cart`).
+const STRONG: u32 = 2;
+/// Weight of a common word that only leans one way (`board`, `post`).
+const WEAK: u32 = 1;
+
+/// Layouts and the weighted keywords that vote for them, in tie-break
+/// order. A keyword matches a whole word or its plural, so `task` matches
+/// `tasks` but `auth` doesn't match `author`; a keyword of several words
+/// matches them in a row, split like the prompt (`what's new` is `what`,
+/// `s`, `new`).
+pub const KEYWORDS: &[(&str, &[(&str, u32)])] = &[
+    (
+        "blog",
+        &[
+            ("blog", NAMES),
+            ("article", STRONG),
+            ("news", WEAK),
+            ("post", WEAK),
+        ],
+    ),
+    (
+        "social",
+        &[
+            ("social", NAMES),
+            ("social network", STRONG),
+            ("twitter", NAMES),
+            ("feed", STRONG),
+            ("followers", STRONG),
+        ],
+    ),
+    (
+        "forum",
+        &[
+            ("forum", NAMES),
+            ("community", STRONG),
+            ("discussion", STRONG),
+            ("threads", STRONG),
+        ],
+    ),
+    (
+        "kanban",
+        &[
+            ("kanban", NAMES),
+            ("trello", NAMES),
+            ("board", WEAK),
+            ("task", STRONG),
+            ("sprint", STRONG),
+        ],
+    ),
+    (
+        "inbox",
+        &[
+            ("mail", STRONG),
+            ("inbox", NAMES),
+            ("message", WEAK),
+            ("email", STRONG),
+        ],
+    ),
+    (
+        "profile",
+        &[
+            ("profile", NAMES),
+            ("account", WEAK),
+            ("preferences", STRONG),
+        ],
+    ),
+    ("settings", &[("settings", NAMES)]),
+    (
+        "docs",
+        &[
+            ("docs", NAMES),
+            ("documentation", NAMES),
+            ("wiki", STRONG),
+            ("handbook", STRONG),
+            ("guide", WEAK),
+        ],
+    ),
+    (
+        "pricing",
+        &[
+            ("pricing", NAMES),
+            ("plans", STRONG),
+            ("subscription", STRONG),
+            ("tiers", STRONG),
+        ],
+    ),
+    (
+        "app-landing",
+        &[
+            ("mobile app", NAMES),
+            ("ios", STRONG),
+            ("android", STRONG),
+            ("app landing", NAMES),
+        ],
+    ),
+    (
+        "saas",
+        &[("saas", NAMES), ("startup", STRONG), ("landing", WEAK)],
+    ),
+    (
+        "crud",
+        &[
+            ("crud", NAMES),
+            ("admin table", STRONG),
+            ("manage", WEAK),
+            ("records", STRONG),
+        ],
+    ),
+    ("dashboard", &[("dashboard", NAMES), ("admin", WEAK)]),
+    (
+        "analytics",
+        &[
+            ("analytics", NAMES),
+            ("metrics", STRONG),
+            ("report", WEAK),
+            ("insights", STRONG),
+        ],
+    ),
+    (
+        "auth",
+        &[
+            ("login", NAMES),
+            ("log in", NAMES),
+            ("signin", NAMES),
+            ("sign in", NAMES),
+            ("signup", NAMES),
+            ("sign up", NAMES),
+            ("register", NAMES),
+            ("registration", STRONG),
+            ("auth", NAMES),
+            ("password", STRONG),
+        ],
+    ),
+    (
+        "store",
+        &[
+            ("store", STRONG),
+            ("shop", NAMES),
+            ("ecommerce", NAMES),
+            ("e-commerce", NAMES),
+            ("product", STRONG),
+            ("catalog", STRONG),
+        ],
+    ),
+    (
+        "checkout",
+        &[
+            ("checkout", NAMES),
+            ("cart", STRONG),
+            ("payment", STRONG),
+            ("purchase", WEAK),
+        ],
+    ),
+    (
+        "error",
+        &[
+            ("404", NAMES),
+            ("not found", STRONG),
+            ("error page", NAMES),
+            ("maintenance", STRONG),
+        ],
+    ),
+    (
+        "status",
+        &[
+            ("status page", NAMES),
+            ("uptime", STRONG),
+            ("incidents", STRONG),
+            ("outage", STRONG),
+        ],
+    ),
+    (
+        "media",
+        &[
+            ("music", STRONG),
+            ("player", WEAK),
+            ("spotify", NAMES),
+            ("media", STRONG),
+            ("podcast", STRONG),
+        ],
+    ),
+    (
+        "jobs",
+        &[
+            ("jobs", NAMES),
+            ("careers", NAMES),
+            ("hiring", STRONG),
+            ("listings", WEAK),
+            ("marketplace", WEAK),
+        ],
+    ),
+    (
+        "changelog",
+        &[
+            ("changelog", NAMES),
+            ("release notes", NAMES),
+            ("what's new", STRONG),
+            ("versions", WEAK),
+        ],
+    ),
+    (
+        "team",
+        &[
+            ("team", WEAK),
+            ("about us", NAMES),
+            ("company page", STRONG),
+            ("who we are", STRONG),
+        ],
+    ),
+    (
+        "faq",
+        &[
+            ("faq", NAMES),
+            ("help center", NAMES),
+            ("support", WEAK),
+            ("knowledge base", STRONG),
+        ],
+    ),
+    (
+        "survey",
+        &[
+            ("survey", NAMES),
+            ("quiz", NAMES),
+            ("questionnaire", NAMES),
+            ("poll", STRONG),
+        ],
+    ),
+];
+
+/// Weight of each keyword of a template layout.
+const TEMPLATE: u32 = STRONG;
+
+pub const DEFAULT_LAYOUT: &str = "saas";
+/// The title when the prompt names nothing and no keyword matched.
+pub const DEFAULT_TITLE: &str = "Generated UI";
+
+/// Tone → theme it implies → words that signal it.
+const TONES: &[(&str, &str, &[&str])] = &[
+    (
+        "playful",
+        "cupcake",
+        &["fun", "playful", "kids", "cute", "colorful"],
+    ),
+    (
+        "professional",
+        "corporate",
+        &["enterprise", "professional", "b2b", "finance", "legal"],
+    ),
+    ("moody", "dark", &["gaming", "moody", "nightlife", "hacker"]),
+    (
+        "retro",
+        "synthwave",
+        &["neon", "80s", "vaporwave", "arcade"],
+    ),
+    ("calm", "nord", &["calm", "minimal", "clean", "simple"]),
+    ("natural", "forest", &["nature", "eco", "outdoor", "plants"]),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Score {
+    pub layout: String,
+    /// Keywords found in the prompt, with their weights.
+    pub matched: Vec<(String, u32)>,
+}
+
+impl Score {
+    pub fn total(&self) -> u32 {
+        self.matched.iter().map(|(_, w)| w).sum()
+    }
+}
+
+/// Everything the engine inferred from a prompt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Idea {
+    /// One entry per layout, in [`KEYWORDS`] order, then extra layouts.
+    pub scores: Vec<Score>,
+    pub layout: String,
+    pub title: String,
+    pub tone: Option<&'static str>,
+    pub theme: Option<&'static str>,
+    pub decision: String,
+}
+
+fn words(prompt: &str) -> Vec<String> {
+    prompt
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Whether prompt word `word` is `keyword` or its plural: `task` or `tasks`,
+/// `box` or `boxes`, `story` or `stories`.
+fn is_form_of(word: &str, keyword: &str) -> bool {
+    let Some(suffix) = word.strip_prefix(keyword) else {
+        return keyword
+            .strip_suffix('y')
+            .and_then(|stem| word.strip_prefix(stem))
+            == Some("ies");
+    };
+    matches!(suffix, "" | "s" | "es")
+}
+
+/// Words that end a name given after "called", "named" or "titled".
+const NAME_ENDS: &[&str] = &[
+    "about",
+    "and",
+    "but",
+    "featuring",
+    "for",
+    "in",
+    "on",
+    "or",
+    "so",
+    "that",
+    "to",
+    "using",
+    "where",
+    "which",
+    "who",
+    "with",
+];
+
+/// Lowercase words allowed inside a proper name: `Bank of Ayr`.
+const JOINERS: &[&str] = &["&", "and", "de", "of", "the"];
+
+/// Words skipped before a name after "for": `for the Acme team`.
+const DETERMINERS: &[&str] = &["a", "an", "my", "our", "the", "their", "your"];
+
+/// The longest names kept, in characters.
+const MAX_TITLE: usize = 60;
+
+/// `word` with its first letter capitalized, unless it has capitals.
+fn capitalize(word: &str) -> String {
+    if word.chars().any(char::is_uppercase) {
+        return word.to_string();
+    }
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn title_case(text: &str) -> String {
+    text.split_whitespace()
+        .map(capitalize)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The first text between quotes (`"…"`, `“…”` or `'…'`) that starts and
+/// ends at word boundaries, so the apostrophe in "what's" doesn't open one.
+fn quoted(prompt: &str) -> Option<&str> {
+    let outside = |c: Option<char>| c.is_none_or(|c| !c.is_alphanumeric());
+    for (open, close) in [('"', '"'), ('“', '”'), ('\'', '\'')] {
+        for (at, _) in prompt.match_indices(open) {
+            if !outside(prompt[..at].chars().next_back()) {
+                continue;
+            }
+            let start = at + open.len_utf8();
+            let found = prompt[start..].match_indices(close).find(|(i, _)| {
+                let after = start + i + close.len_utf8();
+                *i > 0 && outside(prompt[after..].chars().next())
+            });
+            if let Some((i, _)) = found {
+                return Some(&prompt[start..start + i]);
+            }
+        }
+    }
+    None
+}
+
+/// `token` without the punctuation around it, and whether punctuation
+/// after it ends a phrase. Markup such as `<b>` is part of the word.
+fn bare(token: &str) -> (&str, bool) {
+    let word = token.trim_matches(|c: char| !c.is_alphanumeric() && !matches!(c, '&' | '<' | '>'));
+    let ends = token
+        .trim_end_matches(['"', '\'', '”', '’'])
+        .ends_with([',', '.', ';', ':', '!', '?', ')']);
+    (word, ends)
+}
+
+fn proper(word: &str) -> bool {
+    word.chars()
+        .next()
+        .is_some_and(|c| c.is_uppercase() || c.is_ascii_digit())
+}
+
+/// The run of capitalized words `tokens` starts with, allowing
+/// [`JOINERS`] between them: `Acme Rockets`, `Bank of Ayr`.
+fn proper_run(tokens: &[&str]) -> Option<String> {
+    let mut run: Vec<&str> = Vec::new();
+    let mut joiners: Vec<&str> = Vec::new();
+    for token in tokens {
+        let (word, ends) = bare(token);
+        if proper(word) {
+            run.append(&mut joiners);
+            run.push(word);
+            if ends {
+                break;
+            }
+        } else if !run.is_empty() && JOINERS.contains(&word) && !ends {
+            joiners.push(word);
+        } else {
+            break;
+        }
+    }
+    (!run.is_empty()).then(|| run.join(" "))
+}
+
+/// Up to six words from the start of `tokens`, ending before a word of
+/// [`NAME_ENDS`] or after punctuation: the name after "called".
+fn loose_run(tokens: &[&str]) -> Option<String> {
+    let mut run = Vec::new();
+    for token in tokens.iter().take(6) {
+        let (word, ends) = bare(token);
+        if word.is_empty() || NAME_ENDS.contains(&word.to_lowercase().as_str()) {
+            break;
+        }
+        run.push(word);
+        if ends {
+            break;
+        }
+    }
+    (!run.is_empty()).then(|| title_case(&run.join(" ")))
+}
+
+/// `title` on one line without quotes or backticks, trimmed to
+/// [`MAX_TITLE`] at a word boundary; `None` when nothing is left. Markup
+/// characters stay: layouts escape the title when they render it.
+fn sanitize(title: &str) -> Option<String> {
+    let kept: String = title
+        .chars()
+        .filter(|c| !matches!(c, '`' | '"' | '“' | '”'))
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect();
+    let mut out = String::new();
+    for word in kept.split_whitespace() {
+        if out.chars().count() + word.chars().count() + 1 > MAX_TITLE {
+            break;
+        }
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        out.push_str(word);
+    }
+    let out = out
+        .trim_start_matches(|c: char| !c.is_alphanumeric() && c != '<')
+        .trim_end_matches([',', '.', ';', ':', '-', ' ']);
+    (!out.is_empty()).then(|| out.to_string())
+}
+
+/// The name a prompt gives its app, sanitized: quoted text, else the words
+/// after "called", "named" or "titled", else the capitalized words after
+/// "for", else a capitalized phrase ending the prompt (not its first word).
+/// `None` when the prompt names nothing.
+pub fn extract_title(prompt: &str) -> Option<String> {
+    if let Some(title) = quoted(prompt).and_then(sanitize) {
+        return Some(title);
+    }
+    let tokens: Vec<&str> = prompt.split_whitespace().collect();
+    let after = |keys: &[&str], run: &dyn Fn(&[&str]) -> Option<String>| {
+        tokens.iter().enumerate().find_map(|(i, token)| {
+            let (word, ends) = bare(token);
+            if ends || !keys.contains(&word.to_lowercase().as_str()) {
+                return None;
+            }
+            run(&tokens[i + 1..]).and_then(|t| sanitize(&t))
+        })
+    };
+    if let Some(title) = after(&["called", "named", "titled"], &loose_run) {
+        return Some(title);
+    }
+    let for_name = |rest: &[&str]| {
+        let skip = rest
+            .iter()
+            .take_while(|t| DETERMINERS.contains(&bare(t).0.to_lowercase().as_str()))
+            .count();
+        proper_run(&rest[skip..])
+    };
+    if let Some(title) = after(&["for"], &for_name) {
+        return Some(title);
+    }
+    // The last capitalized run, if nothing follows it.
+    let mut start = tokens.len();
+    while start > 1 {
+        let (word, ends) = bare(tokens[start - 1]);
+        if start < tokens.len() && ends {
+            break;
+        }
+        if proper(word) || (start < tokens.len() && JOINERS.contains(&word)) {
+            start -= 1;
+        } else {
+            break;
+        }
+    }
+    while start < tokens.len() && !proper(bare(tokens[start]).0) {
+        start += 1;
+    }
+    let run = proper_run(&tokens[start..])?;
+    (run.split(' ').count() == tokens.len() - start)
+        .then(|| sanitize(&run))
+        .flatten()
+}
+
+/// Scores `prompt` against [`KEYWORDS`] and then the `extra` template
+/// layouts' `(layout, keywords)` entries.
+pub fn explain(prompt: &str, extra: &[(String, Vec<String>)]) -> Idea {
+    let prompt_words = words(prompt);
+    // Where `keyword` first appears, in prompt words.
+    let at = |keyword: &str| {
+        let parts = words(keyword);
+        prompt_words
+            .windows(parts.len().max(1))
+            .position(|run| run.iter().zip(&parts).all(|(w, p)| is_form_of(w, p)))
+    };
+    let hit = |keyword: &str| at(keyword).is_some();
+    let score = |layout: &str, keywords: &mut dyn Iterator<Item = (&str, u32)>| Score {
+        layout: layout.to_string(),
+        matched: keywords
+            .filter(|(k, _)| hit(k))
+            .map(|(k, w)| (k.to_string(), w))
+            .collect(),
+    };
+
+    let scores: Vec<Score> = KEYWORDS
+        .iter()
+        .map(|(layout, keywords)| score(layout, &mut keywords.iter().copied()))
+        .chain(extra.iter().map(|(layout, keywords)| {
+            score(layout, &mut keywords.iter().map(|k| (k.as_str(), TEMPLATE)))
+        }))
+        .collect();
+    let best = scores.iter().map(Score::total).max().unwrap_or(0);
+    let tied: Vec<&Score> = scores.iter().filter(|s| s.total() == best).collect();
+    let most = tied.iter().map(|s| s.matched.len()).max().unwrap_or(0);
+    let leaders: Vec<&Score> = tied
+        .iter()
+        .copied()
+        .filter(|s| s.matched.len() == most)
+        .collect();
+    let others = |leader: &Score| {
+        tied.iter()
+            .filter(|s| s.layout != leader.layout)
+            .map(|s| format!("`{}`", s.layout))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let (layout, decision) = if best == 0 {
+        (
+            DEFAULT_LAYOUT.to_string(),
+            format!(
+                "no keywords matched, so `{}` is the default",
+                DEFAULT_LAYOUT
+            ),
+        )
+    } else if leaders.len() > 1 {
+        (
+            leaders[0].layout.clone(),
+            format!(
+                "`{}` ties with {} at {} with as many keywords and is listed first",
+                leaders[0].layout,
+                others(leaders[0]),
+                best
+            ),
+        )
+    } else if tied.len() > 1 {
+        (
+            leaders[0].layout.clone(),
+            format!(
+                "`{}` ties with {} at {} and matched more keywords ({})",
+                leaders[0].layout,
+                others(leaders[0]),
+                best,
+                most
+            ),
+        )
+    } else {
+        (
+            leaders[0].layout.clone(),
+            format!("`{}` has the highest score ({})", leaders[0].layout, best),
+        )
+    };
+
+    let tone = TONES
+        .iter()
+        .find(|(_, _, signals)| signals.iter().any(|s| hit(s)));
+    let named = theme::BUILT_IN
+        .iter()
+        .copied()
+        .find(|t| prompt_words.iter().any(|w| w == t));
+
+    let title = extract_title(prompt).unwrap_or_else(|| {
+        scores
+            .iter()
+            .find(|s| s.layout == layout)
+            .and_then(|s| s.matched.iter().min_by_key(|(k, _)| at(k)))
+            .map_or_else(|| DEFAULT_TITLE.to_string(), |(k, _)| title_case(k))
+    });
+
+    Idea {
+        scores,
+        layout,
+        title,
+        tone: tone.map(|(name, _, _)| *name),
+        theme: named.or(tone.map(|(_, theme, _)| *theme)),
+        decision,
+    }
+}
+
+impl Idea {
+    /// Score table, each keyword with its weight, followed by the inferred
+    /// options and the decision.
+    pub fn markdown(&self) -> String {
+        let or_none = |v: Option<&str>| v.map_or("none".to_string(), |v| format!("`{}`", v));
+        let mut out = String::from("| Layout | Matched keywords | Score |\n|---|---|---|\n");
+        for s in &self.scores {
+            let marker = if s.layout == self.layout { " ✓" } else { "" };
+            let matched = if s.matched.is_empty() {
+                "—".to_string()
+            } else {
+                s.matched
+                    .iter()
+                    .map(|(k, w)| format!("{} ({})", k, w))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            out.push_str(&format!(
+                "| {}{} | {} | {} |\n",
+                s.layout,
+                marker,
+                matched,
+                s.total()
+            ));
+        }
+        out.push_str(&format!(
+            "\n- **Title:** {}\n- **Theme:** {}\n- **Tone:** {}\n- **Decision:** `{}`: {}",
+            self.title,
+            or_none(self.theme),
+            or_none(self.tone),
+            self.layout,
+            self.decision
+        ));
+        out
+    }
+}
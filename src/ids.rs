@@ -0,0 +1,56 @@
+//! Deterministic element ids for generated markup.
+//!
+//! Hardcoded ids collide when two snippets land on one page, and random ones
+//! break snapshot diffs. Each generation call gets its own allocator, so the
+//! same call always yields the same ids (`dd-drawer-1`, `dd-chart-2`), and a
+//! caller-chosen prefix keeps separately generated snippets apart.
+
+pub const DEFAULT_PREFIX: &str = "dd";
+
+#[derive(Debug, Clone)]
+pub struct IdAllocator {
+    prefix: String,
+    counts: Vec<(String, usize)>,
+}
+
+impl Default for IdAllocator {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl IdAllocator {
+    /// `prefix` is reduced to `[A-Za-z0-9_-]`; empty or missing falls back
+    /// to [`DEFAULT_PREFIX`].
+    pub fn new(prefix: Option<&str>) -> Self {
+        let prefix: String = prefix
+            .unwrap_or_default()
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+            .take(32)
+            .collect();
+        Self {
+            prefix: if prefix.is_empty() {
+                DEFAULT_PREFIX.to_string()
+            } else {
+                prefix
+            },
+            counts: Vec::new(),
+        }
+    }
+
+    /// Next id for `kind`: `<prefix>-<kind>-<n>`, counting from 1 per kind.
+    pub fn next(&mut self, kind: &str) -> String {
+        let n = match self.counts.iter_mut().find(|(k, _)| k == kind) {
+            Some((_, n)) => {
+                *n += 1;
+                *n
+            }
+            None => {
+                self.counts.push((kind.to_string(), 1));
+                1
+            }
+        };
+        format!("{}-{}-{}", self.prefix, kind, n)
+    }
+}
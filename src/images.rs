@@ -0,0 +1,195 @@
+//! Image placeholders for generated layouts: `--placeholders` on
+//! `/daisy-layout` and `placeholders` on `daisyui_scaffold_layout`.
+//!
+//! Builders point `<img>` tags at picsum.photos and img.daisyui.com, which
+//! needs a network connection. [`substitute`] swaps each one for a local
+//! path, an inline SVG or a daisyUI skeleton of the same size. `<figure>`
+//! wrappers are kept as they are, so cards keep their shape around the
+//! replacement.
+
+/// Values accepted for `placeholders`; `remote` keeps the stock photos.
+pub const STRATEGIES: &[&str] = &["remote", "local", "svg", "none"];
+
+/// Size assumed for an image whose URL and classes don't give one.
+const DEFAULT_SIZE: (u32, u32) = (800, 600);
+
+/// The value of `name` in a tag, e.g. `attr(r#"<img src="a.png">"#, "src")`.
+fn attr<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let key = format!(" {}=\"", name);
+    let start = tag.find(&key)? + key.len();
+    let end = tag[start..].find('"')? + start;
+    Some(&tag[start..end])
+}
+
+/// `tag` with `name` set to `value`: in place when it has the attribute,
+/// else added at the end.
+fn set_attr(tag: &str, name: &str, value: &str) -> String {
+    let key = format!(" {}=\"", name);
+    if let Some(start) = tag.find(&key).map(|i| i + key.len()) {
+        let end = tag[start..].find('"').map_or(tag.len(), |e| start + e);
+        return format!("{}{}{}", &tag[..start], value, &tag[end..]);
+    }
+    let close = tag
+        .strip_suffix("/>")
+        .map(|t| t.trim_end().len())
+        .unwrap_or(tag.len() - 1);
+    format!("{} {}=\"{}\"{}", &tag[..close], name, value, &tag[close..])
+}
+
+/// Pixel size a placeholder service encodes in its URL: picsum's
+/// `/800/600` or `/100` (square), placehold.co's `/400x300`.
+fn url_size(src: &str) -> Option<(u32, u32)> {
+    let path = src.split(['?', '#']).next().unwrap_or(src);
+    let numbers: Vec<u32> = path
+        .split('/')
+        .rev()
+        .take(2)
+        .map_while(|s| s.parse().ok())
+        .collect();
+    match numbers.as_slice() {
+        [h, w] => Some((*w, *h)),
+        [side] => Some((*side, *side)),
+        _ => {
+            let (w, h) = path.rsplit('/').next()?.split_once('x')?;
+            Some((w.parse().ok()?, h.parse().ok()?))
+        }
+    }
+}
+
+/// Pixels for a spacing class like `w-32` (Tailwind's 4px steps).
+fn class_px(classes: &[&str], prefix: &str) -> Option<u32> {
+    classes
+        .iter()
+        .find_map(|c| c.strip_prefix(prefix)?.parse::<u32>().ok())
+        .map(|n| n * 4)
+}
+
+/// The image's size: from its URL, else its `w-`/`h-` classes, else
+/// [`DEFAULT_SIZE`].
+fn size(src: &str, classes: &[&str]) -> (u32, u32) {
+    if let Some(size) = url_size(src) {
+        return size;
+    }
+    match (class_px(classes, "w-"), class_px(classes, "h-")) {
+        (Some(w), Some(h)) => (w, h),
+        (Some(side), None) | (None, Some(side)) => (side, side),
+        (None, None) => DEFAULT_SIZE,
+    }
+}
+
+/// A gray `width`×`height` SVG with its dimensions written in the middle,
+/// as a `data:` URI safe to put in a double-quoted attribute.
+pub fn svg_uri(width: u32, height: u32) -> String {
+    let svg = format!(
+        "<svg xmlns='http://www.w3.org/2000/svg' width='{w}' height='{h}' viewBox='0 0 {w} {h}'><rect width='100%' height='100%' fill='#d1d5db'/><text x='50%' y='50%' dominant-baseline='middle' text-anchor='middle' font-family='sans-serif' font-size='{size}' fill='#4b5563'>{w} × {h}</text></svg>",
+        w = width,
+        h = height,
+        size = (width.min(height) / 8).clamp(10, 48)
+    );
+    let mut uri = String::from("data:image/svg+xml,");
+    for byte in svg.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' => uri.push(byte as char),
+            b'-' | b'.' | b'_' | b'~' | b'/' | b':' | b'=' | b'\'' | b',' | b';' => {
+                uri.push(byte as char)
+            }
+            _ => uri.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    uri
+}
+
+/// A lowercase file name from alt text: `Team photo` → `team-photo`.
+fn slug(text: &str) -> String {
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect();
+    words.join("-")
+}
+
+/// A skeleton `<div>` standing in for `tag`. It keeps the image's classes
+/// apart from `object-*` and adds whichever of width and height is missing,
+/// computed from the image's ratio so the skeleton has the same shape.
+fn skeleton(tag: &str, (width, height): (u32, u32)) -> String {
+    let classes: Vec<&str> = attr(tag, "class")
+        .unwrap_or_default()
+        .split_whitespace()
+        .filter(|c| !c.starts_with("object-"))
+        .collect();
+    let has = |prefix: &str| classes.iter().any(|c| c.starts_with(prefix));
+    let mut sized = classes.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+    match (has("w-"), has("h-")) {
+        (true, true) => {}
+        (false, false) => {
+            sized.push(format!("w-[{}px]", width));
+            sized.push(format!("h-[{}px]", height));
+        }
+        (true, false) => sized.push(match class_px(&classes, "w-") {
+            Some(w) => format!("h-[{}px]", w * height / width.max(1)),
+            None => format!("aspect-[{}/{}]", width, height),
+        }),
+        (false, true) => sized.push(match class_px(&classes, "h-") {
+            Some(h) => format!("w-[{}px]", h * width / height.max(1)),
+            None => "w-full".into(),
+        }),
+    }
+    format!(
+        "<div class=\"skeleton {}\" aria-hidden=\"true\"></div>",
+        sized.join(" ")
+    )
+}
+
+/// `html` with every `<img>` replaced by `strategy`: `local` points it at
+/// `/images/<alt-or-image-n>.jpg`, `svg` at an inline gray rectangle, both
+/// with `width` and `height` attributes; `none` swaps it for a skeleton.
+/// `remote` and unknown strategies return `html` unchanged.
+pub fn substitute(html: &str, strategy: &str) -> String {
+    if !matches!(strategy, "local" | "svg" | "none") {
+        return html.to_string();
+    }
+    let mut out = String::with_capacity(html.len());
+    let mut names: Vec<String> = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find("<img") {
+        let Some(len) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = &rest[start..start + len + 1];
+        out.push_str(&rest[..start]);
+        let classes: Vec<&str> = attr(tag, "class")
+            .unwrap_or_default()
+            .split_whitespace()
+            .collect();
+        let (width, height) = size(attr(tag, "src").unwrap_or_default(), &classes);
+        let src = match strategy {
+            "local" => {
+                let base = match slug(attr(tag, "alt").unwrap_or_default()) {
+                    alt if alt.is_empty() => format!("image-{}", names.len() + 1),
+                    alt => alt,
+                };
+                let taken = names.iter().filter(|n| **n == base).count();
+                let name = match taken {
+                    0 => base.clone(),
+                    n => format!("{}-{}", base, n + 1),
+                };
+                names.push(base);
+                Some(format!("/images/{}.jpg", name))
+            }
+            "svg" => Some(svg_uri(width, height)),
+            _ => None,
+        };
+        match src {
+            Some(src) => {
+                let tag = set_attr(tag, "src", &src);
+                let tag = set_attr(&tag, "width", &width.to_string());
+                out.push_str(&set_attr(&tag, "height", &height.to_string()));
+            }
+            None => out.push_str(&skeleton(tag, (width, height))),
+        }
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,423 @@
+//! Word index over component docs, shared by the Zed extension and the MCP
+//! server for `search`.
+//!
+//! Only prose and class names are indexed: tag and attribute names, URLs,
+//! code fence markers, bare numbers and common English words are dropped
+//! first.
+//!
+//! Each term maps to the components that use it and how often, so a doc
+//! that repeats a word fifty times holds one entry for it, and the count can
+//! still weigh in the ranking. Mentions are weighted by TF-IDF: a word that
+//! every component uses, like "class", adds nothing, while a rare one such
+//! as "rotate" counts for a lot.
+//!
+//! Rankings are cached per query, so paging through results or repeating a
+//! search doesn't score every component again. The cache lives with the
+//! index: docs read from a project file get a new index and an empty cache.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+/// Words this short aren't indexed; class names like `btn` just make it.
+const MIN_LEN: usize = 3;
+
+/// Common words that say nothing about a component.
+const STOPWORDS: &[&str] = &[
+    "the", "and", "with", "your", "you", "for", "are", "can", "that", "this", "from", "has",
+    "have", "but", "not", "its", "into", "any", "etc", "was", "will", "when", "which", "there",
+    "their", "them", "then", "than", "also", "only", "more", "other", "some", "such", "about",
+    "how", "now", "here", "use", "used", "using", "way", "like",
+];
+
+/// Score for the component a query word is an alias of, e.g. modal for
+/// "dialog"; enough to put it first.
+const ALIAS: usize = 1000;
+/// Score for a component whose name contains the query.
+const NAME: usize = 100;
+/// Score for a component whose content contains the whole query.
+const PHRASE: usize = 10;
+/// Score per occurrence of a query word, multiplied by its inverse document
+/// frequency.
+const PER_MENTION: f64 = 5.0;
+
+/// Results per page when the caller doesn't ask for a number.
+pub const PAGE_SIZE: usize = 20;
+
+/// Rankings kept before the least recently used one is dropped.
+pub const CACHE_SIZE: usize = 64;
+
+type Ranking = Arc<Vec<(String, usize)>>;
+
+/// Recently ranked queries, most recent last. Shared through `Arc` by the
+/// MCP server, hence the lock; a clone starts empty.
+#[derive(Debug, Default)]
+struct Rankings(Mutex<VecDeque<(Query, Ranking)>>);
+
+impl Clone for Rankings {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Index {
+    terms: HashMap<String, HashMap<String, usize>>,
+    /// Components indexed, for inverse document frequency.
+    components: usize,
+    /// Alias key → component, from [`alias_key`].
+    aliases: HashMap<String, String>,
+    rankings: Rankings,
+}
+
+/// Lowercase words split at anything but letters, digits and inner hyphens,
+/// so "`button`," gives "button" and `class="btn btn-primary"` gives
+/// "class", "btn" and "btn-primary".
+fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric() && c != '-')
+        .map(|w| w.trim_matches('-').to_lowercase())
+        .filter(|w| !w.is_empty())
+}
+
+/// Index form of a lowercase word: a plural "s" or "es" comes off, so
+/// "buttons" and "button" share a bucket, as do "classes" and "class".
+/// Words ending "ss", "us" or "is" keep theirs.
+fn stem(word: &str) -> String {
+    if word.chars().count() <= MIN_LEN {
+        return word.to_string();
+    }
+    for end in ["sses", "xes", "zes", "ches", "shes"] {
+        if word.ends_with(end) {
+            return word[..word.len() - 2].to_string();
+        }
+    }
+    match word.strip_suffix('s') {
+        Some(_) if ["ss", "us", "is"].iter().any(|e| word.ends_with(e)) => word.to_string(),
+        Some(base) => base.to_string(),
+        None => word.to_string(),
+    }
+}
+
+/// [`words`] in index form.
+fn terms(text: &str) -> impl Iterator<Item = String> + '_ {
+    words(text).map(|w| stem(&w))
+}
+
+/// `text` without URLs, code fence lines and HTML markup. Quoted attribute
+/// values stay, since they hold the class names; tag and attribute names go.
+fn strip_markup(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for line in text.lines().filter(|l| !l.trim_start().starts_with("```")) {
+        let mut chars = line.chars().peekable();
+        let mut in_tag = false;
+        while let Some(c) = chars.next() {
+            match c {
+                '<' if chars
+                    .peek()
+                    .is_some_and(|n| n.is_ascii_alphabetic() || *n == '/' || *n == '!') =>
+                {
+                    in_tag = true;
+                    out.push(' ');
+                }
+                '>' if in_tag => {
+                    in_tag = false;
+                    out.push(' ');
+                }
+                '"' | '\'' if in_tag => {
+                    out.push(' ');
+                    for v in chars.by_ref() {
+                        if v == c {
+                            break;
+                        }
+                        out.push(v);
+                    }
+                    out.push(' ');
+                }
+                _ if in_tag => {}
+                _ => out.push(c),
+            }
+        }
+        out.push('\n');
+    }
+    while let Some(at) = out.find("://") {
+        let start = out[..at]
+            .rfind(|c: char| !c.is_ascii_alphabetic())
+            .map_or(0, |i| i + 1);
+        let end = out[at..]
+            .find(|c: char| c.is_whitespace() || "()[]<>\"'".contains(c))
+            .map_or(out.len(), |i| at + i);
+        out.replace_range(start..end, " ");
+    }
+    out
+}
+
+/// Normal form of an alias or a name looked up as one: lowercase words
+/// joined by hyphens, so "Button Group" and "button_group" both give
+/// "button-group".
+pub fn alias_key(text: &str) -> String {
+    words(text)
+        .flat_map(|w| {
+            w.split('-')
+                .filter(|p| !p.is_empty())
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Lowercase with runs of whitespace, line breaks included, collapsed to
+/// one space, so phrases match across wrapped lines.
+fn flatten(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// A parsed search query: plain terms, `"quoted phrases"` that must appear
+/// contiguously, and `-term` exclusions that drop any component containing
+/// them. A quote without a partner is kept as a literal character.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Query {
+    pub terms: Vec<String>,
+    pub phrases: Vec<String>,
+    pub excluded: Vec<String>,
+}
+
+impl Query {
+    pub fn parse(text: &str) -> Self {
+        let mut query = Self::default();
+        let mut rest = text.trim();
+        while !rest.is_empty() {
+            let negated = rest.starts_with('-');
+            let body = if negated { &rest[1..] } else { rest };
+            let quoted = body
+                .strip_prefix('"')
+                .and_then(|after| Some((after, after.find('"')?)));
+            let (token, next) = match quoted {
+                Some((after, close)) => (&after[..close], &after[close + 1..]),
+                None => {
+                    let end = body.find(char::is_whitespace).unwrap_or(body.len());
+                    (&body[..end], &body[end..])
+                }
+            };
+            let token = flatten(token);
+            if !token.is_empty() {
+                match (negated, quoted.is_some()) {
+                    (true, _) => query.excluded.push(token),
+                    (false, true) => query.phrases.push(token),
+                    (false, false) => query.terms.push(token),
+                }
+            }
+            rest = next.trim_start();
+        }
+        query
+    }
+
+    /// The terms and phrases to look for, for highlighting snippets.
+    pub fn highlight(&self) -> String {
+        self.terms
+            .iter()
+            .chain(&self.phrases)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl Index {
+    /// Indexes `(component, content)` pairs.
+    pub fn build<'a>(docs: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        let mut index = Self::default();
+        for (component, content) in docs {
+            index.components += 1;
+            let prose = strip_markup(content);
+            for word in terms(&prose)
+                .filter(|w| w.chars().count() >= MIN_LEN && !STOPWORDS.contains(&w.as_str()))
+                .filter(|w| !w.chars().all(|c| c.is_ascii_digit()))
+            {
+                *index
+                    .terms
+                    .entry(word)
+                    .or_default()
+                    .entry(component.to_string())
+                    .or_default() += 1;
+            }
+        }
+        index
+    }
+
+    /// Lets queries for an alias find its component: `aliases` maps
+    /// [`alias_key`]s to component names.
+    pub fn with_aliases(mut self, aliases: HashMap<String, String>) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// `(alias, component)` for each alias among the query's words, or the
+    /// plain words taken together.
+    pub fn aliased(&self, query: &Query) -> Vec<(String, String)> {
+        let plain = query.terms.join(" ");
+        let mut found: Vec<(String, String)> = std::iter::once(plain.as_str())
+            .chain(query.terms.iter().map(String::as_str))
+            .filter_map(|text| {
+                let key = alias_key(text);
+                let target = self.aliases.get(&key)?;
+                Some((key, target.clone()))
+            })
+            .collect();
+        found.dedup_by(|a, b| a.1 == b.1);
+        found
+    }
+
+    /// Index bucket for a query word, e.g. "`Buttons`" → "button".
+    fn bucket(&self, term: &str) -> Option<&HashMap<String, usize>> {
+        self.terms.get(&terms(term).next()?)
+    }
+
+    /// How many times `component` uses `term`, in any case or plural form.
+    pub fn frequency(&self, term: &str, component: &str) -> usize {
+        self.bucket(term)
+            .and_then(|counts| counts.get(component))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// `ln(components / components using term)`: 0 for a word every
+    /// component uses, larger the rarer it is, and 0 for unknown words.
+    pub fn idf(&self, term: &str) -> f64 {
+        match self.bucket(term).map(HashMap::len) {
+            Some(used) if used > 0 => (self.components as f64 / used as f64).ln(),
+            _ => 0.0,
+        }
+    }
+
+    /// Distinct terms stored.
+    #[allow(dead_code)] // measured by tests; neither crate reports it
+    pub fn vocabulary(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Distinct (term, component) pairs stored.
+    #[allow(dead_code)] // measured by tests; neither crate reports it
+    pub fn postings(&self) -> usize {
+        self.terms.values().map(HashMap::len).sum()
+    }
+
+    /// Components matching `query`, best first (ties by name). A name match
+    /// counts most, then the plain words or a phrase appearing contiguously
+    /// in the content, and every mention of a query word adds its TF-IDF
+    /// weight. A component the query names by an alias comes first.
+    /// A query with only exclusions lists every component that survives
+    /// them, by name.
+    pub fn rank(
+        &self,
+        query: &Query,
+        components: &HashMap<String, String>,
+    ) -> Vec<(String, usize)> {
+        // Stray punctuation, like an unpaired quote, doesn't stop the plain
+        // words matching as a run.
+        let plain = words(&query.terms.join(" ")).collect::<Vec<_>>().join(" ");
+        let stemmed = terms(&plain).collect::<Vec<_>>().join(" ");
+        let positive = !plain.is_empty() || !query.phrases.is_empty();
+        if !positive && query.excluded.is_empty() {
+            return Vec::new();
+        }
+        let words: Vec<String> = query
+            .terms
+            .iter()
+            .chain(&query.phrases)
+            .flat_map(|t| words(t).collect::<Vec<_>>())
+            .collect();
+        let aliased: Vec<String> = self.aliased(query).into_iter().map(|(_, c)| c).collect();
+        let mut ranked: Vec<(String, usize)> = components
+            .iter()
+            .filter_map(|(name, content)| {
+                let content = flatten(content);
+                let has = |text: &str| name.contains(text) || content.contains(text);
+                if query.excluded.iter().any(|t| has(t)) {
+                    return None;
+                }
+                if !query.phrases.iter().all(|p| has(p)) {
+                    return None;
+                }
+                let mut score = 0;
+                if aliased.contains(name) {
+                    score += ALIAS;
+                }
+                if !plain.is_empty() && (name.contains(&plain) || name.contains(&stemmed)) {
+                    score += NAME;
+                }
+                if !plain.is_empty() && content.contains(&plain) {
+                    score += PHRASE;
+                }
+                for phrase in &query.phrases {
+                    if name.contains(phrase) {
+                        score += NAME;
+                    }
+                    if content.contains(phrase) {
+                        score += PHRASE;
+                    }
+                }
+                let mentions: f64 = words
+                    .iter()
+                    .map(|w| self.frequency(w, name) as f64 * self.idf(w))
+                    .sum();
+                score += (PER_MENTION * mentions).round() as usize;
+                (score > 0 || !positive).then(|| (name.clone(), score))
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        ranked
+    }
+
+    /// [`Self::rank`], from the cache when the same query was ranked
+    /// recently. `components` must be the ones the index was built from.
+    fn cached_rank(&self, query: &Query, components: &HashMap<String, String>) -> Ranking {
+        // A poisoned lock only costs the cache; rank without it.
+        let Ok(mut cache) = self.rankings.0.lock() else {
+            return Arc::new(self.rank(query, components));
+        };
+        if let Some(at) = cache.iter().position(|(q, _)| q == query) {
+            let entry = cache.remove(at).unwrap_or_default();
+            let ranking = Arc::clone(&entry.1);
+            cache.push_back(entry);
+            return ranking;
+        }
+        let ranking = Arc::new(self.rank(query, components));
+        if cache.len() == CACHE_SIZE {
+            cache.pop_front();
+        }
+        cache.push_back((query.clone(), Arc::clone(&ranking)));
+        ranking
+    }
+
+    /// Rankings currently cached.
+    #[allow(dead_code)] // measured by tests; neither crate reports it
+    pub fn cached(&self) -> usize {
+        self.rankings.0.lock().map_or(0, |cache| cache.len())
+    }
+
+    /// `limit` matches starting at `offset`, plus the total match count. An
+    /// offset past the end gives an empty page.
+    pub fn page(
+        &self,
+        query: &Query,
+        components: &HashMap<String, String>,
+        limit: usize,
+        offset: usize,
+    ) -> (Vec<(String, usize)>, usize) {
+        let ranked = self.cached_rank(query, components);
+        let page = ranked.iter().skip(offset).take(limit).cloned().collect();
+        (page, ranked.len())
+    }
+}
+
+/// Where a page sits among all matches, e.g. "Showing 21–30 of 47".
+pub fn showing(offset: usize, shown: usize, total: usize) -> String {
+    if shown == 0 {
+        format!("No results at offset {}; {} in total", offset, total)
+    } else {
+        format!("Showing {}–{} of {}", offset + 1, offset + shown, total)
+    }
+}
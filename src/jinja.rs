@@ -0,0 +1,761 @@
+//! Askama and Tera templates made from generated layouts, for
+//! server-rendered Rust apps. Both engines read the same Jinja-style syntax,
+//! so only the comment describing the context differs.
+//!
+//! Sibling elements with the same structure (cards in a grid, table rows,
+//! menu items) collapse into a `{% for %}` loop over the first of them. Text
+//! and attribute values that differ between them become fields of the loop
+//! variable, named after their table column or the element holding them.
+//! Text matching the title becomes `{{ title }}`.
+
+use std::collections::HashMap;
+
+use crate::frameworks;
+use crate::html::{self, Element};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Engine {
+    Askama,
+    Tera,
+}
+
+impl Engine {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "askama" => Some(Self::Askama),
+            "tera" => Some(Self::Tera),
+            _ => None,
+        }
+    }
+}
+
+/// Field names for text, by the class of the element holding it.
+const CLASS_LABELS: &[(&str, &str)] = &[
+    ("card-title", "title"),
+    ("stat-title", "title"),
+    ("footer-title", "title"),
+    ("menu-title", "title"),
+    ("stat-value", "value"),
+    ("stat-desc", "description"),
+    ("badge", "label"),
+    ("label-text", "label"),
+    ("link", "label"),
+];
+
+/// Loop item names, by the class of the repeated element.
+const CLASS_NOUNS: &[(&str, &str)] = &[
+    ("card", "card"),
+    ("badge", "badge"),
+    ("stat", "stat"),
+    ("collapse", "panel"),
+    ("form-control", "field"),
+    ("chat", "message"),
+];
+
+/// SVG parts repeat with the same attributes but are drawing, not data.
+const SVG: &[&str] = &[
+    "svg", "path", "circle", "rect", "line", "polyline", "polygon", "g", "stop",
+];
+
+/// Rust keywords, which Askama fields can't be named.
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while",
+];
+
+/// Opens and closes a reference to a field while the template is built; its
+/// name is only settled at the end.
+const MARK: (char, char) = ('\u{1}', '\u{2}');
+
+/// A value that differs between the items of a loop, or a nested list.
+struct Field {
+    name: String,
+    /// The value in each item, to spot attributes repeating another field.
+    values: Vec<String>,
+    /// How telling the name is: text beats attributes, which beat
+    /// attributes named after a keyword.
+    rank: u8,
+    /// The list's scope when the field is a list.
+    list: Option<usize>,
+}
+
+/// The context (scope 0) or the item of one loop.
+struct Scope {
+    var: String,
+    parent: Option<usize>,
+    fields: Vec<Field>,
+}
+
+struct Builder<'a> {
+    html: &'a str,
+    title: &'a str,
+    elements: Vec<Element>,
+    children: Vec<Vec<usize>>,
+    /// Siblings with the same key can share a loop: tag, first class,
+    /// attribute names and children's keys, with runs counted once.
+    keys: Vec<String>,
+    /// Field names for table cells, from the header cell above them.
+    columns: HashMap<usize, String>,
+    scopes: Vec<Scope>,
+}
+
+/// `html` as an `engine` template; see the module docs. `title` is the text
+/// that becomes `{{ title }}`.
+pub fn template(html: &str, title: &str, engine: Engine) -> String {
+    let mut builder = Builder::new(html, title.trim());
+    let roots: Vec<usize> = (0..builder.elements.len())
+        .filter(|&e| builder.elements[e].parent.is_none())
+        .collect();
+    let body = builder.content(&[(0, html.len())], &[roots], 0, None);
+    format!("{}\n{}", builder.context(engine), builder.resolve(&body))
+}
+
+impl<'a> Builder<'a> {
+    fn new(html: &'a str, title: &'a str) -> Self {
+        let elements = html::elements(html);
+        let mut children = vec![Vec::new(); elements.len()];
+        for (i, e) in elements.iter().enumerate() {
+            if let Some(parent) = e.parent {
+                children[parent].push(i);
+            }
+        }
+        let mut builder = Self {
+            html,
+            title,
+            keys: vec![String::new(); elements.len()],
+            columns: HashMap::new(),
+            elements,
+            children,
+            scopes: vec![Scope {
+                var: String::new(),
+                parent: None,
+                fields: Vec::new(),
+            }],
+        };
+        // Children come after their parent, so their keys are ready first.
+        for e in (0..builder.elements.len()).rev() {
+            let mut names: Vec<String> = frameworks::attributes(builder.open_tag(e))
+                .into_iter()
+                .map(|(name, _)| name.to_ascii_lowercase())
+                .collect();
+            names.sort();
+            let kids: Vec<String> = builder
+                .groups(&builder.children[e])
+                .iter()
+                .map(|g| builder.keys[g[0]].clone())
+                .collect();
+            let element = &builder.elements[e];
+            builder.keys[e] = format!(
+                "{}.{}[{}]({})",
+                element.tag,
+                element.classes.first().map_or("", String::as_str),
+                names.join(" "),
+                kids.join(",")
+            );
+        }
+        builder.columns = builder.columns();
+        builder
+    }
+
+    fn open_end(&self, e: usize) -> usize {
+        let element = &self.elements[e];
+        let mut quote = None;
+        for (i, c) in self.html[element.start..element.end].char_indices() {
+            match (quote, c) {
+                (None, '"' | '\'') => quote = Some(c),
+                (Some(q), _) if q == c => quote = None,
+                (None, '>') => return element.start + i + 1,
+                _ => {}
+            }
+        }
+        element.end
+    }
+
+    fn open_tag(&self, e: usize) -> &'a str {
+        &self.html[self.elements[e].start..self.open_end(e)]
+    }
+
+    /// Where the closing tag starts, or the end for elements without one.
+    fn close_start(&self, e: usize) -> usize {
+        let element = &self.elements[e];
+        let open = self.open_end(e);
+        let span = &self.html[open..element.end];
+        match span.rfind("</") {
+            Some(i) if span[i + 2..].to_ascii_lowercase().starts_with(&element.tag) => open + i,
+            _ => element.end,
+        }
+    }
+
+    /// `kids` split into runs of same-key siblings with only whitespace or
+    /// comments between them.
+    fn groups(&self, kids: &[usize]) -> Vec<Vec<usize>> {
+        let mut out: Vec<Vec<usize>> = Vec::new();
+        for &kid in kids {
+            if let Some(run) = out.last_mut()
+                && let Some(&prev) = run.last()
+                && self.keys[prev] == self.keys[kid]
+                && !SVG.contains(&self.elements[kid].tag.as_str())
+                && blank(&self.html[self.elements[prev].end..self.elements[kid].start])
+            {
+                run.push(kid);
+                continue;
+            }
+            out.push(vec![kid]);
+        }
+        out
+    }
+
+    /// Header text for every body cell of a table with a `<thead>`.
+    fn columns(&self) -> HashMap<usize, String> {
+        let inside = |e: usize, tag: &str| {
+            let mut at = self.elements[e].parent;
+            while let Some(a) = at {
+                if self.elements[a].tag == tag {
+                    return Some(a);
+                }
+                at = self.elements[a].parent;
+            }
+            None
+        };
+        let cells = |row: usize| {
+            self.children[row]
+                .iter()
+                .copied()
+                .filter(|&c| matches!(self.elements[c].tag.as_str(), "th" | "td"))
+        };
+        let rows = (0..self.elements.len()).filter(|&e| self.elements[e].tag == "tr");
+        let mut headers: HashMap<usize, Vec<String>> = HashMap::new();
+        for row in rows.clone().filter(|&r| inside(r, "thead").is_some()) {
+            if let Some(table) = inside(row, "table") {
+                headers.entry(table).or_insert_with(|| {
+                    cells(row)
+                        .map(|c| {
+                            slug(&strip_tags(
+                                &self.html[self.open_end(c)..self.close_start(c)],
+                            ))
+                        })
+                        .collect()
+                });
+            }
+        }
+        let mut out = HashMap::new();
+        for row in rows.filter(|&r| inside(r, "thead").is_none()) {
+            let Some(header) = inside(row, "table").and_then(|t| headers.get(&t)) else {
+                continue;
+            };
+            for (cell, name) in cells(row).zip(header) {
+                if !name.is_empty() {
+                    out.insert(cell, name.clone());
+                }
+            }
+        }
+        out
+    }
+
+    /// The markup between `spans`, one per instance of the same structure,
+    /// whose child elements are `kids`.
+    fn content(
+        &mut self,
+        spans: &[(usize, usize)],
+        kids: &[Vec<usize>],
+        scope: usize,
+        owner: Option<usize>,
+    ) -> String {
+        let html = self.html;
+        let groups: Vec<Vec<Vec<usize>>> = kids.iter().map(|k| self.groups(k)).collect();
+        let count = groups[0].len();
+        let mut out = String::new();
+        for slot in 0..=count {
+            let texts: Vec<&str> = spans
+                .iter()
+                .zip(&groups)
+                .map(|(&(start, end), runs)| {
+                    let from = match slot {
+                        0 => start,
+                        _ => runs[slot - 1]
+                            .last()
+                            .map_or(start, |&e| self.elements[e].end),
+                    };
+                    let to = runs
+                        .get(slot)
+                        .map_or(end, |run| self.elements[run[0]].start);
+                    &html[from.min(to)..to]
+                })
+                .collect();
+            out.push_str(&self.text(&texts, scope, owner));
+            if slot < count {
+                let items: Vec<usize> = groups.iter().flat_map(|g| g[slot].clone()).collect();
+                let text = if groups.iter().all(|g| g[slot].len() == 1) {
+                    self.element(&items, scope)
+                } else {
+                    self.repeat(items, scope)
+                };
+                out.push_str(&text);
+            }
+        }
+        out
+    }
+
+    /// `items`, elements with the same key, as one element.
+    fn element(&mut self, items: &[usize], scope: usize) -> String {
+        let first = items[0];
+        let mut out = self.start_tag(items, scope);
+        let open = self.open_end(first);
+        if open < self.elements[first].end {
+            let spans: Vec<(usize, usize)> = items
+                .iter()
+                .map(|&e| (self.open_end(e), self.close_start(e)))
+                .collect();
+            let kids: Vec<Vec<usize>> = items.iter().map(|&e| self.children[e].clone()).collect();
+            out.push_str(&self.content(&spans, &kids, scope, Some(first)));
+            out.push_str(&self.html[self.close_start(first)..self.elements[first].end]);
+        }
+        out
+    }
+
+    /// The first item's start tag, with attribute values that differ
+    /// between `items` as fields.
+    fn start_tag(&mut self, items: &[usize], scope: usize) -> String {
+        let tags: Vec<&str> = items.iter().map(|&e| self.open_tag(e)).collect();
+        let parsed: Vec<Vec<_>> = tags.iter().map(|t| frameworks::attributes(t)).collect();
+        let mut out = String::new();
+        let mut at = 0;
+        for (name, range) in &parsed[0] {
+            let Some(range) = range else {
+                continue;
+            };
+            let name = name.to_ascii_lowercase();
+            let values: Vec<&str> = tags
+                .iter()
+                .zip(&parsed)
+                .map(|(tag, attrs)| {
+                    attrs
+                        .iter()
+                        .find(|(n, _)| n.eq_ignore_ascii_case(&name))
+                        .and_then(|(_, r)| r.clone())
+                        .map_or("", |r| &tag[r])
+                })
+                .collect();
+            if values.iter().all(|v| *v == values[0]) {
+                continue;
+            }
+            let (prefix, suffix) = match name.as_str() {
+                "src" | "href" => (0, 0),
+                "class" => common(&values, false),
+                _ => common(&values, true),
+            };
+            let base = match (name.as_str(), self.label(items[0])) {
+                ("src", _) => "image".to_string(),
+                ("href", _) => "url".to_string(),
+                ("class", label) if matches!(label.as_str(), "label" | "text" | "value") => {
+                    "class".to_string()
+                }
+                ("class", label) => format!("{}_class", label),
+                (other, _) => other.replace('-', "_"),
+            };
+            let values = values
+                .iter()
+                .map(|v| v[prefix..v.len() - suffix].to_string())
+                .collect();
+            out.push_str(&tags[0][at..range.start + prefix]);
+            out.push_str(&format!(
+                "{{{{ {} }}}}",
+                self.field(scope, &base, values, false)
+            ));
+            at = range.end - suffix;
+        }
+        out.push_str(&tags[0][at..]);
+        out
+    }
+
+    /// `items` as one `{% for %}` loop over a new list in `scope`.
+    fn repeat(&mut self, items: Vec<usize>, scope: usize) -> String {
+        let noun = self.noun(items[0]);
+        let mut var = noun.to_string();
+        let mut n = 1;
+        while self.chain(scope).any(|s| self.scopes[s].var == var) {
+            n += 1;
+            var = format!("{}_{}", noun, n);
+        }
+        let name = self.unique(scope, &format!("{}s", noun));
+        let inner = self.scopes.len();
+        self.scopes.push(Scope {
+            var: var.clone(),
+            parent: Some(scope),
+            fields: Vec::new(),
+        });
+        let fields = &mut self.scopes[scope].fields;
+        fields.push(Field {
+            name,
+            values: Vec::new(),
+            rank: 0,
+            list: Some(inner),
+        });
+        let list = mark(scope, fields.len() - 1);
+        let body = self.element(&items, inner);
+        let start = self.elements[items[0]].start;
+        let line = &self.html[self.html[..start].rfind('\n').map_or(0, |i| i + 1)..start];
+        if line.trim().is_empty() {
+            format!(
+                "{{% for {} in {} %}}\n{}{}\n{}{{% endfor %}}",
+                var, list, line, body, line
+            )
+        } else {
+            format!("{{% for {} in {} %}}{}{{% endfor %}}", var, list, body)
+        }
+    }
+
+    /// Text between elements, one per instance: as it is when they match,
+    /// otherwise a field named after `owner`.
+    fn text(&mut self, texts: &[&str], scope: usize, owner: Option<usize>) -> String {
+        let first = texts[0];
+        if texts.iter().all(|t| t.trim() == first.trim()) {
+            let verbatim =
+                owner.is_some_and(|e| matches!(self.elements[e].tag.as_str(), "script" | "style"));
+            return self.literal(first, verbatim);
+        }
+        let trimmed = first.trim_start();
+        let lead = &first[..first.len() - trimmed.len()];
+        let trail = &trimmed[trimmed.trim_end().len()..];
+        let base = owner.map_or_else(|| "text".to_string(), |e| self.label(e));
+        let values = texts.iter().map(|t| t.trim().to_string()).collect();
+        let field = self.field(scope, &base, values, true);
+        format!("{}{{{{ {} }}}}{}", lead, field, trail)
+    }
+
+    /// Text copied into the template: the title becomes `{{ title }}`, and
+    /// text an engine would read as a tag is kept raw.
+    fn literal(&mut self, text: &str, verbatim: bool) -> String {
+        if ["{{", "{%", "{#"].iter().any(|d| text.contains(d)) {
+            return format!("{{% raw %}}{}{{% endraw %}}", text);
+        }
+        if verbatim || self.title.is_empty() {
+            return text.to_string();
+        }
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(i) = rest.find(self.title) {
+            let after = &rest[i + self.title.len()..];
+            let word = !rest[..i].ends_with(char::is_alphanumeric)
+                && !after.starts_with(char::is_alphanumeric);
+            out.push_str(&rest[..i]);
+            if word {
+                out.push_str("{{ title }}");
+                if !self.scopes[0].fields.iter().any(|f| f.name == "title") {
+                    self.scopes[0].fields.push(Field {
+                        name: "title".into(),
+                        values: Vec::new(),
+                        rank: 2,
+                        list: None,
+                    });
+                }
+            } else {
+                out.push_str(self.title);
+            }
+            rest = after;
+        }
+        out.push_str(rest);
+        out
+    }
+
+    /// A reference to the field of `scope` holding `values`: an existing
+    /// one with the same values, or a new one named after `base`. The more
+    /// telling name wins when an attribute repeats another field.
+    fn field(&mut self, scope: usize, base: &str, values: Vec<String>, from_text: bool) -> String {
+        let rank = match (from_text, KEYWORDS.contains(&base)) {
+            (true, _) => 2,
+            (false, false) => 1,
+            (false, true) => 0,
+        };
+        let existing = self.scopes[scope]
+            .fields
+            .iter()
+            .position(|f| f.list.is_none() && f.values == values);
+        if let Some(i) = existing {
+            if rank > self.scopes[scope].fields[i].rank {
+                let name = self.unique(scope, base);
+                let field = &mut self.scopes[scope].fields[i];
+                field.name = name;
+                field.rank = rank;
+            }
+            return mark(scope, i);
+        }
+        let name = self.unique(scope, base);
+        let fields = &mut self.scopes[scope].fields;
+        fields.push(Field {
+            name,
+            values,
+            rank,
+            list: None,
+        });
+        mark(scope, fields.len() - 1)
+    }
+
+    /// `base`, or `base_2` and on when `scope` already has it.
+    fn unique(&self, scope: usize, base: &str) -> String {
+        let base = if KEYWORDS.contains(&base) {
+            format!("{}_", base)
+        } else {
+            base.to_string()
+        };
+        let taken = |name: &str| self.scopes[scope].fields.iter().any(|f| f.name == name);
+        let mut name = base.clone();
+        let mut n = 1;
+        while taken(&name) {
+            n += 1;
+            name = format!("{}_{}", base, n);
+        }
+        name
+    }
+
+    /// `scope` and the scopes around it.
+    fn chain(&self, scope: usize) -> impl Iterator<Item = usize> + '_ {
+        std::iter::successors(Some(scope), |&s| self.scopes[s].parent)
+    }
+
+    /// Field name for text in `e`: its table column, else what its class or
+    /// tag says it holds.
+    fn label(&self, e: usize) -> String {
+        let mut at = Some(e);
+        while let Some(a) = at {
+            if let Some(column) = self.columns.get(&a) {
+                return column.clone();
+            }
+            at = self.elements[a].parent;
+        }
+        let element = &self.elements[e];
+        if let Some((_, name)) = CLASS_LABELS.iter().find(|(c, _)| element.has_class(c)) {
+            return name.to_string();
+        }
+        match element.tag.as_str() {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "header" | "legend" => "title",
+            "a" | "button" | "label" | "option" | "span" | "summary" => "label",
+            "img" => "image",
+            "figcaption" => "caption",
+            "time" => "date",
+            "td" | "th" => "value",
+            _ => "text",
+        }
+        .to_string()
+    }
+
+    /// Loop item name for a run starting with `e`.
+    fn noun(&self, e: usize) -> &'static str {
+        let element = &self.elements[e];
+        let has_link = self.elements[e + 1..]
+            .iter()
+            .take_while(|d| d.start < element.end)
+            .any(|d| d.tag == "a");
+        match element.tag.as_str() {
+            "tr" => "row",
+            "th" => "column",
+            "option" => "choice",
+            "a" => "link",
+            "nav" => "section",
+            "img" | "figure" => "image",
+            "button" => "button",
+            "article" => "post",
+            "li" if has_link => "link",
+            _ => CLASS_NOUNS
+                .iter()
+                .find(|(c, _)| element.has_class(c))
+                .map_or("item", |(_, noun)| noun),
+        }
+    }
+
+    /// `body` with field references written out.
+    fn resolve(&self, body: &str) -> String {
+        let mut out = String::with_capacity(body.len());
+        let mut rest = body;
+        while let Some(start) = rest.find(MARK.0) {
+            out.push_str(&rest[..start]);
+            let Some(end) = rest[start..].find(MARK.1) else {
+                break;
+            };
+            let (scope, field) = rest[start + 1..start + end]
+                .split_once(':')
+                .and_then(|(s, f)| Some((s.parse::<usize>().ok()?, f.parse::<usize>().ok()?)))
+                .unwrap_or_default();
+            let name = &self.scopes[scope].fields[field].name;
+            match scope {
+                0 => out.push_str(name),
+                _ => out.push_str(&format!("{}.{}", self.scopes[scope].var, name)),
+            }
+            rest = &rest[start + end + 1..];
+        }
+        out.push_str(rest);
+        out
+    }
+
+    /// The comment opening the template, listing the context it reads.
+    fn context(&self, engine: Engine) -> String {
+        match engine {
+            Engine::Tera => {
+                let lines: Vec<String> = self.scopes[0]
+                    .fields
+                    .iter()
+                    .map(|f| format!("    {}: {}", f.name, self.tera_type(f)))
+                    .collect();
+                if lines.is_empty() {
+                    return "{# Tera template with no context: nothing in it changes. #}".into();
+                }
+                format!(
+                    "{{#\n  Tera context for this template:\n{}\n#}}",
+                    lines.join("\n")
+                )
+            }
+            Engine::Askama => {
+                let root = frameworks::component_name(self.title);
+                let path = match slug(&frameworks::decode(self.title)) {
+                    s if s.is_empty() => "layout".to_string(),
+                    s => s,
+                };
+                // Item structs shouldn't shadow the prelude.
+                let mut names: Vec<String> = ["Option", "Result", "String", "Vec", "Box"]
+                    .map(String::from)
+                    .to_vec();
+                names.push(root.clone());
+                let mut structs = Vec::new();
+                self.askama_struct(0, root, &mut names, &mut structs);
+                format!(
+                    "{{#\n  Askama context for this template:\n\n  #[derive(Template)]\n  #[template(path = \"{}.html\")]\n{}#}}",
+                    path,
+                    structs.join("\n")
+                )
+            }
+        }
+    }
+
+    fn tera_type(&self, field: &Field) -> String {
+        let Some(list) = field.list else {
+            return "string".into();
+        };
+        let fields: Vec<String> = self.scopes[list]
+            .fields
+            .iter()
+            .map(|f| match f.list {
+                Some(_) => format!("{}: {}", f.name, self.tera_type(f)),
+                None => f.name.clone(),
+            })
+            .collect();
+        format!("list of {{ {} }}", fields.join(", "))
+    }
+
+    /// The struct for `scope`, named `name`, then those of its lists.
+    fn askama_struct(
+        &self,
+        scope: usize,
+        name: String,
+        names: &mut Vec<String>,
+        structs: &mut Vec<String>,
+    ) {
+        let fields = &self.scopes[scope].fields;
+        let mut lines = Vec::new();
+        let mut lists = Vec::new();
+        for field in fields {
+            let ty = match field.list {
+                Some(list) => {
+                    let base = frameworks::component_name(&self.scopes[list].var);
+                    let mut item = base.clone();
+                    let mut n = 1;
+                    while names.contains(&item) {
+                        n += 1;
+                        item = format!("{}{}", base, n);
+                    }
+                    names.push(item.clone());
+                    lists.push((list, item.clone()));
+                    format!("Vec<{}>", item)
+                }
+                None => "String".into(),
+            };
+            lines.push(format!("      {}: {},\n", field.name, ty));
+        }
+        structs.push(match lines.is_empty() {
+            true => format!("  struct {};\n", name),
+            false => format!("  struct {} {{\n{}  }}\n", name, lines.concat()),
+        });
+        for (list, item) in lists {
+            self.askama_struct(list, item, names, structs);
+        }
+    }
+}
+
+fn mark(scope: usize, field: usize) -> String {
+    format!("{}{}:{}{}", MARK.0, scope, field, MARK.1)
+}
+
+/// Whitespace and comments only.
+fn blank(text: &str) -> bool {
+    let mut rest = text;
+    while let Some(start) = rest.find("<!--") {
+        if !rest[..start].trim().is_empty() {
+            return false;
+        }
+        rest = rest[start..]
+            .find("-->")
+            .map_or("", |e| &rest[start + e + 3..]);
+    }
+    rest.trim().is_empty()
+}
+
+/// Bytes every value starts and ends with, cut back to whitespace (or a
+/// hyphen, with `hyphens`) so a field holds whole words: `Select ` for
+/// `Select Ada` and `Select Grace`, or `dd-` for `dd-name` and `dd-email`.
+fn common(values: &[&str], hyphens: bool) -> (usize, usize) {
+    let first = values[0];
+    let boundary = |c: char| c.is_whitespace() || (hyphens && c == '-');
+    let mut prefix = 0;
+    for (i, c) in first.char_indices() {
+        let end = i + c.len_utf8();
+        if !values.iter().all(|v| v.get(..end) == Some(&first[..end])) {
+            break;
+        }
+        if boundary(c) {
+            prefix = end;
+        }
+    }
+    let shortest = values.iter().map(|v| v.len()).min().unwrap_or(0);
+    let mut suffix = 0;
+    for (i, c) in first.char_indices().rev() {
+        let len = first.len() - i;
+        if prefix + len > shortest || !values.iter().all(|v| v.ends_with(&first[i..])) {
+            break;
+        }
+        if boundary(c) {
+            suffix = len;
+        }
+    }
+    (prefix, suffix)
+}
+
+/// Text with tags removed.
+fn strip_tags(html: &str) -> String {
+    let mut out = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    out
+}
+
+/// `Last updated` as `last_updated`.
+fn slug(text: &str) -> String {
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect();
+    let slug = words.join("_");
+    if slug.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("field_{}", slug)
+    } else {
+        slug
+    }
+}
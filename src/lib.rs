@@ -1,4 +1,50 @@
+mod a11y;
+mod cache;
+mod chunk;
+mod complete;
+mod concepts;
+mod docs;
+mod document;
+mod footprint;
+mod frameworks;
+mod html;
+mod idea;
+mod ids;
+mod images;
+mod index;
+mod jinja;
+mod output;
+mod playground;
+mod plugins;
+mod project;
+mod purity;
+mod responsive;
+mod reverse;
+mod rtl;
+mod sample;
+mod sections;
+mod settings;
+mod site;
+mod snippets;
+mod theme;
+mod tidy;
+mod timing;
+mod variant;
+
+use cache::DocsCache;
+use concepts::ConceptEngine;
+use ids::IdAllocator;
+use sample::Sample;
+use sections::Page;
+use settings::Settings;
+use snippets::{AvatarShape, AvatarSize, Platform};
 use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use timing::{Clock, SystemClock, Timer};
+use variant::Variant;
 use zed_extension_api::{
     self as zed, SlashCommand, SlashCommandArgumentCompletion, SlashCommandOutput,
     SlashCommandOutputSection,
@@ -6,220 +52,11 @@ use zed_extension_api::{
 
 const DAISYUI_DOCS_CONTENT: &str = include_str!("llms.txt");
 
-// ============================================================================
-// DocsCache - Documentation search and retrieval
-// ============================================================================
-
-#[derive(Debug, Clone)]
-struct DocsCache {
-    components: HashMap<String, String>,
-    index: HashMap<String, Vec<String>>,
-}
-
-impl DocsCache {
-    fn load() -> Self {
-        let mut components = HashMap::new();
-        let mut index: HashMap<String, Vec<String>> = HashMap::new();
-        let mut current_component = String::new();
-        let mut current_content = String::new();
-
-        for line in DAISYUI_DOCS_CONTENT.lines() {
-            if let Some(stripped) = line.strip_prefix("### ") {
-                if !current_component.is_empty() {
-                    let key = current_component.trim().to_lowercase();
-                    components.insert(key.clone(), current_content.trim().to_string());
-                    for word in current_content.split_whitespace() {
-                        let word_lower = word.to_lowercase();
-                        if word_lower.len() > 3 {
-                            index.entry(word_lower).or_default().push(key.clone());
-                        }
-                    }
-                }
-                current_component = stripped.to_string();
-                current_content = format!("{}\n", line);
-            } else if !current_component.is_empty() {
-                current_content.push_str(line);
-                current_content.push('\n');
-            }
-        }
-        if !current_component.is_empty() {
-            let key = current_component.trim().to_lowercase();
-            components.insert(key.clone(), current_content.trim().to_string());
-            for word in current_content.split_whitespace() {
-                let word_lower = word.to_lowercase();
-                if word_lower.len() > 3 {
-                    index.entry(word_lower).or_default().push(key.clone());
-                }
-            }
-        }
-        DocsCache { components, index }
-    }
-
-    fn list_components(&self) -> Vec<String> {
-        let mut keys: Vec<String> = self.components.keys().cloned().collect();
-        keys.sort();
-        keys
-    }
-
-    fn get_doc(&self, name: &str) -> Option<String> {
-        if name.is_empty() {
-            return None;
-        }
-        self.components.get(&name.to_lowercase()).cloned()
-    }
-
-    fn search(&self, query: &str) -> Vec<(String, String, usize)> {
-        if query.is_empty() {
-            return Vec::new();
-        }
-        let query = query.to_lowercase();
-        let mut scores: HashMap<String, usize> = HashMap::new();
-
-        for (name, content) in &self.components {
-            let mut score = 0;
-            if name.contains(&query) {
-                score += 100;
-            }
-            if content.to_lowercase().contains(&query) {
-                score += 10;
-            }
-            for word in query.split_whitespace() {
-                if let Some(matches) = self.index.get(word) {
-                    if matches.contains(name) {
-                        score += 5;
-                    }
-                }
-            }
-            if score > 0 {
-                scores.insert(name.clone(), score);
-            }
-        }
-
-        let mut sorted: Vec<_> = scores.keys().cloned().collect();
-        sorted.sort_by(|a, b| scores[b].cmp(&scores[a]).then(a.cmp(b)));
-        sorted
-            .iter()
-            .take(20)
-            .filter_map(|k| {
-                self.components
-                    .get(k)
-                    .map(|c| (k.clone(), c.clone(), scores[k]))
-            })
-            .collect()
-    }
-}
-
-// ============================================================================
-// ConceptEngine - Design concepts
-// ============================================================================
-
-#[derive(Debug, Clone)]
-struct DesignConcept {
-    name: String,
-    description: String,
-    classes: Vec<String>,
-    suggestion: String,
-    snippet: String,
-}
-
-impl DesignConcept {
-    fn to_display(&self) -> String {
-        format!(
-            "## {}\n\n**Description:** {}\n\n**Classes:** {}\n\n**Suggestion:** {}\n\n```html\n{}\n```",
-            self.name,
-            self.description,
-            self.classes.join(", "),
-            self.suggestion,
-            self.snippet
-        )
-    }
-}
-
-struct ConceptEngine {
-    concepts: HashMap<String, DesignConcept>,
-}
-
-impl ConceptEngine {
-    fn new() -> Self {
-        let mut m = HashMap::new();
-        m.insert("glassmorphism".into(), DesignConcept {
-            name: "Glassmorphism".into(),
-            description: "Frosted glass aesthetic with transparency and blur effects".into(),
-            classes: vec!["glass".into(), "backdrop-blur".into()],
-            suggestion: "Apply glass class to cards and modals for depth".into(),
-            snippet: r#"<div class="card glass w-96 shadow-xl"><div class="card-body">Content</div></div>"#.into(),
-        });
-        m.insert(
-            "neumorphism".into(),
-            DesignConcept {
-                name: "Neumorphism".into(),
-                description: "Soft shadows creating extruded surface effect".into(),
-                classes: vec!["shadow-lg".into(), "bg-base-200".into()],
-                suggestion: "Combine soft shadows with subtle gradients".into(),
-                snippet: r#"<button class="btn shadow-lg bg-base-200">Button</button>"#.into(),
-            },
-        );
-        m.insert("darkmode".into(), DesignConcept {
-            name: "Dark Mode".into(),
-            description: "Dark color scheme with high contrast".into(),
-            classes: vec!["bg-base-100".into(), "text-base-content".into()],
-            suggestion: "Use data-theme attribute to toggle themes".into(),
-            snippet: r#"<html data-theme="dark"><body class="bg-base-100 text-base-content">Content</body></html>"#.into(),
-        });
-        m.insert(
-            "gradient".into(),
-            DesignConcept {
-                name: "Gradients".into(),
-                description: "Color transitions for visual depth".into(),
-                classes: vec![
-                    "bg-gradient-to-r".into(),
-                    "from-primary".into(),
-                    "to-secondary".into(),
-                ],
-                suggestion: "Use gradients sparingly on hero sections and CTAs".into(),
-                snippet:
-                    r#"<div class="bg-gradient-to-r from-primary to-secondary p-8">Hero</div>"#
-                        .into(),
-            },
-        );
-        m.insert(
-            "skeleton".into(),
-            DesignConcept {
-                name: "Skeleton Loading".into(),
-                description: "Placeholder UI while content loads".into(),
-                classes: vec!["skeleton".into()],
-                suggestion: "Use skeleton class on elements for loading state".into(),
-                snippet: r#"<div class="skeleton h-32 w-full"></div>"#.into(),
-            },
-        );
-        m.insert(
-            "responsive".into(),
-            DesignConcept {
-                name: "Responsive Design".into(),
-                description: "Adapts layout to different screen sizes".into(),
-                classes: vec!["sm:".into(), "md:".into(), "lg:".into(), "xl:".into()],
-                suggestion: "Use responsive prefixes for breakpoint-specific styles".into(),
-                snippet:
-                    r#"<div class="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-4">...</div>"#
-                        .into(),
-            },
-        );
-        Self { concepts: m }
-    }
+/// Template file names under `plugins::DIR`, one per line.
+const TEMPLATE_INDEX: &str = "index.txt";
 
-    fn get_concept(&self, query: &str) -> Option<&DesignConcept> {
-        if query.is_empty() {
-            return None;
-        }
-        self.concepts.get(&query.to_lowercase())
-    }
-
-    fn list_concepts(&self) -> Vec<String> {
-        let mut v: Vec<String> = self.concepts.keys().cloned().collect();
-        v.sort();
-        v
-    }
-}
+/// Project files that replace the bundled llms.txt, checked in order.
+const DOCS_OVERRIDES: &[&str] = &["daisyui-llms.txt", ".daisy/llms.txt"];
 
 // ============================================================================
 // LayoutEngine - HTML layout generation
@@ -239,249 +76,1171 @@ impl LayoutEngine {
         "dashboard",
         "auth",
         "store",
+        "pricing",
+        "checkout",
+        "error",
+        "crud",
+        "app-landing",
+        "forum",
+        "analytics",
+        "settings",
+        "status",
+        "media",
+        "jobs",
+        "changelog",
+        "team",
+        "faq",
+        "survey",
     ];
 
-    fn generate(layout: &str, title: &str) -> String {
-        let t = Self::sanitize(title);
+    /// Template layouts' own descriptions, then the built-in summaries.
+    fn describe_with(templates: &plugins::Registry, layout: &str) -> Option<String> {
+        match templates.get(layout) {
+            Some(t) if t.description.is_empty() => Some(t.title.clone()),
+            Some(t) => Some(t.description.clone()),
+            None => Self::describe(layout).map(String::from),
+        }
+    }
+
+    /// One-line summary shown above generated code.
+    fn describe(layout: &str) -> Option<&'static str> {
+        Some(match layout {
+            "saas" => {
+                "Landing page: sticky navbar, hero, logo cloud, feature grid, testimonials, pricing tiers and footer."
+            }
+            "blog" => "Blog home: navbar, featured article card, latest stories and a sidebar.",
+            "social" => {
+                "Social feed: navigation sidebar, post composer, a stream of posts and a trends sidebar."
+            }
+            "kanban" => "Kanban board: navbar and swimlanes of task cards with status badges.",
+            "inbox" => "Mail client: folder menu, message list and a reading pane.",
+            "profile" => "Account settings: section menu, user card, profile form and preferences.",
+            "docs" => {
+                "Documentation site: drawer sidebar pinned open on large screens, breadcrumbs and article content."
+            }
+            "dashboard" => {
+                "Admin dashboard shell: drawer sidebar pinned open on large screens, navbar and a content area."
+            }
+            "auth" => "Sign-in card: email and password fields and a login button.",
+            "store" => {
+                "Storefront: navbar with cart, hero and featured products; `checkout` picks up from the cart."
+            }
+            "pricing" => {
+                "Pricing page: hero, monthly/yearly toggle, three plan cards, a feature comparison table and an FAQ."
+            }
+            "checkout" => {
+                "Checkout: Cart → Shipping → Payment → Done steps, shipping and card fields, and an order summary with a promo code."
+            }
+            "error" => {
+                "Error page: a huge status code, a message and home/support buttons; the title picks 404 (default), 500 or maintenance."
+            }
+            "crud" => {
+                "CRUD: a search/filter/New toolbar, a selectable zebra table with status badges and row actions, pagination, and edit/delete modals."
+            }
+            "app-landing" => {
+                "Mobile app landing: a phone mockup hero with store badges, a screenshot carousel, star-rated reviews and a sticky mobile download bar."
+            }
+            "forum" => {
+                "Forum: a category menu, a thread list with reply counts and pinned badges, a thread with nested replies and a reply box, and a New topic modal."
+            }
+            "analytics" => {
+                "Analytics report: a date range and export menu, KPI stats with trends, line/bar/doughnut chart canvases and a top-sources table."
+            }
+            "settings" => {
+                "Settings: General, Security, API Keys, Integrations and Billing tabs with notification toggles, a password form, keys, integrations and invoices."
+            }
+            "status" => {
+                "Status page: an overall status alert, services with status badges and 90-day uptime bars, an incident timeline and a subscribe form."
+            }
+            "media" => {
+                "Music player: a playlist drawer pinned open on large screens, album cards with hover play buttons and a fixed player bar with seek and volume."
+            }
+            "jobs" => {
+                "Job board: a keyword and location search, type and salary filters, job cards with company, tags and salary, a sort menu and pagination."
+            }
+            "changelog" => {
+                "Changelog: an RSS link, a timeline of releases with version badges and Added/Fixed/Changed lists, and older releases in a collapse."
+            }
+            "team" => {
+                "About us: a mission hero, member cards with avatars and social buttons, three values, an office carousel and a join-us link to jobs."
+            }
+            "faq" => {
+                "Help center: a search hero, category cards, grouped question accordions and a contact card opening a support modal."
+            }
+            "survey" => {
+                "Quiz: a card with a progress bar, one question per page with card-style radio options, Back/Next buttons and a radial-progress score."
+            }
+            _ => return None,
+        })
+    }
+
+    /// A layout's markup, its sections joined back together.
+    fn generate(layout: &str, title: &str, v: &Variant, ids: &mut IdAllocator) -> String {
+        Self::build(layout, title, v, ids).html()
+    }
+
+    /// Sample content is seeded from the unescaped title.
+    fn build(layout: &str, title: &str, v: &Variant, ids: &mut IdAllocator) -> Page {
+        Self::build_seeded(layout, title, &mut Sample::new(sample::seed(title)), v, ids)
+    }
+
+    /// Sample content, and how many items repeat, come from `s`.
+    fn build_seeded(
+        layout: &str,
+        title: &str,
+        s: &mut Sample,
+        v: &Variant,
+        ids: &mut IdAllocator,
+    ) -> Page {
+        let t = snippets::escape_title(title);
         match layout {
-            "saas" => Self::saas(&t),
-            "blog" => Self::blog(&t),
-            "social" => Self::social(&t),
-            "kanban" => Self::kanban(&t),
-            "inbox" => Self::inbox(&t),
-            "profile" => Self::profile(&t),
-            "docs" => Self::docs(&t),
-            "dashboard" => Self::dashboard(&t),
-            "auth" => Self::auth(&t),
-            "store" => Self::store(&t),
-            _ => Self::saas(&t),
+            "saas" => Self::saas(&t, v, ids),
+            "blog" => Self::blog(&t, v, ids, s),
+            "social" => Self::social(&t, v, s),
+            "kanban" => Self::kanban(&t, v, ids, s),
+            "inbox" => Self::inbox(&t, v, ids, s),
+            "profile" => Self::profile(&t, v, ids),
+            "docs" => Self::docs(&t, &ids.next("drawer"), v),
+            "dashboard" => Self::dashboard(&t, &ids.next("drawer"), v),
+            "auth" => Self::auth(&t, v, ids),
+            "store" => Self::store(&t, v, ids, s),
+            "pricing" => snippets::pricing_page(&t, &ids.next("billing"), &ids.next("faq"), v),
+            "checkout" => snippets::checkout_page(&t, &ids.next("checkout"), v),
+            "error" => snippets::error_page(&t, v),
+            "crud" => snippets::crud_page(&t, &ids.next("crud"), v),
+            "app-landing" => snippets::app_landing(&t, &ids.next("app"), v),
+            "forum" => snippets::forum_page(&t, &ids.next("forum"), v),
+            "analytics" => Self::analytics(&t, snippets::DEFAULT_CHARTS, v, ids),
+            "settings" => snippets::settings_page(&t, &ids.next("settings"), v),
+            "status" => snippets::status_page(&t, &ids.next("subscribe"), v),
+            "media" => snippets::media_page(&t, &ids.next("drawer"), v),
+            "jobs" => snippets::jobs_page(&t, &ids.next("jobs"), v, s),
+            "changelog" => snippets::changelog_page(&t, v),
+            "team" => snippets::team_page(&t, &ids.next("team"), v),
+            "faq" => snippets::faq_page(&t, &ids.next("help"), v),
+            "survey" => snippets::survey_page(&t, &ids.next("survey"), v),
+            _ => Self::saas(&t, v, ids),
         }
     }
 
-    fn sanitize(text: &str) -> String {
-        text.chars()
-            .filter(|c| c.is_alphanumeric() || c.is_whitespace() || *c == '-' || *c == '_')
-            .take(100)
-            .collect()
+    /// `daisy-only` swaps in the layout's daisyUI-only variant when it has one.
+    fn generate_with_purity(
+        layout: &str,
+        title: &str,
+        purity: &str,
+        s: &mut Sample,
+        v: &Variant,
+        ids: &mut IdAllocator,
+    ) -> String {
+        match purity {
+            "daisy-only" => purity::layout(layout, &snippets::escape_title(title), s, v, ids)
+                .unwrap_or_else(|| Self::build_seeded(layout, title, s, v, ids)),
+            _ => Self::build_seeded(layout, title, s, v, ids),
+        }
+        .html()
     }
 
-    fn saas(t: &str) -> String {
-        format!(
-            r#"<div class="min-h-screen bg-base-100">
-  <div class="navbar bg-base-100 sticky top-0 z-50 border-b border-base-200">
-    <div class="flex-1"><a class="btn btn-ghost text-xl font-bold">{t}</a></div>
-    <div class="flex-none gap-2">
-      <ul class="menu menu-horizontal px-1 hidden sm:flex"><li><a>Features</a></li><li><a>Pricing</a></li></ul>
+    fn saas(t: &str, v: &Variant, ids: &mut IdAllocator) -> Page {
+        let [
+            text_xl,
+            gap2,
+            px1,
+            text_5xl,
+            py6,
+            py24,
+            px4,
+            text_3xl,
+            mb12,
+            gap8,
+            p10,
+        ] = [
+            v.text("xl"),
+            v.space("gap", 2),
+            v.space("px", 1),
+            v.text("5xl"),
+            v.space("py", 6),
+            v.space("py", 24),
+            v.space("px", 4),
+            v.text("3xl"),
+            v.space("mb", 12),
+            v.space("gap", 8),
+            v.space("p", 10),
+        ];
+        let logos = snippets::logo_cloud(&snippets::default_logos(), &ids.next("logos"));
+        let features = ids.next("features");
+        let testimonials =
+            snippets::testimonials(&snippets::default_testimonials(), &ids.next("testimonials"));
+        let pricing =
+            snippets::pricing_tiers(&snippets::default_pricing_tiers(), &ids.next("pricing"));
+        Page::default()
+            .markup(r#"<div class="min-h-screen bg-base-100">"#)
+            .section(
+                "navbar",
+                format!(
+                    r#"
+  <nav class="navbar bg-base-100 sticky top-0 z-50 border-b border-base-200" aria-label="Main">
+    <div class="flex-1"><a class="btn btn-ghost {text_xl} font-bold">{t}</a></div>
+    <div class="flex-none {gap2}">
+      <ul class="menu menu-horizontal {px1} hidden sm:flex"><li><a>Features</a></li><li><a>Pricing</a></li></ul>
       <button class="btn btn-primary">Get Started</button>
     </div>
-  </div>
-  <div class="hero min-h-[80vh] bg-base-200">
+  </nav>"#
+                ),
+            )
+            .section(
+                "hero",
+                format!(
+                    r#"
+  <header class="hero min-h-[80vh] bg-base-200">
     <div class="hero-content text-center">
       <div class="max-w-2xl">
-        <h1 class="text-5xl font-extrabold">Build faster with <span class="text-primary">{t}</span></h1>
-        <p class="py-6 text-xl text-base-content/80">The ultimate scaffolding engine for modern web apps.</p>
+        <h1 class="{text_5xl} font-extrabold">Build faster with <span class="text-primary">{t}</span></h1>
+        <p class="{py6} {text_xl} text-base-content/80">The ultimate scaffolding engine for modern web apps.</p>
         <button class="btn btn-primary btn-lg">Start Free Trial</button>
       </div>
     </div>
-  </div>
-  <div class="py-24 bg-base-100">
-    <div class="container mx-auto px-4">
-      <h2 class="text-3xl font-bold text-center mb-12">Everything you need</h2>
-      <div class="grid grid-cols-1 md:grid-cols-3 gap-8">
+  </header>"#
+                ),
+            )
+            .markup("\n  <main>\n")
+            .section("logos", logos)
+            .section(
+                "features",
+                format!(
+                    r#"
+  <section class="{py24} bg-base-100" aria-labelledby="{features}">
+    <div class="container mx-auto {px4}">
+      <h2 id="{features}" class="{text_3xl} font-bold text-center {mb12}">Everything you need</h2>
+      <div class="grid grid-cols-1 md:grid-cols-3 {gap8}">
         <div class="card bg-base-200 shadow-sm"><div class="card-body"><h3 class="card-title">⚡ Fast</h3><p>Optimized for speed.</p></div></div>
         <div class="card bg-base-200 shadow-sm"><div class="card-body"><h3 class="card-title">🔒 Secure</h3><p>Bank-grade security.</p></div></div>
         <div class="card bg-base-200 shadow-sm"><div class="card-body"><h3 class="card-title">🎨 Themable</h3><p>DaisyUI themes.</p></div></div>
       </div>
     </div>
-  </div>
-  <footer class="footer p-10 bg-base-300"><nav><header class="footer-title">Company</header><a class="link link-hover">About</a></nav></footer>
-</div>"#
-        )
+  </section>"#
+                ),
+            )
+            .markup("\n")
+            .section("testimonials", testimonials)
+            .markup("\n")
+            .section("pricing", pricing)
+            .markup("\n  </main>")
+            .section(
+                "footer",
+                format!(
+                    r#"
+  <footer class="footer {p10} bg-base-300"><nav aria-label="Company"><h6 class="footer-title">Company</h6><a class="link link-hover">About</a></nav></footer>"#
+                ),
+            )
+            .markup("\n</div>")
     }
 
-    fn blog(t: &str) -> String {
-        format!(
-            r#"<div class="min-h-screen bg-base-100">
-  <div class="navbar bg-base-100 border-b border-base-200">
-    <div class="flex-1"><a class="btn btn-ghost text-2xl font-serif">{t}</a></div>
-  </div>
-  <div class="container mx-auto px-4 py-12">
-    <div class="card lg:card-side bg-base-200 shadow-xl mb-16">
-      <figure class="lg:w-1/2"><img src="https://picsum.photos/800/600" class="h-full object-cover" /></figure>
-      <div class="card-body lg:w-1/2"><h2 class="card-title text-4xl font-serif">Featured Article</h2><p>Exploring cutting-edge patterns.</p><button class="btn btn-primary">Read</button></div>
-    </div>
-    <div class="grid md:grid-cols-3 gap-8">
-      <div class="card bg-base-200"><div class="card-body"><div class="badge badge-ghost mb-2">Tech</div><h3 class="card-title">Post Title</h3><p>Post excerpt...</p></div></div>
-    </div>
-  </div>
-</div>"#
-        )
+    fn blog(t: &str, v: &Variant, ids: &mut IdAllocator, s: &mut Sample) -> Page {
+        let [text_2xl, px4, py12, mb16, text_4xl, gap8, mb2, mb6] = [
+            v.text("2xl"),
+            v.space("px", 4),
+            v.space("py", 12),
+            v.space("mb", 16),
+            v.text("4xl"),
+            v.space("gap", 8),
+            v.space("mb", 2),
+            v.space("mb", 6),
+        ];
+        let posts = ids.next("posts");
+        let headlines = s.many(sample::HEADLINES, s.count("posts", 1) + 1);
+        let (featured, lede) = (headlines[0], s.sentence());
+        let stories: String = headlines[1..]
+            .iter()
+            .map(|headline| {
+                let (topic, excerpt) = (s.pick(sample::TOPICS), s.sentence());
+                format!(
+                    r#"        <div class="card bg-base-200"><div class="card-body"><div class="badge badge-ghost {mb2}">{topic}</div><h3 class="card-title">{headline}</h3><p>{excerpt}</p></div></div>
+"#
+                )
+            })
+            .collect();
+        Page::default()
+            .markup(r#"<div class="min-h-screen bg-base-100">"#)
+            .section(
+                "navbar",
+                format!(
+                    r#"
+  <nav class="navbar bg-base-100 border-b border-base-200" aria-label="Main">
+    <div class="flex-1"><a class="btn btn-ghost {text_2xl} font-serif">{t}</a></div>
+  </nav>"#
+                ),
+            )
+            .markup(format!(
+                r#"
+  <main class="container mx-auto {px4} {py12}">"#
+            ))
+            .section(
+                "featured",
+                format!(
+                    r#"
+    <div class="card lg:card-side bg-base-200 shadow-xl {mb16}">
+      <figure class="lg:w-1/2"><img src="https://picsum.photos/800/600" alt="{featured}" class="h-full object-cover" /></figure>
+      <div class="card-body lg:w-1/2"><h2 class="card-title {text_4xl} font-serif">{featured}</h2><p>{lede}</p><button class="btn btn-primary">Read</button></div>
+    </div>"#
+                ),
+            )
+            .section(
+                "posts",
+                format!(
+                    r#"
+    <section aria-labelledby="{posts}">
+      <h2 id="{posts}" class="{text_2xl} font-bold {mb6}">Latest stories</h2>
+      <div class="grid md:grid-cols-3 {gap8}">
+{stories}      </div>
+    </section>"#
+                ),
+            )
+            .markup("\n  </main>\n</div>")
     }
 
-    fn social(t: &str) -> String {
-        format!(
-            r#"<div class="min-h-screen bg-base-100 flex">
-  <div class="w-64 hidden lg:block p-4 border-r border-base-200">
-    <div class="text-2xl font-bold text-primary mb-4">{t}</div>
-    <ul class="menu"><li><a class="active">🏠 Home</a></li><li><a>🔔 Notifications</a></li><li><a>✉️ Messages</a></li></ul>
-    <button class="btn btn-primary w-full mt-8">Post</button>
-  </div>
-  <div class="flex-1 max-w-2xl border-r border-base-200">
-    <div class="sticky top-0 bg-base-100/80 backdrop-blur p-4 border-b font-bold text-xl">Home</div>
-    <div class="p-4 border-b"><textarea class="textarea w-full" placeholder="What's happening?"></textarea><button class="btn btn-primary btn-sm float-right">Post</button></div>
-    <div class="p-4 border-b hover:bg-base-200/50">
-      <div class="flex gap-4"><div class="avatar"><div class="w-12 rounded-full"><img src="https://picsum.photos/100" /></div></div>
-      <div><span class="font-bold">User</span> <span class="opacity-50">@user • 2h</span><p class="mt-1">Just shipped! 🚀</p></div></div>
+    fn social(t: &str, v: &Variant, s: &mut Sample) -> Page {
+        let [p4, text_2xl, mb4, mt8, text_xl, gap4, mt1] = [
+            v.space("p", 4),
+            v.text("2xl"),
+            v.space("mb", 4),
+            v.space("mt", 8),
+            v.text("xl"),
+            v.space("gap", 4),
+            v.space("mt", 1),
+        ];
+        let avatar = snippets::avatar(
+            "https://picsum.photos/100",
+            AvatarSize::Md,
+            AvatarShape::Circle,
+            None,
+        );
+        let posts: String = s
+            .many(sample::STATUSES, s.count("posts", 1))
+            .into_iter()
+            .map(|status| {
+                let name = s.name();
+                let (handle, hours) = (sample::handle(&name), s.between(1, 23));
+                format!(
+                    r#"    <div class="{p4} border-b hover:bg-base-200/50">
+      <div class="flex {gap4}">{avatar}
+      <div><span class="font-bold">{name}</span> <span class="opacity-50">@{handle} • {hours}h</span><p class="{mt1}">{status}</p></div></div>
     </div>
-  </div>
-</div>"#
-        )
+"#
+                )
+            })
+            .collect();
+        Page::default()
+            .markup(r#"<div class="min-h-screen bg-base-100 flex">"#)
+            .section(
+                "sidebar",
+                format!(
+                    r#"
+  <nav class="w-64 hidden lg:block {p4} border-r border-base-200" aria-label="Main">
+    <div class="{text_2xl} font-bold text-primary {mb4}">{t}</div>
+    <ul class="menu"><li><a class="active">🏠 Home</a></li><li><a>🔔 Notifications</a></li><li><a>✉️ Messages</a></li></ul>
+    <button class="btn btn-primary w-full {mt8}">Post</button>
+  </nav>"#
+                ),
+            )
+            .section(
+                "feed",
+                format!(
+                    r#"
+  <main class="flex-1 max-w-2xl border-r border-base-200">
+    <h1 class="sticky top-0 bg-base-100/80 backdrop-blur {p4} border-b font-bold {text_xl}">Home</h1>
+    <div class="{p4} border-b"><textarea class="textarea w-full" placeholder="What's happening?" aria-label="New post"></textarea><button class="btn btn-primary btn-sm float-right">Post</button></div>
+{posts}  </main>"#
+                ),
+            )
+            .markup("\n</div>")
     }
 
-    fn kanban(t: &str) -> String {
-        format!(
-            r#"<div class="h-screen flex flex-col bg-base-200">
-  <div class="navbar bg-base-100 shadow-sm"><div class="flex-1"><h1 class="text-xl font-bold">{t}</h1></div><button class="btn btn-primary btn-sm">Share</button></div>
-  <div class="flex-1 overflow-x-auto p-6">
-    <div class="flex gap-6">
-      <div class="w-80 shrink-0"><h3 class="font-bold mb-3">To Do <span class="badge badge-sm">3</span></h3>
-        <div class="card bg-base-100 p-4 mb-2"><div class="badge badge-warning mb-2">Design</div><p class="font-semibold">Create mockups</p></div>
-        <button class="btn btn-ghost btn-block">+ Add Task</button>
-      </div>
-      <div class="w-80 shrink-0"><h3 class="font-bold mb-3">In Progress <span class="badge badge-sm">1</span></h3>
-        <div class="card bg-base-100 p-4"><div class="badge badge-info mb-2">Dev</div><p class="font-semibold">Implement Auth</p><progress class="progress progress-primary mt-2" value="40" max="100"></progress></div>
-      </div>
-      <div class="w-80 shrink-0"><h3 class="font-bold mb-3">Done <span class="badge badge-sm">2</span></h3>
-        <div class="card bg-base-100 p-4 opacity-60"><p class="line-through">Setup Repo</p></div>
-      </div>
-    </div>
-  </div>
-</div>"#
-        )
+    /// Lanes from the `columns` count, each with `cards` cards: labeled in
+    /// the first lane, with progress in the middle ones and struck through
+    /// in the last. Only the first lane takes new tasks.
+    fn kanban(t: &str, v: &Variant, ids: &mut IdAllocator, s: &mut Sample) -> Page {
+        let [text_xl, p6, gap6, mb3, p4, mb2, mt2] = [
+            v.text("xl"),
+            v.space("p", 6),
+            v.space("gap", 6),
+            v.space("mb", 3),
+            v.space("p", 4),
+            v.space("mb", 2),
+            v.space("mt", 2),
+        ];
+        let lanes = sample::lanes(s.count("columns", 3));
+        let per_lane = s.count("cards", 1);
+        let mut tasks = s.many(sample::TASKS, lanes.len() * per_lane).into_iter();
+        let last = lanes.len() - 1;
+        let lanes: String = lanes
+            .iter()
+            .enumerate()
+            .map(|(i, (name, state))| {
+                let id = ids.next("lane");
+                let badge = snippets::status_badge(state, &per_lane.to_string(), &[], false);
+                let cards: String = tasks
+                    .by_ref()
+                    .take(per_lane)
+                    .map(|task| {
+                        let (label, color) = s.pick(sample::LABELS);
+                        match i {
+                            0 => format!(
+                                r#"        <div class="card bg-base-100 {p4} {mb2}"><div class="badge {color} {mb2}">{label}</div><p class="font-semibold">{task}</p></div>
+"#
+                            ),
+                            _ if i == last => format!(
+                                r#"        <div class="card bg-base-100 {p4} opacity-60"><p class="line-through">{task}</p></div>
+"#
+                            ),
+                            _ => format!(
+                                r#"        <div class="card bg-base-100 {p4}"><div class="badge {color} {mb2}">{label}</div><p class="font-semibold">{task}</p><progress class="progress progress-primary {mt2}" value="{}" max="100"></progress></div>
+"#,
+                                s.between(2, 8) * 10
+                            ),
+                        }
+                    })
+                    .collect();
+                let add = if i == 0 {
+                    "        <button class=\"btn btn-ghost btn-block\">+ Add Task</button>\n"
+                } else {
+                    ""
+                };
+                format!(
+                    r#"      <section class="w-80 shrink-0" aria-labelledby="{id}"><h2 id="{id}" class="font-bold {mb3}">{name} {badge}</h2>
+{cards}{add}      </section>
+"#
+                )
+            })
+            .collect();
+        Page::default()
+            .markup(r#"<div class="h-screen flex flex-col bg-base-200">"#)
+            .section(
+                "navbar",
+                format!(
+                    r#"
+  <nav class="navbar bg-base-100 shadow-sm" aria-label="Main"><div class="flex-1"><h1 class="{text_xl} font-bold">{t}</h1></div><button class="btn btn-primary btn-sm">Share</button></nav>"#
+                ),
+            )
+            .section(
+                "board",
+                format!(
+                    r#"
+  <main class="flex-1 overflow-x-auto {p6}">
+    <div class="flex {gap6}">
+{lanes}    </div>
+  </main>"#
+                ),
+            )
+            .markup("\n</div>")
     }
 
-    fn inbox(t: &str) -> String {
-        format!(
-            r#"<div class="h-screen flex bg-base-100">
-  <div class="w-64 border-r flex flex-col">
-    <div class="p-4 font-bold text-xl"><div class="badge badge-primary badge-lg mr-2">M</div>{t}</div>
-    <button class="btn btn-primary mx-4">✏️ Compose</button>
-    <ul class="menu flex-1 p-2"><li><a class="active">Inbox <span class="badge">4</span></a></li><li><a>Sent</a></li><li><a>Drafts</a></li></ul>
-  </div>
-  <div class="w-80 border-r overflow-y-auto">
-    <input class="input input-bordered w-full m-2" placeholder="Search" style="width:calc(100%-1rem)" />
-    <div class="p-4 hover:bg-base-200 cursor-pointer border-b"><span class="font-bold">Sender</span><div class="font-semibold truncate">Subject line</div><div class="text-sm opacity-60 truncate">Preview text...</div></div>
-  </div>
-  <div class="flex-1 flex flex-col">
-    <div class="p-6 border-b"><h2 class="text-2xl font-bold">Email Subject</h2><div class="mt-2 text-sm">From: <span class="font-bold">sender@example.com</span></div></div>
-    <div class="p-6 flex-1"><p>Email content goes here...</p></div>
-  </div>
-</div>"#
-        )
+    /// The `messages` count sets the list's length; the first message is
+    /// the one shown.
+    fn inbox(t: &str, v: &Variant, ids: &mut IdAllocator, s: &mut Sample) -> Page {
+        let [p4, text_xl, mr2, mx4, p2, m2, text_sm, p6, text_2xl, mt2] = [
+            v.space("p", 4),
+            v.text("xl"),
+            v.space("mr", 2),
+            v.space("mx", 4),
+            v.space("p", 2),
+            v.space("m", 2),
+            v.text("sm"),
+            v.space("p", 6),
+            v.text("2xl"),
+            v.space("mt", 2),
+        ];
+        let list = ids.next("messages");
+        let count = s.count("messages", 1);
+        let messages: Vec<(&str, &str, &str)> = s
+            .many(sample::SENDERS, count)
+            .into_iter()
+            .zip(s.many(sample::SUBJECTS, count))
+            .map(|(sender, subject)| (sender, subject, s.sentence()))
+            .collect();
+        let items: String = messages
+            .iter()
+            .map(|(sender, subject, preview)| {
+                format!(
+                    r#"    <div class="{p4} hover:bg-base-200 cursor-pointer border-b"><span class="font-bold">{sender}</span><div class="font-semibold truncate">{subject}</div><div class="{text_sm} opacity-60 truncate">{preview}</div></div>
+"#
+                )
+            })
+            .collect();
+        let (sender, subject, preview) = messages[0];
+        let (address, body) = (sample::handle(sender), s.sentence());
+        Page::default()
+            .markup(r#"<div class="h-screen flex bg-base-100">"#)
+            .section(
+                "sidebar",
+                format!(
+                    r#"
+  <nav class="w-64 border-r flex flex-col" aria-label="Mailboxes">
+    <div class="{p4} font-bold {text_xl}"><div class="badge badge-primary badge-lg {mr2}">M</div>{t}</div>
+    <button class="btn btn-primary {mx4}" aria-label="Compose"><span aria-hidden="true">✏️</span> Compose</button>
+    <ul class="menu flex-1 {p2}"><li><a class="active">Inbox <span class="badge">4</span></a></li><li><a>Sent</a></li><li><a>Drafts</a></li></ul>
+  </nav>"#
+                ),
+            )
+            .section(
+                "messages",
+                format!(
+                    r#"
+  <section class="w-80 border-r overflow-y-auto" aria-labelledby="{list}">
+    <h2 id="{list}" class="sr-only">Messages</h2>
+    <input class="input input-bordered w-full {m2}" placeholder="Search" aria-label="Search mail" style="width:calc(100%-1rem)" />
+{items}  </section>"#
+                ),
+            )
+            .section(
+                "main",
+                format!(
+                    r#"
+  <main class="flex-1 flex flex-col">
+    <header class="{p6} border-b"><h1 class="{text_2xl} font-bold">{subject}</h1><div class="{mt2} {text_sm}">From: <span class="font-bold">{address}@example.com</span></div></header>
+    <div class="{p6} flex-1"><p>{preview} {body}</p></div>
+  </main>"#
+                ),
+            )
+            .markup("\n</div>")
     }
 
-    fn profile(t: &str) -> String {
-        format!(
-            r#"<div class="min-h-screen bg-base-200 p-4 md:p-8">
-  <div class="max-w-4xl mx-auto">
-    <h1 class="text-3xl font-bold mb-8">{t}</h1>
-    <div class="flex flex-col md:flex-row gap-6">
-      <ul class="menu bg-base-100 rounded-box w-full md:w-64 shadow-sm"><li><a class="active">General</a></li><li><a>Account</a></li><li><a>Notifications</a></li><li><a class="text-error">Danger Zone</a></li></ul>
-      <div class="flex-1 card bg-base-100 shadow-sm">
+    fn profile(t: &str, v: &Variant, ids: &mut IdAllocator) -> Page {
+        let [p4, md_p8, text_3xl, mb8, gap6, mb4, mb6] = [
+            v.space("p", 4),
+            v.space("md:p", 8),
+            v.text("3xl"),
+            v.space("mb", 8),
+            v.space("gap", 6),
+            v.space("mb", 4),
+            v.space("mb", 6),
+        ];
+        let user = snippets::user_card(
+            "User Name",
+            "user@example.com",
+            &snippets::avatar("User", AvatarSize::Xl, AvatarShape::Circle, None),
+            &["Change Avatar"],
+        );
+        let info = ids.next("profile");
+        Page::default()
+            .markup(format!(
+                r#"<div class="min-h-screen bg-base-200 {p4} {md_p8}">
+  <main class="max-w-4xl mx-auto">
+    <h1 class="{text_3xl} font-bold {mb8}">{t}</h1>
+    <div class="flex flex-col md:flex-row {gap6}">"#
+            ))
+            .section(
+                "menu",
+                r#"
+      <nav class="w-full md:w-64" aria-label="Settings"><ul class="menu bg-base-100 rounded-box w-full shadow-sm"><li><a class="active">General</a></li><li><a>Account</a></li><li><a>Notifications</a></li><li><a class="text-error">Danger Zone</a></li></ul></nav>"#,
+            )
+            .section(
+                "main",
+                format!(
+                    r#"
+      <section class="flex-1 card bg-base-100 shadow-sm" aria-labelledby="{info}">
         <div class="card-body">
-          <h2 class="card-title mb-4">Profile Information</h2>
-          <div class="flex items-center gap-4 mb-6"><div class="avatar placeholder"><div class="bg-neutral text-neutral-content rounded-full w-24"><span class="text-3xl">U</span></div></div><button class="btn btn-sm btn-outline">Change Avatar</button></div>
-          <div class="form-control mb-4"><label class="label">Name</label><input class="input input-bordered" value="User Name" /></div>
-          <div class="form-control mb-4"><label class="label">Email</label><input class="input input-bordered" value="user@example.com" /></div>
-          <div class="form-control mb-4"><label class="label">Bio</label><textarea class="textarea textarea-bordered">Bio here...</textarea></div>
+          <h2 id="{info}" class="card-title {mb4}">Profile Information</h2>
+          <div class="{mb6}">{user}</div>
+          <div class="form-control {mb4}"><label class="label" for="{info}-name">Name</label><input id="{info}-name" class="input input-bordered" value="User Name" /></div>
+          <div class="form-control {mb4}"><label class="label" for="{info}-email">Email</label><input id="{info}-email" type="email" class="input input-bordered" value="user@example.com" /></div>
+          <div class="form-control {mb4}"><label class="label" for="{info}-bio">Bio</label><textarea id="{info}-bio" class="textarea textarea-bordered">Bio here...</textarea></div>
           <button class="btn btn-primary">Save Changes</button>
         </div>
-      </div>
+      </section>"#
+                ),
+            )
+            .markup(
+                r#"
     </div>
-  </div>
-</div>"#
-        )
+  </main>
+</div>"#,
+            )
     }
 
-    fn docs(t: &str) -> String {
-        format!(
-            r#"<div class="drawer lg:drawer-open">
-  <input id="docs-drawer" type="checkbox" class="drawer-toggle" />
-  <div class="drawer-content">
-    <div class="navbar bg-base-100 border-b lg:hidden"><label for="docs-drawer" class="btn btn-ghost">☰</label><span class="font-bold">{t}</span></div>
-    <div class="p-8 max-w-4xl mx-auto">
-      <div class="text-sm breadcrumbs mb-4"><ul><li><a>Docs</a></li><li>Installation</li></ul></div>
-      <h1 class="text-4xl font-bold mb-6">Installation</h1>
-      <p class="mb-4 text-lg">Get started in minutes.</p>
-      <div class="mockup-code mb-6"><pre data-prefix="$"><code>npm install package-name</code></pre></div>
-      <h2 class="text-2xl font-bold mt-8 mb-4">Configuration</h2>
+    fn docs(t: &str, drawer: &str, v: &Variant) -> Page {
+        let [p8, text_sm, mb4, text_4xl, mb6, text_lg, text_2xl, mt8, p4] = [
+            v.space("p", 8),
+            v.text("sm"),
+            v.space("mb", 4),
+            v.text("4xl"),
+            v.space("mb", 6),
+            v.text("lg"),
+            v.text("2xl"),
+            v.space("mt", 8),
+            v.space("p", 4),
+        ];
+        Page::default()
+            .markup(format!(
+                r#"<div class="drawer lg:drawer-open">
+  <input id="{drawer}" type="checkbox" class="drawer-toggle" />
+  <div class="drawer-content">"#
+            ))
+            .section(
+                "navbar",
+                format!(
+                    r#"
+    <nav class="navbar bg-base-100 border-b lg:hidden" aria-label="Main"><label for="{drawer}" class="btn btn-ghost" aria-label="Open menu">☰</label><span class="font-bold">{t}</span></nav>"#
+                ),
+            )
+            .section(
+                "main",
+                format!(
+                    r#"
+    <main class="{p8} max-w-4xl mx-auto">
+      <nav class="{text_sm} breadcrumbs {mb4}" aria-label="Breadcrumb"><ul><li><a>Docs</a></li><li>Installation</li></ul></nav>
+      <h1 class="{text_4xl} font-bold {mb6}">Installation</h1>
+      <p class="{mb4} {text_lg}">Get started in minutes.</p>
+      <div class="mockup-code {mb6}"><pre data-prefix="$"><code>npm install package-name</code></pre></div>
+      <h2 class="{text_2xl} font-bold {mt8} {mb4}">Configuration</h2>
       <p>Add to your config file.</p>
-      <div class="alert alert-info mt-8"><span>Requires Node.js 18+</span></div>
-    </div>
-  </div>
-  <div class="drawer-side border-r"><label for="docs-drawer" class="drawer-overlay"></label>
-    <ul class="menu p-4 w-80 min-h-full bg-base-100"><li class="menu-title">{t} Docs</li><li><a class="active">Installation</a></li><li><a>Usage</a></li><li><a>Components</a></li></ul>
-  </div>
-</div>"#
-        )
+      <div class="alert alert-info {mt8}"><span>Requires Node.js 18+</span></div>
+    </main>"#
+                ),
+            )
+            .markup("\n  </div>")
+            .section(
+                "sidebar",
+                format!(
+                    r#"
+  <aside class="drawer-side border-r"><label for="{drawer}" class="drawer-overlay" aria-label="Close menu"></label>
+    <ul class="menu {p4} w-80 min-h-full bg-base-100"><li class="menu-title">{t} Docs</li><li><a class="active">Installation</a></li><li><a>Usage</a></li><li><a>Components</a></li></ul>
+  </aside>"#
+                ),
+            )
+            .markup("\n</div>")
     }
 
-    fn dashboard(t: &str) -> String {
-        format!(
-            r#"<div class="drawer lg:drawer-open">
-  <input id="dash-drawer" type="checkbox" class="drawer-toggle" />
-  <div class="drawer-content flex flex-col">
-    <div class="navbar bg-base-300"><div class="lg:hidden"><label for="dash-drawer" class="btn btn-ghost">☰</label></div><div class="flex-1 font-bold text-xl px-4">{t}</div></div>
-    <div class="p-6">
-      <h2 class="text-2xl font-bold mb-6">Dashboard</h2>
-      <div class="stats shadow mb-6 w-full">
+    fn dashboard(t: &str, drawer: &str, v: &Variant) -> Page {
+        let [text_xl, px4, p6, text_2xl, mb6, p4] = [
+            v.text("xl"),
+            v.space("px", 4),
+            v.space("p", 6),
+            v.text("2xl"),
+            v.space("mb", 6),
+            v.space("p", 4),
+        ];
+        Page::default()
+            .markup(format!(
+                r#"<div class="drawer lg:drawer-open">
+  <input id="{drawer}" type="checkbox" class="drawer-toggle" />
+  <div class="drawer-content flex flex-col">"#
+            ))
+            .section(
+                "navbar",
+                format!(
+                    r#"
+    <nav class="navbar bg-base-300" aria-label="Main"><div class="lg:hidden"><label for="{drawer}" class="btn btn-ghost" aria-label="Open menu">☰</label></div><div class="flex-1 font-bold {text_xl} {px4}">{t}</div></nav>"#
+                ),
+            )
+            .section(
+                "main",
+                format!(
+                    r#"
+    <main class="{p6}">
+      <h1 class="{text_2xl} font-bold {mb6}">Dashboard</h1>
+      <div class="stats shadow {mb6} w-full">
         <div class="stat"><div class="stat-title">Users</div><div class="stat-value">31K</div><div class="stat-desc">↗︎ 22%</div></div>
         <div class="stat"><div class="stat-title">Revenue</div><div class="stat-value">$12.5K</div><div class="stat-desc">↗︎ 14%</div></div>
         <div class="stat"><div class="stat-title">Orders</div><div class="stat-value">1,234</div><div class="stat-desc">↘︎ 3%</div></div>
       </div>
-      <div class="card bg-base-100 shadow"><div class="card-body"><h3 class="card-title">Recent Activity</h3><p>Activity items go here...</p></div></div>
+      <div class="card bg-base-100 shadow"><div class="card-body"><h2 class="card-title">Recent Activity</h2><p>Activity items go here...</p></div></div>
+    </main>"#
+                ),
+            )
+            .markup("\n  </div>")
+            .section(
+                "sidebar",
+                format!(
+                    r#"
+  <aside class="drawer-side"><label for="{drawer}" class="drawer-overlay" aria-label="Close menu"></label>
+    <ul class="menu {p4} w-80 min-h-full bg-base-200"><li class="menu-title">Menu</li><li><a class="active">Overview</a></li><li><a>Analytics</a></li><li><a>Settings</a></li></ul>
+  </aside>"#
+                ),
+            )
+            .markup("\n</div>")
+    }
+
+    fn auth(t: &str, v: &Variant, ids: &mut IdAllocator) -> Page {
+        let [text_xl, text_2xl, mt6] = [v.text("xl"), v.text("2xl"), v.space("mt", 6)];
+        let form = ids.next("login");
+        Page::default()
+            .markup(format!(
+                r#"<div class="min-h-screen flex flex-col bg-base-200">
+  <nav class="navbar" aria-label="Main"><a class="btn btn-ghost {text_xl}">{t}</a></nav>
+  <main class="hero flex-1">
+    <div class="card w-full max-w-sm shadow-2xl bg-base-100">"#
+            ))
+            .section(
+                "form",
+                format!(
+                    r#"
+      <form class="card-body">
+        <h1 class="{text_2xl} font-bold text-center">{t}</h1>
+        <div class="form-control"><label class="label" for="{form}-email"><span class="label-text">Email</span></label><input id="{form}-email" type="email" class="input input-bordered" required /></div>
+        <div class="form-control"><label class="label" for="{form}-password"><span class="label-text">Password</span></label><input id="{form}-password" type="password" class="input input-bordered" required /><label class="label"><a class="label-text-alt link link-hover">Forgot password?</a></label></div>
+        <div class="form-control {mt6}"><button class="btn btn-primary">Login</button></div>
+        <div class="divider">OR</div>
+        <button class="btn btn-outline">Sign up</button>
+      </form>"#
+                ),
+            )
+            .markup(
+                r#"
     </div>
-  </div>
-  <div class="drawer-side"><label for="dash-drawer" class="drawer-overlay"></label>
-    <ul class="menu p-4 w-80 min-h-full bg-base-200"><li class="menu-title">Menu</li><li><a class="active">Overview</a></li><li><a>Analytics</a></li><li><a>Settings</a></li></ul>
-  </div>
-</div>"#
-        )
+  </main>
+</div>"#,
+            )
+    }
+
+    fn store(t: &str, v: &Variant, ids: &mut IdAllocator, s: &mut Sample) -> Page {
+        let [text_xl, py16, text_5xl, py6, p8, text_2xl, mb6, gap6] = [
+            v.text("xl"),
+            v.space("py", 16),
+            v.text("5xl"),
+            v.space("py", 6),
+            v.space("p", 8),
+            v.text("2xl"),
+            v.space("mb", 6),
+            v.space("gap", 6),
+        ];
+        let featured = ids.next("products");
+        let products: String = s
+            .many(sample::PRODUCTS, s.count("cards", 1))
+            .into_iter()
+            .map(|product| {
+                let price = s.price(12, 180);
+                format!(
+                    r#"        <div class="card bg-base-100 shadow"><figure><img src="https://picsum.photos/400/300" alt="{product}" /></figure><div class="card-body"><h3 class="card-title">{product}</h3><p>{price}</p><button class="btn btn-primary btn-sm">Add to Cart</button></div></div>
+"#
+                )
+            })
+            .collect();
+        Page::default()
+            .markup(r#"<div class="min-h-screen bg-base-100">"#)
+            .section(
+                "navbar",
+                format!(
+                    r#"
+  <nav class="navbar bg-base-100 border-b" aria-label="Main"><div class="flex-1"><a class="btn btn-ghost {text_xl}">{t}</a></div>
+    <div class="flex-none"><button class="btn btn-ghost btn-circle" aria-label="Cart, 3 items"><span class="indicator"><svg class="h-5 w-5" aria-hidden="true" fill="none" viewBox="0 0 24 24" stroke="currentColor"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M3 3h2l.4 2M7 13h10l4-8H5.4M7 13L5.4 5M7 13l-2.293 2.293c-.63.63-.184 1.707.707 1.707H17m0 0a2 2 0 100 4 2 2 0 000-4zm-8 2a2 2 0 11-4 0 2 2 0 014 0z" /></svg><span class="badge badge-sm indicator-item">3</span></span></button></div>
+  </nav>"#
+                ),
+            )
+            .section(
+                "hero",
+                format!(
+                    r#"
+  <header class="hero bg-base-200 {py16}"><div class="hero-content text-center"><div><h1 class="{text_5xl} font-bold">{t}</h1><p class="{py6}">Discover amazing products</p><button class="btn btn-primary">Shop Now</button></div></div></header>"#
+                ),
+            )
+            .section(
+                "products",
+                format!(
+                    r#"
+  <main class="container mx-auto {p8}">
+    <section aria-labelledby="{featured}">
+      <h2 id="{featured}" class="{text_2xl} font-bold {mb6}">Featured Products</h2>
+      <div class="grid grid-cols-1 md:grid-cols-3 lg:grid-cols-4 {gap6}">
+{products}      </div>
+    </section>
+  </main>"#
+                ),
+            )
+            .markup("\n</div>")
+    }
+
+    /// An analytics page with one canvas per chart type, ids allocated like
+    /// `create_chart`'s.
+    fn analytics(title: &str, charts: &[&str], v: &Variant, ids: &mut IdAllocator) -> Page {
+        let charts: Vec<(&str, String)> = charts
+            .iter()
+            .map(|kind| (*kind, ids.next("chart")))
+            .collect();
+        snippets::analytics_page(title, &charts, v)
+    }
+}
+
+// ============================================================================
+// Command Registry - Slash command metadata
+// ============================================================================
+
+/// Single source of truth for slash command help, usage strings and the
+/// `extension.toml` descriptions.
+struct CommandSpec {
+    name: &'static str,
+    description: &'static str,
+    args: &'static str,
+    examples: &'static [&'static str],
+}
+
+impl CommandSpec {
+    const ALL: &[CommandSpec] = &[
+        CommandSpec {
+            name: "daisy-help",
+            description: "Show all Daisy Days commands or detailed help for one",
+            args: "[command]",
+            examples: &["/daisy-help", "/daisy-help daisy-layout"],
+        },
+        CommandSpec {
+            name: "daisy-manifest",
+            description: "Summarize everything Daisy Days offers: commands, layouts, concepts, components, themes and options",
+            args: "",
+            examples: &["/daisy-manifest"],
+        },
+        CommandSpec {
+            name: "daisy-search",
+            description: "Search DaisyUI documentation",
+            args: "<query> [limit] [offset]",
+            examples: &[
+                "/daisy-search modal",
+                "/daisy-search modal 10 20",
+                "/daisy-search form input --limit=5",
+            ],
+        },
+        CommandSpec {
+            name: "daisy-doc",
+            description: "Get documentation for a specific DaisyUI component",
+            args: "<component>[#section][, <component>...] [max-chars|full] [--sanitize=lenient|strict]",
+            examples: &[
+                "/daisy-doc button",
+                "/daisy-doc button#syntax",
+                "/daisy-doc modal 1500",
+                "/daisy-doc navbar, drawer, menu",
+                "/daisy-doc modal --sanitize=strict",
+            ],
+        },
+        CommandSpec {
+            name: "daisy-class",
+            description: "Find which DaisyUI component a class belongs to and show its documentation",
+            args: "<class>",
+            examples: &["/daisy-class join-item", "/daisy-class .btn-primary"],
+        },
+        CommandSpec {
+            name: "daisy-examples",
+            description: "Show every code example for a DaisyUI component with captions",
+            args: "<component> [count]",
+            examples: &["/daisy-examples dropdown", "/daisy-examples modal 2"],
+        },
+        CommandSpec {
+            name: "daisy-example",
+            description: "Show one DaisyUI component example by index, or all of them numbered",
+            args: "<component> [index]",
+            examples: &["/daisy-example modal", "/daisy-example modal 2"],
+        },
+        CommandSpec {
+            name: "daisy-modifiers",
+            description: "List a DaisyUI component's modifier classes grouped by size, color and style",
+            args: "<component>",
+            examples: &["/daisy-modifiers button", "/daisy-modifiers badge"],
+        },
+        CommandSpec {
+            name: "daisy-usedby",
+            description: "List the DaisyUI components whose docs mention a component",
+            args: "<component>",
+            examples: &["/daisy-usedby menu", "/daisy-usedby button"],
+        },
+        CommandSpec {
+            name: "daisy-playground",
+            description: "Full-page matrix of a component's colors, sizes and styles, each labeled with its classes",
+            args: "<component>",
+            examples: &["/daisy-playground button", "/daisy-playground alert"],
+        },
+        CommandSpec {
+            name: "daisy-components",
+            description: "List all available DaisyUI components",
+            args: "",
+            examples: &["/daisy-components"],
+        },
+        CommandSpec {
+            name: "daisy-cheatsheet",
+            description: "Table of DaisyUI components with their main class and a one-line summary",
+            args: "[category|name]",
+            examples: &["/daisy-cheatsheet", "/daisy-cheatsheet nav"],
+        },
+        CommandSpec {
+            name: "daisy-random",
+            description: "Show the docs of a random DaisyUI component, optionally from one category",
+            args: "[category]",
+            examples: &["/daisy-random", "/daisy-random navigation"],
+        },
+        CommandSpec {
+            name: "daisy-concept",
+            description: "Get a design concept by key or keywords; see /daisy-concepts for the list",
+            args: "<concept or keywords> [example] [html|jsx|vue|svelte|leptos|yew]",
+            examples: &[
+                "/daisy-concept glassmorphism",
+                "/daisy-concept frosted",
+                "/daisy-concept glassmorphism jsx",
+                "/daisy-concept glassmorphism navbar",
+            ],
+        },
+        CommandSpec {
+            name: "daisy-concepts",
+            description: "List design concepts by category, optionally one category",
+            args: "[category]",
+            examples: &["/daisy-concepts", "/daisy-concepts visual-style"],
+        },
+        CommandSpec {
+            name: "daisy-a11y",
+            description: "Check HTML for unlabelled fields, nameless buttons and images without alt",
+            args: "<html...>",
+            examples: &["/daisy-a11y <button class=\"btn\"><svg></svg></button>"],
+        },
+        CommandSpec {
+            name: "daisy-compare-concepts",
+            description: "Compare two design concepts side by side",
+            args: "<concept> <concept> | <keywords> vs <keywords>",
+            examples: &[
+                "/daisy-compare-concepts glassmorphism neumorphism",
+                "/daisy-compare-concepts dark theme vs frosted",
+            ],
+        },
+        CommandSpec {
+            name: "daisy-style-recipe",
+            description: "Combine a visual style, a layout and a motion concept into one recipe",
+            args: "[seed] [concept...]",
+            examples: &[
+                "/daisy-style-recipe",
+                "/daisy-style-recipe 42",
+                "/daisy-style-recipe darkmode",
+            ],
+        },
+        CommandSpec {
+            name: "daisy-concept-theme",
+            description: "Generate a daisyUI theme in a design concept's colors",
+            args: "<concept> [themeName]",
+            examples: &[
+                "/daisy-concept-theme darkmode",
+                "/daisy-concept-theme retro-terminal crt",
+            ],
+        },
+        CommandSpec {
+            name: "daisy-layout",
+            description: "Generate an HTML layout (saas, blog, social, kanban, inbox, profile, docs, dashboard, auth, store, pricing, checkout, error, crud, app-landing, forum, analytics, settings, status, media, jobs, changelog, team, faq, survey)",
+            args: "<type> [title] [compact|comfortable|spacious] [--variant=NAME] [--theme=NAME] [--format=html|jsx|leptos|yew|askama|tera] [--jsx] [--placeholders=remote|local|svg|none] [--daisyui-version=5|4] [--purity=tailwind|daisy-only] [--annotate-responsive] [--rtl] [--footprint] [--full] [--whitespace=pretty|minified] [--minify] [--id-prefix=NAME] [--concept=NAME] [--cards=N] [--columns=N] [--posts=N] [--messages=N]",
+            examples: &[
+                "/daisy-layout saas Acme",
+                "/daisy-layout saas MyApp --concept glassmorphism",
+                "/daisy-layout blog Field Notes spacious",
+                "/daisy-layout saas Acme --full --theme=cupcake",
+                "/daisy-layout dashboard Admin Panel --jsx",
+                "/daisy-layout crud Team Members --format=askama",
+                "/daisy-layout dashboard Admin --theme=dark --placeholders=local",
+                "/daisy-layout docs Handbook --annotate-responsive",
+                "/daisy-layout store Acme --minify",
+                "/daisy-layout store Acme --cards=12",
+                "/daisy-layout kanban Sprint --columns=5 --cards=2",
+                "/daisy-layout auth Sign in --purity=daisy-only",
+                "/daisy-layout dashboard Admin --rtl",
+                "/daisy-layout blog Field Notes --placeholders=svg",
+            ],
+        },
+        CommandSpec {
+            name: "daisy-layouts",
+            description: "List all available layout types and their sections",
+            args: "",
+            examples: &["/daisy-layouts"],
+        },
+        CommandSpec {
+            name: "daisy-section",
+            description: "Generate one named section of a layout, like the saas hero or the dashboard sidebar",
+            args: "<layout> <section> [title] [--id-prefix=NAME] [--minify]",
+            examples: &[
+                "/daisy-section saas hero Acme",
+                "/daisy-section dashboard sidebar",
+                "/daisy-section blog navbar Field Notes",
+            ],
+        },
+        CommandSpec {
+            name: "daisy-compose",
+            description: "Stitch sections from one or more layouts into one page",
+            args: "<section>... [--title=NAME] [--id-prefix=NAME] [--minify]",
+            examples: &[
+                "/daisy-compose navbar hero pricing faq footer",
+                "/daisy-compose dashboard:navbar analytics:stats crud:table --title=Acme",
+            ],
+        },
+        CommandSpec {
+            name: "daisy-site",
+            description: "Generate a set of linked pages: startup or shop",
+            args: "<preset> [title] [--id-prefix=NAME] [--minify]",
+            examples: &["/daisy-site startup Acme", "/daisy-site shop Field Goods"],
+        },
+        CommandSpec {
+            name: "daisy-idea",
+            description: "Turn a prompt into a layout, or explain how the prompt was read",
+            args: "<prompt> [--explain]",
+            examples: &[
+                "/daisy-idea a playful task board called Sprinty",
+                "/daisy-idea admin analytics dashboard --explain",
+            ],
+        },
+        CommandSpec {
+            name: "daisy-reverse",
+            description: "Recognize the sections of an existing page and return a compose spec with confidence",
+            args: "<worktree path | pasted HTML>",
+            examples: &[
+                "/daisy-reverse site/index.html",
+                "/daisy-reverse <nav class=\"navbar\">…",
+            ],
+        },
+        CommandSpec {
+            name: "daisy-kbd",
+            description: "Render a keyboard shortcut as kbd elements (e.g. cmd+shift+p [mac|pc] [size])",
+            args: "<shortcut> [mac|pc] [xs|sm|md|lg|xl]",
+            examples: &["/daisy-kbd cmd+shift+p", "/daisy-kbd mod+k pc sm"],
+        },
+        CommandSpec {
+            name: "daisy-palette",
+            description: "Generate a command palette (⌘K) modal with its open script",
+            args: "[mac|pc] [--id-prefix=NAME]",
+            examples: &["/daisy-palette", "/daisy-palette pc"],
+        },
+        CommandSpec {
+            name: "daisy-block",
+            description: "Generate a marketing block (testimonials, logo-cloud, pricing-tiers) with optional items",
+            args: "<testimonials|logo-cloud|pricing-tiers> [items] [--footprint]",
+            examples: &[
+                "/daisy-block logo-cloud Acme; Globex; Hooli",
+                "/daisy-block pricing-tiers Free|$0|month|1 project; *Pro|$19|month|Unlimited projects",
+            ],
+        },
+        CommandSpec {
+            name: "daisy-badges",
+            description: "Generate status badges (active, pending, failed, ...), tag chips, or a tag input",
+            args: "<state,state,...|tags a,b|tag-input a,b> [--dot] [--colors=state=color,...] [--suggest=x,y] [--id-prefix=NAME]",
+            examples: &[
+                "/daisy-badges active,pending,failed,archived",
+                "/daisy-badges tag-input rust,wasm --suggest=zed,daisyui",
+                "/daisy-badges queued,running,done --dot --colors=queued=ghost",
+            ],
+        },
+        CommandSpec {
+            name: "daisy-avatar",
+            description: "Generate an avatar or avatar group from an image URL or name (size, shape, online/offline)",
+            args: "<url|name>[, ...] [size] [shape] [online|offline] [max]",
+            examples: &[
+                "/daisy-avatar Jane Doe lg online",
+                "/daisy-avatar Ann, Bob, Cy, Dee 2",
+            ],
+        },
+        CommandSpec {
+            name: "daisy-loading",
+            description: "Generate a loading state: spinner, progress, radial, button, card, or overlay",
+            args: "<kind> [size] [color] [value]",
+            examples: &["/daisy-loading dots lg primary", "/daisy-loading radial 40"],
+        },
+    ];
+
+    fn find(name: &str) -> Option<&'static CommandSpec> {
+        let name = name.trim_start_matches('/');
+        Self::ALL.iter().find(|c| c.name == name)
+    }
+
+    fn synopsis(&self) -> String {
+        if self.args.is_empty() {
+            format!("/{}", self.name)
+        } else {
+            format!("/{} {}", self.name, self.args)
+        }
+    }
+
+    /// Appended to argument errors so they always match the registry.
+    fn usage(name: &str) -> String {
+        Self::find(name)
+            .map(|c| format!("Usage: {}", c.synopsis()))
+            .unwrap_or_default()
     }
 
-    fn auth(t: &str) -> String {
+    fn table() -> String {
+        let rows = Self::ALL
+            .iter()
+            .map(|c| {
+                format!(
+                    "| `{}` | {} |",
+                    c.synopsis().replace('|', "\\|"),
+                    c.description
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
         format!(
-            r#"<div class="hero min-h-screen bg-base-200">
-  <div class="card w-full max-w-sm shadow-2xl bg-base-100">
-    <form class="card-body">
-      <h1 class="text-2xl font-bold text-center">{t}</h1>
-      <div class="form-control"><label class="label"><span class="label-text">Email</span></label><input type="email" class="input input-bordered" required /></div>
-      <div class="form-control"><label class="label"><span class="label-text">Password</span></label><input type="password" class="input input-bordered" required /><label class="label"><a class="label-text-alt link link-hover">Forgot password?</a></label></div>
-      <div class="form-control mt-6"><button class="btn btn-primary">Login</button></div>
-      <div class="divider">OR</div>
-      <button class="btn btn-outline">Sign up</button>
-    </form>
-  </div>
-</div>"#
+            "## Daisy Days Commands\n\n| Command | Description |\n|---------|-------------|\n{}\n\nRun `/daisy-help <command>` for examples.",
+            rows
         )
     }
 
-    fn store(t: &str) -> String {
+    fn detail(&self) -> String {
+        let examples = self
+            .examples
+            .iter()
+            .map(|e| format!("    {}", e))
+            .collect::<Vec<_>>()
+            .join("\n");
         format!(
-            r#"<div class="min-h-screen bg-base-100">
-  <div class="navbar bg-base-100 border-b"><div class="flex-1"><a class="btn btn-ghost text-xl">{t}</a></div>
-    <div class="flex-none"><button class="btn btn-ghost btn-circle"><span class="indicator"><svg class="h-5 w-5" fill="none" viewBox="0 0 24 24" stroke="currentColor"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M3 3h2l.4 2M7 13h10l4-8H5.4M7 13L5.4 5M7 13l-2.293 2.293c-.63.63-.184 1.707.707 1.707H17m0 0a2 2 0 100 4 2 2 0 000-4zm-8 2a2 2 0 11-4 0 2 2 0 014 0z" /></svg><span class="badge badge-sm indicator-item">3</span></span></button></div>
-  </div>
-  <div class="hero bg-base-200 py-16"><div class="hero-content text-center"><div><h1 class="text-5xl font-bold">{t}</h1><p class="py-6">Discover amazing products</p><button class="btn btn-primary">Shop Now</button></div></div></div>
-  <div class="container mx-auto p-8">
-    <h2 class="text-2xl font-bold mb-6">Featured Products</h2>
-    <div class="grid grid-cols-1 md:grid-cols-3 lg:grid-cols-4 gap-6">
-      <div class="card bg-base-100 shadow"><figure><img src="https://picsum.photos/400/300" /></figure><div class="card-body"><h3 class="card-title">Product</h3><p>$99.00</p><button class="btn btn-primary btn-sm">Add to Cart</button></div></div>
-    </div>
-  </div>
-</div>"#
+            "## /{}\n\n{}\n\n**Usage:** `{}`\n\n**Examples:**\n\n{}",
+            self.name,
+            self.description,
+            self.synopsis(),
+            examples
         )
     }
+
+    /// The `[slash_commands.*]` tables for `extension.toml`, printed by
+    /// `/daisy-help --toml`.
+    fn manifest() -> String {
+        Self::ALL
+            .iter()
+            .map(|c| {
+                format!(
+                    "[slash_commands.{}]\ndescription = \"{}\"\nrequires_argument = {}\n",
+                    c.name,
+                    c.description,
+                    c.args.starts_with('<')
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+// ============================================================================
+// Settings - User defaults from Zed and the worktree
+// ============================================================================
+
+/// Zed's `lsp.daisy-days.settings` for the worktree, with any key its
+/// [`settings::FILE`] sets taking precedence. A missing worktree, missing
+/// settings or malformed JSON all fall back to the built-in defaults rather
+/// than failing the command.
+fn load_settings(worktree: Option<&zed::Worktree>) -> Settings {
+    let Some(worktree) = worktree else {
+        return Settings::default();
+    };
+    let user = zed::settings::LspSettings::for_worktree(settings::LSP_NAME, worktree)
+        .ok()
+        .and_then(|lsp| lsp.settings);
+    let project = worktree
+        .read_text_file(settings::FILE)
+        .ok()
+        .and_then(|text| zed::serde_json::from_str::<zed::serde_json::Value>(&text).ok());
+    let parse = |value: Option<zed::serde_json::Value>| {
+        value.map_or_else(Settings::default, |value| settings_from_json(&value))
+    };
+    parse(project).over(parse(user))
+}
+
+/// Settings from a JSON object, either Zed's or the worktree file's.
+fn settings_from_json(value: &zed::serde_json::Value) -> Settings {
+    Settings::from_values(
+        |key| {
+            value.get(key).and_then(|v| match v {
+                zed::serde_json::Value::String(s) => Some(s.clone()),
+                zed::serde_json::Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            })
+        },
+        |key| value.get(key).and_then(|v| v.as_u64()),
+    )
 }
 
 // ============================================================================
@@ -489,86 +1248,761 @@ impl LayoutEngine {
 // ============================================================================
 
 struct DaisyDaysExtension {
-    docs: DocsCache,
-    concepts: ConceptEngine,
+    /// Docs parsed from the bundled llms.txt.
+    docs: Arc<DocsCache>,
+    /// Docs parsed from a project's override file, by worktree root, with a
+    /// hash of the text they came from so an edited file is reparsed.
+    overrides: Mutex<HashMap<String, (u64, Arc<DocsCache>)>>,
+    /// Docs the last slash command used, cached for completions like
+    /// `settings`.
+    active: Mutex<Arc<DocsCache>>,
+    /// The bundled concepts.
+    concepts: Arc<ConceptEngine>,
+    /// Concepts the last slash command used, project ones included, cached
+    /// for completions like `active`.
+    active_concepts: Mutex<Arc<ConceptEngine>>,
+    /// Last settings seen by a slash command. Completions get no worktree,
+    /// so they read this instead.
+    settings: Mutex<Settings>,
+    /// Template layouts from the last slash command's worktree, cached for
+    /// completions like `settings`.
+    layouts: Mutex<plugins::Registry>,
+    /// Title suggestions from the last slash command's worktree, cached for
+    /// `/daisy-layout` title completions.
+    titles: Mutex<Vec<String>>,
+    /// `/daisy-random` and unseeded `/daisy-style-recipe` calls so far; each
+    /// one seeds the next pick.
+    draws: AtomicU64,
+    /// Theme names `/daisy-concept-theme` has generated, accepted by
+    /// `--theme` alongside the built-in ones.
+    themes: Mutex<Vec<String>>,
 }
 
-impl zed::Extension for DaisyDaysExtension {
-    fn new() -> Self {
-        Self {
-            docs: DocsCache::load(),
-            concepts: ConceptEngine::new(),
+impl DaisyDaysExtension {
+    /// Markdown summary of the registries behind each command; the MCP
+    /// server's `daisyui_manifest` has the full JSON.
+    fn manifest(
+        &self,
+        docs: &DocsCache,
+        templates: &plugins::Registry,
+        concepts: &ConceptEngine,
+    ) -> String {
+        let layouts: Vec<String> = LayoutEngine::LAYOUTS
+            .iter()
+            .map(|l| l.to_string())
+            .chain(
+                templates
+                    .layouts
+                    .iter()
+                    .map(|t| format!("{} (template)", t.key)),
+            )
+            .collect();
+        let commands: Vec<String> = CommandSpec::ALL
+            .iter()
+            .map(|c| format!("/{}", c.name))
+            .collect();
+        let rows = [
+            ("Commands", commands),
+            ("Layouts", layouts),
+            ("Concepts", concepts.list_concepts()),
+            ("Components", docs.list_components()),
+            (
+                "Themes",
+                theme::BUILT_IN.iter().map(|t| t.to_string()).collect(),
+            ),
+            (
+                "Blocks",
+                snippets::BLOCKS.iter().map(|b| b.to_string()).collect(),
+            ),
+        ];
+        let mut text = format!(
+            "## Daisy Days {}\n\n| Area | Count | Entries |\n|---|---|---|\n",
+            env!("CARGO_PKG_VERSION")
+        );
+        for (area, entries) in rows {
+            text.push_str(&format!(
+                "| {} | {} | {} |\n",
+                area,
+                entries.len(),
+                entries.join(", ")
+            ));
         }
+        text.push_str("\n### Options\n");
+        for (flag, values) in [
+            ("format", Settings::FORMATS),
+            ("placeholders", images::STRATEGIES),
+            ("daisyui-version", Settings::VERSIONS),
+            ("purity", purity::MODES),
+            ("whitespace", tidy::Style::NAMES),
+            ("sanitize", Settings::SANITIZE),
+        ] {
+            text.push_str(&format!("\n- `--{}`: {}", flag, values.join(", ")));
+        }
+        text.push_str("\n\nThe MCP server's `daisyui_manifest` tool returns the same data as JSON, with tags and sections per layout and class-name categories per component.");
+        text
     }
 
-    fn run_slash_command(
+    /// Docs for `worktree`: its first [`DOCS_OVERRIDES`] file when there is
+    /// one, else the bundled copy. Parsed overrides are cached per worktree
+    /// root until the file's text changes.
+    fn docs_for(&self, worktree: Option<&zed::Worktree>) -> Arc<DocsCache> {
+        let Some(worktree) = worktree else {
+            return self.docs.clone();
+        };
+        let root = worktree.root_path();
+        let Some((path, text)) = DOCS_OVERRIDES
+            .iter()
+            .find_map(|path| Some((*path, worktree.read_text_file(path).ok()?)))
+        else {
+            if let Ok(mut overrides) = self.overrides.lock() {
+                overrides.remove(&root);
+            }
+            return self.docs.clone();
+        };
+        let mut hasher = DefaultHasher::new();
+        text.hash(&mut hasher);
+        let hash = hasher.finish();
+        let load = || {
+            let mut docs = DocsCache::load(&text);
+            docs.origin = Some(path);
+            Arc::new(docs)
+        };
+        let Ok(mut overrides) = self.overrides.lock() else {
+            return load();
+        };
+        match overrides.get(&root) {
+            Some((cached, docs)) if *cached == hash => docs.clone(),
+            _ => {
+                let docs = load();
+                overrides.insert(root, (hash, docs.clone()));
+                docs
+            }
+        }
+    }
+
+    /// Concepts for `worktree`: the bundled ones, overridden and extended by
+    /// its [`concepts::PROJECT_FILE`] when it has one.
+    fn concepts_for(&self, worktree: Option<&zed::Worktree>) -> Result<Arc<ConceptEngine>, String> {
+        match worktree.and_then(|w| w.read_text_file(concepts::PROJECT_FILE).ok()) {
+            Some(text) => self.concepts.with_project(&text).map(Arc::new),
+            None => Ok(self.concepts.clone()),
+        }
+    }
+
+    /// Template layouts listed in `.daisy-days/layouts/index.txt`. Extensions
+    /// can read worktree files but not list directories, hence the index.
+    fn load_templates(worktree: Option<&zed::Worktree>) -> plugins::Registry {
+        let Some(worktree) = worktree else {
+            return plugins::Registry::default();
+        };
+        let Ok(index) = worktree.read_text_file(&format!("{}/{}", plugins::DIR, TEMPLATE_INDEX))
+        else {
+            return plugins::Registry::default();
+        };
+        let mut errors = Vec::new();
+        let mut files = Vec::new();
+        for name in index.lines().map(str::trim) {
+            if name.is_empty() || name.starts_with('#') {
+                continue;
+            }
+            match worktree.read_text_file(&format!("{}/{}", plugins::DIR, name)) {
+                Ok(text) => files.push((name.to_string(), text)),
+                Err(e) => errors.push(format!(
+                    "{}: listed in {} but unreadable: {}",
+                    name, TEMPLATE_INDEX, e
+                )),
+            }
+        }
+        let mut registry = plugins::Registry::load(files, LayoutEngine::LAYOUTS);
+        errors.append(&mut registry.errors);
+        registry.errors = errors;
+        registry
+    }
+
+    /// Renders a marketing block from the compact syntax: items separated by
+    /// `;`, fields by `|`. Pricing features are comma-separated and a leading
+    /// `*` on a tier name highlights it. No items means the defaults.
+    fn block_from_args(kind: &str, spec: &str) -> Result<String, String> {
+        let items: Vec<Vec<&str>> = spec
+            .split(';')
+            .map(str::trim)
+            .filter(|i| !i.is_empty())
+            .map(|i| i.split('|').map(str::trim).collect())
+            .collect();
+        match kind {
+            "testimonials" => {
+                let items = if items.is_empty() {
+                    snippets::default_testimonials()
+                } else {
+                    items
+                        .iter()
+                        .map(|f| snippets::Testimonial {
+                            quote: f[0].to_string(),
+                            name: f.get(1).unwrap_or(&"Anonymous").to_string(),
+                            role: f.get(2).unwrap_or(&"").to_string(),
+                            avatar: f.get(3).map(|s| s.to_string()),
+                        })
+                        .collect()
+                };
+                Ok(snippets::testimonials(
+                    &items,
+                    &IdAllocator::default().next("testimonials"),
+                ))
+            }
+            "logo-cloud" => {
+                let items = if items.is_empty() {
+                    snippets::default_logos()
+                } else {
+                    items
+                        .iter()
+                        .map(|f| snippets::Logo {
+                            name: f[0].to_string(),
+                            image: f.get(1).map(|s| s.to_string()),
+                        })
+                        .collect()
+                };
+                Ok(snippets::logo_cloud(
+                    &items,
+                    &IdAllocator::default().next("logos"),
+                ))
+            }
+            "pricing-tiers" => {
+                let items = if items.is_empty() {
+                    snippets::default_pricing_tiers()
+                } else {
+                    items
+                        .iter()
+                        .map(|f| snippets::PricingTier {
+                            name: f[0].trim_start_matches('*').to_string(),
+                            price: f.get(1).unwrap_or(&"$0").to_string(),
+                            period: f.get(2).unwrap_or(&"month").to_string(),
+                            features: f
+                                .get(3)
+                                .map(|fs| {
+                                    fs.split(',')
+                                        .map(str::trim)
+                                        .filter(|x| !x.is_empty())
+                                        .map(String::from)
+                                        .collect()
+                                })
+                                .unwrap_or_default(),
+                            highlighted: f[0].starts_with('*'),
+                        })
+                        .collect()
+                };
+                Ok(snippets::pricing_tiers(
+                    &items,
+                    &IdAllocator::default().next("pricing"),
+                ))
+            }
+            _ => Err(format!(
+                "Unknown block '{}'. Available: {}",
+                kind,
+                snippets::BLOCKS.join(", ")
+            )),
+        }
+    }
+
+    /// Option tokens (size, shape, presence, group max) may appear anywhere;
+    /// the remaining words form the source. Commas make it a group.
+    fn avatar_from_args(args: &[String]) -> Result<String, String> {
+        let (mut size, mut shape, mut presence, mut max) =
+            (AvatarSize::Md, AvatarShape::Circle, None, 3);
+        let mut words = Vec::new();
+        for arg in args {
+            let token = arg.to_lowercase();
+            if let Some(s) = AvatarSize::parse(&token) {
+                size = s;
+            } else if let Some(s) = AvatarShape::parse(&token) {
+                shape = s;
+            } else if let Some(p) = snippets::Presence::parse(&token) {
+                presence = Some(p);
+            } else if let Ok(n) = token.parse::<usize>() {
+                max = n;
+            } else {
+                words.push(arg.as_str());
+            }
+        }
+        let source = words.join(" ");
+        if source.is_empty() {
+            return Err(format!(
+                "Please provide an image URL or a name. {}. Options: {} | {} | {}",
+                CommandSpec::usage("daisy-avatar"),
+                AvatarSize::NAMES.join(", "),
+                AvatarShape::NAMES.join(", "),
+                snippets::Presence::NAMES.join(", ")
+            ));
+        }
+        if source.contains(',') {
+            let sources: Vec<&str> = source
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .collect();
+            return Ok(snippets::avatar_group(&sources, size, max));
+        }
+        Ok(snippets::avatar(&source, size, shape, presence))
+    }
+
+    /// Header, code and optional report as separate sections, so Zed can fold
+    /// the code on its own and the header can be cited without it. Each part
+    /// is `(text, label)`; an empty report is left out.
+    fn sectioned_output(
+        header: (String, String),
+        code: (String, String),
+        report: Option<(String, String)>,
+    ) -> SlashCommandOutput {
+        let mut text = String::new();
+        let mut sections = Vec::new();
+        let parts = [
+            Some(header),
+            Some(code),
+            report.filter(|(r, _)| !r.is_empty()),
+        ];
+        for (body, label) in parts.into_iter().flatten() {
+            if !text.is_empty() {
+                text.push_str("\n\n");
+            }
+            let start = text.len();
+            text.push_str(&body);
+            sections.push(SlashCommandOutputSection {
+                range: (start..text.len()).into(),
+                label,
+            });
+        }
+        SlashCommandOutput { sections, text }
+    }
+
+    fn size_label(bytes: usize) -> String {
+        if bytes >= 1024 {
+            format!("{:.1} KB", bytes as f64 / 1024.0)
+        } else {
+            format!("{} B", bytes)
+        }
+    }
+
+    fn run_command<C: Clock>(
         &self,
-        command: SlashCommand,
+        command: &str,
         args: Vec<String>,
-        _worktree: Option<&zed::Worktree>,
+        worktree: Option<&zed::Worktree>,
+        timer: &mut Timer<C>,
     ) -> Result<SlashCommandOutput, String> {
-        match command.name.as_str() {
+        let settings = load_settings(worktree);
+        if let Ok(mut cached) = self.settings.lock() {
+            *cached = settings.clone();
+        }
+        let templates = Self::load_templates(worktree);
+        if let Ok(mut cached) = self.layouts.lock() {
+            *cached = templates.clone();
+        }
+        if let (Some(worktree), Ok(mut cached)) = (worktree, self.titles.lock()) {
+            *cached = project::titles(
+                &worktree.root_path(),
+                worktree.read_text_file("package.json").ok().as_deref(),
+                worktree.read_text_file("Cargo.toml").ok().as_deref(),
+            );
+        }
+        let docs = self.docs_for(worktree);
+        if let Ok(mut cached) = self.active.lock() {
+            *cached = docs.clone();
+        }
+        // A broken project file only fails the commands that use concepts.
+        let concepts = self.concepts_for(worktree);
+        if let Ok(mut cached) = self.active_concepts.lock() {
+            *cached = concepts.clone().unwrap_or_else(|_| self.concepts.clone());
+        }
+        let (args, flags) = Settings::split_flags(&args);
+        match command {
             "daisy-search" => {
+                // Up to two trailing numbers are the limit and offset, as in
+                // `/daisy-search modal 10 20`; the query keeps at least one word.
+                let mut args = args;
+                let mut numbers = Vec::new();
+                while numbers.len() < 2
+                    && args.len() > 1
+                    && args.last().is_some_and(|a| a.parse::<usize>().is_ok())
+                {
+                    numbers.insert(0, args.pop().unwrap_or_default());
+                }
                 let query = args.join(" ");
                 if query.is_empty() {
-                    return Err("Please provide a search query".into());
+                    return Err(format!(
+                        "Please provide a search query. {}",
+                        CommandSpec::usage("daisy-search")
+                    ));
+                }
+                let default = index::PAGE_SIZE.to_string();
+                let limit = match numbers.first() {
+                    Some(n) => n.clone(),
+                    None => Settings::resolve(
+                        &flags,
+                        "limit",
+                        settings.search_results.map(|n| n.to_string()).as_deref(),
+                        &default,
+                    ),
+                };
+                let limit = limit
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|n| *n >= 1)
+                    .ok_or_else(|| format!("--limit must be 1 or more, got '{}'", limit))?;
+                let offset = match numbers.get(1) {
+                    Some(n) => n.clone(),
+                    None => Settings::resolve(&flags, "offset", None, "0"),
                 }
-                let results = self.docs.search(&query);
-                if results.is_empty() {
+                .parse::<usize>()
+                .map_err(|_| "--offset must be a number".to_string())?;
+                timer.work(docs.components.len());
+                let (results, total) = docs.search(&query, limit, offset);
+                if total == 0 {
                     return Ok(SlashCommandOutput {
                         text: format!("No results found for '{}'", query),
                         sections: vec![],
                     });
                 }
-                let text = results
-                    .iter()
-                    .map(|(name, _, score)| format!("- **{}** (score: {})", name, score))
-                    .collect::<Vec<_>>()
-                    .join("\n");
-                let output = format!("## Search Results for '{}'\n\n{}", query, text);
-                Ok(SlashCommandOutput {
-                    sections: vec![SlashCommandOutputSection {
-                        range: (0..output.len()).into(),
-                        label: "Search Results".into(),
-                    }],
-                    text: output,
-                })
+                let mut status = index::showing(offset, results.len(), total);
+                if let Some(note) = docs.alias_note(&query) {
+                    status = format!("{}. {}", status, note);
+                }
+                let results: Vec<(String, String, usize)> = results
+                    .into_iter()
+                    .map(|(name, snippet, score)| (name, snippet.markdown(), score))
+                    .collect();
+                Ok(output::search(&query, &status, &results).into())
             }
             "daisy-doc" => {
+                // A trailing number is a character budget; `full` (the
+                // default) shows everything.
+                let mut args = args;
+                let budget = match args.last().map(String::as_str) {
+                    Some(last) if args.len() > 1 && last == "full" => {
+                        args.pop();
+                        None
+                    }
+                    Some(last) if args.len() > 1 && last.parse::<usize>().is_ok() => {
+                        args.pop().and_then(|n| n.parse::<usize>().ok())
+                    }
+                    _ => None,
+                };
                 let name = args.join(" ");
                 if name.is_empty() {
-                    return Err("Please provide a component name".into());
+                    return Err(format!(
+                        "Please provide a component name. {}",
+                        CommandSpec::usage("daisy-doc")
+                    ));
                 }
-                match self.docs.get_doc(&name) {
-                    Some(doc) => Ok(SlashCommandOutput {
-                        sections: vec![SlashCommandOutputSection {
-                            range: (0..doc.len()).into(),
-                            label: format!("Doc: {}", name),
-                        }],
-                        text: doc,
-                    }),
-                    None => Err(format!("Documentation not found for '{}'", name)),
+                let mode =
+                    Settings::resolve(&flags, "sanitize", settings.sanitize.as_deref(), "lenient");
+                Settings::check("sanitize", &mode, Settings::SANITIZE)?;
+                let mode = if mode == "strict" {
+                    docs::Sanitize::Strict
+                } else {
+                    docs::Sanitize::Lenient
+                };
+                let render = |(found, doc, note): (String, String, Option<String>)| {
+                    let mut doc = docs::sanitize_markdown(&doc, mode);
+                    if let Some(budget) = budget {
+                        doc = docs::truncate(&doc, budget, "run with full to see everything");
+                    }
+                    if let Some(note) = note {
+                        doc = format!("> {}\n\n{}", note, doc);
+                    }
+                    (format!("Doc: {}", found), doc)
+                };
+                let mut out = output::Output::default();
+                if !name.contains(',') {
+                    let (label, doc) = render(docs.find_section(&name)?);
+                    out.section(label, &doc);
+                    return Ok(out.into());
                 }
+                // `navbar, drawer, menu`: one section per doc, and names that
+                // don't resolve are listed at the end instead of failing.
+                let mut missing = Vec::new();
+                for name in name.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+                    match docs.find_section(name) {
+                        Ok(found) => {
+                            let (label, doc) = render(found);
+                            if !out.text.is_empty() {
+                                out.push("\n\n");
+                            }
+                            out.section(label, &doc);
+                        }
+                        Err(_) => missing.push(name),
+                    }
+                }
+                if out.sections.is_empty() {
+                    return Err(format!(
+                        "Documentation not found for {}",
+                        missing.join(", ")
+                    ));
+                }
+                if !missing.is_empty() {
+                    out.push("\n\n");
+                    out.section("Not found", &format!("> Not found: {}", missing.join(", ")));
+                }
+                Ok(out.into())
             }
-            "daisy-components" => {
-                let components = self.docs.list_components();
-                let text = format!("## DaisyUI Components\n\n{}", components.join(", "));
+            "daisy-class" => {
+                let class = args.first().ok_or_else(|| {
+                    format!(
+                        "Please provide a class name. {}",
+                        CommandSpec::usage("daisy-class")
+                    )
+                })?;
+                let (component, text) = docs.describe_class(class, docs::Sanitize::Lenient)?;
                 Ok(SlashCommandOutput {
                     sections: vec![SlashCommandOutputSection {
                         range: (0..text.len()).into(),
-                        label: "Components List".into(),
+                        label: format!("Doc: {}", component),
                     }],
                     text,
                 })
             }
-            "daisy-concept" => {
-                let name = args.join(" ");
-                if name.is_empty() {
-                    return Err("Please provide a concept name".into());
+            "daisy-examples" => {
+                let name = args.first().ok_or_else(|| {
+                    format!(
+                        "Please provide a component name. {}",
+                        CommandSpec::usage("daisy-examples")
+                    )
+                })?;
+                let count = match args.get(1) {
+                    Some(n) => Some(
+                        n.parse::<usize>()
+                            .map_err(|_| format!("Count must be a number, got '{}'", n))?,
+                    ),
+                    None => None,
+                };
+                let (name, _, note) = docs.find_doc(name)?;
+                let examples = docs.get_examples(&name);
+                if examples.is_empty() {
+                    return Err(format!("No examples found for '{}'", name));
+                }
+                let mut text = docs::sanitize_markdown(
+                    &docs::examples_markdown(&name, examples, count),
+                    docs::Sanitize::Lenient,
+                );
+                if let Some(note) = note {
+                    text = format!("> {}\n\n{}", note, text);
+                }
+                Ok(SlashCommandOutput {
+                    sections: vec![SlashCommandOutputSection {
+                        range: (0..text.len()).into(),
+                        label: format!("Examples: {}", name),
+                    }],
+                    text,
+                })
+            }
+            "daisy-example" => {
+                let name = args.first().ok_or_else(|| {
+                    format!(
+                        "Please provide a component name. {}",
+                        CommandSpec::usage("daisy-example")
+                    )
+                })?;
+                let index = match args.get(1) {
+                    Some(n) => Some(
+                        n.parse::<usize>()
+                            .map_err(|_| format!("Index must be a number, got '{}'", n))?,
+                    ),
+                    None => None,
+                };
+                let (name, _, note) = docs.find_doc(name)?;
+                let picked = docs::pick_example(&name, docs.get_examples(&name), index)?;
+                let mut text = docs::sanitize_markdown(&picked, docs::Sanitize::Lenient);
+                if let Some(note) = note {
+                    text = format!("> {}\n\n{}", note, text);
+                }
+                Ok(SlashCommandOutput {
+                    sections: vec![SlashCommandOutputSection {
+                        range: (0..text.len()).into(),
+                        label: match index {
+                            Some(i) => format!("Example {}: {}", i, name),
+                            None => format!("Examples: {}", name),
+                        },
+                    }],
+                    text,
+                })
+            }
+            "daisy-playground" => {
+                let name = args.first().ok_or_else(|| {
+                    format!(
+                        "Please provide a component name. {}",
+                        CommandSpec::usage("daisy-playground")
+                    )
+                })?;
+                let (name, doc, note) = docs.find_doc(name)?;
+                let name = name.as_str();
+                let playground = playground::build(name, &doc);
+                let mut header = format!(
+                    "## {} playground\n\n{} Save the page as an .html file and open it in a browser.",
+                    name,
+                    playground.summary(name)
+                );
+                if let Some(note) = note {
+                    header = format!("> {}\n\n{}", note, header);
+                }
+                Ok(Self::sectioned_output(
+                    (header, format!("Playground: {}", name)),
+                    (
+                        format!("```html\n{}\n```", playground.html),
+                        format!(
+                            "{} html ({})",
+                            name,
+                            Self::size_label(playground.html.len())
+                        ),
+                    ),
+                    None,
+                ))
+            }
+            "daisy-manifest" => {
+                let text = self.manifest(
+                    &docs,
+                    &templates,
+                    concepts.as_deref().unwrap_or(&self.concepts),
+                );
+                Ok(SlashCommandOutput {
+                    sections: vec![SlashCommandOutputSection {
+                        range: (0..text.len()).into(),
+                        label: "Manifest".into(),
+                    }],
+                    text,
+                })
+            }
+            "daisy-components" => {
+                let components = docs.list_components();
+                let mut text = format!("## DaisyUI Components\n\n{}", components.join(", "));
+                if let Some(origin) = docs.origin {
+                    text = format!("> Docs from the project's `{}`\n\n{}", origin, text);
+                }
+                if let Some(diagnostic) = &docs.diagnostic {
+                    text = format!("> ⚠️ {}\n\n{}", diagnostic, text);
+                }
+                Ok(SlashCommandOutput {
+                    sections: vec![SlashCommandOutputSection {
+                        range: (0..text.len()).into(),
+                        label: "Components List".into(),
+                    }],
+                    text,
+                })
+            }
+            "daisy-modifiers" => {
+                let name = args.first().ok_or_else(|| {
+                    format!(
+                        "Please provide a component name. {}",
+                        CommandSpec::usage("daisy-modifiers")
+                    )
+                })?;
+                let (name, _, note) = docs.find_doc(name)?;
+                let mut text = docs::format_modifiers(&name, docs.get_modifiers(&name));
+                if let Some(note) = note {
+                    text = format!("> {}\n\n{}", note, text);
                 }
-                match self.concepts.get_concept(&name) {
-                    Some(c) => {
-                        let text = c.to_display();
+                Ok(SlashCommandOutput {
+                    sections: vec![SlashCommandOutputSection {
+                        range: (0..text.len()).into(),
+                        label: format!("Modifiers: {}", name),
+                    }],
+                    text,
+                })
+            }
+            "daisy-usedby" => {
+                let name = args.first().ok_or_else(|| {
+                    format!(
+                        "Please provide a component name. {}",
+                        CommandSpec::usage("daisy-usedby")
+                    )
+                })?;
+                let (name, _, note) = docs.find_doc(name)?;
+                let mut text = docs::used_by_markdown(&name, &docs.references_to(&name));
+                if let Some(note) = note {
+                    text = format!("> {}\n\n{}", note, text);
+                }
+                Ok(SlashCommandOutput {
+                    sections: vec![SlashCommandOutputSection {
+                        range: (0..text.len()).into(),
+                        label: format!("Used by: {}", name),
+                    }],
+                    text,
+                })
+            }
+            "daisy-cheatsheet" => {
+                let filter = args.join(" ");
+                let table = docs::cheatsheet(&docs.summaries, &filter)?;
+                let text = format!("## DaisyUI Cheatsheet\n\n{}", table);
+                Ok(SlashCommandOutput {
+                    sections: vec![SlashCommandOutputSection {
+                        range: (0..text.len()).into(),
+                        label: "Cheatsheet".into(),
+                    }],
+                    text,
+                })
+            }
+            "daisy-random" => {
+                let seed = self.draws.fetch_add(1, Ordering::Relaxed);
+                let names = docs.list_components();
+                let name = docs::random_component(
+                    names.iter().map(String::as_str),
+                    &args.join(" "),
+                    seed,
+                )?;
+                let (name, doc, _) = docs.find_doc(name)?;
+                let mut out = output::Output::default();
+                out.section(
+                    format!("Doc: {}", name),
+                    &docs::sanitize_markdown(&doc, docs::Sanitize::Lenient),
+                );
+                Ok(out.into())
+            }
+            "daisy-concept" => {
+                // A trailing framework name picks the snippet variant.
+                let (target, words) = match args.split_last() {
+                    Some((last, words)) if !words.is_empty() => {
+                        match frameworks::Target::parse(last) {
+                            Some(target) => (target, words),
+                            None => (frameworks::Target::Html, &args[..]),
+                        }
+                    }
+                    _ => (frameworks::Target::Html, &args[..]),
+                };
+                if words.is_empty() {
+                    return Err(format!(
+                        "Please provide a concept name. {}",
+                        CommandSpec::usage("daisy-concept")
+                    ));
+                }
+                let concepts = concepts?;
+                // After an exact key, the other words pick an example by title.
+                let (name, example) = match words.split_first() {
+                    Some((key, rest))
+                        if !rest.is_empty()
+                            && concepts
+                                .find(key)
+                                .is_some_and(|(_, _, note)| note.is_none()) =>
+                    {
+                        (key.clone(), Some(rest.join(" ")))
+                    }
+                    _ => (words.join(" "), None),
+                };
+                match concepts.find(&name) {
+                    Some((key, c, note)) => {
+                        let mut text = match example {
+                            Some(example) => {
+                                let index = c.example(&example).ok_or_else(|| {
+                                    format!(
+                                        "'{}' has no example matching '{}'. Examples: {}",
+                                        key,
+                                        example,
+                                        c.example_titles().join(", ")
+                                    )
+                                })?;
+                                c.to_display_example(target, index)
+                            }
+                            None => c.to_display(target),
+                        };
+                        if let Some(note) = note {
+                            text = format!("> {}\n\n{}", note, text);
+                        }
                         Ok(SlashCommandOutput {
                             sections: vec![SlashCommandOutputSection {
                                 range: (0..text.len()).into(),
@@ -580,88 +2014,1154 @@ impl zed::Extension for DaisyDaysExtension {
                     None => Err(format!(
                         "Concept '{}' not found. Available: {}",
                         name,
-                        self.concepts.list_concepts().join(", ")
+                        concepts.list_concepts().join(", ")
                     )),
                 }
             }
             "daisy-concepts" => {
-                let concepts = self.concepts.list_concepts();
-                let text = format!("## Design Concepts\n\n{}", concepts.join(", "));
+                let concepts = concepts?;
+                let groups = concepts.by_category(args.first().map(String::as_str))?;
+                let mut text = String::new();
+                for error in &concepts.errors {
+                    text.push_str(&format!("> ⚠️ {}\n\n", error));
+                }
+                text.push_str("## Design Concepts\n\n");
+                let mut sections = Vec::new();
+                for (category, members) in &groups {
+                    let start = text.len();
+                    text.push_str(&concepts::category_markdown(category, members));
+                    sections.push(SlashCommandOutputSection {
+                        range: (start..text.len()).into(),
+                        label: format!("Concepts: {}", category),
+                    });
+                    text.push('\n');
+                }
+                if groups.is_empty() {
+                    text.push_str("No concepts in this category yet.\n");
+                    sections.push(SlashCommandOutputSection {
+                        range: (0..text.len()).into(),
+                        label: "Concepts List".into(),
+                    });
+                }
+                Ok(SlashCommandOutput { sections, text })
+            }
+            "daisy-a11y" => {
+                let html = args.join(" ");
+                if html.trim().is_empty() {
+                    return Err(format!(
+                        "Please provide some HTML. {}",
+                        CommandSpec::usage("daisy-a11y")
+                    ));
+                }
+                let hints = concepts
+                    .as_deref()
+                    .unwrap_or(&self.concepts)
+                    .audit_hint(&html);
+                let text = concepts::audit_markdown(&hints);
                 Ok(SlashCommandOutput {
                     sections: vec![SlashCommandOutputSection {
                         range: (0..text.len()).into(),
-                        label: "Concepts List".into(),
+                        label: format!("A11y: {} hint(s)", hints.len()),
+                    }],
+                    text,
+                })
+            }
+            "daisy-compare-concepts" => {
+                // `vs` separates multi-word names; otherwise two words.
+                let (first, second) = match args.iter().position(|a| a == "vs") {
+                    Some(i) => (args[..i].join(" "), args[i + 1..].join(" ")),
+                    None if args.len() == 2 => (args[0].clone(), args[1].clone()),
+                    None => (String::new(), String::new()),
+                };
+                if first.is_empty() || second.is_empty() {
+                    return Err(format!(
+                        "Please provide two concepts. {}",
+                        CommandSpec::usage("daisy-compare-concepts")
+                    ));
+                }
+                let concepts = concepts?;
+                let comparison = concepts.compare(&first, &second)?;
+                let [(first_key, _), (second_key, _)] = comparison.concepts;
+                let [first_snippet, second_snippet] = comparison.snippets();
+                Ok(Self::sectioned_output(
+                    (
+                        comparison.header(),
+                        format!("Compare: {} vs {}", first_key, second_key),
+                    ),
+                    (first_snippet, format!("Concept: {}", first_key)),
+                    Some((second_snippet, format!("Concept: {}", second_key))),
+                ))
+            }
+            "daisy-style-recipe" => {
+                // A leading number is the seed; the rest are concepts to keep.
+                let (seed, include) = match args.first().and_then(|a| a.parse::<u64>().ok()) {
+                    Some(seed) => (seed, &args[1..]),
+                    None => (self.draws.fetch_add(1, Ordering::Relaxed), &args[..]),
+                };
+                let include: Vec<&str> = include.iter().map(String::as_str).collect();
+                let concepts = concepts?;
+                let recipe = concepts.recipe(seed, &include)?;
+                let mut code = format!("```html\n{}\n```", recipe.snippet());
+                for script in recipe.scripts() {
+                    code.push_str(&format!("\n\n```js\n{}\n```", script));
+                }
+                Ok(Self::sectioned_output(
+                    (recipe.header(), format!("Style Recipe: seed {}", seed)),
+                    (code, "Recipe example".into()),
+                    None,
+                ))
+            }
+            "daisy-concept-theme" => {
+                let Some(query) = args.first() else {
+                    return Err(format!(
+                        "Please provide a concept name. {}",
+                        CommandSpec::usage("daisy-concept-theme")
+                    ));
+                };
+                let concepts = concepts?;
+                let Some((key, concept, note)) = concepts.find(query) else {
+                    return Err(format!(
+                        "Concept '{}' not found. Available: {}",
+                        query,
+                        concepts.list_concepts().join(", ")
+                    ));
+                };
+                let name = args.get(1).map(String::as_str).unwrap_or(key);
+                theme::check_name(name)?;
+                if let Ok(mut themes) = self.themes.lock() {
+                    themes.retain(|t| t != name);
+                    themes.push(name.to_string());
+                }
+                let mut text = String::new();
+                if let Some(note) = note {
+                    text.push_str(&format!("> {}\n\n", note));
+                }
+                text.push_str(&format!(
+                    "## Theme: {}\n\n```css\n{}\n```\n\nUse it with `data-theme=\"{}\"`.",
+                    name,
+                    concept.to_theme(name),
+                    name
+                ));
+                Ok(SlashCommandOutput {
+                    sections: vec![SlashCommandOutputSection {
+                        range: (0..text.len()).into(),
+                        label: format!("Theme: {}", name),
                     }],
                     text,
                 })
             }
             "daisy-layout" => {
                 let layout = args.first().map(|s| s.as_str()).unwrap_or("saas");
-                let title = if args.len() > 1 {
-                    args[1..].join(" ")
-                } else {
+                // A trailing variant name is the third positional argument;
+                // `--variant` also takes names that aren't variants.
+                let (title_args, positional) = match args.split_last() {
+                    Some((last, rest))
+                        if rest.len() > 1 && variant::NAMES.contains(&last.as_str()) =>
+                    {
+                        (&args[1..args.len() - 1], Some(last.as_str()))
+                    }
+                    _ => (args.get(1..).unwrap_or_default(), None),
+                };
+                let title = if title_args.is_empty() {
                     "My App".into()
+                } else {
+                    title_args.join(" ")
+                };
+                let variant_name = flags
+                    .get("variant")
+                    .map(String::as_str)
+                    .or(positional)
+                    .unwrap_or(variant::DEFAULT);
+                let variant = Variant::parse(variant_name);
+                let theme = Settings::resolve(&flags, "theme", settings.theme.as_deref(), "");
+                if let Some(name) = flags.get("theme") {
+                    // The settings theme may be one the project defines.
+                    let mut custom = self.themes.lock().map(|t| t.clone()).unwrap_or_default();
+                    custom.extend(settings.theme.clone());
+                    theme::check_known(name, &custom)?;
+                }
+                let format = if Settings::enabled(&flags, "jsx") {
+                    "jsx".to_string()
+                } else {
+                    Settings::resolve(&flags, "format", settings.format.as_deref(), "html")
+                };
+                let placeholders = Settings::resolve(
+                    &flags,
+                    "placeholders",
+                    settings.placeholders.as_deref(),
+                    "remote",
+                );
+                let version = Settings::resolve(
+                    &flags,
+                    "daisyui-version",
+                    settings.daisyui_version.as_deref(),
+                    "5",
+                );
+                Settings::check("format", &format, Settings::FORMATS)?;
+                Settings::check("placeholders", &placeholders, images::STRATEGIES)?;
+                let full = Settings::enabled(&flags, "full");
+                if full && format != "html" {
+                    return Err(format!(
+                        "--full builds an HTML document, so it can't be combined with {} output",
+                        format
+                    ));
+                }
+                let purity =
+                    Settings::resolve(&flags, "purity", settings.purity.as_deref(), "tailwind");
+                Settings::check("daisyui_version", &version, Settings::VERSIONS)?;
+                Settings::check("purity", &purity, purity::MODES)?;
+                let whitespace = if Settings::enabled(&flags, "minify") {
+                    "minified".to_string()
+                } else {
+                    Settings::resolve(
+                        &flags,
+                        "whitespace",
+                        settings.whitespace.as_deref(),
+                        "pretty",
+                    )
+                };
+                Settings::check("whitespace", &whitespace, tidy::Style::NAMES)?;
+                let style = tidy::Style::parse(&whitespace).unwrap_or_default();
+                let mut asked = Vec::new();
+                for key in sample::count_names() {
+                    if let Some(value) = flags.get(key) {
+                        let n = value.parse().map_err(|_| {
+                            format!("Invalid --{} '{}': expected a number of items", key, value)
+                        })?;
+                        asked.push((key, n));
+                    }
+                }
+                let (counts, count_notes) = sample::Counts::parse(layout, asked.iter().copied());
+                let mut ids = IdAllocator::new(flags.get("id-prefix").map(String::as_str));
+                let mut html = match templates.get(layout) {
+                    Some(template) => template.render(&snippets::escape_title(&title), &mut ids),
+                    None => LayoutEngine::generate_with_purity(
+                        layout,
+                        &title,
+                        &purity,
+                        &mut Sample::new(sample::seed(&title)).with_counts(counts.clone()),
+                        &variant.unwrap_or_default(),
+                        &mut ids,
+                    ),
                 };
-                let html = LayoutEngine::generate(layout, &title);
-                let text = format!("## Generated {} Layout\n\n```html\n{}\n```", layout, html);
+                if let Some(concept) = flags.get("concept") {
+                    html = concepts?.apply(concept, &html)?;
+                }
+                // A full document carries the theme on `<html>` for its toggle.
+                if !theme.is_empty() && !full {
+                    html = theme::scope(&html, &theme);
+                }
+                html = images::substitute(&html, &placeholders);
+                let rtl = Settings::enabled(&flags, "rtl");
+                if rtl {
+                    html = rtl::apply(&html);
+                }
+                if Settings::enabled(&flags, "annotate-responsive") {
+                    html = responsive::annotate(&html);
+                }
+                if version == "4" {
+                    html = snippets::avatars_to_v4(&html);
+                }
+                let footprint = Settings::enabled(&flags, "footprint");
+                let mut reports = Vec::new();
+                if footprint {
+                    reports.push(footprint::analyze(&html).markdown());
+                }
+                if purity == "daisy-only" {
+                    reports.push(purity::report(layout, &html));
+                }
+                if full {
+                    html = document::wrap(
+                        &html,
+                        &snippets::escape_title(&title),
+                        Some(theme.as_str()).filter(|t| !t.is_empty()),
+                    );
+                }
+                html = tidy::tidy(&html, style);
+                if let Some(target) = frameworks::Target::parse(&format) {
+                    html = frameworks::layout_component(
+                        &html,
+                        &snippets::escape_title(&title),
+                        target,
+                    );
+                }
+                if let Some(engine) = jinja::Engine::parse(&format) {
+                    html = jinja::template(&html, &snippets::escape_title(&title), engine);
+                }
+
+                let mut applied = vec![
+                    format!("title `{}`", snippets::escape_title(&title)),
+                    format!("format `{}`", format),
+                    format!("placeholders `{}`", placeholders),
+                    format!("daisyUI {}", version),
+                    format!("purity `{}`", purity),
+                    format!("variant `{}`", variant.unwrap_or_default().name),
+                ];
+                if !theme.is_empty() {
+                    applied.insert(1, format!("theme `{}`", theme));
+                }
+                if Settings::enabled(&flags, "annotate-responsive") {
+                    applied.push("responsive annotations".into());
+                }
+                if rtl {
+                    applied.push("right-to-left".into());
+                }
+                if full {
+                    applied.push("full document".into());
+                }
+                if style == tidy::Style::Minified {
+                    applied.push("minified".into());
+                }
+                if let Some(prefix) = flags.get("id-prefix") {
+                    applied.push(format!("id prefix `{}`", prefix));
+                }
+                if let Some(concept) = flags.get("concept") {
+                    applied.push(format!("concept `{}`", concept));
+                }
+                for (key, _) in sample::count_keys(layout) {
+                    if asked.iter().any(|(k, _)| k == key) {
+                        applied.push(format!("{} {}", counts.get(key, 0), key));
+                    }
+                }
+                let hints: Vec<&str> = [
+                    (theme.is_empty(), "`--theme=dark`"),
+                    (format == "html", "`--format=jsx`"),
+                    (placeholders == "remote", "`--placeholders=svg`"),
+                    (
+                        purity == "tailwind" && purity::PURE_LAYOUTS.contains(&layout),
+                        "`--purity=daisy-only`",
+                    ),
+                    (!footprint, "`--footprint`"),
+                    (!full && format == "html", "`--full`"),
+                ]
+                .into_iter()
+                .filter_map(|(show, hint)| show.then_some(hint))
+                .take(3)
+                .collect();
+                let description = LayoutEngine::describe_with(&templates, layout).unwrap_or_else(|| {
+                    "Unknown layout, so the SaaS landing page was generated. Run /daisy-layouts for the list.".into()
+                });
+                let mut header = format!(
+                    "## {} layout\n\n{}\n\n**Applied:** {}",
+                    layout,
+                    description,
+                    applied.join(", ")
+                );
+                if variant.is_none() {
+                    header.push_str(&format!(
+                        "\n\n> Unknown variant '{}', so comfortable was used. Use one of: {}.",
+                        variant_name,
+                        variant::NAMES.join(", ")
+                    ));
+                }
+                for note in &count_notes {
+                    header.push_str(&format!("\n\n> {}", note));
+                }
+                if !hints.is_empty() {
+                    header.push_str(&format!("\n\n**Next:** try {}", hints.join(", ")));
+                }
+                Ok(Self::sectioned_output(
+                    (header, format!("Layout: {}", layout)),
+                    (
+                        format!(
+                            "```{}\n{}\n```",
+                            match format.as_str() {
+                                "askama" | "tera" => "jinja",
+                                "leptos" | "yew" => "rust",
+                                format => format,
+                            },
+                            html
+                        ),
+                        match theme.as_str() {
+                            "" => {
+                                format!("{} {} ({})", layout, format, Self::size_label(html.len()))
+                            }
+                            theme => format!(
+                                "{} {} ({}, {})",
+                                layout,
+                                format,
+                                theme,
+                                Self::size_label(html.len())
+                            ),
+                        },
+                    ),
+                    Some((reports.join("\n\n"), format!("Report: {}", layout))),
+                ))
+            }
+            "daisy-layouts" => {
+                let mut text = String::from("## Available Layouts\n");
+                for layout in LayoutEngine::LAYOUTS {
+                    let page = LayoutEngine::build(
+                        layout,
+                        "My App",
+                        &Variant::default(),
+                        &mut IdAllocator::default(),
+                    );
+                    text.push_str(&format!("\n- **{}**: {}", layout, page.names().join(", ")));
+                }
+                text.push_str("\n\nRun `/daisy-section <layout> <section>` for one section.");
+                if !templates.layouts.is_empty() {
+                    text.push_str("\n\n### Template layouts\n");
+                    for t in &templates.layouts {
+                        text.push_str(&format!(
+                            "\n- **{}** ({}): {}",
+                            t.key,
+                            t.source,
+                            LayoutEngine::describe_with(&templates, &t.key).unwrap_or_default()
+                        ));
+                        if !t.sections.is_empty() {
+                            text.push_str(&format!(" Sections: {}.", t.sections.join(", ")));
+                        }
+                    }
+                }
+                if !templates.errors.is_empty() {
+                    text.push_str("\n\n### Skipped templates\n");
+                    for e in &templates.errors {
+                        text.push_str(&format!("\n- {}", e));
+                    }
+                }
                 Ok(SlashCommandOutput {
                     sections: vec![SlashCommandOutputSection {
                         range: (0..text.len()).into(),
-                        label: format!("Layout: {}", layout),
+                        label: "Layouts List".into(),
                     }],
                     text,
                 })
             }
-            "daisy-layouts" => {
-                let layouts = LayoutEngine::LAYOUTS.join(", ");
-                let text = format!("## Available Layouts\n\n{}", layouts);
+            "daisy-section" => {
+                let (Some(layout), Some(section)) = (args.first(), args.get(1)) else {
+                    return Err(format!(
+                        "Please provide a layout and a section. {}",
+                        CommandSpec::usage("daisy-section")
+                    ));
+                };
+                if !LayoutEngine::LAYOUTS.contains(&layout.as_str()) {
+                    return Err(format!(
+                        "Unknown layout '{}'. Layouts with sections: {}",
+                        layout,
+                        LayoutEngine::LAYOUTS.join(", ")
+                    ));
+                }
+                let title = match args.get(2..) {
+                    Some(rest) if !rest.is_empty() => rest.join(" "),
+                    _ => "My App".into(),
+                };
+                let mut ids = IdAllocator::new(flags.get("id-prefix").map(String::as_str));
+                let page = LayoutEngine::build(layout, &title, &Variant::default(), &mut ids);
+                let style = if Settings::enabled(&flags, "minify") {
+                    tidy::Style::Minified
+                } else {
+                    tidy::Style::Pretty
+                };
+                let html = tidy::tidy(&sections::pick(layout, &page, section)?, style);
+                let header = format!(
+                    "## {} section of {}\n\nTaken from the `{}` layout with title `{}`. Its sections: {}.",
+                    section,
+                    layout,
+                    layout,
+                    snippets::escape_title(&title),
+                    page.names().join(", ")
+                );
+                Ok(Self::sectioned_output(
+                    (header, format!("Section: {} {}", layout, section)),
+                    (
+                        format!("```html\n{}\n```", html),
+                        format!("{} {} ({})", layout, section, Self::size_label(html.len())),
+                    ),
+                    None,
+                ))
+            }
+            "daisy-compose" => {
+                if args.is_empty() {
+                    return Err(format!(
+                        "Please list the sections to compose. {}",
+                        CommandSpec::usage("daisy-compose")
+                    ));
+                }
+                let title = flags.get("title").map_or("My App", String::as_str);
+                let mut ids = IdAllocator::new(flags.get("id-prefix").map(String::as_str));
+                let wanted: Vec<&str> = args.iter().map(String::as_str).collect();
+                let (html, notes) = sections::compose(&wanted, |layout| {
+                    LayoutEngine::build(layout, title, &Variant::default(), &mut ids)
+                })?;
+                let style = if Settings::enabled(&flags, "minify") {
+                    tidy::Style::Minified
+                } else {
+                    tidy::Style::Pretty
+                };
+                let html = tidy::tidy(&html, style);
+                let mut header = format!(
+                    "## Composed page\n\nTitle `{}`; the comment at the top names each section's layout.",
+                    snippets::escape_title(title)
+                );
+                for note in &notes {
+                    header.push_str(&format!("\n- {}", note));
+                }
+                Ok(Self::sectioned_output(
+                    (header, "Composed page".into()),
+                    (
+                        format!("```html\n{}\n```", html),
+                        format!("Composed page ({})", Self::size_label(html.len())),
+                    ),
+                    None,
+                ))
+            }
+            "daisy-site" => {
+                let Some(preset) = args.first() else {
+                    return Err(format!(
+                        "Please provide a site preset. {}",
+                        CommandSpec::usage("daisy-site")
+                    ));
+                };
+                let preset = site::find(preset)?;
+                let title = match args.get(1..) {
+                    Some(rest) if !rest.is_empty() => rest.join(" "),
+                    _ => "My App".into(),
+                };
+                let style = if Settings::enabled(&flags, "minify") {
+                    tidy::Style::Minified
+                } else {
+                    tidy::Style::Pretty
+                };
+                let pages = site::build(preset, &snippets::escape_title(&title), |page| {
+                    let mut ids = IdAllocator::new(flags.get("id-prefix").map(String::as_str));
+                    LayoutEngine::generate(page.layout, &title, &Variant::default(), &mut ids)
+                });
+                let mut out = output::Output::default();
+                out.section(
+                    format!("Site: {}", preset.name),
+                    &format!(
+                        "## {} site\n\n{}, titled `{}`. Save each page under its file name; every navbar links the others.",
+                        preset.name,
+                        preset.description,
+                        snippets::escape_title(&title)
+                    ),
+                );
+                for (page, html) in pages {
+                    let html = tidy::tidy(&html, style);
+                    out.push("\n\n");
+                    out.section(
+                        format!(
+                            "{} ({}, {})",
+                            page.file,
+                            page.layout,
+                            Self::size_label(html.len())
+                        ),
+                        &format!("### {}\n\n```html\n{}\n```", page.file, html),
+                    );
+                }
+                Ok(out.into())
+            }
+            "daisy-idea" => {
+                if args.is_empty() {
+                    return Err(format!(
+                        "Please describe the UI you want. {}",
+                        CommandSpec::usage("daisy-idea")
+                    ));
+                }
+                let idea = idea::explain(&args.join(" "), &templates.keywords());
+                if Settings::enabled(&flags, "explain") {
+                    let text = format!("## Idea: {}\n\n{}", idea.layout, idea.markdown());
+                    return Ok(SlashCommandOutput {
+                        sections: vec![SlashCommandOutputSection {
+                            range: (0..text.len()).into(),
+                            label: format!("Idea: {}", idea.layout),
+                        }],
+                        text,
+                    });
+                }
+                let mut ids = IdAllocator::default();
+                let mut html = match templates.get(&idea.layout) {
+                    Some(template) => {
+                        template.render(&snippets::escape_title(&idea.title), &mut ids)
+                    }
+                    None => LayoutEngine::generate(
+                        &idea.layout,
+                        &idea.title,
+                        &Variant::default(),
+                        &mut ids,
+                    ),
+                };
+                if let Some(theme) = idea.theme {
+                    html = theme::scope(&html, theme);
+                }
+                html = tidy::tidy(&html, tidy::Style::Pretty);
+                let header = format!(
+                    "## {} layout\n\n{}\n\n**Read as:** {}; title `{}`, theme `{}`. Add `--explain` to see the keyword scores.",
+                    idea.layout,
+                    LayoutEngine::describe_with(&templates, &idea.layout).unwrap_or_default(),
+                    idea.decision,
+                    snippets::escape_title(&idea.title),
+                    idea.theme.unwrap_or("none")
+                );
+                Ok(Self::sectioned_output(
+                    (header, format!("Idea: {}", idea.layout)),
+                    (
+                        format!("```html\n{}\n```", html),
+                        format!("{} html ({})", idea.layout, Self::size_label(html.len())),
+                    ),
+                    None,
+                ))
+            }
+            "daisy-reverse" => {
+                if args.is_empty() {
+                    return Err(format!(
+                        "Please provide a file path or HTML. {}",
+                        CommandSpec::usage("daisy-reverse")
+                    ));
+                }
+                let input = args.join(" ");
+                let (html, source) = if input.trim_start().starts_with('<') {
+                    (input, "pasted HTML".to_string())
+                } else {
+                    let worktree = worktree.ok_or("Reading a file needs an open worktree")?;
+                    let html = worktree
+                        .read_text_file(&input)
+                        .map_err(|e| format!("Failed to read '{}': {}", input, e))?;
+                    (html, format!("`{}`", input))
+                };
+                let found = reverse::analyze(&html);
+                let header = format!(
+                    "## Sections in {}\n\n{}\n\nSections under {:.0}% confidence are left out of the spec.",
+                    source,
+                    found.markdown(),
+                    reverse::MIN_CONFIDENCE * 100.0
+                );
+                Ok(Self::sectioned_output(
+                    (header, "Reverse: sections".into()),
+                    (
+                        format!("```json\n{}\n```", found.spec_json()),
+                        "Reverse: compose spec".into(),
+                    ),
+                    None,
+                ))
+            }
+            "daisy-kbd" => {
+                let shortcut = args.first().ok_or_else(|| {
+                    format!(
+                        "Please provide a shortcut. {}",
+                        CommandSpec::usage("daisy-kbd")
+                    )
+                })?;
+                let platform = Self::platform_arg(args.get(1))?;
+                let size = args.get(2).map(|s| s.as_str());
+                let html = snippets::kbd_row(shortcut, platform, size)?;
+                let text = format!("## Shortcut: {}\n\n```html\n{}\n```", shortcut, html);
                 Ok(SlashCommandOutput {
                     sections: vec![SlashCommandOutputSection {
                         range: (0..text.len()).into(),
-                        label: "Layouts List".into(),
+                        label: format!("Kbd: {}", shortcut),
+                    }],
+                    text,
+                })
+            }
+            "daisy-block" => {
+                let kind = args.first().ok_or_else(|| {
+                    format!(
+                        "Please provide a block: {}. {}",
+                        snippets::BLOCKS.join(", "),
+                        CommandSpec::usage("daisy-block")
+                    )
+                })?;
+                let html = Self::block_from_args(kind, &args[1..].join(" "))?;
+                let source = if args.len() > 1 {
+                    "the items you provided"
+                } else {
+                    "sample items; pass your own after the block name"
+                };
+                let header = format!(
+                    "## {} block\n\nMarketing block built from {}. All text is HTML-escaped.",
+                    kind, source
+                );
+                let report = if Settings::enabled(&flags, "footprint") {
+                    footprint::analyze(&html).markdown()
+                } else {
+                    String::new()
+                };
+                Ok(Self::sectioned_output(
+                    (header, format!("Block: {}", kind)),
+                    (
+                        format!("```html\n{}\n```", html),
+                        format!("{} html ({})", kind, Self::size_label(html.len())),
+                    ),
+                    Some((report, format!("Report: {}", kind))),
+                ))
+            }
+            "daisy-badges" => {
+                let split = |items: &[String]| -> Vec<String> {
+                    items
+                        .iter()
+                        .flat_map(|a| a.split(','))
+                        .map(str::trim)
+                        .filter(|s| !s.is_empty())
+                        .map(String::from)
+                        .collect()
+                };
+                let mode = args.first().map(String::as_str);
+                if let Some(kind @ ("tags" | "tag-input")) = mode {
+                    let tags = split(&args[1..]);
+                    let tags: Vec<&str> = tags.iter().map(String::as_str).collect();
+                    let html = if kind == "tags" {
+                        snippets::tag_chips(&tags)
+                    } else {
+                        let suggestions =
+                            split(&[flags.get("suggest").cloned().unwrap_or_default()]);
+                        let suggestions: Vec<&str> =
+                            suggestions.iter().map(String::as_str).collect();
+                        let id = IdAllocator::new(flags.get("id-prefix").map(String::as_str))
+                            .next("tags");
+                        format!(
+                            "{}\n<script>\n{}\n</script>",
+                            snippets::tag_input(&id, &tags, &suggestions),
+                            snippets::tag_input_script(&id)
+                        )
+                    };
+                    let text = format!("## Tags\n\n```html\n{}\n```", html);
+                    return Ok(SlashCommandOutput {
+                        sections: vec![SlashCommandOutputSection {
+                            range: (0..text.len()).into(),
+                            label: "Tags".into(),
+                        }],
+                        text,
+                    });
+                }
+                let states = split(&args);
+                let states: Vec<&str> = states.iter().map(String::as_str).collect();
+                if states.is_empty() {
+                    return Err(format!(
+                        "Please provide at least one state. {}",
+                        CommandSpec::usage("daisy-badges")
+                    ));
+                }
+                let overrides = snippets::parse_status_overrides(
+                    flags.get("colors").map(String::as_str).unwrap_or(""),
+                )?;
+                let dot = Settings::enabled(&flags, "dot");
+                let html = snippets::status_badges(&states, &overrides, dot);
+                let text = format!("## Status Badges\n\n```html\n{}\n```", html);
+                Ok(SlashCommandOutput {
+                    sections: vec![SlashCommandOutputSection {
+                        range: (0..text.len()).into(),
+                        label: "Status Badges".into(),
+                    }],
+                    text,
+                })
+            }
+            "daisy-avatar" => {
+                let html = Self::avatar_from_args(&args)?;
+                let text = format!("## Avatar\n\n```html\n{}\n```", html);
+                Ok(SlashCommandOutput {
+                    sections: vec![SlashCommandOutputSection {
+                        range: (0..text.len()).into(),
+                        label: "Avatar".into(),
                     }],
                     text,
                 })
             }
-            cmd => Err(format!("Unknown command: {}", cmd)),
+            "daisy-loading" => {
+                let kind = args.first().ok_or_else(|| {
+                    format!(
+                        "Please provide a kind: {}. {}",
+                        snippets::LOADING_KINDS.join(", "),
+                        CommandSpec::usage("daisy-loading")
+                    )
+                })?;
+                let (mut size, mut color, mut value) = (None, None, None);
+                for arg in &args[1..] {
+                    if let Ok(v) = arg.trim_end_matches('%').parse::<u8>() {
+                        value = Some(v);
+                    } else if snippets::LOADING_SIZES.contains(&arg.as_str()) {
+                        size = Some(arg.as_str());
+                    } else {
+                        color = Some(arg.as_str());
+                    }
+                }
+                let html = snippets::loading(kind, size, color, value)?;
+                let text = format!("## Loading: {}\n\n```html\n{}\n```", kind, html);
+                Ok(SlashCommandOutput {
+                    sections: vec![SlashCommandOutputSection {
+                        range: (0..text.len()).into(),
+                        label: format!("Loading: {}", kind),
+                    }],
+                    text,
+                })
+            }
+            "daisy-palette" => {
+                let platform = Self::platform_arg(args.first())?;
+                let id =
+                    IdAllocator::new(flags.get("id-prefix").map(String::as_str)).next("palette");
+                let html = snippets::command_palette(
+                    &id,
+                    &snippets::default_palette_commands(),
+                    platform,
+                )?;
+                let text = format!("## Command Palette\n\n```html\n{}\n```", html);
+                Ok(SlashCommandOutput {
+                    sections: vec![SlashCommandOutputSection {
+                        range: (0..text.len()).into(),
+                        label: "Command Palette".into(),
+                    }],
+                    text,
+                })
+            }
+            "daisy-help" => {
+                let text = match args.first() {
+                    _ if flags.contains_key("toml") => {
+                        format!("```toml\n{}```", CommandSpec::manifest())
+                    }
+                    Some(name) => CommandSpec::find(name)
+                        .map(CommandSpec::detail)
+                        .ok_or_else(|| {
+                            format!(
+                                "Unknown command '{}'. Run /daisy-help for the full list",
+                                name
+                            )
+                        })?,
+                    None => CommandSpec::table(),
+                };
+                Ok(SlashCommandOutput {
+                    sections: vec![SlashCommandOutputSection {
+                        range: (0..text.len()).into(),
+                        label: "Daisy Days Help".into(),
+                    }],
+                    text,
+                })
+            }
+            cmd => Err(format!(
+                "Unknown command: {}. Run /daisy-help to see available commands",
+                cmd
+            )),
+        }
+    }
+
+    /// What to suggest when `command` runs slowly.
+    fn slow_hint(command: &str) -> &'static str {
+        match command {
+            "daisy-search" => "consider narrowing the query or lowering --limit",
+            "daisy-examples" => "consider passing a count",
+            "daisy-reverse" => "consider a smaller file",
+            _ => "consider narrowing the arguments",
         }
     }
 
+    /// Part `part` of `output` when it is over `limit` bytes, with a footer
+    /// pointing at the next part. Small output passes through unchanged.
+    fn paginate(
+        output: SlashCommandOutput,
+        part: usize,
+        limit: usize,
+    ) -> Result<SlashCommandOutput, String> {
+        let chunks = chunk::split(&output.text, limit);
+        if chunks.len() == 1 && part == 1 {
+            return Ok(output);
+        }
+        let total = chunks.len();
+        let chunk = chunks.get(part - 1).ok_or_else(|| {
+            format!(
+                "Part {} doesn't exist; this output has {} part{}",
+                part,
+                total,
+                if total == 1 { "" } else { "s" }
+            )
+        })?;
+        let label = output
+            .sections
+            .first()
+            .map_or("Output".to_string(), |s| s.label.clone());
+        let mut text = chunk.render();
+        let mut sections = vec![SlashCommandOutputSection {
+            range: (0..text.len()).into(),
+            label: format!("{} (part {} of {})", label, part, total),
+        }];
+        text.push_str("\n\n");
+        let footer_start = text.len();
+        // Only a part with more after it is truncated; the last one just
+        // says where it sits.
+        if part < total {
+            text.push_str(&format!(
+                "_Truncated — part {} of {}. Re-run with `--part {}` for the next part, or narrow the arguments._",
+                part,
+                total,
+                part + 1
+            ));
+            sections.push(SlashCommandOutputSection {
+                range: (footer_start..text.len()).into(),
+                label: "Truncated".into(),
+            });
+        } else {
+            text.push_str(&format!("_Part {} of {}._", part, total));
+        }
+        Ok(SlashCommandOutput { sections, text })
+    }
+
+    fn platform_arg(arg: Option<&String>) -> Result<Platform, String> {
+        match arg {
+            None => Ok(Platform::Mac),
+            Some(p) => {
+                Platform::parse(p).ok_or_else(|| format!("Unknown platform '{}'. Use mac or pc", p))
+            }
+        }
+    }
+}
+
+impl From<output::Output> for SlashCommandOutput {
+    fn from(output: output::Output) -> Self {
+        SlashCommandOutput {
+            sections: output
+                .sections
+                .into_iter()
+                .map(|(label, range)| SlashCommandOutputSection {
+                    range: range.into(),
+                    label,
+                })
+                .collect(),
+            text: output.text,
+        }
+    }
+}
+
+impl zed::Extension for DaisyDaysExtension {
+    fn new() -> Self {
+        let docs = Arc::new(DocsCache::load(DAISYUI_DOCS_CONTENT));
+        let concepts = Arc::new(ConceptEngine::new());
+        Self {
+            active: Mutex::new(docs.clone()),
+            docs,
+            overrides: Mutex::new(HashMap::new()),
+            active_concepts: Mutex::new(concepts.clone()),
+            concepts,
+            settings: Mutex::new(Settings::default()),
+            layouts: Mutex::new(plugins::Registry::default()),
+            titles: Mutex::new(vec![project::FALLBACK.to_string()]),
+            draws: AtomicU64::new(0),
+            themes: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn run_slash_command(
+        &self,
+        command: SlashCommand,
+        args: Vec<String>,
+        worktree: Option<&zed::Worktree>,
+    ) -> Result<SlashCommandOutput, String> {
+        let mut timer = Timer::start(SystemClock::default());
+        let (_, flags) = Settings::split_flags(&args);
+        let output = self.run_command(&command.name, args, worktree, &mut timer)?;
+        let setting = self.settings.lock().ok().and_then(|s| s.max_output);
+        let limit = match flags.get("max-output") {
+            Some(n) => n
+                .parse::<usize>()
+                .map_err(|_| format!("--max-output must be a number, got '{}'", n))?
+                .max(Settings::MIN_OUTPUT),
+            None => setting.unwrap_or(Settings::MAX_OUTPUT),
+        };
+        let part = match flags.get("part") {
+            Some(n) => n
+                .parse::<usize>()
+                .ok()
+                .filter(|n| *n >= 1)
+                .ok_or_else(|| format!("--part must be 1 or more, got '{}'", n))?,
+            None => 1,
+        };
+        let mut output = Self::paginate(output, part, limit)?;
+        if let Some(footer) = timer.footer(Self::slow_hint(&command.name)) {
+            let start = output.text.len() + 2;
+            output.text.push_str(&format!("\n\n_{}_", footer));
+            output.sections.push(SlashCommandOutputSection {
+                range: (start..output.text.len()).into(),
+                label: "Timing".into(),
+            });
+        }
+        Ok(output)
+    }
+
     fn complete_slash_command_argument(
         &self,
         command: SlashCommand,
-        _args: Vec<String>,
+        args: Vec<String>,
     ) -> Result<Vec<SlashCommandArgumentCompletion>, String> {
+        let limit = self
+            .settings
+            .lock()
+            .ok()
+            .and_then(|s| s.completion_results)
+            .unwrap_or(20);
         match command.name.as_str() {
-            "daisy-layout" => Ok(LayoutEngine::LAYOUTS
+            // Once a layout is chosen, offer the project's name as its title.
+            "daisy-layout"
+                if args.len() > 1
+                    && (LayoutEngine::LAYOUTS.contains(&args[0].as_str())
+                        || self
+                            .layouts
+                            .lock()
+                            .is_ok_and(|t| t.layouts.iter().any(|l| l.key == args[0]))) =>
+            {
+                let titles = self.titles.lock().map(|t| t.clone()).unwrap_or_default();
+                Ok(titles
+                    .into_iter()
+                    .map(|title| SlashCommandArgumentCompletion {
+                        label: title.clone(),
+                        new_text: title,
+                        run_command: true,
+                    })
+                    .collect())
+            }
+            "daisy-layout" => {
+                let templates = self.layouts.lock().map(|t| t.clone()).unwrap_or_default();
+                Ok(LayoutEngine::LAYOUTS
+                    .iter()
+                    .map(|l| (l.to_string(), l.to_string()))
+                    .chain(
+                        templates
+                            .layouts
+                            .iter()
+                            .map(|t| (format!("{} — {}", t.key, t.title), t.key.clone())),
+                    )
+                    .map(|(label, key)| SlashCommandArgumentCompletion {
+                        label,
+                        new_text: key,
+                        run_command: true,
+                    })
+                    .collect())
+            }
+            "daisy-section" if args.len() > 1 => Ok(LayoutEngine::build(
+                &args[0],
+                "My App",
+                &Variant::default(),
+                &mut IdAllocator::default(),
+            )
+            .names()
+            .into_iter()
+            .map(|s| SlashCommandArgumentCompletion {
+                label: s.to_string(),
+                new_text: s.to_string(),
+                run_command: true,
+            })
+            .collect()),
+            "daisy-section" => Ok(LayoutEngine::LAYOUTS
                 .iter()
                 .map(|l| SlashCommandArgumentCompletion {
                     label: l.to_string(),
                     new_text: l.to_string(),
+                    run_command: false,
+                })
+                .collect()),
+            "daisy-site" => Ok(site::PRESETS
+                .iter()
+                .map(|p| SlashCommandArgumentCompletion {
+                    label: p.name.to_string(),
+                    new_text: p.name.to_string(),
+                    run_command: false,
+                })
+                .collect()),
+            "daisy-compose" => Ok(sections::all_names()
+                .into_iter()
+                .map(|s| SlashCommandArgumentCompletion {
+                    label: s.to_string(),
+                    new_text: s.to_string(),
+                    run_command: false,
+                })
+                .collect()),
+            "daisy-concepts" => Ok(concepts::CATEGORIES
+                .iter()
+                .map(|c| SlashCommandArgumentCompletion {
+                    label: c.to_string(),
+                    new_text: c.to_string(),
                     run_command: true,
                 })
                 .collect()),
-            "daisy-concept" => Ok(self
-                .concepts
-                .list_concepts()
+            "daisy-block" => Ok(snippets::BLOCKS
+                .iter()
+                .map(|b| SlashCommandArgumentCompletion {
+                    label: b.to_string(),
+                    new_text: b.to_string(),
+                    run_command: true,
+                })
+                .collect()),
+            "daisy-loading" => Ok(snippets::LOADING_KINDS
+                .iter()
+                .map(|k| SlashCommandArgumentCompletion {
+                    label: k.to_string(),
+                    new_text: k.to_string(),
+                    run_command: true,
+                })
+                .collect()),
+            "daisy-help" => Ok(CommandSpec::ALL
                 .iter()
                 .map(|c| SlashCommandArgumentCompletion {
-                    label: c.clone(),
-                    new_text: c.clone(),
+                    label: c.name.to_string(),
+                    new_text: c.name.to_string(),
                     run_command: true,
                 })
                 .collect()),
-            "daisy-doc" => Ok(self
-                .docs
-                .list_components()
+            "daisy-palette" => Ok(["mac", "pc"]
+                .iter()
+                .map(|p| SlashCommandArgumentCompletion {
+                    label: p.to_string(),
+                    new_text: p.to_string(),
+                    run_command: true,
+                })
+                .collect()),
+            "daisy-concept-theme" if args.len() > 1 => Ok(Vec::new()),
+            "daisy-concept" if args.len() > 1 => {
+                // The concept's example titles, then the frameworks.
+                let concepts = self
+                    .active_concepts
+                    .lock()
+                    .map(|c| c.clone())
+                    .unwrap_or_else(|_| self.concepts.clone());
+                let titles = match concepts.find(&args[0]) {
+                    Some((_, c, None)) => c
+                        .example_titles()
+                        .iter()
+                        .map(|t| t.to_lowercase())
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                Ok(titles
+                    .into_iter()
+                    .chain(frameworks::Target::names().into_iter().map(String::from))
+                    .map(|t| SlashCommandArgumentCompletion {
+                        label: t.clone(),
+                        new_text: t,
+                        run_command: true,
+                    })
+                    .collect())
+            }
+            "daisy-concept"
+            | "daisy-concept-theme"
+            | "daisy-compare-concepts"
+            | "daisy-style-recipe" => Ok(self
+                .active_concepts
+                .lock()
+                .map(|c| c.clone())
+                .unwrap_or_else(|_| self.concepts.clone())
+                .list_concepts()
                 .iter()
-                .take(20)
                 .map(|c| SlashCommandArgumentCompletion {
                     label: c.clone(),
                     new_text: c.clone(),
                     run_command: true,
                 })
                 .collect()),
+            "daisy-doc" | "daisy-examples" | "daisy-example" | "daisy-modifiers"
+            | "daisy-usedby" | "daisy-playground" => {
+                let typed = args.first().map(String::as_str).unwrap_or("");
+                let docs = self
+                    .active
+                    .lock()
+                    .map(|d| d.clone())
+                    .unwrap_or_else(|_| self.docs.clone());
+                let names = docs.list_components();
+                Ok(complete::matching(typed, &names, limit)
+                    .into_iter()
+                    .map(|c| SlashCommandArgumentCompletion {
+                        label: c.to_string(),
+                        new_text: c.to_string(),
+                        run_command: complete::runs(typed, c),
+                    })
+                    .collect())
+            }
             _ => Ok(vec![]),
         }
     }
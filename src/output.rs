@@ -0,0 +1,45 @@
+//! Slash command text built piece by piece, with a labeled section for each
+//! piece that needs one. Ranges are byte offsets taken as each piece is
+//! appended, so they always fall on char boundaries of the final text.
+
+use std::ops::Range;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Output {
+    pub text: String,
+    /// `(label, range)` pairs in text order.
+    pub sections: Vec<(String, Range<usize>)>,
+}
+
+impl Output {
+    /// Appends `text` outside any section.
+    pub fn push(&mut self, text: &str) {
+        self.text.push_str(text);
+    }
+
+    /// Appends `text` as its own section labeled `label`.
+    pub fn section(&mut self, label: impl Into<String>, text: &str) {
+        let start = self.text.len();
+        self.text.push_str(text);
+        self.sections.push((label.into(), start..self.text.len()));
+    }
+}
+
+/// `/daisy-search` output: a header section for the query and `status`,
+/// then one section per `(name, snippet, score)` result, labeled with the
+/// component name.
+pub fn search(query: &str, status: &str, results: &[(String, String, usize)]) -> Output {
+    let mut out = Output::default();
+    out.section(
+        format!("Search: {}", query),
+        &format!("## Search Results for '{}'\n\n_{}_", query, status),
+    );
+    for (i, (name, snippet, score)) in results.iter().enumerate() {
+        out.push(if i == 0 { "\n\n" } else { "\n" });
+        out.section(
+            name.as_str(),
+            &format!("- **{}** (score: {})\n  {}", name, score, snippet),
+        );
+    }
+    out
+}
@@ -0,0 +1,231 @@
+//! Single-component playground pages: every color × size of a component,
+//! once per style variant, with each cell labeled by its exact class string.
+//!
+//! The axes come from the `#### Class names` list in the component's
+//! llms.txt section, and the markup from its first example (the `Syntax`
+//! block), with the component's `class` attribute swapped for each cell.
+
+/// Cells rendered before whole style sections are dropped.
+pub const MAX_CELLS: usize = 120;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Axes {
+    root: String,
+    colors: Vec<String>,
+    sizes: Vec<String>,
+    styles: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Playground {
+    pub html: String,
+    cells: usize,
+    /// Style classes left out to stay under [`MAX_CELLS`].
+    omitted: Vec<String>,
+    /// Set when the docs had no class-name list, so only the example is shown.
+    fallback: bool,
+}
+
+impl Playground {
+    /// One line describing what the page shows, including any truncation.
+    pub fn summary(&self, component: &str) -> String {
+        if self.fallback {
+            return format!(
+                "No class-name catalog for `{}`; the page shows its first documented example.",
+                component
+            );
+        }
+        let mut line = format!(
+            "{} cell{} for `{}`.",
+            self.cells,
+            if self.cells == 1 { "" } else { "s" },
+            component
+        );
+        if !self.omitted.is_empty() {
+            line.push_str(&format!(
+                " Truncated at {} cells; omitted styles: {}.",
+                MAX_CELLS,
+                self.omitted
+                    .iter()
+                    .map(|s| format!("`{}`", s))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        line
+    }
+}
+
+/// The component root and its color, size and style classes.
+fn axes(doc: &str) -> Option<Axes> {
+    let mut axes = Axes::default();
+    for (key, values) in crate::docs::class_names(doc) {
+        match key.as_str() {
+            "component" => axes.root = values.into_iter().next().unwrap_or_default(),
+            "color" => axes.colors = values,
+            "size" => axes.sizes = values,
+            "style" => axes.styles = values,
+            _ => {}
+        }
+    }
+    (!axes.root.is_empty()).then_some(axes)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// `template` with the first `class` attribute naming `root` set to
+/// `classes`. Tokens that aren't part of the component (layout utilities,
+/// `{MODIFIER}` placeholders) are kept after the cell's classes.
+fn instance(template: &str, root: &str, classes: &str) -> String {
+    let mut rest = template;
+    let mut out = String::new();
+    while let Some(pos) = rest.find("class=\"") {
+        let start = pos + 7;
+        let end = start + rest[start..].find('"').unwrap_or(rest.len() - start);
+        let value = &rest[start..end];
+        if value.split_whitespace().any(|t| t == root) {
+            let extra: Vec<&str> = value
+                .split_whitespace()
+                .filter(|t| *t != root && !t.starts_with(&format!("{}-", root)))
+                .filter(|t| !t.contains('{'))
+                .collect();
+            let mut value = classes.to_string();
+            for t in extra {
+                value.push(' ');
+                value.push_str(t);
+            }
+            return format!("{}{}{}{}", out, &rest[..start], value, &rest[end..]);
+        }
+        out.push_str(&rest[..end]);
+        rest = &rest[end..];
+    }
+    format!(r#"<div class="{}">{}</div>"#, classes, out + rest)
+}
+
+fn section(heading: &str, axes: &Axes, style: Option<&str>, template: &str) -> String {
+    let sizes: Vec<Option<&str>> = if axes.sizes.is_empty() {
+        vec![None]
+    } else {
+        axes.sizes.iter().map(|s| Some(s.as_str())).collect()
+    };
+    let colors = std::iter::once(None).chain(axes.colors.iter().map(|c| Some(c.as_str())));
+    let short = |class: &str| {
+        class
+            .strip_prefix(&format!("{}-", axes.root))
+            .unwrap_or(class)
+            .to_string()
+    };
+
+    let mut head = String::from("<th></th>");
+    for size in &sizes {
+        head.push_str(&format!(
+            "<th>{}</th>",
+            size.map_or("default".into(), short)
+        ));
+    }
+    let mut rows = String::new();
+    for color in colors {
+        rows.push_str(&format!(
+            "<tr><th>{}</th>",
+            color.map_or("default".into(), short)
+        ));
+        for size in &sizes {
+            let classes: Vec<&str> = [Some(axes.root.as_str()), style, color, *size]
+                .into_iter()
+                .flatten()
+                .collect();
+            let classes = classes.join(" ");
+            rows.push_str(&format!(
+                r#"<td><div class="mb-2">{}</div><code class="text-xs opacity-70">{}</code></td>"#,
+                instance(template, &axes.root, &classes),
+                escape(&classes)
+            ));
+        }
+        rows.push_str("</tr>\n");
+    }
+    format!(
+        "<section>\n<h2 class=\"text-xl font-semibold mb-4\">{}</h2>\n<div class=\"overflow-x-auto\"><table class=\"table\">\n<thead><tr>{}</tr></thead>\n<tbody>\n{}</tbody>\n</table></div>\n</section>",
+        escape(heading),
+        head,
+        rows
+    )
+}
+
+fn page(component: &str, summary: &str, body: &str) -> String {
+    let title = escape(component);
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en" data-theme="light">
+<head>
+<meta charset="utf-8" />
+<meta name="viewport" content="width=device-width, initial-scale=1" />
+<title>{title} playground</title>
+<link href="https://cdn.jsdelivr.net/npm/daisyui@5" rel="stylesheet" type="text/css" />
+<script src="https://cdn.jsdelivr.net/npm/@tailwindcss/browser@4"></script>
+</head>
+<body>
+<main class="p-6 space-y-10">
+<header>
+<h1 class="text-3xl font-bold">{title} playground</h1>
+<p class="opacity-70">{summary}</p>
+</header>
+{body}
+</main>
+</body>
+</html>
+"#,
+        summary = escape(&summary.replace('`', ""))
+    )
+}
+
+/// Playground page for `component` from its llms.txt section `doc`.
+pub fn build(component: &str, doc: &str) -> Playground {
+    let template = crate::docs::extract_examples(doc)
+        .into_iter()
+        .next()
+        .map(|e| e.code)
+        .unwrap_or_default();
+    let Some(axes) = axes(doc) else {
+        let mut playground = Playground {
+            html: String::new(),
+            cells: 1,
+            omitted: Vec::new(),
+            fallback: true,
+        };
+        let body = format!("<section>\n{}\n</section>", template);
+        playground.html = page(component, &playground.summary(component), &body);
+        return playground;
+    };
+
+    let per_section = (axes.colors.len() + 1) * axes.sizes.len().max(1);
+    let styles = std::iter::once(None).chain(axes.styles.iter().map(|s| Some(s.as_str())));
+    let mut sections = Vec::new();
+    let mut omitted = Vec::new();
+    let mut cells = 0;
+    for style in styles {
+        // The default section is always shown, even when it alone is over the cap.
+        if style.is_some() && cells + per_section > MAX_CELLS {
+            omitted.extend(style.map(String::from));
+            continue;
+        }
+        cells += per_section;
+        sections.push(section(style.unwrap_or("default"), &axes, style, &template));
+    }
+    let mut playground = Playground {
+        html: String::new(),
+        cells,
+        omitted,
+        fallback: false,
+    };
+    playground.html = page(
+        component,
+        &playground.summary(component),
+        &sections.join("\n"),
+    );
+    playground
+}
@@ -0,0 +1,230 @@
+//! Layouts defined in data files, so adding one doesn't mean editing Rust.
+//!
+//! A template file starts with a front-matter block and continues with the
+//! HTML:
+//!
+//! ```text
+//! ---
+//! key: pricing-page
+//! title: Pricing page
+//! description: Three plans with a feature comparison.
+//! tags: pricing, plans, billing
+//! sections: navbar, pricing, footer
+//! ---
+//! <div class="navbar">{{title}}</div>
+//! <div class="drawer"><input id="{{id:drawer}}" type="checkbox" class="drawer-toggle" /></div>
+//! ```
+//!
+//! `{{title}}` takes the caller's (escaped) title and `{{id:<kind>}}` the
+//! next generated id of that kind. Built-in layouts win over a template with
+//! the same key; every rejected file is reported in [`Registry::errors`].
+
+use crate::ids::IdAllocator;
+
+/// Template directory, relative to the project root.
+pub const DIR: &str = ".daisy-days/layouts";
+
+const FIELDS: &[&str] = &["key", "title", "description", "tags", "sections"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutTemplate {
+    pub key: String,
+    pub title: String,
+    pub description: String,
+    pub tags: Vec<String>,
+    pub sections: Vec<String>,
+    /// File the template came from, for error messages.
+    pub source: String,
+    body: String,
+}
+
+enum Piece<'a> {
+    Text(&'a str),
+    Title,
+    Id(&'a str),
+}
+
+/// Splits a template body into text and placeholders, rejecting unknown or
+/// unclosed ones.
+fn pieces(body: &str) -> Result<Vec<Piece<'_>>, String> {
+    let mut out = Vec::new();
+    let mut rest = body;
+    while let Some(open) = rest.find("{{") {
+        out.push(Piece::Text(&rest[..open]));
+        let after = &rest[open + 2..];
+        let close = after
+            .find("}}")
+            .ok_or_else(|| "unclosed `{{` placeholder".to_string())?;
+        let name = after[..close].trim();
+        out.push(match name.split_once(':') {
+            None if name == "title" => Piece::Title,
+            Some(("id", kind))
+                if !kind.is_empty()
+                    && kind.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') =>
+            {
+                Piece::Id(kind)
+            }
+            _ => {
+                return Err(format!(
+                    "unknown placeholder `{{{{{}}}}}` (use {{{{title}}}} or {{{{id:<kind>}}}})",
+                    name
+                ));
+            }
+        });
+        rest = &after[close + 2..];
+    }
+    out.push(Piece::Text(rest));
+    Ok(out)
+}
+
+fn list(value: &str) -> Vec<String> {
+    value
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(|v| v.trim().trim_matches('"').to_lowercase())
+        .filter(|v| !v.is_empty())
+        .collect()
+}
+
+impl LayoutTemplate {
+    /// Parses one template file. `source` names it in error messages.
+    pub fn parse(source: &str, text: &str) -> Result<Self, String> {
+        let fail = |msg: String| format!("{}: {}", source, msg);
+        let text = text.trim_start_matches('\u{feff}');
+        let mut lines = text.split_inclusive('\n');
+        if lines.next().map(str::trim) != Some("---") {
+            return Err(fail("must start with a `---` front-matter line".into()));
+        }
+        let mut template = Self {
+            key: String::new(),
+            title: String::new(),
+            description: String::new(),
+            tags: Vec::new(),
+            sections: Vec::new(),
+            source: source.to_string(),
+            body: String::new(),
+        };
+        let mut consumed = text.find('\n').map_or(text.len(), |i| i + 1);
+        let mut closed = false;
+        for line in lines.by_ref() {
+            consumed += line.len();
+            let line = line.trim();
+            if line == "---" {
+                closed = true;
+                break;
+            }
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (field, value) = line
+                .split_once(':')
+                .ok_or_else(|| fail(format!("expected `field: value`, got `{}`", line)))?;
+            let value = value.trim().trim_matches('"');
+            match field.trim() {
+                "key" => template.key = value.to_lowercase(),
+                "title" => template.title = value.to_string(),
+                "description" => template.description = value.to_string(),
+                "tags" => template.tags = list(value),
+                "sections" => template.sections = list(value),
+                other => {
+                    return Err(fail(format!(
+                        "unknown field `{}` (fields: {})",
+                        other,
+                        FIELDS.join(", ")
+                    )));
+                }
+            }
+        }
+        if !closed {
+            return Err(fail("front matter is missing its closing `---`".into()));
+        }
+        if template.key.is_empty() {
+            return Err(fail("`key` is required".into()));
+        }
+        if !template
+            .key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            return Err(fail(format!(
+                "key `{}` may only contain a-z, 0-9 and `-`",
+                template.key
+            )));
+        }
+        template.body = text[consumed.min(text.len())..].trim().to_string();
+        if template.body.is_empty() {
+            return Err(fail("template body is empty".into()));
+        }
+        pieces(&template.body).map_err(fail)?;
+        if template.title.is_empty() {
+            template.title = template.key.clone();
+        }
+        Ok(template)
+    }
+
+    /// The template with placeholders filled in. `title` must already be
+    /// escaped by the caller.
+    pub fn render(&self, title: &str, ids: &mut IdAllocator) -> String {
+        // Validated in `parse`, so this can't fail.
+        let pieces = pieces(&self.body).unwrap_or_default();
+        let mut out = String::with_capacity(self.body.len());
+        for piece in pieces {
+            match piece {
+                Piece::Text(text) => out.push_str(text),
+                Piece::Title => out.push_str(title),
+                Piece::Id(kind) => out.push_str(&ids.next(kind)),
+            }
+        }
+        out
+    }
+}
+
+/// Templates that loaded, plus one message per file that didn't.
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    pub layouts: Vec<LayoutTemplate>,
+    pub errors: Vec<String>,
+}
+
+impl Registry {
+    /// Parses `(source, text)` files in order. Keys that collide with a
+    /// built-in or an earlier template are rejected.
+    pub fn load(files: Vec<(String, String)>, builtins: &[&str]) -> Self {
+        let mut registry = Self::default();
+        for (source, text) in files {
+            match LayoutTemplate::parse(&source, &text) {
+                Err(e) => registry.errors.push(e),
+                Ok(t) if builtins.contains(&t.key.as_str()) => registry.errors.push(format!(
+                    "{}: `{}` is a built-in layout; choose another key",
+                    source, t.key
+                )),
+                Ok(t) => match registry.get(&t.key) {
+                    Some(first) => registry.errors.push(format!(
+                        "{}: key `{}` is already defined in {}",
+                        source, t.key, first.source
+                    )),
+                    None => registry.layouts.push(t),
+                },
+            }
+        }
+        registry
+    }
+
+    pub fn get(&self, key: &str) -> Option<&LayoutTemplate> {
+        self.layouts.iter().find(|t| t.key == key)
+    }
+
+    /// Idea-engine keywords: each template's key and tags.
+    pub fn keywords(&self) -> Vec<(String, Vec<String>)> {
+        self.layouts
+            .iter()
+            .map(|t| {
+                let mut words = vec![t.key.clone()];
+                words.extend(t.tags.iter().cloned());
+                (t.key.clone(), words)
+            })
+            .collect()
+    }
+}
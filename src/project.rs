@@ -0,0 +1,120 @@
+//! Project names offered as layout titles: `/daisy-layout saas ` completes
+//! to the worktree's directory name and the `name` in its `package.json` or
+//! `Cargo.toml`, title-cased, with "My App" last.
+//!
+//! Completions get no worktree, so the extension reads these files when a
+//! slash command runs and caches [`titles`] for the next completion.
+
+/// The title used when a layout is given none.
+pub const FALLBACK: &str = "My App";
+
+/// The top-level `"name"` of a `package.json`. Names nested in other
+/// objects, like an author's, are skipped.
+pub fn package_json_name(text: &str) -> Option<String> {
+    let mut depth = 0;
+    let mut chars = text.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '{' | '[' => depth += 1,
+            '}' | ']' => depth -= 1,
+            '"' => {
+                let end = string_end(text, i + 1)?;
+                // Skip the string so braces inside it aren't counted.
+                while chars.next().is_some_and(|(j, _)| j < end) {}
+                if depth != 1 || &text[i + 1..end] != "name" {
+                    continue;
+                }
+                let Some(value) = text[end + 1..].trim_start().strip_prefix(':') else {
+                    continue;
+                };
+                let value = value.trim_start().strip_prefix('"')?;
+                let close = string_end(value, 0)?;
+                return Some(value[..close].to_string()).filter(|n| !n.is_empty());
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Index of the quote closing a JSON string whose text starts at `from`.
+fn string_end(text: &str, from: usize) -> Option<usize> {
+    let mut escaped = false;
+    for (i, c) in text[from..].char_indices() {
+        match c {
+            '\\' if !escaped => escaped = true,
+            '"' if !escaped => return Some(from + i),
+            _ => escaped = false,
+        }
+    }
+    None
+}
+
+/// The `name` in a `Cargo.toml`'s `[package]` table.
+pub fn cargo_toml_name(text: &str) -> Option<String> {
+    let mut in_package = false;
+    for line in text.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_package = line == "[package]";
+            continue;
+        }
+        let Some(value) = line.strip_prefix("name") else {
+            continue;
+        };
+        let Some(value) = value.trim_start().strip_prefix('=') else {
+            continue;
+        };
+        if in_package {
+            let name = value.trim().trim_matches(['"', '\'']);
+            return Some(name.to_string()).filter(|n| !n.is_empty());
+        }
+    }
+    None
+}
+
+/// A package or directory name as a title: `@acme/daisy_days-web` becomes
+/// `Daisy Days Web`. Words already holding capitals are kept as they are.
+pub fn title_case(name: &str) -> String {
+    let name = name.rsplit('/').next().unwrap_or(name);
+    name.split(['-', '_', '.', ' '])
+        .filter(|w| !w.is_empty())
+        .map(|word| {
+            if word.chars().any(char::is_uppercase) {
+                return word.to_string();
+            }
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Titles to offer for a worktree rooted at `root`, given the text of its
+/// `package.json` and `Cargo.toml` when they exist: the directory name,
+/// then each file's name, then [`FALLBACK`], without repeats.
+pub fn titles(root: &str, package_json: Option<&str>, cargo_toml: Option<&str>) -> Vec<String> {
+    let dir = root
+        .trim_end_matches(['/', '\\'])
+        .rsplit(['/', '\\'])
+        .next();
+    let names = [
+        dir.map(str::to_string),
+        package_json.and_then(package_json_name),
+        cargo_toml.and_then(cargo_toml_name),
+    ];
+    let mut titles: Vec<String> = Vec::new();
+    for title in names
+        .into_iter()
+        .flatten()
+        .map(|n| title_case(&n))
+        .chain([FALLBACK.to_string()])
+    {
+        if !title.is_empty() && !titles.contains(&title) {
+            titles.push(title);
+        }
+    }
+    titles
+}
@@ -0,0 +1,349 @@
+//! daisyUI-only ("pure") layout variants and the report of Tailwind
+//! utilities they still need.
+//!
+//! Pure variants are written per template rather than derived by rewriting
+//! classes: swapping `flex gap-2` for `join` or a sidebar for `tabs` changes
+//! the markup, not just the class list. They take the same density, ids and
+//! item counts as the Tailwind layouts; density picks daisyUI component sizes
+//! (`btn-sm`, `card-lg`) since spacing utilities are off the table.
+
+use crate::footprint::component_of;
+use crate::ids::IdAllocator;
+use crate::sample::{self, Sample};
+use crate::sections::Page;
+use crate::snippets::{FAQ_CATEGORIES, FAQ_QUESTIONS};
+use crate::variant::Variant;
+
+/// Values accepted for the `purity` option.
+pub const MODES: &[&str] = &["tailwind", "daisy-only"];
+
+/// Layouts with a hand-written daisyUI-only variant.
+pub const PURE_LAYOUTS: &[&str] = &["auth", "profile", "store", "faq"];
+
+/// Modifier classes daisyUI defines without a component prefix.
+const STANDALONE: &[&str] = &["active", "glass", "form-control"];
+
+/// True when `class` (ignoring any `md:`-style variant) comes from daisyUI.
+pub fn is_daisy_class(class: &str) -> bool {
+    let base = class.rsplit(':').next().unwrap_or(class);
+    STANDALONE.contains(&base) || component_of(base).is_some()
+}
+
+/// Tailwind utilities left in `html`, unique and in order of appearance.
+pub fn utilities(html: &str) -> Vec<String> {
+    crate::html::classes(html)
+        .into_iter()
+        .filter(|class| !is_daisy_class(class))
+        .collect()
+}
+
+/// Markdown note listing the utilities `layout` couldn't avoid, or saying it
+/// has no pure variant at all.
+pub fn report(layout: &str, html: &str) -> String {
+    let left = utilities(html);
+    if !PURE_LAYOUTS.contains(&layout) {
+        return format!(
+            "**Purity:** `{}` has no daisyUI-only variant yet ({} utility classes). Pure variants: {}.",
+            layout,
+            left.len(),
+            PURE_LAYOUTS.join(", ")
+        );
+    }
+    if left.is_empty() {
+        return format!("**Purity:** `{}` uses daisyUI classes only.", layout);
+    }
+    format!(
+        "**Purity:** `{}` still needs {} utility class(es) with no daisyUI equivalent: {}",
+        layout,
+        left.len(),
+        left.iter()
+            .map(|c| format!("`{}`", c))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+/// daisyUI-only variant of `layout`, if one exists. `title` must already be
+/// escaped by the caller.
+pub fn layout(
+    layout: &str,
+    title: &str,
+    s: &mut Sample,
+    v: &Variant,
+    ids: &mut IdAllocator,
+) -> Option<Page> {
+    match layout {
+        "auth" => Some(auth(title, v, &ids.next("login"))),
+        "profile" => Some(profile(title, v, &ids.next("profile"))),
+        "store" => Some(store(title, v, &ids.next("products"), s)),
+        "faq" => Some(faq(title, v, &ids.next("help"))),
+        _ => None,
+    }
+}
+
+/// `component` at the variant's take on daisyUI size `size`; the default
+/// `md` leaves the bare component class.
+fn sized(component: &str, size: &str, v: &Variant) -> String {
+    match v.size(size) {
+        "md" => component.to_string(),
+        size => format!("{} {}-{}", component, component, size),
+    }
+}
+
+fn auth(t: &str, v: &Variant, form: &str) -> Page {
+    let [card, input, btn] = ["card", "input", "btn"].map(|c| sized(c, "md", v));
+    Page::default()
+        .markup(format!(
+            r#"<div class="hero min-h-screen bg-base-200">
+  <div class="hero-content">
+    <div class="{card} card-border bg-base-100">"#
+        ))
+        .section(
+            "form",
+            format!(
+                r#"
+      <form class="card-body">
+        <h1 class="card-title">{t}</h1>
+        <fieldset class="fieldset">
+          <label class="label" for="{form}-email">Email</label>
+          <input id="{form}-email" type="email" class="{input}" required />
+          <label class="label" for="{form}-password">Password</label>
+          <input id="{form}-password" type="password" class="{input}" required />
+          <a class="label link link-hover">Forgot password?</a>
+          <button class="{btn} btn-primary">Login</button>
+        </fieldset>
+        <div class="divider">OR</div>
+        <button class="{btn} btn-outline">Sign up</button>
+      </form>"#
+            ),
+        )
+        .markup(
+            r#"
+    </div>
+  </div>
+</div>"#,
+        )
+}
+
+fn profile(t: &str, v: &Variant, info: &str) -> Page {
+    let [card, tabs, input, textarea, toggle, btn] =
+        ["card", "tabs", "input", "textarea", "toggle", "btn"].map(|c| sized(c, "md", v));
+    let small = sized("btn", "sm", v);
+    Page::default()
+        .markup(format!(
+            r#"<div class="hero min-h-screen bg-base-200">
+  <div class="hero-content">
+    <div class="{card} card-border bg-base-100">
+      <div class="card-body">
+        <h1 class="card-title">{t}</h1>"#
+        ))
+        .section(
+            "menu",
+            format!(
+                r#"
+        <div role="tablist" class="{tabs} tabs-border">
+          <a role="tab" class="tab tab-active">General</a>
+          <a role="tab" class="tab">Account</a>
+          <a role="tab" class="tab">Notifications</a>
+          <a role="tab" class="tab">Danger Zone</a>
+        </div>"#
+            ),
+        )
+        .markup(format!(
+            r#"
+        <ul class="list">
+          <li class="list-row">
+            <div class="avatar avatar-placeholder"><div class="mask mask-circle w-12 bg-neutral text-neutral-content"><span>UN</span></div></div>
+            <div class="list-col-grow"><div>User Name</div><div class="label">user@example.com</div></div>
+            <div class="join"><button class="{small} join-item">Change Avatar</button><button class="{small} btn-ghost join-item">Remove</button></div>
+          </li>
+        </ul>
+        <fieldset class="fieldset">
+          <legend class="fieldset-legend">Profile Information</legend>
+          <label class="label" for="{info}-name">Name</label>
+          <input id="{info}-name" type="text" class="{input}" value="User Name" />
+          <label class="label" for="{info}-email">Email</label>
+          <input id="{info}-email" type="email" class="{input}" value="user@example.com" />
+          <label class="label" for="{info}-bio">Bio</label>
+          <textarea id="{info}-bio" class="{textarea}">Bio here...</textarea>
+        </fieldset>
+        <fieldset class="fieldset">
+          <legend class="fieldset-legend">Preferences</legend>
+          <label class="label"><input type="checkbox" class="{toggle} toggle-primary" checked /> Enable email notifications</label>
+        </fieldset>
+        <div class="card-actions">
+          <div class="join"><button class="{btn} btn-primary join-item">Save Changes</button><button class="{btn} join-item">Cancel</button></div>
+        </div>
+      </div>
+    </div>
+  </div>
+</div>"#
+        ))
+}
+
+/// Products as `list` rows rather than a grid of cards, so the page needs
+/// no grid or gap utilities. Reads the `cards` count like the Tailwind store.
+fn store(t: &str, v: &Variant, featured: &str, s: &mut Sample) -> Page {
+    let [btn, card] = ["btn", "card"].map(|c| sized(c, "md", v));
+    let small = sized("btn", "sm", v);
+    let products: String = s
+        .many(sample::PRODUCTS, s.count("cards", 1))
+        .into_iter()
+        .map(|product| {
+            let price = s.price(12, 180);
+            format!(
+                r#"          <li class="list-row"><div class="avatar"><div class="mask mask-squircle w-12"><img src="https://picsum.photos/400/300" alt="" /></div></div><div class="list-col-grow"><div>{product}</div><div class="label">{price}</div></div><button class="{small} btn-primary">Add to Cart</button></li>
+"#
+            )
+        })
+        .collect();
+    Page::default()
+        .markup(r#"<div class="min-h-screen bg-base-100">"#)
+        .section(
+            "navbar",
+            format!(
+                r#"
+  <nav class="navbar bg-base-100" aria-label="Main"><div class="navbar-start"><a class="{btn} btn-ghost">{t}</a></div>
+    <div class="navbar-end"><div class="indicator"><span class="indicator-item badge badge-sm">3</span><button class="{btn} btn-ghost" aria-label="Cart, 3 items">Cart</button></div></div>
+  </nav>"#
+            ),
+        )
+        .section(
+            "hero",
+            format!(
+                r#"
+  <header class="hero bg-base-200"><div class="hero-content"><div class="{card}"><div class="card-body"><h1 class="card-title">{t}</h1><p>Discover amazing products</p><div class="card-actions"><button class="{btn} btn-primary">Shop Now</button></div></div></div></div></header>"#
+            ),
+        )
+        .section(
+            "products",
+            format!(
+                r#"
+  <main class="hero">
+    <section class="hero-content" aria-labelledby="{featured}">
+      <div class="{card} card-border bg-base-100"><div class="card-body">
+        <h2 id="{featured}" class="card-title">Featured Products</h2>
+        <ul class="list">
+{products}        </ul>
+      </div></div>
+    </section>
+  </main>"#
+            ),
+        )
+        .markup("\n</div>")
+}
+
+/// Categories as `list` rows and each category's questions as a vertical
+/// `join` of accordions, all in one card, so the help center needs no grid
+/// or spacing utilities. The support modal sits beside the page like the
+/// Tailwind one.
+fn faq(t: &str, v: &Variant, id: &str) -> Page {
+    let [card, input, textarea, btn] =
+        ["card", "input", "textarea", "btn"].map(|c| sized(c, "md", v));
+    let categories: String = FAQ_CATEGORIES
+        .iter()
+        .map(|(key, icon, heading, blurb)| {
+            format!(
+                r##"            <li class="list-row"><span aria-hidden="true">{icon}</span><div class="list-col-grow"><a href="#{id}-{key}" class="link link-hover">{heading}</a><div class="label">{blurb}</div></div></li>
+"##
+            )
+        })
+        .collect();
+    let groups: String = FAQ_CATEGORIES
+        .iter()
+        .map(|(key, _, heading, _)| {
+            let questions: String = FAQ_QUESTIONS
+                .iter()
+                .filter(|(category, ..)| category == key)
+                .map(|(_, question, answer)| {
+                    format!(
+                        r#"            <div class="collapse collapse-arrow join-item bg-base-200">
+              <input type="radio" name="{id}-{key}" aria-label="{question}" />
+              <div class="collapse-title">{question}</div>
+              <div class="collapse-content"><p>{answer}</p></div>
+            </div>
+"#
+                    )
+                })
+                .collect();
+            format!(
+                r#"        <section id="{id}-{key}" aria-labelledby="{id}-{key}-heading">
+          <div class="divider"></div>
+          <h2 id="{id}-{key}-heading" class="card-title">{heading}</h2>
+          <div class="join join-vertical">
+{questions}          </div>
+        </section>
+"#
+            )
+        })
+        .collect();
+    Page::default()
+        .markup(r#"<div class="min-h-screen bg-base-200">"#)
+        .section(
+            "hero",
+            format!(
+                r#"
+  <header class="hero bg-primary text-primary-content">
+    <div class="hero-content"><div class="{card}"><div class="card-body">
+      <h1 class="card-title">{t}</h1>
+      <p>How can we help?</p>
+      <input id="{id}-search" type="search" class="{input}" placeholder="Search articles" aria-label="Search help articles" />
+    </div></div></div>
+  </header>"#
+            ),
+        )
+        .markup(format!(
+            r#"
+  <main class="hero">
+    <div class="hero-content">
+      <div class="{card} card-border bg-base-100"><div class="card-body">"#
+        ))
+        .section(
+            "categories",
+            format!(
+                r#"
+        <nav aria-label="Help categories">
+          <ul class="list">
+{categories}          </ul>
+        </nav>"#
+            ),
+        )
+        .section("questions", format!("\n{}", groups.trim_end()))
+        .section(
+            "contact",
+            format!(
+                r#"
+        <div role="alert" class="alert">
+          <span>Still need help? Our support team usually replies within a few hours.</span>
+          <button type="button" class="{btn} btn-primary" onclick="document.getElementById('{id}-support').showModal()">Contact support</button>
+        </div>"#
+            ),
+        )
+        .markup("\n      </div></div>\n    </div>\n  </main>\n</div>")
+        .beside(
+            "modals",
+            format!(
+                r#"
+<dialog id="{id}-support" class="modal">
+  <div class="modal-box">
+    <h3 class="card-title">Contact support</h3>
+    <form method="dialog">
+      <fieldset class="fieldset">
+        <label class="label" for="{id}-email">Email</label>
+        <input id="{id}-email" name="email" type="email" autocomplete="email" class="{input}" required />
+        <label class="label" for="{id}-subject">Subject</label>
+        <input id="{id}-subject" name="subject" type="text" class="{input}" required />
+        <label class="label" for="{id}-message">Message</label>
+        <textarea id="{id}-message" name="message" class="{textarea}" rows="5" required></textarea>
+      </fieldset>
+      <div class="modal-action">
+        <button type="submit" value="cancel" class="{btn} btn-ghost" formnovalidate>Cancel</button>
+        <button type="submit" value="send" class="{btn} btn-primary">Send</button>
+      </div>
+    </form>
+  </div>
+  <form method="dialog" class="modal-backdrop"><button>close</button></form>
+</dialog>"#
+            ),
+        )
+}
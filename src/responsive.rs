@@ -0,0 +1,170 @@
+//! Responsive-behavior annotations derived from the markup itself.
+//!
+//! Descriptions come from the breakpoint-prefixed classes on each element,
+//! so they can't drift from what the layout actually does.
+
+pub const BREAKPOINTS: &[&str] = &["sm", "md", "lg", "xl", "2xl"];
+
+const DISPLAY: &[&str] = &[
+    "hidden",
+    "block",
+    "inline",
+    "inline-block",
+    "flex",
+    "inline-flex",
+    "grid",
+    "contents",
+];
+
+const ROLES: &[&str] = &[
+    "drawer",
+    "drawer-side",
+    "navbar",
+    "menu",
+    "hero",
+    "footer",
+    "card",
+    "stats",
+    "table",
+    "tabs",
+    "modal",
+    "dock",
+];
+
+fn split_variant(class: &str) -> (Option<&str>, &str) {
+    match class.split_once(':') {
+        Some((bp, base)) if BREAKPOINTS.contains(&bp) => (Some(bp), base),
+        _ => (None, class),
+    }
+}
+
+fn columns(class: &str) -> Option<&str> {
+    class.strip_prefix("grid-cols-")
+}
+
+fn direction(class: &str) -> Option<&'static str> {
+    match class {
+        "flex-col" | "flex-col-reverse" => Some("stacked"),
+        "flex-row" | "flex-row-reverse" => Some("in a row"),
+        _ => None,
+    }
+}
+
+fn is_width(class: &str) -> bool {
+    class.starts_with("w-") || class.starts_with("max-w-")
+}
+
+/// Plain-language breakpoint behavior for one element's class list, or
+/// `None` when nothing about it changes across breakpoints.
+pub fn describe(classes: &str) -> Option<String> {
+    let classes: Vec<(Option<&str>, &str)> =
+        classes.split_whitespace().map(split_variant).collect();
+    // The unprefixed class matching `pick`, i.e. the below-breakpoint state.
+    let base = |pick: &dyn Fn(&str) -> bool| -> Option<&str> {
+        classes
+            .iter()
+            .find(|(b, c)| b.is_none() && pick(c))
+            .map(|(_, c)| *c)
+    };
+    let responsive = |pick: &dyn Fn(&str) -> bool| -> Vec<(&str, &str)> {
+        classes
+            .iter()
+            .filter_map(|(b, c)| b.filter(|_| pick(c)).map(|b| (b, *c)))
+            .collect()
+    };
+    let mut notes = Vec::new();
+
+    // Display toggles: `hidden lg:flex`, `flex lg:hidden`.
+    let display = responsive(&|c| DISPLAY.contains(&c));
+    if let Some((bp, shown)) = display.first() {
+        if *shown == "hidden" {
+            notes.push(format!("hidden from {}", bp));
+        } else if base(&|c| c == "hidden").is_some() {
+            notes.push(format!("hidden below {}, shown from {}", bp, bp));
+        }
+    }
+
+    // Drawer pinned open at a breakpoint.
+    if let Some((bp, _)) = responsive(&|c| c == "drawer-open").first() {
+        notes.push(format!(
+            "off-canvas drawer below {} (toggle button), pinned open from {}",
+            bp, bp
+        ));
+    }
+
+    // Grid column steps: `grid-cols-1 md:grid-cols-2 lg:grid-cols-4`.
+    let col_steps = responsive(&|c| columns(c).is_some());
+    if !col_steps.is_empty() {
+        let first = base(&|c| columns(c).is_some())
+            .and_then(columns)
+            .unwrap_or("1");
+        let steps = col_steps
+            .iter()
+            .filter_map(|(bp, c)| columns(c).map(|n| format!("{} from {}", n, bp)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        notes.push(format!("columns: {}, {}", first, steps));
+    }
+
+    // Flex direction flips: `flex-col md:flex-row`.
+    if let Some((bp, c)) = responsive(&|c| direction(c).is_some()).first() {
+        let below = base(&|c| direction(c).is_some())
+            .and_then(direction)
+            .unwrap_or("in a row");
+        let above = direction(c).unwrap_or("in a row");
+        notes.push(format!("{} below {}, {} from {}", below, bp, above, bp));
+    }
+
+    // Width changes: `w-full md:w-64`.
+    let widths = responsive(&is_width);
+    if !widths.is_empty() {
+        let first = base(&is_width).unwrap_or("auto width");
+        let steps = widths
+            .iter()
+            .map(|(bp, c)| format!("{} from {}", c, bp))
+            .collect::<Vec<_>>()
+            .join(", ");
+        notes.push(format!("{} → {}", first, steps));
+    }
+
+    (!notes.is_empty()).then(|| notes.join("; "))
+}
+
+/// Name for an element in annotations: its first daisyUI role class, else
+/// its layout mode (`grid`/`flex`), else its tag.
+fn role(tag: &str, classes: &str) -> String {
+    let has = |name: &str| classes.split_whitespace().any(|c| c == name);
+    classes
+        .split_whitespace()
+        .find(|c| ROLES.contains(c))
+        .or_else(|| ["grid", "flex"].into_iter().find(|m| has(m)))
+        .unwrap_or(tag)
+        .to_string()
+}
+
+/// Inserts `<!-- responsive: … -->` above every line whose first element
+/// changes behavior across breakpoints.
+pub fn annotate(html: &str) -> String {
+    let mut out = String::with_capacity(html.len() + html.len() / 8);
+    for line in html.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if let Some(note) = trimmed
+            .strip_prefix('<')
+            .filter(|rest| !rest.starts_with(['/', '!']))
+            .and_then(|rest| {
+                let tag_end = rest.find(['>', ' ']).unwrap_or(rest.len());
+                let open_end = rest.find('>').unwrap_or(rest.len());
+                let open = &rest[..open_end];
+                let classes = open
+                    .split_once("class=\"")
+                    .and_then(|(_, c)| c.split('"').next())?;
+                describe(classes).map(|d| format!("{}: {}", role(&rest[..tag_end], classes), d))
+            })
+        {
+            let indent = &line[..line.len() - trimmed.len()];
+            out.push_str(&format!("{}<!-- responsive: {} -->\n", indent, note));
+        }
+        out.push_str(line);
+    }
+    out
+}
@@ -0,0 +1,291 @@
+//! Reverse compose: recognizes the sections an existing page is made of
+//! (navbar, hero, pricing, footer, ...) so it can be regenerated or
+//! restyled with daisy-days options.
+//!
+//! Matching is heuristic. Landmarks are found by their daisyUI class or
+//! semantic tag. The blocks between them are scored by shape: prices across
+//! cards, quotes with avatars, rows of logos. Every section carries a
+//! confidence and the evidence behind it, and blocks that match nothing are
+//! listed as `unmatched` rather than guessed.
+
+use crate::html::{Element, elements};
+
+/// Sections below this confidence are reported but left out of the spec.
+pub const MIN_CONFIDENCE: f32 = 0.5;
+
+/// Sections `daisyui_compose_block` / `/daisy-block` can regenerate.
+const BLOCKS: &[(&str, &str)] = &[
+    ("logo-cloud", "logo-cloud"),
+    ("testimonials", "testimonials"),
+    ("pricing", "pricing-tiers"),
+];
+
+/// Tags that never form a visible section.
+const SKIP: &[&str] = &[
+    "head", "script", "style", "meta", "link", "title", "template",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Section {
+    pub kind: &'static str,
+    pub confidence: f32,
+    pub evidence: String,
+}
+
+/// Recognized sections in document order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reverse {
+    pub sections: Vec<Section>,
+}
+
+fn landmark(e: &Element) -> Option<Section> {
+    let found = |kind, confidence, evidence: &str| {
+        Some(Section {
+            kind,
+            confidence,
+            evidence: evidence.to_string(),
+        })
+    };
+    if e.has_class("navbar") {
+        return found("navbar", 0.95, "class `navbar`");
+    }
+    if e.has_class("hero") {
+        return found("hero", 0.95, "class `hero`");
+    }
+    if e.has_class("footer") {
+        return found("footer", 0.95, "class `footer`");
+    }
+    if e.has_class("drawer-side") {
+        return found("sidebar", 0.9, "class `drawer-side`");
+    }
+    if e.has_class("stats") {
+        return found("stats", 0.95, "class `stats`");
+    }
+    match e.tag.as_str() {
+        "footer" => found("footer", 0.8, "`<footer>` element"),
+        "aside" => found("sidebar", 0.7, "`<aside>` element"),
+        _ => None,
+    }
+}
+
+/// Scores a block that isn't a landmark by what it contains.
+fn block(html: &str, inner: &[&Element]) -> Section {
+    let lower = html.to_lowercase();
+    let count = |class: &str| inner.iter().filter(|e| e.has_class(class)).count();
+    let cards = count("card");
+    let titles = count("card-title");
+    let stats = count("stat");
+    let prices = lower
+        .match_indices(['$', '€', '£'])
+        .filter(|(i, _)| lower[i + 1..].starts_with(|c: char| c.is_ascii_digit()))
+        .count();
+    let quotes = lower.matches("&ldquo;").count()
+        + lower.matches('“').count()
+        + count_tag(inner, "blockquote");
+    let logos = count_tag(inner, "img") + count("badge");
+    let grid = inner.iter().any(|e| e.has_class("grid"));
+    let mentions = |words: &[&str]| words.iter().any(|w| lower.contains(w));
+
+    let section = |kind, confidence: f32, evidence: String| Section {
+        kind,
+        confidence: confidence.min(0.95),
+        evidence,
+    };
+    if stats >= 2 {
+        return section("stats", 0.8, format!("{} `stat` items", stats));
+    }
+    if prices >= 2 {
+        let mut c = 0.6;
+        if cards >= 2 {
+            c += 0.2;
+        }
+        if mentions(&["pricing", "per month", "/month", "plan"]) {
+            c += 0.1;
+        }
+        return section(
+            "pricing",
+            c,
+            format!("{} prices across {} cards", prices, cards),
+        );
+    }
+    if quotes >= 2 {
+        let avatars = count("avatar");
+        let mut c = 0.6;
+        if avatars >= 2 {
+            c += 0.2;
+        }
+        if mentions(&["testimonial", "loved by", "what our", "customers say"]) {
+            c += 0.1;
+        }
+        return section(
+            "testimonials",
+            c,
+            format!("{} quotes with {} avatars", quotes, avatars),
+        );
+    }
+    if logos >= 4 && cards == 0 {
+        let mut c = 0.6;
+        if mentions(&["trusted by", "used by", "logos", "customers"]) {
+            c += 0.2;
+        }
+        return section(
+            "logo-cloud",
+            c,
+            format!("{} logos or name badges, no cards", logos),
+        );
+    }
+    if cards >= 3 && titles >= 3 {
+        return section(
+            "features",
+            if grid { 0.7 } else { 0.55 },
+            format!(
+                "{} titled cards{}",
+                titles,
+                if grid { " in a grid" } else { "" }
+            ),
+        );
+    }
+    section("unmatched", 0.0, "no known pattern".to_string())
+}
+
+fn count_tag(inner: &[&Element], tag: &str) -> usize {
+    inner.iter().filter(|e| e.tag == tag).count()
+}
+
+fn has_text(html: &str) -> bool {
+    let mut in_tag = false;
+    html.chars().any(|c| match c {
+        '<' => {
+            in_tag = true;
+            false
+        }
+        '>' => {
+            in_tag = false;
+            false
+        }
+        c => !in_tag && !c.is_whitespace(),
+    })
+}
+
+pub fn analyze(html: &str) -> Reverse {
+    let all = elements(html);
+    let inside =
+        |outer: &Element, inner: &Element| inner.start > outer.start && inner.end <= outer.end;
+
+    // Outermost landmarks only; a navbar inside a drawer sidebar is part of it.
+    let mut landmarks: Vec<(usize, Section)> = Vec::new();
+    for (i, e) in all.iter().enumerate() {
+        if landmarks.iter().any(|(l, _)| inside(&all[*l], e)) {
+            continue;
+        }
+        if let Some(section) = landmark(e) {
+            landmarks.push((i, section));
+        }
+    }
+    let holds_landmark = |e: &Element| landmarks.iter().any(|(l, _)| inside(e, &all[*l]));
+
+    // Wrappers are the elements sections hang off: the root, `<main>`,
+    // anything holding a landmark, and the only child of a wrapper.
+    let mut wrapper = vec![false; all.len()];
+    for (i, e) in all.iter().enumerate() {
+        wrapper[i] = match e.parent {
+            None => true,
+            Some(p) => {
+                e.tag == "main"
+                    || holds_landmark(e)
+                    || (wrapper[p] && all.iter().filter(|c| c.parent == Some(p)).count() == 1)
+            }
+        };
+    }
+
+    let mut found: Vec<(usize, Section)> = Vec::new();
+    let mut taken: Vec<usize> = Vec::new();
+    for (i, e) in all.iter().enumerate() {
+        if taken.iter().any(|t| inside(&all[*t], e)) || SKIP.contains(&e.tag.as_str()) {
+            continue;
+        }
+        if let Some((_, section)) = landmarks.iter().find(|(l, _)| *l == i) {
+            found.push((i, section.clone()));
+            taken.push(i);
+            continue;
+        }
+        let parent_is_wrapper = e.parent.is_some_and(|p| wrapper[p]);
+        if wrapper[i] || !(parent_is_wrapper || e.tag == "section") {
+            continue;
+        }
+        let body = &html[e.start..e.end];
+        if !has_text(body) {
+            continue;
+        }
+        let inner: Vec<&Element> = all.iter().filter(|c| inside(e, c) || *c == e).collect();
+        found.push((i, block(body, &inner)));
+        taken.push(i);
+    }
+
+    Reverse {
+        sections: found.into_iter().map(|(_, s)| s).collect(),
+    }
+}
+
+impl Reverse {
+    /// Section kinds confident enough to regenerate, in page order.
+    pub fn spec_sections(&self) -> Vec<&'static str> {
+        self.sections
+            .iter()
+            .filter(|s| s.confidence >= MIN_CONFIDENCE)
+            .map(|s| s.kind)
+            .collect()
+    }
+
+    /// Compose spec as JSON: the section list plus the blocks that
+    /// `daisyui_compose_block` can regenerate.
+    pub fn spec_json(&self) -> String {
+        let sections = self.spec_sections();
+        let quoted = |items: &[&str]| {
+            items
+                .iter()
+                .map(|s| format!("\"{}\"", s))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let blocks: Vec<&str> = sections
+            .iter()
+            .filter_map(|s| BLOCKS.iter().find(|(k, _)| k == s).map(|(_, b)| *b))
+            .collect();
+        format!(
+            "{{\n  \"sections\": [{}],\n  \"blocks\": [{}]\n}}",
+            quoted(&sections),
+            blocks
+                .iter()
+                .map(|b| format!("{{ \"block\": \"{}\" }}", b))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
+    /// Per-section confidence table.
+    pub fn markdown(&self) -> String {
+        if self.sections.is_empty() {
+            return "No sections recognized.".to_string();
+        }
+        let mut out = String::from("| # | Section | Confidence | Evidence |\n|---|---|---|---|\n");
+        for (i, s) in self.sections.iter().enumerate() {
+            let band = if s.confidence >= 0.8 {
+                "high"
+            } else if s.confidence >= MIN_CONFIDENCE {
+                "medium"
+            } else {
+                "low"
+            };
+            out.push_str(&format!(
+                "| {} | {} | {:.0}% ({}) | {} |\n",
+                i + 1,
+                s.kind,
+                s.confidence * 100.0,
+                band,
+                s.evidence
+            ));
+        }
+        out.trim_end().to_string()
+    }
+}
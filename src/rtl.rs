@@ -0,0 +1,123 @@
+//! Right-to-left output: `--rtl` on `/daisy-layout` and `direction: "rtl"`
+//! on `daisyui_scaffold_layout`.
+//!
+//! The builders write physical utilities (`border-r` on a sidebar, `ml-auto`
+//! in a navbar), so [`apply`] mirrors them class by class and marks the root
+//! `dir="rtl"`. Logical utilities (`ms-*`, `me-*`, `ps-*`, `border-s`,
+//! `rounded-s`, `start-*`) already follow the direction and are left alone,
+//! as are flex and grid order and Tailwind 4's `space-x-*`, which the
+//! browser mirrors on its own.
+
+use crate::html;
+
+/// Mirrored utility pairs. A class matches a side when it is that utility
+/// or continues it with `-`: `border-l` covers `border-l-4` and
+/// `border-l-primary`, `ml` covers `ml-auto`, but `rounded-l` doesn't
+/// cover `rounded-lg`.
+pub const PAIRS: &[(&str, &str)] = &[
+    ("ml", "mr"),
+    ("pl", "pr"),
+    ("scroll-ml", "scroll-mr"),
+    ("scroll-pl", "scroll-pr"),
+    ("left", "right"),
+    ("border-l", "border-r"),
+    ("rounded-l", "rounded-r"),
+    ("rounded-tl", "rounded-tr"),
+    ("rounded-bl", "rounded-br"),
+    ("text-left", "text-right"),
+    ("float-left", "float-right"),
+    ("clear-left", "clear-right"),
+    ("origin-left", "origin-right"),
+    ("origin-top-left", "origin-top-right"),
+    ("origin-bottom-left", "origin-bottom-right"),
+    ("bg-left", "bg-right"),
+    ("bg-gradient-to-l", "bg-gradient-to-r"),
+    ("bg-gradient-to-tl", "bg-gradient-to-tr"),
+    ("bg-gradient-to-bl", "bg-gradient-to-br"),
+    ("bg-linear-to-l", "bg-linear-to-r"),
+    ("bg-linear-to-tl", "bg-linear-to-tr"),
+    ("bg-linear-to-bl", "bg-linear-to-br"),
+    ("dropdown-left", "dropdown-right"),
+    ("tooltip-left", "tooltip-right"),
+];
+
+/// Values accepted for `direction`.
+#[allow(dead_code)] // the MCP server's schema enum; the extension has `--rtl`
+pub const DIRECTIONS: &[&str] = &["ltr", "rtl"];
+
+/// `utility` with its side swapped, if it has one.
+fn mirror(utility: &str) -> Option<String> {
+    PAIRS.iter().find_map(|(l, r)| {
+        [(l, r), (r, l)].into_iter().find_map(|(from, to)| {
+            let rest = utility.strip_prefix(from)?;
+            (rest.is_empty() || rest.starts_with('-')).then(|| format!("{}{}", to, rest))
+        })
+    })
+}
+
+/// One class mirrored, keeping its variants (`md:`, `hover:`), a negative
+/// sign and an important mark: `md:-ml-2` becomes `md:-mr-2`.
+pub fn flip(class: &str) -> String {
+    let mut depth = 0;
+    let mut split = 0;
+    for (i, c) in class.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            ':' if depth == 0 => split = i + 1,
+            _ => {}
+        }
+    }
+    let (variants, utility) = class.split_at(split);
+    let (bang, utility) = match utility.strip_prefix('!') {
+        Some(rest) => ("!", rest),
+        None => ("", utility),
+    };
+    let (sign, utility) = match utility.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", utility),
+    };
+    match mirror(utility) {
+        Some(mirrored) => format!("{}{}{}{}", variants, bang, sign, mirrored),
+        None => class.to_string(),
+    }
+}
+
+/// `html` mirrored for a right-to-left language: every `class` attribute
+/// rewritten with [`flip`] and `dir="rtl"` on the root. A fragment with
+/// several top-level elements is wrapped in a `<div dir="rtl">`, the way
+/// `theme::scope` wraps them.
+pub fn apply(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(at) = rest.find("class=\"") {
+        let start = at + 7;
+        let end = rest[start..].find('"').map_or(rest.len(), |e| start + e);
+        out.push_str(&rest[..start]);
+        let classes: Vec<String> = rest[start..end].split_whitespace().map(flip).collect();
+        out.push_str(&classes.join(" "));
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    mark(&out)
+}
+
+fn mark(html: &str) -> String {
+    let elements = html::elements(html);
+    let mut roots = elements.iter().filter(|e| e.parent.is_none());
+    let (Some(root), None) = (roots.next(), roots.next()) else {
+        return format!("<div dir=\"rtl\">\n{}\n</div>", html.trim());
+    };
+    let open = root.start + 1 + root.tag.len();
+    let tag_end = html[root.start..]
+        .find('>')
+        .map_or(html.len(), |e| root.start + e);
+    match html[open..tag_end].find(" dir=\"") {
+        Some(at) => {
+            let value = open + at + 6;
+            let end = html[value..].find('"').map_or(tag_end, |e| value + e);
+            format!("{}rtl{}", &html[..value], &html[end..])
+        }
+        None => format!("{} dir=\"rtl\"{}", &html[..open], &html[open..]),
+    }
+}
@@ -0,0 +1,484 @@
+//! Sample content for layouts: people, product titles, prices, dates and
+//! lorem sentences picked from fixed pools.
+//!
+//! A [`Sample`] is seeded from the layout's title, or from an explicit seed,
+//! so the same seed always fills a layout with the same content while two
+//! titles give visibly different pages. Pool items are plain text with no
+//! characters that need escaping, so they go into markup as they are.
+//!
+//! [`Counts`] set how many items a layout repeats (`cards`, `columns`,
+//! `posts`, `messages`); each layout reads the keys in [`COUNT_KEYS`].
+
+use crate::docs::mix;
+
+pub const FIRST_NAMES: &[&str] = &[
+    "Ada",
+    "Grace",
+    "Alan",
+    "Hedy",
+    "Linus",
+    "Margaret",
+    "Dennis",
+    "Barbara",
+    "Ken",
+    "Radia",
+    "Tim",
+    "Frances",
+    "Guido",
+    "Katherine",
+    "Yukihiro",
+    "Joan",
+    "Sofia",
+    "Omar",
+    "Priya",
+    "Mateo",
+];
+
+pub const LAST_NAMES: &[&str] = &[
+    "Lovelace",
+    "Hopper",
+    "Turing",
+    "Lamarr",
+    "Torvalds",
+    "Hamilton",
+    "Ritchie",
+    "Liskov",
+    "Thompson",
+    "Perlman",
+    "Lee",
+    "Allen",
+    "Rossum",
+    "Johnson",
+    "Matsumoto",
+    "Clarke",
+    "Reyes",
+    "Haddad",
+    "Nair",
+    "Alvarez",
+];
+
+pub const PRODUCTS: &[&str] = &[
+    "Everyday Tote",
+    "Canvas Sneakers",
+    "Ceramic Mug",
+    "Linen Shirt",
+    "Desk Lamp",
+    "Wool Beanie",
+    "Leather Wallet",
+    "Steel Water Bottle",
+    "Travel Backpack",
+    "Scented Candle",
+    "Dotted Notebook",
+    "Wireless Earbuds",
+    "Walnut Cutting Board",
+    "Rain Jacket",
+    "Cotton Throw",
+    "Pour-Over Kettle",
+];
+
+/// Blog post titles.
+pub const HEADLINES: &[&str] = &[
+    "Rust vs Go in Production",
+    "Digital Minimalism",
+    "The Evolution of Modern Web Development",
+    "Designing for Dark Mode",
+    "What We Learned Shipping Weekly",
+    "A Field Guide to Design Tokens",
+    "Why Our Team Writes Less Code",
+    "Notes from a Remote Offsite",
+    "Small Tools, Big Habits",
+    "The Case for Boring Technology",
+    "Accessibility Is a Feature",
+    "Building in Public, One Year In",
+];
+
+/// Blog categories and trend topics.
+pub const TOPICS: &[&str] = &[
+    "Technology",
+    "Design",
+    "Culture",
+    "Business",
+    "Productivity",
+    "Engineering",
+    "Startups",
+    "Remote Work",
+];
+
+/// Short posts for a social feed.
+pub const STATUSES: &[&str] = &[
+    "Just shipped a new update for the platform! 🚀",
+    "Coffee first, then code review. ☕",
+    "Hot take: the best feature is the one you delete.",
+    "Our design system finally has a dark mode. 🌙",
+    "Pairing beats meetings, every time.",
+    "Wrote the docs before the code today. Felt great.",
+    "Who else is refactoring on a Friday? 🙃",
+    "Tiny fix, huge relief. 🐛",
+    "Reading list for the weekend is getting long. 📚",
+    "Demo day went better than expected! 🎉",
+];
+
+/// Cards on a task board.
+pub const TASKS: &[&str] = &[
+    "Create high-fidelity mockups",
+    "Research competitor market",
+    "Implement authentication",
+    "Set up the repository",
+    "Write onboarding emails",
+    "Fix flaky checkout test",
+    "Plan the Q3 roadmap",
+    "Migrate to the new API",
+    "Audit color contrast",
+    "Draft release notes",
+    "Add CSV export",
+    "Interview beta users",
+];
+
+/// Task labels, each with the badge color it is shown in.
+pub const LABELS: &[(&str, &str)] = &[
+    ("Design", "badge-warning"),
+    ("Dev", "badge-info"),
+    ("Research", "badge-secondary"),
+    ("Bug", "badge-error"),
+    ("Docs", "badge-accent"),
+];
+
+/// Senders of mail: services and people alike.
+pub const SENDERS: &[&str] = &[
+    "Apple", "Github", "Figma", "Stripe", "Linear", "Notion", "Vercel", "Slack", "Dropbox", "Zoom",
+];
+
+/// Mail subject lines.
+pub const SUBJECTS: &[&str] = &[
+    "Your receipt for iCloud+",
+    "Security alert",
+    "Invoice for March",
+    "You have been invited to a project",
+    "Weekly summary",
+    "Your trial ends in 3 days",
+    "New comment on your design",
+    "Password changed",
+    "Meeting notes from Tuesday",
+    "Welcome aboard!",
+];
+
+pub const JOB_TITLES: &[&str] = &[
+    "Senior Frontend Engineer",
+    "Product Designer",
+    "Rust Developer",
+    "Technical Writer",
+    "Data Analyst",
+    "DevOps Engineer",
+    "Engineering Manager",
+    "UX Researcher",
+    "Backend Engineer",
+    "Customer Success Lead",
+];
+
+pub const COMPANIES: &[&str] = &[
+    "Northwind",
+    "Globex",
+    "Initech",
+    "Umbrella",
+    "Hooli",
+    "Acme",
+    "Stark",
+    "Wayne",
+    "Cyberdyne",
+    "Soylent",
+];
+
+/// Job locations.
+pub const CITIES: &[&str] = &[
+    "Remote · EU",
+    "Remote · US",
+    "Remote",
+    "Berlin",
+    "Lisbon",
+    "Toronto",
+    "Singapore",
+    "Cairo",
+    "Austin",
+    "London",
+];
+
+/// Skills shown as job tags.
+pub const SKILLS: &[&str] = &[
+    "React",
+    "Figma",
+    "Rust",
+    "Python",
+    "SQL",
+    "Kubernetes",
+    "TypeScript",
+    "Go",
+    "Markdown",
+];
+
+#[allow(dead_code)] // only the MCP server's layouts show dates
+pub const MONTHS: &[&str] = &[
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Lorem ipsum, one sentence each.
+pub const SENTENCES: &[&str] = &[
+    "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+    "Sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.",
+    "Ut enim ad minim veniam, quis nostrud exercitation ullamco.",
+    "Duis aute irure dolor in reprehenderit in voluptate velit esse.",
+    "Excepteur sint occaecat cupidatat non proident.",
+    "Curabitur pretium tincidunt lacus, nulla gravida orci a odio.",
+    "Nullam varius, turpis et commodo pharetra, est eros bibendum elit.",
+    "Integer in mauris eu nibh euismod gravida.",
+    "Praesent blandit odio eu enim pellentesque sed dapibus.",
+    "Vestibulum ante ipsum primis in faucibus orci luctus et ultrices.",
+];
+
+/// Every pool, named, for checks over all of them.
+#[allow(dead_code)] // read by tests
+pub const POOLS: &[(&str, &[&str])] = &[
+    ("first names", FIRST_NAMES),
+    ("last names", LAST_NAMES),
+    ("products", PRODUCTS),
+    ("headlines", HEADLINES),
+    ("topics", TOPICS),
+    ("statuses", STATUSES),
+    ("tasks", TASKS),
+    ("senders", SENDERS),
+    ("subjects", SUBJECTS),
+    ("job titles", JOB_TITLES),
+    ("companies", COMPANIES),
+    ("cities", CITIES),
+    ("skills", SKILLS),
+    ("months", MONTHS),
+    ("sentences", SENTENCES),
+];
+
+/// Kanban lanes between the first ("To Do") and the last ("Done"), in order.
+pub const STAGES: &[&str] = &["In Progress", "Review", "Testing", "Blocked", "Staging"];
+
+/// Names and status-badge states of `count` kanban lanes: "To Do" first,
+/// "Done" last and [`STAGES`] between them, numbered once those run out.
+pub fn lanes(count: usize) -> Vec<(String, &'static str)> {
+    (0..count)
+        .map(|i| match i {
+            0 => ("To Do".to_string(), "todo"),
+            _ if i + 1 == count => ("Done".to_string(), "done"),
+            _ => (
+                STAGES
+                    .get(i - 1)
+                    .map_or_else(|| format!("Stage {}", i + 1), |s| s.to_string()),
+                "in-progress",
+            ),
+        })
+        .collect()
+}
+
+/// The most items a count can ask for, to keep pages small.
+pub const MAX_COUNT: usize = 50;
+
+/// The counts each layout reads: `(layout, [(key, what it counts)])`.
+pub const COUNT_KEYS: &[(&str, &[(&str, &str)])] = &[
+    ("blog", &[("posts", "latest stories")]),
+    ("social", &[("posts", "posts in the feed")]),
+    (
+        "kanban",
+        &[("columns", "lanes"), ("cards", "cards in each lane")],
+    ),
+    ("inbox", &[("messages", "messages in the list")]),
+    ("store", &[("cards", "product cards")]),
+    ("jobs", &[("cards", "job listings")]),
+];
+
+/// The count keys `layout` reads; none for layouts without repeated items.
+pub fn count_keys(layout: &str) -> &'static [(&'static str, &'static str)] {
+    COUNT_KEYS
+        .iter()
+        .find(|(l, _)| *l == layout)
+        .map_or(&[], |(_, keys)| keys)
+}
+
+/// Every count key any layout reads, sorted.
+#[allow(dead_code)] // the extension's `--cards=N` flags; the MCP server takes an object
+pub fn count_names() -> Vec<&'static str> {
+    let mut names: Vec<&str> = COUNT_KEYS
+        .iter()
+        .flat_map(|(_, keys)| keys.iter().map(|(k, _)| *k))
+        .collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+}
+
+/// The keys of [`COUNT_KEYS`] as prose: `blog: posts (latest stories); ...`.
+#[allow(dead_code)] // the MCP server's schema; the extension's help lists flags
+pub fn describe_counts() -> String {
+    COUNT_KEYS
+        .iter()
+        .map(|(layout, keys)| {
+            let keys: Vec<String> = keys
+                .iter()
+                .map(|(key, what)| format!("{} ({})", key, what))
+                .collect();
+            format!("{}: {}", layout, keys.join(", "))
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Item counts a layout was asked for. Keys it doesn't read are dropped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Counts(Vec<(&'static str, usize)>);
+
+impl Counts {
+    /// Counts for `layout` from `(key, n)` pairs, with a note for each key
+    /// the layout doesn't read and each number outside `1..=MAX_COUNT`,
+    /// which is clamped.
+    pub fn parse<'a>(
+        layout: &str,
+        pairs: impl IntoIterator<Item = (&'a str, u64)>,
+    ) -> (Self, Vec<String>) {
+        let keys = count_keys(layout);
+        let mut counts = Vec::new();
+        let mut notes = Vec::new();
+        for (key, n) in pairs {
+            let Some((known, _)) = keys.iter().find(|(k, _)| *k == key) else {
+                notes.push(match keys {
+                    [] => format!("Ignored count '{}': the {} layout has none.", key, layout),
+                    _ => format!(
+                        "Ignored count '{}': the {} layout reads {}.",
+                        key,
+                        layout,
+                        keys.iter().map(|(k, _)| *k).collect::<Vec<_>>().join(", ")
+                    ),
+                });
+                continue;
+            };
+            let clamped = (n as usize).clamp(1, MAX_COUNT);
+            if clamped as u64 != n {
+                notes.push(format!(
+                    "Count '{}' must be between 1 and {}, so {} was used.",
+                    key, MAX_COUNT, clamped
+                ));
+            }
+            counts.push((*known, clamped));
+        }
+        (Self(counts), notes)
+    }
+
+    /// The count for `key`, or `default` when it wasn't given.
+    pub fn get(&self, key: &str, default: usize) -> usize {
+        self.0
+            .iter()
+            .rev()
+            .find(|(k, _)| *k == key)
+            .map_or(default, |(_, n)| *n)
+    }
+}
+
+/// The seed a title gives when no explicit one is passed: its FNV-1a hash.
+pub fn seed(title: &str) -> u64 {
+    title.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// A deterministic stream of sample content, with the counts of items to
+/// draw.
+#[derive(Debug, Clone)]
+pub struct Sample {
+    state: u64,
+    counts: Counts,
+}
+
+impl Sample {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: seed,
+            counts: Counts::default(),
+        }
+    }
+
+    pub fn with_counts(self, counts: Counts) -> Self {
+        Self { counts, ..self }
+    }
+
+    /// How many items `key` repeats: the count asked for, else `default`.
+    pub fn count(&self, key: &str, default: usize) -> usize {
+        self.counts.get(key, default)
+    }
+
+    fn next(&mut self) -> u64 {
+        let out = mix(self.state);
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        out
+    }
+
+    /// A number in `0..n`; `n` must not be zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    /// A number in `low..=high`.
+    pub fn between(&mut self, low: u32, high: u32) -> u32 {
+        low + self.below((high - low) as usize + 1) as u32
+    }
+
+    pub fn pick<T: Copy>(&mut self, pool: &[T]) -> T {
+        pool[self.below(pool.len())]
+    }
+
+    /// `count` different items of `pool`, in the order they were drawn;
+    /// all of them, shuffled, when it holds fewer.
+    pub fn distinct<T: Copy>(&mut self, pool: &[T], count: usize) -> Vec<T> {
+        let mut items = pool.to_vec();
+        let count = count.min(items.len());
+        for i in 0..count {
+            let j = i + self.below(items.len() - i);
+            items.swap(i, j);
+        }
+        items.truncate(count);
+        items
+    }
+
+    /// `count` items of `pool`, going through all of it in a shuffled order
+    /// before any item repeats.
+    pub fn many<T: Copy>(&mut self, pool: &[T], count: usize) -> Vec<T> {
+        let mut items = Vec::with_capacity(count);
+        while items.len() < count {
+            let round = self.distinct(pool, count - items.len());
+            items.extend(round);
+        }
+        items
+    }
+
+    /// A first and last name.
+    pub fn name(&mut self) -> String {
+        format!("{} {}", self.pick(FIRST_NAMES), self.pick(LAST_NAMES))
+    }
+
+    /// A price in whole dollars between `low` and `high`, shown with cents:
+    /// `$48.00`.
+    pub fn price(&mut self, low: u32, high: u32) -> String {
+        format!("${}.00", self.between(low, high))
+    }
+
+    /// A month and day, `Dec 9`.
+    #[allow(dead_code)] // only the MCP server's layouts show dates
+    pub fn date(&mut self) -> String {
+        format!("{} {}", self.pick(MONTHS), self.between(1, 28))
+    }
+
+    pub fn sentence(&mut self) -> &'static str {
+        self.pick(SENTENCES)
+    }
+}
+
+/// `name` as a lowercase handle or mailbox name: `Ada Lovelace` becomes
+/// `adalovelace`.
+pub fn handle(name: &str) -> String {
+    name.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
@@ -0,0 +1,442 @@
+//! Named sections of the built-in layouts: the navbar, hero, sidebar, main
+//! content and so on that `/daisy-section` and `daisyui_scaffold_section`
+//! return on their own.
+//!
+//! Layout builders return a [`Page`]: their markup in order, with each
+//! section added under its name, and `generate` joins it back together.
+//! The builders in the extension and the MCP server don't match element for
+//! element, so a section one of them doesn't have (the trends column in the
+//! extension's social feed) is simply never added by that builder.
+//!
+//! [`compose`] stitches sections from any mix of layouts into one page for
+//! `/daisy-compose` and `daisyui_compose_page`, and [`fill`] puts the
+//! caller's markup in a layout's [`SLOTS`] for `daisyui_scaffold_layout`.
+
+use std::ops::Range;
+
+/// A generated layout: markup that belongs to no section (wrappers,
+/// comments, headings) and the named sections, in page order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Page {
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Markup(String),
+    Section {
+        name: &'static str,
+        markup: String,
+        beside: bool,
+    },
+}
+
+impl Page {
+    /// Markup outside any section.
+    pub fn markup(mut self, markup: impl Into<String>) -> Self {
+        let markup = markup.into();
+        if !markup.is_empty() {
+            self.parts.push(Part::Markup(markup));
+        }
+        self
+    }
+
+    /// A section, or another piece of one added before. Whitespace around
+    /// `markup` is kept in the page but not in the section.
+    pub fn section(self, name: &'static str, markup: impl Into<String>) -> Self {
+        self.piece(name, markup.into(), false)
+    }
+
+    /// A section that sits beside the page's root element rather than in
+    /// it: modals, fixed player bars and their scripts.
+    pub fn beside(self, name: &'static str, markup: impl Into<String>) -> Self {
+        self.piece(name, markup.into(), true)
+    }
+
+    fn piece(self, name: &'static str, markup: String, beside: bool) -> Self {
+        let trimmed = markup.trim();
+        if trimmed.is_empty() {
+            return self.markup(markup);
+        }
+        let start = markup.len() - markup.trim_start().len();
+        let end = start + trimmed.len();
+        let mut page = self.markup(&markup[..start]);
+        page.parts.push(Part::Section {
+            name,
+            markup: trimmed.to_string(),
+            beside,
+        });
+        page.markup(&markup[end..])
+    }
+
+    /// The page with `f` applied to each piece of markup, sections keeping
+    /// their names. Pieces only break between tags, so `f` can rewrite tags
+    /// and attributes.
+    #[allow(dead_code)] // only the MCP server applies concepts to pages
+    pub fn map(self, mut f: impl FnMut(&str) -> String) -> Self {
+        let parts = self
+            .parts
+            .into_iter()
+            .map(|part| match part {
+                Part::Markup(markup) => Part::Markup(f(&markup)),
+                Part::Section {
+                    name,
+                    markup,
+                    beside,
+                } => Part::Section {
+                    name,
+                    markup: f(&markup),
+                    beside,
+                },
+            })
+            .collect();
+        Page { parts }
+    }
+
+    /// The whole page.
+    pub fn html(&self) -> String {
+        self.parts
+            .iter()
+            .map(|part| match part {
+                Part::Markup(markup) | Part::Section { markup, .. } => markup.as_str(),
+            })
+            .collect()
+    }
+
+    /// Names of the page's sections, in order.
+    pub fn names(&self) -> Vec<&'static str> {
+        let mut names = Vec::new();
+        for part in &self.parts {
+            if let Part::Section { name, .. } = part
+                && !names.contains(name)
+            {
+                names.push(*name);
+            }
+        }
+        names
+    }
+
+    /// Pieces of section `name`, and whether each sits beside the root.
+    fn pieces<'a>(&'a self, name: &'a str) -> impl Iterator<Item = (&'a str, bool)> + 'a {
+        self.parts.iter().filter_map(move |part| match part {
+            Part::Section {
+                name: n,
+                markup,
+                beside,
+            } if *n == name => Some((markup.as_str(), *beside)),
+            _ => None,
+        })
+    }
+
+    /// Section `name`'s pieces joined by newlines.
+    pub fn get(&self, name: &str) -> Option<String> {
+        let pieces: Vec<&str> = self.pieces(name).map(|(markup, _)| markup).collect();
+        (!pieces.is_empty()).then(|| pieces.join("\n"))
+    }
+}
+
+/// Template layouts come as plain markup, without named sections.
+impl From<String> for Page {
+    fn from(html: String) -> Self {
+        Page::default().markup(html)
+    }
+}
+
+/// Layouts and their sections in page order.
+pub const SECTIONS: &[(&str, &[&str])] = &[
+    (
+        "saas",
+        &[
+            "navbar",
+            "hero",
+            "logos",
+            "features",
+            "testimonials",
+            "pricing",
+            "footer",
+        ],
+    ),
+    ("blog", &["navbar", "featured", "posts"]),
+    ("social", &["sidebar", "feed", "trends"]),
+    ("kanban", &["navbar", "board"]),
+    ("inbox", &["sidebar", "messages", "main"]),
+    ("profile", &["menu", "main"]),
+    ("docs", &["navbar", "main", "sidebar"]),
+    ("dashboard", &["navbar", "main", "sidebar"]),
+    ("auth", &["form"]),
+    ("store", &["navbar", "hero", "products"]),
+    ("pricing", &["hero", "plans", "comparison", "faq"]),
+    ("checkout", &["steps", "form", "summary"]),
+    ("error", &["hero"]),
+    ("crud", &["toolbar", "table", "pagination", "modals"]),
+    (
+        "app-landing",
+        &["hero", "screenshots", "reviews", "download-bar"],
+    ),
+    ("forum", &["sidebar", "main", "modals"]),
+    ("analytics", &["toolbar", "stats", "charts", "table"]),
+    ("settings", &["main"]),
+    ("status", &["alert", "services", "incidents", "footer"]),
+    ("media", &["navbar", "main", "sidebar", "player"]),
+    ("jobs", &["hero", "sidebar", "main"]),
+    ("changelog", &["header", "timeline", "older"]),
+    ("team", &["hero", "members", "values", "offices", "join"]),
+    (
+        "faq",
+        &["hero", "categories", "questions", "contact", "modals"],
+    ),
+    ("survey", &["quiz"]),
+];
+
+/// Every section name any layout declares, in first-seen order.
+pub fn all_names() -> Vec<&'static str> {
+    let mut names = Vec::new();
+    for (_, sections) in SECTIONS {
+        for name in *sections {
+            if !names.contains(name) {
+                names.push(*name);
+            }
+        }
+    }
+    names
+}
+
+fn declared(layout: &str) -> &'static [&'static str] {
+    SECTIONS
+        .iter()
+        .find(|(l, _)| *l == layout)
+        .map_or(&[], |(_, s)| *s)
+}
+
+/// One section's markup, or an error listing the sections `page` has.
+pub fn pick(layout: &str, page: &Page, section: &str) -> Result<String, String> {
+    let found = page.names();
+    if found.is_empty() {
+        return Err(format!("Layout '{}' has no named sections", layout));
+    }
+    page.get(section).ok_or_else(|| {
+        format!(
+            "Unknown section '{}' for {}. Available: {}",
+            section,
+            layout,
+            found.join(", ")
+        )
+    })
+}
+
+/// A section identifier: `layout:section`, or a bare section name meaning
+/// the first layout in [`SECTIONS`] that declares it (`hero` is the saas
+/// hero, `faq` the pricing page's).
+pub fn resolve(id: &str) -> Result<(&'static str, &'static str), String> {
+    let pick_from = |layout: &'static str, section: &str| {
+        declared(layout)
+            .iter()
+            .find(|name| **name == section)
+            .map(|name| (layout, *name))
+    };
+    match id.split_once(':') {
+        Some((layout, section)) => {
+            let (layout, sections) = SECTIONS
+                .iter()
+                .find(|(l, _)| *l == layout)
+                .ok_or_else(|| format!("Unknown layout '{}' in '{}'", layout, id))?;
+            pick_from(layout, section).ok_or_else(|| {
+                format!(
+                    "Unknown section '{}' for {}. Available: {}",
+                    section,
+                    layout,
+                    sections.join(", ")
+                )
+            })
+        }
+        None => SECTIONS
+            .iter()
+            .find_map(|(layout, _)| pick_from(layout, id))
+            .ok_or_else(|| {
+                format!(
+                    "No layout has a section named '{}'. Sections: {}",
+                    id,
+                    all_names().join(", ")
+                )
+            }),
+    }
+}
+
+/// Sections a page has only one of; later ones are dropped.
+const ONCE: &[&str] = &["navbar", "footer"];
+
+fn full_height(class: &str) -> bool {
+    matches!(class, "min-h-screen" | "h-screen" | "min-h-dvh" | "h-dvh")
+        || (class.starts_with("min-h-[") && class.ends_with("vh]"))
+}
+
+/// `markup` with the full-height classes removed from its first tag, and
+/// whether there were any.
+fn strip_height(markup: &str) -> (String, bool) {
+    let tag_end = markup.find('>').unwrap_or(markup.len());
+    let Some(start) = markup[..tag_end].find("class=\"").map(|i| i + 7) else {
+        return (markup.to_string(), false);
+    };
+    let end = start + markup[start..].find('"').unwrap_or(0);
+    let classes: Vec<&str> = markup[start..end].split_whitespace().collect();
+    let kept: Vec<&str> = classes
+        .iter()
+        .copied()
+        .filter(|c| !full_height(c))
+        .collect();
+    if kept.len() == classes.len() {
+        return (markup.to_string(), false);
+    }
+    (
+        format!("{}{}{}", &markup[..start], kept.join(" "), &markup[end..]),
+        true,
+    )
+}
+
+/// One page from sections named by [`resolve`] identifiers, in order.
+/// `generate` builds a layout's [`Page`]; each layout is generated once, so
+/// ids stay unique when it shares one allocator. Returns the page and notes
+/// on what was changed to make the sections fit together.
+///
+/// The sections share one page wrapper instead of each keeping its
+/// layout's. A second navbar or footer, or a section asked for twice, is
+/// dropped, and only the first full-height section (a `min-h-screen` hero)
+/// keeps its height. A leading comment lists each section's layout.
+pub fn compose(
+    ids: &[&str],
+    mut generate: impl FnMut(&str) -> Page,
+) -> Result<(String, Vec<String>), String> {
+    if ids.is_empty() {
+        return Err("No sections given".into());
+    }
+    let mut pages: Vec<(&str, Page)> = Vec::new();
+    let mut used: Vec<(&str, &str)> = Vec::new();
+    let mut body = Vec::new();
+    let mut beside = Vec::new();
+    let mut notes = Vec::new();
+    let mut tall = false;
+    for id in ids {
+        let (layout, section) = resolve(id)?;
+        if used.contains(&(layout, section)) {
+            notes.push(format!("Dropped the repeated {}:{}.", layout, section));
+            continue;
+        }
+        if let Some((l, s)) = used.iter().find(|(_, s)| *s == section && ONCE.contains(s)) {
+            notes.push(format!(
+                "Dropped {}:{}; the page already has the {} from {}.",
+                layout, section, s, l
+            ));
+            continue;
+        }
+        if !pages.iter().any(|(l, _)| *l == layout) {
+            pages.push((layout, generate(layout)));
+        }
+        let page = &pages.iter().find(|(l, _)| *l == layout).unwrap().1;
+        let mut markup = pick(layout, page, section)?;
+        let (stripped, was_tall) = strip_height(&markup);
+        if was_tall && tall {
+            markup = stripped;
+            notes.push(format!(
+                "Removed the full-height classes from {}:{}; only the first full-height section keeps them.",
+                layout, section
+            ));
+        }
+        tall |= was_tall;
+        used.push((layout, section));
+        if page.pieces(section).all(|(_, beside)| beside) {
+            beside.push(markup);
+        } else {
+            body.push(markup);
+        }
+    }
+    let header = used
+        .iter()
+        .map(|(layout, section)| format!("{} ({})", section, layout))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut html = format!(
+        "<!-- Composed from: {} -->\n<div class=\"min-h-screen bg-base-100\">\n{}\n</div>",
+        header,
+        body.join("\n")
+    );
+    for markup in beside {
+        html.push('\n');
+        html.push_str(&markup);
+    }
+    Ok((html, notes))
+}
+
+/// Sections whose contents `daisyui_scaffold_layout` can replace.
+#[allow(dead_code)] // only the MCP server takes slots
+pub const SLOTS: &[&str] = &["hero", "sidebar", "main", "footer"];
+
+/// The range of `element`'s contents: what lies between its opening tag
+/// and its closing tag.
+fn inner(element: &str) -> Range<usize> {
+    let open = element.find('>').map_or(element.len(), |e| e + 1);
+    let tag: String = element[1..]
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .collect();
+    let close = format!("</{}>", tag);
+    match element[open..].ends_with(&close) {
+        true => open..element.len() - close.len(),
+        false => open..element.len(),
+    }
+}
+
+/// `page`'s markup with the contents of its slot sections replaced by the
+/// markup in `slots`, as given: the section's element and its classes stay,
+/// its children go. Returns notes on slots that aren't in [`SLOTS`] or that
+/// `layout` doesn't have, and a warning for markup holding a `<script>`.
+#[allow(dead_code)] // only the MCP server takes slots
+pub fn fill(layout: &str, page: &Page, slots: &[(&str, &str)]) -> (String, Vec<String>) {
+    let has: Vec<&str> = page
+        .names()
+        .into_iter()
+        .filter(|name| SLOTS.contains(name))
+        .collect();
+    let mut notes = Vec::new();
+    let mut filled = page.clone();
+    for (slot, markup) in slots {
+        if !SLOTS.contains(slot) {
+            notes.push(format!(
+                "Unknown slot '{}'. Slots: {}.",
+                slot,
+                SLOTS.join(", ")
+            ));
+            continue;
+        }
+        if !has.contains(slot) {
+            notes.push(match has.as_slice() {
+                [] => format!(
+                    "The {} layout has no slots, so '{}' was not used.",
+                    layout, slot
+                ),
+                _ => format!(
+                    "The {} layout has no {} slot, so it was not used. Its slots: {}.",
+                    layout,
+                    slot,
+                    has.join(", ")
+                ),
+            });
+            continue;
+        }
+        if markup.to_ascii_lowercase().contains("<script") {
+            notes.push(format!(
+                "Warning: the {} slot contains a <script> tag. It was inserted as given, so check it before using the page.",
+                slot
+            ));
+        }
+        // A section made of several pieces takes the markup in its first.
+        let first = filled.parts.iter_mut().find_map(|part| match part {
+            Part::Section { name, markup, .. } if name == slot => Some(markup),
+            _ => None,
+        });
+        if let Some(element) = first {
+            let range = inner(element);
+            element.replace_range(range, markup);
+        }
+    }
+    (filled.html(), notes)
+}
@@ -0,0 +1,180 @@
+//! Defaults for slash commands, read from Zed's `lsp.daisy-days.settings`
+//! and then a project's `.daisy-days/settings.json`.
+//!
+//! The user settings apply everywhere; a key in the worktree file overrides
+//! them for that project. Explicit flags always win over both, and both win
+//! over the built-in defaults.
+
+use crate::sample;
+use std::collections::HashMap;
+
+/// Where a worktree keeps its defaults.
+pub const FILE: &str = ".daisy-days/settings.json";
+/// The Zed settings key that holds user defaults, under `lsp`.
+pub const LSP_NAME: &str = "daisy-days";
+
+/// Every field is optional; explicit `--flag=value` arguments always win.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Settings {
+    pub theme: Option<String>,
+    pub format: Option<String>,
+    pub placeholders: Option<String>,
+    pub search_results: Option<usize>,
+    pub completion_results: Option<usize>,
+    pub daisyui_version: Option<String>,
+    pub sanitize: Option<String>,
+    pub purity: Option<String>,
+    pub whitespace: Option<String>,
+    pub max_output: Option<usize>,
+}
+
+impl Settings {
+    pub const KEYS: &[&str] = &[
+        "theme",
+        "format",
+        "placeholders",
+        "search_results",
+        "completion_results",
+        "daisyui_version",
+        "sanitize",
+        "purity",
+        "whitespace",
+        "max_output",
+    ];
+    pub const FORMATS: &[&str] = &["html", "jsx", "leptos", "yew", "askama", "tera"];
+    pub const VERSIONS: &[&str] = &["5", "4"];
+    pub const SANITIZE: &[&str] = &["lenient", "strict"];
+    /// Output larger than this (bytes) is split into `--part`s.
+    pub const MAX_OUTPUT: usize = 48 * 1024;
+    pub const MIN_OUTPUT: usize = 1024;
+
+    /// Flags written as `--flag value` as well as `--flag=value`. Any other
+    /// flag is a switch, so `--rtl Admin` leaves `Admin` as an argument.
+    /// Item counts such as `--cards` take a value too.
+    const VALUE_FLAGS: &[&str] = &[
+        "variant",
+        "theme",
+        "format",
+        "placeholders",
+        "daisyui-version",
+        "purity",
+        "whitespace",
+        "id-prefix",
+        "concept",
+        "title",
+        "suggest",
+        "colors",
+        "limit",
+        "offset",
+        "sanitize",
+        "max-output",
+        "part",
+    ];
+
+    /// Settings from a parsed file: `text` reads a string or number value and
+    /// `number` a non-negative integer. Result counts are clamped to 1-100
+    /// and `max_output` to at least [`Self::MIN_OUTPUT`].
+    pub fn from_values(
+        text: impl Fn(&str) -> Option<String>,
+        number: impl Fn(&str) -> Option<u64>,
+    ) -> Self {
+        let count = |key: &str| number(key).map(|n| n.clamp(1, 100) as usize);
+        Self {
+            theme: text("theme"),
+            format: text("format"),
+            placeholders: text("placeholders"),
+            search_results: count("search_results"),
+            completion_results: count("completion_results"),
+            daisyui_version: text("daisyui_version"),
+            sanitize: text("sanitize"),
+            purity: text("purity"),
+            whitespace: text("whitespace"),
+            max_output: number("max_output").map(|n| (n as usize).max(Self::MIN_OUTPUT)),
+        }
+    }
+
+    /// These settings with any field they leave unset taken from `base`.
+    pub fn over(self, base: Self) -> Self {
+        Self {
+            theme: self.theme.or(base.theme),
+            format: self.format.or(base.format),
+            placeholders: self.placeholders.or(base.placeholders),
+            search_results: self.search_results.or(base.search_results),
+            completion_results: self.completion_results.or(base.completion_results),
+            daisyui_version: self.daisyui_version.or(base.daisyui_version),
+            sanitize: self.sanitize.or(base.sanitize),
+            purity: self.purity.or(base.purity),
+            whitespace: self.whitespace.or(base.whitespace),
+            max_output: self.max_output.or(base.max_output),
+        }
+    }
+
+    /// Splits `--key=value` / `--key value` flags from positional arguments.
+    /// Only [`Self::VALUE_FLAGS`] and item counts take the next argument.
+    pub fn split_flags(args: &[String]) -> (Vec<String>, HashMap<String, String>) {
+        let mut positional = Vec::new();
+        let mut flags = HashMap::new();
+        let mut iter = args.iter().peekable();
+        while let Some(arg) = iter.next() {
+            match arg.strip_prefix("--") {
+                Some(flag) => match flag.split_once('=') {
+                    Some((key, value)) => {
+                        flags.insert(key.to_string(), value.to_string());
+                    }
+                    None => {
+                        let value = if Self::takes_value(flag) {
+                            iter.next_if(|next| !next.starts_with("--"))
+                                .cloned()
+                                .unwrap_or_default()
+                        } else {
+                            String::new()
+                        };
+                        flags.insert(flag.to_string(), value);
+                    }
+                },
+                None => positional.push(arg.clone()),
+            }
+        }
+        (positional, flags)
+    }
+
+    fn takes_value(flag: &str) -> bool {
+        Self::VALUE_FLAGS.contains(&flag) || sample::count_names().contains(&flag)
+    }
+
+    /// Boolean flags: `--dot`, `--dot=true`; `--dot=false` turns it off.
+    pub fn enabled(flags: &HashMap<String, String>, key: &str) -> bool {
+        flags.get(key).is_some_and(|v| v != "false")
+    }
+
+    /// Flag > setting > built-in default.
+    pub fn resolve(
+        flags: &HashMap<String, String>,
+        key: &str,
+        setting: Option<&str>,
+        default: &str,
+    ) -> String {
+        flags
+            .get(key)
+            .map(String::as_str)
+            .or(setting)
+            .unwrap_or(default)
+            .to_string()
+    }
+
+    pub fn check(key: &str, value: &str, valid: &[&str]) -> Result<(), String> {
+        if valid.contains(&value) {
+            Ok(())
+        } else {
+            Err(format!(
+                "Invalid {} '{}'. Valid: {}. Defaults can be set in lsp.{}.settings or {} (keys: {})",
+                key,
+                value,
+                valid.join(", "),
+                LSP_NAME,
+                FILE,
+                Self::KEYS.join(", ")
+            ))
+        }
+    }
+}
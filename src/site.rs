@@ -0,0 +1,200 @@
+//! Multi-page sites: `/daisy-site` and `daisyui_scaffold_site`.
+//!
+//! A [`SitePreset`] names the layouts a kind of site is made of and the
+//! file each page is saved as. [`build`] generates every page and
+//! [`link_navbar`] rewrites its navbar so the brand points at the home page
+//! and the menu lists the sibling files, the current one marked with
+//! `aria-current="page"`. A page without a navbar gets one as the first
+//! child of its root.
+
+use crate::html::elements;
+
+/// One page of a preset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Page {
+    /// Suggested file name, e.g. `pricing.html`.
+    pub file: &'static str,
+    pub layout: &'static str,
+    /// Menu text for links to the page.
+    pub label: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SitePreset {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Pages in menu order; the first is the home page.
+    pub pages: &'static [Page],
+}
+
+const fn page(file: &'static str, layout: &'static str, label: &'static str) -> Page {
+    Page {
+        file,
+        layout,
+        label,
+    }
+}
+
+pub const PRESETS: &[SitePreset] = &[
+    SitePreset {
+        name: "startup",
+        description: "SaaS landing page, pricing, docs and sign-in",
+        pages: &[
+            page("index.html", "saas", "Home"),
+            page("pricing.html", "pricing", "Pricing"),
+            page("docs.html", "docs", "Docs"),
+            page("login.html", "auth", "Sign in"),
+        ],
+    },
+    SitePreset {
+        name: "shop",
+        description: "Storefront, checkout, sign-in and account settings",
+        pages: &[
+            page("index.html", "store", "Shop"),
+            page("checkout.html", "checkout", "Checkout"),
+            page("login.html", "auth", "Sign in"),
+            page("account.html", "profile", "Account"),
+        ],
+    },
+];
+
+/// The preset called `name`, or an error listing them all.
+pub fn find(name: &str) -> Result<&'static SitePreset, String> {
+    let name = name.trim().to_ascii_lowercase();
+    PRESETS.iter().find(|p| p.name == name).ok_or_else(|| {
+        format!(
+            "Unknown site preset '{}'. Available: {}",
+            name,
+            PRESETS
+                .iter()
+                .map(|p| p.name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    })
+}
+
+/// The `<li>` items linking every page of `preset`, with `current` marked.
+pub fn menu_items(preset: &SitePreset, current: &str) -> String {
+    preset
+        .pages
+        .iter()
+        .map(|p| {
+            let active = if p.file == current {
+                r#" class="menu-active" aria-current="page""#
+            } else {
+                ""
+            };
+            format!("<li><a href=\"{}\"{}>{}</a></li>", p.file, active, p.label)
+        })
+        .collect()
+}
+
+/// `tag` with `class` added to its class attribute, or given one.
+fn add_class(tag: &str, class: &str) -> String {
+    match tag.find(" class=\"") {
+        Some(at) => format!("{}{} {}", &tag[..at + 8], class, &tag[at + 8..]),
+        None => format!("{} class=\"{}\"{}", &tag[..tag.len() - 1], class, ">"),
+    }
+}
+
+/// `tag` with its `href` set to `href`, added after the tag name if missing.
+fn set_href(tag: &str, href: &str) -> String {
+    match tag.find(" href=\"") {
+        Some(at) => {
+            let start = at + 7;
+            let end = tag[start..].find('"').map_or(tag.len(), |e| start + e);
+            format!("{}{}{}", &tag[..start], href, &tag[end..])
+        }
+        None => format!("<a href=\"{}\"{}", href, &tag[2..]),
+    }
+}
+
+/// The opening tag starting at `at`, as a range.
+fn open_tag(html: &str, at: usize) -> std::ops::Range<usize> {
+    at..html[at..].find('>').map_or(html.len(), |e| at + e + 1)
+}
+
+/// A navbar's markup with the site's links: the brand `<a>` points at the
+/// home page, and the first `menu-horizontal` list gets the page links in
+/// place of its items, or a new list is added at the end. A navbar hidden
+/// on large screens (the docs drawer bar) is shown at every width, with its
+/// drawer button hidden there instead.
+fn rewrite(navbar: &str, tag: &str, preset: &SitePreset, current: &str) -> String {
+    let home = preset.pages[0].file;
+    let items = menu_items(preset, current);
+    let open = open_tag(navbar, 0);
+    let mut head = navbar[open.clone()].to_string();
+    let mut body = navbar[open.end..navbar.len() - tag.len() - 3].to_string();
+    if head.contains("lg:hidden") {
+        head = head.replacen(" lg:hidden", "", 1);
+        if let Some(at) = body.find("<label for=") {
+            let label = open_tag(&body, at);
+            let hidden = add_class(&body[label.clone()], "lg:hidden");
+            body.replace_range(label, &hidden);
+        }
+    }
+    if let Some(at) = body.find("<a ") {
+        let brand = open_tag(&body, at);
+        let linked = set_href(&body[brand.clone()], home);
+        body.replace_range(brand, &linked);
+    }
+    match body
+        .find("menu-horizontal")
+        .and_then(|at| body[..at].rfind("<ul"))
+    {
+        Some(at) => {
+            let list = open_tag(&body, at);
+            let close = body[list.end..]
+                .find("</ul>")
+                .map_or(body.len(), |e| list.end + e);
+            body.replace_range(list.end..close, &items);
+        }
+        None => body.push_str(&format!(
+            "<ul class=\"menu menu-horizontal px-1 ml-auto\">{}</ul>",
+            items
+        )),
+    }
+    format!("{}{}</{}>", head, body, tag)
+}
+
+/// `html` with its first navbar linking the pages of `preset`, `current`
+/// being the file it will be saved as. Without a navbar, one holding
+/// `title` (already escaped) and the links goes first inside the root.
+pub fn link_navbar(html: &str, preset: &SitePreset, current: &str, title: &str) -> String {
+    let all = elements(html);
+    if let Some(nav) = all.iter().find(|e| e.has_class("navbar")) {
+        let linked = rewrite(&html[nav.start..nav.end], &nav.tag, preset, current);
+        return format!("{}{}{}", &html[..nav.start], linked, &html[nav.end..]);
+    }
+    let navbar = format!(
+        "<nav class=\"navbar bg-base-100 border-b border-base-200\" aria-label=\"Main\"><a href=\"{}\" class=\"btn btn-ghost text-xl\">{}</a><ul class=\"menu menu-horizontal px-1 ml-auto\">{}</ul></nav>",
+        preset.pages[0].file,
+        title,
+        menu_items(preset, current)
+    );
+    match all.iter().find(|e| e.parent.is_none()) {
+        Some(root) => {
+            let at = open_tag(html, root.start).end;
+            format!("{}\n{}{}", &html[..at], navbar, &html[at..])
+        }
+        None => format!("{}\n{}", navbar, html),
+    }
+}
+
+/// Every page of `preset` as `(page, html)`, generated by `generate` and
+/// linked to the others.
+pub fn build(
+    preset: &SitePreset,
+    title: &str,
+    mut generate: impl FnMut(&Page) -> String,
+) -> Vec<(Page, String)> {
+    preset
+        .pages
+        .iter()
+        .map(|page| {
+            let html = generate(page);
+            (*page, link_navbar(&html, preset, page.file, title))
+        })
+        .collect()
+}
@@ -0,0 +1,4118 @@
+//! Snippet builders shared by the Zed extension and the MCP server.
+//!
+//! The server includes this file via `#[path]`, so it must stay free of
+//! dependencies beyond `std`.
+
+use crate::sample::{self, Sample};
+use crate::sections::Page;
+use crate::variant::Variant;
+
+// ============================================================================
+// Keyboard shortcuts
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Platform {
+    Mac,
+    Pc,
+}
+
+impl Platform {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "mac" | "macos" | "osx" | "apple" => Some(Self::Mac),
+            "pc" | "win" | "windows" | "linux" | "other" => Some(Self::Pc),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Modifier {
+    Ctrl,
+    Alt,
+    Shift,
+    Meta,
+}
+
+impl Modifier {
+    fn parse(token: &str, platform: Platform) -> Option<Self> {
+        match token {
+            "ctrl" | "control" | "ctl" | "^" | "⌃" => Some(Self::Ctrl),
+            "alt" | "option" | "opt" | "⌥" => Some(Self::Alt),
+            "shift" | "⇧" => Some(Self::Shift),
+            "meta" | "super" | "win" => Some(Self::Meta),
+            // `mod` is the platform's primary modifier, as in most editors.
+            // `cmd` is written with Mac users in mind, so PCs get Ctrl too.
+            "mod" | "cmdorctrl" | "cmd" | "command" | "⌘" => Some(match platform {
+                Platform::Mac => Self::Meta,
+                Platform::Pc => Self::Ctrl,
+            }),
+            _ => None,
+        }
+    }
+
+    fn label(self, platform: Platform) -> &'static str {
+        match (self, platform) {
+            (Self::Ctrl, Platform::Mac) => "⌃",
+            (Self::Alt, Platform::Mac) => "⌥",
+            (Self::Shift, Platform::Mac) => "⇧",
+            (Self::Meta, Platform::Mac) => "⌘",
+            (Self::Ctrl, Platform::Pc) => "Ctrl",
+            (Self::Alt, Platform::Pc) => "Alt",
+            (Self::Shift, Platform::Pc) => "Shift",
+            (Self::Meta, Platform::Pc) => "Win",
+        }
+    }
+}
+
+const NAMED_KEYS: &[(&[&str], &str, &str)] = &[
+    (&["enter", "return"], "↵", "Enter"),
+    (&["esc", "escape"], "Esc", "Esc"),
+    (&["tab"], "⇥", "Tab"),
+    (&["space", "spacebar"], "Space", "Space"),
+    (&["backspace"], "⌫", "Backspace"),
+    (&["delete", "del"], "⌦", "Del"),
+    (&["up", "arrowup"], "↑", "↑"),
+    (&["down", "arrowdown"], "↓", "↓"),
+    (&["left", "arrowleft"], "←", "←"),
+    (&["right", "arrowright"], "→", "→"),
+    (&["home"], "Home", "Home"),
+    (&["end"], "End", "End"),
+    (&["pageup", "pgup"], "PgUp", "PgUp"),
+    (&["pagedown", "pgdn"], "PgDn", "PgDn"),
+    (&["plus"], "+", "+"),
+    (&["minus"], "-", "-"),
+];
+
+/// A parsed shortcut: modifiers in platform order followed by a single key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shortcut {
+    modifiers: Vec<Modifier>,
+    key: String,
+    platform: Platform,
+}
+
+impl Shortcut {
+    /// Parses strings like `cmd+shift+p`, `Ctrl + K` or `mod+enter`.
+    /// Modifier aliases are normalized, duplicates collapse, and exactly one
+    /// non-modifier key is required.
+    pub fn parse(input: &str, platform: Platform) -> Result<Self, String> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err("Empty shortcut".into());
+        }
+        // A trailing `++` means the plus key itself.
+        let (body, plus_key) = match input.strip_suffix("++") {
+            Some(rest) => (rest, true),
+            None => (input, input == "+"),
+        };
+
+        let mut modifiers = Vec::new();
+        let mut key: Option<String> = None;
+        let tokens = body
+            .split('+')
+            .map(|t| t.trim().to_lowercase())
+            .filter(|t| !t.is_empty());
+        for token in tokens.chain(plus_key.then(|| "plus".to_string())) {
+            if let Some(m) = Modifier::parse(&token, platform) {
+                if !modifiers.contains(&m) {
+                    modifiers.push(m);
+                }
+                continue;
+            }
+            let label = Self::key_label(&token, platform)
+                .ok_or_else(|| format!("Unknown key '{}' in shortcut '{}'", token, input))?;
+            if let Some(existing) = &key {
+                return Err(format!(
+                    "Shortcut '{}' has more than one non-modifier key ('{}' and '{}')",
+                    input, existing, label
+                ));
+            }
+            key = Some(label);
+        }
+
+        let key = key.ok_or_else(|| format!("Shortcut '{}' needs a non-modifier key", input))?;
+        modifiers.sort_by_key(|m| Self::order(*m, platform));
+        Ok(Self {
+            modifiers,
+            key,
+            platform,
+        })
+    }
+
+    /// macOS lists ⌃⌥⇧⌘; Windows/Linux list Ctrl, Alt, Shift, Win.
+    fn order(m: Modifier, platform: Platform) -> u8 {
+        match platform {
+            Platform::Mac => match m {
+                Modifier::Ctrl => 0,
+                Modifier::Alt => 1,
+                Modifier::Shift => 2,
+                Modifier::Meta => 3,
+            },
+            Platform::Pc => match m {
+                Modifier::Meta => 0,
+                Modifier::Ctrl => 1,
+                Modifier::Alt => 2,
+                Modifier::Shift => 3,
+            },
+        }
+    }
+
+    fn key_label(token: &str, platform: Platform) -> Option<String> {
+        if let Some((_, mac, pc)) = NAMED_KEYS
+            .iter()
+            .find(|(names, _, _)| names.contains(&token))
+        {
+            return Some(match platform {
+                Platform::Mac => mac.to_string(),
+                Platform::Pc => pc.to_string(),
+            });
+        }
+        if let Some(n) = token.strip_prefix('f').and_then(|n| n.parse::<u8>().ok())
+            && (1..=24).contains(&n)
+        {
+            return Some(format!("F{}", n));
+        }
+        let mut chars = token.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if c.is_ascii_alphanumeric() => {
+                Some(c.to_ascii_uppercase().to_string())
+            }
+            (Some(c), None) if "`-=[]\\;',./".contains(c) => Some(c.to_string()),
+            _ => None,
+        }
+    }
+
+    pub fn labels(&self) -> Vec<String> {
+        self.modifiers
+            .iter()
+            .map(|m| m.label(self.platform).to_string())
+            .chain(std::iter::once(self.key.clone()))
+            .collect()
+    }
+
+    /// Renders the shortcut as a row of `<kbd>` elements.
+    pub fn to_html(&self, size: Option<&str>) -> String {
+        let class = match size {
+            Some(size) => format!("kbd kbd-{}", size),
+            None => "kbd".to_string(),
+        };
+        let sep = match self.platform {
+            Platform::Mac => "",
+            Platform::Pc => "+",
+        };
+        self.labels()
+            .iter()
+            .map(|l| format!(r#"<kbd class="{}">{}</kbd>"#, class, escape(l)))
+            .collect::<Vec<_>>()
+            .join(sep)
+    }
+}
+
+pub const KBD_SIZES: &[&str] = &["xs", "sm", "md", "lg", "xl"];
+
+/// Convenience wrapper: parse and render a shortcut in one step.
+pub fn kbd_row(shortcut: &str, platform: Platform, size: Option<&str>) -> Result<String, String> {
+    if let Some(size) = size
+        && !KBD_SIZES.contains(&size)
+    {
+        return Err(format!(
+            "Unknown kbd size '{}'. Valid: {}",
+            size,
+            KBD_SIZES.join(", ")
+        ));
+    }
+    Ok(Shortcut::parse(shortcut, platform)?.to_html(size))
+}
+
+// ============================================================================
+// Command palette
+// ============================================================================
+
+pub struct PaletteCommand {
+    pub group: String,
+    pub label: String,
+    pub shortcut: Option<String>,
+}
+
+impl PaletteCommand {
+    pub fn new(group: &str, label: &str, shortcut: Option<&str>) -> Self {
+        Self {
+            group: group.to_string(),
+            label: label.to_string(),
+            shortcut: shortcut.map(|s| s.to_string()),
+        }
+    }
+}
+
+pub fn default_palette_commands() -> Vec<PaletteCommand> {
+    vec![
+        PaletteCommand::new("Navigation", "Go to Dashboard", Some("mod+1")),
+        PaletteCommand::new("Navigation", "Go to Projects", Some("mod+2")),
+        PaletteCommand::new("Navigation", "Open Settings", Some("mod+,")),
+        PaletteCommand::new("Actions", "New File", Some("mod+n")),
+        PaletteCommand::new("Actions", "Search Files", Some("mod+p")),
+        PaletteCommand::new("Actions", "Toggle Theme", Some("mod+shift+l")),
+        PaletteCommand::new("Help", "Keyboard Shortcuts", Some("mod+/")),
+    ]
+}
+
+/// Checks an element id given by the caller. Ids land in attributes and in
+/// script string literals, so only `[A-Za-z0-9_-]` is accepted.
+pub fn check_id(id: &str) -> Result<(), String> {
+    if !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid id '{}'. Use letters, digits, '-' and '_'",
+            id
+        ))
+    }
+}
+
+/// A ⌘K-style command palette: a `dialog` modal with a search input, grouped
+/// commands with shortcut hints, and the script that opens it on keydown.
+pub fn command_palette(
+    id: &str,
+    commands: &[PaletteCommand],
+    platform: Platform,
+) -> Result<String, String> {
+    check_id(id)?;
+    let mut groups: Vec<(&str, Vec<&PaletteCommand>)> = Vec::new();
+    for cmd in commands {
+        match groups.iter_mut().find(|(g, _)| *g == cmd.group) {
+            Some((_, items)) => items.push(cmd),
+            None => groups.push((&cmd.group, vec![cmd])),
+        }
+    }
+
+    let mut list = String::new();
+    for (group, items) in &groups {
+        list.push_str(&format!(
+            "        <li class=\"menu-title\">{}</li>\n",
+            escape(group)
+        ));
+        for item in items {
+            let hint = match &item.shortcut {
+                Some(s) => Shortcut::parse(s, platform)?.to_html(Some("sm")),
+                None => String::new(),
+            };
+            list.push_str(&format!(
+                "        <li data-command><a class=\"flex justify-between\"><span>{}</span><span class=\"flex gap-1\">{}</span></a></li>\n",
+                escape(&item.label),
+                hint
+            ));
+        }
+    }
+
+    let open_hint = Shortcut::parse("mod+k", platform)?.to_html(Some("sm"));
+    Ok(format!(
+        r#"<button class="btn btn-ghost btn-sm gap-2" onclick="document.getElementById('{id}').showModal()">Search {open_hint}</button>
+<dialog id="{id}" class="modal modal-top sm:modal-middle">
+  <div class="modal-box p-0 max-w-xl">
+    <label class="input input-lg w-full border-0 border-b rounded-none focus-within:outline-none">
+      <svg class="h-5 w-5 opacity-50" fill="none" viewBox="0 0 24 24" stroke="currentColor"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M21 21l-6-6m2-5a7 7 0 11-14 0 7 7 0 0114 0z" /></svg>
+      <input type="search" class="grow" placeholder="Type a command or search..." aria-label="Search commands" data-palette-input />
+      <kbd class="kbd kbd-sm">Esc</kbd>
+    </label>
+    <ul class="menu w-full max-h-80 overflow-y-auto flex-nowrap" data-palette-list>
+{list}    </ul>
+    <div class="flex gap-4 px-4 py-2 border-t text-xs opacity-60">
+      <span><kbd class="kbd kbd-xs">↑</kbd><kbd class="kbd kbd-xs">↓</kbd> navigate</span>
+      <span><kbd class="kbd kbd-xs">↵</kbd> select</span>
+    </div>
+  </div>
+  <form method="dialog" class="modal-backdrop"><button>close</button></form>
+</dialog>
+<script>
+{script}
+</script>"#,
+        id = id,
+        open_hint = open_hint,
+        list = list,
+        script = palette_script(id)
+    ))
+}
+
+/// Opens the palette on ⌘K / Ctrl+K and filters commands as the user types.
+pub fn palette_script(id: &str) -> String {
+    format!(
+        r#"document.addEventListener('keydown', (e) => {{
+  if ((e.metaKey || e.ctrlKey) && e.key.toLowerCase() === 'k') {{
+    e.preventDefault();
+    const palette = document.getElementById('{id}');
+    palette.open ? palette.close() : palette.showModal();
+    palette.querySelector('[data-palette-input]').focus();
+  }}
+}});
+document.getElementById('{id}').querySelector('[data-palette-input]').addEventListener('input', (e) => {{
+  const q = e.target.value.toLowerCase();
+  document.getElementById('{id}').querySelectorAll('[data-command]').forEach((li) => {{
+    li.hidden = !li.textContent.toLowerCase().includes(q);
+  }});
+}});"#,
+        id = id
+    )
+}
+
+// ============================================================================
+// Marketing blocks
+// ============================================================================
+
+pub const BLOCKS: &[&str] = &["testimonials", "logo-cloud", "pricing-tiers"];
+
+pub struct Testimonial {
+    pub quote: String,
+    pub name: String,
+    pub role: String,
+    pub avatar: Option<String>,
+}
+
+pub struct Logo {
+    pub name: String,
+    pub image: Option<String>,
+}
+
+pub struct PricingTier {
+    pub name: String,
+    pub price: String,
+    pub period: String,
+    pub features: Vec<String>,
+    pub highlighted: bool,
+}
+
+pub fn default_testimonials() -> Vec<Testimonial> {
+    vec![
+        Testimonial {
+            quote: "We shipped our MVP in a week. The components just fit together.".into(),
+            name: "Sara Ahmed".into(),
+            role: "CTO, Lumen".into(),
+            avatar: None,
+        },
+        Testimonial {
+            quote: "Our designers and engineers finally speak the same language.".into(),
+            name: "Marco Rossi".into(),
+            role: "Head of Product, Northwind".into(),
+            avatar: None,
+        },
+        Testimonial {
+            quote: "Theming took minutes instead of weeks.".into(),
+            name: "Lena Park".into(),
+            role: "Founder, Plotline".into(),
+            avatar: None,
+        },
+    ]
+}
+
+pub fn default_logos() -> Vec<Logo> {
+    ["Acme", "Globex", "Initech", "Umbrella", "Hooli", "Stark"]
+        .iter()
+        .map(|n| Logo {
+            name: n.to_string(),
+            image: None,
+        })
+        .collect()
+}
+
+pub fn default_pricing_tiers() -> Vec<PricingTier> {
+    vec![
+        PricingTier {
+            name: "Starter".into(),
+            price: "$0".into(),
+            period: "month".into(),
+            features: vec!["1 project".into(), "Community support".into()],
+            highlighted: false,
+        },
+        PricingTier {
+            name: "Pro".into(),
+            price: "$19".into(),
+            period: "month".into(),
+            features: vec![
+                "Unlimited projects".into(),
+                "Priority support".into(),
+                "Custom themes".into(),
+            ],
+            highlighted: true,
+        },
+        PricingTier {
+            name: "Enterprise".into(),
+            price: "$99".into(),
+            period: "month".into(),
+            features: vec!["SSO & audit logs".into(), "Dedicated manager".into()],
+            highlighted: false,
+        },
+    ]
+}
+
+fn initials(name: &str) -> String {
+    name.split(|c: char| c.is_whitespace() || c == '_' || c == '.')
+        .filter_map(|w| w.chars().next())
+        .take(2)
+        .collect::<String>()
+        .to_uppercase()
+}
+
+/// `heading` is the id of the section's heading, which labels it.
+pub fn testimonials(items: &[Testimonial], heading: &str) -> String {
+    let cards = items
+        .iter()
+        .map(|t| {
+            let avatar = avatar(
+                t.avatar.as_deref().unwrap_or(&t.name),
+                AvatarSize::Sm,
+                AvatarShape::Circle,
+                None,
+            );
+            format!(
+                r#"        <div class="card bg-base-100 shadow-sm">
+          <div class="card-body">
+            <p class="italic">&ldquo;{}&rdquo;</p>
+            <div class="flex items-center gap-3 mt-4">{}<div><div class="font-semibold">{}</div><div class="text-sm opacity-60">{}</div></div></div>
+          </div>
+        </div>
+"#,
+                escape(&t.quote),
+                avatar,
+                escape(&t.name),
+                escape(&t.role)
+            )
+        })
+        .collect::<String>();
+    format!(
+        r#"  <section class="py-24 bg-base-200" aria-labelledby="{heading}">
+    <div class="container mx-auto px-4">
+      <h2 id="{heading}" class="text-3xl font-bold text-center mb-12">Loved by teams</h2>
+      <div class="grid grid-cols-1 md:grid-cols-3 gap-8">
+{cards}      </div>
+    </div>
+  </section>"#
+    )
+}
+
+/// `heading` is the id of the "Trusted by" heading, which labels the section.
+pub fn logo_cloud(items: &[Logo], heading: &str) -> String {
+    let logos = items
+        .iter()
+        .map(|l| match &l.image {
+            Some(src) => format!(
+                r#"        <img src="{}" alt="{}" class="h-8 opacity-60 grayscale" />
+"#,
+                escape(src),
+                escape(&l.name)
+            ),
+            None => format!(
+                r#"        <span class="badge badge-ghost badge-lg">{}</span>
+"#,
+                escape(&l.name)
+            ),
+        })
+        .collect::<String>();
+    format!(
+        r#"  <section class="py-12 bg-base-100" aria-labelledby="{heading}">
+    <div class="container mx-auto px-4 text-center">
+      <h2 id="{heading}" class="text-sm uppercase opacity-60 mb-6">Trusted by</h2>
+      <div class="flex flex-wrap justify-center items-center gap-8">
+{logos}      </div>
+    </div>
+  </section>"#
+    )
+}
+
+/// `heading` is the id of the section's heading, which labels it.
+pub fn pricing_tiers(items: &[PricingTier], heading: &str) -> String {
+    format!(
+        r#"  <section class="py-24 bg-base-100" aria-labelledby="{heading}">
+    <div class="container mx-auto px-4">
+      <h2 id="{heading}" class="text-3xl font-bold text-center mb-12">Simple pricing</h2>
+      <div class="grid grid-cols-1 md:grid-cols-3 gap-8 items-start">
+{}      </div>
+    </div>
+  </section>"#,
+        pricing_cards(items)
+    )
+}
+
+/// One card per tier; a highlighted tier gets `border-primary` and a
+/// "Most popular" badge.
+fn pricing_cards(items: &[PricingTier]) -> String {
+    items
+        .iter()
+        .map(|tier| {
+            let features = tier
+                .features
+                .iter()
+                .map(|f| {
+                    format!(
+                        r#"<li class="flex items-center gap-2">{}<span>{}</span></li>"#,
+                        icon("check", "h-4 w-4 text-success"),
+                        escape(f)
+                    )
+                })
+                .collect::<String>();
+            let (card_class, badge, button) = if tier.highlighted {
+                (
+                    "card bg-base-100 border-2 border-primary shadow-xl",
+                    r#"<div class="badge badge-primary">Most popular</div>"#,
+                    "btn btn-primary",
+                )
+            } else {
+                ("card bg-base-100 border border-base-300", "", "btn btn-outline")
+            };
+            format!(
+                r#"        <div class="{}">
+          <div class="card-body">
+            <div class="flex items-center justify-between"><h3 class="card-title">{}</h3>{}</div>
+            <p class="my-4"><span class="text-4xl font-bold">{}</span><span class="opacity-60">/{}</span></p>
+            <ul class="space-y-2 mb-6">{}</ul>
+            <button class="{}">Choose {}</button>
+          </div>
+        </div>
+"#,
+                card_class,
+                escape(&tier.name),
+                badge,
+                escape(&tier.price),
+                escape(&tier.period),
+                features,
+                button,
+                escape(&tier.name)
+            )
+        })
+        .collect()
+}
+
+/// Features compared on the pricing page, with which default tier
+/// (Starter, Pro, Enterprise) includes each.
+const PRICING_FEATURES: &[(&str, [bool; 3])] = &[
+    ("Projects", [true, true, true]),
+    ("Custom themes", [false, true, true]),
+    ("Priority support", [false, true, true]),
+    ("SSO & audit logs", [false, false, true]),
+    ("Dedicated manager", [false, false, true]),
+];
+
+const PRICING_FAQ: &[(&str, &str)] = &[
+    (
+        "Can I change plans later?",
+        "Yes. Upgrades apply right away and downgrades at the end of the billing period.",
+    ),
+    (
+        "Is there a free trial?",
+        "Pro comes with 14 days free, no card needed.",
+    ),
+    (
+        "Do you offer refunds?",
+        "Yearly plans can be refunded in full within 30 days.",
+    ),
+];
+
+/// A pricing page: hero, a monthly/yearly toggle over the default tiers, a
+/// feature comparison table and an FAQ. `billing` and `faq` name the radio
+/// groups behind the toggle and the accordion.
+pub fn pricing_page(title: &str, billing: &str, faq: &str, v: &Variant) -> Page {
+    let [
+        pt10,
+        gap8,
+        py16,
+        text_5xl,
+        py6,
+        text_lg,
+        px4,
+        pb16,
+        text_3xl,
+        mb8,
+        pb24,
+        space_y2,
+    ] = [
+        v.space("pt", 10),
+        v.space("gap", 8),
+        v.space("py", 16),
+        v.text("5xl"),
+        v.space("py", 6),
+        v.text("lg"),
+        v.space("px", 4),
+        v.space("pb", 16),
+        v.text("3xl"),
+        v.space("mb", 8),
+        v.space("pb", 24),
+        v.space("space-y", 2),
+    ];
+    let monthly = default_pricing_tiers();
+    let yearly: Vec<PricingTier> = monthly
+        .iter()
+        .zip(["$0", "$190", "$990"])
+        .map(|(tier, price)| PricingTier {
+            name: tier.name.clone(),
+            price: price.into(),
+            period: "year".into(),
+            features: tier.features.clone(),
+            highlighted: tier.highlighted,
+        })
+        .collect();
+    let tab = |label: &str, checked: &str, tiers: &[PricingTier]| {
+        format!(
+            r#"      <input type="radio" name="{}" class="tab" aria-label="{}"{} />
+      <div class="tab-content {pt10}">
+        <div class="grid grid-cols-1 md:grid-cols-3 {gap8} items-start">
+{}        </div>
+      </div>
+"#,
+            billing,
+            label,
+            checked,
+            pricing_cards(tiers)
+        )
+    };
+    let mark = |included: bool| {
+        if included {
+            format!(
+                r#"{}<span class="sr-only">Included</span>"#,
+                icon("check", "h-5 w-5 text-success mx-auto")
+            )
+        } else {
+            format!(
+                r#"{}<span class="sr-only">Not included</span>"#,
+                icon("x", "h-5 w-5 text-base-content/40 mx-auto")
+            )
+        }
+    };
+    let rows: String = PRICING_FEATURES
+        .iter()
+        .map(|(feature, included)| {
+            format!(
+                "          <tr><th>{}</th>{}</tr>\n",
+                escape(feature),
+                included
+                    .iter()
+                    .map(|&i| format!(r#"<td class="text-center">{}</td>"#, mark(i)))
+                    .collect::<String>()
+            )
+        })
+        .collect();
+    let questions: String = PRICING_FAQ
+        .iter()
+        .enumerate()
+        .map(|(i, (question, answer))| {
+            format!(
+                r#"      <div class="collapse collapse-arrow bg-base-200">
+        <input type="radio" name="{}" aria-label="{}"{} />
+        <div class="collapse-title font-semibold">{}</div>
+        <div class="collapse-content"><p>{}</p></div>
+      </div>
+"#,
+                faq,
+                question,
+                if i == 0 { " checked" } else { "" },
+                question,
+                answer
+            )
+        })
+        .collect();
+    let nav = brand_nav(title, v);
+    let monthly = tab("Monthly", " checked", &monthly);
+    let yearly = tab("Yearly", "", &yearly);
+    Page::default()
+        .markup(format!(
+            r#"<div class="min-h-screen bg-base-100">
+{nav}"#
+        ))
+        .section(
+            "hero",
+            format!(
+                r#"
+  <header class="hero bg-base-200 {py16}">
+    <div class="hero-content text-center">
+      <div class="max-w-2xl">
+        <h1 class="{text_5xl} font-bold">{title}</h1>
+        <p class="{py6} {text_lg} text-base-content/80">Simple plans that grow with you. Cancel anytime.</p>
+      </div>
+    </div>
+  </header>"#
+            ),
+        )
+        .markup("\n  <main>")
+        .section(
+            "plans",
+            format!(
+                r#"
+  <section class="container mx-auto {px4} {py16}" aria-labelledby="{billing}-plans">
+    <h2 id="{billing}-plans" class="sr-only">Plans</h2>
+    <div role="tablist" class="tabs tabs-box justify-center">
+{monthly}{yearly}    </div>
+  </section>"#
+            ),
+        )
+        .section(
+            "comparison",
+            format!(
+                r#"
+  <section class="container mx-auto {px4} {pb16}" aria-labelledby="{billing}-compare">
+    <h2 id="{billing}-compare" class="{text_3xl} font-bold text-center {mb8}">Compare plans</h2>
+    <div class="overflow-x-auto">
+      <table class="table">
+        <thead><tr><th>Feature</th><th class="text-center">Starter</th><th class="text-center">Pro</th><th class="text-center">Enterprise</th></tr></thead>
+        <tbody>
+{rows}        </tbody>
+      </table>
+    </div>
+  </section>"#
+            ),
+        )
+        .section(
+            "faq",
+            format!(
+                r#"
+  <section class="container mx-auto {px4} {pb24} max-w-3xl {space_y2}" aria-labelledby="{faq}-heading">
+    <h2 id="{faq}-heading" class="{text_3xl} font-bold text-center {mb8}">Frequently asked questions</h2>
+{questions}  </section>"#
+            ),
+        )
+        .markup("\n  </main>\n</div>")
+}
+
+// ============================================================================
+// Avatars
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvatarSize {
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+impl AvatarSize {
+    pub const NAMES: &[&str] = &["xs", "sm", "md", "lg", "xl"];
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "xs" => Some(Self::Xs),
+            "sm" => Some(Self::Sm),
+            "md" => Some(Self::Md),
+            "lg" => Some(Self::Lg),
+            "xl" => Some(Self::Xl),
+            _ => None,
+        }
+    }
+
+    fn width(self) -> &'static str {
+        match self {
+            Self::Xs => "w-6",
+            Self::Sm => "w-8",
+            Self::Md => "w-12",
+            Self::Lg => "w-16",
+            Self::Xl => "w-24",
+        }
+    }
+
+    fn text(self) -> &'static str {
+        match self {
+            Self::Xs => "text-xs",
+            Self::Sm => "text-sm",
+            Self::Md => "text-base",
+            Self::Lg => "text-xl",
+            Self::Xl => "text-3xl",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvatarShape {
+    Circle,
+    Rounded,
+    Squircle,
+    Hexagon,
+}
+
+impl AvatarShape {
+    pub const NAMES: &[&str] = &["circle", "rounded", "squircle", "hexagon"];
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "circle" | "round" => Some(Self::Circle),
+            "rounded" | "square" => Some(Self::Rounded),
+            "squircle" => Some(Self::Squircle),
+            "hexagon" => Some(Self::Hexagon),
+            _ => None,
+        }
+    }
+
+    fn class(self) -> &'static str {
+        match self {
+            Self::Circle => "rounded-full",
+            Self::Rounded => "rounded-box",
+            Self::Squircle => "mask mask-squircle",
+            Self::Hexagon => "mask mask-hexagon",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Presence {
+    Online,
+    Offline,
+}
+
+impl Presence {
+    pub const NAMES: &[&str] = &["online", "offline"];
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "online" => Some(Self::Online),
+            "offline" => Some(Self::Offline),
+            _ => None,
+        }
+    }
+}
+
+/// Anything that looks like a URL or path is an image; everything else is a
+/// name rendered as placeholder initials.
+fn is_image_source(source: &str) -> bool {
+    let lower = source.to_lowercase();
+    ["http://", "https://", "data:", "/", "./", "../"]
+        .iter()
+        .any(|p| lower.starts_with(p))
+        || [".png", ".jpg", ".jpeg", ".webp", ".gif", ".svg", ".avif"]
+            .iter()
+            .any(|ext| lower.ends_with(ext))
+}
+
+/// Renders a daisyUI avatar. daisyUI requires the sized, shaped wrapper
+/// `div` inside `.avatar`; placeholders also need `avatar-placeholder`.
+pub fn avatar(
+    source: &str,
+    size: AvatarSize,
+    shape: AvatarShape,
+    presence: Option<Presence>,
+) -> String {
+    let mut class = String::from("avatar");
+    match presence {
+        Some(Presence::Online) => class.push_str(" avatar-online"),
+        Some(Presence::Offline) => class.push_str(" avatar-offline"),
+        None => {}
+    }
+    if is_image_source(source) {
+        format!(
+            r#"<div class="{}"><div class="{} {}"><img src="{}" alt="" /></div></div>"#,
+            class,
+            size.width(),
+            shape.class(),
+            escape(source)
+        )
+    } else {
+        format!(
+            r#"<div class="{} avatar-placeholder"><div class="bg-neutral text-neutral-content {} {}"><span class="{}">{}</span></div></div>"#,
+            class,
+            size.width(),
+            shape.class(),
+            size.text(),
+            escape(&initials(source))
+        )
+    }
+}
+
+/// Renders overlapping avatars, collapsing everything past `max` into a
+/// `+N` placeholder.
+pub fn avatar_group(sources: &[&str], size: AvatarSize, max: usize) -> String {
+    let max = max.max(1);
+    let mut items: Vec<String> = sources
+        .iter()
+        .take(max)
+        .map(|s| avatar(s, size, AvatarShape::Circle, None))
+        .collect();
+    if sources.len() > max {
+        items.push(format!(
+            r#"<div class="avatar avatar-placeholder"><div class="bg-neutral text-neutral-content {} rounded-full"><span class="{}">+{}</span></div></div>"#,
+            size.width(),
+            size.text(),
+            sources.len() - max
+        ));
+    }
+    let overlap = match size {
+        AvatarSize::Xs | AvatarSize::Sm => "-space-x-3",
+        AvatarSize::Md => "-space-x-4",
+        AvatarSize::Lg | AvatarSize::Xl => "-space-x-6",
+    };
+    format!(
+        r#"<div class="avatar-group {}">{}</div>"#,
+        overlap,
+        items.join("")
+    )
+}
+
+/// Avatar with a name and secondary line, plus optional small buttons. The
+/// first action is outlined, the rest are ghost buttons.
+pub fn user_card(name: &str, role: &str, avatar_html: &str, actions: &[&str]) -> String {
+    let actions = actions
+        .iter()
+        .enumerate()
+        .map(|(i, a)| {
+            let style = if i == 0 { "btn-outline" } else { "btn-ghost" };
+            format!(
+                r#"<button class="btn btn-sm {}">{}</button>"#,
+                style,
+                escape(a)
+            )
+        })
+        .collect::<String>();
+    let actions = if actions.is_empty() {
+        actions
+    } else {
+        format!(r#"<div class="flex gap-2 ml-auto">{}</div>"#, actions)
+    };
+    format!(
+        r#"<div class="flex items-center gap-4">{}<div><div class="font-bold">{}</div><div class="text-sm opacity-60">{}</div></div>{}</div>"#,
+        avatar_html,
+        escape(name),
+        escape(role),
+        actions
+    )
+}
+
+/// Renames the avatar modifiers daisyUI 5 prefixed with `avatar-` back to
+/// their daisyUI 4 spelling, for projects still on v4.
+#[allow(dead_code)] // the extension's `daisyui_version` setting; the MCP server emits v5
+pub fn avatars_to_v4(html: &str) -> String {
+    const RENAMES: &[(&str, &str)] = &[
+        ("avatar-placeholder", "placeholder"),
+        ("avatar-online", "online"),
+        ("avatar-offline", "offline"),
+    ];
+    RENAMES
+        .iter()
+        .fold(html.to_string(), |acc, (v5, v4)| acc.replace(v5, v4))
+}
+
+// ============================================================================
+// Loading states
+// ============================================================================
+
+pub const LOADING_KINDS: &[&str] = &[
+    "spinner", "dots", "ring", "ball", "bars", "infinity", "progress", "radial", "button", "card",
+    "overlay",
+];
+pub const LOADING_SIZES: &[&str] = &["xs", "sm", "md", "lg", "xl"];
+pub const LOADING_COLORS: &[&str] = &[
+    "neutral",
+    "primary",
+    "secondary",
+    "accent",
+    "info",
+    "success",
+    "warning",
+    "error",
+];
+
+/// A bare `loading` indicator; spinner styles are the first six kinds.
+fn loader(style: &str, size: &str, color: Option<&str>) -> String {
+    let color = color.map(|c| format!(" text-{}", c)).unwrap_or_default();
+    format!(
+        r#"<span class="loading loading-{} loading-{}{}"></span>"#,
+        style, size, color
+    )
+}
+
+/// Full-page blocking overlay with a centered loader and a live label.
+pub fn loading_overlay(label: &str, color: Option<&str>) -> String {
+    format!(
+        r#"<div class="fixed inset-0 z-50 grid place-items-center bg-base-100/70 backdrop-blur-sm" role="status" aria-live="polite">
+  <div class="flex flex-col items-center gap-4">
+    {}
+    <span class="text-sm opacity-70">{}</span>
+  </div>
+</div>"#,
+        loader("spinner", "lg", color),
+        escape(label)
+    )
+}
+
+/// Renders a loading pattern. `value` makes progress bars determinate and
+/// sets the radial fill; it is ignored by the other kinds.
+pub fn loading(
+    kind: &str,
+    size: Option<&str>,
+    color: Option<&str>,
+    value: Option<u8>,
+) -> Result<String, String> {
+    if let Some(size) = size
+        && !LOADING_SIZES.contains(&size)
+    {
+        return Err(format!(
+            "Unknown size '{}'. Valid: {}",
+            size,
+            LOADING_SIZES.join(", ")
+        ));
+    }
+    if let Some(color) = color
+        && !LOADING_COLORS.contains(&color)
+    {
+        return Err(format!(
+            "Unknown color '{}'. Valid: {}",
+            color,
+            LOADING_COLORS.join(", ")
+        ));
+    }
+    let value = value.map(|v| v.min(100));
+    let size_or = |default| size.unwrap_or(default);
+    let html = match kind {
+        "spinner" | "dots" | "ring" | "ball" | "bars" | "infinity" => {
+            loader(kind, size_or("md"), color)
+        }
+        "progress" => {
+            let width = match size_or("md") {
+                "xs" => "w-24",
+                "sm" => "w-32",
+                "lg" => "w-72",
+                "xl" => "w-96",
+                _ => "w-56",
+            };
+            let color = color
+                .map(|c| format!(" progress-{}", c))
+                .unwrap_or_default();
+            match value {
+                Some(v) => format!(
+                    r#"<progress class="progress{} {}" value="{}" max="100"></progress>"#,
+                    color, width, v
+                ),
+                None => format!(
+                    r#"<progress class="progress{} {}"></progress>"#,
+                    color, width
+                ),
+            }
+        }
+        "radial" => {
+            let v = value.unwrap_or(70);
+            let rem = match size_or("md") {
+                "xs" => "2.5rem",
+                "sm" => "3.5rem",
+                "lg" => "6rem",
+                "xl" => "8rem",
+                _ => "5rem",
+            };
+            let color = color.map(|c| format!(" text-{}", c)).unwrap_or_default();
+            format!(
+                r#"<div class="radial-progress{}" style="--value:{}; --size:{};" aria-valuenow="{}" role="progressbar">{}%</div>"#,
+                color, v, rem, v, v
+            )
+        }
+        "button" => {
+            let btn_color = color.map(|c| format!(" btn-{}", c)).unwrap_or_default();
+            let btn_size = size
+                .filter(|s| *s != "md")
+                .map(|s| format!(" btn-{}", s))
+                .unwrap_or_default();
+            format!(
+                r#"<button class="btn{}{}" disabled aria-busy="true">{}Saving…</button>"#,
+                btn_color,
+                btn_size,
+                loader("spinner", size_or("sm"), None)
+            )
+        }
+        "card" => format!(
+            r#"<div class="card bg-base-100 shadow-sm w-96">
+  <div class="card-body items-center justify-center min-h-48" role="status" aria-live="polite">
+    {}
+    <span class="text-sm opacity-70">Loading…</span>
+  </div>
+</div>"#,
+            loader("spinner", size_or("lg"), color)
+        ),
+        "overlay" => loading_overlay("Loading…", color),
+        _ => {
+            return Err(format!(
+                "Unknown loading kind '{}'. Valid: {}",
+                kind,
+                LOADING_KINDS.join(", ")
+            ));
+        }
+    };
+    Ok(html)
+}
+
+// ============================================================================
+// Status badges and tags
+// ============================================================================
+
+pub const BADGE_COLORS: &[&str] = &[
+    "neutral",
+    "primary",
+    "secondary",
+    "accent",
+    "info",
+    "success",
+    "warning",
+    "error",
+    "ghost",
+];
+
+/// Built-in semantic state to badge color mapping.
+const STATUS_COLORS: &[(&[&str], &str)] = &[
+    (
+        &[
+            "active",
+            "online",
+            "done",
+            "completed",
+            "success",
+            "paid",
+            "approved",
+            "published",
+            "healthy",
+        ],
+        "success",
+    ),
+    (
+        &[
+            "pending",
+            "queued",
+            "waiting",
+            "todo",
+            "review",
+            "scheduled",
+            "paused",
+        ],
+        "warning",
+    ),
+    (
+        &[
+            "running",
+            "in-progress",
+            "processing",
+            "new",
+            "open",
+            "syncing",
+        ],
+        "info",
+    ),
+    (
+        &[
+            "failed",
+            "error",
+            "rejected",
+            "overdue",
+            "cancelled",
+            "canceled",
+            "blocked",
+            "down",
+        ],
+        "error",
+    ),
+    (
+        &[
+            "archived", "inactive", "disabled", "offline", "closed", "draft",
+        ],
+        "neutral",
+    ),
+];
+
+/// Parses `state=color` overrides, e.g. `pending=info,draft=ghost`.
+pub fn parse_status_overrides(spec: &str) -> Result<Vec<(String, String)>, String> {
+    spec.split(',')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (state, color) = pair
+                .split_once(['=', ':'])
+                .ok_or_else(|| format!("Override '{}' must look like state=color", pair))?;
+            let color = color.trim().to_lowercase();
+            if !BADGE_COLORS.contains(&color.as_str()) {
+                return Err(format!(
+                    "Unknown badge color '{}'. Valid: {}",
+                    color,
+                    BADGE_COLORS.join(", ")
+                ));
+            }
+            Ok((status_key(state), color))
+        })
+        .collect()
+}
+
+fn status_key(state: &str) -> String {
+    state.trim().to_lowercase().replace([' ', '_'], "-")
+}
+
+/// Overrides win; unknown states fall back to `ghost`.
+pub fn status_color(state: &str, overrides: &[(String, String)]) -> String {
+    let key = status_key(state);
+    if let Some((_, color)) = overrides.iter().find(|(s, _)| *s == key) {
+        return color.clone();
+    }
+    STATUS_COLORS
+        .iter()
+        .find(|(states, _)| states.contains(&key.as_str()))
+        .map(|(_, color)| color.to_string())
+        .unwrap_or_else(|| "ghost".into())
+}
+
+/// Badge colored by `state`, showing `label`. With `dot`, a soft badge with a
+/// leading status indicator.
+pub fn status_badge(state: &str, label: &str, overrides: &[(String, String)], dot: bool) -> String {
+    let color = status_color(state, overrides);
+    if dot {
+        // `status` has no ghost variant; the bare class renders a neutral dot.
+        let status = match color.as_str() {
+            "ghost" => "status".to_string(),
+            c => format!("status status-{}", c),
+        };
+        format!(
+            r#"<span class="badge badge-soft badge-{} gap-2"><span class="{}"></span>{}</span>"#,
+            color,
+            status,
+            escape(label)
+        )
+    } else {
+        format!(
+            r#"<span class="badge badge-{}">{}</span>"#,
+            color,
+            escape(label)
+        )
+    }
+}
+
+/// One badge per state, labelled with the state in title case.
+pub fn status_badges(states: &[&str], overrides: &[(String, String)], dot: bool) -> String {
+    let badges = states
+        .iter()
+        .map(|state| status_badge(state, &title_case(state), overrides, dot))
+        .collect::<Vec<_>>()
+        .join("\n  ");
+    format!("<div class=\"flex flex-wrap gap-2\">\n  {}\n</div>", badges)
+}
+
+fn title_case(text: &str) -> String {
+    text.trim()
+        .split(['-', '_', ' '])
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn tag_chip(tag: &str) -> String {
+    format!(
+        r#"<span class="badge badge-neutral gap-1" data-tag="{tag}">{tag}<button type="button" class="cursor-pointer opacity-70 hover:opacity-100" aria-label="Remove {tag}" data-remove-tag>✕</button></span>"#,
+        tag = escape(tag)
+    )
+}
+
+/// A row of removable tag chips.
+pub fn tag_chips(tags: &[&str]) -> String {
+    format!(
+        r#"<div class="flex flex-wrap gap-2">{}</div>"#,
+        tags.iter().map(|t| tag_chip(t)).collect::<String>()
+    )
+}
+
+/// Input with inline chips and a suggestion dropdown; pair it with
+/// [`tag_input_script`].
+pub fn tag_input(id: &str, tags: &[&str], suggestions: &[&str]) -> String {
+    let id = escape(id);
+    let chips = tags.iter().map(|t| tag_chip(t)).collect::<String>();
+    let options = suggestions
+        .iter()
+        .map(|s| format!(r#"<li><a data-suggestion>{}</a></li>"#, escape(s)))
+        .collect::<String>();
+    format!(
+        r#"<div id="{id}" class="dropdown w-full">
+  <label class="input h-auto min-h-12 w-full flex-wrap gap-2 py-2">
+    <span class="contents" data-tags>{chips}</span>
+    <input type="text" class="grow min-w-24" placeholder="Add tag…" data-tag-input tabindex="0" />
+  </label>
+  <ul tabindex="0" class="dropdown-content menu bg-base-100 rounded-box z-10 w-full p-2 shadow-sm" data-suggestions>{options}</ul>
+  <input type="hidden" name="{id}" value="{value}" data-tag-value />
+</div>"#,
+        value = escape(&tags.join(","))
+    )
+}
+
+/// Enter or comma adds a tag, Backspace on an empty field removes the last,
+/// chip buttons remove their tag, and suggestions filter as you type.
+pub fn tag_input_script(id: &str) -> String {
+    format!(
+        r#"(() => {{
+  const root = document.getElementById('{id}');
+  const field = root.querySelector('[data-tag-input]');
+  const tags = root.querySelector('[data-tags]');
+  const value = root.querySelector('[data-tag-value]');
+  const sync = () => {{
+    value.value = [...tags.querySelectorAll('[data-tag]')].map((t) => t.dataset.tag).join(',');
+  }};
+  const add = (text) => {{
+    const tag = text.trim();
+    if (!tag || tags.querySelector(`[data-tag="${{CSS.escape(tag)}}"]`)) return;
+    const chip = document.createElement('span');
+    chip.className = 'badge badge-neutral gap-1';
+    chip.dataset.tag = tag;
+    chip.textContent = tag;
+    const remove = document.createElement('button');
+    remove.type = 'button';
+    remove.className = 'cursor-pointer opacity-70 hover:opacity-100';
+    remove.setAttribute('aria-label', `Remove ${{tag}}`);
+    remove.dataset.removeTag = '';
+    remove.textContent = '✕';
+    chip.append(remove);
+    tags.append(chip);
+    field.value = '';
+    sync();
+  }};
+  field.addEventListener('keydown', (e) => {{
+    if (e.key === 'Enter' || e.key === ',') {{
+      e.preventDefault();
+      add(field.value);
+    }} else if (e.key === 'Backspace' && !field.value) {{
+      tags.lastElementChild?.remove();
+      sync();
+    }}
+  }});
+  field.addEventListener('input', () => {{
+    const q = field.value.toLowerCase();
+    root.querySelectorAll('[data-suggestion]').forEach((s) => {{
+      s.parentElement.hidden = !s.textContent.toLowerCase().includes(q);
+    }});
+  }});
+  root.addEventListener('click', (e) => {{
+    if (e.target.matches('[data-remove-tag]')) {{
+      e.target.parentElement.remove();
+      sync();
+    }} else if (e.target.matches('[data-suggestion]')) {{
+      add(e.target.textContent);
+      field.focus();
+    }}
+  }});
+}})();"#,
+        id = id
+    )
+}
+
+// ============================================================================
+// Checkout
+// ============================================================================
+
+/// Line items on the checkout page: `(name, quantity, unit price in cents)`.
+const CHECKOUT_ITEMS: &[(&str, u32, u32)] = &[
+    ("Wireless headphones", 1, 12900),
+    ("Charging cable", 2, 1900),
+];
+
+const CHECKOUT_SHIPPING: u32 = 500;
+
+fn dollars(cents: u32) -> String {
+    format!("${}.{:02}", cents / 100, cents % 100)
+}
+
+/// A checkout page: steps at Payment, shipping and payment fields, and an
+/// order summary with a promo code. Field ids start with `form`.
+pub fn checkout_page(title: &str, form: &str, v: &Variant) -> Page {
+    let [
+        py2,
+        px4,
+        py10,
+        text_sm,
+        text_4xl,
+        mt2,
+        mb6,
+        mb10,
+        gap8,
+        space_y6,
+        gap4,
+        mt4,
+        my0,
+        text_lg,
+    ] = [
+        v.space("py", 2),
+        v.space("px", 4),
+        v.space("py", 10),
+        v.text("sm"),
+        v.text("4xl"),
+        v.space("mt", 2),
+        v.space("mb", 6),
+        v.space("mb", 10),
+        v.space("gap", 8),
+        v.space("space-y", 6),
+        v.space("gap", 4),
+        v.space("mt", 4),
+        v.space("my", 0),
+        v.text("lg"),
+    ];
+    let field = |name: &str, label: &str, attrs: &str, wide: bool| {
+        format!(
+            r#"            <div class="form-control{}"><label class="label" for="{}-{}"><span class="label-text">{}</span></label><input id="{}-{}" name="{}" {} class="input input-bordered w-full" required /></div>
+"#,
+            if wide { " md:col-span-2" } else { "" },
+            form,
+            name,
+            label,
+            form,
+            name,
+            name,
+            attrs
+        )
+    };
+    let shipping = [
+        field(
+            "name",
+            "Full name",
+            r#"type="text" autocomplete="name""#,
+            false,
+        ),
+        field(
+            "email",
+            "Email",
+            r#"type="email" autocomplete="email""#,
+            false,
+        ),
+        field(
+            "address",
+            "Address",
+            r#"type="text" autocomplete="street-address""#,
+            true,
+        ),
+        field(
+            "city",
+            "City",
+            r#"type="text" autocomplete="address-level2""#,
+            false,
+        ),
+        field(
+            "postal-code",
+            "Postal code",
+            r#"type="text" autocomplete="postal-code""#,
+            false,
+        ),
+    ]
+    .concat();
+    // Card fields are text with a numeric keypad: numbers would drop leading
+    // zeros and show spinners.
+    let payment = [
+        field(
+            "card-number",
+            "Card number",
+            r#"type="text" inputmode="numeric" autocomplete="cc-number" pattern="[0-9 ]{13,19}" maxlength="19" placeholder="1234 5678 9012 3456""#,
+            true,
+        ),
+        field(
+            "expiry",
+            "Expiry",
+            r#"type="text" inputmode="numeric" autocomplete="cc-exp" pattern="(0[1-9]|1[0-2])/[0-9]{2}" maxlength="5" placeholder="MM/YY""#,
+            false,
+        ),
+        field(
+            "cvc",
+            "CVC",
+            r#"type="text" inputmode="numeric" autocomplete="cc-csc" pattern="[0-9]{3,4}" maxlength="4" placeholder="123""#,
+            false,
+        ),
+    ]
+    .concat();
+    let subtotal: u32 = CHECKOUT_ITEMS.iter().map(|(_, n, cents)| n * cents).sum();
+    let total = dollars(subtotal + CHECKOUT_SHIPPING);
+    let items: String = CHECKOUT_ITEMS
+        .iter()
+        .map(|(name, n, cents)| {
+            format!(
+                r#"            <li class="flex justify-between {py2}"><span>{} × {}</span><span>{}</span></li>
+"#,
+                name,
+                n,
+                dollars(n * cents)
+            )
+        })
+        .collect();
+    let nav = brand_nav(title, v);
+    let subtotal = dollars(subtotal);
+    let shipping_cost = dollars(CHECKOUT_SHIPPING);
+    Page::default()
+        .markup(format!(
+            r#"<div class="min-h-screen bg-base-200">
+{nav}
+  <main class="container mx-auto {px4} {py10}">
+    <a href="/store" class="link link-hover {text_sm}">← Continue shopping</a>
+    <h1 class="{text_4xl} font-bold {mt2} {mb6}">{title}</h1>"#
+        ))
+        .section(
+            "steps",
+            format!(
+                r#"
+    <ul class="steps w-full {mb10}">
+      <li class="step step-primary">Cart</li>
+      <li class="step step-primary">Shipping</li>
+      <li class="step step-primary" aria-current="step">Payment</li>
+      <li class="step">Done</li>
+    </ul>"#
+            ),
+        )
+        .markup(format!(
+            r#"
+    <div class="grid grid-cols-1 lg:grid-cols-3 {gap8} items-start">"#
+        ))
+        .section(
+            "form",
+            format!(
+                r#"
+      <form class="lg:col-span-2 {space_y6}">
+        <div class="card bg-base-100 shadow">
+          <div class="card-body">
+            <h2 class="card-title">Shipping</h2>
+            <div class="grid grid-cols-1 md:grid-cols-2 {gap4}">
+{shipping}            </div>
+          </div>
+        </div>
+        <div class="card bg-base-100 shadow">
+          <div class="card-body">
+            <h2 class="card-title">Payment</h2>
+            <div class="grid grid-cols-1 md:grid-cols-2 {gap4}">
+{payment}            </div>
+            <button type="submit" class="btn btn-primary btn-block {mt4}">Pay {total}</button>
+          </div>
+        </div>
+      </form>"#
+            ),
+        )
+        .section(
+            "summary",
+            format!(
+                r#"
+      <aside class="card bg-base-100 shadow" aria-labelledby="{form}-summary">
+        <div class="card-body">
+          <h2 id="{form}-summary" class="card-title">Order summary</h2>
+          <ul class="divide-y divide-base-200">
+{items}          </ul>
+          <div class="join w-full">
+            <input id="{form}-promo" name="promo" type="text" class="input input-bordered join-item w-full" placeholder="Promo code" aria-label="Promo code" />
+            <button type="button" class="btn join-item">Apply</button>
+          </div>
+          <div class="flex justify-between"><span>Subtotal</span><span>{subtotal}</span></div>
+          <div class="flex justify-between"><span>Shipping</span><span>{shipping_cost}</span></div>
+          <div class="divider {my0}"></div>
+          <div class="flex justify-between {text_lg} font-bold"><span>Total</span><span>{total}</span></div>
+        </div>
+      </aside>"#
+            ),
+        )
+        .markup(
+            r#"
+    </div>
+  </main>
+</div>"#,
+        )
+}
+
+// ============================================================================
+// CRUD
+// ============================================================================
+
+/// Rows in the CRUD table: `(name, email, status, updated)`.
+const CRUD_RECORDS: &[(&str, &str, &str, &str)] = &[
+    ("Ada Lovelace", "ada@example.com", "active", "2 hours ago"),
+    ("Grace Hopper", "grace@example.com", "pending", "Yesterday"),
+    ("Alan Turing", "alan@example.com", "active", "3 days ago"),
+    (
+        "Katherine Johnson",
+        "katherine@example.com",
+        "archived",
+        "Last week",
+    ),
+];
+
+/// An admin table for a resource: a search, filter and "New" toolbar, a
+/// selectable table with status badges and row actions, pagination, and
+/// create/edit and delete modals. The modal ids are `{id}-edit` and
+/// `{id}-delete`.
+pub fn crud_page(title: &str, id: &str, v: &Variant) -> Page {
+    let [
+        p2,
+        px4,
+        py10,
+        text_3xl,
+        mb6,
+        gap2,
+        mb4,
+        mt4,
+        text_sm,
+        text_lg,
+        space_y4,
+        py4,
+    ] = [
+        v.space("p", 2),
+        v.space("px", 4),
+        v.space("py", 10),
+        v.text("3xl"),
+        v.space("mb", 6),
+        v.space("gap", 2),
+        v.space("mb", 4),
+        v.space("mt", 4),
+        v.text("sm"),
+        v.text("lg"),
+        v.space("space-y", 4),
+        v.space("py", 4),
+    ];
+    let rows: String = CRUD_RECORDS
+        .iter()
+        .map(|(name, email, status, updated)| {
+            format!(
+                r#"          <tr>
+            <th><label><input type="checkbox" class="checkbox checkbox-sm" aria-label="Select {name}" /></label></th>
+            <td class="font-medium">{name}</td>
+            <td>{email}</td>
+            <td>{badge}</td>
+            <td class="text-base-content/70">{updated}</td>
+            <td class="text-right">
+              <div class="dropdown dropdown-end">
+                <div tabindex="0" role="button" class="btn btn-ghost btn-sm btn-square" aria-label="Actions for {name}">⋯</div>
+                <ul tabindex="0" class="dropdown-content menu bg-base-100 rounded-box z-10 w-32 {p2} shadow-sm">
+                  <li><button type="button" onclick="document.getElementById('{id}-edit').showModal()">Edit</button></li>
+                  <li><button type="button" class="text-error" onclick="document.getElementById('{id}-delete').showModal()">Delete</button></li>
+                </ul>
+              </div>
+            </td>
+          </tr>
+"#,
+                badge = status_badge(status, &title_case(status), &[], false),
+            )
+        })
+        .collect();
+    let nav = brand_nav(title, v);
+    let count = CRUD_RECORDS.len();
+    Page::default()
+        .markup(format!(
+            r#"<div class="min-h-screen bg-base-200">
+{nav}
+  <main class="container mx-auto {px4} {py10}">
+    <h1 class="{text_3xl} font-bold {mb6}">{title}</h1>"#
+        ))
+        .section(
+            "toolbar",
+            format!(
+                r#"
+    <div class="flex flex-wrap items-center {gap2} {mb4}">
+      <label class="input input-bordered flex items-center {gap2} grow max-w-sm">
+        <svg class="h-4 w-4 opacity-50" fill="none" viewBox="0 0 24 24" stroke="currentColor"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M21 21l-6-6m2-5a7 7 0 11-14 0 7 7 0 0114 0z" /></svg>
+        <input id="{id}-search" type="search" class="grow" placeholder="Search records" aria-label="Search records" />
+      </label>
+      <div class="dropdown">
+        <div tabindex="0" role="button" class="btn">Filter</div>
+        <ul tabindex="0" class="dropdown-content menu bg-base-100 rounded-box z-10 w-40 {p2} shadow-sm">
+          <li><a>All</a></li>
+          <li><a>Active</a></li>
+          <li><a>Pending</a></li>
+          <li><a>Archived</a></li>
+        </ul>
+      </div>
+      <button type="button" class="btn btn-primary ml-auto" onclick="document.getElementById('{id}-edit').showModal()">New</button>
+    </div>"#
+            ),
+        )
+        .section(
+            "table",
+            format!(
+                r#"
+    <div class="overflow-x-auto bg-base-100 rounded-box shadow">
+      <table class="table table-zebra">
+        <thead>
+          <tr>
+            <th><label><input type="checkbox" class="checkbox checkbox-sm" aria-label="Select all" /></label></th>
+            <th>Name</th>
+            <th>Email</th>
+            <th>Status</th>
+            <th>Updated</th>
+            <th><span class="sr-only">Actions</span></th>
+          </tr>
+        </thead>
+        <tbody>
+{rows}        </tbody>
+      </table>
+    </div>"#
+            ),
+        )
+        .section(
+            "pagination",
+            format!(
+                r#"
+    <nav class="flex justify-between items-center {mt4}" aria-label="Pagination">
+      <span class="{text_sm} text-base-content/70">Showing 1–{count} of 42</span>
+      <div class="join">
+        <button class="join-item btn btn-sm" aria-label="Previous page">«</button>
+        <button class="join-item btn btn-sm btn-active" aria-current="page">1</button>
+        <button class="join-item btn btn-sm">2</button>
+        <button class="join-item btn btn-sm">3</button>
+        <button class="join-item btn btn-sm" aria-label="Next page">»</button>
+      </div>
+    </nav>"#
+            ),
+        )
+        .markup("\n  </main>\n</div>")
+        .beside(
+            "modals",
+            format!(
+                r#"
+<dialog id="{id}-edit" class="modal">
+  <div class="modal-box">
+    <h3 class="{text_lg} font-bold">Edit record</h3>
+    <form method="dialog" class="{space_y4} {mt4}">
+      <div class="form-control"><label class="label" for="{id}-name"><span class="label-text">Name</span></label><input id="{id}-name" name="name" type="text" class="input input-bordered w-full" required /></div>
+      <div class="form-control"><label class="label" for="{id}-email"><span class="label-text">Email</span></label><input id="{id}-email" name="email" type="email" class="input input-bordered w-full" required /></div>
+      <div class="form-control"><label class="label" for="{id}-status"><span class="label-text">Status</span></label><select id="{id}-status" name="status" class="select select-bordered w-full"><option>Active</option><option>Pending</option><option>Archived</option></select></div>
+      <div class="modal-action">
+        <button type="submit" value="cancel" class="btn btn-ghost" formnovalidate>Cancel</button>
+        <button type="submit" value="save" class="btn btn-primary">Save</button>
+      </div>
+    </form>
+  </div>
+  <form method="dialog" class="modal-backdrop"><button>close</button></form>
+</dialog>"#
+            ),
+        )
+        .beside(
+            "modals",
+            format!(
+                r#"
+<dialog id="{id}-delete" class="modal">
+  <div class="modal-box">
+    <h3 class="{text_lg} font-bold">Delete record?</h3>
+    <p class="{py4}">This can't be undone.</p>
+    <form method="dialog" class="modal-action">
+      <button value="cancel" class="btn btn-ghost">Cancel</button>
+      <button value="delete" class="btn btn-error">Delete</button>
+    </form>
+  </div>
+  <form method="dialog" class="modal-backdrop"><button>close</button></form>
+</dialog>"#
+            ),
+        )
+}
+
+// ============================================================================
+// App landing
+// ============================================================================
+
+/// Screens shown in the screenshot carousel.
+const APP_SCREENS: &[&str] = &["Home", "Search", "Stats", "Profile", "Settings"];
+
+/// Store reviews: `(name, review, stars out of 5)`.
+const APP_REVIEWS: &[(&str, &str, usize)] = &[
+    (
+        "Maya",
+        "Replaced three apps on my phone. The widgets are great.",
+        5,
+    ),
+    ("Jonas", "Fast, clean and the sync just works.", 5),
+    ("Priya", "Love it. Would like a tablet layout next.", 4),
+];
+
+fn stars(count: usize) -> String {
+    let stars: String = (1..=5)
+        .map(|n| {
+            format!(
+                r#"<div class="mask mask-star-2 bg-warning"{}></div>"#,
+                if n == count {
+                    r#" aria-current="true""#
+                } else {
+                    ""
+                }
+            )
+        })
+        .collect();
+    format!(
+        r#"<div class="rating rating-sm" aria-label="{} out of 5 stars">{}</div>"#,
+        count, stars
+    )
+}
+
+/// A mobile app landing page: a hero with a `mockup-phone`, store badge
+/// buttons, a scrolling screenshot carousel, reviews with star ratings, and a
+/// download bar pinned to the bottom on small screens. Section headings get
+/// ids under `id`.
+pub fn app_landing(title: &str, id: &str, v: &Variant) -> Page {
+    let [
+        text_sm,
+        pb20,
+        md_pb0,
+        gap12,
+        text_5xl,
+        py6,
+        text_xl,
+        py16,
+        text_3xl,
+        mb8,
+        gap4,
+        px4,
+        gap6,
+        p4,
+    ] = [
+        v.text("sm"),
+        v.space("pb", 20),
+        v.space("md:pb", 0),
+        v.space("gap", 12),
+        v.text("5xl"),
+        v.space("py", 6),
+        v.text("xl"),
+        v.space("py", 16),
+        v.text("3xl"),
+        v.space("mb", 8),
+        v.space("gap", 4),
+        v.space("px", 4),
+        v.space("gap", 6),
+        v.space("p", 4),
+    ];
+    let badges = r##"<div class="flex flex-wrap gap-3">
+          <a href="#" class="btn btn-neutral h-14 gap-3" aria-label="Download on the App Store"><span class="text-left leading-tight"><span class="block text-xs font-normal">Download on the</span><span class="text-lg">App Store</span></span></a>
+          <a href="#" class="btn btn-neutral h-14 gap-3" aria-label="Get it on Google Play"><span class="text-left leading-tight"><span class="block text-xs font-normal">Get it on</span><span class="text-lg">Google Play</span></span></a>
+        </div>"##;
+    let screens: String = APP_SCREENS
+        .iter()
+        .map(|screen| {
+            format!(
+                r#"      <div class="carousel-item">
+        <div class="w-48 aspect-[9/19] rounded-3xl bg-base-300 grid place-items-center text-base-content/50" role="img" aria-label="{} screen">{}</div>
+      </div>
+"#,
+                screen, screen
+            )
+        })
+        .collect();
+    let reviews: String = APP_REVIEWS
+        .iter()
+        .map(|(name, review, count)| {
+            format!(
+                r#"        <div class="card bg-base-100 shadow-sm">
+          <div class="card-body">
+            {}
+            <p>“{}”</p>
+            <p class="{text_sm} font-semibold">{}</p>
+          </div>
+        </div>
+"#,
+                stars(*count),
+                review,
+                name
+            )
+        })
+        .collect();
+    let nav = brand_nav(title, v);
+    Page::default()
+        .markup(format!(
+            r#"<div class="min-h-screen bg-base-100 {pb20} {md_pb0}">
+{nav}"#
+        ))
+        .section(
+            "hero",
+            format!(
+                r#"
+  <header class="hero min-h-[80vh] bg-base-200">
+    <div class="hero-content flex-col lg:flex-row-reverse {gap12}">
+      <div class="mockup-phone">
+        <div class="mockup-phone-camera"></div>
+        <div class="mockup-phone-display">
+          <div class="w-full h-full bg-gradient-to-b from-primary to-secondary grid place-items-center text-primary-content" role="img" aria-label="App screenshot">{title}</div>
+        </div>
+      </div>
+      <div class="max-w-xl">
+        <h1 class="{text_5xl} font-bold">{title}</h1>
+        <p class="{py6} {text_xl} text-base-content/80">Everything you need, in your pocket. Free on iOS and Android.</p>
+        {badges}
+      </div>
+    </div>
+  </header>"#
+            ),
+        )
+        .markup("\n  <main>")
+        .section(
+            "screenshots",
+            format!(
+                r#"
+  <section class="{py16}" aria-labelledby="{id}-screens">
+    <h2 id="{id}-screens" class="{text_3xl} font-bold text-center {mb8}">Take a look inside</h2>
+    <div class="carousel carousel-center w-full {gap4} {px4}">
+{screens}    </div>
+  </section>"#
+            ),
+        )
+        .section(
+            "reviews",
+            format!(
+                r#"
+  <section class="{py16} bg-base-200" aria-labelledby="{id}-reviews">
+    <div class="container mx-auto {px4}">
+      <h2 id="{id}-reviews" class="{text_3xl} font-bold text-center {mb8}">Loved by users</h2>
+      <div class="grid grid-cols-1 md:grid-cols-3 {gap6}">
+{reviews}      </div>
+    </div>
+  </section>"#
+            ),
+        )
+        .markup("\n  </main>")
+        .section(
+            "download-bar",
+            format!(
+                r##"
+  <div class="fixed bottom-0 inset-x-0 z-40 flex items-center justify-between {gap4} {p4} bg-base-100 border-t border-base-300 md:hidden">
+    <span class="font-semibold">{title}</span>
+    <a href="#" class="btn btn-primary">Get the app</a>
+  </div>"##
+            ),
+        )
+        .markup("\n</div>")
+}
+
+// ============================================================================
+// Forum
+// ============================================================================
+
+const FORUM_CATEGORIES: &[&str] = &["General", "Announcements", "Help", "Show and tell"];
+
+/// Threads in the list: `(title, author, replies, last activity, pinned)`.
+const FORUM_THREADS: &[(&str, &str, u32, &str, bool)] = &[
+    (
+        "Welcome! Read this first",
+        "Sam Rivera",
+        12,
+        "1 day ago",
+        true,
+    ),
+    (
+        "How do I customise the theme?",
+        "Lee Park",
+        8,
+        "5 minutes ago",
+        false,
+    ),
+    (
+        "Share what you've built",
+        "Ana Silva",
+        31,
+        "1 hour ago",
+        false,
+    ),
+    (
+        "Dark mode flickers on load",
+        "Tom Becker",
+        3,
+        "3 hours ago",
+        false,
+    ),
+];
+
+/// Posts in the thread view: `(author, time, body, depth)`. The first is the
+/// original post; depth indents replies.
+const FORUM_POSTS: &[(&str, &str, &str, usize)] = &[
+    (
+        "Lee Park",
+        "2 hours ago",
+        "I'd like to change the primary color without forking the theme. Is there a supported way?",
+        0,
+    ),
+    (
+        "Ana Silva",
+        "1 hour ago",
+        "Override the color variable in your own theme block; everything else is inherited.",
+        1,
+    ),
+    ("Lee Park", "45 minutes ago", "That worked, thanks!", 2),
+    (
+        "Tom Becker",
+        "5 minutes ago",
+        "There's also a theme generator if you want to tweak more.",
+        1,
+    ),
+];
+
+/// A forum: a category menu, a thread list with pinned threads, a thread
+/// view with nested replies and a reply box, and a "New topic" modal with id
+/// `{id}-new-topic`.
+pub fn forum_page(title: &str, id: &str, v: &Variant) -> Page {
+    let [
+        text_xs,
+        gap3,
+        px4,
+        py8,
+        gap6,
+        text_2xl,
+        mb4,
+        space_y8,
+        text_xl,
+        mb2,
+        space_y4,
+        text_lg,
+        mt4,
+    ] = [
+        v.text("xs"),
+        v.space("gap", 3),
+        v.space("px", 4),
+        v.space("py", 8),
+        v.space("gap", 6),
+        v.text("2xl"),
+        v.space("mb", 4),
+        v.space("space-y", 8),
+        v.text("xl"),
+        v.space("mb", 2),
+        v.space("space-y", 4),
+        v.text("lg"),
+        v.space("mt", 4),
+    ];
+    let categories: String = FORUM_CATEGORIES
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            format!(
+                "          <li><a{}>{}</a></li>\n",
+                if i == 0 {
+                    r#" class="menu-active""#
+                } else {
+                    ""
+                },
+                name
+            )
+        })
+        .collect();
+    let threads: String = FORUM_THREADS
+        .iter()
+        .map(|(topic, author, replies, activity, pinned)| {
+            format!(
+                r#"          <li class="list-row items-center">
+            {}
+            <div>
+              <a class="link link-hover font-semibold">{}</a>{}
+              <div class="{text_xs} text-base-content/60">{} · last activity {}</div>
+            </div>
+            <span class="badge badge-ghost" aria-label="{} replies">{}</span>
+          </li>
+"#,
+                avatar(author, AvatarSize::Sm, AvatarShape::Circle, None),
+                topic,
+                if *pinned {
+                    r#" <span class="badge badge-primary badge-sm">Pinned</span>"#
+                } else {
+                    ""
+                },
+                author,
+                activity,
+                replies,
+                replies
+            )
+        })
+        .collect();
+    let posts: String = FORUM_POSTS
+        .iter()
+        .map(|(author, time, body, depth)| {
+            format!(
+                r#"        <article class="card bg-base-100 shadow-sm{}">
+          <div class="card-body">
+            <div class="flex items-center {gap3}">{}<span class="font-semibold">{}</span><span class="{text_xs} text-base-content/60">{}</span></div>
+            <p>{}</p>
+          </div>
+        </article>
+"#,
+                match depth {
+                    0 => String::new(),
+                    d => format!(" ml-{} border-l-4 border-base-300", d * 8),
+                },
+                avatar(author, AvatarSize::Xs, AvatarShape::Circle, None),
+                author,
+                time,
+                body
+            )
+        })
+        .collect();
+    let options = FORUM_CATEGORIES
+        .iter()
+        .map(|name| format!("<option>{}</option>", name))
+        .collect::<String>();
+    Page::default()
+        .markup(format!(
+            r#"<div class="min-h-screen bg-base-200">
+  <div class="container mx-auto {px4} {py8} grid grid-cols-1 lg:grid-cols-4 {gap6}">"#
+        ))
+        .section(
+            "sidebar",
+            format!(
+                r#"
+    <aside>
+      <h1 class="{text_2xl} font-bold {mb4}">{title}</h1>
+      <button type="button" class="btn btn-primary w-full {mb4}" onclick="document.getElementById('{id}-new-topic').showModal()">New topic</button>
+      <nav aria-label="Categories">
+        <ul class="menu bg-base-100 rounded-box w-full">
+          <li class="menu-title">Categories</li>
+{categories}        </ul>
+      </nav>
+    </aside>"#
+            ),
+        )
+        .section(
+            "main",
+            format!(
+                r#"
+    <main class="lg:col-span-3 {space_y8}">
+      <section aria-labelledby="{id}-latest">
+        <h2 id="{id}-latest" class="{text_xl} font-bold {mb2}">Latest threads</h2>
+        <ul class="list bg-base-100 rounded-box shadow-sm">
+{threads}        </ul>
+      </section>
+      <section class="{space_y4}" aria-labelledby="{id}-thread">
+        <h2 id="{id}-thread" class="{text_xl} font-bold">How do I customise the theme?</h2>
+{posts}        <form class="card bg-base-100 shadow-sm">
+          <div class="card-body">
+            <label class="label" for="{id}-reply"><span class="label-text">Reply</span></label>
+            <textarea id="{id}-reply" name="reply" class="textarea textarea-bordered w-full" rows="4" placeholder="Write a reply..."></textarea>
+            <div class="card-actions justify-end"><button type="submit" class="btn btn-primary">Post reply</button></div>
+          </div>
+        </form>
+      </section>
+    </main>"#
+            ),
+        )
+        .markup("\n  </div>\n</div>")
+        .beside(
+            "modals",
+            format!(
+                r#"
+<dialog id="{id}-new-topic" class="modal">
+  <div class="modal-box">
+    <h3 class="{text_lg} font-bold">New topic</h3>
+    <form method="dialog" class="{space_y4} {mt4}">
+      <div class="form-control"><label class="label" for="{id}-topic-title"><span class="label-text">Title</span></label><input id="{id}-topic-title" name="title" type="text" class="input input-bordered w-full" required /></div>
+      <div class="form-control"><label class="label" for="{id}-topic-category"><span class="label-text">Category</span></label><select id="{id}-topic-category" name="category" class="select select-bordered w-full">{options}</select></div>
+      <div class="form-control"><label class="label" for="{id}-topic-body"><span class="label-text">Message</span></label><textarea id="{id}-topic-body" name="body" class="textarea textarea-bordered w-full" rows="5" required></textarea></div>
+      <div class="modal-action">
+        <button type="submit" value="cancel" class="btn btn-ghost" formnovalidate>Cancel</button>
+        <button type="submit" value="post" class="btn btn-primary">Post topic</button>
+      </div>
+    </form>
+  </div>
+  <form method="dialog" class="modal-backdrop"><button>close</button></form>
+</dialog>"#
+            ),
+        )
+}
+
+// ============================================================================
+// Analytics
+// ============================================================================
+
+/// Charts placed when none are asked for.
+pub const DEFAULT_CHARTS: &[&str] = &["line", "bar", "doughnut"];
+
+/// KPIs: `(label, value, change in percent)`.
+const ANALYTICS_KPIS: &[(&str, &str, i32)] = &[
+    ("Visitors", "48,210", 12),
+    ("Page views", "132,904", 8),
+    ("Bounce rate", "41%", -3),
+    ("Avg. session", "3m 12s", 5),
+];
+
+/// Top traffic sources: `(source, visitors, share in percent)`.
+const ANALYTICS_SOURCES: &[(&str, &str, u32)] = &[
+    ("Google", "21,480", 45),
+    ("Direct", "12,050", 25),
+    ("Twitter", "6,730", 14),
+    ("Newsletter", "4,820", 10),
+    ("GitHub", "3,130", 6),
+];
+
+/// An analytics report: a date range, KPI stats with trend badges, one chart
+/// card per `(type, canvas id)` in `charts`, a top-sources table and an export
+/// menu. The canvases are empty; `create_chart` with the same id fills one.
+pub fn analytics_page(title: &str, charts: &[(&str, String)], v: &Variant) -> Page {
+    let [px4, py8, space_y6, gap4, text_3xl, p2, gap6] = [
+        v.space("px", 4),
+        v.space("py", 8),
+        v.space("space-y", 6),
+        v.space("gap", 4),
+        v.text("3xl"),
+        v.space("p", 2),
+        v.space("gap", 6),
+    ];
+    let kpis: String = ANALYTICS_KPIS
+        .iter()
+        .map(|(label, value, change)| {
+            let (color, arrow) = if *change >= 0 {
+                ("success", "↑")
+            } else {
+                ("error", "↓")
+            };
+            format!(
+                r#"      <div class="stat">
+        <div class="stat-title">{}</div>
+        <div class="stat-value">{}</div>
+        <div class="stat-desc"><span class="badge badge-soft badge-{}">{} {}%</span> vs previous period</div>
+      </div>
+"#,
+                label,
+                value,
+                color,
+                arrow,
+                change.abs()
+            )
+        })
+        .collect();
+    let cards: String = charts
+        .iter()
+        .map(|(kind, id)| {
+            format!(
+                r#"      <div class="card bg-base-100 shadow-sm">
+        <div class="card-body">
+          <h2 class="card-title">{} chart</h2>
+          <canvas id="{}" data-chart="{}" class="w-full h-64"></canvas>
+        </div>
+      </div>
+"#,
+                title_case(kind),
+                escape(id),
+                escape(kind)
+            )
+        })
+        .collect();
+    let sources: String = ANALYTICS_SOURCES
+        .iter()
+        .map(|(source, visitors, share)| {
+            format!(
+                r#"            <tr><td>{}</td><td class="text-right">{}</td><td class="w-1/3"><progress class="progress progress-primary" value="{}" max="100" aria-label="{}%"></progress></td></tr>
+"#,
+                source, visitors, share, share
+            )
+        })
+        .collect();
+    let nav = brand_nav(title, v);
+    Page::default()
+        .markup(format!(
+            r#"<div class="min-h-screen bg-base-200">
+{nav}
+  <main class="container mx-auto {px4} {py8} {space_y6}">"#
+        ))
+        .section(
+            "toolbar",
+            format!(
+                r#"
+    <div class="flex flex-wrap items-center {gap4}">
+      <h1 class="{text_3xl} font-bold mr-auto">{title}</h1>
+      <div class="join">
+        <input type="date" name="from" class="input input-bordered join-item" aria-label="From" />
+        <input type="date" name="to" class="input input-bordered join-item" aria-label="To" />
+        <button type="button" class="btn join-item">Apply</button>
+      </div>
+      <div class="dropdown dropdown-end">
+        <div tabindex="0" role="button" class="btn btn-primary">Export</div>
+        <ul tabindex="0" class="dropdown-content menu bg-base-100 rounded-box z-10 w-40 {p2} shadow-sm">
+          <li><a>CSV</a></li>
+          <li><a>PDF</a></li>
+          <li><a>PNG</a></li>
+        </ul>
+      </div>
+    </div>"#
+            ),
+        )
+        .section(
+            "stats",
+            format!(
+                r#"
+    <div class="stats stats-vertical lg:stats-horizontal shadow w-full bg-base-100">
+{kpis}    </div>"#
+            ),
+        )
+        .section(
+            "charts",
+            format!(
+                r#"
+    <div class="grid grid-cols-1 lg:grid-cols-2 {gap6}">
+{cards}    </div>"#
+            ),
+        )
+        .section(
+            "table",
+            format!(
+                r#"
+    <div class="card bg-base-100 shadow-sm">
+      <div class="card-body">
+        <h2 class="card-title">Top sources</h2>
+        <table class="table">
+          <thead><tr><th>Source</th><th class="text-right">Visitors</th><th>Share</th></tr></thead>
+          <tbody>
+{sources}          </tbody>
+        </table>
+      </div>
+    </div>"#
+            ),
+        )
+        .markup("\n  </main>\n</div>")
+}
+
+// ============================================================================
+// Settings
+// ============================================================================
+
+/// Settings tabs: `(key, label)`. The first is shown on load.
+const SETTINGS_TABS: &[(&str, &str)] = &[
+    ("general", "General"),
+    ("security", "Security"),
+    ("api-keys", "API Keys"),
+    ("integrations", "Integrations"),
+    ("billing", "Billing"),
+];
+
+/// Notification toggles on the General tab: `(label, on)`.
+const SETTINGS_NOTIFICATIONS: &[(&str, bool)] = &[
+    ("Email notifications", true),
+    ("Push notifications", false),
+    ("Weekly digest", true),
+];
+
+/// API keys: `(name, key, created)`.
+const SETTINGS_API_KEYS: &[(&str, &str, &str)] = &[
+    ("Production", "dd_live_8f3k2m9q7x4t1c6w", "Mar 2, 2026"),
+    ("Staging", "dd_test_2b7n5r1v9h3j6p0s", "Jan 18, 2026"),
+];
+
+/// Integrations: `(name, description, connected)`.
+const SETTINGS_INTEGRATIONS: &[(&str, &str, bool)] = &[
+    ("Slack", "Post alerts to a channel.", true),
+    ("GitHub", "Link commits and pull requests.", false),
+    ("Google Drive", "Attach files from Drive.", false),
+];
+
+/// Invoices on the Billing tab: `(date, amount, status)`.
+const SETTINGS_INVOICES: &[(&str, &str, &str)] = &[
+    ("Oct 1, 2026", "$19.00", "paid"),
+    ("Sep 1, 2026", "$19.00", "paid"),
+    ("Aug 1, 2026", "$19.00", "refunded"),
+];
+
+/// A settings page: tabs for General, Security, API Keys, Integrations and
+/// Billing with one panel each. Only the first panel is shown; the rest are
+/// `hidden` until [`settings_script`] switches to them.
+pub fn settings_page(title: &str, id: &str, v: &Variant) -> Page {
+    let [
+        space_y2,
+        mt4,
+        gap4,
+        text_sm,
+        text_2xl,
+        text_base,
+        px4,
+        py8,
+        space_y6,
+        text_3xl,
+    ] = [
+        v.space("space-y", 2),
+        v.space("mt", 4),
+        v.space("gap", 4),
+        v.text("sm"),
+        v.text("2xl"),
+        v.text("base"),
+        v.space("px", 4),
+        v.space("py", 8),
+        v.space("space-y", 6),
+        v.text("3xl"),
+    ];
+    let tabs: String = SETTINGS_TABS
+        .iter()
+        .enumerate()
+        .map(|(i, (key, label))| {
+            format!(
+                r#"      <button type="button" role="tab" id="{}-{}-tab" class="tab{}" aria-selected="{}" aria-controls="{}-{}" data-tab="{}">{}</button>
+"#,
+                id,
+                key,
+                if i == 0 { " tab-active" } else { "" },
+                i == 0,
+                id,
+                key,
+                key,
+                label
+            )
+        })
+        .collect();
+    let toggles: String = SETTINGS_NOTIFICATIONS
+        .iter()
+        .map(|(label, on)| {
+            format!(
+                r#"          <label class="label cursor-pointer justify-between w-full"><span class="label-text">{}</span><input type="checkbox" class="toggle toggle-primary"{} /></label>
+"#,
+                label,
+                if *on { " checked" } else { "" }
+            )
+        })
+        .collect();
+    let keys: String = SETTINGS_API_KEYS
+        .iter()
+        .map(|(name, key, created)| {
+            format!(
+                r#"            <tr><td>{}</td><td><code>{}…</code></td><td>{}</td><td class="text-right"><button type="button" class="btn btn-ghost btn-xs" data-copy="{}" aria-label="Copy {} key">Copy</button></td></tr>
+"#,
+                name,
+                &key[..12],
+                created,
+                key,
+                name
+            )
+        })
+        .collect();
+    let integrations: String = SETTINGS_INTEGRATIONS
+        .iter()
+        .map(|(name, description, connected)| {
+            let action = if *connected {
+                r#"<span class="badge badge-success">Connected</span><button type="button" class="btn btn-ghost btn-sm">Disconnect</button>"#
+            } else {
+                r#"<button type="button" class="btn btn-primary btn-sm">Connect</button>"#
+            };
+            format!(
+                r#"          <div class="card bg-base-100 border border-base-300">
+            <div class="card-body">
+              <h3 class="card-title">{}</h3>
+              <p>{}</p>
+              <div class="card-actions justify-end items-center">{}</div>
+            </div>
+          </div>
+"#,
+                name, description, action
+            )
+        })
+        .collect();
+    let invoices: String = SETTINGS_INVOICES
+        .iter()
+        .map(|(date, amount, status)| {
+            format!(
+                r#"              <tr><td>{}</td><td>{}</td><td>{}</td><td class="text-right"><a class="link">Download</a></td></tr>
+"#,
+                date,
+                amount,
+                status_badge(status, &title_case(status), &[], false)
+            )
+        })
+        .collect();
+    let panel = |key: &str, body: String| {
+        format!(
+            r#"    <section id="{id}-{key}" role="tabpanel" aria-labelledby="{id}-{key}-tab" class="card bg-base-100 shadow-sm" data-panel="{key}"{hidden}>
+      <div class="card-body">
+{body}      </div>
+    </section>
+"#,
+            hidden = if key == SETTINGS_TABS[0].0 {
+                ""
+            } else {
+                " hidden"
+            },
+        )
+    };
+    let field = |name: &str, label: &str, autocomplete: &str| {
+        format!(
+            r#"          <div class="form-control"><label class="label" for="{id}-{name}"><span class="label-text">{label}</span></label><input id="{id}-{name}" name="{name}" type="password" autocomplete="{autocomplete}" class="input input-bordered w-full" required /></div>
+"#
+        )
+    };
+    let panels = [
+        panel(
+            "general",
+            format!(
+                r#"        <h2 class="card-title">Notifications</h2>
+        <div class="max-w-md">
+{toggles}        </div>
+"#
+            ),
+        ),
+        panel(
+            "security",
+            format!(
+                r#"        <h2 class="card-title">Change password</h2>
+        <form class="max-w-md {space_y2}">
+{}{}{}          <button type="submit" class="btn btn-primary {mt4}">Update password</button>
+        </form>
+"#,
+                field("current-password", "Current password", "current-password"),
+                field("new-password", "New password", "new-password"),
+                field("confirm-password", "Confirm new password", "new-password")
+            ),
+        ),
+        panel(
+            "api-keys",
+            format!(
+                r#"        <div class="flex justify-between items-center">
+          <h2 class="card-title">API keys</h2>
+          <button type="button" class="btn btn-primary btn-sm">Create key</button>
+        </div>
+        <table class="table">
+          <thead><tr><th>Name</th><th>Key</th><th>Created</th><th><span class="sr-only">Actions</span></th></tr></thead>
+          <tbody>
+{keys}          </tbody>
+        </table>
+"#
+            ),
+        ),
+        panel(
+            "integrations",
+            format!(
+                r#"        <h2 class="card-title">Integrations</h2>
+        <div class="grid grid-cols-1 md:grid-cols-3 {gap4}">
+{integrations}        </div>
+"#
+            ),
+        ),
+        panel(
+            "billing",
+            format!(
+                r#"        <h2 class="card-title">Billing</h2>
+        <div class="card bg-base-200">
+          <div class="card-body flex-row flex-wrap items-center justify-between">
+            <div><p class="{text_sm} text-base-content/70">Current plan</p><p class="{text_2xl} font-bold">Pro <span class="{text_base} font-normal">$19/month</span></p></div>
+            <button type="button" class="btn btn-outline">Change plan</button>
+          </div>
+        </div>
+        <h3 class="font-semibold {mt4}">Invoices</h3>
+        <table class="table">
+          <thead><tr><th>Date</th><th>Amount</th><th>Status</th><th><span class="sr-only">Invoice</span></th></tr></thead>
+          <tbody>
+{invoices}          </tbody>
+        </table>
+"#
+            ),
+        ),
+    ]
+    .concat();
+    let nav = brand_nav(title, v);
+    let script = settings_script(id);
+    Page::default()
+        .markup(format!(
+            r#"<div id="{id}" class="min-h-screen bg-base-200">
+{nav}"#
+        ))
+        .section(
+            "main",
+            format!(
+                r#"
+  <main class="container mx-auto {px4} {py8} max-w-4xl {space_y6}">
+    <h1 class="{text_3xl} font-bold">{title}</h1>
+    <div role="tablist" class="tabs tabs-box">
+{tabs}    </div>
+{panels}  </main>"#
+            ),
+        )
+        .markup("\n</div>")
+        .beside(
+            "main",
+            format!(
+                r#"
+<script>
+{script}
+</script>"#
+            ),
+        )
+}
+
+/// Switches the settings panel when a tab is clicked and copies API keys.
+pub fn settings_script(id: &str) -> String {
+    format!(
+        r#"(() => {{
+  const root = document.getElementById('{id}');
+  const tabs = root.querySelectorAll('[data-tab]');
+  tabs.forEach((tab) => tab.addEventListener('click', () => {{
+    tabs.forEach((t) => {{
+      t.classList.toggle('tab-active', t === tab);
+      t.setAttribute('aria-selected', t === tab);
+    }});
+    root.querySelectorAll('[data-panel]').forEach((panel) => {{
+      panel.hidden = panel.dataset.panel !== tab.dataset.tab;
+    }});
+  }}));
+  root.addEventListener('click', (e) => {{
+    const button = e.target.closest('[data-copy]');
+    if (button) navigator.clipboard.writeText(button.dataset.copy);
+  }});
+}})();"#
+    )
+}
+
+// ============================================================================
+// Status page
+// ============================================================================
+
+/// Days of uptime history shown per service.
+const STATUS_DAYS: usize = 90;
+
+/// Days with an issue: `(days ago, bar color)`.
+type StatusIssues = &'static [(usize, &'static str)];
+
+/// Services: `(name, state, uptime, issues)`.
+const STATUS_SERVICES: &[(&str, &str, &str, StatusIssues)] = &[
+    ("Website", "operational", "100%", &[]),
+    (
+        "API",
+        "operational",
+        "99.95%",
+        &[(12, "warning"), (47, "error")],
+    ),
+    ("Dashboard", "operational", "99.99%", &[(30, "warning")]),
+    (
+        "Webhooks",
+        "degraded",
+        "99.80%",
+        &[(0, "warning"), (5, "warning"), (66, "error")],
+    ),
+];
+
+/// Resolved incidents, newest first: `(date, title, summary)`.
+const STATUS_INCIDENTS: &[(&str, &str, &str)] = &[
+    (
+        "Oct 4",
+        "Elevated API latency",
+        "Requests in one region were slow for 20 minutes after a bad deploy. Rolled back.",
+    ),
+    (
+        "Sep 17",
+        "Dashboard login errors",
+        "Some sign-ins failed while a certificate was renewed.",
+    ),
+    (
+        "Aug 30",
+        "API outage",
+        "A database failover took the API down for 12 minutes.",
+    ),
+];
+
+/// A public status page: an overall status alert, services with a status
+/// badge and a 90-day uptime bar, an incident timeline, and a subscribe form
+/// whose email field is `{form}-email`.
+pub fn status_page(title: &str, form: &str, v: &Variant) -> Page {
+    let [
+        py4,
+        mb2,
+        text_xs,
+        mt1,
+        text_sm,
+        mb4,
+        mt2,
+        px4,
+        py10,
+        space_y8,
+        text_3xl,
+        text_xl,
+        p10,
+    ] = [
+        v.space("py", 4),
+        v.space("mb", 2),
+        v.text("xs"),
+        v.space("mt", 1),
+        v.text("sm"),
+        v.space("mb", 4),
+        v.space("mt", 2),
+        v.space("px", 4),
+        v.space("py", 10),
+        v.space("space-y", 8),
+        v.text("3xl"),
+        v.text("xl"),
+        v.space("p", 10),
+    ];
+    let states = [
+        ("operational".to_string(), "success".to_string()),
+        ("degraded".to_string(), "warning".to_string()),
+        ("outage".to_string(), "error".to_string()),
+    ];
+    let services: String = STATUS_SERVICES
+        .iter()
+        .map(|(name, state, uptime, issues)| {
+            let bar: String = (0..STATUS_DAYS)
+                .rev()
+                .map(|ago| {
+                    let color = issues
+                        .iter()
+                        .find(|(day, _)| *day == ago)
+                        .map_or("success", |(_, color)| color);
+                    format!(r#"<div class="h-8 flex-1 rounded-sm bg-{}"></div>"#, color)
+                })
+                .collect();
+            format!(
+                r#"        <li class="{py4}">
+          <div class="flex justify-between items-center {mb2}"><span class="font-semibold">{}</span>{}</div>
+          <div class="flex gap-px" role="img" aria-label="{} uptime over the last {} days">{}</div>
+          <div class="flex justify-between {text_xs} text-base-content/60 {mt1}"><span>{} days ago</span><span>{} uptime</span><span>Today</span></div>
+        </li>
+"#,
+                name,
+                status_badge(state, &title_case(state), &states, false),
+                uptime,
+                STATUS_DAYS,
+                bar,
+                STATUS_DAYS,
+                uptime
+            )
+        })
+        .collect();
+    let last = STATUS_INCIDENTS.len() - 1;
+    let incidents: String = STATUS_INCIDENTS
+        .iter()
+        .enumerate()
+        .map(|(i, (date, heading, summary))| {
+            format!(
+                r#"        <li>
+          {}<div class="timeline-start {text_sm} text-base-content/60">{}</div>
+          <div class="timeline-middle"><span class="status status-success"></span></div>
+          <div class="timeline-end timeline-box {mb4}"><p class="font-semibold">{}</p><p class="{text_sm}">{}</p><span class="badge badge-ghost badge-sm {mt2}">Resolved</span></div>
+          {}
+        </li>
+"#,
+                if i == 0 { "" } else { "<hr />" },
+                date,
+                heading,
+                summary,
+                if i == last { "" } else { "<hr />" }
+            )
+        })
+        .collect();
+    let nav = brand_nav(title, v);
+    Page::default()
+        .markup(format!(
+            r#"<div class="min-h-screen bg-base-200 flex flex-col">
+{nav}
+  <main class="container mx-auto {px4} {py10} max-w-3xl {space_y8} flex-1">
+    <h1 class="{text_3xl} font-bold">{title}</h1>"#
+        ))
+        .section(
+            "alert",
+            r#"
+    <div role="alert" class="alert alert-success"><span>All systems operational</span></div>"#,
+        )
+        .section(
+            "services",
+            format!(
+                r#"
+    <section class="card bg-base-100 shadow-sm" aria-labelledby="{form}-services">
+      <div class="card-body">
+        <h2 id="{form}-services" class="card-title">Services</h2>
+        <ul class="divide-y divide-base-200">
+{services}        </ul>
+      </div>
+    </section>"#
+            ),
+        )
+        .section(
+            "incidents",
+            format!(
+                r#"
+    <section aria-labelledby="{form}-incidents">
+      <h2 id="{form}-incidents" class="{text_xl} font-bold {mb4}">Past incidents</h2>
+      <ul class="timeline timeline-vertical timeline-compact">
+{incidents}      </ul>
+    </section>"#
+            ),
+        )
+        .markup("\n  </main>")
+        .section(
+            "footer",
+            format!(
+                r#"
+  <footer class="footer footer-center {p10} bg-base-100">
+    <form class="w-full max-w-md">
+      <label class="label" for="{form}-email"><span class="label-text">Get notified when incidents are created or resolved</span></label>
+      <div class="join w-full">
+        <input id="{form}-email" name="email" type="email" autocomplete="email" class="input input-bordered join-item w-full" placeholder="you@example.com" required />
+        <button type="submit" class="btn btn-primary join-item">Subscribe</button>
+      </div>
+    </form>
+  </footer>"#
+            ),
+        )
+        .markup("\n</div>")
+}
+
+// ============================================================================
+// Media player
+// ============================================================================
+
+const MEDIA_PLAYLISTS: &[&str] = &["Liked songs", "Focus", "Road trip", "Podcasts"];
+
+/// Albums in the grid: `(title, artist)`.
+const MEDIA_ALBUMS: &[(&str, &str)] = &[
+    ("Night Drive", "The Midnights"),
+    ("Blue Hours", "Lena Sky"),
+    ("Paper Planes", "Northbound"),
+    ("Slow Tides", "Coral Club"),
+    ("Static Bloom", "Vera Lux"),
+    ("Open Roads", "The Wanderers"),
+];
+
+/// A music player: a library drawer with playlists (pinned open on large
+/// screens), a grid of album cards with play buttons on hover, and a player
+/// bar fixed to the bottom. `drawer` is the drawer toggle's id.
+pub fn media_page(title: &str, drawer: &str, v: &Variant) -> Page {
+    let [
+        p4,
+        text_sm,
+        pb24,
+        px2,
+        text_xl,
+        p6,
+        text_3xl,
+        mb6,
+        gap4,
+        pb28,
+        px4,
+        mb4,
+        py3,
+        gap3,
+        gap1,
+        gap2,
+        text_xs,
+    ] = [
+        v.space("p", 4),
+        v.text("sm"),
+        v.space("pb", 24),
+        v.space("px", 2),
+        v.text("xl"),
+        v.space("p", 6),
+        v.text("3xl"),
+        v.space("mb", 6),
+        v.space("gap", 4),
+        v.space("pb", 28),
+        v.space("px", 4),
+        v.space("mb", 4),
+        v.space("py", 3),
+        v.space("gap", 3),
+        v.space("gap", 1),
+        v.space("gap", 2),
+        v.text("xs"),
+    ];
+    let playlists: String = MEDIA_PLAYLISTS
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            format!(
+                "          <li><a{}>{}</a></li>\n",
+                if i == 0 {
+                    r#" class="menu-active""#
+                } else {
+                    ""
+                },
+                name
+            )
+        })
+        .collect();
+    let albums: String = MEDIA_ALBUMS
+        .iter()
+        .map(|(album, artist)| {
+            format!(
+                r#"        <div class="card bg-base-200 group">
+          <figure class="relative aspect-square bg-gradient-to-br from-primary to-secondary">
+            <button type="button" class="btn btn-circle btn-primary absolute bottom-3 right-3 opacity-0 group-hover:opacity-100 focus:opacity-100 transition-opacity" aria-label="Play {}">▶</button>
+          </figure>
+          <div class="card-body {p4}"><h3 class="font-semibold truncate">{}</h3><p class="{text_sm} text-base-content/60 truncate">{}</p></div>
+        </div>
+"#,
+                album, album, artist
+            )
+        })
+        .collect();
+    let (album, artist) = MEDIA_ALBUMS[0];
+    Page::default()
+        .markup(format!(
+            r#"<div class="drawer lg:drawer-open">
+  <input id="{drawer}" type="checkbox" class="drawer-toggle" />
+  <div class="drawer-content flex flex-col min-h-screen {pb24}">"#
+        ))
+        .section(
+            "navbar",
+            format!(
+                r#"
+    <nav class="navbar bg-base-100 border-b border-base-200 lg:hidden" aria-label="Main">
+      <label for="{drawer}" class="btn btn-square btn-ghost" aria-label="Open library">☰</label>
+      <div class="flex-1 {px2} {text_xl} font-bold">{title}</div>
+    </nav>"#
+            ),
+        )
+        .section(
+            "main",
+            format!(
+                r#"
+    <main class="{p6}">
+      <h1 class="{text_3xl} font-bold {mb6}">Good evening</h1>
+      <div class="grid grid-cols-2 sm:grid-cols-3 xl:grid-cols-6 {gap4}">
+{albums}      </div>
+    </main>"#
+            ),
+        )
+        .markup("\n  </div>")
+        .section(
+            "sidebar",
+            format!(
+                r#"
+  <div class="drawer-side z-40">
+    <label for="{drawer}" class="drawer-overlay" aria-label="Close library"></label>
+    <aside class="w-64 min-h-full bg-base-200 {p4} {pb28}">
+      <div class="{text_xl} font-bold {px4} {mb4}">{title}</div>
+      <nav aria-label="Library">
+        <ul class="menu w-full">
+          <li class="menu-title">Your library</li>
+{playlists}        </ul>
+      </nav>
+    </aside>
+  </div>"#
+            ),
+        )
+        .markup("\n</div>")
+        .beside(
+            "player",
+            format!(
+                r#"
+<div class="fixed bottom-0 inset-x-0 z-50 bg-base-300 border-t border-base-content/10 {px4} {py3}">
+  <div class="flex items-center {gap4}">
+    <div class="flex items-center {gap3} w-1/4 min-w-0">
+      <div class="avatar"><div class="w-12 rounded bg-gradient-to-br from-primary to-secondary"></div></div>
+      <div class="min-w-0"><p class="font-semibold truncate">{album}</p><p class="{text_sm} text-base-content/60 truncate">{artist}</p></div>
+    </div>
+    <div class="flex-1 flex flex-col items-center {gap1}">
+      <div class="flex items-center {gap2}">
+        <button type="button" class="btn btn-circle btn-ghost btn-sm" aria-label="Previous">⏮</button>
+        <button type="button" class="btn btn-circle btn-primary" aria-label="Play">▶</button>
+        <button type="button" class="btn btn-circle btn-ghost btn-sm" aria-label="Next">⏭</button>
+      </div>
+      <div class="flex items-center {gap2} w-full max-w-xl {text_xs}">
+        <span>1:24</span>
+        <input type="range" min="0" max="215" value="84" class="range range-xs range-primary flex-1" aria-label="Seek" />
+        <span>3:35</span>
+      </div>
+    </div>
+    <div class="hidden md:flex items-center {gap2} w-1/4 justify-end">
+      <span aria-hidden="true">🔊</span>
+      <input type="range" min="0" max="100" value="70" class="range range-xs w-24" aria-label="Volume" />
+    </div>
+  </div>
+</div>"#
+            ),
+        )
+}
+
+// ============================================================================
+// Job board
+// ============================================================================
+
+/// Job types offered as filters: `(name, label, checked)`.
+const JOB_FILTERS: &[(&str, &str, bool)] = &[
+    ("remote", "Remote", true),
+    ("full-time", "Full-time", true),
+    ("part-time", "Part-time", false),
+    ("contract", "Contract", false),
+];
+
+/// Job listings shown when no `cards` count is given.
+const JOB_COUNT: usize = 4;
+
+/// Listings from `sample`, as many as its `cards` count: `(role, company,
+/// location, salary, tags)`, the tags being a work mode, a job type and a
+/// skill.
+fn job_listings(
+    sample: &mut Sample,
+) -> Vec<(
+    &'static str,
+    &'static str,
+    &'static str,
+    String,
+    [&'static str; 3],
+)> {
+    let count = sample.count("cards", JOB_COUNT);
+    let roles = sample.many(sample::JOB_TITLES, count);
+    let companies = sample.many(sample::COMPANIES, count);
+    roles
+        .into_iter()
+        .zip(companies)
+        .map(|(role, company)| {
+            let location = sample.pick(sample::CITIES);
+            let low = sample.between(6, 15) * 10;
+            let salary = format!("${}k–${}k", low, low + 30);
+            let mode = if location.starts_with("Remote") {
+                "Remote"
+            } else {
+                sample.pick(&["Hybrid", "On-site"])
+            };
+            let kind = sample.pick(&["Full-time", "Full-time", "Contract", "Part-time"]);
+            (
+                role,
+                company,
+                location,
+                salary,
+                [mode, kind, sample.pick(sample::SKILLS)],
+            )
+        })
+        .collect()
+}
+
+/// A job board: a keyword and location search, a filters sidebar, and job
+/// cards drawn from `sample` with a sort menu and pagination. Field ids
+/// start with `id`.
+pub fn jobs_page(title: &str, id: &str, v: &Variant, sample: &mut Sample) -> Page {
+    let [
+        gap3,
+        gap4,
+        text_sm,
+        gap2,
+        mt2,
+        py12,
+        text_4xl,
+        py4,
+        px4,
+        py8,
+        gap6,
+        mb2,
+        mt4,
+        text_xs,
+        space_y4,
+    ] = [
+        v.space("gap", 3),
+        v.space("gap", 4),
+        v.text("sm"),
+        v.space("gap", 2),
+        v.space("mt", 2),
+        v.space("py", 12),
+        v.text("4xl"),
+        v.space("py", 4),
+        v.space("px", 4),
+        v.space("py", 8),
+        v.space("gap", 6),
+        v.space("mb", 2),
+        v.space("mt", 4),
+        v.text("xs"),
+        v.space("space-y", 4),
+    ];
+    let filters: String = JOB_FILTERS
+        .iter()
+        .map(|(name, label, checked)| {
+            format!(
+                r#"          <label class="label cursor-pointer justify-start {gap3}"><input type="checkbox" name="type" value="{}" class="checkbox checkbox-sm checkbox-primary"{} /><span class="label-text">{}</span></label>
+"#,
+                name,
+                if *checked { " checked" } else { "" },
+                label
+            )
+        })
+        .collect();
+    let listings = job_listings(sample);
+    let jobs: String = listings
+        .iter()
+        .map(|(role, company, location, salary, tags)| {
+            let tags: String = tags
+                .iter()
+                .map(|tag| format!(r#"<span class="badge badge-outline">{}</span>"#, tag))
+                .collect::<Vec<_>>()
+                .join(" ");
+            format!(
+                r#"        <div class="card bg-base-100 shadow-sm">
+          <div class="card-body flex-row flex-wrap items-center {gap4}">
+            {}
+            <div class="flex-1 min-w-48">
+              <h2 class="card-title">{}</h2>
+              <p class="{text_sm} text-base-content/70">{} · {}</p>
+              <div class="flex flex-wrap {gap2} {mt2}">{}</div>
+            </div>
+            <div class="text-right">
+              <p class="font-semibold">{}</p>
+              <button type="button" class="btn btn-primary btn-sm {mt2}">Apply</button>
+            </div>
+          </div>
+        </div>
+"#,
+                avatar(company, AvatarSize::Md, AvatarShape::Rounded, None),
+                role,
+                company,
+                location,
+                tags,
+                salary
+            )
+        })
+        .collect();
+    let nav = brand_nav(title, v);
+    let found = match listings.len() {
+        1 => "1 job found".to_string(),
+        n => format!("{} jobs found", n),
+    };
+    Page::default()
+        .markup(format!(
+            r#"<div class="min-h-screen bg-base-200">
+{nav}"#
+        ))
+        .section(
+            "hero",
+            format!(
+                r#"
+  <header class="hero bg-base-100 {py12}">
+    <div class="hero-content text-center">
+      <div class="max-w-2xl">
+        <h1 class="{text_4xl} font-bold">{title}</h1>
+        <p class="{py4} text-base-content/70">Find your next role at a team you'll love.</p>
+        <form class="join w-full">
+          <input id="{id}-keyword" name="q" type="search" class="input input-bordered join-item w-full" placeholder="Job title or keyword" aria-label="Keyword" />
+          <input id="{id}-location" name="location" type="text" class="input input-bordered join-item w-full" placeholder="Location" aria-label="Location" />
+          <button type="submit" class="btn btn-primary join-item">Search</button>
+        </form>
+      </div>
+    </div>
+  </header>"#
+            ),
+        )
+        .markup(format!(
+            r#"
+  <div class="container mx-auto {px4} {py8} grid grid-cols-1 lg:grid-cols-4 {gap6}">"#
+        ))
+        .section(
+            "sidebar",
+            format!(
+                r#"
+    <aside class="card bg-base-100 shadow-sm h-fit">
+      <div class="card-body">
+        <h2 class="card-title">Filters</h2>
+        <fieldset>
+          <legend class="font-semibold {mb2}">Job type</legend>
+{filters}        </fieldset>
+        <label class="label {mt4}" for="{id}-salary"><span class="label-text">Minimum salary: $80k</span></label>
+        <input id="{id}-salary" name="salary" type="range" min="0" max="200" value="80" step="10" class="range range-primary range-sm" />
+        <div class="flex justify-between {text_xs} text-base-content/60"><span>$0</span><span>$200k+</span></div>
+      </div>
+    </aside>"#
+            ),
+        )
+        .section(
+            "main",
+            format!(
+                r#"
+    <main class="lg:col-span-3 {space_y4}">
+      <div class="flex flex-wrap justify-between items-center {gap2}">
+        <p class="{text_sm} text-base-content/70">{found}</p>
+        <select id="{id}-sort" name="sort" class="select select-bordered select-sm" aria-label="Sort by">
+          <option>Most recent</option>
+          <option>Highest salary</option>
+          <option>Most relevant</option>
+        </select>
+      </div>
+{jobs}      <nav class="join flex justify-center" aria-label="Pagination">
+        <button class="join-item btn btn-sm" aria-label="Previous page">«</button>
+        <button class="join-item btn btn-sm btn-active" aria-current="page">1</button>
+        <button class="join-item btn btn-sm">2</button>
+        <button class="join-item btn btn-sm">3</button>
+        <button class="join-item btn btn-sm" aria-label="Next page">»</button>
+      </nav>
+    </main>"#
+            ),
+        )
+        .markup("\n  </div>\n</div>")
+}
+
+// ============================================================================
+// Changelog
+// ============================================================================
+
+/// Change categories and their badge colors.
+const CHANGE_KINDS: &[(&str, &str)] = &[
+    ("Added", "success"),
+    ("Fixed", "info"),
+    ("Changed", "warning"),
+];
+
+/// A release's changes: `(kind, text)`.
+type Changes = &'static [(&'static str, &'static str)];
+
+/// Releases, newest first: `(version, date, changes)`.
+const RELEASES: &[(&str, &str, Changes)] = &[
+    (
+        "v2.4.0",
+        "Oct 8, 2026",
+        &[
+            ("Added", "Dark mode for the dashboard."),
+            ("Added", "CSV export on every report."),
+            ("Fixed", "Dates in the past week showing the wrong weekday."),
+        ],
+    ),
+    (
+        "v2.3.1",
+        "Sep 22, 2026",
+        &[
+            ("Fixed", "Uploads over 10 MB timing out."),
+            ("Changed", "Faster search on large workspaces."),
+        ],
+    ),
+    (
+        "v2.3.0",
+        "Sep 3, 2026",
+        &[
+            ("Added", "Keyboard shortcuts for common actions."),
+            ("Changed", "New onboarding checklist."),
+        ],
+    ),
+    (
+        "v2.2.0",
+        "Aug 12, 2026",
+        &[
+            ("Added", "Team roles."),
+            ("Fixed", "Notification emails sent twice."),
+        ],
+    ),
+    (
+        "v2.1.0",
+        "Jul 20, 2026",
+        &[
+            ("Added", "Public API."),
+            ("Changed", "Renamed Projects to Spaces."),
+        ],
+    ),
+];
+
+/// Releases shown in the timeline; the rest go in the collapse.
+const RECENT_RELEASES: usize = 3;
+
+/// Change lists grouped by kind, in [`CHANGE_KINDS`] order.
+fn release_changes(changes: Changes, indent: &str) -> String {
+    CHANGE_KINDS
+        .iter()
+        .filter_map(|(kind, color)| {
+            let items: Vec<&str> = changes
+                .iter()
+                .filter(|(k, _)| k == kind)
+                .map(|(_, text)| *text)
+                .collect();
+            if items.is_empty() {
+                return None;
+            }
+            let items: String = items
+                .iter()
+                .map(|text| format!("{indent}    <li>{text}</li>\n"))
+                .collect();
+            Some(format!(
+                "{indent}<span class=\"badge badge-{color} badge-sm\">{kind}</span>\n{indent}<ul class=\"list-disc list-inside mb-2\">\n{items}{indent}</ul>\n"
+            ))
+        })
+        .collect()
+}
+
+/// A changelog: a header with an RSS link, recent releases on a vertical
+/// timeline with changes grouped as Added, Fixed and Changed, and older
+/// releases in a collapse.
+pub fn changelog_page(title: &str, v: &Variant) -> Page {
+    let [mb6, gap2, mb2, text_sm, mb4, px4, py10, mb8, text_4xl, mt4] = [
+        v.space("mb", 6),
+        v.space("gap", 2),
+        v.space("mb", 2),
+        v.text("sm"),
+        v.space("mb", 4),
+        v.space("px", 4),
+        v.space("py", 10),
+        v.space("mb", 8),
+        v.text("4xl"),
+        v.space("mt", 4),
+    ];
+    let recent = &RELEASES[..RECENT_RELEASES.min(RELEASES.len())];
+    let older = &RELEASES[recent.len()..];
+    let timeline: String = recent
+        .iter()
+        .enumerate()
+        .map(|(i, (version, date, changes))| {
+            format!(
+                r#"      <li>
+        {}<div class="timeline-middle"><span class="status status-primary"></span></div>
+        <div class="timeline-end timeline-box w-full {mb6}">
+          <div class="flex items-center {gap2} {mb2}"><span class="badge badge-primary">{}</span><time class="{text_sm} text-base-content/60">{}</time></div>
+{}        </div>
+        {}
+      </li>
+"#,
+                if i == 0 { "" } else { "<hr />" },
+                version,
+                date,
+                release_changes(changes, "          "),
+                if i + 1 == recent.len() { "" } else { "<hr />" }
+            )
+        })
+        .collect();
+    let older: String = older
+        .iter()
+        .map(|(version, date, changes)| {
+            format!(
+                r#"          <div class="{mb4}">
+            <div class="flex items-center {gap2} {mb2}"><span class="badge badge-ghost">{}</span><time class="{text_sm} text-base-content/60">{}</time></div>
+{}          </div>
+"#,
+                version,
+                date,
+                release_changes(changes, "            ")
+            )
+        })
+        .collect();
+    let nav = brand_nav(title, v);
+    Page::default()
+        .markup(format!(
+            r#"<div class="min-h-screen bg-base-200">
+{nav}
+  <main class="container mx-auto {px4} {py10} max-w-3xl">"#
+        ))
+        .section(
+            "header",
+            format!(
+                r#"
+    <header class="flex items-center justify-between {mb8}">
+      <div>
+        <h1 class="{text_4xl} font-bold">{title}</h1>
+        <p class="text-base-content/70">New features, fixes and improvements.</p>
+      </div>
+      <a href="/changelog.xml" class="btn btn-outline btn-sm">RSS</a>
+    </header>"#
+            ),
+        )
+        .section(
+            "timeline",
+            format!(
+                r#"
+    <ul class="timeline timeline-vertical timeline-compact">
+{timeline}    </ul>"#
+            ),
+        )
+        .section(
+            "older",
+            format!(
+                r#"
+    <div class="collapse collapse-arrow bg-base-100 {mt4}">
+      <input type="checkbox" aria-label="Show older releases" />
+      <div class="collapse-title font-medium">Older releases</div>
+      <div class="collapse-content">
+{older}      </div>
+    </div>"#
+            ),
+        )
+        .markup("\n  </main>\n</div>")
+}
+
+// ============================================================================
+// Team
+// ============================================================================
+
+/// Team members: `(name, role)`.
+const TEAM_MEMBERS: &[(&str, &str)] = &[
+    ("Ada Lovelace", "Founder & CEO"),
+    ("Grace Hopper", "CTO"),
+    ("Alan Turing", "Head of Research"),
+    ("Katherine Johnson", "Lead Engineer"),
+    ("Hedy Lamarr", "Product Designer"),
+    ("Linus Pauling", "Customer Success"),
+];
+
+/// Social links on each member card: `(site, button text)`.
+const TEAM_SOCIALS: &[(&str, &str)] = &[("LinkedIn", "in"), ("X", "𝕏"), ("GitHub", "GH")];
+
+/// Values: `(icon, title, text)`.
+const TEAM_VALUES: &[(&str, &str, &str)] = &[
+    (
+        "🤝",
+        "Customers first",
+        "We build what people need, not what's easy to ship.",
+    ),
+    (
+        "🌱",
+        "Keep learning",
+        "Every mistake is a lesson we write down and share.",
+    ),
+    (
+        "🌍",
+        "Work anywhere",
+        "Fully remote across twelve time zones since day one.",
+    ),
+];
+
+const TEAM_OFFICES: &[&str] = &["Lisbon", "Toronto", "Singapore", "Team offsite"];
+
+/// An about-us page: a mission hero, member cards with social buttons, three
+/// values, an office photo carousel and a call to join that links to `/jobs`.
+/// Section headings get ids under `id`.
+pub fn team_page(title: &str, id: &str, v: &Variant) -> Page {
+    let [
+        mt2,
+        text_sm,
+        text_4xl,
+        py20,
+        text_5xl,
+        py6,
+        text_xl,
+        px4,
+        py16,
+        text_3xl,
+        mb10,
+        gap6,
+        pb16,
+        mb8,
+        gap4,
+        py4,
+    ] = [
+        v.space("mt", 2),
+        v.text("sm"),
+        v.text("4xl"),
+        v.space("py", 20),
+        v.text("5xl"),
+        v.space("py", 6),
+        v.text("xl"),
+        v.space("px", 4),
+        v.space("py", 16),
+        v.text("3xl"),
+        v.space("mb", 10),
+        v.space("gap", 6),
+        v.space("pb", 16),
+        v.space("mb", 8),
+        v.space("gap", 4),
+        v.space("py", 4),
+    ];
+    let members: String = TEAM_MEMBERS
+        .iter()
+        .map(|(name, role)| {
+            let socials: String = TEAM_SOCIALS
+                .iter()
+                .map(|(site, text)| {
+                    format!(
+                        r##"<a href="#" class="btn btn-ghost btn-circle btn-sm" aria-label="{} on {}">{}</a>"##,
+                        name, site, text
+                    )
+                })
+                .collect();
+            format!(
+                r#"      <div class="card bg-base-100 shadow-sm">
+        <div class="card-body items-center text-center">
+          {}
+          <h3 class="card-title {mt2}">{}</h3>
+          <p class="{text_sm} text-base-content/70">{}</p>
+          <div class="card-actions">{}</div>
+        </div>
+      </div>
+"#,
+                avatar(name, AvatarSize::Lg, AvatarShape::Circle, None),
+                name,
+                role,
+                socials
+            )
+        })
+        .collect();
+    let values: String = TEAM_VALUES
+        .iter()
+        .map(|(icon, heading, text)| {
+            format!(
+                r#"      <div class="card bg-base-200">
+        <div class="card-body">
+          <span class="{text_4xl}" aria-hidden="true">{}</span>
+          <h3 class="card-title">{}</h3>
+          <p>{}</p>
+        </div>
+      </div>
+"#,
+                icon, heading, text
+            )
+        })
+        .collect();
+    let offices: String = TEAM_OFFICES
+        .iter()
+        .map(|office| {
+            format!(
+                r#"      <div class="carousel-item">
+        <div class="w-80 h-56 rounded-box bg-gradient-to-br from-primary/30 to-secondary/30 grid place-items-center font-semibold" role="img" aria-label="{} office">{}</div>
+      </div>
+"#,
+                office, office
+            )
+        })
+        .collect();
+    let nav = brand_nav(title, v);
+    Page::default()
+        .markup(format!(
+            r#"<div class="min-h-screen bg-base-100">
+{nav}"#
+        ))
+        .section(
+            "hero",
+            format!(
+                r#"
+  <header class="hero bg-base-200 {py20}">
+    <div class="hero-content text-center">
+      <div class="max-w-2xl">
+        <h1 class="{text_5xl} font-bold">{title}</h1>
+        <p class="{py6} {text_xl} text-base-content/80">Our mission is to make great software tools anyone can pick up and love.</p>
+      </div>
+    </div>
+  </header>"#
+            ),
+        )
+        .markup("\n  <main>")
+        .section(
+            "members",
+            format!(
+                r#"
+  <section class="container mx-auto {px4} {py16}" aria-labelledby="{id}-members">
+    <h2 id="{id}-members" class="{text_3xl} font-bold text-center {mb10}">Meet the team</h2>
+    <div class="grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-3 {gap6}">
+{members}    </div>
+  </section>"#
+            ),
+        )
+        .section(
+            "values",
+            format!(
+                r#"
+  <section class="container mx-auto {px4} {pb16}" aria-labelledby="{id}-values">
+    <h2 id="{id}-values" class="{text_3xl} font-bold text-center {mb10}">What we value</h2>
+    <div class="grid grid-cols-1 md:grid-cols-3 {gap6}">
+{values}    </div>
+  </section>"#
+            ),
+        )
+        .section(
+            "offices",
+            format!(
+                r#"
+  <section class="{py16} bg-base-200" aria-labelledby="{id}-offices">
+    <h2 id="{id}-offices" class="{text_3xl} font-bold text-center {mb8}">Where we work</h2>
+    <div class="carousel carousel-center w-full {gap4} {px4}">
+{offices}    </div>
+  </section>"#
+            ),
+        )
+        .section(
+            "join",
+            format!(
+                r#"
+  <section class="{py20} text-center" aria-labelledby="{id}-join">
+    <h2 id="{id}-join" class="{text_3xl} font-bold">Want to join us?</h2>
+    <p class="{py4} text-base-content/70">We're hiring across engineering, design and support.</p>
+    <a href="/jobs" class="btn btn-primary btn-lg">See open roles</a>
+  </section>"#
+            ),
+        )
+        .markup("\n  </main>\n</div>")
+}
+
+// ============================================================================
+// FAQ
+// ============================================================================
+
+/// Help categories: `(key, icon, title, blurb)`. Questions are grouped under
+/// the same keys.
+pub const FAQ_CATEGORIES: &[(&str, &str, &str, &str)] = &[
+    (
+        "getting-started",
+        "🚀",
+        "Getting Started",
+        "Set up your workspace and invite your team.",
+    ),
+    (
+        "account",
+        "👤",
+        "Account",
+        "Profile, password and security settings.",
+    ),
+    (
+        "billing",
+        "💳",
+        "Billing",
+        "Plans, invoices and payment methods.",
+    ),
+];
+
+/// Questions: `(category key, question, answer)`.
+pub const FAQ_QUESTIONS: &[(&str, &str, &str)] = &[
+    (
+        "getting-started",
+        "How do I create a workspace?",
+        "Sign up, pick a name and you're in. You can create more from the workspace menu.",
+    ),
+    (
+        "getting-started",
+        "How do I invite teammates?",
+        "Open Settings → Members and send invites by email.",
+    ),
+    (
+        "account",
+        "How do I reset my password?",
+        "Use \"Forgot password\" on the sign-in page and follow the link we email you.",
+    ),
+    (
+        "account",
+        "Can I turn on two-factor authentication?",
+        "Yes, under Settings → Security. Any authenticator app works.",
+    ),
+    (
+        "billing",
+        "Can I change plans at any time?",
+        "Yes. Upgrades apply right away; downgrades apply at the next billing date.",
+    ),
+    (
+        "billing",
+        "Where can I find my invoices?",
+        "Every invoice is under Settings → Billing, ready to download as PDF.",
+    ),
+];
+
+/// A help center: a search hero, category cards, questions grouped in
+/// accordions by category, and a contact card that opens a support modal with
+/// id `{id}-support`.
+pub fn faq_page(title: &str, id: &str, v: &Variant) -> Page {
+    let [
+        text_3xl,
+        text_sm,
+        space_y2,
+        text_2xl,
+        mb2,
+        py16,
+        text_4xl,
+        py4,
+        px4,
+        py12,
+        space_y12,
+        gap4,
+        space_y10,
+        text_lg,
+        space_y4,
+        mt4,
+    ] = [
+        v.text("3xl"),
+        v.text("sm"),
+        v.space("space-y", 2),
+        v.text("2xl"),
+        v.space("mb", 2),
+        v.space("py", 16),
+        v.text("4xl"),
+        v.space("py", 4),
+        v.space("px", 4),
+        v.space("py", 12),
+        v.space("space-y", 12),
+        v.space("gap", 4),
+        v.space("space-y", 10),
+        v.text("lg"),
+        v.space("space-y", 4),
+        v.space("mt", 4),
+    ];
+    let categories: String = FAQ_CATEGORIES
+        .iter()
+        .map(|(key, icon, heading, blurb)| {
+            format!(
+                r##"      <a href="#{}-{}" class="card bg-base-100 shadow-sm hover:shadow-md transition-shadow">
+        <div class="card-body">
+          <span class="{text_3xl}" aria-hidden="true">{}</span>
+          <h2 class="card-title">{}</h2>
+          <p class="{text_sm} text-base-content/70">{}</p>
+        </div>
+      </a>
+"##,
+                id, key, icon, heading, blurb
+            )
+        })
+        .collect();
+    let groups: String = FAQ_CATEGORIES
+        .iter()
+        .map(|(key, _, heading, _)| {
+            let questions: String = FAQ_QUESTIONS
+                .iter()
+                .filter(|(category, ..)| category == key)
+                .map(|(_, question, answer)| {
+                    format!(
+                        r#"        <div class="collapse collapse-arrow bg-base-100 border border-base-300">
+          <input type="radio" name="{}-{}" aria-label="{}" />
+          <div class="collapse-title font-semibold">{}</div>
+          <div class="collapse-content {text_sm}">{}</div>
+        </div>
+"#,
+                        id, key, question, question, answer
+                    )
+                })
+                .collect();
+            format!(
+                r#"      <section id="{id}-{key}" class="{space_y2}" aria-labelledby="{id}-{key}-heading">
+        <h2 id="{id}-{key}-heading" class="{text_2xl} font-bold {mb2}">{heading}</h2>
+{questions}      </section>
+"#
+            )
+        })
+        .collect();
+    let nav = brand_nav(title, v);
+    Page::default()
+        .markup(format!(
+            r#"<div class="min-h-screen bg-base-200">
+{nav}"#
+        ))
+        .section(
+            "hero",
+            format!(
+                r#"
+  <header class="hero bg-primary text-primary-content {py16}">
+    <div class="hero-content text-center">
+      <div class="max-w-xl w-full">
+        <h1 class="{text_4xl} font-bold">{title}</h1>
+        <p class="{py4}">How can we help?</p>
+        <label class="input input-lg w-full text-base-content">
+          <svg class="h-5 w-5 opacity-50" fill="none" viewBox="0 0 24 24" stroke="currentColor" aria-hidden="true"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M21 21l-6-6m2-5a7 7 0 11-14 0 7 7 0 0114 0z" /></svg>
+          <input id="{id}-search" type="search" class="grow" placeholder="Search articles" aria-label="Search help articles" />
+        </label>
+      </div>
+    </div>
+  </header>"#
+            ),
+        )
+        .markup(format!(
+            r#"
+  <main class="container mx-auto {px4} {py12} max-w-4xl {space_y12}">"#
+        ))
+        .section(
+            "categories",
+            format!(
+                r#"
+    <nav class="grid grid-cols-1 md:grid-cols-3 {gap4}" aria-label="Help categories">
+{categories}    </nav>"#
+            ),
+        )
+        .section(
+            "questions",
+            format!(
+                r#"
+    <div class="{space_y10}">
+{groups}    </div>"#
+            ),
+        )
+        .section(
+            "contact",
+            format!(
+                r#"
+    <div class="card bg-base-100 shadow-sm">
+      <div class="card-body items-center text-center">
+        <h2 class="card-title">Still need help?</h2>
+        <p class="text-base-content/70">Our support team usually replies within a few hours.</p>
+        <button type="button" class="btn btn-primary" onclick="document.getElementById('{id}-support').showModal()">Contact support</button>
+      </div>
+    </div>"#
+            ),
+        )
+        .markup("\n  </main>\n</div>")
+        .beside(
+            "modals",
+            format!(
+                r#"
+<dialog id="{id}-support" class="modal">
+  <div class="modal-box">
+    <h3 class="{text_lg} font-bold">Contact support</h3>
+    <form method="dialog" class="{space_y4} {mt4}">
+      <div class="form-control"><label class="label" for="{id}-email"><span class="label-text">Email</span></label><input id="{id}-email" name="email" type="email" autocomplete="email" class="input input-bordered w-full" required /></div>
+      <div class="form-control"><label class="label" for="{id}-subject"><span class="label-text">Subject</span></label><input id="{id}-subject" name="subject" type="text" class="input input-bordered w-full" required /></div>
+      <div class="form-control"><label class="label" for="{id}-message"><span class="label-text">Message</span></label><textarea id="{id}-message" name="message" class="textarea textarea-bordered w-full" rows="5" required></textarea></div>
+      <div class="modal-action">
+        <button type="submit" value="cancel" class="btn btn-ghost" formnovalidate>Cancel</button>
+        <button type="submit" value="send" class="btn btn-primary">Send</button>
+      </div>
+    </form>
+  </div>
+  <form method="dialog" class="modal-backdrop"><button>close</button></form>
+</dialog>"#
+            ),
+        )
+}
+
+// ============================================================================
+// Survey
+// ============================================================================
+
+/// Quiz questions: `(question, options, index of the correct option)`.
+const SURVEY_QUESTIONS: &[(&str, &[&str], usize)] = &[
+    (
+        "Which HTML element opens as a modal?",
+        &["<div>", "<dialog>", "<section>"],
+        1,
+    ),
+    (
+        "Which CSS feature lays out items in two dimensions?",
+        &["Grid", "Float", "Inline-block"],
+        0,
+    ),
+    (
+        "Which attribute hides an element without CSS?",
+        &["invisible", "display", "hidden"],
+        2,
+    ),
+];
+
+/// A one-question-per-page quiz in a card: a progress bar, radio options as
+/// selectable cards, Back and Next buttons, and a results page with a
+/// `radial-progress` score. Later pages are `hidden` until
+/// [`survey_script`] moves to them.
+pub fn survey_page(title: &str, id: &str, v: &Variant) -> Page {
+    let [
+        gap3,
+        p4,
+        space_y3,
+        text_lg,
+        mb3,
+        text_2xl,
+        space_y6,
+        space_y4,
+    ] = [
+        v.space("gap", 3),
+        v.space("p", 4),
+        v.space("space-y", 3),
+        v.text("lg"),
+        v.space("mb", 3),
+        v.text("2xl"),
+        v.space("space-y", 6),
+        v.space("space-y", 4),
+    ];
+    let steps: String = SURVEY_QUESTIONS
+        .iter()
+        .enumerate()
+        .map(|(n, (question, options, correct))| {
+            let options: String = options
+                .iter()
+                .enumerate()
+                .map(|(i, option)| {
+                    format!(
+                        r#"            <label class="card card-border cursor-pointer has-[:checked]:border-primary has-[:checked]:bg-primary/10">
+              <div class="card-body flex-row items-center {gap3} {p4}"><input type="radio" name="{}-q{}" value="{}" class="radio radio-primary"{} /><span>{}</span></div>
+            </label>
+"#,
+                        id,
+                        n + 1,
+                        i,
+                        if i == *correct { " data-correct" } else { "" },
+                        escape(option)
+                    )
+                })
+                .collect();
+            format!(
+                r#"          <fieldset class="{space_y3}" data-step="{}"{}>
+            <legend class="font-semibold {text_lg} {mb3}">{}</legend>
+{}          </fieldset>
+"#,
+                n + 1,
+                if n == 0 { "" } else { " hidden" },
+                question,
+                options
+            )
+        })
+        .collect();
+    let count = SURVEY_QUESTIONS.len();
+    let nav = brand_nav(title, v);
+    let script = survey_script(id);
+    Page::default()
+        .markup(format!(
+            r#"<div id="{id}" class="min-h-screen bg-base-200 flex flex-col">
+{nav}
+  <main class="flex-1 grid place-items-center {p4}">"#
+        ))
+        .section(
+            "quiz",
+            format!(
+                r#"
+    <div class="card bg-base-100 shadow-xl w-full max-w-lg">
+      <div class="card-body">
+        <h1 class="card-title {text_2xl}">{title}</h1>
+        <progress class="progress progress-primary" value="1" max="{count}" aria-label="Question progress" data-survey-progress></progress>
+        <form class="{space_y6}">
+{steps}          <div class="text-center {space_y4}" data-step="results" hidden>
+            <div class="radial-progress text-primary mx-auto" style="--value:0; --size:8rem;" role="progressbar" aria-valuenow="0" aria-label="Score" data-survey-score>0%</div>
+            <p>You got <span data-survey-correct>0</span> of {count} right.</p>
+            <button type="button" class="btn btn-outline" data-survey-restart>Start over</button>
+          </div>
+          <div class="card-actions justify-between" data-survey-nav>
+            <button type="button" class="btn btn-ghost" data-survey-back disabled>Back</button>
+            <button type="button" class="btn btn-primary" data-survey-next>Next</button>
+          </div>
+        </form>
+      </div>
+    </div>"#
+            ),
+        )
+        .markup("\n  </main>\n</div>")
+        .beside(
+            "quiz",
+            format!(
+                r#"
+<script>
+{script}
+</script>"#
+            ),
+        )
+}
+
+/// Moves between survey pages once the current one is answered and scores the
+/// answers on the results page.
+pub fn survey_script(id: &str) -> String {
+    format!(
+        r#"(() => {{
+  const root = document.getElementById('{id}');
+  const steps = [...root.querySelectorAll('fieldset[data-step]')];
+  const results = root.querySelector('[data-step="results"]');
+  const progress = root.querySelector('[data-survey-progress]');
+  const back = root.querySelector('[data-survey-back]');
+  const next = root.querySelector('[data-survey-next]');
+  let current = 0;
+  const show = (i) => {{
+    current = i;
+    const done = i === steps.length;
+    steps.forEach((step, n) => {{ step.hidden = n !== i; }});
+    results.hidden = !done;
+    root.querySelector('[data-survey-nav]').hidden = done;
+    progress.value = Math.min(i + 1, steps.length);
+    back.disabled = i === 0;
+    next.textContent = i === steps.length - 1 ? 'See results' : 'Next';
+    if (done) {{
+      const correct = root.querySelectorAll('input[data-correct]:checked').length;
+      const score = Math.round((correct / steps.length) * 100);
+      const dial = root.querySelector('[data-survey-score]');
+      dial.style.setProperty('--value', score);
+      dial.setAttribute('aria-valuenow', score);
+      dial.textContent = `${{score}}%`;
+      root.querySelector('[data-survey-correct]').textContent = correct;
+    }}
+  }};
+  next.addEventListener('click', () => {{
+    if (steps[current].querySelector('input:checked')) show(current + 1);
+  }});
+  back.addEventListener('click', () => show(current - 1));
+  root.querySelector('[data-survey-restart]').addEventListener('click', () => {{
+    root.querySelector('form').reset();
+    show(0);
+  }});
+}})();"#
+    )
+}
+
+// ============================================================================
+// Error pages
+// ============================================================================
+
+/// Variants of the error page: `(keyword, code, heading, message)`. The
+/// first is the default.
+const ERROR_PAGES: &[(&str, &str, &str, &str)] = &[
+    (
+        "404",
+        "404",
+        "Page not found",
+        "The page you're looking for doesn't exist or has moved.",
+    ),
+    (
+        "500",
+        "500",
+        "Something went wrong",
+        "We hit an error on our side. Try again in a minute.",
+    ),
+    (
+        "maintenance",
+        "503",
+        "Down for maintenance",
+        "We're making some improvements and will be back shortly.",
+    ),
+];
+
+/// A full-screen error page; the first variant keyword in `title` picks
+/// 404, 500 or maintenance, and 404 is the default.
+pub fn error_page(title: &str, v: &Variant) -> Page {
+    let [text_9xl, text_3xl, mt4, py6, gap2] = [
+        v.text("9xl"),
+        v.text("3xl"),
+        v.space("mt", 4),
+        v.space("py", 6),
+        v.space("gap", 2),
+    ];
+    let lower = title.to_lowercase();
+    let (keyword, code, heading, message) = ERROR_PAGES
+        .iter()
+        .find(|(keyword, ..)| lower.contains(keyword))
+        .unwrap_or(&ERROR_PAGES[0]);
+    let alert = if *keyword == "maintenance" {
+        r#"      <div role="alert" class="alert alert-warning mb-6"><span>Scheduled maintenance: 02:00–04:00 UTC. Your data is safe.</span></div>
+"#
+    } else {
+        ""
+    };
+    Page::default()
+        .section(
+            "hero",
+            format!(
+                r#"<main class="hero min-h-screen bg-base-200 bg-[radial-gradient(circle,var(--color-base-300)_1px,transparent_1px)] bg-[size:1.5rem_1.5rem]">
+  <div class="hero-content text-center">
+    <div class="max-w-md">
+      <p class="{text_9xl} font-black text-primary/80">{code}</p>
+      <h1 class="{text_3xl} font-bold {mt4}">{heading}</h1>
+      <p class="{py6} text-base-content/70">{message}</p>
+{alert}      <nav class="flex flex-wrap justify-center {gap2}" aria-label="Ways out">
+        <a href="/" class="btn btn-primary">Go home</a>
+        <a href="/support" class="btn btn-ghost">Contact support</a>
+      </nav>
+    </div>
+  </div>
+</main>"#
+            ),
+        )
+}
+
+// ============================================================================
+// Icons
+// ============================================================================
+
+/// Inline heroicons-style outline SVGs; unknown names render nothing.
+pub fn icon(name: &str, class: &str) -> String {
+    let path = match name {
+        "check" => "M5 13l4 4L19 7",
+        "x" => "M6 18L18 6M6 6l12 12",
+        "star" => {
+            "M11.48 3.5a.56.56 0 011.04 0l2.12 5.11 5.52.44c.5.04.7.66.32.99l-4.2 3.6 1.28 5.38a.56.56 0 01-.84.61L12 16.77l-4.72 2.86a.56.56 0 01-.84-.61l1.28-5.38-4.2-3.6a.56.56 0 01.32-.99l5.52-.44 2.12-5.11z"
+        }
+        "search" => "M21 21l-6-6m2-5a7 7 0 11-14 0 7 7 0 0114 0z",
+        "menu" => "M4 6h16M4 12h16M4 18h16",
+        "cart" => {
+            "M3 3h2l.4 2M7 13h10l4-8H5.4M7 13L5.4 5M7 13l-2.293 2.293c-.63.63-.184 1.707.707 1.707H17m0 0a2 2 0 100 4 2 2 0 000-4zm-8 2a2 2 0 11-4 0 2 2 0 014 0z"
+        }
+        _ => return String::new(),
+    };
+    format!(
+        r#"<svg class="{}" fill="none" viewBox="0 0 24 24" stroke="currentColor" aria-hidden="true"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="{}" /></svg>"#,
+        class, path
+    )
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// A caller's title for a layout: at most 100 characters, whitespace as
+/// spaces, other control characters dropped, and escaped so it stays text in
+/// any element or quoted attribute.
+pub fn escape_title(text: &str) -> String {
+    let title: String = text
+        .chars()
+        .filter_map(|c| match c {
+            c if c.is_whitespace() => Some(' '),
+            c if c.is_control() => None,
+            c => Some(c),
+        })
+        .take(100)
+        .collect();
+    escape(&title)
+}
+
+/// A navbar holding only the brand, for pages with no navigation of their
+/// own, so every layout still has a `<nav>` landmark.
+fn brand_nav(title: &str, v: &Variant) -> String {
+    format!(
+        r#"  <nav class="navbar bg-base-100 border-b border-base-200" aria-label="Main"><a href="/" class="btn btn-ghost {}">{}</a></nav>"#,
+        v.text("xl"),
+        title
+    )
+}
+
+/// Escapes text for safe interpolation into markup.
+pub fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
@@ -0,0 +1,250 @@
+//! Complete `@plugin "daisyui/theme"` blocks.
+//!
+//! daisyUI 5 wants every color of a custom theme spelled out, so a theme is
+//! built from overrides on top of the stock light or dark palette: dark when
+//! the `base-100` given is a dark hex color, light otherwise. Colors are hex
+//! so chart palettes can read them back.
+
+use crate::html;
+
+/// Built-in daisyUI themes, usable as `data-theme` without any CSS.
+pub const BUILT_IN: &[&str] = &[
+    "light",
+    "dark",
+    "cupcake",
+    "bumblebee",
+    "emerald",
+    "corporate",
+    "synthwave",
+    "retro",
+    "cyberpunk",
+    "valentine",
+    "halloween",
+    "garden",
+    "forest",
+    "aqua",
+    "lofi",
+    "pastel",
+    "fantasy",
+    "wireframe",
+    "black",
+    "luxury",
+    "dracula",
+    "cmyk",
+    "autumn",
+    "business",
+    "acid",
+    "lemonade",
+    "night",
+    "coffee",
+    "winter",
+    "dim",
+    "nord",
+    "sunset",
+    "caramellatte",
+    "abyss",
+    "silk",
+];
+
+/// The theme's color variables, without `--color-`, in output order.
+pub const COLORS: &[&str] = &[
+    "base-100",
+    "base-200",
+    "base-300",
+    "base-content",
+    "primary",
+    "primary-content",
+    "secondary",
+    "secondary-content",
+    "accent",
+    "accent-content",
+    "neutral",
+    "neutral-content",
+    "info",
+    "info-content",
+    "success",
+    "success-content",
+    "warning",
+    "warning-content",
+    "error",
+    "error-content",
+];
+
+/// The shape variables that follow the colors, without `--`.
+pub const SHAPE: &[&str] = &[
+    "radius-selector",
+    "radius-field",
+    "radius-box",
+    "size-selector",
+    "size-field",
+    "border",
+    "depth",
+    "noise",
+];
+
+/// daisyUI's light theme, in [`COLORS`] order.
+const LIGHT: [&str; 20] = [
+    "#ffffff", "#f8f8f8", "#eeeeee", "#18181b", "#422ad5", "#e0e7ff", "#f43098", "#fce7f3",
+    "#00d3bb", "#084d49", "#09090b", "#e4e4e7", "#00bafe", "#042e49", "#00d390", "#004c39",
+    "#fcb700", "#793205", "#ff627d", "#4d0218",
+];
+
+/// daisyUI's dark theme, in [`COLORS`] order.
+const DARK: [&str; 20] = [
+    "#1d232a", "#191e24", "#15191e", "#ecf9ff", "#605dff", "#edf1fe", "#f43098", "#f9e4f0",
+    "#00d3bb", "#084d49", "#09090b", "#e4e4e7", "#00bafe", "#042e49", "#00d390", "#004c39",
+    "#fcb700", "#793205", "#ff627d", "#4d0218",
+];
+
+/// Defaults in [`SHAPE`] order.
+const SHAPE_DEFAULTS: [&str; 8] = [
+    "0.5rem", "0.25rem", "0.5rem", "0.25rem", "0.25rem", "1px", "1", "0",
+];
+
+/// Every variable an override may set.
+pub fn variables() -> Vec<&'static str> {
+    COLORS.iter().chain(SHAPE).copied().collect()
+}
+
+/// Checks one `variable=value` override.
+pub fn parse_override(entry: &str) -> Result<(String, String), String> {
+    let (key, value) = entry
+        .split_once('=')
+        .ok_or_else(|| format!("theme entry `{}` must be `variable=value`", entry))?;
+    let (key, value) = (key.trim(), value.trim());
+    if !variables().contains(&key) {
+        return Err(format!(
+            "unknown theme variable `{}` (variables: {})",
+            key,
+            variables().join(", ")
+        ));
+    }
+    if value.is_empty() || value.contains([';', '{', '}']) {
+        return Err(format!("theme value for `{}` must be a CSS value", key));
+    }
+    Ok((key.to_string(), value.to_string()))
+}
+
+/// Whether a `#rgb`, `#rrggbb` or `#rrggbbaa` color is dark; `None` for
+/// anything else.
+pub fn is_dark(color: &str) -> Option<bool> {
+    let hex = color.trim().strip_prefix('#')?;
+    let hex: String = match hex.len() {
+        3 => hex.chars().flat_map(|c| [c, c]).collect(),
+        6 | 8 => hex[..6].to_string(),
+        _ => return None,
+    };
+    let channel = |i: usize| {
+        let c = u8::from_str_radix(&hex[i..i + 2], 16).ok()? as f64 / 255.0;
+        Some(if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        })
+    };
+    let luminance = 0.2126 * channel(0)? + 0.7152 * channel(2)? + 0.0722 * channel(4)?;
+    Some(luminance < 0.2)
+}
+
+/// A theme named `name` with `overrides` (from [`parse_override`]) applied;
+/// later overrides win.
+pub fn plugin(name: &str, overrides: &[(String, String)]) -> String {
+    let (dark, variables) = declarations(overrides);
+    format!(
+        "@plugin \"daisyui/theme\" {{\n  name: \"{}\";\n  default: false;\n  prefersdark: false;\n  color-scheme: {};\n{}}}",
+        name,
+        if dark { "dark" } else { "light" },
+        variables
+    )
+}
+
+/// Whether the theme is dark, and one `--variable: value;` line per color
+/// and shape variable.
+pub fn declarations(overrides: &[(String, String)]) -> (bool, String) {
+    let get = |key: &str| {
+        overrides
+            .iter()
+            .rev()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    };
+    let dark = get("base-100").and_then(is_dark).unwrap_or(false);
+    let palette = if dark { &DARK } else { &LIGHT };
+    let mut css = String::new();
+    for (key, default) in COLORS.iter().zip(palette) {
+        css.push_str(&format!(
+            "  --color-{}: {};\n",
+            key,
+            get(key).unwrap_or(default)
+        ));
+    }
+    for (key, default) in SHAPE.iter().zip(SHAPE_DEFAULTS) {
+        css.push_str(&format!("  --{}: {};\n", key, get(key).unwrap_or(default)));
+    }
+    (dark, css)
+}
+
+/// Rejects names that can't be used as `data-theme` values.
+pub fn check_name(name: &str) -> Result<(), String> {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        Ok(())
+    } else {
+        Err(format!(
+            "Theme names may only contain a-z, 0-9 and `-`, got '{}'",
+            name
+        ))
+    }
+}
+
+/// Accepts a built-in theme or one of `custom` (themes generated earlier),
+/// naming both lists otherwise.
+pub fn check_known(name: &str, custom: &[String]) -> Result<(), String> {
+    if BUILT_IN.contains(&name) || custom.iter().any(|c| c == name) {
+        return Ok(());
+    }
+    let mut message = format!(
+        "Unknown theme '{}'. Built-in themes: {}",
+        name,
+        BUILT_IN.join(", ")
+    );
+    if !custom.is_empty() {
+        message.push_str(&format!(". Generated themes: {}", custom.join(", ")));
+    }
+    Err(message)
+}
+
+/// Puts `html` under `data-theme="name"`. A fragment with a single root
+/// element gets the attribute on that element (replacing one it already
+/// has); one with several, such as a page followed by its modals, is
+/// wrapped in a `<div>` so nothing is left outside the theme.
+pub fn scope(html: &str, name: &str) -> String {
+    let name: String = name
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .take(40)
+        .collect();
+    if name.is_empty() {
+        return html.to_string();
+    }
+    let elements = html::elements(html);
+    let mut roots = elements.iter().filter(|e| e.parent.is_none());
+    let (Some(root), None) = (roots.next(), roots.next()) else {
+        return format!("<div data-theme=\"{}\">\n{}\n</div>", name, html.trim());
+    };
+    let open = root.start + 1 + root.tag.len();
+    let tag_end = html[root.start..]
+        .find('>')
+        .map_or(html.len(), |e| root.start + e);
+    match html[open..tag_end].find(" data-theme=\"") {
+        Some(at) => {
+            let value = open + at + 13;
+            let end = html[value..].find('"').map_or(tag_end, |e| value + e);
+            format!("{}{}{}", &html[..value], name, &html[end..])
+        }
+        None => format!("{} data-theme=\"{}\"{}", &html[..open], name, &html[open..]),
+    }
+}
@@ -0,0 +1,408 @@
+//! Whitespace for generated markup. `pretty` puts block elements on their
+//! own lines indented two spaces per level, keeps inline elements and text
+//! together, and breaks a line of text only where the source had a line
+//! break. `minified` collapses every whitespace run between tags to a
+//! single space, or to nothing beside a block element. `<pre>`,
+//! `<textarea>`, `<script>` and `<style>` are copied as they are.
+//! `<html>` and `<body>` don't indent what they hold, so a page's body reads
+//! the same as the fragment it wraps, and a page keeps its final line break.
+
+use crate::html::{self, Element};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Style {
+    #[default]
+    Pretty,
+    Minified,
+}
+
+impl Style {
+    pub const NAMES: &[&str] = &["pretty", "minified"];
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "pretty" => Some(Self::Pretty),
+            "minified" => Some(Self::Minified),
+            _ => None,
+        }
+    }
+}
+
+/// Elements laid out on their own lines. An element holding one of these is
+/// laid out the same way.
+const BLOCK: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "base",
+    "blockquote",
+    "body",
+    "caption",
+    "col",
+    "colgroup",
+    "dd",
+    "details",
+    "dialog",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hgroup",
+    "hr",
+    "html",
+    "legend",
+    "li",
+    "link",
+    "main",
+    "menu",
+    "meta",
+    "nav",
+    "noscript",
+    "ol",
+    "optgroup",
+    "option",
+    "p",
+    "pre",
+    "script",
+    "search",
+    "section",
+    "style",
+    "summary",
+    "table",
+    "tbody",
+    "td",
+    "template",
+    "tfoot",
+    "th",
+    "thead",
+    "title",
+    "tr",
+    "ul",
+];
+
+/// Elements whose contents are copied verbatim.
+const RAW: &[&str] = &["pre", "textarea", "script", "style"];
+
+/// `html` with its whitespace laid out in `style`; see the module docs.
+pub fn tidy(html: &str, style: Style) -> String {
+    let tidy = Tidy::new(html, style);
+    let roots: Vec<usize> = (0..tidy.elements.len())
+        .filter(|&e| tidy.elements[e].parent.is_none())
+        .collect();
+    let mut pieces = tidy.content(0, html.len(), &roots, 0);
+    trim(&mut pieces);
+    match style {
+        Style::Pretty if html.ends_with('\n') => tidy.lines(&pieces, 0).join("\n") + "\n",
+        Style::Pretty => tidy.lines(&pieces, 0).join("\n"),
+        Style::Minified => join(&pieces),
+    }
+}
+
+/// A step of laid-out markup. Whitespace pieces between two words become
+/// the widest of them: nothing, a space or a line break.
+#[derive(Debug, Clone, PartialEq)]
+enum Piece {
+    Word(String),
+    /// Whitespace without a line break.
+    Space,
+    /// Whitespace with a line break in the source.
+    Newline,
+    /// The side of a block element, where whitespace doesn't render.
+    Edge,
+    /// An element already laid out over several indented lines.
+    Lines(Vec<String>),
+}
+
+impl Piece {
+    fn is_gap(&self) -> bool {
+        matches!(self, Self::Space | Self::Newline | Self::Edge)
+    }
+}
+
+struct Tidy<'a> {
+    html: &'a str,
+    style: Style,
+    elements: Vec<Element>,
+    children: Vec<Vec<usize>>,
+    /// End of each element's opening tag.
+    opens: Vec<usize>,
+    /// Start of each element's own closing tag, if it has one.
+    closes: Vec<Option<usize>>,
+    /// Where each element's markup ends. An unclosed element ends with its
+    /// last child, leaving anything after that to its parent.
+    ends: Vec<usize>,
+    blocks: Vec<bool>,
+}
+
+impl<'a> Tidy<'a> {
+    fn new(html: &'a str, style: Style) -> Self {
+        let elements = html::elements(html);
+        let mut children = vec![Vec::new(); elements.len()];
+        for (i, e) in elements.iter().enumerate() {
+            if let Some(parent) = e.parent {
+                children[parent].push(i);
+            }
+        }
+        let opens: Vec<usize> = elements.iter().map(|e| open_end(html, e)).collect();
+        let closes: Vec<Option<usize>> = elements
+            .iter()
+            .zip(&opens)
+            .map(|(e, &open)| close_start(html, e, open))
+            .collect();
+        let mut tidy = Self {
+            html,
+            style,
+            ends: vec![0; elements.len()],
+            blocks: vec![false; elements.len()],
+            elements,
+            children,
+            opens,
+            closes,
+        };
+        // Children come after their parent, so theirs are ready first.
+        for e in (0..tidy.elements.len()).rev() {
+            let kids = &tidy.children[e];
+            tidy.ends[e] = match tidy.closes[e] {
+                Some(_) => tidy.elements[e].end,
+                None => kids.last().map_or(tidy.opens[e], |&k| tidy.ends[k]),
+            };
+            tidy.blocks[e] = BLOCK.contains(&tidy.elements[e].tag.as_str())
+                || (!RAW.contains(&tidy.elements[e].tag.as_str())
+                    && kids.iter().any(|&k| tidy.blocks[k]));
+        }
+        tidy
+    }
+
+    /// Pieces for `from..to`, which holds the elements `kids` and the text,
+    /// comments and doctypes between them.
+    fn content(&self, from: usize, to: usize, kids: &[usize], depth: usize) -> Vec<Piece> {
+        let mut out = Vec::new();
+        let mut at = from;
+        for &kid in kids {
+            self.gap(&self.html[at..self.elements[kid].start], &mut out);
+            self.element(kid, depth, &mut out);
+            at = self.ends[kid];
+        }
+        self.gap(&self.html[at.min(to)..to], &mut out);
+        out
+    }
+
+    /// Text, comments and doctypes between elements.
+    fn gap(&self, mut text: &str, out: &mut Vec<Piece>) {
+        while !text.is_empty() {
+            let mark = ["<!", "<?"]
+                .iter()
+                .filter_map(|m| text.find(m))
+                .min()
+                .unwrap_or(text.len());
+            words(&text[..mark], out);
+            text = &text[mark..];
+            if text.is_empty() {
+                break;
+            }
+            let end = if text.starts_with("<!--") {
+                text.find("-->").map_or(text.len(), |e| e + 3)
+            } else {
+                text.find('>').map_or(text.len(), |e| e + 1)
+            };
+            // A comment between words keeps their spacing when minified.
+            match self.style {
+                Style::Pretty => {
+                    out.extend([Piece::Edge, Piece::Word(text[..end].into()), Piece::Edge])
+                }
+                Style::Minified => out.push(Piece::Word(text[..end].into())),
+            }
+            text = &text[end..];
+        }
+    }
+
+    fn element(&self, e: usize, depth: usize, out: &mut Vec<Piece>) {
+        let element = &self.elements[e];
+        let open = &self.html[element.start..self.opens[e]];
+        let block = self.blocks[e];
+        let piece = match self.closes[e] {
+            _ if RAW.contains(&element.tag.as_str()) => {
+                Piece::Word(self.html[element.start..self.ends[e]].into())
+            }
+            None if self.children[e].is_empty() => {
+                Piece::Word(self.html[element.start..self.ends[e]].into())
+            }
+            close => {
+                let inner_end = close.unwrap_or(self.ends[e]);
+                let close = close.map_or("", |c| &self.html[c..self.ends[e]]);
+                let inner_depth = match element.tag.as_str() {
+                    "html" | "body" => depth,
+                    _ => depth + 1,
+                };
+                let mut inner =
+                    self.content(self.opens[e], inner_end, &self.children[e], inner_depth);
+                // A block child or comment gets its own line even when it's
+                // all the element holds.
+                let edges = inner.contains(&Piece::Edge);
+                if block {
+                    trim(&mut inner);
+                }
+                let spread = self.style == Style::Pretty
+                    && (edges
+                        || inner
+                            .iter()
+                            .any(|p| matches!(p, Piece::Newline | Piece::Lines(_))));
+                if spread {
+                    let indent = "  ".repeat(depth);
+                    let mut lines = vec![format!("{}{}", indent, open)];
+                    trim(&mut inner);
+                    lines.extend(self.lines(&inner, inner_depth));
+                    if !close.is_empty() {
+                        lines.push(format!("{}{}", indent, close));
+                    }
+                    out.extend([Piece::Edge, Piece::Lines(lines), Piece::Edge]);
+                    return;
+                }
+                Piece::Word(format!("{}{}{}", open, join(&inner), close))
+            }
+        };
+        if block {
+            out.extend([Piece::Edge, piece, Piece::Edge]);
+        } else {
+            out.push(piece);
+        }
+    }
+
+    /// `pieces` as lines indented `depth` levels.
+    fn lines(&self, pieces: &[Piece], depth: usize) -> Vec<String> {
+        let indent = "  ".repeat(depth);
+        let mut lines = Vec::new();
+        let mut line = String::new();
+        let mut gap = None;
+        for piece in pieces {
+            match piece {
+                Piece::Word(word) => {
+                    match gap.take() {
+                        Some(Piece::Space) if !line.is_empty() => line.push(' '),
+                        Some(_) if !line.is_empty() => lines.push(std::mem::take(&mut line)),
+                        _ => {}
+                    }
+                    if line.is_empty() {
+                        line.push_str(&indent);
+                    }
+                    line.push_str(word);
+                }
+                Piece::Lines(block) => {
+                    if !line.is_empty() {
+                        lines.push(std::mem::take(&mut line));
+                    }
+                    lines.extend(block.iter().cloned());
+                    gap = None;
+                }
+                ws => gap = Some(wider(gap, ws)),
+            }
+        }
+        if !line.is_empty() {
+            lines.push(line);
+        }
+        lines
+    }
+}
+
+/// `text` as words and the whitespace between them.
+fn words(text: &str, out: &mut Vec<Piece>) {
+    let mut rest = text;
+    while !rest.is_empty() {
+        let space = rest.len() - rest.trim_start_matches(is_space).len();
+        if space > 0 {
+            out.push(if rest[..space].contains('\n') {
+                Piece::Newline
+            } else {
+                Piece::Space
+            });
+            rest = &rest[space..];
+            continue;
+        }
+        let word = rest.find(is_space).unwrap_or(rest.len());
+        out.push(Piece::Word(rest[..word].into()));
+        rest = &rest[word..];
+    }
+}
+
+fn is_space(c: char) -> bool {
+    c.is_ascii_whitespace()
+}
+
+/// The wider of two whitespace pieces.
+fn wider(gap: Option<Piece>, ws: &Piece) -> Piece {
+    let rank = |p: &Piece| match p {
+        Piece::Space => 0,
+        Piece::Newline => 1,
+        _ => 2,
+    };
+    match gap {
+        Some(gap) if rank(&gap) >= rank(ws) => gap,
+        _ => ws.clone(),
+    }
+}
+
+/// `pieces` on one line: whitespace beside a block element is dropped and
+/// any other run becomes one space.
+fn join(pieces: &[Piece]) -> String {
+    let mut out = String::new();
+    let mut gap = None;
+    for piece in pieces {
+        match piece {
+            Piece::Word(word) => {
+                if let Some(Piece::Space | Piece::Newline) = gap.take() {
+                    out.push(' ');
+                }
+                out.push_str(word);
+            }
+            Piece::Lines(lines) => out.push_str(&lines.join(" ")),
+            ws => gap = Some(wider(gap, ws)),
+        }
+    }
+    if let Some(Piece::Space | Piece::Newline) = gap {
+        out.push(' ');
+    }
+    out
+}
+
+/// Drops whitespace at both ends of `pieces`.
+fn trim(pieces: &mut Vec<Piece>) {
+    while pieces.last().is_some_and(Piece::is_gap) {
+        pieces.pop();
+    }
+    let lead = pieces.iter().take_while(|p| p.is_gap()).count();
+    pieces.drain(..lead);
+}
+
+/// End of `element`'s opening tag, skipping `>` inside quoted values.
+fn open_end(html: &str, element: &Element) -> usize {
+    let mut quote = None;
+    for (i, c) in html[element.start..element.end].char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            (None, '>') => return element.start + i + 1,
+            _ => {}
+        }
+    }
+    element.end
+}
+
+/// Start of `element`'s own closing tag, if its markup ends with one.
+fn close_start(html: &str, element: &Element, open: usize) -> Option<usize> {
+    let span = &html[open..element.end];
+    let at = span.rfind("</")?;
+    let name = span[at + 2..].trim_end_matches('>').trim_end();
+    name.eq_ignore_ascii_case(&element.tag).then_some(open + at)
+}
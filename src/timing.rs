@@ -0,0 +1,78 @@
+//! Slow-command detection for slash commands.
+//!
+//! The extension runs as wasm32-wasip1, where `Instant` is backed by the
+//! host's monotonic clock. A [`Clock`] that returns `None` stands in for
+//! hosts without one: the timer then falls back to the work units a command
+//! reports (docs sections scanned, files visited), so a slow path is still
+//! flagged.
+
+use std::time::{Duration, Instant};
+
+/// Commands slower than this get a footer.
+pub const SLOW: Duration = Duration::from_millis(250);
+
+/// Without a clock, this many work units count as slow.
+pub const SLOW_WORK: usize = 5_000;
+
+pub trait Clock {
+    /// Time since an arbitrary fixed origin, or `None` when unavailable.
+    fn now(&self) -> Option<Duration>;
+}
+
+pub struct SystemClock {
+    origin: Instant,
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self {
+            origin: Instant::now(),
+        }
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Option<Duration> {
+        Some(self.origin.elapsed())
+    }
+}
+
+pub struct Timer<C: Clock> {
+    clock: C,
+    start: Option<Duration>,
+    work: usize,
+}
+
+impl<C: Clock> Timer<C> {
+    pub fn start(clock: C) -> Self {
+        let start = clock.now();
+        Self {
+            clock,
+            start,
+            work: 0,
+        }
+    }
+
+    /// Records `units` of work: items scanned, files visited, and so on.
+    pub fn work(&mut self, units: usize) {
+        self.work += units;
+    }
+
+    /// Footer for a slow run, e.g. "took 412ms — narrow the query", or
+    /// `None` when the command was quick.
+    pub fn footer(&self, hint: &str) -> Option<String> {
+        let elapsed = self
+            .start
+            .zip(self.clock.now())
+            .map(|(start, now)| now.saturating_sub(start));
+        match elapsed {
+            Some(elapsed) if elapsed >= SLOW => {
+                Some(format!("took {}ms — {}", elapsed.as_millis(), hint))
+            }
+            None if self.work >= SLOW_WORK => {
+                Some(format!("processed {} items — {}", self.work, hint))
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Density variants for generated layouts.
+//!
+//! Layout builders ask a [`Variant`] for their padding, gap and text-size
+//! classes instead of hard-coding them. Builders pass the comfortable value
+//! (`space("p", 6)`, `text("4xl")`), so comfortable reproduces the layout as
+//! written and the other variants scale every token the same way.
+
+/// Values accepted for `variant`, tightest first.
+pub const NAMES: &[&str] = &["compact", "comfortable", "spacious"];
+
+pub const DEFAULT: &str = "comfortable";
+
+/// Spacing steps in Tailwind's default scale; scaled values snap to one so
+/// the classes also exist in Tailwind 3 (daisyUI 4).
+const SPACING: &[u32] = &[
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 14, 16, 20, 24, 28, 32, 36, 40, 44, 48, 52, 56, 60, 64,
+    72, 80, 96,
+];
+
+const TEXT_SIZES: &[&str] = &[
+    "xs", "sm", "base", "lg", "xl", "2xl", "3xl", "4xl", "5xl", "6xl", "7xl", "8xl", "9xl",
+];
+
+/// daisyUI component sizes (`btn-xs` to `btn-xl`).
+const COMPONENT_SIZES: &[&str] = &["xs", "sm", "md", "lg", "xl"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Variant {
+    pub name: &'static str,
+    /// Spacing multiplier as `(numerator, denominator)`.
+    scale: (u32, u32),
+    /// Steps along the type scale.
+    text_shift: i32,
+}
+
+impl Default for Variant {
+    fn default() -> Self {
+        Self::COMFORTABLE
+    }
+}
+
+impl Variant {
+    pub const COMPACT: Self = Self {
+        name: "compact",
+        scale: (1, 3),
+        text_shift: -1,
+    };
+    pub const COMFORTABLE: Self = Self {
+        name: "comfortable",
+        scale: (1, 1),
+        text_shift: 0,
+    };
+    pub const SPACIOUS: Self = Self {
+        name: "spacious",
+        scale: (5, 3),
+        text_shift: 1,
+    };
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "compact" => Some(Self::COMPACT),
+            "comfortable" => Some(Self::COMFORTABLE),
+            "spacious" => Some(Self::SPACIOUS),
+            _ => None,
+        }
+    }
+
+    /// A spacing class such as `p-6`, `py-24` or `gap-4`, scaled from its
+    /// comfortable step: `space("p", 6)` is `p-2`, `p-6` or `p-10`.
+    pub fn space(&self, prefix: &str, step: u32) -> String {
+        if step == 0 {
+            return format!("{}-0", prefix);
+        }
+        let (num, den) = self.scale;
+        let scaled = (step * num + den / 2) / den;
+        let snapped = SPACING
+            .iter()
+            .copied()
+            .min_by_key(|s| s.abs_diff(scaled))
+            .unwrap_or(step);
+        format!("{}-{}", prefix, snapped)
+    }
+
+    /// A text-size class one step down or up the type scale from the
+    /// comfortable `size`: `text("4xl")` is `text-3xl`, `text-4xl` or
+    /// `text-5xl`.
+    pub fn text(&self, size: &str) -> String {
+        let Some(i) = TEXT_SIZES.iter().position(|s| *s == size) else {
+            return format!("text-{}", size);
+        };
+        let shifted = (i as i32 + self.text_shift).clamp(0, TEXT_SIZES.len() as i32 - 1);
+        format!("text-{}", TEXT_SIZES[shifted as usize])
+    }
+
+    /// A daisyUI size one step down or up from the comfortable `size`, for
+    /// markup that sizes components instead of spacing them: `size("md")` is
+    /// `sm`, `md` or `lg`.
+    pub fn size(&self, size: &str) -> &'static str {
+        let i = COMPONENT_SIZES.iter().position(|s| *s == size).unwrap_or(2);
+        let shifted = (i as i32 + self.text_shift).clamp(0, COMPONENT_SIZES.len() as i32 - 1);
+        COMPONENT_SIZES[shifted as usize]
+    }
+}